@@ -64,6 +64,17 @@ impl Default for EngineConfig {
     }
 }
 
+impl EngineConfig {
+    /// Memory limit for the AD engine in bytes.
+    ///
+    /// This is the single MB to bytes conversion for the AD tape limit;
+    /// services pass it to `pricer_core::types::tape::Tape::set_default_memory_limit`
+    /// at start-up.
+    pub fn memory_limit_bytes(&self) -> usize {
+        self.memory_limit_mb.saturating_mul(1024 * 1024)
+    }
+}
+
 fn default_thread_pool_size() -> usize {
     num_cpus::get()
 }
@@ -117,5 +128,6 @@ mod tests {
         let settings = Settings::default();
         assert!(settings.engine.thread_pool_size > 0);
         assert_eq!(settings.engine.memory_limit_mb, 1024);
+        assert_eq!(settings.engine.memory_limit_bytes(), 1024 * 1024 * 1024);
     }
 }
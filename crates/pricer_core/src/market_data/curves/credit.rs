@@ -3,6 +3,7 @@
 //! This module provides:
 //! - [`CreditCurve`]: Generic trait for hazard rate and survival probability calculations
 //! - [`HazardRateCurve`]: Interpolated hazard rate curve implementation
+//! - [`HazardInterpolation`]: Interpolation scheme for hazard rate pillars
 
 use crate::market_data::error::MarketDataError;
 use crate::math::interpolators::{Interpolator, LinearInterpolator};
@@ -138,6 +139,19 @@ pub trait CreditCurve<T: Float> {
     }
 }
 
+/// Interpolation scheme for [`HazardRateCurve`] pillars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HazardInterpolation {
+    /// Hazard rates interpolated linearly between pillars.
+    Linear,
+
+    /// Hazard rate constant on each interval `(tᵢ₋₁, tᵢ]` (ISDA convention).
+    ///
+    /// The first pillar's rate applies from time zero, and the last pillar's
+    /// rate is extrapolated flat beyond the final tenor.
+    PiecewiseConstant,
+}
+
 /// Interpolated hazard rate curve.
 ///
 /// Stores a set of (tenor, hazard_rate) pairs and interpolates between them
@@ -184,6 +198,8 @@ pub struct HazardRateCurve<T: Float> {
     hazard_rates: Vec<T>,
     /// Whether to allow flat extrapolation beyond pillars
     allow_extrapolation: bool,
+    /// Interpolation scheme between pillars
+    interpolation: HazardInterpolation,
 }
 
 impl<T: Float> HazardRateCurve<T> {
@@ -261,9 +277,76 @@ impl<T: Float> HazardRateCurve<T> {
             tenors: tenors.to_vec(),
             hazard_rates: hazard_rates.to_vec(),
             allow_extrapolation,
+            interpolation: HazardInterpolation::Linear,
         })
     }
 
+    /// Construct a piecewise-constant hazard rate curve.
+    ///
+    /// This is the curve shape produced by ISDA CDS bootstrapping: hazard
+    /// rate `λᵢ` applies on `(tᵢ₋₁, tᵢ]` with `t₋₁ = 0`, and the last rate is
+    /// extrapolated flat. A single pillar gives a flat curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `tenors` - Tenor points in years (must be sorted, at least 1 point)
+    /// * `hazard_rates` - Hazard rate on the interval ending at each tenor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pricer_core::market_data::curves::{CreditCurve, HazardRateCurve};
+    ///
+    /// let curve = HazardRateCurve::piecewise_constant(&[1.0_f64, 3.0], &[0.01, 0.02]).unwrap();
+    ///
+    /// // ∫₀² λ = 0.01 * 1 + 0.02 * 1
+    /// let surv = curve.survival_probability(2.0).unwrap();
+    /// assert!((surv - (-0.03_f64).exp()).abs() < 1e-12);
+    /// ```
+    pub fn piecewise_constant(tenors: &[T], hazard_rates: &[T]) -> Result<Self, MarketDataError> {
+        if tenors.is_empty() {
+            return Err(MarketDataError::InsufficientData { got: 0, need: 1 });
+        }
+        if hazard_rates.len() != tenors.len() {
+            return Err(MarketDataError::InsufficientData {
+                got: hazard_rates.len(),
+                need: tenors.len(),
+            });
+        }
+
+        // Reuse validation by padding a single pillar, then drop the padding
+        let mut curve = if tenors.len() == 1 {
+            let padded_t = [tenors[0], tenors[0] + T::one()];
+            let padded_h = [hazard_rates[0], hazard_rates[0]];
+            let mut c = Self::new(&padded_t, &padded_h, true)?;
+            c.tenors.truncate(1);
+            c.hazard_rates.truncate(1);
+            c
+        } else {
+            Self::new(tenors, hazard_rates, true)?
+        };
+        curve.interpolation = HazardInterpolation::PiecewiseConstant;
+        Ok(curve)
+    }
+
+    /// Return the pillar tenors.
+    #[inline]
+    pub fn tenors(&self) -> &[T] {
+        &self.tenors
+    }
+
+    /// Return the pillar hazard rates.
+    #[inline]
+    pub fn hazard_rates(&self) -> &[T] {
+        &self.hazard_rates
+    }
+
+    /// Return the interpolation scheme.
+    #[inline]
+    pub fn interpolation(&self) -> HazardInterpolation {
+        self.interpolation
+    }
+
     /// Return the tenor domain.
     ///
     /// # Returns
@@ -294,6 +377,15 @@ impl<T: Float> HazardRateCurve<T> {
 
     /// Interpolate hazard rate at time t.
     fn interpolate_hazard_rate(&self, t: T) -> Result<T, MarketDataError> {
+        if self.interpolation == HazardInterpolation::PiecewiseConstant {
+            let i = self
+                .tenors
+                .iter()
+                .position(|&ti| t <= ti)
+                .unwrap_or(self.tenors.len() - 1);
+            return Ok(self.hazard_rates[i]);
+        }
+
         let (t_min, t_max) = self.domain();
 
         // Handle extrapolation
@@ -335,6 +427,21 @@ impl<T: Float> HazardRateCurve<T> {
             return Ok(T::zero());
         }
 
+        if self.interpolation == HazardInterpolation::PiecewiseConstant {
+            let mut integral = T::zero();
+            let mut prev_t = T::zero();
+            let last = self.tenors.len() - 1;
+            for (i, (&ti, &hi)) in self.tenors.iter().zip(self.hazard_rates.iter()).enumerate() {
+                let end_t = if t < ti || i == last { t } else { ti };
+                integral = integral + hi * (end_t - prev_t);
+                if end_t >= t {
+                    break;
+                }
+                prev_t = ti;
+            }
+            return Ok(integral);
+        }
+
         let (t_min, t_max) = self.domain();
 
         // For t <= t_min, assume constant hazard rate from 0 to t_min
@@ -524,6 +631,50 @@ impl<T: Float> CreditCurve<T> for FlatHazardRateCurve<T> {
 mod tests {
     use super::*;
 
+    // ========================================
+    // Piecewise-constant HazardRateCurve Tests
+    // ========================================
+
+    #[test]
+    fn test_piecewise_constant_hazard_rate() {
+        let curve = HazardRateCurve::piecewise_constant(&[1.0_f64, 3.0], &[0.01, 0.02]).unwrap();
+        assert_eq!(
+            curve.interpolation(),
+            HazardInterpolation::PiecewiseConstant
+        );
+        assert_eq!(curve.hazard_rate(0.5).unwrap(), 0.01);
+        assert_eq!(curve.hazard_rate(1.0).unwrap(), 0.01);
+        assert_eq!(curve.hazard_rate(1.5).unwrap(), 0.02);
+        assert_eq!(curve.hazard_rate(10.0).unwrap(), 0.02);
+    }
+
+    #[test]
+    fn test_piecewise_constant_survival() {
+        let curve = HazardRateCurve::piecewise_constant(&[1.0_f64, 3.0], &[0.01, 0.02]).unwrap();
+        let s05 = curve.survival_probability(0.5).unwrap();
+        assert!((s05 - (-0.005_f64).exp()).abs() < 1e-14);
+        let s3 = curve.survival_probability(3.0).unwrap();
+        assert!((s3 - (-0.05_f64).exp()).abs() < 1e-14);
+        // Flat extrapolation beyond last pillar
+        let s5 = curve.survival_probability(5.0).unwrap();
+        assert!((s5 - (-0.09_f64).exp()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_piecewise_constant_single_pillar() {
+        let curve = HazardRateCurve::piecewise_constant(&[5.0_f64], &[0.02]).unwrap();
+        assert_eq!(curve.len(), 1);
+        let s = curve.survival_probability(7.0).unwrap();
+        assert!((s - (-0.14_f64).exp()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_piecewise_constant_invalid() {
+        assert!(HazardRateCurve::<f64>::piecewise_constant(&[], &[]).is_err());
+        assert!(HazardRateCurve::piecewise_constant(&[1.0_f64], &[-0.01]).is_err());
+        assert!(HazardRateCurve::piecewise_constant(&[2.0_f64, 1.0], &[0.01, 0.01]).is_err());
+    }
+
     // ========================================
     // FlatHazardRateCurve Tests
     // ========================================
@@ -10,6 +10,7 @@
 //! - [`CurveSet`]: Container for managing multiple named yield curves
 //! - [`CreditCurve`]: Generic trait for hazard rate and survival probability calculations
 //! - [`HazardRateCurve`]: Interpolated hazard rate curve implementation
+//! - [`HazardInterpolation`]: Linear or piecewise-constant hazard rate pillars
 //! - [`FlatHazardRateCurve`]: Constant hazard rate curve implementation
 
 mod credit;
@@ -19,7 +20,7 @@ mod flat;
 mod interpolated;
 mod traits;
 
-pub use credit::{CreditCurve, FlatHazardRateCurve, HazardRateCurve};
+pub use credit::{CreditCurve, FlatHazardRateCurve, HazardInterpolation, HazardRateCurve};
 pub use curve_enum::{CurveEnum, CurveName};
 pub use curve_set::CurveSet;
 pub use flat::FlatCurve;
@@ -0,0 +1,510 @@
+//! Equity market data: dividends, dividend yield, repo and borrow.
+//!
+//! This module provides:
+//! - [`Dividend`]: Discrete cash or proportional dividend
+//! - [`DividendSchedule`]: Time-ordered collection of discrete dividends
+//! - [`EquityMarket`]: Spot, curves and dividends producing a consistent forward
+//!
+//! # Forward Model
+//!
+//! Continuous carry is captured by the growth factor
+//!
+//! ```text
+//! G(t) = DF_q(t)·e^(-b·t) / (DF_r(t)·e^(-s·t)) · ∏_{t_j ≤ t} (1 - p_j)
+//! ```
+//!
+//! where `DF_r` is the discount curve, `DF_q` the dividend yield curve,
+//! `s` the repo spread over the discount rate, `b` the stock borrow cost
+//! and `p_j` the proportional dividends.
+//!
+//! Cash dividends use the escrowed (spot-adjustment) model: the dividends
+//! paid up to a horizon are removed from spot in forward-neutral terms,
+//!
+//! ```text
+//! S*(T) = S₀ - Σ_{0 < t_i ≤ T} D_i / G(t_i)
+//! F(T)  = G(T)·S*(T)
+//! ```
+//!
+//! Volatility is applied to the driftless escrowed process `X` with
+//! `X(0) = S*(T)`, from which `S(t) = G(t)·(X(t) + Σ_{t < t_i ≤ T} D_i / G(t_i))`.
+//!
+//! # Example
+//!
+//! ```
+//! use pricer_core::market_data::curves::CurveEnum;
+//! use pricer_core::market_data::equity::{DividendSchedule, EquityMarket};
+//!
+//! let dividends = DividendSchedule::new()
+//!     .with_cash(0.5, 2.0)
+//!     .unwrap()
+//!     .with_proportional(0.75, 0.01)
+//!     .unwrap();
+//!
+//! let market = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05))
+//!     .unwrap()
+//!     .with_repo_spread(-0.002)
+//!     .with_borrow_cost(0.001)
+//!     .with_dividends(dividends);
+//!
+//! let forward = market.forward(1.0).unwrap();
+//! assert!(forward < 100.0 * (0.05_f64).exp());
+//! ```
+
+use super::curves::{CurveEnum, YieldCurve};
+use super::error::MarketDataError;
+use num_traits::Float;
+
+/// Discrete dividend.
+///
+/// # Variants
+///
+/// - `Cash`: Fixed cash amount paid at the ex-dividend time
+/// - `Proportional`: Fraction of the cum-dividend spot paid at the ex-dividend time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dividend<T: Float> {
+    /// Fixed cash dividend.
+    Cash {
+        /// Ex-dividend time in years
+        ex_time: T,
+        /// Cash amount
+        amount: T,
+    },
+    /// Proportional dividend (dividend yield paid at a discrete time).
+    Proportional {
+        /// Ex-dividend time in years
+        ex_time: T,
+        /// Fraction of spot, in `[0, 1)`
+        ratio: T,
+    },
+}
+
+impl<T: Float> Dividend<T> {
+    /// Return the ex-dividend time.
+    #[inline]
+    pub fn ex_time(&self) -> T {
+        match self {
+            Dividend::Cash { ex_time, .. } | Dividend::Proportional { ex_time, .. } => *ex_time,
+        }
+    }
+
+    /// Return whether this is a cash dividend.
+    #[inline]
+    pub fn is_cash(&self) -> bool {
+        matches!(self, Dividend::Cash { .. })
+    }
+}
+
+/// Time-ordered schedule of discrete dividends.
+///
+/// Dividends are kept sorted by ex-dividend time; entries sharing an
+/// ex-dividend time are applied in insertion order.
+///
+/// # Example
+///
+/// ```
+/// use pricer_core::market_data::equity::DividendSchedule;
+///
+/// let schedule = DividendSchedule::new()
+///     .with_cash(1.0_f64, 1.5)
+///     .unwrap()
+///     .with_cash(0.5, 1.5)
+///     .unwrap();
+/// assert_eq!(schedule.len(), 2);
+/// assert_eq!(schedule.dividends()[0].ex_time(), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DividendSchedule<T: Float> {
+    /// Dividends sorted by ex-dividend time
+    dividends: Vec<Dividend<T>>,
+}
+
+impl<T: Float> DividendSchedule<T> {
+    /// Construct an empty schedule.
+    pub fn new() -> Self {
+        Self {
+            dividends: Vec::new(),
+        }
+    }
+
+    /// Add a cash dividend.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InvalidMaturity` for a negative ex-dividend
+    /// time and `MarketDataError::InvalidDividend` for a negative amount.
+    pub fn with_cash(self, ex_time: T, amount: T) -> Result<Self, MarketDataError> {
+        if amount < T::zero() || amount.is_nan() {
+            return Err(MarketDataError::InvalidDividend {
+                reason: format!(
+                    "cash amount {} must be non-negative",
+                    amount.to_f64().unwrap_or(0.0)
+                ),
+            });
+        }
+        self.insert(Dividend::Cash { ex_time, amount })
+    }
+
+    /// Add a proportional dividend.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InvalidMaturity` for a negative ex-dividend
+    /// time and `MarketDataError::InvalidDividend` for a ratio outside `[0, 1)`.
+    pub fn with_proportional(self, ex_time: T, ratio: T) -> Result<Self, MarketDataError> {
+        if ratio.is_nan() || ratio < T::zero() || ratio >= T::one() {
+            return Err(MarketDataError::InvalidDividend {
+                reason: format!(
+                    "proportional ratio {} must lie in [0, 1)",
+                    ratio.to_f64().unwrap_or(0.0)
+                ),
+            });
+        }
+        self.insert(Dividend::Proportional { ex_time, ratio })
+    }
+
+    fn insert(mut self, dividend: Dividend<T>) -> Result<Self, MarketDataError> {
+        let ex_time = dividend.ex_time();
+        if ex_time.is_nan() || ex_time < T::zero() {
+            return Err(MarketDataError::InvalidMaturity {
+                t: ex_time.to_f64().unwrap_or(0.0),
+            });
+        }
+        let pos = self.dividends.partition_point(|d| d.ex_time() <= ex_time);
+        self.dividends.insert(pos, dividend);
+        Ok(self)
+    }
+
+    /// Return the dividends sorted by ex-dividend time.
+    #[inline]
+    pub fn dividends(&self) -> &[Dividend<T>] {
+        &self.dividends
+    }
+
+    /// Return the number of dividends.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dividends.len()
+    }
+
+    /// Return whether the schedule is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dividends.is_empty()
+    }
+
+    /// Iterate over dividends with ex-dividend time in `(from, to]`.
+    pub fn between(&self, from: T, to: T) -> impl Iterator<Item = &Dividend<T>> {
+        self.dividends
+            .iter()
+            .filter(move |d| d.ex_time() > from && d.ex_time() <= to)
+    }
+}
+
+/// Equity market data for a single underlying.
+///
+/// Combines spot, a discount curve, a continuous dividend yield curve,
+/// repo spread, stock borrow cost and discrete dividends into a
+/// consistent forward curve. See the [module documentation](crate::market_data::equity) for
+/// the model.
+///
+/// The dividend yield curve is expressed as a [`CurveEnum`] whose
+/// "discount factor" `DF_q(t) = exp(-∫q)` gives the yield carry.
+#[derive(Debug, Clone)]
+pub struct EquityMarket<T: Float> {
+    /// Spot price
+    spot: T,
+    /// Discount (risk-free) curve
+    discount_curve: CurveEnum<T>,
+    /// Continuous dividend yield curve
+    dividend_curve: CurveEnum<T>,
+    /// Repo spread over the discount rate (continuously compounded)
+    repo_spread: T,
+    /// Stock borrow cost (continuously compounded)
+    borrow_cost: T,
+    /// Discrete dividends
+    dividends: DividendSchedule<T>,
+}
+
+impl<T: Float> EquityMarket<T> {
+    /// Construct an equity market with no dividends, repo or borrow.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - Spot price (must be positive)
+    /// * `discount_curve` - Discount curve for the payoff currency
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InvalidSpot` if `spot <= 0`.
+    pub fn new(spot: T, discount_curve: CurveEnum<T>) -> Result<Self, MarketDataError> {
+        if spot.is_nan() || spot <= T::zero() {
+            return Err(MarketDataError::InvalidSpot {
+                spot: spot.to_f64().unwrap_or(0.0),
+            });
+        }
+        Ok(Self {
+            spot,
+            discount_curve,
+            dividend_curve: CurveEnum::flat(T::zero()),
+            repo_spread: T::zero(),
+            borrow_cost: T::zero(),
+            dividends: DividendSchedule::new(),
+        })
+    }
+
+    /// Set a flat continuous dividend yield.
+    pub fn with_dividend_yield(self, dividend_yield: T) -> Self {
+        self.with_dividend_curve(CurveEnum::flat(dividend_yield))
+    }
+
+    /// Set a continuous dividend yield term structure.
+    pub fn with_dividend_curve(mut self, dividend_curve: CurveEnum<T>) -> Self {
+        self.dividend_curve = dividend_curve;
+        self
+    }
+
+    /// Set the repo spread over the discount rate.
+    pub fn with_repo_spread(mut self, repo_spread: T) -> Self {
+        self.repo_spread = repo_spread;
+        self
+    }
+
+    /// Set the stock borrow cost.
+    pub fn with_borrow_cost(mut self, borrow_cost: T) -> Self {
+        self.borrow_cost = borrow_cost;
+        self
+    }
+
+    /// Set the discrete dividend schedule.
+    pub fn with_dividends(mut self, dividends: DividendSchedule<T>) -> Self {
+        self.dividends = dividends;
+        self
+    }
+
+    /// Return the spot price.
+    #[inline]
+    pub fn spot(&self) -> T {
+        self.spot
+    }
+
+    /// Return the discount curve.
+    #[inline]
+    pub fn discount_curve(&self) -> &CurveEnum<T> {
+        &self.discount_curve
+    }
+
+    /// Return the continuous dividend yield curve.
+    #[inline]
+    pub fn dividend_curve(&self) -> &CurveEnum<T> {
+        &self.dividend_curve
+    }
+
+    /// Return the repo spread.
+    #[inline]
+    pub fn repo_spread(&self) -> T {
+        self.repo_spread
+    }
+
+    /// Return the stock borrow cost.
+    #[inline]
+    pub fn borrow_cost(&self) -> T {
+        self.borrow_cost
+    }
+
+    /// Return the discrete dividend schedule.
+    #[inline]
+    pub fn dividends(&self) -> &DividendSchedule<T> {
+        &self.dividends
+    }
+
+    /// Return the discount factor to time `t`.
+    #[inline]
+    pub fn discount_factor(&self, t: T) -> Result<T, MarketDataError> {
+        self.discount_curve.discount_factor(t)
+    }
+
+    /// Return the growth factor `G(t)` from continuous carry and
+    /// proportional dividends paid in `(0, t]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InvalidMaturity` if `t < 0`.
+    pub fn growth_factor(&self, t: T) -> Result<T, MarketDataError> {
+        let df_r = self.discount_curve.discount_factor(t)?;
+        let df_q = self.dividend_curve.discount_factor(t)?;
+        let carry = ((self.repo_spread - self.borrow_cost) * t).exp();
+        let proportional = self
+            .dividends
+            .between(T::zero(), t)
+            .fold(T::one(), |acc, d| match d {
+                Dividend::Proportional { ratio, .. } => acc * (T::one() - *ratio),
+                Dividend::Cash { .. } => acc,
+            });
+        Ok(df_q * carry * proportional / df_r)
+    }
+
+    /// Return the forward-neutral value of cash dividends with ex-dividend
+    /// time in `(from, to]`, i.e. `Σ D_i / G(t_i)`.
+    pub fn cash_dividend_escrow(&self, from: T, to: T) -> Result<T, MarketDataError> {
+        self.dividends
+            .between(from, to)
+            .try_fold(T::zero(), |acc, d| match d {
+                Dividend::Cash { ex_time, amount } => {
+                    Ok(acc + *amount / self.growth_factor(*ex_time)?)
+                }
+                Dividend::Proportional { .. } => Ok(acc),
+            })
+    }
+
+    /// Return the escrowed spot `S*(t)`: spot less the cash dividends
+    /// paid in `(0, t]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InvalidSpot` if the dividends exhaust spot.
+    pub fn escrowed_spot(&self, t: T) -> Result<T, MarketDataError> {
+        let escrowed = self.spot - self.cash_dividend_escrow(T::zero(), t)?;
+        if escrowed.is_nan() || escrowed <= T::zero() {
+            return Err(MarketDataError::InvalidSpot {
+                spot: escrowed.to_f64().unwrap_or(0.0),
+            });
+        }
+        Ok(escrowed)
+    }
+
+    /// Return the forward price `F(t) = G(t)·S*(t)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InvalidMaturity` if `t < 0` and
+    /// `MarketDataError::InvalidSpot` if the dividends exhaust spot.
+    pub fn forward(&self, t: T) -> Result<T, MarketDataError> {
+        Ok(self.growth_factor(t)? * self.escrowed_spot(t)?)
+    }
+
+    /// Return the equivalent continuous carry yield `q_eff` to time `t`,
+    /// such that `F(t) = S₀·exp((r - q_eff)·t)` with `r` the discount
+    /// zero rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InvalidMaturity` if `t <= 0`.
+    pub fn implied_dividend_yield(&self, t: T) -> Result<T, MarketDataError> {
+        if t <= T::zero() {
+            return Err(MarketDataError::InvalidMaturity {
+                t: t.to_f64().unwrap_or(0.0),
+            });
+        }
+        let forward = self.forward(t)?;
+        let df = self.discount_factor(t)?;
+        Ok(-(forward * df / self.spot).ln() / t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn flat_market() -> EquityMarket<f64> {
+        EquityMarket::new(100.0, CurveEnum::flat(0.05)).unwrap()
+    }
+
+    #[test]
+    fn test_schedule_sorted_and_validated() {
+        let schedule = DividendSchedule::new()
+            .with_cash(1.0, 2.0)
+            .unwrap()
+            .with_proportional(0.25, 0.02)
+            .unwrap()
+            .with_cash(0.5, 1.0)
+            .unwrap();
+        let times: Vec<f64> = schedule.dividends().iter().map(|d| d.ex_time()).collect();
+        assert_eq!(times, vec![0.25, 0.5, 1.0]);
+        assert!(!schedule.dividends()[0].is_cash());
+        assert_eq!(schedule.between(0.25, 1.0).count(), 2);
+
+        assert!(DividendSchedule::new().with_cash(-0.1, 1.0).is_err());
+        assert!(DividendSchedule::new().with_cash(0.5, -1.0).is_err());
+        assert!(DividendSchedule::new().with_proportional(0.5, 1.0).is_err());
+        assert!(DividendSchedule::<f64>::new().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_spot() {
+        assert!(EquityMarket::new(0.0, CurveEnum::flat(0.05)).is_err());
+    }
+
+    #[test]
+    fn test_forward_continuous_carry() {
+        // F = S·exp((r + s - q - b)·t)
+        let market = flat_market()
+            .with_dividend_yield(0.02)
+            .with_repo_spread(0.004)
+            .with_borrow_cost(0.01);
+        let forward = market.forward(2.0).unwrap();
+        assert_relative_eq!(
+            forward,
+            100.0 * ((0.05 + 0.004 - 0.02 - 0.01) * 2.0_f64).exp(),
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            market.implied_dividend_yield(2.0).unwrap(),
+            0.02 + 0.01 - 0.004,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_forward_cash_dividends() {
+        let market = flat_market().with_dividends(
+            DividendSchedule::new()
+                .with_cash(0.5, 3.0)
+                .unwrap()
+                .with_cash(1.5, 3.0)
+                .unwrap(),
+        );
+        // Before the first ex-date the forward is unaffected
+        assert_relative_eq!(
+            market.forward(0.4).unwrap(),
+            100.0 * (0.05 * 0.4_f64).exp(),
+            epsilon = 1e-10
+        );
+        // F(1) = S·e^(r) - D·e^(r·(1 - 0.5))
+        assert_relative_eq!(
+            market.forward(1.0).unwrap(),
+            100.0 * 0.05_f64.exp() - 3.0 * (0.05 * 0.5_f64).exp(),
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            market.escrowed_spot(2.0).unwrap(),
+            100.0 - 3.0 * (-0.025_f64).exp() - 3.0 * (-0.075_f64).exp(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_forward_proportional_dividends() {
+        let market = flat_market().with_dividends(
+            DividendSchedule::new()
+                .with_proportional(0.5, 0.03)
+                .unwrap(),
+        );
+        assert_relative_eq!(
+            market.forward(1.0).unwrap(),
+            100.0 * 0.97 * 0.05_f64.exp(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_dividends_exhausting_spot() {
+        let market =
+            flat_market().with_dividends(DividendSchedule::new().with_cash(0.5, 150.0).unwrap());
+        assert!(market.forward(0.25).is_ok());
+        assert!(matches!(
+            market.forward(1.0),
+            Err(MarketDataError::InvalidSpot { .. })
+        ));
+    }
+}
@@ -23,6 +23,8 @@ use thiserror::Error;
 /// - `CurveNotFound`: Requested curve does not exist in CurveSet
 /// - `InterpolationFailed`: Interpolation operation failed
 /// - `MissingData`: Required market data is missing
+/// - `InvalidSpot`: Non-positive (or dividend-exhausted) spot price
+/// - `InvalidDividend`: Malformed dividend entry
 ///
 /// # Examples
 ///
@@ -99,6 +101,20 @@ pub enum MarketDataError {
         /// Description of what data is missing
         description: String,
     },
+
+    /// Invalid spot price (non-positive).
+    #[error("Invalid spot: S = {spot}")]
+    InvalidSpot {
+        /// The invalid spot value
+        spot: f64,
+    },
+
+    /// Invalid dividend entry.
+    #[error("Invalid dividend: {reason}")]
+    InvalidDividend {
+        /// Description of why the dividend is invalid
+        reason: String,
+    },
 }
 
 impl From<MarketDataError> for PricingError {
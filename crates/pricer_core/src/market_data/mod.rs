@@ -13,6 +13,7 @@
 //!
 //! - [`curves`]: Yield curve trait and implementations (FlatCurve, InterpolatedCurve)
 //! - [`surfaces`]: Volatility surface trait and implementations (FlatVol, InterpolatedVolSurface)
+//! - [`equity`]: Equity market data (dividends, dividend yield, repo, borrow)
 //! - [`error`]: Market data error types (MarketDataError)
 //!
 //! # Example
@@ -33,6 +34,7 @@
 //! ```
 
 pub mod curves;
+pub mod equity;
 pub mod error;
 pub mod surfaces;
 
@@ -41,6 +43,7 @@ pub use curves::{
     CreditCurve, CurveEnum, CurveInterpolation, CurveName, CurveSet, FlatCurve,
     FlatHazardRateCurve, HazardRateCurve, InterpolatedCurve, YieldCurve,
 };
+pub use equity::{Dividend, DividendSchedule, EquityMarket};
 pub use error::MarketDataError;
 pub use surfaces::{
     FlatVol, FxDeltaPoint, FxVolatilitySurface, InterpolatedVolSurface, VolatilitySurface,
@@ -0,0 +1,499 @@
+//! Differential evolution global minimiser.
+//!
+//! This module provides [`DifferentialEvolution`], a population-based
+//! derivative-free search over a bounded box. It is intended as the global
+//! stage ahead of a local optimiser when the objective has several local
+//! minima, as Heston and SABR calibration objectives often do.
+//!
+//! # Algorithm
+//!
+//! The classic `rand/1/bin` scheme of Storn and Price:
+//!
+//! ```text
+//! vᵢ = x_r1 + F·(x_r2 - x_r3)          (mutation)
+//! uᵢ,ⱼ = vᵢ,ⱼ  if U < CR or j = j_rand   (binomial crossover)
+//!        xᵢ,ⱼ  otherwise
+//! ```
+//!
+//! Mutant components falling outside the box are bounced back between the
+//! bound and the parent, so the population stays feasible for the bounds.
+//! General [`Constraint`]s are handled with Deb's feasibility rules: a
+//! feasible point beats an infeasible one, two infeasible points compare by
+//! total violation, and two feasible points compare by objective. No
+//! penalty weight needs tuning.
+//!
+//! The search is deterministic for a given [`DifferentialEvolutionConfig::seed`].
+//!
+//! # Example
+//!
+//! ```
+//! use pricer_core::math::solvers::DifferentialEvolution;
+//! use pricer_core::traits::calibration::ParameterBounds;
+//!
+//! // Rastrigin function: many local minima, global minimum at the origin
+//! let rastrigin = |p: &[f64]| {
+//!     p.iter()
+//!         .map(|x| x * x - 10.0 * (2.0 * std::f64::consts::PI * x).cos() + 10.0)
+//!         .sum::<f64>()
+//! };
+//! let bounds = [ParameterBounds::new(-5.12, 5.12); 2];
+//!
+//! let de = DifferentialEvolution::with_defaults();
+//! let result = de.minimise(rastrigin, &bounds, &[]).unwrap();
+//!
+//! assert!(result.objective < 1e-6);
+//! ```
+
+use super::minimisation::{constraint_violation, project, validate_bounds, MinimisationResult};
+use crate::traits::calibration::{Constraint, ParameterBounds};
+use crate::types::SolverError;
+
+/// Configuration for differential evolution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifferentialEvolutionConfig {
+    /// Population size; `0` selects `max(10·n, 20)` for `n` parameters.
+    pub population_size: usize,
+    /// Maximum number of generations.
+    pub max_generations: usize,
+    /// Differential weight `F` in `(0, 2]`.
+    pub differential_weight: f64,
+    /// Crossover probability `CR` in `[0, 1]`.
+    pub crossover_rate: f64,
+    /// Convergence tolerance on the population objective spread; the
+    /// parameter spread must also fall below `√tolerance` of the box width.
+    pub tolerance: f64,
+    /// Seed of the internal random number generator.
+    pub seed: u64,
+}
+
+impl Default for DifferentialEvolutionConfig {
+    fn default() -> Self {
+        Self {
+            population_size: 0,
+            max_generations: 1000,
+            differential_weight: 0.7,
+            crossover_rate: 0.9,
+            tolerance: 1e-10,
+            seed: 42,
+        }
+    }
+}
+
+impl DifferentialEvolutionConfig {
+    /// Create a new configuration with the given generation budget and seed.
+    pub fn new(max_generations: usize, seed: u64) -> Self {
+        Self {
+            max_generations,
+            seed,
+            ..Default::default()
+        }
+    }
+
+    /// Create a fast configuration for use ahead of a local optimiser.
+    pub fn fast() -> Self {
+        Self {
+            max_generations: 200,
+            tolerance: 1e-6,
+            ..Default::default()
+        }
+    }
+}
+
+/// Differential evolution minimiser over a bounded box.
+///
+/// Every parameter must have finite bounds, which define the initial
+/// sampling region as well as the feasible set.
+#[derive(Debug, Clone)]
+pub struct DifferentialEvolution {
+    config: DifferentialEvolutionConfig,
+}
+
+/// Population member with its objective and constraint violation.
+#[derive(Debug, Clone)]
+struct Member {
+    params: Vec<f64>,
+    objective: f64,
+    violation: f64,
+}
+
+impl Member {
+    /// Deb's feasibility rules; ties favour `self` so the search can drift.
+    fn no_worse_than(&self, other: &Member) -> bool {
+        match (self.violation > 0.0, other.violation > 0.0) {
+            (false, false) => self.objective <= other.objective,
+            (false, true) => true,
+            (true, false) => false,
+            (true, true) => self.violation <= other.violation,
+        }
+    }
+}
+
+impl DifferentialEvolution {
+    /// Create a new solver with the given configuration.
+    pub fn new(config: DifferentialEvolutionConfig) -> Self {
+        Self { config }
+    }
+
+    /// Create a solver with default configuration.
+    pub fn with_defaults() -> Self {
+        Self::new(DifferentialEvolutionConfig::default())
+    }
+
+    /// Get the solver configuration.
+    pub fn config(&self) -> &DifferentialEvolutionConfig {
+        &self.config
+    }
+
+    /// Minimise `objective` over the box subject to `constraints`.
+    ///
+    /// # Arguments
+    ///
+    /// * `objective` - Function to minimise
+    /// * `bounds` - Finite bounds for every parameter
+    /// * `constraints` - Additional constraints handled by feasibility rules
+    ///
+    /// # Errors
+    ///
+    /// Returns `SolverError::NumericalInstability` for empty, inverted or
+    /// infinite bounds, or an invalid configuration.
+    pub fn minimise<F>(
+        &self,
+        objective: F,
+        bounds: &[ParameterBounds],
+        constraints: &[Constraint],
+    ) -> Result<MinimisationResult, SolverError>
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        self.run(&objective, bounds, constraints, None)
+    }
+
+    /// Minimise `objective`, seeding the population with `initial_params`.
+    ///
+    /// The initial guess (projected onto the box) replaces one random
+    /// member, so the result is never worse than the guess.
+    ///
+    /// # Errors
+    ///
+    /// As for [`minimise`](Self::minimise), and additionally if the initial
+    /// guess length differs from the number of bounds.
+    pub fn minimise_with_initial<F>(
+        &self,
+        objective: F,
+        initial_params: &[f64],
+        bounds: &[ParameterBounds],
+        constraints: &[Constraint],
+    ) -> Result<MinimisationResult, SolverError>
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        if initial_params.len() != bounds.len() {
+            return Err(SolverError::NumericalInstability(format!(
+                "Initial guess has {} parameters, bounds have {}",
+                initial_params.len(),
+                bounds.len()
+            )));
+        }
+        self.run(&objective, bounds, constraints, Some(initial_params))
+    }
+
+    fn run(
+        &self,
+        objective: &dyn Fn(&[f64]) -> f64,
+        bounds: &[ParameterBounds],
+        constraints: &[Constraint],
+        initial_params: Option<&[f64]>,
+    ) -> Result<MinimisationResult, SolverError> {
+        let n = bounds.len();
+        validate_bounds(bounds, n)?;
+        if bounds
+            .iter()
+            .any(|b| !b.min.is_finite() || !b.max.is_finite())
+        {
+            return Err(SolverError::NumericalInstability(
+                "Differential evolution requires finite bounds".to_string(),
+            ));
+        }
+        self.validate_config()?;
+
+        let size = if self.config.population_size == 0 {
+            (10 * n).max(20)
+        } else {
+            self.config.population_size.max(4)
+        };
+        let mut rng = SplitMix64::new(self.config.seed);
+        let mut evaluations = 0;
+        let mut evaluate = |params: Vec<f64>| {
+            evaluations += 1;
+            let value = objective(&params);
+            Member {
+                objective: if value.is_nan() { f64::INFINITY } else { value },
+                violation: constraint_violation(constraints, &params),
+                params,
+            }
+        };
+
+        let mut population: Vec<Member> = (0..size)
+            .map(|i| {
+                let params = match initial_params {
+                    Some(initial) if i == 0 => {
+                        let mut p = initial.to_vec();
+                        project(&mut p, bounds);
+                        p
+                    }
+                    _ => bounds
+                        .iter()
+                        .map(|b| b.min + rng.next_f64() * (b.max - b.min))
+                        .collect(),
+                };
+                evaluate(params)
+            })
+            .collect();
+
+        let mut converged = false;
+        let mut generations = 0;
+        while generations < self.config.max_generations {
+            if self.has_converged(&population, bounds) {
+                converged = true;
+                break;
+            }
+            generations += 1;
+
+            let mut next = Vec::with_capacity(size);
+            for (i, parent) in population.iter().enumerate() {
+                let [r1, r2, r3] = distinct_indices(&mut rng, size, i);
+                let j_rand = rng.next_index(n);
+                let trial: Vec<f64> = (0..n)
+                    .map(|j| {
+                        if j != j_rand && rng.next_f64() >= self.config.crossover_rate {
+                            return parent.params[j];
+                        }
+                        let v = population[r1].params[j]
+                            + self.config.differential_weight
+                                * (population[r2].params[j] - population[r3].params[j]);
+                        let b = &bounds[j];
+                        if v < b.min {
+                            b.min + rng.next_f64() * (parent.params[j] - b.min)
+                        } else if v > b.max {
+                            b.max - rng.next_f64() * (b.max - parent.params[j])
+                        } else {
+                            v
+                        }
+                    })
+                    .collect();
+
+                let candidate = evaluate(trial);
+                if candidate.no_worse_than(parent) {
+                    next.push(candidate);
+                } else {
+                    next.push(parent.clone());
+                }
+            }
+            population = next;
+        }
+        if !converged {
+            converged = self.has_converged(&population, bounds);
+        }
+
+        let best = population
+            .into_iter()
+            .reduce(|best, m| if best.no_worse_than(&m) { best } else { m })
+            .expect("population is non-empty");
+
+        Ok(MinimisationResult {
+            params: best.params,
+            objective: best.objective,
+            iterations: generations,
+            function_evaluations: evaluations,
+            converged: converged && best.violation == 0.0,
+        })
+    }
+
+    fn validate_config(&self) -> Result<(), SolverError> {
+        let f = self.config.differential_weight;
+        let cr = self.config.crossover_rate;
+        if f.is_nan() || f <= 0.0 || f > 2.0 {
+            return Err(SolverError::NumericalInstability(format!(
+                "Differential weight must be in (0, 2], got {}",
+                f
+            )));
+        }
+        if cr.is_nan() || !(0.0..=1.0).contains(&cr) {
+            return Err(SolverError::NumericalInstability(format!(
+                "Crossover rate must be in [0, 1], got {}",
+                cr
+            )));
+        }
+        Ok(())
+    }
+
+    /// All members feasible, with objectives within `tolerance` and every
+    /// parameter within `√tolerance` of the box width.
+    ///
+    /// The parameter test stops a population sitting on a plateau of the
+    /// objective from being reported as converged.
+    fn has_converged(&self, population: &[Member], bounds: &[ParameterBounds]) -> bool {
+        if population.iter().any(|m| m.violation > 0.0) {
+            return false;
+        }
+        let (lo, hi) = spread(population.iter().map(|m| m.objective));
+        if !hi.is_finite() || hi - lo > self.config.tolerance * (1.0 + lo.abs()) {
+            return false;
+        }
+        let param_tolerance = self.config.tolerance.sqrt();
+        bounds.iter().enumerate().all(|(j, b)| {
+            let (lo, hi) = spread(population.iter().map(|m| m.params[j]));
+            hi - lo <= param_tolerance * (b.max - b.min)
+        })
+    }
+}
+
+/// Minimum and maximum of the values.
+fn spread(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+        (lo.min(v), hi.max(v))
+    })
+}
+
+/// Three distinct indices in `0..size`, all different from `exclude`.
+fn distinct_indices(rng: &mut SplitMix64, size: usize, exclude: usize) -> [usize; 3] {
+    let mut picked = [exclude; 3];
+    for k in 0..3 {
+        loop {
+            let candidate = rng.next_index(size);
+            if candidate != exclude && !picked[..k].contains(&candidate) {
+                picked[k] = candidate;
+                break;
+            }
+        }
+    }
+    picked
+}
+
+/// SplitMix64 generator; small, fast and sufficient for population sampling.
+#[derive(Debug, Clone)]
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform in `0..n`.
+    fn next_index(&mut self, n: usize) -> usize {
+        ((self.next_f64() * n as f64) as usize).min(n - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::f64::consts::PI;
+
+    fn rastrigin(p: &[f64]) -> f64 {
+        p.iter()
+            .map(|x| x * x - 10.0 * (2.0 * PI * x).cos() + 10.0)
+            .sum()
+    }
+
+    #[test]
+    fn test_rastrigin_global_minimum() {
+        let bounds = [ParameterBounds::new(-5.12, 5.12); 3];
+        let result = DifferentialEvolution::with_defaults()
+            .minimise(rastrigin, &bounds, &[])
+            .unwrap();
+        assert!(result.converged);
+        for x in &result.params {
+            assert!(x.abs() < 1e-4, "params = {:?}", result.params);
+        }
+    }
+
+    #[test]
+    fn test_deterministic_for_seed() {
+        let bounds = [ParameterBounds::new(-5.12, 5.12); 2];
+        let config = DifferentialEvolutionConfig::new(50, 7);
+        let a = DifferentialEvolution::new(config)
+            .minimise(rastrigin, &bounds, &[])
+            .unwrap();
+        let b = DifferentialEvolution::new(config)
+            .minimise(rastrigin, &bounds, &[])
+            .unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_linear_constraint_is_respected() {
+        // Minimise (x - 1)² + (y - 1)² subject to x + y <= 1
+        let constraints = [Constraint::LinearInequality {
+            coefficients: vec![1.0, 1.0],
+            rhs: 1.0,
+        }];
+        let bounds = [ParameterBounds::new(-2.0, 2.0); 2];
+        let result = DifferentialEvolution::with_defaults()
+            .minimise(
+                |p| (p[0] - 1.0).powi(2) + (p[1] - 1.0).powi(2),
+                &bounds,
+                &constraints,
+            )
+            .unwrap();
+        assert!(constraints[0].is_satisfied(&result.params));
+        assert_relative_eq!(result.params[0], 0.5, epsilon = 1e-3);
+        assert_relative_eq!(result.params[1], 0.5, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_initial_guess_is_kept_when_optimal() {
+        let bounds = [ParameterBounds::new(0.0, 1.0); 2];
+        let config = DifferentialEvolutionConfig::new(1, 3);
+        let result = DifferentialEvolution::new(config)
+            .minimise_with_initial(
+                |p| (p[0] - 0.25).powi(2) + (p[1] - 0.75).powi(2),
+                &[0.25, 0.75],
+                &bounds,
+                &[],
+            )
+            .unwrap();
+        assert_eq!(result.objective, 0.0);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let de = DifferentialEvolution::with_defaults();
+        assert!(de.minimise(|_| 0.0, &[], &[]).is_err());
+        assert!(de
+            .minimise(|_| 0.0, &[ParameterBounds::positive()], &[])
+            .is_err());
+        assert!(de
+            .minimise_with_initial(
+                |_| 0.0,
+                &[0.5, 0.5],
+                &[ParameterBounds::unit_interval()],
+                &[]
+            )
+            .is_err());
+
+        let config = DifferentialEvolutionConfig {
+            crossover_rate: 1.5,
+            ..Default::default()
+        };
+        assert!(DifferentialEvolution::new(config)
+            .minimise(|_| 0.0, &[ParameterBounds::unit_interval()], &[])
+            .is_err());
+    }
+}
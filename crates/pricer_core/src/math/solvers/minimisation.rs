@@ -0,0 +1,162 @@
+//! Shared types for bound-constrained minimisers.
+//!
+//! This module provides the result type returned by [`LbfgsbSolver`] and
+//! [`DifferentialEvolution`], and helpers translating calibration
+//! [`Constraint`]s into box bounds and violation measures.
+//!
+//! [`LbfgsbSolver`]: super::LbfgsbSolver
+//! [`DifferentialEvolution`]: super::DifferentialEvolution
+
+use crate::traits::calibration::{Constraint, ParameterBounds};
+use crate::types::SolverError;
+
+/// Result of a scalar minimisation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimisationResult {
+    /// Final parameters (always within the bounds).
+    pub params: Vec<f64>,
+    /// Objective value at the final parameters.
+    pub objective: f64,
+    /// Number of iterations (or generations) performed.
+    pub iterations: usize,
+    /// Number of objective evaluations.
+    pub function_evaluations: usize,
+    /// Whether a convergence criterion was met.
+    pub converged: bool,
+}
+
+/// Collect box bounds for `n_params` parameters.
+///
+/// Starts from `bounds` (missing entries are unbounded) and intersects the
+/// [`Constraint::Bounds`] entries of `constraints`. Other constraint kinds
+/// are ignored.
+///
+/// # Example
+///
+/// ```
+/// use pricer_core::math::solvers::bounds_from_constraints;
+/// use pricer_core::traits::calibration::{Constraint, ParameterBounds};
+///
+/// let bounds = bounds_from_constraints(
+///     &[ParameterBounds::new(0.0, 2.0)],
+///     &[Constraint::bounds(0, 0.5, 3.0), Constraint::positive(1)],
+///     2,
+/// );
+/// assert_eq!(bounds[0], ParameterBounds::new(0.5, 2.0));
+/// assert!(bounds[1].min > 0.0);
+/// ```
+pub fn bounds_from_constraints(
+    bounds: &[ParameterBounds],
+    constraints: &[Constraint],
+    n_params: usize,
+) -> Vec<ParameterBounds> {
+    let mut result: Vec<ParameterBounds> = (0..n_params)
+        .map(|i| bounds.get(i).copied().unwrap_or_default())
+        .collect();
+    for constraint in constraints {
+        if let Constraint::Bounds {
+            param_index,
+            bounds,
+        } = constraint
+        {
+            if let Some(b) = result.get_mut(*param_index) {
+                b.min = b.min.max(bounds.min);
+                b.max = b.max.min(bounds.max);
+            }
+        }
+    }
+    result
+}
+
+/// Total violation of the non-box constraints at `params`.
+///
+/// Box constraints are excluded because the bounded minimisers keep
+/// iterates feasible for them by construction.
+pub fn constraint_violation(constraints: &[Constraint], params: &[f64]) -> f64 {
+    constraints
+        .iter()
+        .filter(|c| !matches!(c, Constraint::Bounds { .. }))
+        .map(|c| c.violation(params))
+        .sum()
+}
+
+/// Validate bounds for `n_params` parameters.
+///
+/// An empty slice means unbounded; otherwise one entry per parameter is
+/// required, each with `min <= max`.
+pub(crate) fn validate_bounds(
+    bounds: &[ParameterBounds],
+    n_params: usize,
+) -> Result<(), SolverError> {
+    if n_params == 0 {
+        return Err(SolverError::NumericalInstability(
+            "Empty parameter vector".to_string(),
+        ));
+    }
+    if !bounds.is_empty() && bounds.len() != n_params {
+        return Err(SolverError::NumericalInstability(format!(
+            "Expected {} parameter bounds, got {}",
+            n_params,
+            bounds.len()
+        )));
+    }
+    if let Some((i, b)) = bounds
+        .iter()
+        .enumerate()
+        .find(|(_, b)| b.min.is_nan() || b.max.is_nan() || b.min > b.max)
+    {
+        return Err(SolverError::NumericalInstability(format!(
+            "Invalid bounds [{}, {}] for parameter {}",
+            b.min, b.max, i
+        )));
+    }
+    Ok(())
+}
+
+/// Project `params` onto the box in place.
+pub(crate) fn project(params: &mut [f64], bounds: &[ParameterBounds]) {
+    for (p, b) in params.iter_mut().zip(bounds) {
+        *p = b.clamp(*p);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_from_constraints_intersects() {
+        let bounds = bounds_from_constraints(
+            &[],
+            &[
+                Constraint::bounds(1, -1.0, 1.0),
+                Constraint::bounds(1, 0.0, 2.0),
+                Constraint::bounds(5, 0.0, 1.0),
+            ],
+            2,
+        );
+        assert_eq!(bounds[0], ParameterBounds::unbounded());
+        assert_eq!(bounds[1], ParameterBounds::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_constraint_violation_skips_bounds() {
+        let constraints = vec![
+            Constraint::bounds(0, 0.0, 1.0),
+            Constraint::LinearInequality {
+                coefficients: vec![1.0, 1.0],
+                rhs: 1.0,
+            },
+        ];
+        assert_eq!(constraint_violation(&constraints, &[5.0, -4.0]), 0.0);
+        assert!((constraint_violation(&constraints, &[1.0, 0.5]) - 0.5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_validate_bounds() {
+        assert!(validate_bounds(&[], 2).is_ok());
+        assert!(validate_bounds(&[], 0).is_err());
+        assert!(validate_bounds(&[ParameterBounds::new(0.0, 1.0)], 2).is_err());
+        assert!(validate_bounds(&[ParameterBounds::new(1.0, 0.0)], 1).is_err());
+    }
+}
@@ -15,6 +15,8 @@
 //! ### Optimization
 //!
 //! - [`LevenbergMarquardtSolver`]: Nonlinear least-squares for model calibration
+//! - [`LbfgsbSolver`]: Bound-constrained quasi-Newton minimisation
+//! - [`DifferentialEvolution`]: Derivative-free global search over a box
 //!
 //! ## Configuration
 //!
@@ -23,6 +25,9 @@
 //! - `max_iterations`: Maximum iteration count (default: 100)
 //!
 //! The LM solver uses [`LMConfig`] with additional parameters for damping control.
+//! The bounded minimisers use [`LbfgsbConfig`] and [`DifferentialEvolutionConfig`],
+//! take [`ParameterBounds`](crate::traits::calibration::ParameterBounds) per
+//! parameter, and return a [`MinimisationResult`].
 //!
 //! ## AD Compatibility
 //!
@@ -67,11 +72,17 @@
 
 mod brent;
 mod config;
+mod differential_evolution;
+mod lbfgsb;
 mod levenberg_marquardt;
+mod minimisation;
 mod newton_raphson;
 
 // Re-export public types at module level
 pub use brent::BrentSolver;
 pub use config::SolverConfig;
+pub use differential_evolution::{DifferentialEvolution, DifferentialEvolutionConfig};
+pub use lbfgsb::{LbfgsbConfig, LbfgsbSolver};
 pub use levenberg_marquardt::{LMConfig, LMResult, LevenbergMarquardtSolver};
+pub use minimisation::{bounds_from_constraints, constraint_violation, MinimisationResult};
 pub use newton_raphson::NewtonRaphsonSolver;
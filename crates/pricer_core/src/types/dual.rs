@@ -32,7 +32,8 @@
 ///
 /// NOTE: `DualNumber` (`Dual64`) does NOT implement `num_traits::Float`.
 /// To use smoothing functions with AD, they need to be refactored to use
-/// a more permissive trait bound (e.g., `DualNum<f64>` or a custom `Scalar` trait).
+/// a more permissive trait bound (e.g., `DualNum<f64>` or a custom `Scalar` trait),
+/// or called with the [`FloatDual`] wrapper defined below.
 ///
 /// Example of intended usage (requires trait bound refactoring):
 ///
@@ -54,3 +55,427 @@
 /// ```
 #[cfg(feature = "num-dual-mode")]
 pub type DualNumber = num_dual::Dual64;
+
+#[cfg(feature = "num-dual-mode")]
+pub use float_dual::FloatDual;
+
+#[cfg(feature = "num-dual-mode")]
+mod float_dual {
+    use num_dual::{Dual64, DualNum};
+    use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+    use std::cmp::Ordering;
+    use std::num::FpCategory;
+    use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+    /// Forward-mode dual number implementing [`num_traits::Float`].
+    ///
+    /// `Dual64` cannot be used with code generic over `T: Float` because
+    /// num-dual does not implement that trait. `FloatDual` is a thin newtype
+    /// that does, so curves, instruments and analytical models written
+    /// against `Float` can propagate a single directional derivative.
+    ///
+    /// Comparisons and rounding act on the real part only; the derivative of
+    /// piecewise-constant functions (`floor`, `signum`, ...) is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pricer_core::types::dual::FloatDual;
+    /// use num_traits::Float;
+    ///
+    /// fn discount<T: Float>(rate: T, t: T) -> T {
+    ///     (-rate * t).exp()
+    /// }
+    ///
+    /// let rate = FloatDual::variable(0.05);
+    /// let df = discount(rate, FloatDual::constant(2.0));
+    /// assert!((df.eps() + 2.0 * df.re()).abs() < 1e-12);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct FloatDual(pub Dual64);
+
+    impl FloatDual {
+        /// Create a dual number from a real part and a derivative.
+        #[inline]
+        pub fn new(re: f64, eps: f64) -> Self {
+            Self(Dual64::new(re, eps))
+        }
+
+        /// Create a constant (zero derivative).
+        #[inline]
+        pub fn constant(re: f64) -> Self {
+            Self::new(re, 0.0)
+        }
+
+        /// Create the active variable (unit derivative).
+        #[inline]
+        pub fn variable(re: f64) -> Self {
+            Self::new(re, 1.0)
+        }
+
+        /// Return the real part (function value).
+        #[inline]
+        pub fn re(&self) -> f64 {
+            self.0.re
+        }
+
+        /// Return the dual part (directional derivative).
+        #[inline]
+        pub fn eps(&self) -> f64 {
+            self.0.eps
+        }
+
+        #[inline]
+        fn map_re(self, f: impl Fn(f64) -> f64) -> Self {
+            Self::constant(f(self.0.re))
+        }
+    }
+
+    impl From<f64> for FloatDual {
+        fn from(re: f64) -> Self {
+            Self::constant(re)
+        }
+    }
+
+    impl From<Dual64> for FloatDual {
+        fn from(d: Dual64) -> Self {
+            Self(d)
+        }
+    }
+
+    impl PartialOrd for FloatDual {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.0.re.partial_cmp(&other.0.re)
+        }
+    }
+
+    macro_rules! forward_binop {
+        ($trt:ident, $method:ident) => {
+            impl $trt for FloatDual {
+                type Output = Self;
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self(self.0.$method(rhs.0))
+                }
+            }
+        };
+    }
+
+    forward_binop!(Add, add);
+    forward_binop!(Sub, sub);
+    forward_binop!(Mul, mul);
+    forward_binop!(Div, div);
+
+    impl Rem for FloatDual {
+        type Output = Self;
+        #[inline]
+        fn rem(self, rhs: Self) -> Self {
+            // x mod y = x - y * trunc(x / y); trunc is locally constant
+            let q = (self.0.re / rhs.0.re).trunc();
+            Self(self.0 - rhs.0 * q)
+        }
+    }
+
+    impl Neg for FloatDual {
+        type Output = Self;
+        #[inline]
+        fn neg(self) -> Self {
+            Self(-self.0)
+        }
+    }
+
+    impl Zero for FloatDual {
+        fn zero() -> Self {
+            Self::constant(0.0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0.re == 0.0 && self.0.eps == 0.0
+        }
+    }
+
+    impl One for FloatDual {
+        fn one() -> Self {
+            Self::constant(1.0)
+        }
+    }
+
+    impl Num for FloatDual {
+        type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+        fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            f64::from_str_radix(s, radix).map(Self::constant)
+        }
+    }
+
+    impl ToPrimitive for FloatDual {
+        fn to_i64(&self) -> Option<i64> {
+            self.0.re.to_i64()
+        }
+        fn to_u64(&self) -> Option<u64> {
+            self.0.re.to_u64()
+        }
+        fn to_f64(&self) -> Option<f64> {
+            Some(self.0.re)
+        }
+    }
+
+    impl NumCast for FloatDual {
+        fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+            n.to_f64().map(Self::constant)
+        }
+    }
+
+    impl Float for FloatDual {
+        fn nan() -> Self {
+            Self::constant(f64::NAN)
+        }
+        fn infinity() -> Self {
+            Self::constant(f64::INFINITY)
+        }
+        fn neg_infinity() -> Self {
+            Self::constant(f64::NEG_INFINITY)
+        }
+        fn neg_zero() -> Self {
+            Self::constant(-0.0)
+        }
+        fn min_value() -> Self {
+            Self::constant(f64::MIN)
+        }
+        fn min_positive_value() -> Self {
+            Self::constant(f64::MIN_POSITIVE)
+        }
+        fn epsilon() -> Self {
+            Self::constant(f64::EPSILON)
+        }
+        fn max_value() -> Self {
+            Self::constant(f64::MAX)
+        }
+        fn is_nan(self) -> bool {
+            self.0.re.is_nan()
+        }
+        fn is_infinite(self) -> bool {
+            self.0.re.is_infinite()
+        }
+        fn is_finite(self) -> bool {
+            self.0.re.is_finite()
+        }
+        fn is_normal(self) -> bool {
+            self.0.re.is_normal()
+        }
+        fn classify(self) -> FpCategory {
+            self.0.re.classify()
+        }
+        fn floor(self) -> Self {
+            self.map_re(f64::floor)
+        }
+        fn ceil(self) -> Self {
+            self.map_re(f64::ceil)
+        }
+        fn round(self) -> Self {
+            self.map_re(f64::round)
+        }
+        fn trunc(self) -> Self {
+            self.map_re(f64::trunc)
+        }
+        fn fract(self) -> Self {
+            Self(self.0 - self.0.re.trunc())
+        }
+        fn abs(self) -> Self {
+            if self.0.re < 0.0 {
+                -self
+            } else {
+                self
+            }
+        }
+        fn signum(self) -> Self {
+            self.map_re(f64::signum)
+        }
+        fn is_sign_positive(self) -> bool {
+            self.0.re.is_sign_positive()
+        }
+        fn is_sign_negative(self) -> bool {
+            self.0.re.is_sign_negative()
+        }
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            Self(self.0 * a.0 + b.0)
+        }
+        fn recip(self) -> Self {
+            Self(self.0.recip())
+        }
+        fn powi(self, n: i32) -> Self {
+            Self(self.0.powi(n))
+        }
+        fn powf(self, n: Self) -> Self {
+            if n.0.eps == 0.0 {
+                Self(self.0.powf(n.0.re))
+            } else {
+                Self(self.0.powd(n.0))
+            }
+        }
+        fn sqrt(self) -> Self {
+            Self(self.0.sqrt())
+        }
+        fn exp(self) -> Self {
+            Self(self.0.exp())
+        }
+        fn exp2(self) -> Self {
+            Self(self.0.exp2())
+        }
+        fn ln(self) -> Self {
+            Self(self.0.ln())
+        }
+        fn log(self, base: Self) -> Self {
+            self.ln() / base.ln()
+        }
+        fn log2(self) -> Self {
+            Self(self.0.log2())
+        }
+        fn log10(self) -> Self {
+            Self(self.0.log10())
+        }
+        fn max(self, other: Self) -> Self {
+            if self.0.re >= other.0.re || other.is_nan() {
+                self
+            } else {
+                other
+            }
+        }
+        fn min(self, other: Self) -> Self {
+            if self.0.re <= other.0.re || other.is_nan() {
+                self
+            } else {
+                other
+            }
+        }
+        fn abs_sub(self, other: Self) -> Self {
+            if self.0.re <= other.0.re {
+                Self::zero()
+            } else {
+                self - other
+            }
+        }
+        fn cbrt(self) -> Self {
+            Self(self.0.cbrt())
+        }
+        fn hypot(self, other: Self) -> Self {
+            (self * self + other * other).sqrt()
+        }
+        fn sin(self) -> Self {
+            Self(self.0.sin())
+        }
+        fn cos(self) -> Self {
+            Self(self.0.cos())
+        }
+        fn tan(self) -> Self {
+            Self(self.0.tan())
+        }
+        fn asin(self) -> Self {
+            Self(self.0.asin())
+        }
+        fn acos(self) -> Self {
+            Self(self.0.acos())
+        }
+        fn atan(self) -> Self {
+            Self(self.0.atan())
+        }
+        fn atan2(self, other: Self) -> Self {
+            // d atan2(y, x) = (x dy - y dx) / (x^2 + y^2)
+            let (y, x) = (self.0, other.0);
+            let re = y.re.atan2(x.re);
+            let eps = (x.re * y.eps - y.re * x.eps) / (x.re * x.re + y.re * y.re);
+            Self::new(re, eps)
+        }
+        fn sin_cos(self) -> (Self, Self) {
+            let (s, c) = self.0.sin_cos();
+            (Self(s), Self(c))
+        }
+        fn exp_m1(self) -> Self {
+            Self(self.0.exp_m1())
+        }
+        fn ln_1p(self) -> Self {
+            Self(self.0.ln_1p())
+        }
+        fn sinh(self) -> Self {
+            Self(self.0.sinh())
+        }
+        fn cosh(self) -> Self {
+            Self(self.0.cosh())
+        }
+        fn tanh(self) -> Self {
+            Self(self.0.tanh())
+        }
+        fn asinh(self) -> Self {
+            Self(self.0.asinh())
+        }
+        fn acosh(self) -> Self {
+            Self(self.0.acosh())
+        }
+        fn atanh(self) -> Self {
+            Self(self.0.atanh())
+        }
+        fn integer_decode(self) -> (u64, i16, i8) {
+            self.0.re.integer_decode()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use approx::assert_relative_eq;
+
+        fn black_scholes_like<T: Float>(s: T, k: T, r: T, t: T) -> T {
+            let df = (-r * t).exp();
+            (s - k * df).max(T::zero()).sqrt() + (s / k).ln().powi(2)
+        }
+
+        #[test]
+        fn test_derivative_matches_finite_difference() {
+            let h = 1e-6;
+            let f = |s: f64| black_scholes_like(s, 100.0, 0.05, 1.0);
+            let fd = (f(110.0 + h) - f(110.0 - h)) / (2.0 * h);
+
+            let ad = black_scholes_like(
+                FloatDual::variable(110.0),
+                FloatDual::constant(100.0),
+                FloatDual::constant(0.05),
+                FloatDual::constant(1.0),
+            );
+            assert_relative_eq!(ad.re(), f(110.0), epsilon = 1e-12);
+            assert_relative_eq!(ad.eps(), fd, epsilon = 1e-6);
+        }
+
+        #[test]
+        fn test_comparison_uses_real_part() {
+            let a = FloatDual::new(1.0, 5.0);
+            let b = FloatDual::new(2.0, -5.0);
+            assert!(a < b);
+            assert_eq!(a.max(b), b);
+            assert_eq!(a.min(b), a);
+        }
+
+        #[test]
+        fn test_powf_with_dual_exponent() {
+            // d/dx 2^x = 2^x ln 2
+            let x = FloatDual::variable(3.0);
+            let y = FloatDual::constant(2.0).powf(x);
+            assert_relative_eq!(y.re(), 8.0, epsilon = 1e-12);
+            assert_relative_eq!(y.eps(), 8.0 * 2.0_f64.ln(), epsilon = 1e-12);
+        }
+
+        #[test]
+        fn test_atan2_derivative() {
+            let h = 1e-6;
+            let fd = ((1.0 + h).atan2(2.0) - (1.0 - h).atan2(2.0)) / (2.0 * h);
+            let ad = FloatDual::variable(1.0).atan2(FloatDual::constant(2.0));
+            assert_relative_eq!(ad.eps(), fd, epsilon = 1e-8);
+        }
+
+        #[test]
+        fn test_numcast() {
+            let x: FloatDual = NumCast::from(2.5_f64).unwrap();
+            assert_eq!(x.re(), 2.5);
+            assert_eq!(x.eps(), 0.0);
+            assert_eq!(x.to_f64(), Some(2.5));
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! This module provides:
 //! - `dual`: Dual number type integration with num-dual for automatic differentiation (when `num-dual-mode` feature is enabled)
+//! - `tape`: Tape-based reverse-mode automatic differentiation (`AReal` implementing `Float`)
 //! - `time`: Time types (Date, DayCountConvention, BusinessDayConvention) for financial calculations
 //! - `currency`: ISO 4217 currency codes with metadata
 //! - `currency_pair`: Currency pair types for FX calculations
@@ -20,6 +21,7 @@ pub mod currency_pair;
 #[cfg(feature = "num-dual-mode")]
 pub mod dual;
 pub mod error;
+pub mod tape;
 pub mod time;
 
 // Re-export commonly used types at module level
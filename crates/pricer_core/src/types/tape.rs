@@ -0,0 +1,1025 @@
+//! Tape-based reverse-mode automatic differentiation.
+//!
+//! This module provides [`AReal`], an operator-overloading adjoint type
+//! implementing [`num_traits::Float`], and [`Tape`], the thread-local
+//! Wengert list it records to. Any code generic over `T: Float` (curves,
+//! instruments, analytical models) can be evaluated with `AReal` and then
+//! differentiated with respect to all of its inputs in a single backward
+//! sweep, on stable Rust and without Enzyme.
+//!
+//! ## Recording
+//!
+//! Each operation with at least one active operand appends a node holding
+//! the indices of its operands and the local partial derivatives. Constants
+//! are never recorded, so only the active computation occupies memory.
+//!
+//! ## Memory cap and checkpointing
+//!
+//! [`Tape::set_memory_limit`] caps the tape of the calling thread and
+//! [`Tape::set_default_memory_limit`] the tapes of all threads (typically
+//! from the engine's `memory_limit_mb` setting at start-up). Once the cap is reached, recording
+//! stops and [`Tape::gradient`] reports [`TapeError::MemoryLimitExceeded`]
+//! instead of returning wrong sensitivities.
+//!
+//! Long computations can stay under the cap with [`Tape::collapse`], which
+//! sweeps a recorded segment, rewinds the tape to the segment start and
+//! replaces the segment by a single node carrying its Jacobian. Externally
+//! computed derivatives (e.g. from a solver via the implicit function
+//! theorem) can be attached with [`Tape::record_external`].
+//!
+//! ## Threading
+//!
+//! The tape is thread-local: values recorded on one thread cannot be
+//! differentiated on another.
+//!
+//! # Example
+//!
+//! ```
+//! use num_traits::Float;
+//! use pricer_core::types::tape::Tape;
+//!
+//! fn forward_value<T: Float>(spot: T, rate: T, expiry: T) -> T {
+//!     spot * (rate * expiry).exp()
+//! }
+//!
+//! let (value, gradient) = Tape::differentiate(&[100.0, 0.05, 2.0], |x| {
+//!     forward_value(x[0], x[1], x[2])
+//! })
+//! .unwrap();
+//!
+//! let growth = (0.05_f64 * 2.0).exp();
+//! assert!((value - 100.0 * growth).abs() < 1e-10);
+//! assert!((gradient[0] - growth).abs() < 1e-12);
+//! assert!((gradient[1] - 200.0 * growth).abs() < 1e-10);
+//! assert!((gradient[2] - 5.0 * growth).abs() < 1e-10);
+//! ```
+
+use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::num::FpCategory;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use thiserror::Error;
+
+/// Index marking a constant (unrecorded) value.
+const CONSTANT: u32 = u32::MAX;
+
+/// Errors from tape recording and adjoint sweeps.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TapeError {
+    /// Recording stopped because the tape reached its memory limit.
+    #[error("AD tape exceeded its memory limit of {limit_bytes} bytes")]
+    MemoryLimitExceeded {
+        /// Configured limit in bytes.
+        limit_bytes: usize,
+    },
+
+    /// The value was not recorded on the current tape (it was recorded on
+    /// another thread, or the tape was reset or rewound since).
+    #[error("Value at tape index {index} is not on the current tape")]
+    InvalidOutput {
+        /// Tape index of the value.
+        index: usize,
+    },
+
+    /// A collapsed segment depends on a recorded value not listed as input.
+    #[error("Collapsed segment depends on unlisted tape index {index}")]
+    UnlistedInput {
+        /// Tape index of the missing input.
+        index: usize,
+    },
+}
+
+/// Operand of a recorded node: the operand's tape index and the local
+/// partial derivative of the node with respect to it.
+#[derive(Debug, Clone, Copy)]
+struct Operand {
+    index: u32,
+    partial: f64,
+}
+
+/// Thread-local tape storage.
+#[derive(Debug)]
+struct TapeData {
+    /// Operand range of node `i` is `starts[i]..starts[i + 1]`.
+    starts: Vec<usize>,
+    operands: Vec<Operand>,
+    memory_limit: Option<usize>,
+    overflowed: bool,
+}
+
+impl Default for TapeData {
+    fn default() -> Self {
+        Self {
+            starts: vec![0],
+            operands: Vec::new(),
+            memory_limit: default_memory_limit(),
+            overflowed: false,
+        }
+    }
+}
+
+impl TapeData {
+    fn len(&self) -> usize {
+        self.starts.len() - 1
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.starts.len() * std::mem::size_of::<usize>()
+            + self.operands.len() * std::mem::size_of::<Operand>()
+    }
+
+    /// Append a node, returning its index or `CONSTANT` if the memory
+    /// limit is reached.
+    fn push(&mut self, operands: impl Iterator<Item = Operand> + Clone) -> u32 {
+        if self.overflowed {
+            return CONSTANT;
+        }
+        let n_operands = operands.clone().count();
+        if let Some(limit) = self.memory_limit {
+            let required =
+                std::mem::size_of::<usize>() + n_operands * std::mem::size_of::<Operand>();
+            if self.memory_usage() + required > limit {
+                self.overflowed = true;
+                return CONSTANT;
+            }
+        }
+        let index = self.len();
+        if index >= CONSTANT as usize {
+            self.overflowed = true;
+            return CONSTANT;
+        }
+        self.operands.extend(operands);
+        self.starts.push(self.operands.len());
+        index as u32
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.starts.truncate(len + 1);
+            self.operands.truncate(self.starts[len]);
+        }
+    }
+
+    /// Propagate adjoints from `output` down to node `stop`.
+    fn sweep(&self, output: u32, stop: usize) -> Result<Vec<f64>, TapeError> {
+        if self.overflowed {
+            return Err(TapeError::MemoryLimitExceeded {
+                limit_bytes: self.memory_limit.unwrap_or(usize::MAX),
+            });
+        }
+        let output = output as usize;
+        if output >= self.len() {
+            return Err(TapeError::InvalidOutput { index: output });
+        }
+        let mut adjoints = vec![0.0; output + 1];
+        adjoints[output] = 1.0;
+        for node in (stop..=output).rev() {
+            let adjoint = adjoints[node];
+            if adjoint == 0.0 {
+                continue;
+            }
+            for op in &self.operands[self.starts[node]..self.starts[node + 1]] {
+                adjoints[op.index as usize] += adjoint * op.partial;
+            }
+        }
+        Ok(adjoints)
+    }
+}
+
+/// Process-wide memory limit for newly created thread tapes, in bytes
+/// (`usize::MAX` for unlimited).
+static DEFAULT_MEMORY_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+fn default_memory_limit() -> Option<usize> {
+    match DEFAULT_MEMORY_LIMIT.load(AtomicOrdering::Relaxed) {
+        usize::MAX => None,
+        limit => Some(limit),
+    }
+}
+
+thread_local! {
+    static TAPE: RefCell<TapeData> = RefCell::new(TapeData::default());
+}
+
+/// Position on the tape, used to mark the start of a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TapePosition(usize);
+
+/// Adjoints from a backward sweep.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Adjoints {
+    values: Vec<f64>,
+}
+
+impl Adjoints {
+    /// Sensitivity of the swept output to `x` (zero for constants).
+    pub fn wrt(&self, x: AReal) -> f64 {
+        if x.index == CONSTANT {
+            return 0.0;
+        }
+        self.values.get(x.index as usize).copied().unwrap_or(0.0)
+    }
+
+    /// Sensitivities to each of `xs`.
+    pub fn wrt_all(&self, xs: &[AReal]) -> Vec<f64> {
+        xs.iter().map(|&x| self.wrt(x)).collect()
+    }
+}
+
+/// Handle to the thread-local AD tape.
+///
+/// All methods act on the tape of the calling thread.
+#[derive(Debug, Clone, Copy)]
+pub struct Tape;
+
+impl Tape {
+    /// Clear the tape, keeping the memory limit.
+    pub fn reset() {
+        TAPE.with(|t| {
+            let mut tape = t.borrow_mut();
+            let limit = tape.memory_limit;
+            *tape = TapeData {
+                memory_limit: limit,
+                ..TapeData::default()
+            };
+        });
+    }
+
+    /// Set the memory limit in bytes (`None` for unlimited).
+    pub fn set_memory_limit(limit_bytes: Option<usize>) {
+        TAPE.with(|t| t.borrow_mut().memory_limit = limit_bytes);
+    }
+
+    /// Set the memory limit in bytes for the calling thread and for every
+    /// thread whose tape is first used afterwards (`None` for unlimited).
+    ///
+    /// Intended to be called once at start-up with the engine
+    /// configuration's `memory_limit_bytes()`, before worker threads start.
+    pub fn set_default_memory_limit(limit_bytes: Option<usize>) {
+        DEFAULT_MEMORY_LIMIT.store(limit_bytes.unwrap_or(usize::MAX), AtomicOrdering::Relaxed);
+        Self::set_memory_limit(limit_bytes);
+    }
+
+    /// Current memory limit in bytes.
+    pub fn memory_limit() -> Option<usize> {
+        TAPE.with(|t| t.borrow().memory_limit)
+    }
+
+    /// Approximate memory used by recorded nodes, in bytes.
+    pub fn memory_usage() -> usize {
+        TAPE.with(|t| t.borrow().memory_usage())
+    }
+
+    /// Number of recorded nodes.
+    pub fn len() -> usize {
+        TAPE.with(|t| t.borrow().len())
+    }
+
+    /// Whether the tape is empty.
+    pub fn is_empty() -> bool {
+        Self::len() == 0
+    }
+
+    /// Whether recording stopped at the memory limit.
+    pub fn is_overflowed() -> bool {
+        TAPE.with(|t| t.borrow().overflowed)
+    }
+
+    /// Register an independent variable.
+    pub fn variable(value: f64) -> AReal {
+        let index = TAPE.with(|t| t.borrow_mut().push(std::iter::empty()));
+        AReal { value, index }
+    }
+
+    /// Record a node with externally computed partial derivatives.
+    ///
+    /// `partials` pairs each input with `∂value/∂input`. Constant inputs
+    /// are ignored; if none is active the result is a constant.
+    pub fn record_external(value: f64, partials: &[(AReal, f64)]) -> AReal {
+        AReal::record(value, partials.iter().map(|&(x, d)| (x.index, d)))
+    }
+
+    /// Current end of the tape.
+    pub fn position() -> TapePosition {
+        TapePosition(Self::len())
+    }
+
+    /// Discard every node recorded after `position`.
+    ///
+    /// Values recorded after `position` must not be used afterwards.
+    pub fn rewind(position: TapePosition) {
+        TAPE.with(|t| t.borrow_mut().truncate(position.0));
+    }
+
+    /// Backward sweep from `output` over the whole tape.
+    ///
+    /// # Errors
+    ///
+    /// - `TapeError::MemoryLimitExceeded` if recording stopped at the limit
+    /// - `TapeError::InvalidOutput` if `output` is not on the current tape
+    pub fn gradient(output: AReal) -> Result<Adjoints, TapeError> {
+        if output.index == CONSTANT {
+            return Tape::check_overflow().map(|_| Adjoints { values: Vec::new() });
+        }
+        TAPE.with(|t| t.borrow().sweep(output.index, 0))
+            .map(|values| Adjoints { values })
+    }
+
+    /// Replace the segment recorded since `start` by a single node.
+    ///
+    /// Sweeps the segment from `output` to compute `∂output/∂input` for
+    /// each of `inputs` (values recorded before `start`), rewinds the tape
+    /// to `start` and records one node with those partials. This is the
+    /// checkpointing hook: memory used by the segment is released while
+    /// the sensitivities to its inputs are kept.
+    ///
+    /// # Errors
+    ///
+    /// - `TapeError::MemoryLimitExceeded` if recording stopped at the limit
+    /// - `TapeError::InvalidOutput` if `output` is not on the current tape
+    /// - `TapeError::UnlistedInput` if the segment depends on a value
+    ///   recorded before `start` that is not in `inputs`
+    pub fn collapse(
+        start: TapePosition,
+        inputs: &[AReal],
+        output: AReal,
+    ) -> Result<AReal, TapeError> {
+        if output.index == CONSTANT || (output.index as usize) < start.0 {
+            Tape::check_overflow()?;
+            return Ok(output);
+        }
+        let adjoints = TAPE.with(|t| t.borrow().sweep(output.index, start.0))?;
+        if let Some(index) = (0..start.0.min(adjoints.len()))
+            .find(|&i| adjoints[i] != 0.0 && !inputs.iter().any(|x| x.index as usize == i))
+        {
+            return Err(TapeError::UnlistedInput { index });
+        }
+        let partials: Vec<(AReal, f64)> = inputs
+            .iter()
+            .map(|&x| {
+                let d = if x.index == CONSTANT {
+                    0.0
+                } else {
+                    adjoints.get(x.index as usize).copied().unwrap_or(0.0)
+                };
+                (x, d)
+            })
+            .collect();
+        Tape::rewind(start);
+        Ok(Tape::record_external(output.value, &partials))
+    }
+
+    /// Evaluate `f` on fresh variables and return its value and gradient.
+    ///
+    /// Resets the tape of the calling thread before recording.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Tape::gradient`].
+    pub fn differentiate<F>(inputs: &[f64], f: F) -> Result<(f64, Vec<f64>), TapeError>
+    where
+        F: FnOnce(&[AReal]) -> AReal,
+    {
+        Tape::reset();
+        let variables: Vec<AReal> = inputs.iter().map(|&x| Tape::variable(x)).collect();
+        let output = f(&variables);
+        let adjoints = Tape::gradient(output)?;
+        Ok((output.value, adjoints.wrt_all(&variables)))
+    }
+
+    fn check_overflow() -> Result<(), TapeError> {
+        TAPE.with(|t| {
+            let tape = t.borrow();
+            if tape.overflowed {
+                Err(TapeError::MemoryLimitExceeded {
+                    limit_bytes: tape.memory_limit.unwrap_or(usize::MAX),
+                })
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+/// Active real number recorded on the thread-local [`Tape`].
+///
+/// Holds the value and the index of the node that produced it. Arithmetic
+/// and `Float` functions record their local derivatives; comparisons and
+/// rounding act on the value only, and piecewise-constant functions
+/// (`floor`, `signum`, ...) have zero derivative.
+///
+/// # Example
+///
+/// ```
+/// use num_traits::Float;
+/// use pricer_core::types::tape::{AReal, Tape};
+///
+/// Tape::reset();
+/// let x = Tape::variable(2.0);
+/// let y = Tape::variable(3.0);
+/// let z = x * y + x.sin() * AReal::constant(4.0);
+///
+/// let adjoints = Tape::gradient(z).unwrap();
+/// assert!((adjoints.wrt(x) - (3.0 + 4.0 * 2.0_f64.cos())).abs() < 1e-12);
+/// assert!((adjoints.wrt(y) - 2.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AReal {
+    value: f64,
+    index: u32,
+}
+
+impl AReal {
+    /// Create a constant (not recorded, zero derivative).
+    #[inline]
+    pub fn constant(value: f64) -> Self {
+        Self {
+            value,
+            index: CONSTANT,
+        }
+    }
+
+    /// Return the value.
+    #[inline]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Whether the value is recorded on the tape.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.index != CONSTANT
+    }
+
+    fn record(value: f64, operands: impl Iterator<Item = (u32, f64)> + Clone) -> Self {
+        let active = operands
+            .filter(|&(index, _)| index != CONSTANT)
+            .map(|(index, partial)| Operand { index, partial });
+        if active.clone().next().is_none() {
+            return Self::constant(value);
+        }
+        let index = TAPE.with(|t| t.borrow_mut().push(active));
+        Self { value, index }
+    }
+
+    #[inline]
+    fn unary(self, value: f64, partial: f64) -> Self {
+        if self.index == CONSTANT {
+            return Self::constant(value);
+        }
+        Self::record(value, std::iter::once((self.index, partial)))
+    }
+
+    #[inline]
+    fn binary(self, other: Self, value: f64, d_self: f64, d_other: f64) -> Self {
+        if self.index == CONSTANT && other.index == CONSTANT {
+            return Self::constant(value);
+        }
+        Self::record(
+            value,
+            [(self.index, d_self), (other.index, d_other)].into_iter(),
+        )
+    }
+
+    #[inline]
+    fn map_value(self, f: impl Fn(f64) -> f64) -> Self {
+        Self::constant(f(self.value))
+    }
+}
+
+impl From<f64> for AReal {
+    fn from(value: f64) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl PartialEq for AReal {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for AReal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl Add for AReal {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value + rhs.value, 1.0, 1.0)
+    }
+}
+
+impl Sub for AReal {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value - rhs.value, 1.0, -1.0)
+    }
+}
+
+impl Mul for AReal {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.binary(rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl Div for AReal {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        self.binary(rhs, value, 1.0 / rhs.value, -value / rhs.value)
+    }
+}
+
+impl Rem for AReal {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        // x mod y = x - y * trunc(x / y); trunc is locally constant
+        let q = (self.value / rhs.value).trunc();
+        self.binary(rhs, self.value % rhs.value, 1.0, -q)
+    }
+}
+
+impl Neg for AReal {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.unary(-self.value, -1.0)
+    }
+}
+
+impl Zero for AReal {
+    fn zero() -> Self {
+        Self::constant(0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.value == 0.0
+    }
+}
+
+impl One for AReal {
+    fn one() -> Self {
+        Self::constant(1.0)
+    }
+}
+
+impl Num for AReal {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+impl ToPrimitive for AReal {
+    fn to_i64(&self) -> Option<i64> {
+        self.value.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.value.to_u64()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.value)
+    }
+}
+
+impl NumCast for AReal {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        n.to_f64().map(Self::constant)
+    }
+}
+
+impl Float for AReal {
+    fn nan() -> Self {
+        Self::constant(f64::NAN)
+    }
+    fn infinity() -> Self {
+        Self::constant(f64::INFINITY)
+    }
+    fn neg_infinity() -> Self {
+        Self::constant(f64::NEG_INFINITY)
+    }
+    fn neg_zero() -> Self {
+        Self::constant(-0.0)
+    }
+    fn min_value() -> Self {
+        Self::constant(f64::MIN)
+    }
+    fn min_positive_value() -> Self {
+        Self::constant(f64::MIN_POSITIVE)
+    }
+    fn epsilon() -> Self {
+        Self::constant(f64::EPSILON)
+    }
+    fn max_value() -> Self {
+        Self::constant(f64::MAX)
+    }
+    fn is_nan(self) -> bool {
+        self.value.is_nan()
+    }
+    fn is_infinite(self) -> bool {
+        self.value.is_infinite()
+    }
+    fn is_finite(self) -> bool {
+        self.value.is_finite()
+    }
+    fn is_normal(self) -> bool {
+        self.value.is_normal()
+    }
+    fn classify(self) -> FpCategory {
+        self.value.classify()
+    }
+    fn floor(self) -> Self {
+        self.map_value(f64::floor)
+    }
+    fn ceil(self) -> Self {
+        self.map_value(f64::ceil)
+    }
+    fn round(self) -> Self {
+        self.map_value(f64::round)
+    }
+    fn trunc(self) -> Self {
+        self.map_value(f64::trunc)
+    }
+    fn fract(self) -> Self {
+        self.unary(self.value.fract(), 1.0)
+    }
+    fn abs(self) -> Self {
+        if self.value < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+    fn signum(self) -> Self {
+        self.map_value(f64::signum)
+    }
+    fn is_sign_positive(self) -> bool {
+        self.value.is_sign_positive()
+    }
+    fn is_sign_negative(self) -> bool {
+        self.value.is_sign_negative()
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+    fn recip(self) -> Self {
+        let value = 1.0 / self.value;
+        self.unary(value, -value * value)
+    }
+    fn powi(self, n: i32) -> Self {
+        let partial = if n == 0 {
+            0.0
+        } else {
+            n as f64 * self.value.powi(n - 1)
+        };
+        self.unary(self.value.powi(n), partial)
+    }
+    fn powf(self, n: Self) -> Self {
+        let value = self.value.powf(n.value);
+        let d_self = if n.value == 0.0 {
+            0.0
+        } else {
+            n.value * self.value.powf(n.value - 1.0)
+        };
+        if n.index == CONSTANT {
+            self.unary(value, d_self)
+        } else {
+            self.binary(n, value, d_self, value * self.value.ln())
+        }
+    }
+    fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        self.unary(value, 0.5 / value)
+    }
+    fn exp(self) -> Self {
+        let value = self.value.exp();
+        self.unary(value, value)
+    }
+    fn exp2(self) -> Self {
+        let value = self.value.exp2();
+        self.unary(value, value * std::f64::consts::LN_2)
+    }
+    fn ln(self) -> Self {
+        self.unary(self.value.ln(), 1.0 / self.value)
+    }
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+    fn log2(self) -> Self {
+        self.unary(
+            self.value.log2(),
+            1.0 / (self.value * std::f64::consts::LN_2),
+        )
+    }
+    fn log10(self) -> Self {
+        self.unary(
+            self.value.log10(),
+            1.0 / (self.value * std::f64::consts::LN_10),
+        )
+    }
+    fn max(self, other: Self) -> Self {
+        if self.value >= other.value || other.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+    fn min(self, other: Self) -> Self {
+        if self.value <= other.value || other.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        if self.value <= other.value {
+            Self::zero()
+        } else {
+            self - other
+        }
+    }
+    fn cbrt(self) -> Self {
+        let value = self.value.cbrt();
+        self.unary(value, 1.0 / (3.0 * value * value))
+    }
+    fn hypot(self, other: Self) -> Self {
+        let value = self.value.hypot(other.value);
+        self.binary(other, value, self.value / value, other.value / value)
+    }
+    fn sin(self) -> Self {
+        self.unary(self.value.sin(), self.value.cos())
+    }
+    fn cos(self) -> Self {
+        self.unary(self.value.cos(), -self.value.sin())
+    }
+    fn tan(self) -> Self {
+        let value = self.value.tan();
+        self.unary(value, 1.0 + value * value)
+    }
+    fn asin(self) -> Self {
+        self.unary(
+            self.value.asin(),
+            1.0 / (1.0 - self.value * self.value).sqrt(),
+        )
+    }
+    fn acos(self) -> Self {
+        self.unary(
+            self.value.acos(),
+            -1.0 / (1.0 - self.value * self.value).sqrt(),
+        )
+    }
+    fn atan(self) -> Self {
+        self.unary(self.value.atan(), 1.0 / (1.0 + self.value * self.value))
+    }
+    fn atan2(self, other: Self) -> Self {
+        // d atan2(y, x) = (x dy - y dx) / (x^2 + y^2)
+        let (y, x) = (self.value, other.value);
+        let r2 = x * x + y * y;
+        self.binary(other, y.atan2(x), x / r2, -y / r2)
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+    fn exp_m1(self) -> Self {
+        self.unary(self.value.exp_m1(), self.value.exp())
+    }
+    fn ln_1p(self) -> Self {
+        self.unary(self.value.ln_1p(), 1.0 / (1.0 + self.value))
+    }
+    fn sinh(self) -> Self {
+        self.unary(self.value.sinh(), self.value.cosh())
+    }
+    fn cosh(self) -> Self {
+        self.unary(self.value.cosh(), self.value.sinh())
+    }
+    fn tanh(self) -> Self {
+        let value = self.value.tanh();
+        self.unary(value, 1.0 - value * value)
+    }
+    fn asinh(self) -> Self {
+        self.unary(
+            self.value.asinh(),
+            1.0 / (self.value * self.value + 1.0).sqrt(),
+        )
+    }
+    fn acosh(self) -> Self {
+        self.unary(
+            self.value.acosh(),
+            1.0 / (self.value * self.value - 1.0).sqrt(),
+        )
+    }
+    fn atanh(self) -> Self {
+        self.unary(self.value.atanh(), 1.0 / (1.0 - self.value * self.value))
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.value.integer_decode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn black_scholes_like<T: Float>(s: T, k: T, r: T, t: T) -> T {
+        let df = (-r * t).exp();
+        (s - k * df).max(T::zero()).sqrt() + (s / k).ln().powi(2)
+    }
+
+    fn central_difference(f: impl Fn(&[f64]) -> f64, x: &[f64], i: usize) -> f64 {
+        let h = 1e-6 * x[i].abs().max(1.0);
+        let mut up = x.to_vec();
+        let mut down = x.to_vec();
+        up[i] += h;
+        down[i] -= h;
+        (f(&up) - f(&down)) / (2.0 * h)
+    }
+
+    #[test]
+    fn test_gradient_matches_finite_difference() {
+        let x = [110.0, 100.0, 0.05, 1.5];
+        let f = |p: &[f64]| black_scholes_like(p[0], p[1], p[2], p[3]);
+        let (value, gradient) =
+            Tape::differentiate(&x, |v| black_scholes_like(v[0], v[1], v[2], v[3])).unwrap();
+
+        assert_relative_eq!(value, f(&x), epsilon = 1e-12);
+        for (i, g) in gradient.iter().enumerate() {
+            assert_relative_eq!(*g, central_difference(f, &x, i), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_elementary_functions() {
+        fn g<T: Float>(x: T, y: T) -> T {
+            let two = T::from(2.0).unwrap();
+            x.sin() * y.cos()
+                + x.tan()
+                + (x / two).asin()
+                + (x / two).acos() * y
+                + x.atan2(y)
+                + x.powf(y)
+                + y.cbrt()
+                + x.hypot(y)
+                + x.sinh()
+                + y.tanh()
+                + x.exp2()
+                + y.log10()
+                + y.log2()
+                + x.ln_1p()
+                + x.exp_m1()
+                + (x + y).asinh()
+                + (y + two).acosh()
+                + (x / two).atanh()
+                + y.recip()
+                + (x * y) % two
+                + x.mul_add(y, x)
+        }
+        let x = [0.7, 1.3];
+        let f = |p: &[f64]| g(p[0], p[1]);
+        let (value, gradient) = Tape::differentiate(&x, |v| g(v[0], v[1])).unwrap();
+        assert_relative_eq!(value, f(&x), epsilon = 1e-12);
+        for (i, d) in gradient.iter().enumerate() {
+            assert_relative_eq!(*d, central_difference(f, &x, i), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_constants_are_not_recorded() {
+        Tape::reset();
+        let c = AReal::constant(2.0);
+        let d = c * c + c.exp();
+        assert!(!d.is_active());
+        assert!(Tape::is_empty());
+
+        let x = Tape::variable(1.0);
+        let _ = x * c;
+        assert_eq!(Tape::len(), 2);
+    }
+
+    #[test]
+    fn test_comparison_uses_value() {
+        Tape::reset();
+        let a = Tape::variable(1.0);
+        let b = AReal::constant(2.0);
+        assert!(a < b);
+        assert_eq!(a.max(b).value(), 2.0);
+        assert!(!a.max(b).is_active());
+        assert!(a.min(b).is_active());
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        Tape::reset();
+        Tape::set_memory_limit(Some(1024));
+        let x = Tape::variable(1.0);
+        let mut y = x;
+        for _ in 0..1000 {
+            y = y * x + x;
+        }
+        assert!(Tape::is_overflowed());
+        assert!(Tape::memory_usage() <= 1024);
+        assert_eq!(
+            Tape::gradient(y),
+            Err(TapeError::MemoryLimitExceeded { limit_bytes: 1024 })
+        );
+
+        Tape::reset();
+        assert!(!Tape::is_overflowed());
+        assert_eq!(Tape::memory_limit(), Some(1024));
+        Tape::set_memory_limit(None);
+    }
+
+    #[test]
+    fn test_default_memory_limit_applies_to_new_threads() {
+        // Large enough not to affect tests running concurrently.
+        let limit = 1 << 30;
+        Tape::set_default_memory_limit(Some(limit));
+        assert_eq!(Tape::memory_limit(), Some(limit));
+        let worker = std::thread::spawn(Tape::memory_limit).join().unwrap();
+        assert_eq!(worker, Some(limit));
+
+        Tape::set_default_memory_limit(None);
+        assert_eq!(std::thread::spawn(Tape::memory_limit).join().unwrap(), None);
+    }
+
+    #[test]
+    fn test_collapse_preserves_gradient() {
+        fn segment<T: Float>(a: T, b: T) -> T {
+            let mut acc = a;
+            for _ in 0..50 {
+                acc = (acc * b).sin() + a;
+            }
+            acc
+        }
+
+        Tape::reset();
+        let a = Tape::variable(0.3);
+        let b = Tape::variable(0.9);
+        let full = segment(a, b) * a;
+        let expected = Tape::gradient(full).unwrap();
+
+        Tape::reset();
+        let a = Tape::variable(0.3);
+        let b = Tape::variable(0.9);
+        let start = Tape::position();
+        let inner = segment(a, b);
+        let before = Tape::len();
+        let inner = Tape::collapse(start, &[a, b], inner).unwrap();
+        assert_eq!(Tape::len(), start.0 + 1);
+        assert!(Tape::len() < before);
+        let collapsed = inner * a;
+        let adjoints = Tape::gradient(collapsed).unwrap();
+
+        assert_relative_eq!(collapsed.value(), full.value(), epsilon = 1e-14);
+        assert_relative_eq!(adjoints.wrt(a), expected.wrt(a), epsilon = 1e-12);
+        assert_relative_eq!(adjoints.wrt(b), expected.wrt(b), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_collapse_detects_unlisted_input() {
+        Tape::reset();
+        let a = Tape::variable(1.0);
+        let b = Tape::variable(2.0);
+        let start = Tape::position();
+        let y = a * b;
+        assert_eq!(
+            Tape::collapse(start, &[a], y),
+            Err(TapeError::UnlistedInput { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_record_external() {
+        // Attach d(x²)/dx = 2x computed outside the tape
+        Tape::reset();
+        let x = Tape::variable(3.0);
+        let y = Tape::record_external(9.0, &[(x, 6.0)]);
+        let z = y * x;
+        let adjoints = Tape::gradient(z).unwrap();
+        assert_relative_eq!(adjoints.wrt(x), 27.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_rewound_value_is_invalid() {
+        Tape::reset();
+        let x = Tape::variable(1.0);
+        let start = Tape::position();
+        let y = x.exp();
+        Tape::rewind(start);
+        assert!(matches!(
+            Tape::gradient(y),
+            Err(TapeError::InvalidOutput { .. })
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+//! Black-76 formula for options on a forward.
+//!
+//! ## Mathematical Formulas
+//!
+//! **Call Price**: C = F·N(d₁) - K·N(d₂)
+//! **Put Price**: P = K·N(-d₂) - F·N(-d₁)
+//!
+//! Where:
+//! - d₁ = ln(F/K) / (σ√T) + σ√T / 2
+//! - d₂ = d₁ - σ√T
+//!
+//! Prices are undiscounted; callers multiply by the discount factor or
+//! annuity of the contract. Shifted (displaced) models pass the shifted
+//! forward and strike.
+
+use num_traits::Float;
+
+use super::distributions::norm_cdf;
+
+/// Undiscounted Black-76 price of a call or put on a forward.
+///
+/// Returns the intrinsic value `max(±(F - K), 0)` when the total
+/// standard deviation `σ√T` is not positive.
+///
+/// # Arguments
+/// * `forward` - Forward price (positive)
+/// * `strike` - Strike (positive)
+/// * `expiry` - Time to expiry in years
+/// * `volatility` - Lognormal volatility
+/// * `is_call` - `true` for a call, `false` for a put
+///
+/// # Examples
+/// ```
+/// use pricer_models::analytical::black76_price;
+///
+/// let call = black76_price(0.03_f64, 0.03, 2.0, 0.2, true);
+/// let put = black76_price(0.03_f64, 0.03, 2.0, 0.2, false);
+/// assert!(call > 0.0);
+/// assert!((call - put).abs() < 1e-15);
+/// ```
+pub fn black76_price<T: Float>(
+    forward: T,
+    strike: T,
+    expiry: T,
+    volatility: T,
+    is_call: bool,
+) -> T {
+    let sd = volatility * expiry.sqrt();
+    if sd <= T::zero() {
+        return if is_call {
+            (forward - strike).max(T::zero())
+        } else {
+            (strike - forward).max(T::zero())
+        };
+    }
+    let half = T::from(0.5).unwrap();
+    let d1 = (forward / strike).ln() / sd + half * sd;
+    let d2 = d1 - sd;
+    if is_call {
+        forward * norm_cdf(d1) - strike * norm_cdf(d2)
+    } else {
+        strike * norm_cdf(-d2) - forward * norm_cdf(-d1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_put_call_parity() {
+        let (f, k) = (105.0_f64, 100.0);
+        let call = black76_price(f, k, 1.5, 0.25, true);
+        let put = black76_price(f, k, 1.5, 0.25, false);
+        assert_relative_eq!(call - put, f - k, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_zero_deviation_is_intrinsic() {
+        assert_eq!(black76_price(105.0_f64, 100.0, 0.0, 0.2, true), 5.0);
+        assert_eq!(black76_price(105.0_f64, 100.0, 1.0, 0.0, false), 0.0);
+    }
+
+    #[test]
+    fn test_at_the_money_value() {
+        // ATM: C = F·(2N(σ√T / 2) - 1)
+        let call = black76_price(100.0_f64, 100.0, 1.0, 0.2, true);
+        assert_relative_eq!(call, 7.965_567_46, epsilon = 1e-4);
+    }
+}
@@ -5,12 +5,16 @@
 //!
 //! ## Mathematical Formulas
 //!
-//! **Call Price**: C = S·N(d₁) - K·e^(-rT)·N(d₂)
-//! **Put Price**: P = K·e^(-rT)·N(-d₂) - S·N(-d₁)
+//! **Call Price**: C = S·e^(-qT)·N(d₁) - K·e^(-rT)·N(d₂)
+//! **Put Price**: P = K·e^(-rT)·N(-d₂) - S·e^(-qT)·N(-d₁)
 //!
 //! Where:
-//! - d₁ = (ln(S/K) + (r + σ²/2)T) / (σ√T)
+//! - d₁ = (ln(S/K) + (r - q + σ²/2)T) / (σ√T)
 //! - d₂ = d₁ - σ√T
+//! - q is the continuous dividend yield (zero unless set)
+//!
+//! Discrete dividends, dividend curves and repo/borrow costs are handled by
+//! [`EquityBlackScholes`](super::EquityBlackScholes).
 
 use num_traits::Float;
 
@@ -44,6 +48,8 @@ pub struct BlackScholes<T: Float> {
     spot: T,
     /// Risk-free interest rate (r)
     rate: T,
+    /// Continuous dividend yield (q)
+    dividend_yield: T,
     /// Volatility (σ)
     volatility: T,
 }
@@ -90,10 +96,28 @@ impl<T: Float> BlackScholes<T> {
         Ok(Self {
             spot,
             rate,
+            dividend_yield: zero,
             volatility,
         })
     }
 
+    /// Sets a continuous dividend yield.
+    ///
+    /// # Examples
+    /// ```
+    /// use pricer_models::analytical::BlackScholes;
+    ///
+    /// let bs = BlackScholes::new(100.0_f64, 0.05, 0.2).unwrap();
+    /// let with_div = bs.clone().with_dividend_yield(0.03);
+    ///
+    /// // Dividends lower the forward and hence the call value
+    /// assert!(with_div.price_call(100.0, 1.0) < bs.price_call(100.0, 1.0));
+    /// ```
+    pub fn with_dividend_yield(mut self, dividend_yield: T) -> Self {
+        self.dividend_yield = dividend_yield;
+        self
+    }
+
     /// Returns the spot price.
     #[inline]
     pub fn spot(&self) -> T {
@@ -106,15 +130,27 @@ impl<T: Float> BlackScholes<T> {
         self.rate
     }
 
+    /// Returns the continuous dividend yield.
+    #[inline]
+    pub fn dividend_yield(&self) -> T {
+        self.dividend_yield
+    }
+
     /// Returns the volatility.
     #[inline]
     pub fn volatility(&self) -> T {
         self.volatility
     }
 
+    /// Dividend discount factor e^(-qT).
+    #[inline]
+    fn dividend_discount(&self, expiry: T) -> T {
+        (-self.dividend_yield * expiry).exp()
+    }
+
     /// Computes the d1 term of the Black-Scholes formula.
     ///
-    /// d₁ = (ln(S/K) + (r + σ²/2)T) / (σ√T)
+    /// d₁ = (ln(S/K) + (r - q + σ²/2)T) / (σ√T)
     ///
     /// # Arguments
     /// * `strike` - Strike price (K)
@@ -144,9 +180,10 @@ impl<T: Float> BlackScholes<T> {
         let sqrt_t = expiry.sqrt();
         let vol_sqrt_t = self.volatility * sqrt_t;
 
-        // d1 = (ln(S/K) + (r + σ²/2)T) / (σ√T)
+        // d1 = (ln(S/K) + (r - q + σ²/2)T) / (σ√T)
         let log_moneyness = (self.spot / strike).ln();
-        let drift = (self.rate + half * self.volatility * self.volatility) * expiry;
+        let drift =
+            (self.rate - self.dividend_yield + half * self.volatility * self.volatility) * expiry;
 
         (log_moneyness + drift) / vol_sqrt_t
     }
@@ -175,7 +212,7 @@ impl<T: Float> BlackScholes<T> {
 
     /// Computes European call option price.
     ///
-    /// C = S·N(d₁) - K·e^(-rT)·N(d₂)
+    /// C = S·e^(-qT)·N(d₁) - K·e^(-rT)·N(d₂)
     ///
     /// # Arguments
     /// * `strike` - Strike price (K)
@@ -210,13 +247,13 @@ impl<T: Float> BlackScholes<T> {
 
         let discount = (-self.rate * expiry).exp();
 
-        // C = S·N(d₁) - K·e^(-rT)·N(d₂)
-        self.spot * norm_cdf(d1) - strike * discount * norm_cdf(d2)
+        // C = S·e^(-qT)·N(d₁) - K·e^(-rT)·N(d₂)
+        self.spot * self.dividend_discount(expiry) * norm_cdf(d1) - strike * discount * norm_cdf(d2)
     }
 
     /// Computes European put option price.
     ///
-    /// P = K·e^(-rT)·N(-d₂) - S·N(-d₁)
+    /// P = K·e^(-rT)·N(-d₂) - S·e^(-qT)·N(-d₁)
     ///
     /// # Arguments
     /// * `strike` - Strike price (K)
@@ -251,14 +288,15 @@ impl<T: Float> BlackScholes<T> {
 
         let discount = (-self.rate * expiry).exp();
 
-        // P = K·e^(-rT)·N(-d₂) - S·N(-d₁)
-        strike * discount * norm_cdf(-d2) - self.spot * norm_cdf(-d1)
+        // P = K·e^(-rT)·N(-d₂) - S·e^(-qT)·N(-d₁)
+        strike * discount * norm_cdf(-d2)
+            - self.spot * self.dividend_discount(expiry) * norm_cdf(-d1)
     }
 
     /// Computes Delta (∂V/∂S).
     ///
-    /// - Call Delta = N(d₁)
-    /// - Put Delta = N(d₁) - 1
+    /// - Call Delta = e^(-qT)·N(d₁)
+    /// - Put Delta = e^(-qT)·(N(d₁) - 1)
     ///
     /// # Arguments
     /// * `strike` - Strike price
@@ -283,17 +321,18 @@ impl<T: Float> BlackScholes<T> {
 
         let d1 = self.d1(strike, expiry);
         let n_d1 = norm_cdf(d1);
+        let q_discount = self.dividend_discount(expiry);
 
         if is_call {
-            n_d1
+            q_discount * n_d1
         } else {
-            n_d1 - T::one()
+            q_discount * (n_d1 - T::one())
         }
     }
 
     /// Computes Gamma (∂²V/∂S²).
     ///
-    /// Gamma = φ(d₁) / (S·σ·√T)
+    /// Gamma = e^(-qT)·φ(d₁) / (S·σ·√T)
     ///
     /// Gamma is the same for both calls and puts.
     ///
@@ -314,13 +353,13 @@ impl<T: Float> BlackScholes<T> {
         let d1 = self.d1(strike, expiry);
         let sqrt_t = expiry.sqrt();
 
-        // Gamma = φ(d₁) / (S·σ·√T)
-        norm_pdf(d1) / (self.spot * self.volatility * sqrt_t)
+        // Gamma = e^(-qT)·φ(d₁) / (S·σ·√T)
+        self.dividend_discount(expiry) * norm_pdf(d1) / (self.spot * self.volatility * sqrt_t)
     }
 
     /// Computes Vega (∂V/∂σ).
     ///
-    /// Vega = S·√T·φ(d₁)
+    /// Vega = S·e^(-qT)·√T·φ(d₁)
     ///
     /// Vega is the same for both calls and puts.
     ///
@@ -341,14 +380,14 @@ impl<T: Float> BlackScholes<T> {
         let d1 = self.d1(strike, expiry);
         let sqrt_t = expiry.sqrt();
 
-        // Vega = S·√T·φ(d₁)
-        self.spot * sqrt_t * norm_pdf(d1)
+        // Vega = S·e^(-qT)·√T·φ(d₁)
+        self.spot * self.dividend_discount(expiry) * sqrt_t * norm_pdf(d1)
     }
 
     /// Computes Theta (∂V/∂t).
     ///
-    /// - Call Theta = -(S·σ·φ(d₁))/(2√T) - r·K·e^(-rT)·N(d₂)
-    /// - Put Theta = -(S·σ·φ(d₁))/(2√T) + r·K·e^(-rT)·N(-d₂)
+    /// - Call Theta = -(S·e^(-qT)·σ·φ(d₁))/(2√T) + q·S·e^(-qT)·N(d₁) - r·K·e^(-rT)·N(d₂)
+    /// - Put Theta = -(S·e^(-qT)·σ·φ(d₁))/(2√T) - q·S·e^(-qT)·N(-d₁) + r·K·e^(-rT)·N(-d₂)
     ///
     /// Note: This returns the rate of change with respect to time,
     /// which is typically negative (time decay).
@@ -372,17 +411,20 @@ impl<T: Float> BlackScholes<T> {
         let d2 = self.d2(strike, expiry);
         let sqrt_t = expiry.sqrt();
         let discount = (-self.rate * expiry).exp();
+        let forward_spot = self.spot * self.dividend_discount(expiry);
         let two = T::from(2.0).unwrap();
 
-        // Common term: -(S·σ·φ(d₁))/(2√T)
-        let term1 = -(self.spot * self.volatility * norm_pdf(d1)) / (two * sqrt_t);
+        // Common term: -(S·e^(-qT)·σ·φ(d₁))/(2√T)
+        let term1 = -(forward_spot * self.volatility * norm_pdf(d1)) / (two * sqrt_t);
 
         if is_call {
-            // Call Theta = term1 - r·K·e^(-rT)·N(d₂)
-            term1 - self.rate * strike * discount * norm_cdf(d2)
+            // Call Theta = term1 + q·S·e^(-qT)·N(d₁) - r·K·e^(-rT)·N(d₂)
+            term1 + self.dividend_yield * forward_spot * norm_cdf(d1)
+                - self.rate * strike * discount * norm_cdf(d2)
         } else {
-            // Put Theta = term1 + r·K·e^(-rT)·N(-d₂)
-            term1 + self.rate * strike * discount * norm_cdf(-d2)
+            // Put Theta = term1 - q·S·e^(-qT)·N(-d₁) + r·K·e^(-rT)·N(-d₂)
+            term1 - self.dividend_yield * forward_spot * norm_cdf(-d1)
+                + self.rate * strike * discount * norm_cdf(-d2)
         }
     }
 
@@ -959,6 +1001,82 @@ mod tests {
         assert!(debug_str.contains("spot"));
     }
 
+    // ==========================================================
+    // Dividend Yield Tests
+    // ==========================================================
+
+    #[test]
+    fn test_dividend_yield_put_call_parity() {
+        // C - P = S·e^(-qT) - K·e^(-rT)
+        let bs = BlackScholes::new(100.0_f64, 0.05, 0.2)
+            .unwrap()
+            .with_dividend_yield(0.03);
+        assert_eq!(bs.dividend_yield(), 0.03);
+
+        let (strike, expiry) = (95.0, 1.5);
+        let parity = bs.price_call(strike, expiry) - bs.price_put(strike, expiry);
+        let expected = 100.0 * (-0.03 * expiry).exp() - strike * (-0.05 * expiry).exp();
+        assert_relative_eq!(parity, expected, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_dividend_yield_equals_rate_is_driftless() {
+        // With q = r the forward equals spot and d₁ = σ√T/2 at the money
+        let bs = BlackScholes::new(100.0_f64, 0.04, 0.25)
+            .unwrap()
+            .with_dividend_yield(0.04);
+        assert_relative_eq!(bs.d1(100.0, 2.0), 0.125 * 2.0_f64.sqrt(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_dividend_yield_greeks_vs_finite_diff() {
+        let (spot, rate, q, vol) = (100.0_f64, 0.05, 0.02, 0.3);
+        let (strike, expiry) = (105.0, 0.75);
+        let bs = |s: f64, v: f64| {
+            BlackScholes::new(s, rate, v)
+                .unwrap()
+                .with_dividend_yield(q)
+        };
+        let base = bs(spot, vol);
+        let h = 0.01;
+
+        for is_call in [true, false] {
+            let price = |s: f64, v: f64, t: f64| {
+                let m = bs(s, v);
+                if is_call {
+                    m.price_call(strike, t)
+                } else {
+                    m.price_put(strike, t)
+                }
+            };
+
+            let fd_delta =
+                (price(spot + h, vol, expiry) - price(spot - h, vol, expiry)) / (2.0 * h);
+            assert_relative_eq!(
+                base.delta(strike, expiry, is_call),
+                fd_delta,
+                epsilon = 1e-4
+            );
+
+            let fd_gamma = (price(spot + h, vol, expiry) - 2.0 * price(spot, vol, expiry)
+                + price(spot - h, vol, expiry))
+                / (h * h);
+            assert_relative_eq!(base.gamma(strike, expiry), fd_gamma, epsilon = 1e-3);
+
+            let fd_vega = (price(spot, vol + h, expiry) - price(spot, vol - h, expiry)) / (2.0 * h);
+            assert_relative_eq!(base.vega(strike, expiry), fd_vega, epsilon = 1e-3);
+
+            // Theta is the derivative with respect to calendar time
+            let fd_theta =
+                -(price(spot, vol, expiry + h) - price(spot, vol, expiry - h)) / (2.0 * h);
+            assert_relative_eq!(
+                base.theta(strike, expiry, is_call),
+                fd_theta,
+                epsilon = 1e-3
+            );
+        }
+    }
+
     // ==========================================================
     // f32 Compatibility Tests
     // ==========================================================
@@ -970,6 +1088,32 @@ mod tests {
         assert!(call > 0.0_f32);
     }
 
+    // ==========================================================
+    // Reverse-mode AD Tests
+    // ==========================================================
+
+    #[test]
+    fn test_tape_greeks_match_analytical() {
+        use pricer_core::types::tape::Tape;
+
+        let (spot, rate, vol, q, strike, expiry) = (100.0, 0.05, 0.2, 0.02, 105.0, 1.5);
+        let (price, gradient) = Tape::differentiate(&[spot, rate, vol, q], |x| {
+            BlackScholes::new(x[0], x[1], x[2])
+                .unwrap()
+                .with_dividend_yield(x[3])
+                .price_call(strike.into(), expiry.into())
+        })
+        .unwrap();
+
+        let bs = BlackScholes::new(spot, rate, vol)
+            .unwrap()
+            .with_dividend_yield(q);
+        assert_relative_eq!(price, bs.price_call(strike, expiry), epsilon = 1e-12);
+        assert_relative_eq!(gradient[0], bs.delta(strike, expiry, true), epsilon = 1e-5);
+        assert_relative_eq!(gradient[1], bs.rho(strike, expiry, true), epsilon = 1e-3);
+        assert_relative_eq!(gradient[2], bs.vega(strike, expiry), epsilon = 1e-3);
+    }
+
     // ==========================================================
     // Dual64 AD Compatibility Tests
     // ==========================================================
@@ -0,0 +1,326 @@
+//! Black-Scholes pricing on an [`EquityMarket`] with discrete dividends.
+//!
+//! European options are priced as Black-76 on the market forward,
+//! following the escrowed dividend model:
+//!
+//! **Call Price**: C = DF(T)·(F·N(d₁) - K·N(d₂))
+//! **Put Price**: P = DF(T)·(K·N(-d₂) - F·N(-d₁))
+//!
+//! Where:
+//! - F = G(T)·S*(T) is the forward including dividend yield, repo, borrow
+//!   and discrete dividends
+//! - d₁ = (ln(F/K) + σ²T/2) / (σ√T)
+//! - d₂ = d₁ - σ√T
+//!
+//! The volatility σ applies to the escrowed process, so cash dividends do
+//! not contribute to the diffusion.
+
+use num_traits::Float;
+use pricer_core::market_data::equity::EquityMarket;
+
+use super::distributions::{norm_cdf, norm_pdf};
+use super::error::AnalyticalError;
+use crate::instruments::{PayoffType, VanillaOption};
+
+/// Escrowed-dividend Black-Scholes model over an [`EquityMarket`].
+///
+/// # Type Parameters
+/// * `T` - Floating-point type implementing `Float` (e.g., `f64`, `Dual64`)
+///
+/// # Examples
+/// ```
+/// use pricer_core::market_data::curves::CurveEnum;
+/// use pricer_core::market_data::equity::{DividendSchedule, EquityMarket};
+/// use pricer_models::analytical::EquityBlackScholes;
+///
+/// let market = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05))
+///     .unwrap()
+///     .with_dividends(DividendSchedule::new().with_cash(0.5, 2.0).unwrap());
+/// let model = EquityBlackScholes::new(&market, 0.2).unwrap();
+///
+/// // Put-call parity: C - P = DF·(F - K)
+/// let call = model.price_call(100.0, 1.0).unwrap();
+/// let put = model.price_put(100.0, 1.0).unwrap();
+/// let forward = model.forward(1.0).unwrap();
+/// let df = market.discount_factor(1.0).unwrap();
+/// assert!((call - put - df * (forward - 100.0)).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EquityBlackScholes<'a, T: Float> {
+    /// Equity market data
+    market: &'a EquityMarket<T>,
+    /// Volatility of the escrowed process (σ)
+    volatility: T,
+}
+
+impl<'a, T: Float> EquityBlackScholes<'a, T> {
+    /// Creates a new model over the given market.
+    ///
+    /// # Errors
+    /// - `AnalyticalError::InvalidVolatility` if volatility <= 0
+    pub fn new(market: &'a EquityMarket<T>, volatility: T) -> Result<Self, AnalyticalError> {
+        if volatility <= T::zero() {
+            return Err(AnalyticalError::InvalidVolatility {
+                volatility: volatility.to_f64().unwrap_or(0.0),
+            });
+        }
+        Ok(Self { market, volatility })
+    }
+
+    /// Returns the equity market.
+    #[inline]
+    pub fn market(&self) -> &EquityMarket<T> {
+        self.market
+    }
+
+    /// Returns the volatility.
+    #[inline]
+    pub fn volatility(&self) -> T {
+        self.volatility
+    }
+
+    /// Returns the forward price to `expiry`.
+    pub fn forward(&self, expiry: T) -> Result<T, AnalyticalError> {
+        Ok(self.market.forward(expiry)?)
+    }
+
+    /// Undiscounted Black-76 value and d₁.
+    fn black(&self, forward: T, strike: T, expiry: T, is_call: bool) -> (T, T) {
+        let epsilon = T::from(1e-10).unwrap();
+        if expiry <= epsilon {
+            let intrinsic = if is_call {
+                (forward - strike).max(T::zero())
+            } else {
+                (strike - forward).max(T::zero())
+            };
+            let d1 = if forward > strike {
+                T::infinity()
+            } else {
+                T::neg_infinity()
+            };
+            return (intrinsic, d1);
+        }
+
+        let half = T::from(0.5).unwrap();
+        let vol_sqrt_t = self.volatility * expiry.sqrt();
+        let d1 = ((forward / strike).ln() + half * vol_sqrt_t * vol_sqrt_t) / vol_sqrt_t;
+        let d2 = d1 - vol_sqrt_t;
+
+        let value = if is_call {
+            forward * norm_cdf(d1) - strike * norm_cdf(d2)
+        } else {
+            strike * norm_cdf(-d2) - forward * norm_cdf(-d1)
+        };
+        (value, d1)
+    }
+
+    /// Calculates the European call option price.
+    pub fn price_call(&self, strike: T, expiry: T) -> Result<T, AnalyticalError> {
+        self.price(strike, expiry, true)
+    }
+
+    /// Calculates the European put option price.
+    pub fn price_put(&self, strike: T, expiry: T) -> Result<T, AnalyticalError> {
+        self.price(strike, expiry, false)
+    }
+
+    fn price(&self, strike: T, expiry: T, is_call: bool) -> Result<T, AnalyticalError> {
+        let forward = self.forward(expiry)?;
+        let df = self.market.discount_factor(expiry)?;
+        Ok(df * self.black(forward, strike, expiry, is_call).0)
+    }
+
+    /// Calculates Delta with respect to spot, holding dividends fixed.
+    ///
+    /// - Call Delta = DF(T)·G(T)·N(d₁)
+    /// - Put Delta = DF(T)·G(T)·(N(d₁) - 1)
+    pub fn delta(&self, strike: T, expiry: T, is_call: bool) -> Result<T, AnalyticalError> {
+        let forward = self.forward(expiry)?;
+        let df = self.market.discount_factor(expiry)?;
+        let growth = self.market.growth_factor(expiry)?;
+        let d1 = self.black(forward, strike, expiry, is_call).1;
+        let n_d1 = norm_cdf(d1);
+        Ok(if is_call {
+            df * growth * n_d1
+        } else {
+            df * growth * (n_d1 - T::one())
+        })
+    }
+
+    /// Calculates Vega (sensitivity to the escrowed volatility).
+    ///
+    /// Vega = DF(T)·F·√T·φ(d₁)
+    pub fn vega(&self, strike: T, expiry: T) -> Result<T, AnalyticalError> {
+        let epsilon = T::from(1e-10).unwrap();
+        if expiry <= epsilon {
+            return Ok(T::zero());
+        }
+        let forward = self.forward(expiry)?;
+        let df = self.market.discount_factor(expiry)?;
+        let d1 = self.black(forward, strike, expiry, true).1;
+        Ok(df * forward * expiry.sqrt() * norm_pdf(d1))
+    }
+
+    /// Prices a European vanilla option.
+    ///
+    /// # Errors
+    /// - `AnalyticalError::UnsupportedExerciseStyle` for non-European
+    ///   exercise or digital payoffs
+    /// - `AnalyticalError::MarketData` if the forward cannot be computed
+    pub fn price_option(&self, option: &VanillaOption<T>) -> Result<T, AnalyticalError> {
+        if !option.exercise_style().is_european() {
+            return Err(AnalyticalError::UnsupportedExerciseStyle {
+                style: "non-European".to_string(),
+            });
+        }
+
+        let strike = option.strike();
+        let expiry = option.expiry();
+        let unit_price = match option.payoff_type() {
+            PayoffType::Call => self.price_call(strike, expiry)?,
+            PayoffType::Put => self.price_put(strike, expiry)?,
+            PayoffType::DigitalCall | PayoffType::DigitalPut => {
+                return Err(AnalyticalError::UnsupportedExerciseStyle {
+                    style: "Digital options require different pricing".to_string(),
+                });
+            }
+        };
+        Ok(option.notional() * unit_price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::BlackScholes;
+    use crate::instruments::{ExerciseStyle, InstrumentParams};
+    use approx::assert_relative_eq;
+    use pricer_core::market_data::curves::CurveEnum;
+    use pricer_core::market_data::equity::DividendSchedule;
+
+    #[test]
+    fn test_matches_black_scholes_with_yield() {
+        let market = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_dividend_yield(0.02);
+        let model = EquityBlackScholes::new(&market, 0.25).unwrap();
+        let bs = BlackScholes::new(100.0, 0.05, 0.25)
+            .unwrap()
+            .with_dividend_yield(0.02);
+
+        for strike in [80.0, 100.0, 120.0] {
+            assert_relative_eq!(
+                model.price_call(strike, 1.5).unwrap(),
+                bs.price_call(strike, 1.5),
+                epsilon = 1e-10
+            );
+            assert_relative_eq!(
+                model.price_put(strike, 1.5).unwrap(),
+                bs.price_put(strike, 1.5),
+                epsilon = 1e-10
+            );
+            assert_relative_eq!(
+                model.delta(strike, 1.5, true).unwrap(),
+                bs.delta(strike, 1.5, true),
+                epsilon = 1e-10
+            );
+            assert_relative_eq!(
+                model.vega(strike, 1.5).unwrap(),
+                bs.vega(strike, 1.5),
+                epsilon = 1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn test_borrow_cost_acts_as_yield() {
+        let with_borrow = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_borrow_cost(0.01)
+            .with_repo_spread(-0.005);
+        let with_yield = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_dividend_yield(0.015);
+        let a = EquityBlackScholes::new(&with_borrow, 0.2).unwrap();
+        let b = EquityBlackScholes::new(&with_yield, 0.2).unwrap();
+        assert_relative_eq!(
+            a.price_call(100.0, 2.0).unwrap(),
+            b.price_call(100.0, 2.0).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_cash_dividends_parity_and_delta() {
+        let market = EquityMarket::new(100.0_f64, CurveEnum::flat(0.04))
+            .unwrap()
+            .with_dividends(
+                DividendSchedule::new()
+                    .with_cash(0.3, 1.5)
+                    .unwrap()
+                    .with_cash(0.8, 1.5)
+                    .unwrap(),
+            );
+        let model = EquityBlackScholes::new(&market, 0.3).unwrap();
+        let (strike, expiry) = (95.0, 1.0);
+
+        let call = model.price_call(strike, expiry).unwrap();
+        let put = model.price_put(strike, expiry).unwrap();
+        let df = market.discount_factor(expiry).unwrap();
+        let forward = model.forward(expiry).unwrap();
+        assert_relative_eq!(call - put, df * (forward - strike), epsilon = 1e-10);
+
+        // Dividends lower the call value
+        let no_div = EquityMarket::new(100.0_f64, CurveEnum::flat(0.04)).unwrap();
+        let plain = EquityBlackScholes::new(&no_div, 0.3).unwrap();
+        assert!(call < plain.price_call(strike, expiry).unwrap());
+
+        // Delta vs bumped spot with the same dividends
+        let h = 0.01;
+        let bumped = |s: f64| {
+            let m = EquityMarket::new(s, CurveEnum::flat(0.04))
+                .unwrap()
+                .with_dividends(market.dividends().clone());
+            EquityBlackScholes::new(&m, 0.3)
+                .unwrap()
+                .price_call(strike, expiry)
+                .unwrap()
+        };
+        let fd_delta = (bumped(100.0 + h) - bumped(100.0 - h)) / (2.0 * h);
+        assert_relative_eq!(
+            model.delta(strike, expiry, true).unwrap(),
+            fd_delta,
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    fn test_price_option() {
+        let market = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05)).unwrap();
+        let model = EquityBlackScholes::new(&market, 0.2).unwrap();
+        let params = InstrumentParams::new(100.0, 1.0, 10.0).unwrap();
+
+        let call = VanillaOption::new(params, PayoffType::Call, ExerciseStyle::European, 1e-6);
+        assert_relative_eq!(
+            model.price_option(&call).unwrap(),
+            10.0 * model.price_call(100.0, 1.0).unwrap(),
+            epsilon = 1e-10
+        );
+
+        let american = VanillaOption::new(params, PayoffType::Call, ExerciseStyle::American, 1e-6);
+        assert!(model.price_option(&american).is_err());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let market = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_dividends(DividendSchedule::new().with_cash(0.5, 200.0).unwrap());
+        assert!(EquityBlackScholes::new(&market, 0.0).is_err());
+        let model = EquityBlackScholes::new(&market, 0.2).unwrap();
+        assert!(matches!(
+            model.price_call(100.0, 1.0),
+            Err(AnalyticalError::MarketData(_))
+        ));
+    }
+}
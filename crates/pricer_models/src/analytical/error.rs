@@ -3,6 +3,7 @@
 //! This module provides:
 //! - `AnalyticalError`: Errors specific to analytical pricing models
 
+use pricer_core::market_data::MarketDataError;
 use pricer_core::types::PricingError;
 use thiserror::Error;
 
@@ -15,7 +16,9 @@ use thiserror::Error;
 /// - `InvalidVolatility`: Non-positive volatility
 /// - `InvalidSpot`: Non-positive spot price (for Black-Scholes)
 /// - `UnsupportedExerciseStyle`: Exercise style not supported by model
+/// - `InvalidParameter`: Model parameter outside its admissible range
 /// - `NumericalInstability`: Computation encountered numerical issues
+/// - `MarketData`: Market data lookup failed (curves, dividends)
 ///
 /// # Examples
 /// ```
@@ -47,20 +50,34 @@ pub enum AnalyticalError {
         style: String,
     },
 
+    /// Model parameter outside its admissible range.
+    #[error("Invalid parameter: {name} = {value}")]
+    InvalidParameter {
+        /// Parameter name
+        name: &'static str,
+        /// The invalid value
+        value: f64,
+    },
+
     /// Numerical instability during computation.
     #[error("Numerical instability: {message}")]
     NumericalInstability {
         /// Description of the numerical issue
         message: String,
     },
+
+    /// Market data lookup failed.
+    #[error("Market data error: {0}")]
+    MarketData(#[from] MarketDataError),
 }
 
 impl From<AnalyticalError> for PricingError {
     fn from(err: AnalyticalError) -> Self {
         match err {
-            AnalyticalError::InvalidVolatility { .. } | AnalyticalError::InvalidSpot { .. } => {
-                PricingError::InvalidInput(err.to_string())
-            }
+            AnalyticalError::InvalidVolatility { .. }
+            | AnalyticalError::InvalidSpot { .. }
+            | AnalyticalError::InvalidParameter { .. }
+            | AnalyticalError::MarketData(_) => PricingError::InvalidInput(err.to_string()),
             AnalyticalError::UnsupportedExerciseStyle { .. } => {
                 PricingError::UnsupportedInstrument(err.to_string())
             }
@@ -151,6 +168,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_invalid_parameter_to_pricing_error() {
+        let err = AnalyticalError::InvalidParameter {
+            name: "kappa",
+            value: -1.0,
+        };
+        assert_eq!(format!("{}", err), "Invalid parameter: kappa = -1");
+        let pricing_err: PricingError = err.into();
+        assert!(matches!(pricing_err, PricingError::InvalidInput(_)));
+    }
+
     #[test]
     fn test_unsupported_exercise_to_pricing_error() {
         let err = AnalyticalError::UnsupportedExerciseStyle {
@@ -0,0 +1,336 @@
+//! Carr-Madan FFT pricing of strike strips.
+//!
+//! For log-moneyness `k = ln(K/F)` the damped call `e^{αk}·c(k)`, with
+//! `c(k) = E[(e^X − e^k)⁺]`, is square integrable and has the transform
+//!
+//! `ψ(v) = φ(v − (α+1)i) / (α² + α − v² + i(2α+1)v)`.
+//!
+//! Sampling `ψ` at `v_j = jη` with Simpson weights and inverting with an
+//! `N`-point FFT yields `c(k)` on the grid `k_m = −Nλ/2 + mλ`,
+//! `λ = 2π/(Nη)`. The call price is `e^{−rT}·F·c(k)`.
+
+use std::f64::consts::PI;
+
+use super::characteristic::CharacteristicFunction;
+use super::complex::Complex64;
+use super::{check_contract, FourierMarket};
+use crate::analytical::error::AnalyticalError;
+
+/// Default FFT size.
+const DEFAULT_POINTS: usize = 4096;
+
+/// Default spacing of the integration grid.
+const DEFAULT_ETA: f64 = 0.25;
+
+/// Default damping exponent.
+const DEFAULT_ALPHA: f64 = 1.5;
+
+/// Carr-Madan FFT pricer.
+///
+/// # Examples
+/// ```
+/// use pricer_models::analytical::fourier::{CarrMadanPricer, FourierMarket, HestonCf};
+///
+/// let heston = HestonCf::new(0.04, 1.5, 0.04, 0.5, -0.7).unwrap();
+/// let market = FourierMarket::new(100.0, 0.03, 0.0).unwrap();
+/// let fft = CarrMadanPricer::default();
+///
+/// let strip = fft.call_strip(&heston, &market, 1.0).unwrap();
+/// assert_eq!(strip.strikes.len(), 4096);
+/// let call = strip.interpolate(100.0).unwrap();
+/// assert!(call > 0.0 && call < 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CarrMadanPricer {
+    n: usize,
+    eta: f64,
+    alpha: f64,
+}
+
+impl Default for CarrMadanPricer {
+    fn default() -> Self {
+        Self {
+            n: DEFAULT_POINTS,
+            eta: DEFAULT_ETA,
+            alpha: DEFAULT_ALPHA,
+        }
+    }
+}
+
+/// Call prices on the FFT strike grid, with strikes ascending.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrikeStrip {
+    /// Strikes `F·e^{k_m}`.
+    pub strikes: Vec<f64>,
+    /// Call prices at `strikes`.
+    pub calls: Vec<f64>,
+}
+
+impl StrikeStrip {
+    /// Interpolates the call price at `strike`, cubic in log-strike.
+    ///
+    /// Returns `None` outside the interior of the strip.
+    pub fn interpolate(&self, strike: f64) -> Option<f64> {
+        let n = self.strikes.len();
+        if n < 4 || !(strike >= self.strikes[1] && strike <= self.strikes[n - 2]) {
+            return None;
+        }
+        // First node above the strike, so that two nodes lie on each side
+        let upper = self
+            .strikes
+            .partition_point(|&s| s < strike)
+            .clamp(2, n - 2);
+        let nodes = upper - 2..upper + 2;
+        let log_strike = strike.ln();
+        let log_nodes: Vec<f64> = self.strikes[nodes.clone()].iter().map(|s| s.ln()).collect();
+
+        // Lagrange interpolation on four nodes
+        Some(
+            self.calls[nodes]
+                .iter()
+                .enumerate()
+                .map(|(i, &call)| {
+                    let basis: f64 = (0..4)
+                        .filter(|&j| j != i)
+                        .map(|j| (log_strike - log_nodes[j]) / (log_nodes[i] - log_nodes[j]))
+                        .product();
+                    basis * call
+                })
+                .sum(),
+        )
+    }
+}
+
+impl CarrMadanPricer {
+    /// Creates a pricer with `n` FFT points, grid spacing `eta` and
+    /// damping `alpha`.
+    ///
+    /// The log-strike spacing is `2π/(n·eta)`.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` unless `n` is a power
+    /// of two of at least 4 and `eta`, `alpha` are positive.
+    pub fn new(n: usize, eta: f64, alpha: f64) -> Result<Self, AnalyticalError> {
+        if n < 4 || !n.is_power_of_two() {
+            return Err(AnalyticalError::InvalidParameter {
+                name: "n",
+                value: n as f64,
+            });
+        }
+        if !(eta > 0.0 && eta.is_finite()) {
+            return Err(AnalyticalError::InvalidParameter {
+                name: "eta",
+                value: eta,
+            });
+        }
+        if !(alpha > 0.0 && alpha.is_finite()) {
+            return Err(AnalyticalError::InvalidParameter {
+                name: "alpha",
+                value: alpha,
+            });
+        }
+        Ok(Self { n, eta, alpha })
+    }
+
+    /// Spacing of the log-strike grid.
+    #[inline]
+    pub fn log_strike_spacing(&self) -> f64 {
+        2.0 * PI / (self.n as f64 * self.eta)
+    }
+
+    /// Prices calls on the whole FFT strike grid.
+    ///
+    /// The model must have a finite moment of order `α + 1`.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` for a non-positive
+    /// expiry.
+    pub fn call_strip<M: CharacteristicFunction + ?Sized>(
+        &self,
+        model: &M,
+        market: &FourierMarket,
+        expiry: f64,
+    ) -> Result<StrikeStrip, AnalyticalError> {
+        check_contract(1.0, expiry)?;
+        let (n, eta, alpha) = (self.n, self.eta, self.alpha);
+        let lambda = self.log_strike_spacing();
+        let lower = -0.5 * n as f64 * lambda;
+        let i = Complex64::i();
+
+        let mut samples: Vec<Complex64> = (0..n)
+            .map(|j| {
+                let v = j as f64 * eta;
+                let phi = model.characteristic_function(Complex64::new(v, -(alpha + 1.0)), expiry);
+                let denominator =
+                    Complex64::new(alpha * alpha + alpha - v * v, (2.0 * alpha + 1.0) * v);
+                let simpson = match j {
+                    0 => 1.0 / 3.0,
+                    j if j % 2 == 1 => 4.0 / 3.0,
+                    _ => 2.0 / 3.0,
+                };
+                phi / denominator * (i * (-lower * v)).exp() * (eta * simpson)
+            })
+            .collect();
+        fft(&mut samples);
+
+        let forward = market.forward(expiry);
+        let scale = market.discount(expiry) * forward;
+        let (strikes, calls) = samples
+            .iter()
+            .enumerate()
+            .map(|(m, transform)| {
+                let k = lower + m as f64 * lambda;
+                let call = (-alpha * k).exp() / PI * transform.re;
+                (forward * k.exp(), scale * call)
+            })
+            .unzip();
+        Ok(StrikeStrip { strikes, calls })
+    }
+
+    /// Prices European options at arbitrary strikes by interpolating the
+    /// strip; puts follow from put-call parity.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` for a non-positive
+    /// strike or expiry, or a strike outside the strip.
+    pub fn price_strikes<M: CharacteristicFunction + ?Sized>(
+        &self,
+        model: &M,
+        market: &FourierMarket,
+        strikes: &[f64],
+        expiry: f64,
+        is_call: bool,
+    ) -> Result<Vec<f64>, AnalyticalError> {
+        for &strike in strikes {
+            check_contract(strike, expiry)?;
+        }
+        let strip = self.call_strip(model, market, expiry)?;
+        let forward = market.forward(expiry);
+        let discount = market.discount(expiry);
+        strikes
+            .iter()
+            .map(|&strike| {
+                let call = strip
+                    .interpolate(strike)
+                    .ok_or(AnalyticalError::InvalidParameter {
+                        name: "strike",
+                        value: strike,
+                    })?;
+                Ok(if is_call {
+                    call
+                } else {
+                    call - discount * (forward - strike)
+                })
+            })
+            .collect()
+    }
+}
+
+/// In-place radix-2 FFT, `X_m = Σ_j x_j·e^{−2πi·jm/N}`.
+fn fft(data: &mut [Complex64]) {
+    let n = data.len();
+    debug_assert!(n.is_power_of_two());
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f64;
+        let root = Complex64::new(angle.cos(), angle.sin());
+        for chunk in data.chunks_mut(len) {
+            let mut twiddle = Complex64::from(1.0);
+            let (even, odd) = chunk.split_at_mut(len / 2);
+            for (e, o) in even.iter_mut().zip(odd.iter_mut()) {
+                let t = *o * twiddle;
+                *o = *e - t;
+                *e = *e + t;
+                twiddle = twiddle * root;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::fourier::{BatesCf, CgmyCf, CosPricer, HestonCf, VarianceGammaCf};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_fft_matches_direct_transform() {
+        let input: Vec<Complex64> = (0..8)
+            .map(|j| Complex64::new(j as f64, (j * j) as f64 * 0.1))
+            .collect();
+        let mut output = input.clone();
+        fft(&mut output);
+        for (m, value) in output.iter().enumerate() {
+            let direct = input
+                .iter()
+                .enumerate()
+                .fold(Complex64::default(), |acc, (j, &x)| {
+                    let angle = -2.0 * PI * (j * m) as f64 / 8.0;
+                    acc + x * Complex64::new(angle.cos(), angle.sin())
+                });
+            assert_relative_eq!(value.re, direct.re, epsilon = 1e-10);
+            assert_relative_eq!(value.im, direct.im, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_cos_across_models() {
+        let heston = HestonCf::new(0.0175, 1.5768, 0.0398, 0.5751, -0.5711).unwrap();
+        let models: [&dyn CharacteristicFunction; 4] = [
+            &heston,
+            &BatesCf::new(heston, 0.3, -0.1, 0.2).unwrap(),
+            &VarianceGammaCf::new(0.12, 0.2, -0.14).unwrap(),
+            &CgmyCf::new(1.0, 5.0, 5.0, 0.5).unwrap(),
+        ];
+        let market = FourierMarket::new(100.0, 0.05, 0.01).unwrap();
+        let strikes = [80.0, 90.0, 100.0, 110.0, 125.0];
+        let fft = CarrMadanPricer::default();
+        let cos = CosPricer::new(1024, 12.0).unwrap();
+        for model in models {
+            for is_call in [true, false] {
+                let reference = cos
+                    .price_strikes(model, &market, &strikes, 1.0, is_call)
+                    .unwrap();
+                let prices = fft
+                    .price_strikes(model, &market, &strikes, 1.0, is_call)
+                    .unwrap();
+                for (price, expected) in prices.iter().zip(&reference) {
+                    assert_relative_eq!(*price, *expected, epsilon = 1e-3);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strip_bounds_and_validation() {
+        let heston = HestonCf::new(0.04, 1.5, 0.04, 0.5, -0.7).unwrap();
+        let market = FourierMarket::new(100.0, 0.0, 0.0).unwrap();
+        let fft = CarrMadanPricer::new(256, 0.25, 1.5).unwrap();
+        let strip = fft.call_strip(&heston, &market, 1.0).unwrap();
+        assert!(strip.strikes.windows(2).all(|w| w[0] < w[1]));
+        assert!(strip.interpolate(strip.strikes[0] * 0.5).is_none());
+        assert!(fft
+            .price_strikes(&heston, &market, &[1e9], 1.0, true)
+            .is_err());
+
+        assert!(CarrMadanPricer::new(1000, 0.25, 1.5).is_err());
+        assert!(CarrMadanPricer::new(1024, 0.0, 1.5).is_err());
+        assert!(CarrMadanPricer::new(1024, 0.25, -1.0).is_err());
+    }
+}
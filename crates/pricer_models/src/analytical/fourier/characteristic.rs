@@ -0,0 +1,702 @@
+//! Characteristic functions of the log-forward return.
+//!
+//! Every model describes `X_t = ln(S_t / F_t)`, the log return relative to
+//! the forward `F_t = S_0·e^{(r−q)t}`. Drift and carry are therefore left
+//! to the market inputs, and `E[e^{X_t}] = 1` for every model.
+
+use std::f64::consts::PI;
+
+use super::complex::Complex64;
+use crate::analytical::error::AnalyticalError;
+use crate::models::heston::HestonParams;
+use crate::models::jump_diffusion::{BatesParams, MertonParams};
+
+/// Step of the finite differences in [`CharacteristicFunction::cumulants`].
+const CUMULANT_STEP: f64 = 1e-2;
+
+/// A model priced through the characteristic function of its log-forward
+/// return.
+pub trait CharacteristicFunction {
+    /// Returns `E[exp(i·u·X_t)]` for the log-forward return `X_t` at `t`.
+    ///
+    /// `u` may be complex; pricing methods evaluate the function inside
+    /// the strip of regularity as well as on the real axis.
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64;
+
+    /// Returns the first, second and fourth cumulants of `X_t`.
+    ///
+    /// The default differentiates the cumulant generating function
+    /// `K(s) = ln φ(−i·s)` numerically at zero, which requires exponential
+    /// moments of order up to `±0.02`.
+    fn cumulants(&self, t: f64) -> Cumulants {
+        let h = CUMULANT_STEP;
+        let k = |s: f64| {
+            self.characteristic_function(Complex64::new(0.0, -s), t)
+                .ln()
+                .re
+        };
+        let (k_m2, k_m1, k_p1, k_p2) = (k(-2.0 * h), k(-h), k(h), k(2.0 * h));
+        Cumulants {
+            c1: (k_p1 - k_m1) / (2.0 * h),
+            c2: (k_p1 + k_m1) / (h * h),
+            c4: (k_p2 - 4.0 * (k_p1 + k_m1) + k_m2) / h.powi(4),
+        }
+    }
+}
+
+/// Cumulants of the log-forward return, used to truncate the COS range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cumulants {
+    /// Mean.
+    pub c1: f64,
+    /// Variance.
+    pub c2: f64,
+    /// Fourth cumulant.
+    pub c4: f64,
+}
+
+fn check(name: &'static str, value: f64, valid: bool) -> Result<(), AnalyticalError> {
+    if value.is_finite() && valid {
+        Ok(())
+    } else {
+        Err(AnalyticalError::InvalidParameter { name, value })
+    }
+}
+
+/// Heston stochastic volatility model.
+///
+/// `dv = κ(θ − v)dt + ξ√v dW_v` with `d⟨W_S, W_v⟩ = ρ dt`. The
+/// characteristic function uses the Albrecher et al. ("little Heston
+/// trap") branch, which is continuous in `u`.
+///
+/// # Examples
+/// ```
+/// use pricer_models::analytical::fourier::{CharacteristicFunction, Complex64, HestonCf};
+///
+/// let heston = HestonCf::new(0.04, 1.5, 0.04, 0.5, -0.7).unwrap();
+/// // Martingale: E[exp(X)] = φ(−i) = 1
+/// let phi = heston.characteristic_function(Complex64::new(0.0, -1.0), 1.0);
+/// assert!((phi.re - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HestonCf {
+    /// Initial variance.
+    pub v0: f64,
+    /// Mean reversion speed.
+    pub kappa: f64,
+    /// Long-run variance.
+    pub theta: f64,
+    /// Volatility of variance.
+    pub xi: f64,
+    /// Spot-variance correlation.
+    pub rho: f64,
+}
+
+impl HestonCf {
+    /// Creates a Heston model.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` unless `v0 ≥ 0`,
+    /// `κ > 0`, `θ ≥ 0`, `ξ > 0` and `|ρ| ≤ 1`.
+    pub fn new(
+        v0: f64,
+        kappa: f64,
+        theta: f64,
+        xi: f64,
+        rho: f64,
+    ) -> Result<Self, AnalyticalError> {
+        check("v0", v0, v0 >= 0.0)?;
+        check("kappa", kappa, kappa > 0.0)?;
+        check("theta", theta, theta >= 0.0)?;
+        check("xi", xi, xi > 0.0)?;
+        check("rho", rho, rho.abs() <= 1.0)?;
+        Ok(Self {
+            v0,
+            kappa,
+            theta,
+            xi,
+            rho,
+        })
+    }
+
+    /// Creates the model from Monte Carlo Heston parameters.
+    ///
+    /// # Errors
+    /// As for [`HestonCf::new`].
+    pub fn from_params(params: &HestonParams<f64>) -> Result<Self, AnalyticalError> {
+        Self::new(params.v0, params.kappa, params.theta, params.xi, params.rho)
+    }
+
+    /// Returns `(C, D)` with `φ(u) = exp(C + D·v0)`.
+    pub fn exponents(&self, u: Complex64, t: f64) -> (Complex64, Complex64) {
+        let i = Complex64::i();
+        let xi2 = self.xi * self.xi;
+
+        let alpha = u * (u + i) * -0.5;
+        let beta = self.kappa - i * u * (self.rho * self.xi);
+        let d = (beta * beta - alpha * (2.0 * xi2)).sqrt();
+        // Avoid numerical issues with small d
+        let d = if d.norm() < 1e-10 {
+            Complex64::new(1e-10, 0.0)
+        } else {
+            d
+        };
+
+        let g = (beta - d) / (beta + d);
+        let exp_dt = (d * -t).exp();
+        let one_minus_g_exp = 1.0 - g * exp_dt;
+
+        let c = ((beta - d) * t - 2.0 * (one_minus_g_exp / (1.0 - g)).ln())
+            * (self.kappa * self.theta / xi2);
+        let d_fn = (beta - d) / xi2 * ((1.0 - exp_dt) / one_minus_g_exp);
+        (c, d_fn)
+    }
+}
+
+impl CharacteristicFunction for HestonCf {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let (c, d) = self.exponents(u, t);
+        (c + d * self.v0).exp()
+    }
+}
+
+/// Default number of Adams steps in [`RoughHestonCf`].
+pub const ROUGH_HESTON_STEPS: usize = 200;
+
+/// Rough Heston model (El Euch and Rosenbaum 2019).
+///
+/// The variance is the Volterra process
+/// `v_t = v0 + (1/Γ(α))∫(t − s)^{α−1}[κ(θ − v_s)ds + ξ√v_s dW_v]` with
+/// `α = H + ½`, which reduces to [`HestonCf`] at `H = ½`. The
+/// characteristic function is `exp(κθ·I¹h(T) + v0·I^{1−α}h(T))`, where `h`
+/// solves the fractional Riccati equation
+/// `D^α h = −½u(u + i) + (iρξu − κ)h + ½ξ²h²`, `h(0) = 0`. It is
+/// integrated with the fractional Adams predictor-corrector on `n_steps`
+/// uniform steps, at a cost of `O(n_steps²)` per evaluation.
+///
+/// # Examples
+/// ```
+/// use pricer_models::analytical::fourier::{CosPricer, FourierMarket, RoughHestonCf};
+///
+/// let rough = RoughHestonCf::new(0.04, 1.5, 0.04, 0.5, -0.7, 0.1).unwrap();
+/// let market = FourierMarket::new(100.0, 0.03, 0.0).unwrap();
+/// let call = CosPricer::default().price(&rough, &market, 100.0, 0.25, true).unwrap();
+/// assert!(call > 0.0 && call < 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoughHestonCf {
+    /// Initial variance.
+    pub v0: f64,
+    /// Mean reversion speed.
+    pub kappa: f64,
+    /// Long-run variance.
+    pub theta: f64,
+    /// Volatility of variance.
+    pub xi: f64,
+    /// Spot-variance correlation.
+    pub rho: f64,
+    /// Hurst exponent of the variance, in `(0, ½]`.
+    pub hurst: f64,
+    /// Number of Adams steps of the fractional Riccati solver.
+    pub n_steps: usize,
+}
+
+impl RoughHestonCf {
+    /// Creates a rough Heston model with [`ROUGH_HESTON_STEPS`] Adams
+    /// steps.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` unless the Heston
+    /// parameters satisfy [`HestonCf::new`] and `0 < H ≤ ½`.
+    pub fn new(
+        v0: f64,
+        kappa: f64,
+        theta: f64,
+        xi: f64,
+        rho: f64,
+        hurst: f64,
+    ) -> Result<Self, AnalyticalError> {
+        Self::from_heston(HestonCf::new(v0, kappa, theta, xi, rho)?, hurst)
+    }
+
+    /// Roughens a Heston model to Hurst exponent `hurst`.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` unless `0 < H ≤ ½`.
+    pub fn from_heston(heston: HestonCf, hurst: f64) -> Result<Self, AnalyticalError> {
+        check("hurst", hurst, hurst > 0.0 && hurst <= 0.5)?;
+        Ok(Self {
+            v0: heston.v0,
+            kappa: heston.kappa,
+            theta: heston.theta,
+            xi: heston.xi,
+            rho: heston.rho,
+            hurst,
+            n_steps: ROUGH_HESTON_STEPS,
+        })
+    }
+
+    /// Sets the number of Adams steps.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` if `n_steps == 0`.
+    pub fn with_steps(mut self, n_steps: usize) -> Result<Self, AnalyticalError> {
+        check("n_steps", n_steps as f64, n_steps > 0)?;
+        self.n_steps = n_steps;
+        Ok(self)
+    }
+
+    /// Returns `(I¹h(T), I¹F(h)(T))`, the exponent coefficients of `κθ`
+    /// and `v0`; `I^{1−α}h = I¹F(h)` since `h = I^α F(h)`.
+    fn riccati_integrals(&self, u: Complex64, t: f64) -> (Complex64, Complex64) {
+        let n = self.n_steps.max(1);
+        let alpha = self.hurst + 0.5;
+        let dt = t / n as f64;
+        let i = Complex64::i();
+
+        let a = u * (u + i) * -0.5;
+        let b = i * u * (self.rho * self.xi) - self.kappa;
+        let c = 0.5 * self.xi * self.xi;
+        let riccati = |x: Complex64| a + b * x + c * x * x;
+
+        // Weights depend on the lag m = k − j only
+        let scale_p = dt.powf(alpha) / gamma(alpha + 1.0);
+        let scale_c = dt.powf(alpha) / gamma(alpha + 2.0);
+        let pow = |m: usize, p: f64| (m as f64).powf(p);
+        let predictor: Vec<f64> = (0..n)
+            .map(|m| scale_p * (pow(m + 1, alpha) - pow(m, alpha)))
+            .collect();
+        let corrector: Vec<f64> = (0..n)
+            .map(|m| {
+                scale_c
+                    * (pow(m + 2, alpha + 1.0) + pow(m, alpha + 1.0)
+                        - 2.0 * pow(m + 1, alpha + 1.0))
+            })
+            .collect();
+
+        let mut h = vec![Complex64::default(); n + 1];
+        let mut f = vec![Complex64::default(); n + 1];
+        f[0] = riccati(h[0]);
+        for k in 0..n {
+            let history = &f[..=k];
+            let guess = history
+                .iter()
+                .enumerate()
+                .fold(Complex64::default(), |acc, (j, &fj)| {
+                    acc + fj * predictor[k - j]
+                });
+            let first = scale_c * (pow(k, alpha + 1.0) - (k as f64 - alpha) * pow(k + 1, alpha));
+            let interior = history
+                .iter()
+                .enumerate()
+                .skip(1)
+                .fold(Complex64::default(), |acc, (j, &fj)| {
+                    acc + fj * corrector[k - j]
+                });
+            h[k + 1] = f[0] * first + interior + riccati(guess) * scale_c;
+            f[k + 1] = riccati(h[k + 1]);
+        }
+
+        let trapezoid = |values: &[Complex64]| {
+            let inner = values[1..n]
+                .iter()
+                .fold(Complex64::default(), |acc, &v| acc + v);
+            (inner + (values[0] + values[n]) * 0.5) * dt
+        };
+        (trapezoid(&h), trapezoid(&f))
+    }
+}
+
+impl CharacteristicFunction for RoughHestonCf {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        if t <= 0.0 {
+            return Complex64::from(1.0);
+        }
+        let (mean_reversion, variance) = self.riccati_integrals(u, t);
+        (mean_reversion * (self.kappa * self.theta) + variance * self.v0).exp()
+    }
+}
+
+/// Bates model: Heston with lognormal (Merton) jumps in the spot.
+///
+/// Jumps arrive at rate `λ` with `ln(1 + J) ~ N(μ_J, δ_J²)`; the drift is
+/// compensated so that the forward is unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatesCf {
+    /// Diffusive Heston part.
+    pub heston: HestonCf,
+    /// Jump intensity `λ` (per year).
+    pub jump_intensity: f64,
+    /// Mean log jump size `μ_J`.
+    pub jump_mean: f64,
+    /// Log jump size volatility `δ_J`.
+    pub jump_vol: f64,
+}
+
+impl BatesCf {
+    /// Creates a Bates model.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` for a negative
+    /// intensity or jump volatility, or a non-finite jump mean.
+    pub fn new(
+        heston: HestonCf,
+        jump_intensity: f64,
+        jump_mean: f64,
+        jump_vol: f64,
+    ) -> Result<Self, AnalyticalError> {
+        check("jump_intensity", jump_intensity, jump_intensity >= 0.0)?;
+        check("jump_mean", jump_mean, true)?;
+        check("jump_vol", jump_vol, jump_vol >= 0.0)?;
+        Ok(Self {
+            heston,
+            jump_intensity,
+            jump_mean,
+            jump_vol,
+        })
+    }
+
+    /// Creates the model from Monte Carlo Bates parameters.
+    ///
+    /// # Errors
+    /// As for [`HestonCf::new`] and [`BatesCf::new`].
+    pub fn from_params(params: &BatesParams<f64>) -> Result<Self, AnalyticalError> {
+        Self::new(
+            HestonCf::from_params(&params.heston)?,
+            params.jump_intensity,
+            params.jump_mean,
+            params.jump_vol,
+        )
+    }
+}
+
+impl CharacteristicFunction for BatesCf {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let jumps =
+            lognormal_jump_exponent(u, t, self.jump_intensity, self.jump_mean, self.jump_vol);
+        self.heston.characteristic_function(u, t) * jumps.exp()
+    }
+}
+
+/// Merton jump diffusion: Black-Scholes with lognormal jumps.
+///
+/// Jumps arrive at rate `λ` with `ln(1 + J) ~ N(μ_J, δ_J²)`, compensated
+/// as in [`BatesCf`]. Prices are the Poisson mixture of Black-Scholes
+/// prices (Merton 1976).
+///
+/// # Examples
+/// ```
+/// use pricer_models::analytical::fourier::{CosPricer, FourierMarket, MertonCf};
+///
+/// let merton = MertonCf::new(0.15, 0.5, -0.1, 0.2).unwrap();
+/// let market = FourierMarket::new(100.0, 0.05, 0.0).unwrap();
+/// let call = CosPricer::default().price(&merton, &market, 100.0, 1.0, true).unwrap();
+/// assert!(call > 0.0 && call < 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MertonCf {
+    /// Diffusive volatility `σ`.
+    pub sigma: f64,
+    /// Jump intensity `λ` (per year).
+    pub jump_intensity: f64,
+    /// Mean log jump size `μ_J`.
+    pub jump_mean: f64,
+    /// Log jump size volatility `δ_J`.
+    pub jump_vol: f64,
+}
+
+impl MertonCf {
+    /// Creates a Merton model.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` for a negative
+    /// volatility, intensity or jump volatility, or a non-finite jump mean.
+    pub fn new(
+        sigma: f64,
+        jump_intensity: f64,
+        jump_mean: f64,
+        jump_vol: f64,
+    ) -> Result<Self, AnalyticalError> {
+        check("sigma", sigma, sigma >= 0.0)?;
+        check("jump_intensity", jump_intensity, jump_intensity >= 0.0)?;
+        check("jump_mean", jump_mean, true)?;
+        check("jump_vol", jump_vol, jump_vol >= 0.0)?;
+        Ok(Self {
+            sigma,
+            jump_intensity,
+            jump_mean,
+            jump_vol,
+        })
+    }
+
+    /// Creates the model from Monte Carlo Merton parameters.
+    ///
+    /// # Errors
+    /// As for [`MertonCf::new`].
+    pub fn from_params(params: &MertonParams<f64>) -> Result<Self, AnalyticalError> {
+        Self::new(
+            params.volatility,
+            params.jump_intensity,
+            params.jump_mean,
+            params.jump_vol,
+        )
+    }
+}
+
+impl CharacteristicFunction for MertonCf {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let variance = self.sigma * self.sigma * t;
+        let diffusion = (i * u + u * u) * (-0.5 * variance);
+        let jumps =
+            lognormal_jump_exponent(u, t, self.jump_intensity, self.jump_mean, self.jump_vol);
+        (diffusion + jumps).exp()
+    }
+}
+
+/// Exponent `λt·(E[e^{iu·ln(1+J)}] − 1 − iu·E[J])` of compensated
+/// lognormal jumps.
+fn lognormal_jump_exponent(u: Complex64, t: f64, intensity: f64, mean: f64, vol: f64) -> Complex64 {
+    let i = Complex64::i();
+    let jump_cf = (i * u * mean - u * u * (0.5 * vol * vol)).exp();
+    let compensator = (mean + 0.5 * vol * vol).exp() - 1.0;
+    (jump_cf - 1.0 - i * u * compensator) * (intensity * t)
+}
+
+/// Variance Gamma model (Madan, Carr and Chang 1998).
+///
+/// Brownian motion with drift `θ` and volatility `σ` evaluated at a gamma
+/// time change of unit mean rate and variance rate `ν`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VarianceGammaCf {
+    /// Volatility of the subordinated Brownian motion.
+    pub sigma: f64,
+    /// Variance rate of the gamma time change.
+    pub nu: f64,
+    /// Drift of the subordinated Brownian motion (skew).
+    pub theta: f64,
+}
+
+impl VarianceGammaCf {
+    /// Creates a Variance Gamma model.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` unless `σ > 0`, `ν > 0`
+    /// and `1 − θν − σ²ν/2 > 0` (finite forward).
+    pub fn new(sigma: f64, nu: f64, theta: f64) -> Result<Self, AnalyticalError> {
+        check("sigma", sigma, sigma > 0.0)?;
+        check("nu", nu, nu > 0.0)?;
+        check(
+            "theta",
+            theta,
+            1.0 - theta * nu - 0.5 * sigma * sigma * nu > 0.0,
+        )?;
+        Ok(Self { sigma, nu, theta })
+    }
+}
+
+impl CharacteristicFunction for VarianceGammaCf {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        let i = Complex64::i();
+        let (sigma, nu, theta) = (self.sigma, self.nu, self.theta);
+        let omega = (1.0 - theta * nu - 0.5 * sigma * sigma * nu).ln() / nu;
+        let base = 1.0 - i * u * (theta * nu) + u * u * (0.5 * sigma * sigma * nu);
+        (i * u * (omega * t) - base.ln() * (t / nu)).exp()
+    }
+}
+
+/// CGMY tempered stable model (Carr, Geman, Madan and Yor 2002).
+///
+/// Lévy density `C·e^{−G|x|}/|x|^{1+Y}` for `x < 0` and
+/// `C·e^{−Mx}/x^{1+Y}` for `x > 0`, without a diffusion component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CgmyCf {
+    /// Overall activity `C`.
+    pub c: f64,
+    /// Exponential decay of negative jumps `G`.
+    pub g: f64,
+    /// Exponential decay of positive jumps `M`.
+    pub m: f64,
+    /// Fine structure `Y`.
+    pub y: f64,
+}
+
+impl CgmyCf {
+    /// Creates a CGMY model.
+    ///
+    /// # Errors
+    /// Returns `AnalyticalError::InvalidParameter` unless `C > 0`, `G > 0`,
+    /// `M > 1` (finite forward) and `Y < 2` with `Y ∉ {0, 1}`.
+    pub fn new(c: f64, g: f64, m: f64, y: f64) -> Result<Self, AnalyticalError> {
+        check("c", c, c > 0.0)?;
+        check("g", g, g > 0.0)?;
+        check("m", m, m > 1.0)?;
+        check("y", y, y < 2.0 && y != 0.0 && y != 1.0)?;
+        Ok(Self { c, g, m, y })
+    }
+
+    /// Lévy exponent `ψ(u) = CΓ(−Y)[(M − iu)^Y − M^Y + (G + iu)^Y − G^Y]`.
+    fn exponent(&self, u: Complex64) -> Complex64 {
+        let i = Complex64::i();
+        let (g, m, y) = (self.g, self.m, self.y);
+        let terms = (m - i * u).powf(y) - m.powf(y) + (g + i * u).powf(y) - g.powf(y);
+        terms * (self.c * gamma(-y))
+    }
+}
+
+impl CharacteristicFunction for CgmyCf {
+    fn characteristic_function(&self, u: Complex64, t: f64) -> Complex64 {
+        // Martingale correction ω = −ψ(−i)
+        let omega = -self.exponent(Complex64::new(0.0, -1.0)).re;
+        ((self.exponent(u) + Complex64::i() * u * omega) * t).exp()
+    }
+}
+
+/// Gamma function for real non-integer arguments (Lanczos, g = 7).
+fn gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula
+        return PI / ((PI * x).sin() * gamma(1.0 - x));
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFS[0], |acc, (k, &c)| acc + c / (x + k as f64 + 1.0));
+    (2.0 * PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * series
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn heston() -> HestonCf {
+        HestonCf::new(0.0175, 1.5768, 0.0398, 0.5751, -0.5711).unwrap()
+    }
+
+    #[test]
+    fn test_martingale_and_normalisation() {
+        let models: [&dyn CharacteristicFunction; 5] = [
+            &heston(),
+            &BatesCf::new(heston(), 0.5, -0.1, 0.15).unwrap(),
+            &MertonCf::new(0.2, 0.5, -0.1, 0.15).unwrap(),
+            &VarianceGammaCf::new(0.12, 0.2, -0.14).unwrap(),
+            &CgmyCf::new(1.0, 5.0, 5.0, 0.5).unwrap(),
+        ];
+        for model in models {
+            let at_zero = model.characteristic_function(Complex64::default(), 1.0);
+            let forward = model.characteristic_function(Complex64::new(0.0, -1.0), 1.0);
+            assert_relative_eq!(at_zero.re, 1.0, epsilon = 1e-12);
+            assert_relative_eq!(forward.re, 1.0, epsilon = 1e-10);
+            assert!(forward.im.abs() < 1e-10);
+            // |φ(u)| ≤ 1 on the real axis
+            assert!(
+                model
+                    .characteristic_function(Complex64::from(3.0), 1.0)
+                    .norm()
+                    <= 1.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_cumulants() {
+        // Variance Gamma: c1 = (θ + ω)t, c2 = (σ² + νθ²)t
+        let (sigma, nu, theta) = (0.12, 0.2, -0.14);
+        let vg = VarianceGammaCf::new(sigma, nu, theta).unwrap();
+        let omega = (1.0 - theta * nu - 0.5 * sigma * sigma * nu).ln() / nu;
+        let cumulants = vg.cumulants(2.0);
+        assert_relative_eq!(cumulants.c1, 2.0 * (theta + omega), max_relative = 1e-4);
+        assert_relative_eq!(
+            cumulants.c2,
+            2.0 * (sigma * sigma + nu * theta * theta),
+            max_relative = 1e-3
+        );
+        // c4 = 3(σ⁴ν + 2θ⁴ν³ + 4σ²θ²ν²)t
+        let c4 = 3.0
+            * (sigma.powi(4) * nu
+                + 2.0 * theta.powi(4) * nu.powi(3)
+                + 4.0 * sigma * sigma * theta * theta * nu * nu)
+            * 2.0;
+        assert_relative_eq!(cumulants.c4, c4, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn test_gamma_function() {
+        assert_relative_eq!(gamma(5.0), 24.0, max_relative = 1e-12);
+        assert_relative_eq!(gamma(0.5), PI.sqrt(), max_relative = 1e-12);
+        assert_relative_eq!(gamma(-0.5), -2.0 * PI.sqrt(), max_relative = 1e-12);
+        assert_relative_eq!(gamma(-1.5), 4.0 * PI.sqrt() / 3.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_rough_heston_reduces_to_heston() {
+        let rough = RoughHestonCf::from_heston(heston(), 0.5).unwrap();
+        for u in [
+            Complex64::from(0.5),
+            Complex64::from(3.0),
+            Complex64::new(1.0, -0.5),
+        ] {
+            let expected = heston().characteristic_function(u, 1.0);
+            let phi = rough.characteristic_function(u, 1.0);
+            assert_relative_eq!(phi.re, expected.re, epsilon = 1e-5);
+            assert_relative_eq!(phi.im, expected.im, epsilon = 1e-5);
+        }
+        // Exact martingale: the Riccati source vanishes at u = −i
+        let rough = RoughHestonCf::from_heston(heston(), 0.1).unwrap();
+        let forward = rough.characteristic_function(Complex64::new(0.0, -1.0), 0.5);
+        assert_relative_eq!(forward.re, 1.0, epsilon = 1e-12);
+        assert!(
+            rough
+                .characteristic_function(Complex64::from(3.0), 0.5)
+                .norm()
+                <= 1.0
+        );
+    }
+
+    #[test]
+    fn test_rough_heston_adams_convergence() {
+        let u = Complex64::from(2.0);
+        let rough = RoughHestonCf::from_heston(heston(), 0.1).unwrap();
+        let reference = rough
+            .with_steps(800)
+            .unwrap()
+            .characteristic_function(u, 0.5);
+        let error = |n: usize| {
+            (rough.with_steps(n).unwrap().characteristic_function(u, 0.5) - reference).norm()
+        };
+        assert!(error(200) < error(50));
+        assert!(error(200) < 1e-3);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(RoughHestonCf::from_heston(heston(), 0.0).is_err());
+        assert!(RoughHestonCf::from_heston(heston(), 0.7).is_err());
+        assert!(RoughHestonCf::from_heston(heston(), 0.1)
+            .unwrap()
+            .with_steps(0)
+            .is_err());
+        assert!(HestonCf::new(0.04, 1.0, 0.04, 0.5, -1.5).is_err());
+        assert!(HestonCf::new(-0.01, 1.0, 0.04, 0.5, 0.0).is_err());
+        assert!(VarianceGammaCf::new(0.5, 2.0, 0.5).is_err());
+        assert!(CgmyCf::new(1.0, 5.0, 0.5, 0.5).is_err());
+        assert!(CgmyCf::new(1.0, 5.0, 5.0, 1.0).is_err());
+        assert!(BatesCf::new(heston(), -1.0, 0.0, 0.1).is_err());
+        assert!(MertonCf::new(-0.2, 0.5, 0.0, 0.1).is_err());
+        assert!(MertonCf::new(0.2, 0.5, f64::INFINITY, 0.1).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+//! Minimal complex arithmetic for characteristic functions.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Complex number `re + i·im`.
+///
+/// Only the operations needed to evaluate characteristic functions are
+/// provided; logarithms, square roots and powers use the principal branch.
+///
+/// # Examples
+/// ```
+/// use pricer_models::analytical::fourier::Complex64;
+///
+/// let z = Complex64::new(0.0, std::f64::consts::PI).exp();
+/// assert!((z.re + 1.0).abs() < 1e-15 && z.im.abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex64 {
+    /// Real part.
+    pub re: f64,
+    /// Imaginary part.
+    pub im: f64,
+}
+
+impl Complex64 {
+    /// Creates `re + i·im`.
+    #[inline]
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// The imaginary unit.
+    #[inline]
+    pub const fn i() -> Self {
+        Self { re: 0.0, im: 1.0 }
+    }
+
+    /// Modulus `|z|`.
+    #[inline]
+    pub fn norm(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Argument in `(-π, π]`.
+    #[inline]
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// Complex conjugate.
+    #[inline]
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Exponential `e^z`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        let exp_re = self.re.exp();
+        Self::new(exp_re * self.im.cos(), exp_re * self.im.sin())
+    }
+
+    /// Principal logarithm.
+    #[inline]
+    pub fn ln(self) -> Self {
+        Self::new(self.norm().ln(), self.arg())
+    }
+
+    /// Principal square root.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        let sqrt_r = self.norm().sqrt();
+        let half_arg = 0.5 * self.arg();
+        Self::new(sqrt_r * half_arg.cos(), sqrt_r * half_arg.sin())
+    }
+
+    /// Principal power `z^p` for real `p`.
+    #[inline]
+    pub fn powf(self, p: f64) -> Self {
+        if self.re == 0.0 && self.im == 0.0 {
+            return Self::default();
+        }
+        (self.ln() * p).exp()
+    }
+}
+
+impl From<f64> for Complex64 {
+    #[inline]
+    fn from(re: f64) -> Self {
+        Self::new(re, 0.0)
+    }
+}
+
+impl Add for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denom,
+            (self.im * rhs.re - self.re * rhs.im) / denom,
+        )
+    }
+}
+
+impl Neg for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl Add<f64> for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: f64) -> Self {
+        Self::new(self.re + rhs, self.im)
+    }
+}
+
+impl Sub<f64> for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: f64) -> Self {
+        Self::new(self.re - rhs, self.im)
+    }
+}
+
+impl Mul<f64> for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl Div<f64> for Complex64 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+impl Add<Complex64> for f64 {
+    type Output = Complex64;
+    #[inline]
+    fn add(self, rhs: Complex64) -> Complex64 {
+        rhs + self
+    }
+}
+
+impl Sub<Complex64> for f64 {
+    type Output = Complex64;
+    #[inline]
+    fn sub(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self - rhs.re, -rhs.im)
+    }
+}
+
+impl Mul<Complex64> for f64 {
+    type Output = Complex64;
+    #[inline]
+    fn mul(self, rhs: Complex64) -> Complex64 {
+        rhs * self
+    }
+}
+
+impl Div<Complex64> for f64 {
+    type Output = Complex64;
+    #[inline]
+    fn div(self, rhs: Complex64) -> Complex64 {
+        Complex64::from(self) / rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_complex64_operations() {
+        let a = Complex64::new(1.0, 2.0);
+        let b = Complex64::new(3.0, 4.0);
+
+        let sum = a + b;
+        assert!((sum.re - 4.0).abs() < 1e-10);
+        assert!((sum.im - 6.0).abs() < 1e-10);
+
+        let prod = a * b;
+        // (1+2i)(3+4i) = 3 + 4i + 6i + 8i^2 = 3 + 10i - 8 = -5 + 10i
+        assert!((prod.re - (-5.0)).abs() < 1e-10);
+        assert!((prod.im - 10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_complex64_exp() {
+        let z = Complex64::new(0.0, PI);
+        let exp_z = z.exp();
+        // e^(i*pi) = -1
+        assert!((exp_z.re - (-1.0)).abs() < 1e-10);
+        assert!(exp_z.im.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_complex64_roots_and_powers() {
+        let z = Complex64::new(-4.0, 0.0);
+        let root = z.sqrt();
+        assert!(root.re.abs() < 1e-12 && (root.im - 2.0).abs() < 1e-12);
+        let cube = Complex64::new(1.0, 1.0).powf(3.0);
+        // (1+i)^3 = -2 + 2i
+        assert!((cube.re + 2.0).abs() < 1e-12 && (cube.im - 2.0).abs() < 1e-12);
+        let ratio = 1.0 / Complex64::i();
+        assert!(ratio.re.abs() < 1e-15 && (ratio.im + 1.0).abs() < 1e-15);
+    }
+}
@@ -2,9 +2,13 @@
 //!
 //! This module provides closed-form solutions for option pricing:
 //! - Black-Scholes model for lognormal dynamics
+//! - Black-76 model for options on forwards
 //! - Bachelier model for normal dynamics
 //! - Garman-Kohlhagen model for FX options
+//! - Escrowed-dividend Black-Scholes over an equity market
 //! - Analytical Greeks (Delta, Gamma, Vega, Theta, Rho)
+//! - Fourier pricing (COS, Carr-Madan FFT) for characteristic-function
+//!   models in [`fourier`]
 //!
 //! ## Design Principles
 //!
@@ -14,17 +18,22 @@
 
 pub mod distributions;
 pub mod error;
+pub mod fourier;
 
 mod bachelier;
+mod black76;
 mod black_scholes;
+mod equity;
 
 #[cfg(feature = "fx")]
 pub mod garman_kohlhagen;
 
 // Re-export main types at module level
 pub use bachelier::Bachelier;
+pub use black76::black76_price;
 pub use black_scholes::BlackScholes;
 pub use distributions::{norm_cdf, norm_pdf};
+pub use equity::EquityBlackScholes;
 pub use error::AnalyticalError;
 
 #[cfg(feature = "fx")]
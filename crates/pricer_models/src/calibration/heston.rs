@@ -10,16 +10,19 @@
 //!
 //! The Heston model is calibrated by minimising the sum of squared
 //! differences between model and market option prices (or implied vols).
-//! Uses Levenberg-Marquardt optimisation with Feller condition as
-//! parameter boundary constraint.
+//! Uses Levenberg-Marquardt optimisation within parameter bounds, with
+//! the Feller condition as an optional constraint. The objective is often
+//! multimodal; [`GlobalSearch::with_global_search`] adds a
+//! differential evolution stage before the local optimiser.
 //!
 //! ## Characteristic Function Pricing
 //!
-//! European options are priced using the semi-analytical Fourier
-//! transform method via the Heston characteristic function:
+//! European options are priced with the COS method of
+//! [`analytical::fourier`](crate::analytical::fourier) from the Heston
+//! characteristic function of the log-forward return:
 //!
 //! ```text
-//! φ(u) = exp(C(u) + D(u)*v0 + i*u*ln(S))
+//! φ(u) = exp(C(u) + D(u)*v0)
 //! ```
 //!
 //! where C(u) and D(u) are complex-valued functions of the model
@@ -30,7 +33,12 @@ use pricer_core::traits::calibration::{
 };
 use std::f64::consts::PI;
 
-use super::{ModelCalibrator, ModelCalibratorConfig};
+use super::{GlobalSearch, ModelCalibrator, ModelCalibratorConfig};
+use crate::analytical::distributions::norm_cdf;
+use crate::analytical::fourier::{CharacteristicFunction, CosPricer, FourierMarket, HestonCf};
+
+/// Truncation width of the COS range, in cumulant standard deviations.
+const COS_TRUNCATION: f64 = 12.0;
 
 /// Heston calibration market data point.
 ///
@@ -76,6 +84,24 @@ impl HestonMarketPoint {
         }
     }
 
+    /// Weighted residual of a model price against this point, in implied
+    /// volatility when the point is quoted as a volatility.
+    pub(super) fn residual(&self, spot: f64, rate: f64, model_price: f64) -> f64 {
+        if self.is_vol {
+            let model_vol = HestonCalibrator::implied_vol(
+                spot,
+                self.strike,
+                self.expiry,
+                rate,
+                model_price,
+                self.is_call,
+            );
+            self.weight * (model_vol - self.market_value)
+        } else {
+            self.weight * (model_price - self.market_value)
+        }
+    }
+
     /// Set the weight for this point.
     pub fn with_weight(mut self, weight: f64) -> Self {
         self.weight = weight;
@@ -220,19 +246,19 @@ impl HestonParamIndex {
 /// ## Feller Condition
 ///
 /// The Feller condition (2*kappa*theta > xi^2) is recommended but
-/// not strictly enforced. Violations are penalised in the objective.
+/// not enforced by default. When enabled it is passed to the optimiser as
+/// a constraint: the global stage only prefers Feller-satisfying points,
+/// and the local stage penalises violations.
 #[derive(Debug, Clone)]
 pub struct HestonCalibrator {
     /// Underlying model calibrator.
     calibrator: ModelCalibrator,
     /// Parameter names.
     param_names: Vec<String>,
-    /// Number of integration points for characteristic function.
+    /// Number of COS expansion terms for characteristic function pricing.
     integration_points: usize,
-    /// Enforce Feller condition as hard constraint.
+    /// Enforce Feller condition as a constraint.
     enforce_feller: bool,
-    /// Feller violation penalty factor.
-    feller_penalty: f64,
 }
 
 impl Default for HestonCalibrator {
@@ -241,6 +267,12 @@ impl Default for HestonCalibrator {
     }
 }
 
+impl GlobalSearch for HestonCalibrator {
+    fn model_calibrator_mut(&mut self) -> &mut ModelCalibrator {
+        &mut self.calibrator
+    }
+}
+
 impl HestonCalibrator {
     /// Create a new Heston calibrator with default settings.
     pub fn new() -> Self {
@@ -263,7 +295,6 @@ impl HestonCalibrator {
             ],
             integration_points: 128,
             enforce_feller: false,
-            feller_penalty: 100.0,
         }
     }
 
@@ -280,23 +311,38 @@ impl HestonCalibrator {
             ],
             integration_points: 128,
             enforce_feller: false,
-            feller_penalty: 100.0,
         }
     }
 
-    /// Set number of integration points for FFT pricing.
+    /// Set the number of COS expansion terms used for pricing.
     pub fn with_integration_points(mut self, n: usize) -> Self {
         self.integration_points = n;
         self
     }
 
     /// Enable Feller condition enforcement.
+    ///
+    /// `penalty` weights the violation `xi² - 2·kappa·theta` in the local
+    /// optimiser.
     pub fn with_feller_enforcement(mut self, enforce: bool, penalty: f64) -> Self {
         self.enforce_feller = enforce;
-        self.feller_penalty = penalty;
+        let config = self
+            .calibrator
+            .config()
+            .clone()
+            .with_constraint_penalty(penalty);
+        self.calibrator = ModelCalibrator::new(config);
         self
     }
 
+    /// Feller condition as a calibration constraint.
+    pub fn feller_constraint() -> Constraint {
+        Constraint::Custom {
+            name: "feller".into(),
+            constraint_fn: feller_violation,
+        }
+    }
+
     /// Get parameter names.
     pub fn param_names(&self) -> &[String] {
         &self.param_names
@@ -320,7 +366,7 @@ impl HestonCalibrator {
 
     /// Price a European option using Heston characteristic function.
     ///
-    /// Uses Gauss-Legendre quadrature for numerical integration.
+    /// Uses the COS method with `integration_points` expansion terms.
     #[allow(clippy::too_many_arguments)]
     pub fn price_option(
         &self,
@@ -332,109 +378,16 @@ impl HestonCalibrator {
         params: &[f64],
         is_call: bool,
     ) -> f64 {
-        let v0 = params[HestonParamIndex::V0];
-        let theta = params[HestonParamIndex::THETA];
-        let kappa = params[HestonParamIndex::KAPPA];
-        let xi = params[HestonParamIndex::XI];
-        let rho = params[HestonParamIndex::RHO];
-
-        let forward = spot * ((rate - dividend) * expiry).exp();
-        let discount = (-rate * expiry).exp();
-
-        // Integration via trapezoidal rule
-        let n = self.integration_points;
-        let u_max = 100.0; // Upper limit for integration
-        let du = u_max / n as f64;
-
-        let mut p1 = 0.0;
-        let mut p2 = 0.0;
-
-        for i in 1..n {
-            let u = i as f64 * du;
-
-            // P1: probability with stock price as numeraire (u - i)
-            let u1 = Complex64::new(u, -1.0);
-            let phi1 = self.characteristic_function(u1, expiry, v0, theta, kappa, xi, rho);
-            let exp_term1 = Complex64::new(0.0, -u * strike.ln()).exp();
-            let integrand1 = (phi1 * exp_term1).re / u;
-
-            // P2: probability with money market as numeraire (u + 0i)
-            let u2 = Complex64::new(u, 0.0);
-            let phi2 = self.characteristic_function(u2, expiry, v0, theta, kappa, xi, rho);
-            let exp_term2 = Complex64::new(0.0, -u * strike.ln()).exp();
-            let integrand2 = (phi2 * exp_term2).re / u;
-
-            p1 += integrand1;
-            p2 += integrand2;
-        }
-
-        p1 = 0.5 + p1 * du / PI;
-        p2 = 0.5 + p2 * du / PI;
-
-        // Clamp probabilities to [0, 1]
-        p1 = p1.clamp(0.0, 1.0);
-        p2 = p2.clamp(0.0, 1.0);
-
-        let call_price = forward * discount * p1 - strike * discount * p2;
-
-        if is_call {
-            call_price.max(0.0)
-        } else {
-            // Put-call parity
-            (call_price - forward * discount + strike * discount).max(0.0)
-        }
-    }
-
-    /// Heston characteristic function.
-    ///
-    /// Uses the formulation from Gatheral (2006) for numerical stability.
-    #[allow(clippy::too_many_arguments)]
-    fn characteristic_function(
-        &self,
-        u: Complex64,
-        t: f64,
-        v0: f64,
-        theta: f64,
-        kappa: f64,
-        xi: f64,
-        rho: f64,
-    ) -> Complex64 {
-        let i = Complex64::i();
-        let one = Complex64::new(1.0, 0.0);
-        let two = Complex64::new(2.0, 0.0);
-
-        // Modified parameters for log-stock characteristic function
-        let xi2 = xi * xi;
-        // alpha = -0.5 * u * (u + i)
-        let alpha = (u * (u + i)) * (-0.5);
-        // beta = kappa - rho * xi * i * u
-        let beta = Complex64::new(kappa, 0.0) - (i * u) * (rho * xi);
-
-        let discriminant = beta * beta - two * xi2 * alpha;
-        let d = discriminant.sqrt();
-
-        // Avoid numerical issues with small d
-        let d_safe = if d.norm() < 1e-10 {
-            Complex64::new(1e-10, 0.0)
-        } else {
-            d
-        };
-
-        let g = (beta - d_safe) / (beta + d_safe);
-
-        let exp_dt = (d_safe * (-t)).exp();
-        let one_minus_g_exp = one - g * exp_dt;
-        let one_minus_g = one - g;
-
-        // C and D functions
-        // c = (kappa * theta / xi2) * ((beta - d) * t - 2 * ln((1 - g*exp(-d*t)) / (1 - g)))
-        let ln_term = (one_minus_g_exp / one_minus_g).ln();
-        let c = ((beta - d_safe) * t - two * ln_term) * (kappa * theta / xi2);
-
-        // d_fn = ((beta - d) / xi2) * (1 - exp(-d*t)) / (1 - g*exp(-d*t))
-        let d_fn = ((beta - d_safe) * (1.0 / xi2)) * ((one - exp_dt) / one_minus_g_exp);
-
-        (c + d_fn * v0).exp()
+        heston_price_numerical(
+            spot,
+            strike,
+            expiry,
+            rate,
+            dividend,
+            params,
+            is_call,
+            self.integration_points,
+        )
     }
 
     /// Compute implied volatility from price using Newton-Raphson.
@@ -494,8 +447,6 @@ impl Calibrator for HestonCalibrator {
         let dividend = market_data.dividend;
         let points = market_data.points.clone();
         let n_points = self.integration_points;
-        let enforce_feller = self.enforce_feller;
-        let feller_penalty = self.feller_penalty;
 
         let residuals = move |params: &[f64]| {
             let mut resids: Vec<f64> = Vec::with_capacity(points.len());
@@ -511,37 +462,19 @@ impl Calibrator for HestonCalibrator {
                     point.is_call,
                     n_points,
                 );
-
-                let residual = if point.is_vol {
-                    // Convert model price to implied vol and compare
-                    let model_vol = HestonCalibrator::implied_vol(
-                        spot,
-                        point.strike,
-                        point.expiry,
-                        rate,
-                        model_price,
-                        point.is_call,
-                    );
-                    point.weight * (model_vol - point.market_value)
-                } else {
-                    // Compare prices directly
-                    point.weight * (model_price - point.market_value)
-                };
-
-                resids.push(residual);
-            }
-
-            // Add Feller penalty if enabled
-            if enforce_feller && !HestonCalibrator::satisfies_feller(params) {
-                let violation = 1.0 - HestonCalibrator::feller_ratio(params);
-                resids.push(feller_penalty * violation.max(0.0));
+                resids.push(point.residual(spot, rate, model_price));
             }
 
             resids
         };
 
+        let constraints = if self.enforce_feller {
+            vec![HestonCalibrator::feller_constraint()]
+        } else {
+            Vec::new()
+        };
         self.calibrator
-            .calibrate_with_residuals(residuals, initial_params)
+            .calibrate_with_constraints(residuals, initial_params, &constraints)
     }
 
     fn objective_function(
@@ -566,32 +499,23 @@ impl Calibrator for HestonCalibrator {
                     params,
                     point.is_call,
                 );
-
-                if point.is_vol {
-                    let model_vol = HestonCalibrator::implied_vol(
-                        spot,
-                        point.strike,
-                        point.expiry,
-                        rate,
-                        model_price,
-                        point.is_call,
-                    );
-                    point.weight * (model_vol - point.market_value)
-                } else {
-                    point.weight * (model_price - point.market_value)
-                }
+                point.residual(spot, rate, model_price)
             })
             .collect()
     }
 
     fn constraints(&self) -> Vec<Constraint> {
-        vec![
+        let mut constraints = vec![
             Constraint::positive(HestonParamIndex::V0),    // v0 > 0
             Constraint::positive(HestonParamIndex::THETA), // theta > 0
             Constraint::positive(HestonParamIndex::KAPPA), // kappa > 0
             Constraint::positive(HestonParamIndex::XI),    // xi > 0
             Constraint::bounds(HestonParamIndex::RHO, -0.999, 0.999), // |rho| < 1
-        ]
+        ];
+        if self.enforce_feller {
+            constraints.push(HestonCalibrator::feller_constraint());
+        }
+        constraints
     }
 }
 
@@ -636,157 +560,18 @@ pub fn calibrate_heston(
 // Internal helper types and functions
 // =============================================================================
 
-/// Simple complex number type for characteristic function.
-#[derive(Debug, Clone, Copy)]
-struct Complex64 {
-    re: f64,
-    im: f64,
-}
-
-impl Complex64 {
-    fn new(re: f64, im: f64) -> Self {
-        Self { re, im }
-    }
-
-    fn i() -> Self {
-        Self { re: 0.0, im: 1.0 }
-    }
-
-    fn norm(&self) -> f64 {
-        (self.re * self.re + self.im * self.im).sqrt()
-    }
-
-    fn exp(self) -> Self {
-        let exp_re = self.re.exp();
-        Self {
-            re: exp_re * self.im.cos(),
-            im: exp_re * self.im.sin(),
-        }
-    }
-
-    fn ln(self) -> Self {
-        let r = self.norm();
-        let theta = self.im.atan2(self.re);
-        Self {
-            re: r.ln(),
-            im: theta,
-        }
-    }
-
-    fn sqrt(self) -> Self {
-        let r = self.norm();
-        let theta = self.im.atan2(self.re);
-        let sqrt_r = r.sqrt();
-        Self {
-            re: sqrt_r * (theta / 2.0).cos(),
-            im: sqrt_r * (theta / 2.0).sin(),
-        }
-    }
-}
-
-impl std::ops::Add for Complex64 {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self {
-        Self {
-            re: self.re + rhs.re,
-            im: self.im + rhs.im,
-        }
-    }
-}
-
-impl std::ops::Sub for Complex64 {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self {
-        Self {
-            re: self.re - rhs.re,
-            im: self.im - rhs.im,
-        }
-    }
-}
-
-impl std::ops::Mul for Complex64 {
-    type Output = Self;
-    fn mul(self, rhs: Self) -> Self {
-        Self {
-            re: self.re * rhs.re - self.im * rhs.im,
-            im: self.re * rhs.im + self.im * rhs.re,
-        }
-    }
-}
-
-impl std::ops::Mul<f64> for Complex64 {
-    type Output = Self;
-    fn mul(self, rhs: f64) -> Self {
-        Self {
-            re: self.re * rhs,
-            im: self.im * rhs,
-        }
-    }
-}
-
-impl std::ops::Div for Complex64 {
-    type Output = Self;
-    fn div(self, rhs: Self) -> Self {
-        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
-        Self {
-            re: (self.re * rhs.re + self.im * rhs.im) / denom,
-            im: (self.im * rhs.re - self.re * rhs.im) / denom,
-        }
-    }
-}
-
-impl std::ops::Mul<Complex64> for f64 {
-    type Output = Complex64;
-    fn mul(self, rhs: Complex64) -> Complex64 {
-        Complex64 {
-            re: self * rhs.re,
-            im: self * rhs.im,
-        }
-    }
-}
-
-impl std::ops::Sub<Complex64> for f64 {
-    type Output = Complex64;
-    fn sub(self, rhs: Complex64) -> Complex64 {
-        Complex64 {
-            re: self - rhs.re,
-            im: -rhs.im,
-        }
-    }
-}
-
-impl std::ops::Add<Complex64> for f64 {
-    type Output = Complex64;
-    fn add(self, rhs: Complex64) -> Complex64 {
-        Complex64 {
-            re: self + rhs.re,
-            im: rhs.im,
-        }
-    }
-}
-
-impl std::ops::Div<Complex64> for f64 {
-    type Output = Complex64;
-    fn div(self, rhs: Complex64) -> Complex64 {
-        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
-        Complex64 {
-            re: self * rhs.re / denom,
-            im: -self * rhs.im / denom,
-        }
-    }
-}
-
-impl std::ops::Neg for Complex64 {
-    type Output = Self;
-    fn neg(self) -> Self {
-        Self {
-            re: -self.re,
-            im: -self.im,
-        }
-    }
+/// Feller violation `xi² - 2·kappa·theta` (non-positive when satisfied).
+fn feller_violation(params: &[f64]) -> f64 {
+    let theta = params[HestonParamIndex::THETA];
+    let kappa = params[HestonParamIndex::KAPPA];
+    let xi = params[HestonParamIndex::XI];
+    xi * xi - 2.0 * kappa * theta
 }
 
-/// Heston option price using numerical integration.
+/// Heston option price using the COS method with `n_terms` terms.
+///
+/// Parameters are not validated, so that the optimiser may probe any
+/// point; prices that cannot be computed are NaN.
 #[allow(clippy::too_many_arguments)]
 fn heston_price_numerical(
     spot: f64,
@@ -796,106 +581,42 @@ fn heston_price_numerical(
     dividend: f64,
     params: &[f64],
     is_call: bool,
-    n_points: usize,
+    n_terms: usize,
 ) -> f64 {
-    let v0 = params[HestonParamIndex::V0];
-    let theta = params[HestonParamIndex::THETA];
-    let kappa = params[HestonParamIndex::KAPPA];
-    let xi = params[HestonParamIndex::XI];
-    let rho = params[HestonParamIndex::RHO];
-
-    let forward = spot * ((rate - dividend) * expiry).exp();
-    let discount = (-rate * expiry).exp();
-    let log_strike = strike.ln();
-
-    // Integration via trapezoidal rule
-    let u_max = 100.0;
-    let du = u_max / n_points as f64;
-
-    let mut p1 = 0.0;
-    let mut p2 = 0.0;
-
-    for i in 1..n_points {
-        let u = i as f64 * du;
-
-        // P1 integral (stock price numeraire)
-        let u_shifted = Complex64::new(u, -1.0);
-        let phi1 = heston_cf(u_shifted, expiry, v0, theta, kappa, xi, rho, forward);
-        let exp_term1 = Complex64::new(0.0, -u * log_strike).exp();
-        let integrand1 = (phi1 * exp_term1).re / u;
-
-        // P2 integral (money market numeraire)
-        let u_real = Complex64::new(u, 0.0);
-        let phi2 = heston_cf(u_real, expiry, v0, theta, kappa, xi, rho, forward);
-        let exp_term2 = Complex64::new(0.0, -u * log_strike).exp();
-        let integrand2 = (phi2 * exp_term2).re / u;
-
-        p1 += integrand1;
-        p2 += integrand2;
-    }
-
-    p1 = 0.5 + p1 * du / PI;
-    p2 = 0.5 + p2 * du / PI;
-
-    // Clamp to valid range
-    p1 = p1.clamp(0.0, 1.0);
-    p2 = p2.clamp(0.0, 1.0);
-
-    let call_price = forward * discount * p1 - strike * discount * p2;
-
-    if is_call {
-        call_price.max(0.0)
-    } else {
-        // Put-call parity
-        (call_price - forward * discount + strike * discount).max(0.0)
-    }
+    let model = HestonCf {
+        v0: params[HestonParamIndex::V0],
+        kappa: params[HestonParamIndex::KAPPA],
+        theta: params[HestonParamIndex::THETA],
+        xi: params[HestonParamIndex::XI],
+        rho: params[HestonParamIndex::RHO],
+    };
+    cos_price(
+        &model, spot, strike, expiry, rate, dividend, is_call, n_terms,
+    )
 }
 
-/// Heston characteristic function (Gatheral formulation).
+/// Option price of `model` using the COS method with `n_terms` terms.
+///
+/// Prices that cannot be computed are NaN.
 #[allow(clippy::too_many_arguments)]
-fn heston_cf(
-    u: Complex64,
-    t: f64,
-    v0: f64,
-    theta: f64,
-    kappa: f64,
-    xi: f64,
-    rho: f64,
-    forward: f64,
-) -> Complex64 {
-    let i = Complex64::i();
-    let log_fwd = forward.ln();
-
-    // Characteristic function parameters
-    let xi2 = xi * xi;
-    let u_i = u * i;
-    let alpha = Complex64::new(-0.5, 0.0) * u * (u + i);
-    let beta = Complex64::new(kappa, 0.0) - Complex64::new(rho * xi, 0.0) * u_i;
-
-    let discriminant = beta * beta - Complex64::new(2.0 * xi2, 0.0) * alpha;
-    let d = discriminant.sqrt();
-
-    // Handle near-zero d for numerical stability
-    let d_safe = if d.norm() < 1e-10 {
-        Complex64::new(1e-10, 0.0)
-    } else {
-        d
+pub(super) fn cos_price<M: CharacteristicFunction>(
+    model: &M,
+    spot: f64,
+    strike: f64,
+    expiry: f64,
+    rate: f64,
+    dividend: f64,
+    is_call: bool,
+    n_terms: usize,
+) -> f64 {
+    let market = FourierMarket {
+        spot,
+        rate,
+        dividend_yield: dividend,
     };
-
-    let g = (beta - d_safe) / (beta + d_safe);
-    let exp_dt = (d_safe * Complex64::new(-t, 0.0)).exp();
-
-    let one = Complex64::new(1.0, 0.0);
-    let c_coeff = kappa * theta / xi2;
-
-    let c = Complex64::new(c_coeff, 0.0)
-        * ((beta - d_safe) * t - Complex64::new(2.0, 0.0) * ((one - g * exp_dt) / (one - g)).ln());
-
-    let d_fn = ((beta - d_safe) / Complex64::new(xi2, 0.0)) * ((one - exp_dt) / (one - g * exp_dt));
-
-    // Full characteristic function: exp(i*u*ln(F) + C + D*v0)
-    let log_fwd_term = u_i * Complex64::new(log_fwd, 0.0);
-    (log_fwd_term + c + d_fn * Complex64::new(v0, 0.0)).exp()
+    CosPricer::new(n_terms.max(2), COS_TRUNCATION)
+        .and_then(|cos| cos.price(model, &market, strike, expiry, is_call))
+        .map_or(f64::NAN, |price| price.max(0.0))
 }
 
 /// Black-Scholes price and vega for implied vol calculation.
@@ -913,7 +634,6 @@ fn black_scholes_with_vega(
 
     let discount = (-rate * expiry).exp();
 
-    // Normal CDF approximation
     let n_d1 = norm_cdf(d1);
     let n_d2 = norm_cdf(d2);
     let n_neg_d1 = norm_cdf(-d1);
@@ -932,28 +652,10 @@ fn black_scholes_with_vega(
     (price, vega)
 }
 
-/// Standard normal CDF approximation (Abramowitz and Stegun).
-fn norm_cdf(x: f64) -> f64 {
-    let a1 = 0.254829592;
-    let a2 = -0.284496736;
-    let a3 = 1.421413741;
-    let a4 = -1.453152027;
-    let a5 = 1.061405429;
-    let p = 0.3275911;
-
-    let sign = if x < 0.0 { -1.0 } else { 1.0 };
-    let x_abs = x.abs();
-
-    let t = 1.0 / (1.0 + p * x_abs);
-    let y =
-        1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x_abs * x_abs / 2.0).exp();
-
-    0.5 * (1.0 + sign * y)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pricer_core::math::solvers::DifferentialEvolutionConfig;
 
     #[test]
     fn test_heston_market_point_from_price() {
@@ -1032,33 +734,10 @@ mod tests {
         assert_eq!(constraints.len(), 5);
     }
 
-    #[test]
-    fn test_complex64_operations() {
-        let a = Complex64::new(1.0, 2.0);
-        let b = Complex64::new(3.0, 4.0);
-
-        let sum = a + b;
-        assert!((sum.re - 4.0).abs() < 1e-10);
-        assert!((sum.im - 6.0).abs() < 1e-10);
-
-        let prod = a * b;
-        // (1+2i)(3+4i) = 3 + 4i + 6i + 8i^2 = 3 + 10i - 8 = -5 + 10i
-        assert!((prod.re - (-5.0)).abs() < 1e-10);
-        assert!((prod.im - 10.0).abs() < 1e-10);
-    }
-
-    #[test]
-    fn test_complex64_exp() {
-        let z = Complex64::new(0.0, PI);
-        let exp_z = z.exp();
-        // e^(i*pi) = -1
-        assert!((exp_z.re - (-1.0)).abs() < 1e-10);
-        assert!(exp_z.im.abs() < 1e-10);
-    }
-
     #[test]
     fn test_norm_cdf() {
-        assert!((norm_cdf(0.0) - 0.5).abs() < 1e-6);
+        assert!((norm_cdf(0.0_f64) - 0.5).abs() < 1e-6);
+        assert!((norm_cdf(1.0_f64) - 0.841_344_746).abs() < 1e-6);
         assert!(norm_cdf(-5.0) < 0.001);
         assert!(norm_cdf(5.0) > 0.999);
     }
@@ -1067,8 +746,8 @@ mod tests {
     fn test_black_scholes_with_vega() {
         let (price, vega) = black_scholes_with_vega(100.0, 100.0, 1.0, 0.05, 0.2, true);
 
-        // ATM call should have reasonable price
-        assert!(price > 5.0 && price < 15.0);
+        // ATM call: 10.450584 (Hull)
+        assert!((price - 10.450_584).abs() < 1e-5);
         // Vega should be positive
         assert!(vega > 0.0);
     }
@@ -1103,16 +782,24 @@ mod tests {
         let rate = 0.05;
 
         let call = calibrator.price_option(spot, strike, expiry, rate, 0.0, &params, true);
-        let _put = calibrator.price_option(spot, strike, expiry, rate, 0.0, &params, false);
+        let put = calibrator.price_option(spot, strike, expiry, rate, 0.0, &params, false);
 
         // Verify call price is reasonable (positive, less than spot)
         assert!(call > 0.0, "Call price should be positive: {}", call);
         assert!(call < spot, "Call price should be less than spot: {}", call);
 
-        // Note: The characteristic function integration is a basic implementation.
-        // For production, a more robust FFT-based approach would be used.
-        // The calibration still works because it compares model vs market prices
-        // computed consistently with the same method.
+        let parity = spot - strike * (-rate * expiry).exp();
+        assert!((call - put - parity).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_heston_price_matches_fourier_reference() {
+        // Fang and Oosterlee (2008), Table 4
+        let params = vec![0.0175, 0.0398, 1.5768, 0.5751, -0.5711];
+        let price = HestonCalibrator::new()
+            .with_integration_points(256)
+            .price_option(100.0, 100.0, 1.0, 0.0, 0.0, &params, true);
+        assert!((price - 5.785_155_450).abs() < 1e-6, "price = {price}");
     }
 
     #[test]
@@ -1125,10 +812,14 @@ mod tests {
 
         let mut data = HestonCalibrationData::new(spot, rate);
 
-        // Generate prices for various strikes
-        for strike in [90.0, 95.0, 100.0, 105.0, 110.0] {
-            let price = calibrator.price_option(spot, strike, 1.0, rate, 0.0, &true_params, true);
-            data.add_call(strike, 1.0, price);
+        // Generate prices for various strikes; two expiries separate v0
+        // from theta
+        for expiry in [0.25, 1.0] {
+            for strike in [90.0, 95.0, 100.0, 105.0, 110.0] {
+                let price =
+                    calibrator.price_option(spot, strike, expiry, rate, 0.0, &true_params, true);
+                data.add_call(strike, expiry, price);
+            }
         }
 
         // Perturbed initial guess
@@ -1163,7 +854,59 @@ mod tests {
 
         assert_eq!(calibrator.integration_points, 64);
         assert!(calibrator.enforce_feller);
-        assert_eq!(calibrator.feller_penalty, 50.0);
+        assert_eq!(calibrator.calibrator.config().constraint_penalty, 50.0);
+        assert_eq!(calibrator.constraints().len(), 6);
+    }
+
+    #[test]
+    fn test_feller_constraint() {
+        let constraint = HestonCalibrator::feller_constraint();
+        // 2·1.5·0.04 = 0.12 > 0.09
+        assert!(constraint.is_satisfied(&[0.04, 0.04, 1.5, 0.3, -0.7]));
+        // 2·0.5·0.04 = 0.04 < 0.25
+        let violating = [0.04, 0.04, 0.5, 0.5, -0.7];
+        assert!(!constraint.is_satisfied(&violating));
+        assert!((constraint.violation(&violating) - 0.21).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_calibrate_heston_global_search_with_feller() {
+        let true_params = vec![0.04, 0.04, 1.5, 0.3, -0.7];
+        let local = HestonCalibrator::new()
+            .with_integration_points(128)
+            .with_feller_enforcement(true, 100.0);
+        let global = local
+            .clone()
+            .with_global_search(DifferentialEvolutionConfig::new(60, 11));
+        let spot = 100.0;
+        let rate = 0.05;
+
+        let mut data = HestonCalibrationData::new(spot, rate);
+        for expiry in [0.5, 1.0] {
+            for strike in [90.0, 100.0, 110.0] {
+                let price = heston_price_numerical(
+                    spot,
+                    strike,
+                    expiry,
+                    rate,
+                    0.0,
+                    &true_params,
+                    true,
+                    128,
+                );
+                data.add_call(strike, expiry, price);
+            }
+        }
+
+        // Initial guess far from the truth and violating Feller
+        let initial = vec![0.2, 0.2, 0.2, 1.5, 0.5];
+        let config = CalibrationConfig::default();
+        let local_result = local.calibrate(&data, initial.clone(), &config);
+        let global_result = global.calibrate(&data, initial, &config);
+
+        assert!(global_result.residual_ss < 1e-8);
+        assert!(global_result.residual_ss <= local_result.residual_ss + 1e-12);
+        assert!(HestonCalibrator::feller_constraint().violation(&global_result.params) < 1e-4);
     }
 
     #[test]
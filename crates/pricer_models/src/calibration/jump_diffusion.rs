@@ -0,0 +1,613 @@
+//! Merton and Bates jump diffusion calibration.
+//!
+//! Both models are calibrated to the same vanilla option quotes as the
+//! Heston model ([`HestonCalibrationData`]), by least squares on prices or
+//! implied volatilities. European options are priced with the COS method
+//! from [`MertonCf`] and [`BatesCf`].
+//!
+//! Parameter vectors:
+//!
+//! - Merton: `[sigma, lambda, mu_j, delta_j]` ([`MertonParamIndex`])
+//! - Bates: `[v0, theta, kappa, xi, rho, lambda, mu_j, delta_j]`
+//!   ([`BatesParamIndex`]), whose first five entries follow
+//!   [`HestonParamIndex`]
+//!
+//! Jumps add short-dated skew that a pure diffusion cannot produce, so
+//! quotes across several expiries are needed to separate the jump
+//! parameters from the diffusion.
+
+use pricer_core::traits::calibration::{
+    CalibrationConfig, CalibrationResult, Calibrator, Constraint, ParameterBounds,
+};
+
+use super::heston::{cos_price, HestonCalibrationData, HestonCalibrator, HestonParamIndex};
+use super::{GlobalSearch, ModelCalibrator, ModelCalibratorConfig};
+use crate::analytical::fourier::{BatesCf, HestonCf, MertonCf};
+
+/// Default number of COS expansion terms.
+const DEFAULT_COS_TERMS: usize = 128;
+
+/// Parameter indices for the Merton calibration vector.
+pub struct MertonParamIndex;
+
+impl MertonParamIndex {
+    /// Diffusive volatility index.
+    pub const SIGMA: usize = 0;
+    /// Jump intensity index.
+    pub const LAMBDA: usize = 1;
+    /// Mean log jump size index.
+    pub const JUMP_MEAN: usize = 2;
+    /// Log jump size volatility index.
+    pub const JUMP_VOL: usize = 3;
+    /// Total number of parameters.
+    pub const COUNT: usize = 4;
+}
+
+/// Parameter indices for the Bates calibration vector.
+pub struct BatesParamIndex;
+
+impl BatesParamIndex {
+    /// Initial variance index.
+    pub const V0: usize = HestonParamIndex::V0;
+    /// Long-term variance index.
+    pub const THETA: usize = HestonParamIndex::THETA;
+    /// Mean reversion speed index.
+    pub const KAPPA: usize = HestonParamIndex::KAPPA;
+    /// Vol-of-vol index.
+    pub const XI: usize = HestonParamIndex::XI;
+    /// Correlation index.
+    pub const RHO: usize = HestonParamIndex::RHO;
+    /// Jump intensity index.
+    pub const LAMBDA: usize = 5;
+    /// Mean log jump size index.
+    pub const JUMP_MEAN: usize = 6;
+    /// Log jump size volatility index.
+    pub const JUMP_VOL: usize = 7;
+    /// Total number of parameters.
+    pub const COUNT: usize = 8;
+}
+
+/// Weighted residuals of `price` against every quote in `market_data`.
+fn quote_residuals(
+    market_data: &HestonCalibrationData,
+    price: impl Fn(f64, f64, bool) -> f64,
+) -> Vec<f64> {
+    market_data
+        .points
+        .iter()
+        .map(|point| {
+            let model_price = price(point.strike, point.expiry, point.is_call);
+            point.residual(market_data.spot, market_data.rate, model_price)
+        })
+        .collect()
+}
+
+/// Merton jump diffusion calibrator.
+///
+/// ## Parameter Bounds
+///
+/// - 0 < sigma ≤ 2 (diffusive volatility)
+/// - 0 ≤ lambda ≤ 10 (jumps per year)
+/// - -1 ≤ mu_j ≤ 1 (mean log jump)
+/// - 0 < delta_j ≤ 1 (log jump volatility)
+#[derive(Debug, Clone)]
+pub struct MertonCalibrator {
+    /// Underlying model calibrator.
+    calibrator: ModelCalibrator,
+    /// Number of COS expansion terms for pricing.
+    integration_points: usize,
+}
+
+impl Default for MertonCalibrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GlobalSearch for MertonCalibrator {
+    fn model_calibrator_mut(&mut self) -> &mut ModelCalibrator {
+        &mut self.calibrator
+    }
+}
+
+impl MertonCalibrator {
+    /// Create a new Merton calibrator with default settings.
+    pub fn new() -> Self {
+        let config = ModelCalibratorConfig::default().with_bounds(vec![
+            ParameterBounds::new(1e-4, 2.0), // sigma
+            ParameterBounds::new(0.0, 10.0), // lambda
+            ParameterBounds::new(-1.0, 1.0), // mu_j
+            ParameterBounds::new(1e-4, 1.0), // delta_j
+        ]);
+        Self::with_config(config)
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(config: ModelCalibratorConfig) -> Self {
+        Self {
+            calibrator: ModelCalibrator::new(config),
+            integration_points: DEFAULT_COS_TERMS,
+        }
+    }
+
+    /// Set the number of COS expansion terms used for pricing.
+    pub fn with_integration_points(mut self, n: usize) -> Self {
+        self.integration_points = n;
+        self
+    }
+
+    /// Get parameter names.
+    pub fn param_names(&self) -> &[&'static str] {
+        &["sigma", "lambda", "mu_j", "delta_j"]
+    }
+
+    /// Price a European option from a Merton parameter vector.
+    #[allow(clippy::too_many_arguments)]
+    pub fn price_option(
+        &self,
+        spot: f64,
+        strike: f64,
+        expiry: f64,
+        rate: f64,
+        dividend: f64,
+        params: &[f64],
+        is_call: bool,
+    ) -> f64 {
+        merton_price(
+            spot,
+            strike,
+            expiry,
+            rate,
+            dividend,
+            params,
+            is_call,
+            self.integration_points,
+        )
+    }
+}
+
+impl Calibrator for MertonCalibrator {
+    type MarketData = HestonCalibrationData;
+    type ModelParams = Vec<f64>;
+
+    fn calibrate(
+        &self,
+        market_data: &Self::MarketData,
+        initial_params: Self::ModelParams,
+        _config: &CalibrationConfig,
+    ) -> CalibrationResult<Self::ModelParams> {
+        if let Err(e) = market_data.validate() {
+            return CalibrationResult::not_converged(initial_params, 0, f64::INFINITY, e);
+        }
+        let data = market_data.clone();
+        let n_terms = self.integration_points;
+        let residuals = move |params: &[f64]| {
+            quote_residuals(&data, |strike, expiry, is_call| {
+                merton_price(
+                    data.spot,
+                    strike,
+                    expiry,
+                    data.rate,
+                    data.dividend,
+                    params,
+                    is_call,
+                    n_terms,
+                )
+            })
+        };
+        self.calibrator
+            .calibrate_with_constraints(residuals, initial_params, &[])
+    }
+
+    fn objective_function(
+        &self,
+        params: &Self::ModelParams,
+        market_data: &Self::MarketData,
+    ) -> Vec<f64> {
+        quote_residuals(market_data, |strike, expiry, is_call| {
+            self.price_option(
+                market_data.spot,
+                strike,
+                expiry,
+                market_data.rate,
+                market_data.dividend,
+                params,
+                is_call,
+            )
+        })
+    }
+
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::positive(MertonParamIndex::SIGMA),
+            Constraint::bounds(MertonParamIndex::LAMBDA, 0.0, 10.0),
+            Constraint::bounds(MertonParamIndex::JUMP_MEAN, -1.0, 1.0),
+            Constraint::positive(MertonParamIndex::JUMP_VOL),
+        ]
+    }
+}
+
+/// Bates (Heston with lognormal jumps) calibrator.
+///
+/// ## Parameter Bounds
+///
+/// The Heston bounds of [`HestonCalibrator`], plus
+///
+/// - 0 ≤ lambda ≤ 10 (jumps per year)
+/// - -1 ≤ mu_j ≤ 1 (mean log jump)
+/// - 0 < delta_j ≤ 1 (log jump volatility)
+///
+/// The Feller condition on the variance process can be enforced as for
+/// the Heston calibrator.
+#[derive(Debug, Clone)]
+pub struct BatesCalibrator {
+    /// Underlying model calibrator.
+    calibrator: ModelCalibrator,
+    /// Number of COS expansion terms for pricing.
+    integration_points: usize,
+    /// Enforce Feller condition as a constraint.
+    enforce_feller: bool,
+}
+
+impl Default for BatesCalibrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GlobalSearch for BatesCalibrator {
+    fn model_calibrator_mut(&mut self) -> &mut ModelCalibrator {
+        &mut self.calibrator
+    }
+}
+
+impl BatesCalibrator {
+    /// Create a new Bates calibrator with default settings.
+    pub fn new() -> Self {
+        let config = ModelCalibratorConfig::default().with_bounds(vec![
+            ParameterBounds::new(1e-6, 1.0),     // v0
+            ParameterBounds::new(1e-6, 1.0),     // theta
+            ParameterBounds::new(0.01, 20.0),    // kappa
+            ParameterBounds::new(0.01, 2.0),     // xi
+            ParameterBounds::new(-0.999, 0.999), // rho
+            ParameterBounds::new(0.0, 10.0),     // lambda
+            ParameterBounds::new(-1.0, 1.0),     // mu_j
+            ParameterBounds::new(1e-4, 1.0),     // delta_j
+        ]);
+        Self::with_config(config)
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(config: ModelCalibratorConfig) -> Self {
+        Self {
+            calibrator: ModelCalibrator::new(config),
+            integration_points: DEFAULT_COS_TERMS,
+            enforce_feller: false,
+        }
+    }
+
+    /// Set the number of COS expansion terms used for pricing.
+    pub fn with_integration_points(mut self, n: usize) -> Self {
+        self.integration_points = n;
+        self
+    }
+
+    /// Enable Feller condition enforcement.
+    ///
+    /// `penalty` weights the violation `xi² - 2·kappa·theta` in the local
+    /// optimiser.
+    pub fn with_feller_enforcement(mut self, enforce: bool, penalty: f64) -> Self {
+        self.enforce_feller = enforce;
+        let config = self
+            .calibrator
+            .config()
+            .clone()
+            .with_constraint_penalty(penalty);
+        self.calibrator = ModelCalibrator::new(config);
+        self
+    }
+
+    /// Get parameter names.
+    pub fn param_names(&self) -> &[&'static str] {
+        &[
+            "v0", "theta", "kappa", "xi", "rho", "lambda", "mu_j", "delta_j",
+        ]
+    }
+
+    /// Price a European option from a Bates parameter vector.
+    #[allow(clippy::too_many_arguments)]
+    pub fn price_option(
+        &self,
+        spot: f64,
+        strike: f64,
+        expiry: f64,
+        rate: f64,
+        dividend: f64,
+        params: &[f64],
+        is_call: bool,
+    ) -> f64 {
+        bates_price(
+            spot,
+            strike,
+            expiry,
+            rate,
+            dividend,
+            params,
+            is_call,
+            self.integration_points,
+        )
+    }
+
+    fn feller_constraints(&self) -> Vec<Constraint> {
+        if self.enforce_feller {
+            vec![HestonCalibrator::feller_constraint()]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl Calibrator for BatesCalibrator {
+    type MarketData = HestonCalibrationData;
+    type ModelParams = Vec<f64>;
+
+    fn calibrate(
+        &self,
+        market_data: &Self::MarketData,
+        initial_params: Self::ModelParams,
+        _config: &CalibrationConfig,
+    ) -> CalibrationResult<Self::ModelParams> {
+        if let Err(e) = market_data.validate() {
+            return CalibrationResult::not_converged(initial_params, 0, f64::INFINITY, e);
+        }
+        let data = market_data.clone();
+        let n_terms = self.integration_points;
+        let residuals = move |params: &[f64]| {
+            quote_residuals(&data, |strike, expiry, is_call| {
+                bates_price(
+                    data.spot,
+                    strike,
+                    expiry,
+                    data.rate,
+                    data.dividend,
+                    params,
+                    is_call,
+                    n_terms,
+                )
+            })
+        };
+        self.calibrator.calibrate_with_constraints(
+            residuals,
+            initial_params,
+            &self.feller_constraints(),
+        )
+    }
+
+    fn objective_function(
+        &self,
+        params: &Self::ModelParams,
+        market_data: &Self::MarketData,
+    ) -> Vec<f64> {
+        quote_residuals(market_data, |strike, expiry, is_call| {
+            self.price_option(
+                market_data.spot,
+                strike,
+                expiry,
+                market_data.rate,
+                market_data.dividend,
+                params,
+                is_call,
+            )
+        })
+    }
+
+    fn constraints(&self) -> Vec<Constraint> {
+        let mut constraints = vec![
+            Constraint::positive(BatesParamIndex::V0),
+            Constraint::positive(BatesParamIndex::THETA),
+            Constraint::positive(BatesParamIndex::KAPPA),
+            Constraint::positive(BatesParamIndex::XI),
+            Constraint::bounds(BatesParamIndex::RHO, -0.999, 0.999),
+            Constraint::bounds(BatesParamIndex::LAMBDA, 0.0, 10.0),
+            Constraint::bounds(BatesParamIndex::JUMP_MEAN, -1.0, 1.0),
+            Constraint::positive(BatesParamIndex::JUMP_VOL),
+        ];
+        constraints.extend(self.feller_constraints());
+        constraints
+    }
+}
+
+/// Convenience function to calibrate the Merton model.
+///
+/// # Arguments
+///
+/// * `market_data` - Market option data
+/// * `initial_params` - Initial parameter guess [sigma, lambda, mu_j, delta_j]
+///
+/// # Example
+///
+/// ```
+/// use pricer_models::calibration::{calibrate_merton, HestonCalibrationData};
+///
+/// let mut data = HestonCalibrationData::new(100.0, 0.05);
+/// data.add_call(90.0, 0.5, 13.2);
+/// data.add_call(100.0, 0.5, 6.3);
+/// data.add_call(110.0, 0.5, 2.1);
+///
+/// let result = calibrate_merton(&data, vec![0.2, 0.5, -0.1, 0.1]);
+/// assert_eq!(result.params.len(), 4);
+/// ```
+pub fn calibrate_merton(
+    market_data: &HestonCalibrationData,
+    initial_params: Vec<f64>,
+) -> CalibrationResult<Vec<f64>> {
+    MertonCalibrator::new().calibrate(market_data, initial_params, &CalibrationConfig::default())
+}
+
+/// Convenience function to calibrate the Bates model.
+///
+/// # Arguments
+///
+/// * `market_data` - Market option data
+/// * `initial_params` - Initial parameter guess
+///   [v0, theta, kappa, xi, rho, lambda, mu_j, delta_j]
+pub fn calibrate_bates(
+    market_data: &HestonCalibrationData,
+    initial_params: Vec<f64>,
+) -> CalibrationResult<Vec<f64>> {
+    BatesCalibrator::new().calibrate(market_data, initial_params, &CalibrationConfig::default())
+}
+
+// =============================================================================
+// Internal helper functions
+// =============================================================================
+
+/// Merton option price; parameters are not validated.
+#[allow(clippy::too_many_arguments)]
+fn merton_price(
+    spot: f64,
+    strike: f64,
+    expiry: f64,
+    rate: f64,
+    dividend: f64,
+    params: &[f64],
+    is_call: bool,
+    n_terms: usize,
+) -> f64 {
+    let model = MertonCf {
+        sigma: params[MertonParamIndex::SIGMA],
+        jump_intensity: params[MertonParamIndex::LAMBDA],
+        jump_mean: params[MertonParamIndex::JUMP_MEAN],
+        jump_vol: params[MertonParamIndex::JUMP_VOL],
+    };
+    cos_price(
+        &model, spot, strike, expiry, rate, dividend, is_call, n_terms,
+    )
+}
+
+/// Bates option price; parameters are not validated.
+#[allow(clippy::too_many_arguments)]
+fn bates_price(
+    spot: f64,
+    strike: f64,
+    expiry: f64,
+    rate: f64,
+    dividend: f64,
+    params: &[f64],
+    is_call: bool,
+    n_terms: usize,
+) -> f64 {
+    let model = BatesCf {
+        heston: HestonCf {
+            v0: params[BatesParamIndex::V0],
+            kappa: params[BatesParamIndex::KAPPA],
+            theta: params[BatesParamIndex::THETA],
+            xi: params[BatesParamIndex::XI],
+            rho: params[BatesParamIndex::RHO],
+        },
+        jump_intensity: params[BatesParamIndex::LAMBDA],
+        jump_mean: params[BatesParamIndex::JUMP_MEAN],
+        jump_vol: params[BatesParamIndex::JUMP_VOL],
+    };
+    cos_price(
+        &model, spot, strike, expiry, rate, dividend, is_call, n_terms,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::HestonMarketPoint;
+
+    fn synthetic_data(price: impl Fn(f64, f64) -> f64) -> HestonCalibrationData {
+        let mut data = HestonCalibrationData::new(100.0, 0.03);
+        for expiry in [0.1, 0.5, 1.5] {
+            for strike in [80.0, 90.0, 100.0, 110.0, 120.0] {
+                data.add_call(strike, expiry, price(strike, expiry));
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_bates_without_jumps_matches_heston() {
+        let heston = [0.04, 0.05, 1.5, 0.5, -0.6];
+        let mut bates = heston.to_vec();
+        bates.extend([0.0, -0.1, 0.2]);
+        let price = BatesCalibrator::new().price_option(100.0, 95.0, 1.0, 0.03, 0.01, &bates, true);
+        let reference =
+            HestonCalibrator::new().price_option(100.0, 95.0, 1.0, 0.03, 0.01, &heston, true);
+        assert!((price - reference).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_calibrate_merton_synthetic() {
+        let true_params = [0.15, 0.8, -0.12, 0.15];
+        let calibrator = MertonCalibrator::new();
+        let data = synthetic_data(|strike, expiry| {
+            calibrator.price_option(100.0, strike, expiry, 0.03, 0.0, &true_params, true)
+        });
+
+        let result = calibrate_merton(&data, vec![0.2, 0.4, -0.05, 0.1]);
+        assert!(result.converged);
+        for (fitted, expected) in result.params.iter().zip(&true_params) {
+            assert!(
+                (fitted - expected).abs() < 1e-3,
+                "{:?} vs {:?}",
+                result.params,
+                true_params
+            );
+        }
+    }
+
+    #[test]
+    fn test_calibrate_bates_synthetic() {
+        let true_params = [0.03, 0.04, 2.0, 0.4, -0.6, 0.5, -0.15, 0.1];
+        let calibrator = BatesCalibrator::new();
+        let data = synthetic_data(|strike, expiry| {
+            calibrator.price_option(100.0, strike, expiry, 0.03, 0.0, &true_params, true)
+        });
+
+        let initial = vec![0.04, 0.04, 1.5, 0.5, -0.5, 0.3, -0.1, 0.15];
+        let result = calibrate_bates(&data, initial.clone());
+        let initial_error: f64 = calibrator
+            .objective_function(&initial, &data)
+            .iter()
+            .map(|r| r * r)
+            .sum();
+        let fitted_error: f64 = calibrator
+            .objective_function(&result.params, &data)
+            .iter()
+            .map(|r| r * r)
+            .sum();
+        assert!(
+            fitted_error < 1e-4 * initial_error,
+            "{} vs {}",
+            fitted_error,
+            initial_error
+        );
+        assert!((result.params[BatesParamIndex::V0] - 0.03).abs() < 5e-3);
+    }
+
+    #[test]
+    fn test_vol_quotes_and_invalid_data() {
+        let mut data = HestonCalibrationData::new(100.0, 0.03);
+        data.add_point(HestonMarketPoint::from_implied_vol(100.0, 0.5, 0.2, true));
+        let residuals =
+            MertonCalibrator::new().objective_function(&vec![0.2, 0.0, 0.0, 0.1], &data);
+        // No jumps: the implied volatility is sigma
+        assert!(residuals[0].abs() < 1e-4, "{:?}", residuals);
+
+        let empty = HestonCalibrationData::new(100.0, 0.03);
+        assert!(!calibrate_merton(&empty, vec![0.2, 0.5, -0.1, 0.1]).converged);
+        assert!(!calibrate_bates(&empty, vec![0.04; 8]).converged);
+        assert_eq!(
+            BatesCalibrator::new().constraints().len(),
+            BatesParamIndex::COUNT
+        );
+        assert_eq!(
+            MertonCalibrator::new().param_names().len(),
+            MertonParamIndex::COUNT
+        );
+    }
+}
@@ -0,0 +1,483 @@
+//! LIBOR market model calibration.
+//!
+//! This module calibrates the Rebonato volatility `(a, b, c, d)` and
+//! correlation `(β, ρ∞)` of a [`LmmParams`] to at-the-money caplet and
+//! swaption volatilities.
+//!
+//! ## Calibration Approach
+//!
+//! Caplet volatilities are the root-mean-square forward volatilities and
+//! swaption volatilities use Rebonato's frozen-weights formula, so every
+//! residual is closed form. The joint fit leaves the per-forward scalings
+//! `k_i` of the base parameters unchanged; afterwards
+//! [`LmmCalibrator::fit_caplets_exactly`] can set `k_i` so that every
+//! quoted caplet is repriced exactly, with the swaption fit only mildly
+//! disturbed when the parametric form is adequate.
+//!
+//! Quotes are displaced Black volatilities in the displacement of the base
+//! parameters.
+
+use pricer_core::traits::calibration::{
+    CalibrationConfig, CalibrationResult, Calibrator, Constraint, ParameterBounds,
+};
+
+use super::{ModelCalibrator, ModelCalibratorConfig};
+use crate::models::rates::lmm::{LmmError, LmmParams, RebonatoCorrelation, RebonatoVolatility};
+
+/// Residual assigned to every quote when a trial point is not a valid
+/// parameter set.
+const INVALID_RESIDUAL: f64 = 1.0;
+
+/// At-the-money caplet quote on forward `index`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LmmCapletQuote {
+    /// Forward index `i` (caplet on `[T_i, T_{i+1}]`).
+    pub index: usize,
+    /// Market displaced Black volatility.
+    pub vol: f64,
+    /// Weight for this quote in calibration.
+    pub weight: f64,
+}
+
+/// At-the-money swaption quote on the swap from `T_start` to `T_end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LmmSwaptionQuote {
+    /// Index of the first reset date (option expiry `T_start`).
+    pub start: usize,
+    /// Index of the final payment date.
+    pub end: usize,
+    /// Market displaced Black volatility.
+    pub vol: f64,
+    /// Weight for this quote in calibration.
+    pub weight: f64,
+}
+
+/// LIBOR market model calibration data.
+#[derive(Debug, Clone, Default)]
+pub struct LmmCalibrationData {
+    /// Caplet quotes.
+    pub caplets: Vec<LmmCapletQuote>,
+    /// Swaption quotes.
+    pub swaptions: Vec<LmmSwaptionQuote>,
+}
+
+impl LmmCalibrationData {
+    /// Create empty calibration data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an at-the-money caplet volatility.
+    pub fn add_caplet(&mut self, index: usize, vol: f64) {
+        self.caplets.push(LmmCapletQuote {
+            index,
+            vol,
+            weight: 1.0,
+        });
+    }
+
+    /// Add an at-the-money swaption volatility.
+    pub fn add_swaption(&mut self, start: usize, end: usize, vol: f64) {
+        self.swaptions.push(LmmSwaptionQuote {
+            start,
+            end,
+            vol,
+            weight: 1.0,
+        });
+    }
+
+    /// Number of quotes.
+    pub fn len(&self) -> usize {
+        self.caplets.len() + self.swaptions.len()
+    }
+
+    /// Check if empty.
+    pub fn is_empty(&self) -> bool {
+        self.caplets.is_empty() && self.swaptions.is_empty()
+    }
+
+    /// Validate the data against a tenor structure of `n_forwards`.
+    pub fn validate(&self, n_forwards: usize) -> Result<(), String> {
+        if self.is_empty() {
+            return Err("No caplet or swaption data provided".to_string());
+        }
+        for (i, caplet) in self.caplets.iter().enumerate() {
+            if caplet.index >= n_forwards {
+                return Err(format!("Caplet {}: index out of range", i));
+            }
+            if caplet.vol <= 0.0 {
+                return Err(format!("Caplet {}: volatility must be positive", i));
+            }
+        }
+        for (i, swaption) in self.swaptions.iter().enumerate() {
+            if swaption.start >= swaption.end || swaption.end > n_forwards {
+                return Err(format!("Swaption {}: invalid start/end indices", i));
+            }
+            if swaption.vol <= 0.0 {
+                return Err(format!("Swaption {}: volatility must be positive", i));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// LIBOR market model parameter indices.
+///
+/// - `params[0..4]` = a, b, c, d (Rebonato volatility)
+/// - `params[4]` = β (correlation decay)
+/// - `params[5]` = ρ∞ (long correlation)
+#[derive(Debug, Clone, Copy)]
+pub struct LmmParamIndex;
+
+impl LmmParamIndex {
+    /// Short-end level index.
+    pub const A: usize = 0;
+    /// Hump slope index.
+    pub const B: usize = 1;
+    /// Hump decay index.
+    pub const C: usize = 2;
+    /// Long-end level index.
+    pub const D: usize = 3;
+    /// Correlation decay index.
+    pub const CORRELATION_DECAY: usize = 4;
+    /// Long correlation index.
+    pub const LONG_CORRELATION: usize = 5;
+    /// Number of parameters.
+    pub const COUNT: usize = 6;
+}
+
+/// LIBOR market model calibrator.
+///
+/// Calibrates the Rebonato volatility and correlation of a base
+/// [`LmmParams`] (tenor, forwards, displacement and scalings are kept) to
+/// caplet and swaption volatilities using Levenberg-Marquardt
+/// optimisation.
+///
+/// ## Parameter Bounds
+///
+/// - a ∈ [-0.5, 1], b ∈ [-1, 2], c ∈ [0.01, 5], d ∈ [0.001, 1]
+/// - β ∈ [0.001, 3], ρ∞ ∈ [0, 0.99]
+#[derive(Debug, Clone)]
+pub struct LmmCalibrator {
+    /// Parameters supplying everything but volatility and correlation.
+    base: LmmParams,
+    /// Underlying model calibrator.
+    calibrator: ModelCalibrator,
+}
+
+impl LmmCalibrator {
+    /// Create a new LMM calibrator around `base`.
+    pub fn new(base: LmmParams) -> Self {
+        let config = ModelCalibratorConfig::default().with_bounds(vec![
+            ParameterBounds::new(-0.5, 1.0),  // a
+            ParameterBounds::new(-1.0, 2.0),  // b
+            ParameterBounds::new(0.01, 5.0),  // c
+            ParameterBounds::new(0.001, 1.0), // d
+            ParameterBounds::new(0.001, 3.0), // beta
+            ParameterBounds::new(0.0, 0.99),  // long correlation
+        ]);
+        Self::with_config(base, config)
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(base: LmmParams, config: ModelCalibratorConfig) -> Self {
+        Self {
+            base,
+            calibrator: ModelCalibrator::new(config),
+        }
+    }
+
+    /// Base parameters.
+    pub fn base(&self) -> &LmmParams {
+        &self.base
+    }
+
+    /// Base parameters with the volatility and correlation in `params`.
+    pub fn build_params(&self, params: &[f64]) -> Result<LmmParams, LmmError> {
+        build_params(&self.base, params)
+    }
+
+    /// Set the scalings `k_i` of the quoted forwards so that every caplet
+    /// quote in `market_data` is repriced exactly.
+    ///
+    /// Unquoted forwards keep their scaling. With several quotes on one
+    /// forward, the last one wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError` if a quote is outside the tenor structure.
+    pub fn fit_caplets_exactly(
+        params: LmmParams,
+        market_data: &LmmCalibrationData,
+    ) -> Result<LmmParams, LmmError> {
+        let mut scaling = params.scaling().to_vec();
+        for caplet in &market_data.caplets {
+            let atm = *params.forwards().get(caplet.index).ok_or_else(|| {
+                LmmError::InvalidIndex(format!("caplet on forward {}", caplet.index))
+            })?;
+            let model = params.caplet_volatility(caplet.index, atm)?;
+            // Without the SABR layer the ATM volatility is linear in k_i
+            scaling[caplet.index] *= caplet.vol / model;
+        }
+        params.with_scaling(scaling)
+    }
+}
+
+/// Base parameters with the volatility and correlation in `params`.
+fn build_params(base: &LmmParams, params: &[f64]) -> Result<LmmParams, LmmError> {
+    let volatility = RebonatoVolatility::new(
+        params[LmmParamIndex::A],
+        params[LmmParamIndex::B],
+        params[LmmParamIndex::C],
+        params[LmmParamIndex::D],
+    )?;
+    let correlation = RebonatoCorrelation::new(
+        params[LmmParamIndex::CORRELATION_DECAY],
+        params[LmmParamIndex::LONG_CORRELATION],
+    )?;
+    Ok(base
+        .clone()
+        .with_volatility(volatility)
+        .with_correlation(correlation))
+}
+
+/// Weighted volatility residuals, caplets first.
+fn residuals(base: &LmmParams, market_data: &LmmCalibrationData, params: &[f64]) -> Vec<f64> {
+    let Ok(model) = build_params(base, params) else {
+        return vec![INVALID_RESIDUAL; market_data.len()];
+    };
+    let caplets = market_data.caplets.iter().map(|caplet| {
+        let atm = model.forwards()[caplet.index];
+        model
+            .caplet_volatility(caplet.index, atm)
+            .map_or(INVALID_RESIDUAL, |vol| caplet.weight * (vol - caplet.vol))
+    });
+    let swaptions = market_data.swaptions.iter().map(|swaption| {
+        model
+            .swap_rate(swaption.start, swaption.end)
+            .and_then(|atm| model.swaption_volatility(swaption.start, swaption.end, atm))
+            .map_or(INVALID_RESIDUAL, |vol| {
+                swaption.weight * (vol - swaption.vol)
+            })
+    });
+    caplets.chain(swaptions).collect()
+}
+
+impl Calibrator for LmmCalibrator {
+    type MarketData = LmmCalibrationData;
+    type ModelParams = Vec<f64>;
+
+    fn calibrate(
+        &self,
+        market_data: &Self::MarketData,
+        initial_params: Self::ModelParams,
+        _config: &CalibrationConfig,
+    ) -> CalibrationResult<Self::ModelParams> {
+        if let Err(e) = market_data.validate(self.base.n_forwards()) {
+            return CalibrationResult::not_converged(initial_params, 0, f64::INFINITY, e);
+        }
+
+        let base = self.base.clone();
+        let market_data = market_data.clone();
+        self.calibrator.calibrate_with_residuals(
+            move |params: &[f64]| residuals(&base, &market_data, params),
+            initial_params,
+        )
+    }
+
+    fn objective_function(
+        &self,
+        params: &Self::ModelParams,
+        market_data: &Self::MarketData,
+    ) -> Vec<f64> {
+        residuals(&self.base, market_data, params)
+    }
+
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::non_negative(LmmParamIndex::C),
+            Constraint::non_negative(LmmParamIndex::D),
+            Constraint::positive(LmmParamIndex::CORRELATION_DECAY),
+            Constraint::bounds(LmmParamIndex::LONG_CORRELATION, 0.0, 0.99),
+        ]
+    }
+}
+
+/// Convenience function to calibrate the LIBOR market model.
+///
+/// # Arguments
+///
+/// * `base` - Tenor structure, forwards and displacement to calibrate on
+/// * `market_data` - Caplet and swaption volatilities
+/// * `initial_params` - Initial guess [a, b, c, d, β, ρ∞]
+///
+/// # Returns
+///
+/// Calibration result with optimised parameters; turn them into model
+/// parameters with [`LmmCalibrator::build_params`].
+///
+/// # Example
+///
+/// ```
+/// use pricer_models::calibration::lmm::{calibrate_lmm, LmmCalibrationData};
+/// use pricer_models::models::rates::lmm::{LmmParams, RebonatoCorrelation, RebonatoVolatility};
+/// use pricer_core::market_data::curves::CurveEnum;
+///
+/// let base = LmmParams::from_curve(
+///     (1..=11).map(f64::from).collect(),
+///     &CurveEnum::flat(0.03),
+///     0.0,
+///     RebonatoVolatility::flat(0.2).unwrap(),
+///     RebonatoCorrelation::new(0.1, 0.5).unwrap(),
+/// )
+/// .unwrap();
+///
+/// let mut data = LmmCalibrationData::new();
+/// data.add_caplet(1, 0.24);
+/// data.add_caplet(4, 0.21);
+/// data.add_swaption(1, 6, 0.19);
+/// data.add_swaption(4, 10, 0.17);
+///
+/// let initial = vec![0.05, 0.1, 0.8, 0.15, 0.1, 0.4];
+/// let result = calibrate_lmm(base, &data, initial);
+///
+/// if result.converged {
+///     println!("Calibrated long-end volatility: {}", result.params[3]);
+/// }
+/// ```
+pub fn calibrate_lmm(
+    base: LmmParams,
+    market_data: &LmmCalibrationData,
+    initial_params: Vec<f64>,
+) -> CalibrationResult<Vec<f64>> {
+    let calibrator = LmmCalibrator::new(base);
+    calibrator.calibrate(market_data, initial_params, &CalibrationConfig::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pricer_core::market_data::curves::{CurveEnum, CurveInterpolation, InterpolatedCurve};
+
+    /// Annual forwards fixing at 1y … 10y on an upward-sloping curve.
+    fn base() -> LmmParams {
+        let times = [1.0, 2.0, 5.0, 10.0, 12.0];
+        let rates = [0.02, 0.024, 0.03, 0.034, 0.035];
+        let curve = CurveEnum::Interpolated(
+            InterpolatedCurve::new(&times, &rates, CurveInterpolation::Linear, true).unwrap(),
+        );
+        LmmParams::from_curve(
+            (1..=11).map(f64::from).collect(),
+            &curve,
+            0.005,
+            RebonatoVolatility::flat(0.2).unwrap(),
+            RebonatoCorrelation::new(0.1, 0.5).unwrap(),
+        )
+        .unwrap()
+    }
+
+    /// Quotes generated by the model at `truth`.
+    fn synthetic_data(truth: &[f64]) -> LmmCalibrationData {
+        let model = build_params(&base(), truth).unwrap();
+        let mut data = LmmCalibrationData::new();
+        for i in 0..10 {
+            let atm = model.forwards()[i];
+            data.add_caplet(i, model.caplet_volatility(i, atm).unwrap());
+        }
+        for (start, end) in [(1, 3), (1, 6), (2, 7), (3, 10), (5, 10), (2, 10), (7, 10)] {
+            let atm = model.swap_rate(start, end).unwrap();
+            let vol = model.swaption_volatility(start, end, atm).unwrap();
+            data.add_swaption(start, end, vol);
+        }
+        data
+    }
+
+    #[test]
+    fn test_lmm_calibration_data_validation() {
+        let mut data = LmmCalibrationData::new();
+        assert!(data.is_empty());
+        assert!(data.validate(10).is_err());
+
+        data.add_caplet(2, 0.2);
+        data.add_swaption(1, 5, 0.18);
+        assert_eq!(data.len(), 2);
+        assert!(data.validate(10).is_ok());
+        assert!(data.validate(2).is_err());
+
+        let mut bad = LmmCalibrationData::new();
+        bad.add_swaption(5, 5, 0.18);
+        assert!(bad.validate(10).is_err());
+    }
+
+    #[test]
+    fn test_lmm_objective_at_truth() {
+        let truth = [0.05, 0.3, 1.2, 0.12, 0.15, 0.35];
+        let data = synthetic_data(&truth);
+        let calibrator = LmmCalibrator::new(base());
+        let residuals = calibrator.objective_function(&truth.to_vec(), &data);
+        assert_eq!(residuals.len(), data.len());
+        assert!(residuals.iter().all(|r| r.abs() < 1e-14));
+
+        // Invalid trial points are penalised rather than rejected
+        let invalid = vec![-0.5, 0.3, 1.2, 0.1, 0.15, 0.35];
+        assert!(calibrator
+            .objective_function(&invalid, &data)
+            .iter()
+            .all(|r| *r == INVALID_RESIDUAL));
+    }
+
+    #[test]
+    fn test_calibrate_lmm_round_trip() {
+        let truth = [0.05, 0.3, 1.2, 0.12, 0.15, 0.35];
+        let data = synthetic_data(&truth);
+        let initial = vec![0.1, 0.1, 0.8, 0.15, 0.1, 0.5];
+
+        let result = calibrate_lmm(base(), &data, initial);
+        assert!(result.converged, "Calibration did not converge");
+        assert!(
+            result.residual_ss < 1e-10,
+            "residual {}",
+            result.residual_ss
+        );
+
+        let calibrated = build_params(&base(), &result.params).unwrap();
+        let expected = build_params(&base(), &truth).unwrap();
+        for i in 0..10 {
+            let atm = expected.forwards()[i];
+            let vol = calibrated.caplet_volatility(i, atm).unwrap();
+            let target = expected.caplet_volatility(i, atm).unwrap();
+            assert!((vol - target).abs() < 1e-5);
+        }
+        assert!(
+            (result.params[LmmParamIndex::LONG_CORRELATION] - truth[5]).abs() < 0.05,
+            "long correlation {}",
+            result.params[LmmParamIndex::LONG_CORRELATION]
+        );
+    }
+
+    #[test]
+    fn test_fit_caplets_exactly() {
+        let truth = [0.05, 0.3, 1.2, 0.12, 0.15, 0.35];
+        let mut data = synthetic_data(&truth);
+        // Bump one caplet quote off the parametric form
+        data.caplets[3].vol += 0.01;
+
+        let params = build_params(&base(), &truth).unwrap();
+        let fitted = LmmCalibrator::fit_caplets_exactly(params, &data).unwrap();
+        for caplet in &data.caplets {
+            let atm = fitted.forwards()[caplet.index];
+            let vol = fitted.caplet_volatility(caplet.index, atm).unwrap();
+            assert!((vol - caplet.vol).abs() < 1e-12);
+        }
+        assert!(fitted.scaling()[3] > 1.0);
+        assert!((fitted.scaling()[4] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lmm_calibrator_constraints() {
+        let calibrator = LmmCalibrator::new(base());
+        assert_eq!(calibrator.constraints().len(), 4);
+        assert_eq!(LmmParamIndex::COUNT, 6);
+        assert_eq!(calibrator.base().n_forwards(), 10);
+    }
+}
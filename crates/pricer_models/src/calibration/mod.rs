@@ -1,11 +1,20 @@
 //! Model calibration module.
 //!
 //! This module provides calibration infrastructure for financial models:
-//! - [`ModelCalibrator`]: Generic calibrator using Levenberg-Marquardt
+//! - [`ModelCalibrator`]: Generic calibrator using Levenberg-Marquardt or
+//!   L-BFGS-B, with an optional differential evolution global stage
 //! - [`HestonCalibrator`]: Heston stochastic volatility model calibration
-//! - [`SABRCalibrator`]: SABR stochastic volatility model calibration
+//! - [`MertonCalibrator`] / [`BatesCalibrator`]: Jump diffusion calibration
+//!   to the same quotes as the Heston model
+//! - [`RoughHestonCalibrator`] / [`RoughBergomiCalibrator`]: Rough
+//!   volatility calibration to the same quotes, by Fourier and Monte Carlo
+//! - [`SABRCalibrator`]: SABR stochastic volatility model calibration (Hagan
+//!   or arbitrage-free PDE, optionally shifted)
 //! - [`HullWhiteCalibrator`]: Hull-White short rate model calibration
+//! - `LmmCalibrator`: LIBOR market model calibration to caplets and
+//!   swaptions (with the `rates` feature)
 //! - [`SwaptionCalibrator`]: Swaption volatility surface calibration
+//!   (Hull-White, SABR, or G2++ with the `rates` feature)
 //! - [`CalibrationError`]: Comprehensive error types for calibration
 //! - [`CalibrationResult`]: Generic calibration result with diagnostics
 //! - [`CalibrationTarget`]: Target types for calibration (options, swaptions)
@@ -41,8 +50,12 @@
 mod error;
 pub mod heston;
 pub mod hull_white;
+pub mod jump_diffusion;
+#[cfg(feature = "rates")]
+pub mod lmm;
 mod model_calibrator;
 mod result;
+pub mod rough;
 pub mod sabr;
 mod swaption_calibrator;
 mod targets;
@@ -55,13 +68,26 @@ pub use hull_white::{
     calibrate_hull_white, HWParamIndex, HWSwaptionPoint, HullWhiteCalibrationData,
     HullWhiteCalibrator,
 };
-pub use model_calibrator::{ModelCalibrator, ModelCalibratorConfig};
+pub use jump_diffusion::{
+    calibrate_bates, calibrate_merton, BatesCalibrator, BatesParamIndex, MertonCalibrator,
+    MertonParamIndex,
+};
+#[cfg(feature = "rates")]
+pub use lmm::{
+    calibrate_lmm, LmmCalibrationData, LmmCalibrator, LmmCapletQuote, LmmParamIndex,
+    LmmSwaptionQuote,
+};
+pub use model_calibrator::{GlobalSearch, LocalOptimiser, ModelCalibrator, ModelCalibratorConfig};
 pub use result::{CalibrationDiagnostics, CalibrationResult};
+pub use rough::{
+    calibrate_rough_bergomi, calibrate_rough_heston, RoughBergomiCalibrator,
+    RoughBergomiParamIndex, RoughHestonCalibrator, RoughHestonParamIndex,
+};
 pub use sabr::{
     calibrate_sabr, calibrate_sabr_fixed_beta, SABRCalibrationData, SABRCalibrator, SABRParamIndex,
-    SABRSmilePoint,
+    SABRSmilePoint, SABRVolMethod,
 };
 pub use swaption_calibrator::{
-    SwaptionCalibrator, SwaptionMarketData, SwaptionMarketPoint, VolatilityType,
+    SwaptionCalibrator, SwaptionMarketData, SwaptionMarketPoint, SwaptionModelType, VolatilityType,
 };
 pub use targets::{CalibrationTarget, OptionTarget, SwaptionTarget};
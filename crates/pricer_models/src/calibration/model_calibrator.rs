@@ -2,13 +2,33 @@
 //!
 //! This module provides a generic calibrator that wraps the LM solver
 //! and integrates with the Calibrator trait from pricer_core.
-
-use pricer_core::math::solvers::{LMConfig, LMResult, LevenbergMarquardtSolver};
+//!
+//! The local stage can alternatively use the bound-constrained
+//! [`LbfgsbSolver`], and an optional [`DifferentialEvolution`] global stage
+//! can run first to escape local minima. Box constraints become bounds;
+//! other [`Constraint`]s are handled by feasibility rules in the global
+//! stage and by a weighted penalty in the local stage.
+
+use pricer_core::math::solvers::{
+    bounds_from_constraints, DifferentialEvolution, DifferentialEvolutionConfig, LMConfig,
+    LMResult, LbfgsbConfig, LbfgsbSolver, LevenbergMarquardtSolver,
+};
 use pricer_core::traits::calibration::{
     CalibrationConfig, CalibrationResult, Calibrator, Constraint, ParameterBounds,
 };
 use pricer_core::types::CalibrationError;
 
+/// Local optimiser used by [`ModelCalibrator`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LocalOptimiser {
+    /// Levenberg-Marquardt on the residual vector, with parameters clamped
+    /// to the bounds (configured by [`ModelCalibratorConfig::lm_config`]).
+    #[default]
+    LevenbergMarquardt,
+    /// L-BFGS-B on the sum of squared residuals with projected gradients.
+    Lbfgsb(LbfgsbConfig),
+}
+
 /// Configuration for the model calibrator.
 #[derive(Debug, Clone)]
 pub struct ModelCalibratorConfig {
@@ -18,15 +38,19 @@ pub struct ModelCalibratorConfig {
     pub bounds: Vec<ParameterBounds>,
     /// Whether to apply bounds constraints.
     pub enforce_bounds: bool,
+    /// Local optimiser.
+    pub local: LocalOptimiser,
+    /// Optional global search run before the local optimiser.
+    pub global: Option<DifferentialEvolutionConfig>,
+    /// Additional constraints applied to every calibration.
+    pub constraints: Vec<Constraint>,
+    /// Weight on each non-box constraint violation in the local stage.
+    pub constraint_penalty: f64,
 }
 
 impl Default for ModelCalibratorConfig {
     fn default() -> Self {
-        Self {
-            lm_config: LMConfig::default(),
-            bounds: Vec::new(),
-            enforce_bounds: true,
-        }
+        Self::new(LMConfig::default())
     }
 }
 
@@ -37,6 +61,10 @@ impl ModelCalibratorConfig {
             lm_config,
             bounds: Vec::new(),
             enforce_bounds: true,
+            local: LocalOptimiser::default(),
+            global: None,
+            constraints: Vec::new(),
+            constraint_penalty: 1e3,
         }
     }
 
@@ -52,18 +80,40 @@ impl ModelCalibratorConfig {
         self
     }
 
+    /// Set the local optimiser.
+    pub fn with_local_optimiser(mut self, local: LocalOptimiser) -> Self {
+        self.local = local;
+        self
+    }
+
+    /// Run a differential evolution global stage before the local optimiser.
+    ///
+    /// Requires finite bounds for every parameter.
+    pub fn with_global_search(mut self, config: DifferentialEvolutionConfig) -> Self {
+        self.global = Some(config);
+        self
+    }
+
+    /// Set constraints applied to every calibration.
+    pub fn with_constraints(mut self, constraints: Vec<Constraint>) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Set the weight on constraint violations in the local stage.
+    pub fn with_constraint_penalty(mut self, penalty: f64) -> Self {
+        self.constraint_penalty = penalty;
+        self
+    }
+
     /// Create from CalibrationConfig.
     pub fn from_calibration_config(config: &CalibrationConfig) -> Self {
-        Self {
-            lm_config: LMConfig {
-                tolerance: config.tolerance,
-                max_iterations: config.max_iterations,
-                param_tolerance: config.param_tolerance,
-                ..LMConfig::default()
-            },
-            bounds: Vec::new(),
-            enforce_bounds: true,
-        }
+        Self::new(LMConfig {
+            tolerance: config.tolerance,
+            max_iterations: config.max_iterations,
+            param_tolerance: config.param_tolerance,
+            ..LMConfig::default()
+        })
     }
 }
 
@@ -133,51 +183,134 @@ impl ModelCalibrator {
     where
         F: Fn(&[f64]) -> Vec<f64>,
     {
-        // Apply bounds to initial params if needed
-        let initial = if self.config.enforce_bounds && !self.config.bounds.is_empty() {
-            self.apply_bounds(&initial_params)
+        self.calibrate_with_constraints(residuals, initial_params, &[])
+    }
+
+    /// Calibrate using a residual function subject to constraints.
+    ///
+    /// `constraints` are combined with the configured ones.
+    /// [`Constraint::Bounds`] entries tighten the parameter bounds when
+    /// bounds are enforced; other constraints are handled by feasibility
+    /// rules in the global stage and by a penalty in the local stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `residuals` - Function computing residuals from parameters
+    /// * `initial_params` - Starting parameter values
+    /// * `constraints` - Additional constraints for this calibration
+    ///
+    /// # Returns
+    ///
+    /// Calibration result with final parameters. The reported residual sum
+    /// of squares excludes constraint penalties.
+    pub fn calibrate_with_constraints<F>(
+        &self,
+        residuals: F,
+        initial_params: Vec<f64>,
+        constraints: &[Constraint],
+    ) -> CalibrationResult<Vec<f64>>
+    where
+        F: Fn(&[f64]) -> Vec<f64>,
+    {
+        let constraints: Vec<Constraint> = self
+            .config
+            .constraints
+            .iter()
+            .chain(constraints)
+            .cloned()
+            .collect();
+        let n_params = initial_params.len();
+        let bounds = if self.config.enforce_bounds {
+            bounds_from_constraints(&self.config.bounds, &constraints, n_params)
         } else {
-            initial_params
+            vec![ParameterBounds::unbounded(); n_params]
         };
+        let penalised: Vec<&Constraint> = constraints
+            .iter()
+            .filter(|c| !matches!(c, Constraint::Bounds { .. }))
+            .collect();
 
-        // Create constrained residual function
-        // Always use bounds-aware version (handles empty bounds correctly)
-        let bounds = self.config.bounds.clone();
-        let enforce = self.config.enforce_bounds && !bounds.is_empty();
-
-        let constrained_residuals = move |params: &[f64]| {
-            if enforce {
-                let clamped: Vec<f64> = params
-                    .iter()
-                    .enumerate()
-                    .map(|(i, &p)| bounds.get(i).map_or(p, |b| b.clamp(p)))
-                    .collect();
-                residuals(&clamped)
+        // Residuals are only ever evaluated inside the bounds
+        let clamp = |params: &[f64]| -> Vec<f64> {
+            params
+                .iter()
+                .zip(&bounds)
+                .map(|(&p, b)| b.clamp(p))
+                .collect()
+        };
+        let sum_of_squares = |params: &[f64]| -> f64 {
+            let ss: f64 = residuals(&clamp(params)).iter().map(|r| r * r).sum();
+            if ss.is_nan() {
+                f64::INFINITY
             } else {
-                residuals(params)
+                ss
             }
         };
 
-        // Run LM solver
-        let solver = LevenbergMarquardtSolver::new(self.config.lm_config);
-        match solver.solve(constrained_residuals, initial) {
-            Ok(lm_result) => {
-                let mut result = self.convert_lm_result(lm_result);
-                // Apply bounds to final result if enabled
-                if enforce {
-                    result.params = self.apply_bounds(&result.params);
+        let mut start = clamp(&initial_params);
+        let mut global_iterations = 0;
+        if let Some(de_config) = self.config.global {
+            let de = DifferentialEvolution::new(de_config);
+            match de.minimise_with_initial(sum_of_squares, &start, &bounds, &constraints) {
+                Ok(de_result) => {
+                    start = de_result.params;
+                    global_iterations = de_result.iterations;
                 }
-                result
+                Err(e) => return self.solver_failure(e.into()),
             }
-            Err(e) => {
-                let calib_err: CalibrationError = e.into();
-                CalibrationResult::not_converged(
-                    Vec::new(),
-                    calib_err.iterations,
-                    calib_err.residual_ss,
-                    format!("{}", calib_err),
-                )
+        }
+
+        let penalty = self.config.constraint_penalty;
+        let (params, converged, iterations) = match self.config.local {
+            LocalOptimiser::LevenbergMarquardt => {
+                let penalised_residuals = |params: &[f64]| {
+                    let clamped = clamp(params);
+                    let mut r = residuals(&clamped);
+                    r.extend(penalised.iter().map(|c| penalty * c.violation(&clamped)));
+                    r
+                };
+                let solver = LevenbergMarquardtSolver::new(self.config.lm_config);
+                match solver.solve(penalised_residuals, start) {
+                    Ok(lm_result) => {
+                        let result = self.convert_lm_result(lm_result);
+                        (clamp(&result.params), result.converged, result.iterations)
+                    }
+                    Err(e) => return self.solver_failure(e.into()),
+                }
+            }
+            LocalOptimiser::Lbfgsb(lbfgsb_config) => {
+                let objective = |params: &[f64]| {
+                    let violation: f64 = penalised
+                        .iter()
+                        .map(|c| (penalty * c.violation(params)).powi(2))
+                        .sum();
+                    sum_of_squares(params) + violation
+                };
+                let solver = LbfgsbSolver::new(lbfgsb_config);
+                match solver.minimise(objective, start, &bounds) {
+                    Ok(min_result) => (
+                        min_result.params,
+                        min_result.converged,
+                        min_result.iterations,
+                    ),
+                    Err(e) => return self.solver_failure(e.into()),
+                }
             }
+        };
+
+        let residual_ss = sum_of_squares(&params);
+        let violation: f64 = penalised.iter().map(|c| c.violation(&params)).sum();
+        let result = CalibrationResult::with_details(
+            params,
+            converged,
+            global_iterations + iterations,
+            residual_ss,
+            Vec::new(),
+        );
+        if violation > 0.0 {
+            result.with_message(format!("Constraint violation {:.3e}", violation))
+        } else {
+            result
         }
     }
 
@@ -202,13 +335,14 @@ impl ModelCalibrator {
         }
     }
 
-    /// Apply bounds to parameters.
-    fn apply_bounds(&self, params: &[f64]) -> Vec<f64> {
-        params
-            .iter()
-            .enumerate()
-            .map(|(i, &p)| self.config.bounds.get(i).map_or(p, |b| b.clamp(p)))
-            .collect()
+    /// Result for a solver error.
+    fn solver_failure(&self, error: CalibrationError) -> CalibrationResult<Vec<f64>> {
+        CalibrationResult::not_converged(
+            Vec::new(),
+            error.iterations,
+            error.residual_ss,
+            format!("{}", error),
+        )
     }
 
     /// Convert LM result to CalibrationResult.
@@ -223,6 +357,35 @@ impl ModelCalibrator {
     }
 }
 
+/// Differential evolution global search for calibrators built on a
+/// [`ModelCalibrator`].
+///
+/// Implementors only expose their inner calibrator; the builder is shared.
+///
+/// # Example
+///
+/// ```
+/// use pricer_core::math::solvers::DifferentialEvolutionConfig;
+/// use pricer_models::calibration::{GlobalSearch, HestonCalibrator};
+///
+/// let calibrator =
+///     HestonCalibrator::new().with_global_search(DifferentialEvolutionConfig::fast());
+/// ```
+pub trait GlobalSearch: Sized {
+    /// The inner calibrator whose configuration is extended.
+    fn model_calibrator_mut(&mut self) -> &mut ModelCalibrator;
+
+    /// Run a differential evolution global search before the local
+    /// optimiser, within the parameter bounds.
+    ///
+    /// See [`ModelCalibratorConfig::with_global_search`].
+    fn with_global_search(mut self, config: DifferentialEvolutionConfig) -> Self {
+        let calibrator = self.model_calibrator_mut();
+        calibrator.config = std::mem::take(&mut calibrator.config).with_global_search(config);
+        self
+    }
+}
+
 /// Implement Calibrator trait for a specific market data type.
 ///
 /// This is a helper struct that wraps ModelCalibrator for use with
@@ -272,7 +435,7 @@ where
     ) -> CalibrationResult<Self::ModelParams> {
         let residuals = |params: &[f64]| (self.residual_fn)(params, market_data);
         self.calibrator
-            .calibrate_with_residuals(residuals, initial_params)
+            .calibrate_with_constraints(residuals, initial_params, &self.constraints)
     }
 
     fn objective_function(
@@ -291,7 +454,6 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use pricer_core::math::solvers::LMConfig;
 
     #[test]
     fn test_model_calibrator_new() {
@@ -415,6 +577,104 @@ mod tests {
         assert_eq!(constraints.len(), 1);
     }
 
+    #[test]
+    fn test_lbfgsb_local_optimiser_with_active_bound() {
+        let config = ModelCalibratorConfig::default()
+            .with_bounds(vec![
+                ParameterBounds::new(0.0, 1.0),
+                ParameterBounds::new(0.0, 10.0),
+            ])
+            .with_local_optimiser(LocalOptimiser::Lbfgsb(LbfgsbConfig::default()));
+        let calibrator = ModelCalibrator::new(config);
+
+        let residuals = |params: &[f64]| vec![params[0] - 2.0, params[1] - 3.0];
+        let result = calibrator.calibrate_with_residuals(residuals, vec![0.5, 0.5]);
+
+        assert!(result.converged);
+        assert!((result.params[0] - 1.0).abs() < 1e-8);
+        assert!((result.params[1] - 3.0).abs() < 1e-6);
+        assert!((result.residual_ss - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_global_search_escapes_local_minimum() {
+        // x² + 10·sin²(πx): local minima near every integer, global at 0
+        let residuals = |params: &[f64]| {
+            let x = params[0];
+            vec![x, 10.0_f64.sqrt() * (std::f64::consts::PI * x).sin()]
+        };
+        let bounds = vec![ParameterBounds::new(-3.0, 1.0)];
+
+        let local =
+            ModelCalibrator::new(ModelCalibratorConfig::default().with_bounds(bounds.clone()));
+        let global = ModelCalibrator::new(
+            ModelCalibratorConfig::default()
+                .with_bounds(bounds)
+                .with_global_search(DifferentialEvolutionConfig::fast()),
+        );
+
+        let stuck = local.calibrate_with_residuals(residuals, vec![-2.0]);
+        let found = global.calibrate_with_residuals(residuals, vec![-2.0]);
+
+        assert!(stuck.params[0] < -1.5);
+        assert!(found.converged);
+        assert!(found.params[0].abs() < 1e-6);
+        assert!(found.residual_ss < stuck.residual_ss);
+    }
+
+    #[test]
+    fn test_linear_constraint_penalty() {
+        // Fit (2, 3) subject to p0 + p1 <= 4
+        let constraint = Constraint::LinearInequality {
+            coefficients: vec![1.0, 1.0],
+            rhs: 4.0,
+        };
+        let residuals = |params: &[f64]| vec![params[0] - 2.0, params[1] - 3.0];
+
+        for local in [
+            LocalOptimiser::LevenbergMarquardt,
+            LocalOptimiser::Lbfgsb(LbfgsbConfig::default()),
+        ] {
+            let calibrator =
+                ModelCalibrator::new(ModelCalibratorConfig::default().with_local_optimiser(local));
+            let result = calibrator.calibrate_with_constraints(
+                residuals,
+                vec![0.0, 0.0],
+                std::slice::from_ref(&constraint),
+            );
+            assert!(constraint.violation(&result.params) < 1e-5);
+            assert!((result.params[0] - 1.5).abs() < 1e-4);
+            assert!((result.params[1] - 2.5).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_global_search_requires_finite_bounds() {
+        let config = ModelCalibratorConfig::default()
+            .with_global_search(DifferentialEvolutionConfig::fast());
+        let result = ModelCalibrator::new(config)
+            .calibrate_with_residuals(|params: &[f64]| vec![params[0] - 1.0], vec![0.0]);
+        assert!(!result.converged);
+    }
+
+    #[test]
+    fn test_generic_calibrator_applies_constraints() {
+        let residual_fn = |params: &[f64], target: &Vec<f64>| {
+            params.iter().zip(target).map(|(p, t)| p - t).collect()
+        };
+        let calibrator = GenericCalibrator::new(ModelCalibratorConfig::default(), residual_fn)
+            .with_constraints(vec![Constraint::bounds(0, 0.0, 1.5)]);
+
+        let result = calibrator.calibrate(
+            &vec![2.0, 3.0],
+            vec![0.0, 0.0],
+            &CalibrationConfig::default(),
+        );
+
+        assert!((result.params[0] - 1.5).abs() < 1e-8);
+        assert!((result.params[1] - 3.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_exponential_fit() {
         let calibrator = ModelCalibrator::with_defaults();
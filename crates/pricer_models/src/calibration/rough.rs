@@ -0,0 +1,659 @@
+//! Rough Heston and rough Bergomi calibration.
+//!
+//! Both models are calibrated to the same vanilla option quotes as the
+//! Heston model ([`HestonCalibrationData`]), so fits can be compared quote
+//! by quote. Rough Heston prices European options with the COS method
+//! from [`RoughHestonCf`]; rough Bergomi prices them by Monte Carlo with
+//! [`RoughBergomiModel`] on a fixed set of normals (common random numbers),
+//! which keeps the objective deterministic and smooth in the parameters.
+//!
+//! Parameter vectors:
+//!
+//! - Rough Heston: `[v0, theta, kappa, xi, rho, hurst]`
+//!   ([`RoughHestonParamIndex`]), whose first five entries follow
+//!   [`HestonParamIndex`]
+//! - Rough Bergomi: `[xi0, eta, rho, hurst]` ([`RoughBergomiParamIndex`])
+//!
+//! Quotes are priced one expiry at a time: every strike of an expiry
+//! shares one COS expansion or one set of simulated paths, and calls
+//! follow from puts by put-call parity on the exact forward.
+
+use pricer_core::traits::calibration::{
+    CalibrationConfig, CalibrationResult, Calibrator, Constraint, ParameterBounds,
+};
+
+use super::heston::{HestonCalibrationData, HestonParamIndex};
+use super::{GlobalSearch, ModelCalibrator, ModelCalibratorConfig};
+use crate::analytical::fourier::{CosPricer, FourierMarket, RoughHestonCf, ROUGH_HESTON_STEPS};
+use crate::models::rough_bergomi::{RoughBergomiModel, RoughBergomiParams, NORMALS_PER_STEP};
+
+/// Default number of COS expansion terms.
+const DEFAULT_COS_TERMS: usize = 128;
+
+/// COS truncation width in cumulant standard deviations.
+const COS_TRUNCATION: f64 = 12.0;
+
+/// Parameter indices for the rough Heston calibration vector.
+pub struct RoughHestonParamIndex;
+
+impl RoughHestonParamIndex {
+    /// Index of v0 (initial variance).
+    pub const V0: usize = HestonParamIndex::V0;
+    /// Index of theta (long-run variance).
+    pub const THETA: usize = HestonParamIndex::THETA;
+    /// Index of kappa (mean reversion speed).
+    pub const KAPPA: usize = HestonParamIndex::KAPPA;
+    /// Index of xi (volatility of variance).
+    pub const XI: usize = HestonParamIndex::XI;
+    /// Index of rho (spot-variance correlation).
+    pub const RHO: usize = HestonParamIndex::RHO;
+    /// Index of hurst (Hurst exponent of the variance).
+    pub const HURST: usize = 5;
+    /// Total number of parameters.
+    pub const COUNT: usize = 6;
+}
+
+/// Parameter indices for the rough Bergomi calibration vector.
+pub struct RoughBergomiParamIndex;
+
+impl RoughBergomiParamIndex {
+    /// Index of xi0 (flat forward variance).
+    pub const XI0: usize = 0;
+    /// Index of eta (volatility of volatility).
+    pub const ETA: usize = 1;
+    /// Index of rho (spot-volatility correlation).
+    pub const RHO: usize = 2;
+    /// Index of hurst (Hurst exponent).
+    pub const HURST: usize = 3;
+    /// Total number of parameters.
+    pub const COUNT: usize = 4;
+}
+
+/// Weighted residuals of every quote, priced one expiry at a time.
+///
+/// `put_strip(expiry, strikes)` returns present-value put prices for the
+/// strikes of one expiry; calls use put-call parity.
+fn strip_residuals(
+    market_data: &HestonCalibrationData,
+    mut put_strip: impl FnMut(f64, &[f64]) -> Vec<f64>,
+) -> Vec<f64> {
+    let mut residuals = vec![f64::NAN; market_data.points.len()];
+    let mut priced = vec![false; market_data.points.len()];
+    for first in 0..market_data.points.len() {
+        if priced[first] {
+            continue;
+        }
+        let expiry = market_data.points[first].expiry;
+        let members: Vec<usize> = (first..market_data.points.len())
+            .filter(|&i| market_data.points[i].expiry == expiry)
+            .collect();
+        let strikes: Vec<f64> = members
+            .iter()
+            .map(|&i| market_data.points[i].strike)
+            .collect();
+        let puts = put_strip(expiry, &strikes);
+
+        let forward = market_data.spot * ((market_data.rate - market_data.dividend) * expiry).exp();
+        let discount = (-market_data.rate * expiry).exp();
+        for (&i, &put) in members.iter().zip(&puts) {
+            let point = &market_data.points[i];
+            let model_price = if point.is_call {
+                parity_call(put, forward, discount, point.strike)
+            } else {
+                put
+            };
+            residuals[i] = point.residual(market_data.spot, market_data.rate, model_price);
+            priced[i] = true;
+        }
+    }
+    residuals
+}
+
+/// Call price from a put price by put-call parity, floored at zero; NaN
+/// puts stay NaN.
+fn parity_call(put: f64, forward: f64, discount: f64, strike: f64) -> f64 {
+    let call = put + discount * (forward - strike);
+    if call < 0.0 {
+        0.0
+    } else {
+        call
+    }
+}
+
+/// Rough Heston calibrator.
+///
+/// ## Parameter Bounds
+///
+/// The Heston bounds of
+/// [`HestonCalibrator`](super::heston::HestonCalibrator), plus
+///
+/// - 0.01 ≤ hurst ≤ 0.5 (Hurst exponent; 0.5 is classical Heston)
+#[derive(Debug, Clone)]
+pub struct RoughHestonCalibrator {
+    /// Underlying model calibrator.
+    calibrator: ModelCalibrator,
+    /// Number of COS expansion terms for pricing.
+    integration_points: usize,
+    /// Number of Adams steps of the fractional Riccati solver.
+    riccati_steps: usize,
+}
+
+impl Default for RoughHestonCalibrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GlobalSearch for RoughHestonCalibrator {
+    fn model_calibrator_mut(&mut self) -> &mut ModelCalibrator {
+        &mut self.calibrator
+    }
+}
+
+impl RoughHestonCalibrator {
+    /// Create a new rough Heston calibrator with default settings.
+    pub fn new() -> Self {
+        let config = ModelCalibratorConfig::default().with_bounds(vec![
+            ParameterBounds::new(1e-6, 1.0),     // v0
+            ParameterBounds::new(1e-6, 1.0),     // theta
+            ParameterBounds::new(0.01, 20.0),    // kappa
+            ParameterBounds::new(0.01, 2.0),     // xi
+            ParameterBounds::new(-0.999, 0.999), // rho
+            ParameterBounds::new(0.01, 0.5),     // hurst
+        ]);
+        Self::with_config(config)
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(config: ModelCalibratorConfig) -> Self {
+        Self {
+            calibrator: ModelCalibrator::new(config),
+            integration_points: DEFAULT_COS_TERMS,
+            riccati_steps: ROUGH_HESTON_STEPS,
+        }
+    }
+
+    /// Set the number of COS expansion terms used for pricing.
+    pub fn with_integration_points(mut self, n: usize) -> Self {
+        self.integration_points = n;
+        self
+    }
+
+    /// Set the number of Adams steps of the fractional Riccati solver.
+    pub fn with_riccati_steps(mut self, n: usize) -> Self {
+        self.riccati_steps = n;
+        self
+    }
+
+    /// Get parameter names.
+    pub fn param_names(&self) -> &[&'static str] {
+        &["v0", "theta", "kappa", "xi", "rho", "hurst"]
+    }
+
+    /// Price a European option from a rough Heston parameter vector.
+    #[allow(clippy::too_many_arguments)]
+    pub fn price_option(
+        &self,
+        spot: f64,
+        strike: f64,
+        expiry: f64,
+        rate: f64,
+        dividend: f64,
+        params: &[f64],
+        is_call: bool,
+    ) -> f64 {
+        let market = FourierMarket {
+            spot,
+            rate,
+            dividend_yield: dividend,
+        };
+        self.price_strip(&market, params, expiry, &[strike], is_call)[0]
+    }
+
+    /// Prices a strip of strikes; parameters are not validated.
+    fn price_strip(
+        &self,
+        market: &FourierMarket,
+        params: &[f64],
+        expiry: f64,
+        strikes: &[f64],
+        is_call: bool,
+    ) -> Vec<f64> {
+        let model = RoughHestonCf {
+            v0: params[RoughHestonParamIndex::V0],
+            kappa: params[RoughHestonParamIndex::KAPPA],
+            theta: params[RoughHestonParamIndex::THETA],
+            xi: params[RoughHestonParamIndex::XI],
+            rho: params[RoughHestonParamIndex::RHO],
+            hurst: params[RoughHestonParamIndex::HURST],
+            n_steps: self.riccati_steps.max(1),
+        };
+        CosPricer::new(self.integration_points.max(2), COS_TRUNCATION)
+            .and_then(|cos| cos.price_strikes(&model, market, strikes, expiry, is_call))
+            .map_or_else(
+                |_| vec![f64::NAN; strikes.len()],
+                |prices| prices.into_iter().map(|p| p.max(0.0)).collect(),
+            )
+    }
+
+    fn residuals(&self, market_data: &HestonCalibrationData, params: &[f64]) -> Vec<f64> {
+        let market = FourierMarket {
+            spot: market_data.spot,
+            rate: market_data.rate,
+            dividend_yield: market_data.dividend,
+        };
+        strip_residuals(market_data, |expiry, strikes| {
+            self.price_strip(&market, params, expiry, strikes, false)
+        })
+    }
+}
+
+impl Calibrator for RoughHestonCalibrator {
+    type MarketData = HestonCalibrationData;
+    type ModelParams = Vec<f64>;
+
+    fn calibrate(
+        &self,
+        market_data: &Self::MarketData,
+        initial_params: Self::ModelParams,
+        _config: &CalibrationConfig,
+    ) -> CalibrationResult<Self::ModelParams> {
+        if let Err(e) = market_data.validate() {
+            return CalibrationResult::not_converged(initial_params, 0, f64::INFINITY, e);
+        }
+        let data = market_data.clone();
+        let pricer = self.clone();
+        let residuals = move |params: &[f64]| pricer.residuals(&data, params);
+        self.calibrator
+            .calibrate_with_constraints(residuals, initial_params, &[])
+    }
+
+    fn objective_function(
+        &self,
+        params: &Self::ModelParams,
+        market_data: &Self::MarketData,
+    ) -> Vec<f64> {
+        self.residuals(market_data, params)
+    }
+
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::positive(RoughHestonParamIndex::V0),
+            Constraint::positive(RoughHestonParamIndex::THETA),
+            Constraint::positive(RoughHestonParamIndex::KAPPA),
+            Constraint::positive(RoughHestonParamIndex::XI),
+            Constraint::bounds(RoughHestonParamIndex::RHO, -0.999, 0.999),
+            Constraint::bounds(RoughHestonParamIndex::HURST, 0.01, 0.5),
+        ]
+    }
+}
+
+/// Rough Bergomi calibrator.
+///
+/// Every expiry is simulated on `n_steps` uniform steps from the same
+/// standard normals, laid out as for [`RoughBergomiModel::price_european`]
+/// (`3·n_steps` per path, each path also used antithetically), and calls
+/// are priced from puts by parity on the exact forward. Using the
+/// same normals for every parameter vector makes the Monte Carlo objective
+/// deterministic, so the local optimiser's finite differences are not
+/// swamped by simulation noise.
+///
+/// ## Parameter Bounds
+///
+/// - 1e-4 ≤ xi0 ≤ 1 (flat forward variance)
+/// - 0 ≤ eta ≤ 5 (volatility of volatility)
+/// - -0.999 ≤ rho ≤ 0.999 (spot-volatility correlation)
+/// - 0.01 ≤ hurst ≤ 0.5 (Hurst exponent)
+#[derive(Debug, Clone)]
+pub struct RoughBergomiCalibrator {
+    /// Underlying model calibrator.
+    calibrator: ModelCalibrator,
+    /// Time steps per expiry.
+    n_steps: usize,
+    /// Standard normals shared by every expiry and parameter vector.
+    normals: Vec<f64>,
+}
+
+impl GlobalSearch for RoughBergomiCalibrator {
+    fn model_calibrator_mut(&mut self) -> &mut ModelCalibrator {
+        &mut self.calibrator
+    }
+}
+
+impl RoughBergomiCalibrator {
+    /// Create a rough Bergomi calibrator simulating `n_steps` steps per
+    /// expiry from `normals`.
+    pub fn new(n_steps: usize, normals: Vec<f64>) -> Self {
+        let config = ModelCalibratorConfig::default().with_bounds(vec![
+            ParameterBounds::new(1e-4, 1.0),     // xi0
+            ParameterBounds::new(0.0, 5.0),      // eta
+            ParameterBounds::new(-0.999, 0.999), // rho
+            ParameterBounds::new(0.01, 0.5),     // hurst
+        ]);
+        Self::with_config(config, n_steps, normals)
+    }
+
+    /// Create with custom configuration.
+    pub fn with_config(config: ModelCalibratorConfig, n_steps: usize, normals: Vec<f64>) -> Self {
+        Self {
+            calibrator: ModelCalibrator::new(config),
+            n_steps,
+            normals,
+        }
+    }
+
+    /// Get parameter names.
+    pub fn param_names(&self) -> &[&'static str] {
+        &["xi0", "eta", "rho", "hurst"]
+    }
+
+    /// Number of simulated paths (before antithetics).
+    pub fn n_paths(&self) -> usize {
+        self.normals.len() / (NORMALS_PER_STEP * self.n_steps.max(1))
+    }
+
+    /// Price a European option from a rough Bergomi parameter vector.
+    ///
+    /// Returns NaN if the parameters or the normals are invalid.
+    #[allow(clippy::too_many_arguments)]
+    pub fn price_option(
+        &self,
+        spot: f64,
+        strike: f64,
+        expiry: f64,
+        rate: f64,
+        dividend: f64,
+        params: &[f64],
+        is_call: bool,
+    ) -> f64 {
+        self.price_strip(spot, rate, dividend, params, expiry, &[strike], is_call)[0]
+    }
+
+    /// Prices a strip of strikes, or NaNs for invalid inputs.
+    ///
+    /// Calls follow from the simulated puts by parity on the exact
+    /// forward, which removes the Monte Carlo error of the forward.
+    #[allow(clippy::too_many_arguments)]
+    fn price_strip(
+        &self,
+        spot: f64,
+        rate: f64,
+        dividend: f64,
+        params: &[f64],
+        expiry: f64,
+        strikes: &[f64],
+        is_call: bool,
+    ) -> Vec<f64> {
+        let puts = self.put_strip(spot, rate, dividend, params, expiry, strikes);
+        if !is_call {
+            return puts;
+        }
+        let forward = spot * ((rate - dividend) * expiry).exp();
+        let discount = (-rate * expiry).exp();
+        puts.iter()
+            .zip(strikes)
+            .map(|(&put, &strike)| parity_call(put, forward, discount, strike))
+            .collect()
+    }
+
+    /// Simulated put prices of a strip, or NaNs for invalid inputs.
+    fn put_strip(
+        &self,
+        spot: f64,
+        rate: f64,
+        dividend: f64,
+        params: &[f64],
+        expiry: f64,
+        strikes: &[f64],
+    ) -> Vec<f64> {
+        RoughBergomiParams::new(
+            spot,
+            rate,
+            dividend,
+            params[RoughBergomiParamIndex::XI0],
+            params[RoughBergomiParamIndex::ETA],
+            params[RoughBergomiParamIndex::RHO],
+            params[RoughBergomiParamIndex::HURST],
+        )
+        .and_then(|p| RoughBergomiModel::new(p, expiry, self.n_steps))
+        .and_then(|model| model.price_european(strikes, false, &self.normals))
+        .unwrap_or_else(|_| vec![f64::NAN; strikes.len()])
+    }
+
+    fn residuals(&self, market_data: &HestonCalibrationData, params: &[f64]) -> Vec<f64> {
+        strip_residuals(market_data, |expiry, strikes| {
+            self.put_strip(
+                market_data.spot,
+                market_data.rate,
+                market_data.dividend,
+                params,
+                expiry,
+                strikes,
+            )
+        })
+    }
+
+    fn validate_normals(&self) -> Result<(), String> {
+        let per_path = NORMALS_PER_STEP * self.n_steps;
+        if self.n_steps == 0 || self.normals.is_empty() || self.normals.len() % per_path != 0 {
+            return Err(format!(
+                "{} normals for {} steps: need a positive multiple of {}",
+                self.normals.len(),
+                self.n_steps,
+                per_path
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Calibrator for RoughBergomiCalibrator {
+    type MarketData = HestonCalibrationData;
+    type ModelParams = Vec<f64>;
+
+    fn calibrate(
+        &self,
+        market_data: &Self::MarketData,
+        initial_params: Self::ModelParams,
+        _config: &CalibrationConfig,
+    ) -> CalibrationResult<Self::ModelParams> {
+        if let Err(e) = market_data.validate().and_then(|_| self.validate_normals()) {
+            return CalibrationResult::not_converged(initial_params, 0, f64::INFINITY, e);
+        }
+        let data = market_data.clone();
+        let pricer = self.clone();
+        let residuals = move |params: &[f64]| pricer.residuals(&data, params);
+        self.calibrator
+            .calibrate_with_constraints(residuals, initial_params, &[])
+    }
+
+    fn objective_function(
+        &self,
+        params: &Self::ModelParams,
+        market_data: &Self::MarketData,
+    ) -> Vec<f64> {
+        self.residuals(market_data, params)
+    }
+
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::positive(RoughBergomiParamIndex::XI0),
+            Constraint::bounds(RoughBergomiParamIndex::ETA, 0.0, 5.0),
+            Constraint::bounds(RoughBergomiParamIndex::RHO, -0.999, 0.999),
+            Constraint::bounds(RoughBergomiParamIndex::HURST, 0.01, 0.5),
+        ]
+    }
+}
+
+/// Convenience function to calibrate the rough Heston model.
+///
+/// # Arguments
+///
+/// * `market_data` - Market option data
+/// * `initial_params` - Initial parameter guess
+///   [v0, theta, kappa, xi, rho, hurst]
+///
+/// # Example
+///
+/// ```
+/// use pricer_models::calibration::{calibrate_rough_heston, HestonCalibrationData};
+///
+/// let mut data = HestonCalibrationData::new(100.0, 0.03);
+/// data.add_call(90.0, 0.25, 11.2);
+/// data.add_call(100.0, 0.25, 4.2);
+/// data.add_call(110.0, 0.25, 0.9);
+///
+/// let result = calibrate_rough_heston(&data, vec![0.04, 0.04, 1.5, 0.5, -0.7, 0.1]);
+/// assert_eq!(result.params.len(), 6);
+/// ```
+pub fn calibrate_rough_heston(
+    market_data: &HestonCalibrationData,
+    initial_params: Vec<f64>,
+) -> CalibrationResult<Vec<f64>> {
+    RoughHestonCalibrator::new().calibrate(
+        market_data,
+        initial_params,
+        &CalibrationConfig::default(),
+    )
+}
+
+/// Convenience function to calibrate the rough Bergomi model.
+///
+/// # Arguments
+///
+/// * `market_data` - Market option data
+/// * `initial_params` - Initial parameter guess [xi0, eta, rho, hurst]
+/// * `n_steps` - Time steps per expiry
+/// * `normals` - Standard normals, `3·n_steps` per path
+pub fn calibrate_rough_bergomi(
+    market_data: &HestonCalibrationData,
+    initial_params: Vec<f64>,
+    n_steps: usize,
+    normals: Vec<f64>,
+) -> CalibrationResult<Vec<f64>> {
+    RoughBergomiCalibrator::new(n_steps, normals).calibrate(
+        market_data,
+        initial_params,
+        &CalibrationConfig::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::HestonCalibrator;
+    use crate::test_utils::normals;
+
+    fn synthetic_data(price: impl Fn(f64, f64, bool) -> f64) -> HestonCalibrationData {
+        let mut data = HestonCalibrationData::new(100.0, 0.03);
+        for expiry in [0.1, 0.5, 1.5] {
+            for strike in [80.0, 90.0, 100.0, 110.0, 120.0] {
+                if strike < 100.0 {
+                    data.add_put(strike, expiry, price(strike, expiry, false));
+                } else {
+                    data.add_call(strike, expiry, price(strike, expiry, true));
+                }
+            }
+        }
+        data
+    }
+
+    fn squared_error(residuals: Vec<f64>) -> f64 {
+        residuals.iter().map(|r| r * r).sum()
+    }
+
+    #[test]
+    fn test_rough_heston_at_half_matches_heston() {
+        let heston = [0.04, 0.05, 1.5, 0.5, -0.6];
+        let mut rough = heston.to_vec();
+        rough.push(0.5);
+        let calibrator = RoughHestonCalibrator::new().with_riccati_steps(400);
+        for is_call in [true, false] {
+            let price = calibrator.price_option(100.0, 95.0, 1.0, 0.03, 0.01, &rough, is_call);
+            let reference = HestonCalibrator::new()
+                .price_option(100.0, 95.0, 1.0, 0.03, 0.01, &heston, is_call);
+            assert!(
+                (price - reference).abs() < 1e-4,
+                "{} vs {}",
+                price,
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn test_calibrate_rough_heston_synthetic() {
+        let true_params = [0.03, 0.04, 1.0, 0.4, -0.7, 0.1];
+        let calibrator = RoughHestonCalibrator::new()
+            .with_integration_points(64)
+            .with_riccati_steps(40);
+        let data = synthetic_data(|strike, expiry, is_call| {
+            calibrator.price_option(100.0, strike, expiry, 0.03, 0.0, &true_params, is_call)
+        });
+
+        let initial = vec![0.04, 0.04, 1.5, 0.5, -0.5, 0.3];
+        let result = calibrator.calibrate(&data, initial.clone(), &CalibrationConfig::default());
+        let initial_error = squared_error(calibrator.objective_function(&initial, &data));
+        let fitted_error = squared_error(calibrator.objective_function(&result.params, &data));
+        assert!(
+            fitted_error < 1e-3 * initial_error,
+            "{} vs {}",
+            fitted_error,
+            initial_error
+        );
+        assert!((result.params[RoughHestonParamIndex::V0] - 0.03).abs() < 5e-3);
+        assert!((result.params[RoughHestonParamIndex::RHO] + 0.7).abs() < 5e-2);
+    }
+
+    #[test]
+    fn test_calibrate_rough_bergomi_synthetic() {
+        let (n_steps, n_paths) = (16, 400);
+        let mut normal = normals(17);
+        let z: Vec<f64> = (0..n_paths * NORMALS_PER_STEP * n_steps)
+            .map(|_| normal())
+            .collect();
+        let calibrator = RoughBergomiCalibrator::new(n_steps, z.clone());
+        assert_eq!(calibrator.n_paths(), n_paths);
+
+        let true_params = [0.04, 1.5, -0.8, 0.1];
+        let data = synthetic_data(|strike, expiry, is_call| {
+            calibrator.price_option(100.0, strike, expiry, 0.03, 0.0, &true_params, is_call)
+        });
+
+        let initial = vec![0.05, 1.0, -0.5, 0.2];
+        let result = calibrate_rough_bergomi(&data, initial.clone(), n_steps, z);
+        let initial_error = squared_error(calibrator.objective_function(&initial, &data));
+        let fitted_error = squared_error(calibrator.objective_function(&result.params, &data));
+        assert!(
+            fitted_error < 1e-4 * initial_error,
+            "{} vs {}",
+            fitted_error,
+            initial_error
+        );
+        assert!((result.params[RoughBergomiParamIndex::XI0] - 0.04).abs() < 2e-3);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let empty = HestonCalibrationData::new(100.0, 0.03);
+        assert!(!calibrate_rough_heston(&empty, vec![0.04; 6]).converged);
+
+        let mut data = HestonCalibrationData::new(100.0, 0.03);
+        data.add_call(100.0, 0.5, 5.0);
+        let result = calibrate_rough_bergomi(&data, vec![0.04, 1.5, -0.7, 0.1], 10, vec![0.0; 29]);
+        assert!(!result.converged);
+        assert!(RoughBergomiCalibrator::new(10, vec![0.0; 30])
+            .price_option(100.0, 100.0, 0.5, 0.03, 0.0, &[0.04, 1.5, -0.7, 0.7], true)
+            .is_nan());
+        assert_eq!(
+            RoughHestonCalibrator::new().constraints().len(),
+            RoughHestonParamIndex::COUNT
+        );
+        assert_eq!(
+            RoughBergomiCalibrator::new(10, Vec::new())
+                .param_names()
+                .len(),
+            RoughBergomiParamIndex::COUNT
+        );
+    }
+}
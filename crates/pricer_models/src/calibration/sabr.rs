@@ -25,12 +25,22 @@
 //! Beta can be:
 //! - Fixed at a specific value (common choice: 0.5 or 1.0)
 //! - Calibrated along with other parameters
+//!
+//! ## Negative Rates
+//!
+//! With a displacement (`SABRCalibrationData::with_shift`) the forward and
+//! strikes only need to be above `-shift`, and vols are shifted Black vols.
+//! [`SABRVolMethod::ArbitrageFree`] replaces the Hagan formula with the
+//! arbitrage-free PDE of [`ArbitrageFreeSABR`], whose density stays positive
+//! down to the lowest strikes.
 
 use pricer_core::traits::calibration::{
     CalibrationConfig, CalibrationResult, Calibrator, Constraint, ParameterBounds,
 };
 
 use super::{ModelCalibrator, ModelCalibratorConfig};
+use crate::models::sabr::SABRParams;
+use crate::models::sabr_pde::{ArbitrageFreeSABR, SABRPdeConfig};
 
 /// SABR smile point for calibration.
 ///
@@ -80,6 +90,8 @@ pub struct SABRCalibrationData {
     pub smile_points: Vec<SABRSmilePoint>,
     /// Fixed beta value (None means calibrate beta).
     pub fixed_beta: Option<f64>,
+    /// Displacement applied to forward and strikes (0 for plain SABR).
+    pub shift: f64,
 }
 
 impl SABRCalibrationData {
@@ -91,6 +103,7 @@ impl SABRCalibrationData {
             atm_vol,
             smile_points: Vec::new(),
             fixed_beta: None,
+            shift: 0.0,
         }
     }
 
@@ -100,6 +113,15 @@ impl SABRCalibrationData {
         self
     }
 
+    /// Set the displacement for shifted SABR.
+    ///
+    /// Vols are then shifted Black vols of `forward + shift` and
+    /// `strike + shift`.
+    pub fn with_shift(mut self, shift: f64) -> Self {
+        self.shift = shift;
+        self
+    }
+
     /// Add a smile point.
     pub fn add_smile_point(&mut self, strike: f64, vol: f64) {
         self.smile_points.push(SABRSmilePoint::new(strike, vol));
@@ -128,8 +150,11 @@ impl SABRCalibrationData {
 
     /// Validate the data.
     pub fn validate(&self) -> Result<(), String> {
-        if self.forward <= 0.0 {
-            return Err("Forward must be positive".to_string());
+        if !(self.shift >= 0.0 && self.shift.is_finite()) {
+            return Err("Shift must be non-negative and finite".to_string());
+        }
+        if self.forward + self.shift <= 0.0 {
+            return Err("Shifted forward must be positive".to_string());
         }
         if self.expiry <= 0.0 {
             return Err("Expiry must be positive".to_string());
@@ -143,8 +168,11 @@ impl SABRCalibrationData {
             }
         }
         for (i, point) in self.smile_points.iter().enumerate() {
-            if point.strike <= 0.0 {
-                return Err(format!("Smile point {}: strike must be positive", i));
+            if point.strike + self.shift <= 0.0 {
+                return Err(format!(
+                    "Smile point {}: shifted strike must be positive",
+                    i
+                ));
             }
             if point.implied_vol <= 0.0 {
                 return Err(format!("Smile point {}: vol must be positive", i));
@@ -200,13 +228,26 @@ impl SABRParamIndex {
     }
 }
 
+/// Formula used for model implied vols during SABR calibration.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SABRVolMethod {
+    /// Hagan et al. (2002) expansion. Fast, but its density can turn
+    /// negative at low strikes and long expiries.
+    #[default]
+    Hagan,
+    /// Arbitrage-free SABR (Hagan et al. 2014) solved on the given grid.
+    /// One PDE solve prices the whole smile.
+    ArbitrageFree(SABRPdeConfig),
+}
+
 /// SABR model calibrator.
 ///
 /// # Requirement: 6.2
 ///
 /// Calibrates SABR model parameters to market implied volatility
 /// data using Levenberg-Marquardt optimisation with the Hagan
-/// approximation formula.
+/// approximation formula, or with the arbitrage-free PDE when
+/// configured via [`SABRCalibrator::with_method`].
 ///
 /// ## Parameter Bounds
 ///
@@ -220,6 +261,8 @@ pub struct SABRCalibrator {
     calibrator: ModelCalibrator,
     /// Fixed beta value (None means calibrate).
     fixed_beta: Option<f64>,
+    /// Model implied vol formula.
+    method: SABRVolMethod,
 }
 
 impl Default for SABRCalibrator {
@@ -241,6 +284,7 @@ impl SABRCalibrator {
         Self {
             calibrator: ModelCalibrator::new(config),
             fixed_beta: None,
+            method: SABRVolMethod::Hagan,
         }
     }
 
@@ -255,6 +299,7 @@ impl SABRCalibrator {
         Self {
             calibrator: ModelCalibrator::new(config),
             fixed_beta: Some(beta),
+            method: SABRVolMethod::Hagan,
         }
     }
 
@@ -263,14 +308,26 @@ impl SABRCalibrator {
         Self {
             calibrator: ModelCalibrator::new(config),
             fixed_beta,
+            method: SABRVolMethod::Hagan,
         }
     }
 
+    /// Set the implied vol formula used to fit the smile.
+    pub fn with_method(mut self, method: SABRVolMethod) -> Self {
+        self.method = method;
+        self
+    }
+
     /// Get the fixed beta value if any.
     pub fn fixed_beta(&self) -> Option<f64> {
         self.fixed_beta
     }
 
+    /// Get the implied vol formula.
+    pub fn method(&self) -> SABRVolMethod {
+        self.method
+    }
+
     /// Compute SABR implied volatility using Hagan formula.
     pub fn implied_vol(
         forward: f64,
@@ -314,10 +371,8 @@ impl Calibrator for SABRCalibrator {
             return CalibrationResult::not_converged(initial_params, 0, f64::INFINITY, e);
         }
 
-        let forward = market_data.forward;
-        let expiry = market_data.expiry;
-        let atm_vol = market_data.atm_vol;
-        let smile_points = market_data.smile_points.clone();
+        let data = market_data.clone();
+        let method = self.method;
         let fixed_beta = self.fixed_beta.or(market_data.fixed_beta);
 
         let residuals = move |params: &[f64]| {
@@ -327,19 +382,7 @@ impl Calibrator for SABRCalibrator {
                 (params[0], params[1], params[2], params[3])
             };
 
-            let mut resids = Vec::with_capacity(1 + smile_points.len());
-
-            // ATM residual (higher weight for ATM)
-            let atm_model_vol = sabr_hagan_vol(forward, forward, expiry, alpha, beta, rho, nu);
-            resids.push(2.0 * (atm_model_vol - atm_vol));
-
-            // Smile residuals
-            for point in &smile_points {
-                let model_vol = sabr_hagan_vol(forward, point.strike, expiry, alpha, beta, rho, nu);
-                resids.push(point.weight * (model_vol - point.implied_vol));
-            }
-
-            resids
+            sabr_residuals(&method, &data, alpha, beta, rho, nu)
         };
 
         self.calibrator
@@ -352,22 +395,7 @@ impl Calibrator for SABRCalibrator {
         market_data: &Self::MarketData,
     ) -> Vec<f64> {
         let (alpha, beta, rho, nu) = self.extract_params(params, market_data);
-        let forward = market_data.forward;
-        let expiry = market_data.expiry;
-
-        let mut resids = Vec::with_capacity(1 + market_data.smile_points.len());
-
-        // ATM residual
-        let atm_model = sabr_hagan_vol(forward, forward, expiry, alpha, beta, rho, nu);
-        resids.push(2.0 * (atm_model - market_data.atm_vol));
-
-        // Smile residuals
-        for point in &market_data.smile_points {
-            let model_vol = sabr_hagan_vol(forward, point.strike, expiry, alpha, beta, rho, nu);
-            resids.push(point.weight * (model_vol - point.implied_vol));
-        }
-
-        resids
+        sabr_residuals(&self.method, market_data, alpha, beta, rho, nu)
     }
 
     fn constraints(&self) -> Vec<Constraint> {
@@ -449,6 +477,64 @@ pub fn calibrate_sabr_fixed_beta(
     calibrator.calibrate(market_data, initial_params, &CalibrationConfig::default())
 }
 
+/// Weighted vol residuals: ATM first (weight 2), then each smile point.
+///
+/// Strikes the model cannot price give NaN, which the optimiser treats as
+/// an infinite objective.
+fn sabr_residuals(
+    method: &SABRVolMethod,
+    data: &SABRCalibrationData,
+    alpha: f64,
+    beta: f64,
+    rho: f64,
+    nu: f64,
+) -> Vec<f64> {
+    let strikes: Vec<f64> = std::iter::once(data.forward)
+        .chain(data.smile_points.iter().map(|p| p.strike))
+        .collect();
+
+    let model_vols: Vec<f64> = match method {
+        SABRVolMethod::Hagan => {
+            let forward = data.forward + data.shift;
+            strikes
+                .iter()
+                .map(|k| sabr_hagan_vol(forward, k + data.shift, data.expiry, alpha, beta, rho, nu))
+                .collect()
+        }
+        SABRVolMethod::ArbitrageFree(config) => {
+            let model = SABRParams::new_shifted(
+                data.forward,
+                alpha,
+                nu,
+                rho,
+                beta,
+                data.expiry,
+                data.shift,
+            )
+            .and_then(|params| ArbitrageFreeSABR::new(params, *config));
+            match model {
+                Ok(model) => strikes
+                    .iter()
+                    .map(|&k| model.implied_vol(k).unwrap_or(f64::NAN))
+                    .collect(),
+                Err(_) => vec![f64::NAN; strikes.len()],
+            }
+        }
+    };
+
+    let mut resids = Vec::with_capacity(strikes.len());
+
+    // ATM residual (higher weight for ATM)
+    resids.push(2.0 * (model_vols[0] - data.atm_vol));
+
+    // Smile residuals
+    for (point, model_vol) in data.smile_points.iter().zip(&model_vols[1..]) {
+        resids.push(point.weight * (model_vol - point.implied_vol));
+    }
+
+    resids
+}
+
 // =============================================================================
 // Hagan formula implementation
 // =============================================================================
@@ -757,4 +843,94 @@ mod tests {
         let vol_beta1 = sabr_hagan_vol(forward, 0.025, expiry, alpha, 1.0, rho, nu);
         assert!(vol_beta1 > 0.0);
     }
+
+    #[test]
+    fn test_sabr_calibration_data_shift_validation() {
+        let mut data = SABRCalibrationData::new(-0.002, 5.0, 0.3).with_shift(0.02);
+        data.add_smile_point(-0.015, 0.5);
+        assert!(data.validate().is_ok());
+
+        data.add_smile_point(-0.025, 0.5);
+        assert!(data.validate().is_err());
+
+        let data = SABRCalibrationData::new(-0.002, 5.0, 0.3);
+        assert!(data.validate().is_err());
+
+        let data = SABRCalibrationData::new(0.03, 1.0, 0.2).with_shift(-0.01);
+        assert!(data.validate().is_err());
+    }
+
+    #[test]
+    fn test_sabr_calibrator_method() {
+        assert_eq!(SABRCalibrator::new().method(), SABRVolMethod::Hagan);
+
+        let method = SABRVolMethod::ArbitrageFree(SABRPdeConfig::default());
+        let calibrator = SABRCalibrator::with_fixed_beta(0.5).with_method(method);
+        assert_eq!(calibrator.method(), method);
+        assert_eq!(calibrator.fixed_beta(), Some(0.5));
+    }
+
+    #[test]
+    fn test_calibrate_shifted_hagan_negative_rates() {
+        let (forward, shift, expiry, beta) = (-0.002, 0.02, 5.0, 0.5);
+        let (alpha, rho, nu) = (0.012, -0.25, 0.35);
+        let vol = |k: f64| sabr_hagan_vol(forward + shift, k + shift, expiry, alpha, beta, rho, nu);
+
+        let mut data = SABRCalibrationData::new(forward, expiry, vol(forward))
+            .with_fixed_beta(beta)
+            .with_shift(shift);
+        for k in [-0.012, -0.008, -0.004, 0.002, 0.006, 0.012] {
+            data.add_smile_point(k, vol(k));
+        }
+
+        let result = calibrate_sabr(&data, vec![0.02, 0.0, 0.5]);
+        assert!(result.converged);
+        assert!((result.params[0] - alpha).abs() < 1e-4);
+        assert!((result.params[1] - rho).abs() < 1e-3);
+        assert!((result.params[2] - nu).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_calibrate_arbitrage_free_negative_strikes() {
+        let config = SABRPdeConfig::default()
+            .with_grid_points(200)
+            .with_time_steps(40);
+        let (forward, shift, expiry, beta) = (-0.002, 0.02, 10.0, 0.5);
+        let (alpha, rho, nu) = (0.01, -0.3, 0.5);
+
+        let params = SABRParams::new_shifted(forward, alpha, nu, rho, beta, expiry, shift).unwrap();
+        let model = ArbitrageFreeSABR::new(params, config).unwrap();
+
+        // Hagan's density is negative at the lowest of these strikes
+        let mut data =
+            SABRCalibrationData::new(forward, expiry, model.implied_vol(forward).unwrap())
+                .with_fixed_beta(beta)
+                .with_shift(shift);
+        for k in [-0.016, -0.012, -0.008, -0.004, 0.004, 0.01, 0.02] {
+            data.add_smile_point(k, model.implied_vol(k).unwrap());
+        }
+
+        let calibrator =
+            SABRCalibrator::with_fixed_beta(beta).with_method(SABRVolMethod::ArbitrageFree(config));
+        let result =
+            calibrator.calibrate(&data, vec![0.015, 0.0, 0.3], &CalibrationConfig::default());
+
+        assert!(result.converged);
+        assert!((result.params[0] - alpha).abs() < 1e-4);
+        assert!((result.params[1] - rho).abs() < 1e-2);
+        assert!((result.params[2] - nu).abs() < 1e-2);
+
+        let fitted = SABRParams::new_shifted(
+            forward,
+            result.params[0],
+            result.params[2],
+            result.params[1],
+            beta,
+            expiry,
+            shift,
+        )
+        .unwrap();
+        let fitted = ArbitrageFreeSABR::new(fitted, config).unwrap();
+        assert!(fitted.density().iter().all(|&q| q >= 0.0));
+    }
 }
@@ -9,6 +9,13 @@ use pricer_core::traits::calibration::{
 
 use super::{ModelCalibrator, ModelCalibratorConfig};
 
+#[cfg(feature = "rates")]
+use crate::analytical::{Bachelier, BlackScholes};
+#[cfg(feature = "rates")]
+use crate::models::rates::G2PlusPlusParams;
+#[cfg(feature = "rates")]
+use pricer_core::market_data::curves::{CurveEnum, YieldCurve};
+
 /// Type of volatility used in calibration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VolatilityType {
@@ -105,6 +112,8 @@ impl SwaptionMarketData {
 /// The calibrator can be used with different pricing models:
 /// - Hull-White 1F: Calibrate mean reversion and volatility
 /// - SABR: Calibrate alpha, beta, rho, nu
+/// - G2++: Calibrate a, sigma, b, eta, rho against an initial curve
+///   (requires `rates` feature)
 ///
 /// # Example
 ///
@@ -125,6 +134,11 @@ pub struct SwaptionCalibrator {
     param_names: Vec<String>,
     /// Model volatility function.
     model_type: SwaptionModelType,
+    /// Fixed-leg payments per year of the underlying swaps.
+    fixed_frequency: u32,
+    /// Initial discount curve for G2++.
+    #[cfg(feature = "rates")]
+    initial_curve: Option<CurveEnum<f64>>,
 }
 
 /// Type of model to calibrate.
@@ -136,6 +150,9 @@ pub enum SwaptionModelType {
     Sabr,
     /// Flat volatility (for testing).
     FlatVol,
+    /// G2++ two-factor model (requires `rates` feature).
+    #[cfg(feature = "rates")]
+    G2PlusPlus,
 }
 
 impl SwaptionCalibrator {
@@ -147,12 +164,23 @@ impl SwaptionCalibrator {
                 vec!["alpha".into(), "beta".into(), "rho".into(), "nu".into()]
             }
             SwaptionModelType::FlatVol => vec!["vol".into()],
+            #[cfg(feature = "rates")]
+            SwaptionModelType::G2PlusPlus => vec![
+                "a".into(),
+                "sigma".into(),
+                "b".into(),
+                "eta".into(),
+                "rho".into(),
+            ],
         };
 
         Self {
             calibrator: ModelCalibrator::new(config),
             param_names,
             model_type,
+            fixed_frequency: 1,
+            #[cfg(feature = "rates")]
+            initial_curve: None,
         }
     }
 
@@ -178,6 +206,35 @@ impl SwaptionCalibrator {
         Self::new(config, SwaptionModelType::Sabr)
     }
 
+    /// Create a G2++ calibrator against an initial discount curve.
+    ///
+    /// Parameters are ordered `[a, sigma, b, eta, rho]`. Model
+    /// volatilities are implied from the Brigo-Mercurio swaption price
+    /// with the forward swap rate and annuity of `initial_curve`, so the
+    /// forwards in the market data are not used. The fixed leg pays
+    /// annually unless changed with
+    /// [`with_fixed_frequency`](Self::with_fixed_frequency).
+    #[cfg(feature = "rates")]
+    pub fn new_g2pp(initial_curve: CurveEnum<f64>) -> Self {
+        let config = ModelCalibratorConfig::default().with_bounds(vec![
+            ParameterBounds::positive(),         // a > 0
+            ParameterBounds::positive(),         // sigma > 0
+            ParameterBounds::positive(),         // b > 0
+            ParameterBounds::positive(),         // eta > 0
+            ParameterBounds::new(-0.999, 0.999), // rho in (-1, 1)
+        ]);
+
+        let mut calibrator = Self::new(config, SwaptionModelType::G2PlusPlus);
+        calibrator.initial_curve = Some(initial_curve);
+        calibrator
+    }
+
+    /// Set the number of fixed-leg payments per year (default 1).
+    pub fn with_fixed_frequency(mut self, frequency: u32) -> Self {
+        self.fixed_frequency = frequency.max(1);
+        self
+    }
+
     /// Create a flat volatility calibrator (for testing).
     pub fn new_flat_vol() -> Self {
         let config =
@@ -197,7 +254,14 @@ impl SwaptionCalibrator {
     }
 
     /// Compute model volatility for given parameters and market point.
-    fn model_vol(&self, params: &[f64], point: &SwaptionMarketPoint, forward: f64) -> f64 {
+    #[cfg_attr(not(feature = "rates"), allow(unused_variables))]
+    fn model_vol(
+        &self,
+        params: &[f64],
+        point: &SwaptionMarketPoint,
+        forward: f64,
+        vol_type: VolatilityType,
+    ) -> f64 {
         match self.model_type {
             SwaptionModelType::FlatVol => params[0],
             SwaptionModelType::HullWhite1F => {
@@ -224,8 +288,102 @@ impl SwaptionCalibrator {
 
                 sabr_implied_vol(forward, point.strike, point.expiry, alpha, beta, rho, nu)
             }
+            #[cfg(feature = "rates")]
+            SwaptionModelType::G2PlusPlus => self.g2pp_vol(params, point, vol_type),
         }
     }
+
+    /// G2++ implied volatility, or NaN if the parameters are invalid or
+    /// the price cannot be inverted.
+    #[cfg(feature = "rates")]
+    fn g2pp_vol(
+        &self,
+        params: &[f64],
+        point: &SwaptionMarketPoint,
+        vol_type: VolatilityType,
+    ) -> f64 {
+        let Some(curve) = &self.initial_curve else {
+            return f64::NAN;
+        };
+        let Ok(model) = G2PlusPlusParams::new(
+            params[0],
+            params[1],
+            params[2],
+            params[3],
+            params[4],
+            curve.clone(),
+        ) else {
+            return f64::NAN;
+        };
+
+        let frequency = f64::from(self.fixed_frequency);
+        let n_payments = ((point.tenor * frequency).round() as usize).max(1);
+        let payments: Vec<f64> = (1..=n_payments)
+            .map(|i| point.expiry + i as f64 / frequency)
+            .collect();
+        let Ok(discounts) = payments
+            .iter()
+            .map(|&t| curve.discount_factor(t))
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return f64::NAN;
+        };
+        let Ok(start) = curve.discount_factor(point.expiry) else {
+            return f64::NAN;
+        };
+        let annuity: f64 = discounts.iter().sum::<f64>() / frequency;
+        let forward = (start - discounts[n_payments - 1]) / annuity;
+
+        // Price the out-of-the-money side for a stable inversion
+        let is_payer = point.strike >= forward;
+        let Ok(price) = model.swaption(point.expiry, &payments, point.strike, is_payer) else {
+            return f64::NAN;
+        };
+        let target = price / annuity;
+
+        let (expiry, strike) = (point.expiry, point.strike);
+        let undiscounted = |vol: f64| -> f64 {
+            match vol_type {
+                VolatilityType::LogNormal => BlackScholes::new(forward, 0.0, vol)
+                    .map(|bs| {
+                        if is_payer {
+                            bs.price_call(strike, expiry)
+                        } else {
+                            bs.price_put(strike, expiry)
+                        }
+                    })
+                    .unwrap_or(f64::NAN),
+                VolatilityType::Normal => Bachelier::new(forward, vol)
+                    .map(|b| {
+                        if is_payer {
+                            b.price_call(strike, expiry)
+                        } else {
+                            b.price_put(strike, expiry)
+                        }
+                    })
+                    .unwrap_or(f64::NAN),
+            }
+        };
+        let upper = match vol_type {
+            VolatilityType::LogNormal => 5.0,
+            VolatilityType::Normal => 0.5,
+        };
+        // Option prices increase with volatility; bisect the bracket
+        let (mut lo, mut hi) = (0.0, upper);
+        let at_upper = undiscounted(hi);
+        if at_upper.is_nan() || at_upper <= target {
+            return f64::NAN;
+        }
+        for _ in 0..100 {
+            let mid = 0.5 * (lo + hi);
+            if undiscounted(mid) > target {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        0.5 * (lo + hi)
+    }
 }
 
 impl Calibrator for SwaptionCalibrator {
@@ -253,7 +411,7 @@ impl Calibrator for SwaptionCalibrator {
                 .iter()
                 .zip(&market_data.forwards)
                 .map(|(point, &forward)| {
-                    let model_vol = self.model_vol(params, point, forward);
+                    let model_vol = self.model_vol(params, point, forward, market_data.vol_type);
                     point.weight * (model_vol - point.volatility)
                 })
                 .collect()
@@ -273,7 +431,7 @@ impl Calibrator for SwaptionCalibrator {
             .iter()
             .zip(&market_data.forwards)
             .map(|(point, &forward)| {
-                let model_vol = self.model_vol(params, point, forward);
+                let model_vol = self.model_vol(params, point, forward, market_data.vol_type);
                 point.weight * (model_vol - point.volatility)
             })
             .collect()
@@ -291,6 +449,14 @@ impl Calibrator for SwaptionCalibrator {
                 Constraint::bounds(2, -0.999, 0.999), // rho in (-1, 1)
                 Constraint::positive(3),              // nu > 0
             ],
+            #[cfg(feature = "rates")]
+            SwaptionModelType::G2PlusPlus => vec![
+                Constraint::positive(0),              // a > 0
+                Constraint::positive(1),              // sigma > 0
+                Constraint::positive(2),              // b > 0
+                Constraint::positive(3),              // eta > 0
+                Constraint::bounds(4, -0.999, 0.999), // rho in (-1, 1)
+            ],
         }
     }
 }
@@ -496,6 +662,66 @@ mod tests {
         assert_eq!(vol_type, VolatilityType::LogNormal);
     }
 
+    #[cfg(feature = "rates")]
+    fn g2pp_market_data(params: &[f64], vol_type: VolatilityType) -> SwaptionMarketData {
+        // Vols implied by the calibrator itself, on an expiry x tenor matrix
+        let generator = SwaptionCalibrator::new_g2pp(CurveEnum::flat(0.03));
+        let mut data = SwaptionMarketData::new(vol_type);
+        for expiry in [1.0, 2.0, 5.0, 10.0] {
+            for tenor in [1.0, 2.0, 5.0, 10.0] {
+                let mut point = SwaptionMarketPoint::new(expiry, tenor, 0.03, 0.0);
+                point.volatility = generator.model_vol(params, &point, 0.03, vol_type);
+                data.add_point(point, 0.03, 1.0);
+            }
+        }
+        data
+    }
+
+    #[test]
+    #[cfg(feature = "rates")]
+    fn test_g2pp_calibrator() {
+        let calibrator = SwaptionCalibrator::new_g2pp(CurveEnum::flat(0.03));
+        assert_eq!(calibrator.model_type(), SwaptionModelType::G2PlusPlus);
+        assert_eq!(calibrator.param_names().len(), 5);
+        assert_eq!(calibrator.constraints().len(), 5);
+
+        // Normal vol of a short expiry is close to the total short-rate vol
+        let params = [0.5, 0.01, 0.05, 0.008, -0.7];
+        let point = SwaptionMarketPoint::new(0.25, 1.0, 0.03, 0.0);
+        let vol = calibrator.model_vol(&params, &point, 0.03, VolatilityType::Normal);
+        assert!(vol > 0.003 && vol < 0.015, "normal vol {vol}");
+
+        // Invalid parameters give NaN rather than panicking
+        let invalid = [0.5, 0.01, 0.05, 0.008, -1.5];
+        assert!(calibrator
+            .model_vol(&invalid, &point, 0.03, VolatilityType::Normal)
+            .is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "rates")]
+    fn test_calibrate_g2pp_round_trip() {
+        let target = [0.5, 0.01, 0.05, 0.008, -0.7];
+        for vol_type in [VolatilityType::Normal, VolatilityType::LogNormal] {
+            let data = g2pp_market_data(&target, vol_type);
+            let calibrator = SwaptionCalibrator::new_g2pp(CurveEnum::flat(0.03));
+            let result = calibrator.calibrate(
+                &data,
+                vec![0.4, 0.012, 0.08, 0.006, -0.6],
+                &CalibrationConfig::default(),
+            );
+
+            let scale = data.points.iter().map(|p| p.volatility).sum::<f64>() / 16.0;
+            let fit = calibrator.objective_function(&result.params, &data);
+            let max_error = fit.iter().fold(0.0_f64, |m, r| m.max(r.abs()));
+            assert!(
+                max_error < 1e-3 * scale,
+                "{vol_type:?}: max error {max_error}, params {:?}",
+                result.params
+            );
+        }
+    }
+
     #[test]
     fn test_calibrate_sabr_smoke() {
         // Smoke test for SABR calibration
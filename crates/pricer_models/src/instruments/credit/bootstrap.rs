@@ -0,0 +1,396 @@
+//! Credit curve bootstrapping from CDS quotes.
+//!
+//! [`IsdaCreditCurveBuilder`] strips a piecewise-constant
+//! [`HazardRateCurve`] from a term structure of CDS quotes, one pillar per
+//! quote, so that every input contract reprices exactly under the ISDA
+//! Standard Model ([`IsdaCdsPricer`]).
+//!
+//! Quotes may be par spreads, quoted spreads on standard coupons, or
+//! upfronts (see [`CdsQuoteType`]). Quoted spreads are converted to upfronts
+//! first, which is how the standard model defines them.
+//!
+//! # Example
+//!
+//! ```
+//! use pricer_models::instruments::credit::{
+//!     standard_maturity, CdsQuote, CdsQuoteType, IsdaCreditCurveBuilder,
+//! };
+//! use pricer_core::market_data::curves::{CreditCurve, FlatCurve};
+//! use pricer_core::types::time::Date;
+//!
+//! let trade_date = Date::from_ymd(2024, 4, 10).unwrap();
+//! let quotes = [
+//!     CdsQuote::new(standard_maturity(trade_date, 1), CdsQuoteType::ParSpread(0.006)),
+//!     CdsQuote::new(standard_maturity(trade_date, 3), CdsQuoteType::ParSpread(0.009)),
+//!     CdsQuote::new(standard_maturity(trade_date, 5), CdsQuoteType::ParSpread(0.012)),
+//! ];
+//!
+//! let discount = FlatCurve::new(0.03);
+//! let builder = IsdaCreditCurveBuilder::new(trade_date, 0.4);
+//! let curve = builder.build(&quotes, &discount).unwrap();
+//!
+//! assert_eq!(curve.len(), 3);
+//! assert!(curve.survival_probability(5.0).unwrap() < 1.0);
+//! ```
+
+use pricer_core::market_data::curves::{HazardRateCurve, YieldCurve};
+use pricer_core::math::solvers::{BrentSolver, SolverConfig};
+use pricer_core::types::time::Date;
+
+use super::cds::CdsDirection;
+use super::isda::{upfront_from_quoted_spread, IsdaCdsContract, IsdaCdsPricer};
+use crate::calibration::CalibrationError;
+
+/// Upper bracket for hazard rate root searches.
+const MAX_HAZARD_RATE: f64 = 10.0;
+
+/// Market quote convention for a single CDS.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CdsQuoteType {
+    /// Par spread: the running coupon at which the upfront is zero.
+    ParSpread(f64),
+    /// Quoted (flat) spread on a contract paying a standard coupon.
+    QuotedSpread {
+        /// Quoted spread
+        spread: f64,
+        /// Running coupon of the traded contract
+        coupon: f64,
+    },
+    /// Clean upfront (protection buyer, fraction of notional) on a contract
+    /// paying a standard coupon.
+    Upfront {
+        /// Points upfront as a fraction of notional
+        upfront: f64,
+        /// Running coupon of the traded contract
+        coupon: f64,
+    },
+}
+
+/// A CDS quote for a given maturity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CdsQuote {
+    /// Scheduled termination date of the quoted contract.
+    pub maturity: Date,
+    /// Quote value and convention.
+    pub quote: CdsQuoteType,
+}
+
+impl CdsQuote {
+    /// Create a new CDS quote.
+    pub fn new(maturity: Date, quote: CdsQuoteType) -> Self {
+        Self { maturity, quote }
+    }
+}
+
+/// Bootstrapper for piecewise-constant hazard rate curves (ISDA Standard Model).
+///
+/// Each quote adds one pillar at its maturity; the hazard rate on the new
+/// interval is solved with Brent's method holding earlier pillars fixed.
+#[derive(Debug, Clone)]
+pub struct IsdaCreditCurveBuilder {
+    /// Trade date (curve base date).
+    trade_date: Date,
+    /// Recovery rate assumed for all quotes.
+    recovery_rate: f64,
+    /// Discount curve pillar times added to the integration grid.
+    discount_knots: Vec<f64>,
+    /// Root-finding tolerance on the repricing error.
+    tolerance: f64,
+}
+
+impl IsdaCreditCurveBuilder {
+    /// Create a new builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_date` - Trade date (curve base date)
+    /// * `recovery_rate` - Recovery rate assumption (0.4 for senior unsecured)
+    pub fn new(trade_date: Date, recovery_rate: f64) -> Self {
+        Self {
+            trade_date,
+            recovery_rate,
+            discount_knots: Vec::new(),
+            tolerance: 1e-14,
+        }
+    }
+
+    /// Set discount curve pillar times (years from trade date).
+    ///
+    /// Including them makes the leg integrals exact for piecewise-flat
+    /// forward discount curves, as in the ISDA reference implementation.
+    pub fn with_discount_knots(mut self, knots: &[f64]) -> Self {
+        self.discount_knots = knots.to_vec();
+        self
+    }
+
+    /// Set the root-finding tolerance.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Build the contract a quote refers to.
+    pub fn contract(&self, quote: &CdsQuote) -> Result<IsdaCdsContract<f64>, CalibrationError> {
+        let coupon = match quote.quote {
+            CdsQuoteType::ParSpread(s) => s,
+            CdsQuoteType::QuotedSpread { coupon, .. } | CdsQuoteType::Upfront { coupon, .. } => {
+                coupon
+            }
+        };
+        IsdaCdsContract::new(
+            self.trade_date,
+            quote.maturity,
+            coupon,
+            self.recovery_rate,
+            1.0,
+            CdsDirection::BuyProtection,
+        )
+        .map_err(|e| CalibrationError::invalid_market_data(e.to_string()))
+    }
+
+    /// Bootstrap a hazard rate curve repricing every quote.
+    ///
+    /// # Arguments
+    ///
+    /// * `quotes` - CDS quotes (any order; distinct maturities)
+    /// * `discount_curve` - Discount curve
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalibrationError` if there are no quotes, maturities repeat,
+    /// or a quote cannot be matched with a non-negative hazard rate.
+    pub fn build<D: YieldCurve<f64>>(
+        &self,
+        quotes: &[CdsQuote],
+        discount_curve: &D,
+    ) -> Result<HazardRateCurve<f64>, CalibrationError> {
+        if quotes.is_empty() {
+            return Err(CalibrationError::insufficient_data(1, 0));
+        }
+
+        let mut sorted = quotes.to_vec();
+        sorted.sort_by_key(|q| q.maturity);
+        if sorted.windows(2).any(|w| w[0].maturity == w[1].maturity) {
+            return Err(CalibrationError::invalid_market_data(
+                "CDS quotes must have distinct maturities",
+            ));
+        }
+
+        let solver = BrentSolver::new(SolverConfig::new(self.tolerance, 200));
+        let mut tenors = Vec::with_capacity(sorted.len());
+        let mut hazards = Vec::with_capacity(sorted.len());
+
+        for quote in &sorted {
+            let cds = self.contract(quote)?;
+            let target_upfront = match quote.quote {
+                CdsQuoteType::ParSpread(_) => 0.0,
+                CdsQuoteType::Upfront { upfront, .. } => upfront,
+                CdsQuoteType::QuotedSpread { spread, .. } => {
+                    upfront_from_quoted_spread(&cds, spread, discount_curve)
+                        .map_err(|e| CalibrationError::invalid_market_data(e.to_string()))?
+                }
+            };
+
+            tenors.push(cds.time(cds.maturity()));
+            hazards.push(0.0);
+            let knots: Vec<f64> = tenors
+                .iter()
+                .chain(self.discount_knots.iter())
+                .copied()
+                .collect();
+
+            // Brent's interpolation steps may probe outside the bracket;
+            // clamp so trial curves always carry admissible hazard rates.
+            let objective = |h: f64| -> f64 {
+                let mut trial = hazards.clone();
+                *trial.last_mut().unwrap() = h.clamp(0.0, MAX_HAZARD_RATE);
+                HazardRateCurve::piecewise_constant(&tenors, &trial)
+                    .ok()
+                    .and_then(|curve| {
+                        IsdaCdsPricer::new(discount_curve, &curve)
+                            .with_knots(&knots)
+                            .upfront(&cds)
+                            .ok()
+                    })
+                    .map_or(f64::NAN, |u| u - target_upfront)
+            };
+
+            let h = solver
+                .find_root(objective, 0.0, MAX_HAZARD_RATE)
+                .map_err(|e| {
+                    CalibrationError::invalid_market_data(format!(
+                        "Cannot bootstrap CDS quote maturing {}: {}",
+                        quote.maturity, e
+                    ))
+                })?;
+            *hazards.last_mut().unwrap() = h.clamp(0.0, MAX_HAZARD_RATE);
+        }
+
+        HazardRateCurve::piecewise_constant(&tenors, &hazards)
+            .map_err(|e| CalibrationError::numerical_instability(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::credit::isda::{standard_maturity, StandardCoupon};
+    use pricer_core::market_data::curves::{CurveInterpolation, FlatCurve, InterpolatedCurve};
+
+    fn trade_date() -> Date {
+        Date::from_ymd(2024, 4, 10).unwrap()
+    }
+
+    fn par_quotes() -> Vec<CdsQuote> {
+        [
+            (1, 0.006),
+            (2, 0.0075),
+            (3, 0.009),
+            (5, 0.012),
+            (7, 0.0135),
+            (10, 0.0165),
+        ]
+        .iter()
+        .map(|&(y, s)| {
+            CdsQuote::new(
+                standard_maturity(trade_date(), y),
+                CdsQuoteType::ParSpread(s),
+            )
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_bootstrap_reprices_par_spreads() {
+        let discount = FlatCurve::new(0.03);
+        let builder = IsdaCreditCurveBuilder::new(trade_date(), 0.4);
+        let quotes = par_quotes();
+        let curve = builder.build(&quotes, &discount).unwrap();
+
+        assert_eq!(curve.len(), quotes.len());
+        for q in &quotes {
+            let cds = builder.contract(q).unwrap();
+            let par = IsdaCdsPricer::new(&discount, &curve)
+                .with_knots(curve.tenors())
+                .par_spread(&cds)
+                .unwrap();
+            let CdsQuoteType::ParSpread(s) = q.quote else {
+                unreachable!()
+            };
+            assert!((par - s).abs() < 1e-12, "par {} vs quote {}", par, s);
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_upward_sloping_spreads_give_increasing_hazards() {
+        let discount = FlatCurve::new(0.03);
+        let curve = IsdaCreditCurveBuilder::new(trade_date(), 0.4)
+            .build(&par_quotes(), &discount)
+            .unwrap();
+        for w in curve.hazard_rates().windows(2) {
+            assert!(w[1] > w[0]);
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_upfront_and_quoted_spread_quotes() {
+        let discount = FlatCurve::new(0.02);
+        let builder = IsdaCreditCurveBuilder::new(trade_date(), 0.25);
+        let c = StandardCoupon::Bp500.rate();
+        let quotes = vec![
+            CdsQuote::new(
+                standard_maturity(trade_date(), 1),
+                CdsQuoteType::Upfront {
+                    upfront: -0.02,
+                    coupon: c,
+                },
+            ),
+            CdsQuote::new(
+                standard_maturity(trade_date(), 3),
+                CdsQuoteType::QuotedSpread {
+                    spread: 0.045,
+                    coupon: c,
+                },
+            ),
+            CdsQuote::new(
+                standard_maturity(trade_date(), 5),
+                CdsQuoteType::Upfront {
+                    upfront: 0.03,
+                    coupon: c,
+                },
+            ),
+        ];
+        let curve = builder.build(&quotes, &discount).unwrap();
+
+        let pricer = IsdaCdsPricer::new(&discount, &curve).with_knots(curve.tenors());
+        let u1 = pricer
+            .upfront(&builder.contract(&quotes[0]).unwrap())
+            .unwrap();
+        let u5 = pricer
+            .upfront(&builder.contract(&quotes[2]).unwrap())
+            .unwrap();
+        assert!((u1 + 0.02).abs() < 1e-12);
+        assert!((u5 - 0.03).abs() < 1e-12);
+
+        let cds3 = builder.contract(&quotes[1]).unwrap();
+        let expected = upfront_from_quoted_spread(&cds3, 0.045, &discount).unwrap();
+        assert!((pricer.upfront(&cds3).unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bootstrap_with_interpolated_discount_curve() {
+        let tenors = [0.5, 1.0, 2.0, 5.0, 10.0];
+        let discount = InterpolatedCurve::new(
+            &tenors,
+            &[0.045, 0.043, 0.04, 0.038, 0.039],
+            CurveInterpolation::LogLinear,
+            true,
+        )
+        .unwrap();
+        let builder = IsdaCreditCurveBuilder::new(trade_date(), 0.4).with_discount_knots(&tenors);
+        let quotes = par_quotes();
+        let curve = builder.build(&quotes, &discount).unwrap();
+
+        let knots: Vec<f64> = curve
+            .tenors()
+            .iter()
+            .chain(tenors.iter())
+            .copied()
+            .collect();
+        for q in &quotes {
+            let cds = builder.contract(q).unwrap();
+            let par = IsdaCdsPricer::new(&discount, &curve)
+                .with_knots(&knots)
+                .par_spread(&cds)
+                .unwrap();
+            let CdsQuoteType::ParSpread(s) = q.quote else {
+                unreachable!()
+            };
+            assert!((par - s).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_errors() {
+        let discount = FlatCurve::new(0.03);
+        let builder = IsdaCreditCurveBuilder::new(trade_date(), 0.4);
+        assert!(builder.build(&[], &discount).is_err());
+
+        let m = standard_maturity(trade_date(), 5);
+        let dup = [
+            CdsQuote::new(m, CdsQuoteType::ParSpread(0.01)),
+            CdsQuote::new(m, CdsQuoteType::ParSpread(0.02)),
+        ];
+        assert!(builder.build(&dup, &discount).is_err());
+
+        // Upfront below the zero-hazard value cannot be matched
+        let bad = [CdsQuote::new(
+            m,
+            CdsQuoteType::Upfront {
+                upfront: -0.5,
+                coupon: 0.01,
+            },
+        )];
+        assert!(builder.build(&bad, &discount).is_err());
+    }
+}
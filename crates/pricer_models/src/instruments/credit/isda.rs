@@ -0,0 +1,983 @@
+//! ISDA CDS Standard Model.
+//!
+//! This module implements the conventions and valuation of the ISDA CDS
+//! Standard Model used to quote single-name CDS since the 2009 "Big Bang":
+//!
+//! - **IMM dates**: coupons accrue between the 20th of March, June,
+//!   September and December, with payments rolled to the following
+//!   business day
+//! - **Standard maturities**: semi-annual roll to 20 June / 20 December
+//! - **Standard coupons**: 100bp or 500bp ([`StandardCoupon`])
+//! - **Step-in date**: protection starts on T+1; cash settlement on T+3
+//!   business days
+//! - **Accrual on default**: premium accrued since the last coupon date is
+//!   paid on default
+//!
+//! Both legs are integrated exactly under the model assumptions of
+//! piecewise-constant hazard and forward rates between grid points, which is
+//! what makes quotes reprice to machine precision after bootstrapping.
+//!
+//! # Quote Conventions
+//!
+//! ```text
+//! RPV01_clean = RPV01_dirty − accrued
+//! ParSpread   = ProtectionLeg / RPV01_clean
+//! Upfront     = ProtectionLeg − Coupon × RPV01_clean      (protection buyer)
+//! ```
+//!
+//! A *quoted spread* is the flat-hazard-curve spread equivalent to an
+//! upfront on a standard-coupon contract; see [`upfront_from_quoted_spread`]
+//! and [`quoted_spread_from_upfront`].
+//!
+//! # Example
+//!
+//! ```
+//! use pricer_models::instruments::credit::{
+//!     CdsDirection, IsdaCdsContract, IsdaCdsPricer, StandardCoupon,
+//! };
+//! use pricer_core::market_data::curves::{FlatCurve, FlatHazardRateCurve};
+//! use pricer_core::types::time::Date;
+//!
+//! let trade_date = Date::from_ymd(2024, 4, 10).unwrap();
+//! let cds = IsdaCdsContract::standard(
+//!     trade_date,
+//!     5,
+//!     StandardCoupon::Bp100,
+//!     0.4,
+//!     10_000_000.0,
+//!     CdsDirection::BuyProtection,
+//! )
+//! .unwrap();
+//! assert_eq!(cds.maturity(), Date::from_ymd(2029, 6, 20).unwrap());
+//!
+//! let discount = FlatCurve::new(0.03_f64);
+//! let credit = FlatHazardRateCurve::new(0.02);
+//! let pricer = IsdaCdsPricer::new(&discount, &credit);
+//!
+//! let par = pricer.par_spread(&cds).unwrap();
+//! assert!((par - 0.02 * 0.6).abs() < 5e-4); // credit triangle
+//! ```
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use num_traits::Float;
+use pricer_core::market_data::curves::{CreditCurve, FlatHazardRateCurve, YieldCurve};
+use pricer_core::market_data::error::MarketDataError;
+use pricer_core::math::solvers::{BrentSolver, SolverConfig};
+use pricer_core::types::time::{Date, DayCountConvention};
+
+use super::cds::CdsDirection;
+use crate::instruments::error::InstrumentError;
+use crate::schedules::Period;
+
+/// Days in the ACT/360 premium day count year.
+const PREMIUM_DAYS_PER_YEAR: f64 = 360.0;
+
+/// Days in the ACT/365F curve time year.
+const CURVE_DAYS_PER_YEAR: f64 = 365.0;
+
+/// Business days between trade date and cash settlement.
+const CASH_SETTLE_LAG: u32 = 3;
+
+/// Upper bracket for flat hazard rate root searches.
+const MAX_HAZARD_RATE: f64 = 10.0;
+
+/// Standardised fixed coupons for single-name CDS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StandardCoupon {
+    /// 100bp running coupon (investment grade).
+    Bp100,
+    /// 500bp running coupon (high yield).
+    Bp500,
+}
+
+impl StandardCoupon {
+    /// Return the coupon as a decimal rate.
+    #[inline]
+    pub fn rate(&self) -> f64 {
+        match self {
+            StandardCoupon::Bp100 => 0.01,
+            StandardCoupon::Bp500 => 0.05,
+        }
+    }
+}
+
+// ================================================================
+// Date conventions
+// ================================================================
+
+fn to_date(d: NaiveDate) -> Date {
+    Date::from_ymd(d.year(), d.month(), d.day()).expect("NaiveDate is always a valid Date")
+}
+
+fn add_days(date: Date, days: u64) -> Date {
+    to_date(date.into_inner() + Days::new(days))
+}
+
+fn previous_day(date: Date) -> Date {
+    to_date(date.into_inner().pred_opt().unwrap())
+}
+
+fn is_weekend(date: Date) -> bool {
+    matches!(date.into_inner().weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Roll a date forward to the next business day (weekends only).
+pub fn adjust_following(date: Date) -> Date {
+    let mut d = date;
+    while is_weekend(d) {
+        d = add_days(d, 1);
+    }
+    d
+}
+
+/// Add `n` business days (weekends only) to a date.
+pub fn add_business_days(date: Date, n: u32) -> Date {
+    let mut d = date;
+    let mut remaining = n;
+    while remaining > 0 {
+        d = add_days(d, 1);
+        if !is_weekend(d) {
+            remaining -= 1;
+        }
+    }
+    d
+}
+
+/// Return whether a date is an (unadjusted) IMM date: the 20th of
+/// March, June, September or December.
+pub fn is_imm_date(date: Date) -> bool {
+    date.day() == 20 && date.month() % 3 == 0
+}
+
+/// Return the first unadjusted IMM date strictly after `date`.
+pub fn next_imm_date(date: Date) -> Date {
+    let (y, m, d) = (date.year(), date.month(), date.day());
+    let quarter_month = m.div_ceil(3) * 3;
+    if m < quarter_month || (m == quarter_month && d < 20) {
+        Date::from_ymd(y, quarter_month, 20).unwrap()
+    } else if quarter_month == 12 {
+        Date::from_ymd(y + 1, 3, 20).unwrap()
+    } else {
+        Date::from_ymd(y, quarter_month + 3, 20).unwrap()
+    }
+}
+
+/// Return the last unadjusted IMM date on or before `date`.
+pub fn previous_imm_date(date: Date) -> Date {
+    if is_imm_date(date) {
+        return date;
+    }
+    let next = next_imm_date(date).into_inner();
+    to_date(next.checked_sub_months(Months::new(3)).unwrap())
+}
+
+/// Return the semi-annual CDS roll date applying to a trade date.
+///
+/// Under the post-2015 convention, on-the-run contracts roll on 20 March
+/// (to a 20 June maturity) and 20 September (to a 20 December maturity).
+pub fn cds_roll_date(trade_date: Date) -> Date {
+    let (y, m, d) = (trade_date.year(), trade_date.month(), trade_date.day());
+    let md = (m, d);
+    if md < (3, 20) {
+        Date::from_ymd(y - 1, 12, 20).unwrap()
+    } else if md < (9, 20) {
+        Date::from_ymd(y, 6, 20).unwrap()
+    } else {
+        Date::from_ymd(y, 12, 20).unwrap()
+    }
+}
+
+/// Return the standard maturity of an on-the-run CDS with `tenor_years`.
+///
+/// # Example
+///
+/// ```
+/// use pricer_models::instruments::credit::standard_maturity;
+/// use pricer_core::types::time::Date;
+///
+/// let t = Date::from_ymd(2024, 10, 1).unwrap();
+/// assert_eq!(standard_maturity(t, 5), Date::from_ymd(2029, 12, 20).unwrap());
+/// ```
+pub fn standard_maturity(trade_date: Date, tenor_years: u32) -> Date {
+    let roll = cds_roll_date(trade_date).into_inner();
+    to_date(
+        roll.checked_add_months(Months::new(12 * tenor_years))
+            .unwrap(),
+    )
+}
+
+// ================================================================
+// Contract
+// ================================================================
+
+/// Single-name CDS contract under ISDA Standard Model conventions.
+///
+/// # Type Parameters
+///
+/// * `T` - Floating-point type implementing `Float` (e.g., `f64`, `Dual64`)
+#[derive(Debug, Clone)]
+pub struct IsdaCdsContract<T: Float> {
+    /// Trade date (curve base date, t = 0).
+    trade_date: Date,
+    /// Scheduled termination date (unadjusted).
+    maturity: Date,
+    /// Fixed running coupon.
+    coupon: T,
+    /// Recovery rate assumption.
+    recovery_rate: T,
+    /// Notional principal amount.
+    notional: T,
+    /// Buy or sell protection.
+    direction: CdsDirection,
+}
+
+impl<T: Float> IsdaCdsContract<T> {
+    /// Create a new ISDA CDS contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_date` - Trade date (valuation base date)
+    /// * `maturity` - Scheduled termination date
+    /// * `coupon` - Running coupon (e.g., 0.01 for 100bp)
+    /// * `recovery_rate` - Recovery rate in `[0, 1)`
+    /// * `notional` - Notional principal amount
+    /// * `direction` - Buy or sell protection
+    ///
+    /// # Errors
+    ///
+    /// Returns `InstrumentError` if the maturity is not after the step-in
+    /// date, the recovery rate is outside `[0, 1)`, or the coupon is negative.
+    pub fn new(
+        trade_date: Date,
+        maturity: Date,
+        coupon: T,
+        recovery_rate: T,
+        notional: T,
+        direction: CdsDirection,
+    ) -> Result<Self, InstrumentError> {
+        if maturity <= add_days(trade_date, 1) {
+            return Err(InstrumentError::InvalidParameter {
+                message: format!("CDS maturity {} must be after step-in date", maturity),
+            });
+        }
+        if recovery_rate < T::zero() || recovery_rate >= T::one() {
+            return Err(InstrumentError::InvalidParameter {
+                message: format!(
+                    "Recovery rate must be in [0, 1), got {}",
+                    recovery_rate.to_f64().unwrap_or(f64::NAN)
+                ),
+            });
+        }
+        if coupon < T::zero() {
+            return Err(InstrumentError::InvalidParameter {
+                message: "CDS coupon must be non-negative".to_string(),
+            });
+        }
+        Ok(Self {
+            trade_date,
+            maturity,
+            coupon,
+            recovery_rate,
+            notional,
+            direction,
+        })
+    }
+
+    /// Create an on-the-run standard contract.
+    ///
+    /// The maturity follows the semi-annual roll convention
+    /// ([`standard_maturity`]) and the coupon is a [`StandardCoupon`].
+    pub fn standard(
+        trade_date: Date,
+        tenor_years: u32,
+        coupon: StandardCoupon,
+        recovery_rate: T,
+        notional: T,
+        direction: CdsDirection,
+    ) -> Result<Self, InstrumentError> {
+        Self::new(
+            trade_date,
+            standard_maturity(trade_date, tenor_years),
+            T::from(coupon.rate()).unwrap(),
+            recovery_rate,
+            notional,
+            direction,
+        )
+    }
+
+    /// Return a copy of this contract with a different running coupon.
+    pub fn with_coupon(&self, coupon: T) -> Self {
+        Self {
+            coupon,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the trade date.
+    #[inline]
+    pub fn trade_date(&self) -> Date {
+        self.trade_date
+    }
+
+    /// Returns the scheduled termination date.
+    #[inline]
+    pub fn maturity(&self) -> Date {
+        self.maturity
+    }
+
+    /// Returns the running coupon.
+    #[inline]
+    pub fn coupon(&self) -> T {
+        self.coupon
+    }
+
+    /// Returns the recovery rate.
+    #[inline]
+    pub fn recovery_rate(&self) -> T {
+        self.recovery_rate
+    }
+
+    /// Returns the notional.
+    #[inline]
+    pub fn notional(&self) -> T {
+        self.notional
+    }
+
+    /// Returns the direction.
+    #[inline]
+    pub fn direction(&self) -> CdsDirection {
+        self.direction
+    }
+
+    /// Returns the step-in date (T+1 calendar day), when protection starts.
+    #[inline]
+    pub fn step_in_date(&self) -> Date {
+        add_days(self.trade_date, 1)
+    }
+
+    /// Returns the cash settlement date (T+3 business days).
+    #[inline]
+    pub fn cash_settle_date(&self) -> Date {
+        add_business_days(self.trade_date, CASH_SETTLE_LAG)
+    }
+
+    /// Returns the accrual start date: the last adjusted IMM date on or
+    /// before the step-in date.
+    pub fn accrual_start_date(&self) -> Date {
+        let step_in = self.step_in_date();
+        let mut imm = previous_imm_date(step_in);
+        while adjust_following(imm) > step_in {
+            imm = previous_imm_date(previous_day(imm));
+        }
+        adjust_following(imm)
+    }
+
+    /// Returns the premium accrual periods.
+    ///
+    /// Accrual dates are IMM dates adjusted to the following business day;
+    /// the final period accrues through the maturity date inclusive (its end
+    /// date is maturity + 1) and pays on the adjusted maturity.
+    pub fn coupon_periods(&self) -> Vec<Period> {
+        let dc = DayCountConvention::ActualActual360;
+        let mut periods = Vec::new();
+        let mut start = self.accrual_start_date();
+        let mut imm = next_imm_date(start);
+
+        while imm < self.maturity {
+            let end = adjust_following(imm);
+            periods.push(Period::new(start, end, end, dc));
+            start = end;
+            imm = next_imm_date(imm);
+        }
+
+        let last_end = add_days(self.maturity, 1);
+        periods.push(Period::new(
+            start,
+            last_end,
+            adjust_following(self.maturity),
+            dc,
+        ));
+        periods
+    }
+
+    /// Returns the premium accrued from the accrual start to the step-in
+    /// date, per unit notional and unit coupon.
+    pub fn accrued_year_fraction(&self) -> T {
+        let days = self.step_in_date() - self.accrual_start_date();
+        T::from(days as f64 / PREMIUM_DAYS_PER_YEAR).unwrap()
+    }
+
+    /// Returns the curve time (ACT/365F from trade date) of a date.
+    #[inline]
+    pub fn time(&self, date: Date) -> T {
+        T::from((date - self.trade_date) as f64 / CURVE_DAYS_PER_YEAR).unwrap()
+    }
+}
+
+// ================================================================
+// Pricer
+// ================================================================
+
+/// CDS values under the ISDA Standard Model, per unit notional and
+/// expressed at the cash settlement date.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IsdaCdsValuation<T: Float> {
+    /// Protection leg value (LGD-weighted).
+    pub protection_leg: T,
+    /// Dirty risky annuity (includes the full first coupon and accrual on default).
+    pub rpv01_dirty: T,
+    /// Clean risky annuity (dirty minus accrued).
+    pub rpv01_clean: T,
+    /// Par spread: protection leg over clean risky annuity.
+    pub par_spread: T,
+    /// Clean upfront for the protection buyer, as a fraction of notional.
+    pub upfront: T,
+}
+
+/// CDS pricer implementing the ISDA Standard Model.
+///
+/// # Type Parameters
+///
+/// * `T` - Floating-point type implementing `Float`
+/// * `D` - Discount curve implementing `YieldCurve<T>`
+/// * `C` - Credit curve implementing `CreditCurve<T>`
+pub struct IsdaCdsPricer<'a, T: Float, D: YieldCurve<T>, C: CreditCurve<T>> {
+    /// Discount curve for present value calculations.
+    discount_curve: &'a D,
+    /// Credit curve for survival probability calculations.
+    credit_curve: &'a C,
+    /// Additional integration knots (curve pillar times).
+    knots: Vec<T>,
+}
+
+impl<'a, T: Float, D: YieldCurve<T>, C: CreditCurve<T>> IsdaCdsPricer<'a, T, D, C> {
+    /// Create a new ISDA CDS pricer.
+    ///
+    /// # Arguments
+    ///
+    /// * `discount_curve` - Discount curve for discounting
+    /// * `credit_curve` - Credit curve for survival probabilities
+    pub fn new(discount_curve: &'a D, credit_curve: &'a C) -> Self {
+        Self {
+            discount_curve,
+            credit_curve,
+            knots: Vec::new(),
+        }
+    }
+
+    /// Add curve pillar times to the integration grid.
+    ///
+    /// The ISDA integrals are exact when every hazard and discount curve
+    /// knot inside the protection period is a grid point.
+    pub fn with_knots(mut self, knots: &[T]) -> Self {
+        self.knots = knots.to_vec();
+        self
+    }
+
+    /// Value a contract, per unit notional, at the cash settlement date.
+    pub fn value(&self, cds: &IsdaCdsContract<T>) -> Result<IsdaCdsValuation<T>, MarketDataError> {
+        let df_settle = self
+            .discount_curve
+            .discount_factor(cds.time(cds.cash_settle_date()))?;
+
+        let protection_leg = self.protection_leg_raw(cds)? / df_settle;
+        let rpv01_dirty = self.rpv01_raw(cds)? / df_settle;
+        let rpv01_clean = rpv01_dirty - cds.accrued_year_fraction();
+
+        let par_spread = if rpv01_clean > T::zero() {
+            protection_leg / rpv01_clean
+        } else {
+            T::zero()
+        };
+
+        Ok(IsdaCdsValuation {
+            protection_leg,
+            rpv01_dirty,
+            rpv01_clean,
+            par_spread,
+            upfront: protection_leg - cds.coupon() * rpv01_clean,
+        })
+    }
+
+    /// Return the par spread of the contract.
+    pub fn par_spread(&self, cds: &IsdaCdsContract<T>) -> Result<T, MarketDataError> {
+        Ok(self.value(cds)?.par_spread)
+    }
+
+    /// Return the clean upfront (protection buyer, fraction of notional).
+    pub fn upfront(&self, cds: &IsdaCdsContract<T>) -> Result<T, MarketDataError> {
+        Ok(self.value(cds)?.upfront)
+    }
+
+    /// Return the clean present value for the contract's direction.
+    pub fn clean_pv(&self, cds: &IsdaCdsContract<T>) -> Result<T, MarketDataError> {
+        let v = self.value(cds)?;
+        Ok(v.upfront * cds.notional() * cds.direction().protection_multiplier())
+    }
+
+    /// Return the dirty present value for the contract's direction.
+    ///
+    /// This is the cash amount exchanged at settlement, including the
+    /// accrued premium rebated to the protection buyer.
+    pub fn dirty_pv(&self, cds: &IsdaCdsContract<T>) -> Result<T, MarketDataError> {
+        let v = self.value(cds)?;
+        let dirty = v.protection_leg - cds.coupon() * v.rpv01_dirty;
+        Ok(dirty * cds.notional() * cds.direction().protection_multiplier())
+    }
+
+    /// Sorted integration grid between `start` and `end` (inclusive).
+    fn grid(&self, cds: &IsdaCdsContract<T>, start: T, end: T) -> Vec<T> {
+        let mut grid = vec![start];
+        let inner = cds
+            .coupon_periods()
+            .iter()
+            .map(|p| cds.time(p.end()))
+            .chain(self.knots.iter().copied())
+            .filter(|&t| t > start && t < end)
+            .collect::<Vec<_>>();
+        grid.extend(inner);
+        grid.push(end);
+        grid.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        grid.dedup();
+        grid
+    }
+
+    /// Discounted survival-weighted value P(t) = DF(t) Q(t).
+    fn df_and_survival(&self, t: T) -> Result<(T, T), MarketDataError> {
+        Ok((
+            self.discount_curve.discount_factor(t)?,
+            self.credit_curve.survival_probability(t)?,
+        ))
+    }
+
+    /// Protection leg at trade date per unit notional.
+    ///
+    /// On each sub-interval with constant hazard λ and forward f:
+    /// `∫ λ e^{-(λ+f)u} P_s du = λ/(λ+f) (P_s − P_e)`.
+    fn protection_leg_raw(&self, cds: &IsdaCdsContract<T>) -> Result<T, MarketDataError> {
+        let start = cds.time(cds.step_in_date());
+        let end = cds.time(cds.maturity());
+        let grid = self.grid(cds, start, end);
+
+        let mut pv = T::zero();
+        let (mut df_s, mut q_s) = self.df_and_survival(start)?;
+        for &t in grid.iter().skip(1) {
+            let (df_e, q_e) = self.df_and_survival(t)?;
+            let a = (q_s / q_e).ln();
+            let b = (df_s / df_e).ln();
+            let p_s = df_s * q_s;
+            let p_e = df_e * q_e;
+            pv = pv
+                + if (a + b).abs() < T::from(1e-12).unwrap() {
+                    a * p_s
+                } else {
+                    a / (a + b) * (p_s - p_e)
+                };
+            df_s = df_e;
+            q_s = q_e;
+        }
+
+        Ok(pv * (T::one() - cds.recovery_rate()))
+    }
+
+    /// Dirty risky annuity at trade date per unit notional and coupon.
+    fn rpv01_raw(&self, cds: &IsdaCdsContract<T>) -> Result<T, MarketDataError> {
+        let step_in = cds.time(cds.step_in_date());
+        let k = T::from(CURVE_DAYS_PER_YEAR / PREMIUM_DAYS_PER_YEAR).unwrap();
+        let mut rpv01 = T::zero();
+
+        for period in cds.coupon_periods() {
+            let acc_start = cds.time(period.start());
+            // Protection is observed at the end of the last accrued day
+            let obs_end = cds.time(previous_day(period.end()));
+            let pay = cds.time(period.payment());
+            let yf = T::from(period.year_fraction()).unwrap();
+
+            let df_pay = self.discount_curve.discount_factor(pay)?;
+            let q_end = self.credit_curve.survival_probability(obs_end)?;
+            rpv01 = rpv01 + yf * df_pay * q_end;
+
+            // Accrual on default over [max(acc_start, step_in), obs_end]
+            let from = if acc_start > step_in {
+                acc_start
+            } else {
+                step_in
+            };
+            if from >= obs_end {
+                continue;
+            }
+            let grid = self.grid(cds, from, obs_end);
+            let (mut df_s, mut q_s) = self.df_and_survival(from)?;
+            for w in grid.windows(2) {
+                let (s, e) = (w[0], w[1]);
+                let (df_e, q_e) = self.df_and_survival(e)?;
+                let dt = e - s;
+                let a = (q_s / q_e).ln();
+                let b = (df_s / df_e).ln();
+                let p_s = df_s * q_s;
+                let tau_s = (s - acc_start) * k;
+                let lambda = a / dt;
+                let r = (a + b) / dt;
+                let x = r * dt;
+
+                // ∫₀^dt (τ_s + k u) e^{-r u} du
+                let (i0, i1) = if x.abs() < T::from(1e-8).unwrap() {
+                    (dt, dt * dt / (T::one() + T::one()))
+                } else {
+                    let e_x = (-x).exp();
+                    (
+                        (T::one() - e_x) / r,
+                        (T::one() - e_x * (T::one() + x)) / (r * r),
+                    )
+                };
+
+                rpv01 = rpv01 + lambda * p_s * (tau_s * i0 + k * i1);
+                df_s = df_e;
+                q_s = q_e;
+            }
+        }
+
+        Ok(rpv01)
+    }
+}
+
+// ================================================================
+// Quote conversions
+// ================================================================
+
+/// Solve for the flat hazard rate at which `objective` vanishes.
+fn solve_flat_hazard<F>(objective: F) -> Result<f64, MarketDataError>
+where
+    F: Fn(f64) -> Result<f64, MarketDataError>,
+{
+    // Surface pricing errors before entering the solver
+    objective(0.0)?;
+    let solver = BrentSolver::new(SolverConfig::new(1e-14, 200));
+    // Brent's interpolation steps may probe outside the bracket
+    let clamped = |h: f64| h.clamp(0.0, MAX_HAZARD_RATE);
+    solver
+        .find_root(
+            |h| objective(clamped(h)).unwrap_or(f64::NAN),
+            0.0,
+            MAX_HAZARD_RATE,
+        )
+        .map(clamped)
+        .map_err(|e| MarketDataError::InterpolationFailed {
+            reason: format!("Flat hazard rate solve failed: {}", e),
+        })
+}
+
+/// Flat hazard rate that reprices a contract's upfront.
+///
+/// # Arguments
+///
+/// * `cds` - Contract (its coupon is used)
+/// * `upfront` - Clean upfront for the protection buyer, fraction of notional
+/// * `discount_curve` - Discount curve
+pub fn flat_hazard_from_upfront<D: YieldCurve<f64>>(
+    cds: &IsdaCdsContract<f64>,
+    upfront: f64,
+    discount_curve: &D,
+) -> Result<f64, MarketDataError> {
+    solve_flat_hazard(|h| {
+        let credit = FlatHazardRateCurve::new(h);
+        Ok(IsdaCdsPricer::new(discount_curve, &credit).upfront(cds)? - upfront)
+    })
+}
+
+/// Convert a quoted spread to an upfront on a standard-coupon contract.
+///
+/// The quoted spread defines a flat hazard curve under which a contract
+/// paying the quoted spread as coupon has zero upfront; the contract is then
+/// valued on that curve with its own coupon.
+///
+/// # Example
+///
+/// ```
+/// use pricer_models::instruments::credit::{
+///     quoted_spread_from_upfront, upfront_from_quoted_spread, CdsDirection, IsdaCdsContract,
+///     StandardCoupon,
+/// };
+/// use pricer_core::market_data::curves::FlatCurve;
+/// use pricer_core::types::time::Date;
+///
+/// let cds = IsdaCdsContract::standard(
+///     Date::from_ymd(2024, 4, 10).unwrap(),
+///     5,
+///     StandardCoupon::Bp100,
+///     0.4,
+///     1.0,
+///     CdsDirection::BuyProtection,
+/// )
+/// .unwrap();
+/// let discount = FlatCurve::new(0.03);
+///
+/// // Quoted spread above the coupon: the buyer pays upfront
+/// let upfront = upfront_from_quoted_spread(&cds, 0.025, &discount).unwrap();
+/// assert!(upfront > 0.0);
+///
+/// let back = quoted_spread_from_upfront(&cds, upfront, &discount).unwrap();
+/// assert!((back - 0.025).abs() < 1e-10);
+/// ```
+pub fn upfront_from_quoted_spread<D: YieldCurve<f64>>(
+    cds: &IsdaCdsContract<f64>,
+    quoted_spread: f64,
+    discount_curve: &D,
+) -> Result<f64, MarketDataError> {
+    let at_quote = cds.with_coupon(quoted_spread);
+    let h = flat_hazard_from_upfront(&at_quote, 0.0, discount_curve)?;
+    let credit = FlatHazardRateCurve::new(h);
+    IsdaCdsPricer::new(discount_curve, &credit).upfront(cds)
+}
+
+/// Convert an upfront on a standard-coupon contract to a quoted spread.
+///
+/// Inverse of [`upfront_from_quoted_spread`]: solve for the flat hazard
+/// curve repricing the upfront, then return the par spread on that curve.
+pub fn quoted_spread_from_upfront<D: YieldCurve<f64>>(
+    cds: &IsdaCdsContract<f64>,
+    upfront: f64,
+    discount_curve: &D,
+) -> Result<f64, MarketDataError> {
+    let h = flat_hazard_from_upfront(cds, upfront, discount_curve)?;
+    let credit = FlatHazardRateCurve::new(h);
+    IsdaCdsPricer::new(discount_curve, &credit).par_spread(cds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use pricer_core::market_data::curves::FlatCurve;
+
+    fn d(y: i32, m: u32, day: u32) -> Date {
+        Date::from_ymd(y, m, day).unwrap()
+    }
+
+    fn contract(trade: Date, coupon: f64) -> IsdaCdsContract<f64> {
+        IsdaCdsContract::new(
+            trade,
+            standard_maturity(trade, 5),
+            coupon,
+            0.4,
+            1.0,
+            CdsDirection::BuyProtection,
+        )
+        .unwrap()
+    }
+
+    // ========================================
+    // Date convention tests
+    // ========================================
+
+    #[test]
+    fn test_imm_dates() {
+        assert!(is_imm_date(d(2024, 3, 20)));
+        assert!(!is_imm_date(d(2024, 4, 20)));
+        assert_eq!(next_imm_date(d(2024, 3, 19)), d(2024, 3, 20));
+        assert_eq!(next_imm_date(d(2024, 3, 20)), d(2024, 6, 20));
+        assert_eq!(next_imm_date(d(2024, 12, 21)), d(2025, 3, 20));
+        assert_eq!(previous_imm_date(d(2024, 3, 20)), d(2024, 3, 20));
+        assert_eq!(previous_imm_date(d(2024, 3, 19)), d(2023, 12, 20));
+        assert_eq!(previous_imm_date(d(2024, 8, 1)), d(2024, 6, 20));
+    }
+
+    #[test]
+    fn test_standard_maturity_semi_annual_roll() {
+        assert_eq!(standard_maturity(d(2024, 3, 19), 5), d(2028, 12, 20));
+        assert_eq!(standard_maturity(d(2024, 3, 20), 5), d(2029, 6, 20));
+        assert_eq!(standard_maturity(d(2024, 9, 19), 1), d(2025, 6, 20));
+        assert_eq!(standard_maturity(d(2024, 9, 20), 1), d(2025, 12, 20));
+    }
+
+    #[test]
+    fn test_business_day_adjustment() {
+        // 2024-04-20 is a Saturday
+        assert_eq!(adjust_following(d(2024, 4, 20)), d(2024, 4, 22));
+        // Friday trade settles the following Wednesday
+        assert_eq!(add_business_days(d(2024, 4, 12), 3), d(2024, 4, 17));
+    }
+
+    #[test]
+    fn test_step_in_and_accrual_start() {
+        let cds = contract(d(2024, 4, 10), 0.01);
+        assert_eq!(cds.step_in_date(), d(2024, 4, 11));
+        assert_eq!(cds.cash_settle_date(), d(2024, 4, 15));
+        assert_eq!(cds.accrual_start_date(), d(2024, 3, 20));
+        // 22 days of accrued at ACT/360
+        assert_relative_eq!(cds.accrued_year_fraction(), 22.0 / 360.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_accrual_start_on_weekend_imm() {
+        // 2021-06-20 is a Sunday: coupon date adjusts to Monday 21st, so
+        // a Sunday step-in still accrues from the previous coupon date
+        let cds = contract(d(2021, 6, 19), 0.01);
+        assert_eq!(cds.accrual_start_date(), d(2021, 3, 22));
+        let cds = contract(d(2021, 6, 20), 0.01);
+        assert_eq!(cds.accrual_start_date(), d(2021, 6, 21));
+    }
+
+    #[test]
+    fn test_coupon_periods() {
+        let cds = contract(d(2024, 4, 10), 0.01);
+        let periods = cds.coupon_periods();
+        assert_eq!(periods.len(), 21);
+        assert_eq!(periods[0].start(), d(2024, 3, 20));
+        assert_eq!(periods[0].end(), d(2024, 6, 20));
+        // Final period accrues through maturity inclusive
+        let last = periods.last().unwrap();
+        assert_eq!(last.end(), d(2029, 6, 21));
+        assert_eq!(last.payment(), d(2029, 6, 20));
+        for w in periods.windows(2) {
+            assert_eq!(w[0].end(), w[1].start());
+        }
+    }
+
+    #[test]
+    fn test_invalid_contract() {
+        let t = d(2024, 4, 10);
+        assert!(IsdaCdsContract::new(t, t, 0.01, 0.4, 1.0, CdsDirection::BuyProtection).is_err());
+        assert!(IsdaCdsContract::new(
+            t,
+            d(2029, 6, 20),
+            0.01,
+            1.0,
+            1.0,
+            CdsDirection::BuyProtection
+        )
+        .is_err());
+    }
+
+    // ========================================
+    // Pricing tests
+    // ========================================
+
+    #[test]
+    fn test_zero_hazard_has_no_protection() {
+        let cds = contract(d(2024, 4, 10), 0.01);
+        let discount = FlatCurve::new(0.03);
+        let credit = FlatHazardRateCurve::new(0.0);
+        let v = IsdaCdsPricer::new(&discount, &credit).value(&cds).unwrap();
+        assert_eq!(v.protection_leg, 0.0);
+        assert_eq!(v.par_spread, 0.0);
+        assert!(v.upfront < 0.0);
+    }
+
+    #[test]
+    fn test_credit_triangle() {
+        // With continuous premium, spread ≈ λ (1 − R)
+        let cds = contract(d(2024, 4, 10), 0.01);
+        let discount = FlatCurve::new(0.0);
+        let credit = FlatHazardRateCurve::new(0.02);
+        let par = IsdaCdsPricer::new(&discount, &credit)
+            .par_spread(&cds)
+            .unwrap();
+        // ACT/360 premium vs ACT/365 hazard gives the 365/360 factor
+        assert_relative_eq!(par, 0.02 * 0.6 * 360.0 / 365.0, epsilon = 2e-5);
+    }
+
+    #[test]
+    fn test_upfront_zero_at_par_coupon() {
+        let cds = contract(d(2024, 4, 10), 0.01);
+        let discount = FlatCurve::new(0.03);
+        let credit = FlatHazardRateCurve::new(0.03);
+        let pricer = IsdaCdsPricer::new(&discount, &credit);
+        let par = pricer.par_spread(&cds).unwrap();
+        let at_par = cds.with_coupon(par);
+        assert!(pricer.upfront(&at_par).unwrap().abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_dirty_minus_clean_is_accrued() {
+        let cds = IsdaCdsContract::new(
+            d(2024, 4, 10),
+            d(2029, 6, 20),
+            0.05,
+            0.4,
+            10_000_000.0,
+            CdsDirection::BuyProtection,
+        )
+        .unwrap();
+        let discount = FlatCurve::new(0.03);
+        let credit = FlatHazardRateCurve::new(0.05);
+        let pricer = IsdaCdsPricer::new(&discount, &credit);
+        let clean = pricer.clean_pv(&cds).unwrap();
+        let dirty = pricer.dirty_pv(&cds).unwrap();
+        let accrued = 0.05 * 22.0 / 360.0 * 10_000_000.0;
+        assert_relative_eq!(clean - dirty, accrued, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_direction_symmetry() {
+        let buy = contract(d(2024, 4, 10), 0.01);
+        let mut sell = buy.clone();
+        sell.direction = CdsDirection::SellProtection;
+        let discount = FlatCurve::new(0.03);
+        let credit = FlatHazardRateCurve::new(0.03);
+        let pricer = IsdaCdsPricer::new(&discount, &credit);
+        assert_relative_eq!(
+            pricer.clean_pv(&buy).unwrap(),
+            -pricer.clean_pv(&sell).unwrap(),
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn test_accrual_on_default_increases_rpv01() {
+        let cds = contract(d(2024, 4, 10), 0.01);
+        let discount = FlatCurve::new(0.03);
+        let low = FlatHazardRateCurve::new(0.0);
+        let high = FlatHazardRateCurve::new(0.2);
+
+        // With no default, RPV01 is the sum of discounted accrual fractions
+        let v0 = IsdaCdsPricer::new(&discount, &low).value(&cds).unwrap();
+        let annuity: f64 = cds
+            .coupon_periods()
+            .iter()
+            .map(|p| p.year_fraction() * discount.discount_factor(cds.time(p.payment())).unwrap())
+            .sum::<f64>()
+            / discount
+                .discount_factor(cds.time(cds.cash_settle_date()))
+                .unwrap();
+        assert_relative_eq!(v0.rpv01_dirty, annuity, epsilon = 1e-12);
+
+        // Defaults shorten the annuity, accrual on default partially offsets
+        let v1 = IsdaCdsPricer::new(&discount, &high).value(&cds).unwrap();
+        assert!(v1.rpv01_dirty < v0.rpv01_dirty);
+    }
+
+    // ========================================
+    // Quote conversion tests
+    // ========================================
+
+    #[test]
+    fn test_quoted_spread_upfront_round_trip() {
+        let cds = IsdaCdsContract::standard(
+            d(2024, 4, 10),
+            5,
+            StandardCoupon::Bp500,
+            0.4,
+            1.0,
+            CdsDirection::BuyProtection,
+        )
+        .unwrap();
+        let discount = FlatCurve::new(0.04);
+
+        for &quoted in &[0.01, 0.05, 0.12] {
+            let upfront = upfront_from_quoted_spread(&cds, quoted, &discount).unwrap();
+            let back = quoted_spread_from_upfront(&cds, upfront, &discount).unwrap();
+            assert_relative_eq!(back, quoted, epsilon = 1e-10);
+            // Quote below the coupon means the buyer receives upfront
+            assert_eq!(upfront < 0.0, quoted < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_quoted_spread_equal_to_coupon_has_zero_upfront() {
+        let cds = contract(d(2024, 4, 10), 0.01);
+        let discount = FlatCurve::new(0.03);
+        let upfront = upfront_from_quoted_spread(&cds, 0.01, &discount).unwrap();
+        assert!(upfront.abs() < 1e-12);
+    }
+}
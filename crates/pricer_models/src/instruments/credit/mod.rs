@@ -2,6 +2,8 @@
 //!
 //! This module provides credit derivative instruments including:
 //! - [`CreditDefaultSwap`]: Single-name CDS with protection and premium legs
+//! - [`IsdaCdsContract`] / [`IsdaCdsPricer`]: ISDA CDS Standard Model conventions and valuation
+//! - [`IsdaCreditCurveBuilder`]: Piecewise-constant hazard curve bootstrapping from CDS quotes
 //!
 //! # Feature Flag
 //!
@@ -48,11 +50,18 @@
 //! assert_eq!(credit_instrument.type_name(), "CreditDefaultSwap");
 //! ```
 
+mod bootstrap;
 mod cds;
+pub mod isda;
 mod pricing;
 pub mod simulation;
 
+pub use bootstrap::{CdsQuote, CdsQuoteType, IsdaCreditCurveBuilder};
 pub use cds::{CdsDirection, CreditDefaultSwap};
+pub use isda::{
+    quoted_spread_from_upfront, standard_maturity, upfront_from_quoted_spread, IsdaCdsContract,
+    IsdaCdsPricer, IsdaCdsValuation, StandardCoupon,
+};
 pub use pricing::{CdsPriceResult, CdsPricer};
 pub use simulation::{
     CreditMonteCarloSimulator, CreditPathResult, DefaultStatus, DefaultTimeSimulator,
@@ -0,0 +1,308 @@
+//! Zero-coupon inflation curve bootstrapping from ZCIS quotes.
+//!
+//! [`InflationCurveBuilder`] strips a [`ZeroCouponInflationCurve`] from
+//! spot-starting zero-coupon inflation swap breakevens, one pillar per
+//! quote, so that every quoted swap reprices exactly:
+//!
+//! ```text
+//! I_ref(T) = I_ref(T₀) × (1 + K)^τ
+//! ```
+//!
+//! The curve is based on the latest published fixing. Each pillar sits at
+//! the last monthly print the quote's final reference index depends on,
+//! so later quotes never disturb earlier ones. A seasonality adjustment,
+//! if given, is applied during the bootstrap.
+//!
+//! # Example
+//!
+//! ```
+//! use pricer_models::instruments::inflation::{
+//!     CpiIndex, IndexFixings, IndexMonth, InflationCurveBuilder, InflationIndex, ZcisQuote,
+//! };
+//! use pricer_core::types::time::Date;
+//!
+//! let valuation_date = Date::from_ymd(2024, 4, 15).unwrap();
+//! let fixings = IndexFixings::new()
+//!     .with_fixing(IndexMonth::new(2024, 1).unwrap(), 122.1)
+//!     .unwrap()
+//!     .with_fixing(IndexMonth::new(2024, 2).unwrap(), 122.8)
+//!     .unwrap();
+//! let quotes = [
+//!     ZcisQuote::new(Date::from_ymd(2025, 4, 15).unwrap(), 0.024),
+//!     ZcisQuote::new(Date::from_ymd(2029, 4, 15).unwrap(), 0.022),
+//! ];
+//!
+//! let builder = InflationCurveBuilder::new(
+//!     valuation_date,
+//!     CpiIndex::standard(InflationIndex::EuHicpXt),
+//!     fixings,
+//! );
+//! let curve = builder.build(&quotes).unwrap();
+//! assert_eq!(curve.tenors().len(), 2);
+//! ```
+
+use pricer_core::math::solvers::{BrentSolver, SolverConfig};
+use pricer_core::types::time::Date;
+
+use super::curve::{Seasonality, ZeroCouponInflationCurve};
+use super::index::{CpiIndex, IndexFixings};
+use super::pricing::zero_coupon_inflation_par_rate;
+use super::swap::{InflationSwapDirection, ZeroCouponInflationSwap};
+use crate::calibration::CalibrationError;
+
+/// Bracket for zero-coupon inflation rate root searches.
+const MIN_ZERO_RATE: f64 = -0.5;
+const MAX_ZERO_RATE: f64 = 1.0;
+
+/// A zero-coupon inflation swap breakeven quote.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZcisQuote {
+    /// Maturity of the spot-starting swap.
+    pub maturity: Date,
+    /// Breakeven fixed rate.
+    pub rate: f64,
+}
+
+impl ZcisQuote {
+    /// Create a new ZCIS quote.
+    pub fn new(maturity: Date, rate: f64) -> Self {
+        Self { maturity, rate }
+    }
+}
+
+/// Bootstrapper for zero-coupon inflation curves.
+#[derive(Debug, Clone)]
+pub struct InflationCurveBuilder {
+    /// Valuation date (start date of quoted swaps).
+    valuation_date: Date,
+    /// Index and observation conventions of the quoted swaps.
+    index: CpiIndex,
+    /// Historical prints; the latest is the curve base.
+    fixings: IndexFixings,
+    /// Seasonality applied to projected prints.
+    seasonality: Seasonality,
+    /// Root-finding tolerance on the zero rate.
+    tolerance: f64,
+}
+
+impl InflationCurveBuilder {
+    /// Create a new builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `valuation_date` - Valuation date; quoted swaps start here
+    /// * `index` - Index and observation conventions of the quoted swaps
+    /// * `fixings` - Historical prints, covering the swaps' base reference index
+    pub fn new(valuation_date: Date, index: CpiIndex, fixings: IndexFixings) -> Self {
+        Self {
+            valuation_date,
+            index,
+            fixings,
+            seasonality: Seasonality::default(),
+            tolerance: 1e-14,
+        }
+    }
+
+    /// Set the seasonality adjustment.
+    pub fn with_seasonality(mut self, seasonality: Seasonality) -> Self {
+        self.seasonality = seasonality;
+        self
+    }
+
+    /// Set the root-finding tolerance.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Build the swap a quote refers to (unit notional, paying fixed).
+    pub fn swap(
+        &self,
+        quote: &ZcisQuote,
+    ) -> Result<ZeroCouponInflationSwap<f64>, CalibrationError> {
+        ZeroCouponInflationSwap::new(
+            1.0,
+            quote.rate,
+            self.valuation_date,
+            quote.maturity,
+            self.index,
+            InflationSwapDirection::PayFixed,
+        )
+        .map_err(|e| CalibrationError::invalid_market_data(e.to_string()))
+    }
+
+    /// Bootstrap a curve repricing every quote.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalibrationError` if there are no quotes or fixings, two
+    /// quotes share a pillar month, a quote needs no print beyond the
+    /// latest fixing, or a breakeven cannot be matched.
+    pub fn build(
+        &self,
+        quotes: &[ZcisQuote],
+    ) -> Result<ZeroCouponInflationCurve<f64>, CalibrationError> {
+        if quotes.is_empty() {
+            return Err(CalibrationError::insufficient_data(1, 0));
+        }
+        let (base_month, base_value) = self
+            .fixings
+            .last()
+            .ok_or_else(|| CalibrationError::insufficient_data(1, 0))?;
+
+        let mut sorted = quotes.to_vec();
+        sorted.sort_by_key(|q| q.maturity);
+
+        let solver = BrentSolver::new(SolverConfig::new(self.tolerance, 200));
+        let mut tenors: Vec<f64> = Vec::with_capacity(sorted.len());
+        let mut rates: Vec<f64> = Vec::with_capacity(sorted.len());
+
+        let mut last_pillar = base_month;
+
+        for quote in &sorted {
+            let swap = self.swap(quote)?;
+            let pillar = self.index.last_required_month(quote.maturity);
+            if pillar <= last_pillar {
+                return Err(CalibrationError::invalid_market_data(format!(
+                    "ZCIS maturing {} adds no pillar after {}",
+                    quote.maturity, last_pillar
+                )));
+            }
+            last_pillar = pillar;
+            let t = pillar.months_since(base_month) as f64 / 12.0;
+
+            tenors.push(t);
+            rates.push(0.0);
+
+            // Brent's interpolation steps may probe outside the bracket;
+            // clamp so trial curves always carry admissible rates.
+            let objective = |z: f64| -> f64 {
+                let mut trial = rates.clone();
+                *trial.last_mut().unwrap() = z.clamp(MIN_ZERO_RATE, MAX_ZERO_RATE);
+                ZeroCouponInflationCurve::new(base_month, base_value, &tenors, &trial)
+                    .ok()
+                    .map(|curve| {
+                        curve
+                            .with_seasonality(self.seasonality)
+                            .with_fixings(self.fixings.clone())
+                    })
+                    .and_then(|curve| zero_coupon_inflation_par_rate(&swap, &curve).ok())
+                    .map_or(f64::NAN, |par| par - quote.rate)
+            };
+
+            let z = solver
+                .find_root(objective, MIN_ZERO_RATE, MAX_ZERO_RATE)
+                .map_err(|e| {
+                    CalibrationError::invalid_market_data(format!(
+                        "Cannot bootstrap ZCIS quote maturing {}: {}",
+                        quote.maturity, e
+                    ))
+                })?;
+            *rates.last_mut().unwrap() = z.clamp(MIN_ZERO_RATE, MAX_ZERO_RATE);
+        }
+
+        ZeroCouponInflationCurve::new(base_month, base_value, &tenors, &rates)
+            .map(|curve| {
+                curve
+                    .with_seasonality(self.seasonality)
+                    .with_fixings(self.fixings.clone())
+            })
+            .map_err(|e| CalibrationError::numerical_instability(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::inflation::{IndexInterpolation, IndexMonth, InflationIndex};
+
+    fn d(y: i32, m: u32, day: u32) -> Date {
+        Date::from_ymd(y, m, day).unwrap()
+    }
+
+    fn fixings() -> IndexFixings {
+        IndexFixings::new()
+            .with_fixing(IndexMonth::new(2023, 12).unwrap(), 305.7)
+            .unwrap()
+            .with_fixing(IndexMonth::new(2024, 1).unwrap(), 306.9)
+            .unwrap()
+            .with_fixing(IndexMonth::new(2024, 2).unwrap(), 308.4)
+            .unwrap()
+    }
+
+    fn quotes() -> Vec<ZcisQuote> {
+        [
+            (1, 0.0265),
+            (2, 0.0250),
+            (3, 0.0245),
+            (5, 0.0240),
+            (10, 0.0238),
+        ]
+        .iter()
+        .map(|&(y, r)| ZcisQuote::new(d(2024 + y, 4, 15), r))
+        .collect()
+    }
+
+    fn assert_reprices(builder: &InflationCurveBuilder, curve: &ZeroCouponInflationCurve<f64>) {
+        for q in quotes() {
+            let swap = builder.swap(&q).unwrap();
+            let par = zero_coupon_inflation_par_rate(&swap, curve).unwrap();
+            assert!(
+                (par - q.rate).abs() < 1e-12,
+                "par {} vs quote {}",
+                par,
+                q.rate
+            );
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_reprices_linear_index() {
+        let builder = InflationCurveBuilder::new(
+            d(2024, 4, 15),
+            CpiIndex::standard(InflationIndex::UsCpi),
+            fixings(),
+        );
+        let curve = builder.build(&quotes()).unwrap();
+        assert_eq!(curve.base_month(), IndexMonth::new(2024, 2).unwrap());
+        assert_eq!(curve.tenors().len(), 5);
+        assert_reprices(&builder, &curve);
+    }
+
+    #[test]
+    fn test_bootstrap_reprices_with_seasonality() {
+        let mut factors = [1.0; 12];
+        factors[0] = 0.994;
+        factors[3] = 1.004;
+        factors[9] = 1.002;
+        let builder = InflationCurveBuilder::new(
+            d(2024, 4, 15),
+            CpiIndex::new(InflationIndex::EuHicpXt, 3, IndexInterpolation::Flat),
+            fixings(),
+        )
+        .with_seasonality(Seasonality::new(factors).unwrap());
+        let curve = builder.build(&quotes()).unwrap();
+        assert_reprices(&builder, &curve);
+        assert_eq!(
+            curve.seasonality().factors(),
+            Seasonality::new(factors).unwrap().factors()
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_validation() {
+        let index = CpiIndex::standard(InflationIndex::UsCpi);
+        let builder = InflationCurveBuilder::new(d(2024, 4, 15), index, fixings());
+        assert!(builder.build(&[]).is_err());
+
+        let no_fixings = InflationCurveBuilder::new(d(2024, 4, 15), index, IndexFixings::new());
+        assert!(no_fixings.build(&quotes()).is_err());
+
+        // Two maturities in the same month share a pillar
+        let clash = [
+            ZcisQuote::new(d(2025, 4, 15), 0.02),
+            ZcisQuote::new(d(2025, 4, 20), 0.021),
+        ];
+        assert!(builder.build(&clash).is_err());
+    }
+}
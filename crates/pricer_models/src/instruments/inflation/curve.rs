@@ -0,0 +1,357 @@
+//! Zero-coupon inflation curve with seasonality adjustment.
+//!
+//! The curve projects monthly index prints from a base month (the latest
+//! published print) using annually compounded zero-coupon inflation rates:
+//!
+//! ```text
+//! I(m) = I(m₀) × (1 + z(t))^t × S(m₀, m),    t = (m - m₀) / 12
+//! ```
+//!
+//! where `z` is interpolated linearly in `t` between pillars (flat beyond
+//! them) and `S` is the multiplicative seasonal adjustment accumulated over
+//! the calendar months in `(m₀, m]`. Seasonal factors are normalised to
+//! multiply to one over a year, so they redistribute inflation within a
+//! year without changing year-on-year growth.
+
+use num_traits::Float;
+use pricer_core::market_data::error::MarketDataError;
+use pricer_core::types::time::Date;
+
+use super::index::{CpiIndex, IndexFixings, IndexMonth};
+
+/// Monthly multiplicative seasonality of an inflation index.
+///
+/// # Examples
+///
+/// ```
+/// use pricer_models::instruments::inflation::{IndexMonth, Seasonality};
+///
+/// let mut factors = [1.0; 12];
+/// factors[0] = 0.995; // January prints tend to be weak
+/// factors[6] = 1.005;
+/// let seasonality = Seasonality::new(factors).unwrap();
+///
+/// let dec = IndexMonth::new(2024, 12).unwrap();
+/// let next_dec = IndexMonth::new(2025, 12).unwrap();
+/// assert!((seasonality.adjustment(dec, next_dec) - 1.0).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Seasonality {
+    /// Log-adjustment per calendar month, normalised to sum to zero.
+    log_factors: [f64; 12],
+}
+
+impl Seasonality {
+    /// Create a seasonality from multiplicative monthly factors (January first).
+    ///
+    /// Factors are rescaled so that their product over a year is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::InterpolationFailed` if any factor is not positive.
+    pub fn new(factors: [f64; 12]) -> Result<Self, MarketDataError> {
+        if factors.iter().any(|&f| !(f > 0.0 && f.is_finite())) {
+            return Err(MarketDataError::InterpolationFailed {
+                reason: "Seasonality factors must be positive".to_string(),
+            });
+        }
+        let mut log_factors = factors.map(f64::ln);
+        let mean = log_factors.iter().sum::<f64>() / 12.0;
+        log_factors.iter_mut().for_each(|l| *l -= mean);
+        Ok(Self { log_factors })
+    }
+
+    /// Return the normalised multiplicative factors (January first).
+    pub fn factors(&self) -> [f64; 12] {
+        self.log_factors.map(f64::exp)
+    }
+
+    /// Return the cumulative adjustment from `from` to `to`.
+    ///
+    /// Whole years contribute nothing; only the residual months in
+    /// `(from, to]` are accumulated. The adjustment is inverted when `to`
+    /// precedes `from`.
+    pub fn adjustment(&self, from: IndexMonth, to: IndexMonth) -> f64 {
+        let months = to.months_since(from);
+        if months < 0 {
+            return 1.0 / self.adjustment(to, from);
+        }
+        let log_adj: f64 = (1..=months % 12)
+            .map(|k| self.log_factors[from.add_months(k).month() as usize - 1])
+            .sum();
+        log_adj.exp()
+    }
+}
+
+/// Zero-coupon inflation curve.
+///
+/// # Type Parameters
+///
+/// * `T` - Floating-point type (e.g., `f64`, `Dual64`)
+///
+/// # Examples
+///
+/// ```
+/// use pricer_models::instruments::inflation::{IndexMonth, ZeroCouponInflationCurve};
+///
+/// let base = IndexMonth::new(2024, 1).unwrap();
+/// let curve = ZeroCouponInflationCurve::new(base, 300.0_f64, &[1.0, 5.0], &[0.025, 0.022]).unwrap();
+///
+/// let projected = curve.index_value(IndexMonth::new(2025, 1).unwrap()).unwrap();
+/// assert!((projected - 300.0 * 1.025).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZeroCouponInflationCurve<T: Float> {
+    base_month: IndexMonth,
+    base_value: T,
+    tenors: Vec<T>,
+    zero_rates: Vec<T>,
+    seasonality: Seasonality,
+    fixings: IndexFixings,
+}
+
+impl<T: Float> ZeroCouponInflationCurve<T> {
+    /// Create a curve from pillar zero-coupon inflation rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_month` - Month of the latest published print
+    /// * `base_value` - Index value for `base_month`
+    /// * `tenors` - Pillar times in years from `base_month`, strictly increasing
+    /// * `zero_rates` - Annually compounded zero-coupon inflation rates
+    ///
+    /// # Errors
+    ///
+    /// - `MarketDataError::InsufficientData` if no pillars are given
+    /// - `MarketDataError::InvalidMaturity` for non-positive or unsorted tenors
+    /// - `MarketDataError::InterpolationFailed` for mismatched lengths,
+    ///   a non-positive base value, or rates at or below -100%
+    pub fn new(
+        base_month: IndexMonth,
+        base_value: T,
+        tenors: &[T],
+        zero_rates: &[T],
+    ) -> Result<Self, MarketDataError> {
+        if tenors.is_empty() {
+            return Err(MarketDataError::InsufficientData { got: 0, need: 1 });
+        }
+        if tenors.len() != zero_rates.len() {
+            return Err(MarketDataError::InterpolationFailed {
+                reason: format!(
+                    "Tenors ({}) and zero rates ({}) must have equal length",
+                    tenors.len(),
+                    zero_rates.len()
+                ),
+            });
+        }
+        if base_value <= T::zero() {
+            return Err(MarketDataError::InterpolationFailed {
+                reason: "Base index value must be positive".to_string(),
+            });
+        }
+        let mut prev = T::zero();
+        for &t in tenors {
+            if t <= prev {
+                return Err(MarketDataError::InvalidMaturity {
+                    t: t.to_f64().unwrap_or(f64::NAN),
+                });
+            }
+            prev = t;
+        }
+        if zero_rates.iter().any(|&z| z <= -T::one()) {
+            return Err(MarketDataError::InterpolationFailed {
+                reason: "Zero inflation rates must exceed -100%".to_string(),
+            });
+        }
+
+        Ok(Self {
+            base_month,
+            base_value,
+            tenors: tenors.to_vec(),
+            zero_rates: zero_rates.to_vec(),
+            seasonality: Seasonality::default(),
+            fixings: IndexFixings::new(),
+        })
+    }
+
+    /// Apply a seasonality adjustment to projected prints.
+    pub fn with_seasonality(mut self, seasonality: Seasonality) -> Self {
+        self.seasonality = seasonality;
+        self
+    }
+
+    /// Attach historical prints for months up to the base month.
+    pub fn with_fixings(mut self, fixings: IndexFixings) -> Self {
+        self.fixings = fixings;
+        self
+    }
+
+    /// Return the base month.
+    #[inline]
+    pub fn base_month(&self) -> IndexMonth {
+        self.base_month
+    }
+
+    /// Return the base index value.
+    #[inline]
+    pub fn base_value(&self) -> T {
+        self.base_value
+    }
+
+    /// Return the pillar tenors.
+    #[inline]
+    pub fn tenors(&self) -> &[T] {
+        &self.tenors
+    }
+
+    /// Return the pillar zero-coupon inflation rates.
+    #[inline]
+    pub fn zero_rates(&self) -> &[T] {
+        &self.zero_rates
+    }
+
+    /// Return the seasonality adjustment.
+    #[inline]
+    pub fn seasonality(&self) -> &Seasonality {
+        &self.seasonality
+    }
+
+    /// Return the historical prints.
+    #[inline]
+    pub fn fixings(&self) -> &IndexFixings {
+        &self.fixings
+    }
+
+    /// Interpolated zero-coupon inflation rate at time `t` from the base month.
+    pub fn zero_rate(&self, t: T) -> T {
+        let n = self.tenors.len();
+        if t <= self.tenors[0] {
+            return self.zero_rates[0];
+        }
+        if t >= self.tenors[n - 1] {
+            return self.zero_rates[n - 1];
+        }
+        let i = self.tenors.iter().position(|&ti| t <= ti).unwrap();
+        let (t0, t1) = (self.tenors[i - 1], self.tenors[i]);
+        let (z0, z1) = (self.zero_rates[i - 1], self.zero_rates[i]);
+        z0 + (z1 - z0) * (t - t0) / (t1 - t0)
+    }
+
+    /// Index print for `month`: historical up to the base month, projected after.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MarketDataError::MissingData` for a month before the base
+    /// month that has no historical print.
+    pub fn index_value(&self, month: IndexMonth) -> Result<T, MarketDataError> {
+        let months = month.months_since(self.base_month);
+        if months == 0 {
+            return Ok(self.base_value);
+        }
+        if months < 0 {
+            return self
+                .fixings
+                .get(month)
+                .map(|v| T::from(v).unwrap())
+                .ok_or_else(|| MarketDataError::MissingData {
+                    description: format!("Inflation index fixing for {}", month),
+                });
+        }
+
+        let t = T::from(months as f64 / 12.0).unwrap();
+        let growth = (T::one() + self.zero_rate(t)).powf(t);
+        let seasonal = T::from(self.seasonality.adjustment(self.base_month, month)).unwrap();
+        Ok(self.base_value * growth * seasonal)
+    }
+
+    /// Reference index for `date` under the conventions of `index`.
+    pub fn reference_index(&self, index: &CpiIndex, date: Date) -> Result<T, MarketDataError> {
+        index.reference_value(date, |month| self.index_value(month))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::inflation::{IndexInterpolation, InflationIndex};
+
+    fn m(y: i32, mo: u32) -> IndexMonth {
+        IndexMonth::new(y, mo).unwrap()
+    }
+
+    fn curve() -> ZeroCouponInflationCurve<f64> {
+        ZeroCouponInflationCurve::new(m(2024, 1), 300.0, &[1.0, 5.0], &[0.03, 0.02]).unwrap()
+    }
+
+    #[test]
+    fn test_new_validation() {
+        assert!(ZeroCouponInflationCurve::<f64>::new(m(2024, 1), 300.0, &[], &[]).is_err());
+        assert!(
+            ZeroCouponInflationCurve::new(m(2024, 1), 300.0, &[2.0, 1.0], &[0.02, 0.02]).is_err()
+        );
+        assert!(ZeroCouponInflationCurve::new(m(2024, 1), 300.0, &[1.0], &[0.02, 0.02]).is_err());
+        assert!(ZeroCouponInflationCurve::new(m(2024, 1), -1.0, &[1.0], &[0.02]).is_err());
+        assert!(ZeroCouponInflationCurve::new(m(2024, 1), 300.0, &[1.0], &[-1.0]).is_err());
+    }
+
+    #[test]
+    fn test_zero_rate_interpolation() {
+        let c = curve();
+        assert_eq!(c.zero_rate(0.5), 0.03);
+        assert!((c.zero_rate(3.0) - 0.025).abs() < 1e-15);
+        assert_eq!(c.zero_rate(10.0), 0.02);
+    }
+
+    #[test]
+    fn test_index_projection() {
+        let c = curve();
+        assert_eq!(c.index_value(m(2024, 1)).unwrap(), 300.0);
+        let v = c.index_value(m(2027, 1)).unwrap();
+        assert!((v - 300.0 * 1.025_f64.powi(3)).abs() < 1e-10);
+        // Before the base month only fixings are available
+        assert!(c.index_value(m(2023, 12)).is_err());
+        let fixings = IndexFixings::new().with_fixing(m(2023, 12), 298.0).unwrap();
+        let c = c.with_fixings(fixings);
+        assert_eq!(c.index_value(m(2023, 12)).unwrap(), 298.0);
+    }
+
+    #[test]
+    fn test_seasonality_normalisation() {
+        let mut factors = [1.01; 12];
+        factors[3] = 1.05;
+        let s = Seasonality::new(factors).unwrap();
+        let product: f64 = s.factors().iter().product();
+        assert!((product - 1.0).abs() < 1e-14);
+        assert!(Seasonality::new([0.0; 12]).is_err());
+
+        // Adjustment accumulates residual months and inverts backwards
+        let a = s.adjustment(m(2024, 2), m(2024, 5));
+        let f = s.factors();
+        assert!((a - f[2] * f[3] * f[4]).abs() < 1e-14);
+        assert!((s.adjustment(m(2024, 5), m(2024, 2)) * a - 1.0).abs() < 1e-14);
+        assert!((s.adjustment(m(2024, 2), m(2026, 2)) - 1.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_seasonality_preserves_annual_growth() {
+        let mut factors = [1.0; 12];
+        factors[0] = 0.99;
+        factors[6] = 1.01;
+        let c = curve().with_seasonality(Seasonality::new(factors).unwrap());
+        let v = c.index_value(m(2025, 1)).unwrap();
+        assert!((v - 309.0).abs() < 1e-10);
+        // Mid-year prints are shifted by the seasonal pattern
+        let plain = curve().index_value(m(2024, 7)).unwrap();
+        let seasonal = c.index_value(m(2024, 7)).unwrap();
+        assert!(seasonal > plain);
+    }
+
+    #[test]
+    fn test_reference_index() {
+        let c = curve();
+        let index = CpiIndex::new(InflationIndex::EuHicpXt, 3, IndexInterpolation::Flat);
+        let date = Date::from_ymd(2025, 4, 15).unwrap();
+        let r = c.reference_index(&index, date).unwrap();
+        assert!((r - 309.0).abs() < 1e-10);
+    }
+}
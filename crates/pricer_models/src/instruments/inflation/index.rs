@@ -0,0 +1,414 @@
+//! Inflation index definitions and reference index conventions.
+//!
+//! Inflation-linked cashflows do not reference the index value on the
+//! payment date. Instead they use a *reference index* observed a number of
+//! months earlier (the publication lag), optionally interpolated linearly
+//! between two monthly prints:
+//!
+//! ```text
+//! Flat:   I_ref(d) = I(m(d) - L)
+//! Linear: I_ref(d) = I(m(d) - L) + (day(d) - 1) / days_in_month(d)
+//!                    × [I(m(d) - L + 1) - I(m(d) - L)]
+//! ```
+//!
+//! where `m(d)` is the calendar month of date `d` and `L` the lag in months.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{Datelike, Months, NaiveDate};
+use num_traits::Float;
+use pricer_core::market_data::error::MarketDataError;
+use pricer_core::types::time::Date;
+use pricer_core::types::Currency;
+
+use crate::instruments::error::InstrumentError;
+
+/// Published consumer price index families.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InflationIndex {
+    /// US CPI for all urban consumers, non seasonally adjusted (CPI-U NSA).
+    UsCpi,
+    /// Eurozone HICP excluding tobacco (HICPxT).
+    EuHicpXt,
+    /// UK Retail Price Index (RPI).
+    UkRpi,
+}
+
+impl InflationIndex {
+    /// Return the index name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            InflationIndex::UsCpi => "USCPI",
+            InflationIndex::EuHicpXt => "HICPxT",
+            InflationIndex::UkRpi => "UKRPI",
+        }
+    }
+
+    /// Return the currency of swaps referencing this index.
+    pub fn currency(&self) -> Currency {
+        match self {
+            InflationIndex::UsCpi => Currency::USD,
+            InflationIndex::EuHicpXt => Currency::EUR,
+            InflationIndex::UkRpi => Currency::GBP,
+        }
+    }
+
+    /// Return the market-standard observation lag in months.
+    pub fn default_lag_months(&self) -> u32 {
+        match self {
+            InflationIndex::UsCpi | InflationIndex::EuHicpXt => 3,
+            InflationIndex::UkRpi => 2,
+        }
+    }
+
+    /// Return the market-standard reference index interpolation.
+    pub fn default_interpolation(&self) -> IndexInterpolation {
+        match self {
+            InflationIndex::UsCpi => IndexInterpolation::Linear,
+            InflationIndex::EuHicpXt | InflationIndex::UkRpi => IndexInterpolation::Flat,
+        }
+    }
+}
+
+impl fmt::Display for InflationIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Interpolation of the reference index within a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexInterpolation {
+    /// Use the lagged monthly print for every day of the month.
+    Flat,
+    /// Interpolate linearly between the lagged print and the following one.
+    Linear,
+}
+
+/// Calendar month of an index publication.
+///
+/// Ordered chronologically, so it can key historical fixings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IndexMonth {
+    year: i32,
+    month: u32,
+}
+
+impl IndexMonth {
+    /// Create an index month.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InstrumentError::InvalidParameter` if `month` is not in 1..=12.
+    pub fn new(year: i32, month: u32) -> Result<Self, InstrumentError> {
+        if !(1..=12).contains(&month) {
+            return Err(InstrumentError::InvalidParameter {
+                message: format!("Month must be in 1..=12, got {}", month),
+            });
+        }
+        Ok(Self { year, month })
+    }
+
+    /// Return the calendar month containing `date`.
+    pub fn of(date: Date) -> Self {
+        Self {
+            year: date.year(),
+            month: date.month(),
+        }
+    }
+
+    /// Return the year.
+    #[inline]
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Return the month (1-12).
+    #[inline]
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Shift by a (possibly negative) number of months.
+    pub fn add_months(&self, months: i32) -> Self {
+        let ordinal = self.ordinal() + months;
+        Self {
+            year: ordinal.div_euclid(12),
+            month: ordinal.rem_euclid(12) as u32 + 1,
+        }
+    }
+
+    /// Return the number of months from `earlier` to `self`.
+    pub fn months_since(&self, earlier: IndexMonth) -> i32 {
+        self.ordinal() - earlier.ordinal()
+    }
+
+    fn ordinal(&self) -> i32 {
+        self.year * 12 + self.month as i32 - 1
+    }
+}
+
+impl fmt::Display for IndexMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+/// Consumer price index with its observation conventions.
+///
+/// # Examples
+///
+/// ```
+/// use pricer_models::instruments::inflation::{CpiIndex, IndexInterpolation, InflationIndex};
+///
+/// let hicp = CpiIndex::standard(InflationIndex::EuHicpXt);
+/// assert_eq!(hicp.lag_months(), 3);
+/// assert_eq!(hicp.interpolation(), IndexInterpolation::Flat);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CpiIndex {
+    index: InflationIndex,
+    lag_months: u32,
+    interpolation: IndexInterpolation,
+}
+
+impl CpiIndex {
+    /// Create an index with explicit lag and interpolation.
+    pub fn new(index: InflationIndex, lag_months: u32, interpolation: IndexInterpolation) -> Self {
+        Self {
+            index,
+            lag_months,
+            interpolation,
+        }
+    }
+
+    /// Create an index with its market-standard conventions.
+    pub fn standard(index: InflationIndex) -> Self {
+        Self::new(
+            index,
+            index.default_lag_months(),
+            index.default_interpolation(),
+        )
+    }
+
+    /// Return the underlying index family.
+    #[inline]
+    pub fn index(&self) -> InflationIndex {
+        self.index
+    }
+
+    /// Return the observation lag in months.
+    #[inline]
+    pub fn lag_months(&self) -> u32 {
+        self.lag_months
+    }
+
+    /// Return the reference index interpolation.
+    #[inline]
+    pub fn interpolation(&self) -> IndexInterpolation {
+        self.interpolation
+    }
+
+    /// Return the lagged month whose print the reference index for `date` starts from.
+    pub fn reference_month(&self, date: Date) -> IndexMonth {
+        IndexMonth::of(date).add_months(-(self.lag_months as i32))
+    }
+
+    /// Return the interpolation weight on the month after [`Self::reference_month`].
+    ///
+    /// Always zero for flat interpolation.
+    pub fn interpolation_weight(&self, date: Date) -> f64 {
+        match self.interpolation {
+            IndexInterpolation::Flat => 0.0,
+            IndexInterpolation::Linear => {
+                (date.day() - 1) as f64 / days_in_month(date.into_inner()) as f64
+            }
+        }
+    }
+
+    /// Return the latest monthly print the reference index for `date` depends on.
+    pub fn last_required_month(&self, date: Date) -> IndexMonth {
+        let month = self.reference_month(date);
+        if self.interpolation_weight(date) > 0.0 {
+            month.add_months(1)
+        } else {
+            month
+        }
+    }
+
+    /// Compute the reference index for `date` from monthly index values.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - Observation date (e.g. swap start or maturity)
+    /// * `index_value` - Monthly index value source (fixings or projections)
+    pub fn reference_value<T, F>(&self, date: Date, index_value: F) -> Result<T, MarketDataError>
+    where
+        T: Float,
+        F: Fn(IndexMonth) -> Result<T, MarketDataError>,
+    {
+        let month = self.reference_month(date);
+        let i1 = index_value(month)?;
+        let weight = self.interpolation_weight(date);
+        if weight == 0.0 {
+            return Ok(i1);
+        }
+        let i2 = index_value(month.add_months(1))?;
+        Ok(i1 + T::from(weight).unwrap() * (i2 - i1))
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let first = date.with_day(1).unwrap();
+    let next = first + Months::new(1);
+    (next - first).num_days() as u32
+}
+
+/// Historical monthly index prints.
+///
+/// # Examples
+///
+/// ```
+/// use pricer_models::instruments::inflation::{IndexFixings, IndexMonth};
+///
+/// let fixings = IndexFixings::new()
+///     .with_fixing(IndexMonth::new(2024, 1).unwrap(), 308.417).unwrap()
+///     .with_fixing(IndexMonth::new(2024, 2).unwrap(), 310.326).unwrap();
+/// assert_eq!(fixings.last(), Some((IndexMonth::new(2024, 2).unwrap(), 310.326)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexFixings {
+    values: BTreeMap<IndexMonth, f64>,
+}
+
+impl IndexFixings {
+    /// Create an empty fixing history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace the print for `month`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InstrumentError::InvalidParameter` if `value` is not positive.
+    pub fn insert(&mut self, month: IndexMonth, value: f64) -> Result<(), InstrumentError> {
+        if !(value > 0.0 && value.is_finite()) {
+            return Err(InstrumentError::InvalidParameter {
+                message: format!("Index fixing for {} must be positive, got {}", month, value),
+            });
+        }
+        self.values.insert(month, value);
+        Ok(())
+    }
+
+    /// Builder-style variant of [`Self::insert`].
+    pub fn with_fixing(mut self, month: IndexMonth, value: f64) -> Result<Self, InstrumentError> {
+        self.insert(month, value)?;
+        Ok(self)
+    }
+
+    /// Return the print for `month`, if published.
+    pub fn get(&self, month: IndexMonth) -> Option<f64> {
+        self.values.get(&month).copied()
+    }
+
+    /// Return the most recent print.
+    pub fn last(&self) -> Option<(IndexMonth, f64)> {
+        self.values.iter().next_back().map(|(&m, &v)| (m, v))
+    }
+
+    /// Return the number of prints.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Return whether no prints are stored.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> Date {
+        Date::from_ymd(y, m, day).unwrap()
+    }
+
+    fn m(y: i32, mo: u32) -> IndexMonth {
+        IndexMonth::new(y, mo).unwrap()
+    }
+
+    #[test]
+    fn test_standard_conventions() {
+        let us = CpiIndex::standard(InflationIndex::UsCpi);
+        assert_eq!(us.lag_months(), 3);
+        assert_eq!(us.interpolation(), IndexInterpolation::Linear);
+        assert_eq!(InflationIndex::UkRpi.default_lag_months(), 2);
+        assert_eq!(InflationIndex::EuHicpXt.currency(), Currency::EUR);
+        assert_eq!(format!("{}", InflationIndex::EuHicpXt), "HICPxT");
+    }
+
+    #[test]
+    fn test_index_month_arithmetic() {
+        assert!(IndexMonth::new(2024, 13).is_err());
+        assert_eq!(m(2024, 2).add_months(-3), m(2023, 11));
+        assert_eq!(m(2024, 11).add_months(14), m(2026, 1));
+        assert_eq!(m(2026, 1).months_since(m(2024, 11)), 14);
+        assert!(m(2023, 12) < m(2024, 1));
+        assert_eq!(format!("{}", m(2024, 3)), "2024-03");
+    }
+
+    #[test]
+    fn test_reference_value_flat() {
+        let index = CpiIndex::new(InflationIndex::EuHicpXt, 3, IndexInterpolation::Flat);
+        let value = index
+            .reference_value(d(2024, 5, 20), |month| {
+                assert_eq!(month, m(2024, 2));
+                Ok(120.0_f64)
+            })
+            .unwrap();
+        assert_eq!(value, 120.0);
+        assert_eq!(index.last_required_month(d(2024, 5, 20)), m(2024, 2));
+    }
+
+    #[test]
+    fn test_reference_value_linear() {
+        let index = CpiIndex::standard(InflationIndex::UsCpi);
+        let fixings = IndexFixings::new()
+            .with_fixing(m(2024, 1), 300.0)
+            .unwrap()
+            .with_fixing(m(2024, 2), 303.0)
+            .unwrap();
+        let lookup = |month| {
+            fixings.get(month).ok_or(MarketDataError::MissingData {
+                description: format!("{}", month),
+            })
+        };
+
+        // April 16th: 15 of 30 days into the month
+        let value: f64 = index.reference_value(d(2024, 4, 16), lookup).unwrap();
+        assert!((value - 301.5).abs() < 1e-12);
+        assert_eq!(index.last_required_month(d(2024, 4, 16)), m(2024, 2));
+
+        // First of the month needs only the lagged print
+        let value: f64 = index.reference_value(d(2024, 4, 1), lookup).unwrap();
+        assert_eq!(value, 300.0);
+        assert_eq!(index.last_required_month(d(2024, 4, 1)), m(2024, 1));
+    }
+
+    #[test]
+    fn test_fixings_validation() {
+        let mut fixings = IndexFixings::new();
+        assert!(fixings.is_empty());
+        assert!(fixings.insert(m(2024, 1), -1.0).is_err());
+        fixings.insert(m(2024, 1), 100.0).unwrap();
+        fixings.insert(m(2023, 12), 99.0).unwrap();
+        assert_eq!(fixings.len(), 2);
+        assert_eq!(fixings.last(), Some((m(2024, 1), 100.0)));
+        assert_eq!(fixings.get(m(2023, 11)), None);
+    }
+}
@@ -0,0 +1,234 @@
+//! Inflation-linked derivative instruments.
+//!
+//! This module provides inflation instruments and market data including:
+//! - [`CpiIndex`]: Price index with publication lag and interpolation conventions
+//! - [`ZeroCouponInflationCurve`]: Zero-coupon inflation curve with [`Seasonality`]
+//! - [`InflationCurveBuilder`]: Curve bootstrapping from ZCIS quotes
+//! - [`ZeroCouponInflationSwap`] and [`YearOnYearInflationSwap`]
+//!
+//! # Feature Flag
+//!
+//! This module is available when the `inflation` feature is enabled.
+//!
+//! # Architecture
+//!
+//! Inflation instruments follow the same enum dispatch pattern as other
+//! asset classes for Enzyme AD compatibility. The [`InflationInstrument`]
+//! enum wraps all inflation derivative types.
+//!
+//! # Examples
+//!
+//! ```
+//! use pricer_models::instruments::inflation::{
+//!     price_zero_coupon_inflation_swap, CpiIndex, IndexMonth, InflationIndex,
+//!     InflationInstrument, InflationSwapDirection, ZeroCouponInflationCurve,
+//!     ZeroCouponInflationSwap,
+//! };
+//! use pricer_models::instruments::InstrumentTrait;
+//! use pricer_core::market_data::curves::FlatCurve;
+//! use pricer_core::types::time::Date;
+//!
+//! let swap = ZeroCouponInflationSwap::new(
+//!     1_000_000.0,
+//!     0.02,
+//!     Date::from_ymd(2024, 4, 15).unwrap(),
+//!     Date::from_ymd(2029, 4, 15).unwrap(),
+//!     CpiIndex::standard(InflationIndex::EuHicpXt),
+//!     InflationSwapDirection::PayFixed,
+//! )
+//! .unwrap();
+//!
+//! let base = IndexMonth::new(2024, 1).unwrap();
+//! let curve = ZeroCouponInflationCurve::new(base, 120.0, &[1.0], &[0.025]).unwrap();
+//! let discount = FlatCurve::new(0.03);
+//!
+//! let valuation_date = Date::from_ymd(2024, 4, 15).unwrap();
+//! let pv = price_zero_coupon_inflation_swap(&swap, &curve, &discount, valuation_date).unwrap();
+//! assert!(pv > 0.0);
+//!
+//! let instrument = InflationInstrument::ZeroCoupon(swap);
+//! assert_eq!(instrument.type_name(), "ZeroCouponInflationSwap");
+//! ```
+
+mod bootstrap;
+mod curve;
+mod index;
+pub mod pricing;
+mod swap;
+
+pub use bootstrap::{InflationCurveBuilder, ZcisQuote};
+pub use curve::{Seasonality, ZeroCouponInflationCurve};
+pub use index::{CpiIndex, IndexFixings, IndexInterpolation, IndexMonth, InflationIndex};
+pub use pricing::{
+    price_year_on_year_inflation_swap, price_zero_coupon_inflation_swap,
+    year_on_year_inflation_par_rate, zero_coupon_inflation_par_rate,
+};
+pub use swap::{InflationSwapDirection, YearOnYearInflationSwap, ZeroCouponInflationSwap};
+
+use num_traits::Float;
+use pricer_core::types::Currency;
+
+use crate::instruments::traits::InstrumentTrait;
+
+/// Inflation derivative instrument enum for static dispatch.
+///
+/// Wraps all inflation derivative types for Enzyme-compatible static
+/// dispatch without trait objects or dynamic allocation.
+///
+/// # Type Parameters
+///
+/// * `T` - Floating-point type implementing `Float` (e.g., `f64`, `Dual64`)
+///
+/// # Variants
+///
+/// - `ZeroCoupon`: Zero-coupon inflation swap
+/// - `YearOnYear`: Year-on-year inflation swap
+#[derive(Debug, Clone)]
+pub enum InflationInstrument<T: Float> {
+    /// Zero-coupon inflation swap.
+    ZeroCoupon(ZeroCouponInflationSwap<T>),
+    /// Year-on-year inflation swap.
+    YearOnYear(YearOnYearInflationSwap<T>),
+}
+
+impl<T: Float> InflationInstrument<T> {
+    /// Compute the payoff at given spot price.
+    ///
+    /// Note: inflation swap payoffs are not spot-based; returns zero.
+    #[inline]
+    pub fn payoff(&self, spot: T) -> T {
+        match self {
+            InflationInstrument::ZeroCoupon(swap) => swap.payoff(spot),
+            InflationInstrument::YearOnYear(swap) => swap.payoff(spot),
+        }
+    }
+
+    /// Return time to expiry in years.
+    #[inline]
+    pub fn expiry(&self) -> T {
+        match self {
+            InflationInstrument::ZeroCoupon(swap) => swap.expiry(),
+            InflationInstrument::YearOnYear(swap) => swap.expiry(),
+        }
+    }
+
+    /// Return the settlement currency.
+    #[inline]
+    pub fn currency(&self) -> Currency {
+        match self {
+            InflationInstrument::ZeroCoupon(swap) => swap.currency(),
+            InflationInstrument::YearOnYear(swap) => swap.currency(),
+        }
+    }
+
+    /// Return the referenced index.
+    pub fn index(&self) -> &CpiIndex {
+        match self {
+            InflationInstrument::ZeroCoupon(swap) => swap.index(),
+            InflationInstrument::YearOnYear(swap) => swap.index(),
+        }
+    }
+
+    /// Return whether this is a zero-coupon inflation swap.
+    #[inline]
+    pub fn is_zero_coupon(&self) -> bool {
+        matches!(self, InflationInstrument::ZeroCoupon(_))
+    }
+
+    /// Return whether this is a year-on-year inflation swap.
+    #[inline]
+    pub fn is_year_on_year(&self) -> bool {
+        matches!(self, InflationInstrument::YearOnYear(_))
+    }
+
+    /// Return a reference to the zero-coupon swap if this is a ZeroCoupon variant.
+    pub fn as_zero_coupon(&self) -> Option<&ZeroCouponInflationSwap<T>> {
+        match self {
+            InflationInstrument::ZeroCoupon(swap) => Some(swap),
+            _ => None,
+        }
+    }
+
+    /// Return a reference to the year-on-year swap if this is a YearOnYear variant.
+    pub fn as_year_on_year(&self) -> Option<&YearOnYearInflationSwap<T>> {
+        match self {
+            InflationInstrument::YearOnYear(swap) => Some(swap),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Float> InstrumentTrait<T> for InflationInstrument<T> {
+    #[inline]
+    fn payoff(&self, spot: T) -> T {
+        self.payoff(spot)
+    }
+
+    #[inline]
+    fn expiry(&self) -> T {
+        self.expiry()
+    }
+
+    #[inline]
+    fn currency(&self) -> Currency {
+        self.currency()
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            InflationInstrument::ZeroCoupon(swap) => swap.type_name(),
+            InflationInstrument::YearOnYear(swap) => swap.type_name(),
+        }
+    }
+
+    fn notional(&self) -> T {
+        match self {
+            InflationInstrument::ZeroCoupon(swap) => swap.notional(),
+            InflationInstrument::YearOnYear(swap) => swap.notional(),
+        }
+    }
+}
+
+impl<T: Float> From<ZeroCouponInflationSwap<T>> for InflationInstrument<T> {
+    fn from(swap: ZeroCouponInflationSwap<T>) -> Self {
+        InflationInstrument::ZeroCoupon(swap)
+    }
+}
+
+impl<T: Float> From<YearOnYearInflationSwap<T>> for InflationInstrument<T> {
+    fn from(swap: YearOnYearInflationSwap<T>) -> Self {
+        InflationInstrument::YearOnYear(swap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pricer_core::types::time::Date;
+
+    fn zcis() -> ZeroCouponInflationSwap<f64> {
+        ZeroCouponInflationSwap::new(
+            1_000_000.0,
+            0.02,
+            Date::from_ymd(2024, 4, 15).unwrap(),
+            Date::from_ymd(2029, 4, 15).unwrap(),
+            CpiIndex::standard(InflationIndex::UkRpi),
+            InflationSwapDirection::PayFixed,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_inflation_instrument_dispatch() {
+        let instrument: InflationInstrument<f64> = zcis().into();
+        assert!(instrument.is_zero_coupon());
+        assert!(!instrument.is_year_on_year());
+        assert!(instrument.as_zero_coupon().is_some());
+        assert!(instrument.as_year_on_year().is_none());
+        assert_eq!(instrument.currency(), Currency::GBP);
+        assert_eq!(instrument.notional(), 1_000_000.0);
+        assert_eq!(instrument.payoff(100.0), 0.0);
+        assert_eq!(instrument.index().index(), InflationIndex::UkRpi);
+        assert!((instrument.expiry() - 5.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,310 @@
+//! Inflation swap pricing.
+//!
+//! Cashflows are projected from a [`ZeroCouponInflationCurve`] and
+//! discounted on a nominal yield curve with ACT/365F times from the
+//! valuation date. Cashflows paid on or before the valuation date are
+//! excluded.
+//!
+//! Year-on-year ratios are projected as ratios of forward index values,
+//! i.e. without the convexity adjustment arising from the correlation
+//! between the two index fixings and nominal rates.
+
+use num_traits::Float;
+use pricer_core::market_data::curves::YieldCurve;
+use pricer_core::market_data::error::MarketDataError;
+use pricer_core::types::time::{Date, DayCountConvention};
+
+use super::curve::ZeroCouponInflationCurve;
+use super::swap::{YearOnYearInflationSwap, ZeroCouponInflationSwap};
+
+fn discount_factor<T, D>(
+    discount_curve: &D,
+    valuation_date: Date,
+    date: Date,
+) -> Result<T, MarketDataError>
+where
+    T: Float,
+    D: YieldCurve<T>,
+{
+    let t = DayCountConvention::ActualActual365.year_fraction_dates(valuation_date, date);
+    discount_curve.discount_factor(T::from(t).unwrap())
+}
+
+/// Present value of a zero-coupon inflation swap.
+///
+/// # Arguments
+///
+/// * `swap` - The zero-coupon inflation swap
+/// * `inflation_curve` - Curve projecting the reference index
+/// * `discount_curve` - Nominal discount curve
+/// * `valuation_date` - The valuation date
+///
+/// # Returns
+///
+/// PV from the perspective of the swap direction.
+pub fn price_zero_coupon_inflation_swap<T, D>(
+    swap: &ZeroCouponInflationSwap<T>,
+    inflation_curve: &ZeroCouponInflationCurve<T>,
+    discount_curve: &D,
+    valuation_date: Date,
+) -> Result<T, MarketDataError>
+where
+    T: Float,
+    D: YieldCurve<T>,
+{
+    if swap.maturity_date() <= valuation_date {
+        return Ok(T::zero());
+    }
+
+    let base = inflation_curve.reference_index(swap.index(), swap.start_date())?;
+    let final_index = inflation_curve.reference_index(swap.index(), swap.maturity_date())?;
+    let df = discount_factor(discount_curve, valuation_date, swap.maturity_date())?;
+
+    let direction = swap.direction();
+    let inflation =
+        direction.inflation_multiplier::<T>() * swap.inflation_payment(base, final_index);
+    let fixed = direction.fixed_multiplier::<T>() * swap.fixed_payment();
+    Ok((inflation + fixed) * df)
+}
+
+/// Breakeven rate of a zero-coupon inflation swap.
+///
+/// The rate `K` solving `(1 + K)^τ = I_ref(T) / I_ref(T₀)`; it does not
+/// depend on discounting.
+pub fn zero_coupon_inflation_par_rate<T: Float>(
+    swap: &ZeroCouponInflationSwap<T>,
+    inflation_curve: &ZeroCouponInflationCurve<T>,
+) -> Result<T, MarketDataError> {
+    let base = inflation_curve.reference_index(swap.index(), swap.start_date())?;
+    let final_index = inflation_curve.reference_index(swap.index(), swap.maturity_date())?;
+    Ok((final_index / base).powf(T::one() / swap.tenor_years()) - T::one())
+}
+
+/// Discounted inflation leg value and fixed leg annuity of a YoY swap.
+fn yoy_legs<T, D>(
+    swap: &YearOnYearInflationSwap<T>,
+    inflation_curve: &ZeroCouponInflationCurve<T>,
+    discount_curve: &D,
+    valuation_date: Date,
+) -> Result<(T, T), MarketDataError>
+where
+    T: Float,
+    D: YieldCurve<T>,
+{
+    let mut inflation_pv = T::zero();
+    let mut annuity = T::zero();
+
+    for period in swap.schedule().periods() {
+        if period.payment() <= valuation_date {
+            continue;
+        }
+        let i_start = inflation_curve.reference_index(swap.index(), period.start())?;
+        let i_end = inflation_curve.reference_index(swap.index(), period.end())?;
+        let yf = T::from(period.year_fraction()).unwrap();
+        let df = discount_factor(discount_curve, valuation_date, period.payment())?;
+
+        inflation_pv = inflation_pv + (i_end / i_start - T::one()) * yf * df;
+        annuity = annuity + yf * df;
+    }
+
+    Ok((inflation_pv, annuity))
+}
+
+/// Present value of a year-on-year inflation swap.
+///
+/// # Arguments
+///
+/// * `swap` - The year-on-year inflation swap
+/// * `inflation_curve` - Curve projecting the reference index
+/// * `discount_curve` - Nominal discount curve
+/// * `valuation_date` - The valuation date
+///
+/// # Returns
+///
+/// PV from the perspective of the swap direction.
+pub fn price_year_on_year_inflation_swap<T, D>(
+    swap: &YearOnYearInflationSwap<T>,
+    inflation_curve: &ZeroCouponInflationCurve<T>,
+    discount_curve: &D,
+    valuation_date: Date,
+) -> Result<T, MarketDataError>
+where
+    T: Float,
+    D: YieldCurve<T>,
+{
+    let (inflation_pv, annuity) = yoy_legs(swap, inflation_curve, discount_curve, valuation_date)?;
+    let direction = swap.direction();
+    let inflation = direction.inflation_multiplier::<T>() * inflation_pv;
+    let fixed = direction.fixed_multiplier::<T>() * swap.fixed_rate() * annuity;
+    Ok(swap.notional() * (inflation + fixed))
+}
+
+/// Par fixed rate of a year-on-year inflation swap.
+///
+/// # Errors
+///
+/// Returns `MarketDataError::InvalidMaturity` if no cashflows remain after
+/// the valuation date.
+pub fn year_on_year_inflation_par_rate<T, D>(
+    swap: &YearOnYearInflationSwap<T>,
+    inflation_curve: &ZeroCouponInflationCurve<T>,
+    discount_curve: &D,
+    valuation_date: Date,
+) -> Result<T, MarketDataError>
+where
+    T: Float,
+    D: YieldCurve<T>,
+{
+    let (inflation_pv, annuity) = yoy_legs(swap, inflation_curve, discount_curve, valuation_date)?;
+    if annuity <= T::zero() {
+        return Err(MarketDataError::InvalidMaturity {
+            t: DayCountConvention::ActualActual365
+                .year_fraction_dates(valuation_date, swap.schedule().end_date()),
+        });
+    }
+    Ok(inflation_pv / annuity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::inflation::{
+        CpiIndex, IndexFixings, IndexInterpolation, IndexMonth, InflationIndex,
+        InflationSwapDirection,
+    };
+    use crate::schedules::{Frequency, ScheduleBuilder};
+    use pricer_core::market_data::curves::FlatCurve;
+
+    fn d(y: i32, m: u32, day: u32) -> Date {
+        Date::from_ymd(y, m, day).unwrap()
+    }
+
+    fn index() -> CpiIndex {
+        CpiIndex::new(InflationIndex::EuHicpXt, 3, IndexInterpolation::Flat)
+    }
+
+    fn flat_inflation(rate: f64) -> ZeroCouponInflationCurve<f64> {
+        ZeroCouponInflationCurve::new(IndexMonth::new(2024, 1).unwrap(), 120.0, &[1.0], &[rate])
+            .unwrap()
+    }
+
+    fn zcis(rate: f64, direction: InflationSwapDirection) -> ZeroCouponInflationSwap<f64> {
+        ZeroCouponInflationSwap::new(
+            1_000_000.0,
+            rate,
+            d(2024, 4, 15),
+            d(2029, 4, 15),
+            index(),
+            direction,
+        )
+        .unwrap()
+    }
+
+    fn yoy(rate: f64) -> YearOnYearInflationSwap<f64> {
+        let schedule = ScheduleBuilder::new()
+            .start(d(2024, 4, 15))
+            .end(d(2029, 4, 15))
+            .frequency(Frequency::Annual)
+            .day_count(DayCountConvention::Thirty360)
+            .build()
+            .unwrap();
+        YearOnYearInflationSwap::new(
+            1_000_000.0,
+            rate,
+            schedule,
+            index(),
+            InflationSwapDirection::PayFixed,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_zcis_par_rate_on_flat_curve() {
+        let curve = flat_inflation(0.025);
+        let swap = zcis(0.02, InflationSwapDirection::PayFixed);
+        let par = zero_coupon_inflation_par_rate(&swap, &curve).unwrap();
+        assert!((par - 0.025).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_zcis_pv_zero_at_par_and_direction() {
+        let curve = flat_inflation(0.025);
+        let discount = FlatCurve::new(0.03);
+        let valuation = d(2024, 4, 15);
+
+        let at_par = zcis(0.025, InflationSwapDirection::PayFixed);
+        let pv = price_zero_coupon_inflation_swap(&at_par, &curve, &discount, valuation).unwrap();
+        assert!(pv.abs() < 1e-8);
+
+        let payer = zcis(0.02, InflationSwapDirection::PayFixed);
+        let receiver = zcis(0.02, InflationSwapDirection::ReceiveFixed);
+        let pv_pay =
+            price_zero_coupon_inflation_swap(&payer, &curve, &discount, valuation).unwrap();
+        let pv_rec =
+            price_zero_coupon_inflation_swap(&receiver, &curve, &discount, valuation).unwrap();
+        assert!(pv_pay > 0.0);
+        assert!((pv_pay + pv_rec).abs() < 1e-8);
+
+        let t = DayCountConvention::ActualActual365.year_fraction_dates(valuation, d(2029, 4, 15));
+        let expected = 1_000_000.0 * (1.025_f64.powi(5) - 1.02_f64.powi(5)) * (-0.03 * t).exp();
+        assert!((pv_pay - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_zcis_matured_is_worthless() {
+        let curve = flat_inflation(0.025);
+        let discount = FlatCurve::new(0.03);
+        let swap = zcis(0.02, InflationSwapDirection::PayFixed);
+        let pv = price_zero_coupon_inflation_swap(&swap, &curve, &discount, d(2030, 1, 1)).unwrap();
+        assert_eq!(pv, 0.0);
+    }
+
+    #[test]
+    fn test_zcis_uses_fixings_for_base_index() {
+        let fixings = IndexFixings::new()
+            .with_fixing(IndexMonth::new(2023, 12).unwrap(), 118.0)
+            .unwrap();
+        let curve = flat_inflation(0.025).with_fixings(fixings);
+        // Start in March: base reference month is December 2023
+        let swap = ZeroCouponInflationSwap::new(
+            1.0,
+            0.02,
+            d(2024, 3, 15),
+            d(2025, 3, 15),
+            index(),
+            InflationSwapDirection::PayFixed,
+        )
+        .unwrap();
+        let par = zero_coupon_inflation_par_rate(&swap, &curve).unwrap();
+        let final_index = curve
+            .index_value(IndexMonth::new(2024, 12).unwrap())
+            .unwrap();
+        assert!((par - (final_index / 118.0 - 1.0)).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_yoy_par_rate_on_flat_curve() {
+        // Flat zero-coupon inflation implies flat year-on-year inflation
+        let curve = flat_inflation(0.025);
+        let discount = FlatCurve::new(0.03);
+        let valuation = d(2024, 4, 15);
+        let swap = yoy(0.02);
+
+        let par = year_on_year_inflation_par_rate(&swap, &curve, &discount, valuation).unwrap();
+        assert!((par - 0.025).abs() < 1e-12);
+
+        let pv =
+            price_year_on_year_inflation_swap(&yoy(par), &curve, &discount, valuation).unwrap();
+        assert!(pv.abs() < 1e-8);
+        let pv = price_year_on_year_inflation_swap(&swap, &curve, &discount, valuation).unwrap();
+        assert!(pv > 0.0);
+    }
+
+    #[test]
+    fn test_yoy_after_maturity_has_no_annuity() {
+        let curve = flat_inflation(0.025);
+        let discount = FlatCurve::new(0.03);
+        let swap = yoy(0.02);
+        assert!(year_on_year_inflation_par_rate(&swap, &curve, &discount, d(2030, 1, 1)).is_err());
+    }
+}
@@ -0,0 +1,481 @@
+//! Zero-coupon and year-on-year inflation swaps.
+//!
+//! # Cashflows
+//!
+//! A zero-coupon inflation swap (ZCIS) exchanges a single pair of payments
+//! at maturity:
+//!
+//! ```text
+//! Inflation leg: N × [I_ref(T) / I_ref(T₀) - 1]
+//! Fixed leg:     N × [(1 + K)^τ - 1]
+//! ```
+//!
+//! A year-on-year swap (YoY) pays, for each period `[tᵢ₋₁, tᵢ]`:
+//!
+//! ```text
+//! Inflation leg: N × α × [I_ref(tᵢ) / I_ref(tᵢ₋₁) - 1]
+//! Fixed leg:     N × α × K
+//! ```
+//!
+//! where `I_ref` is the lagged reference index of [`CpiIndex`] and `α` the
+//! period accrual fraction.
+
+use std::fmt;
+
+use num_traits::Float;
+use pricer_core::types::time::{Date, DayCountConvention};
+use pricer_core::types::Currency;
+
+use super::index::CpiIndex;
+use crate::instruments::error::InstrumentError;
+use crate::instruments::traits::InstrumentTrait;
+use crate::schedules::Schedule;
+
+/// Inflation swap direction (payer or receiver of the fixed rate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InflationSwapDirection {
+    /// Pay fixed, receive inflation.
+    PayFixed,
+    /// Receive fixed, pay inflation.
+    ReceiveFixed,
+}
+
+impl InflationSwapDirection {
+    /// Returns the multiplier for fixed leg cashflows.
+    ///
+    /// - PayFixed: -1 (negative = pay)
+    /// - ReceiveFixed: +1 (positive = receive)
+    #[inline]
+    pub fn fixed_multiplier<T: Float>(&self) -> T {
+        match self {
+            InflationSwapDirection::PayFixed => -T::one(),
+            InflationSwapDirection::ReceiveFixed => T::one(),
+        }
+    }
+
+    /// Returns the multiplier for inflation leg cashflows.
+    ///
+    /// - PayFixed: +1 (positive = receive)
+    /// - ReceiveFixed: -1 (negative = pay)
+    #[inline]
+    pub fn inflation_multiplier<T: Float>(&self) -> T {
+        -self.fixed_multiplier::<T>()
+    }
+}
+
+impl fmt::Display for InflationSwapDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InflationSwapDirection::PayFixed => write!(f, "Pay Fixed"),
+            InflationSwapDirection::ReceiveFixed => write!(f, "Receive Fixed"),
+        }
+    }
+}
+
+fn validate_notional<T: Float>(notional: T) -> Result<(), InstrumentError> {
+    if notional <= T::zero() {
+        return Err(InstrumentError::InvalidNotional {
+            notional: notional.to_f64().unwrap_or(f64::NAN),
+        });
+    }
+    Ok(())
+}
+
+/// Zero-coupon inflation swap.
+///
+/// # Examples
+///
+/// ```
+/// use pricer_models::instruments::inflation::{
+///     CpiIndex, InflationIndex, InflationSwapDirection, ZeroCouponInflationSwap,
+/// };
+/// use pricer_core::types::time::Date;
+///
+/// let swap = ZeroCouponInflationSwap::new(
+///     10_000_000.0_f64,
+///     0.025,
+///     Date::from_ymd(2024, 3, 15).unwrap(),
+///     Date::from_ymd(2029, 3, 15).unwrap(),
+///     CpiIndex::standard(InflationIndex::EuHicpXt),
+///     InflationSwapDirection::PayFixed,
+/// )
+/// .unwrap();
+///
+/// assert!((swap.tenor_years() - 5.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZeroCouponInflationSwap<T: Float> {
+    notional: T,
+    fixed_rate: T,
+    start_date: Date,
+    maturity_date: Date,
+    index: CpiIndex,
+    currency: Currency,
+    direction: InflationSwapDirection,
+}
+
+impl<T: Float> ZeroCouponInflationSwap<T> {
+    /// Create a zero-coupon inflation swap settling in the index currency.
+    ///
+    /// # Errors
+    ///
+    /// - `InstrumentError::InvalidNotional` for a non-positive notional
+    /// - `InstrumentError::InvalidParameter` if maturity is not after start
+    pub fn new(
+        notional: T,
+        fixed_rate: T,
+        start_date: Date,
+        maturity_date: Date,
+        index: CpiIndex,
+        direction: InflationSwapDirection,
+    ) -> Result<Self, InstrumentError> {
+        validate_notional(notional)?;
+        if maturity_date <= start_date {
+            return Err(InstrumentError::InvalidParameter {
+                message: format!(
+                    "Maturity {} must be after start {}",
+                    maturity_date, start_date
+                ),
+            });
+        }
+        Ok(Self {
+            notional,
+            fixed_rate,
+            start_date,
+            maturity_date,
+            index,
+            currency: index.index().currency(),
+            direction,
+        })
+    }
+
+    /// Override the settlement currency.
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Returns the notional amount.
+    #[inline]
+    pub fn notional(&self) -> T {
+        self.notional
+    }
+
+    /// Returns the fixed (breakeven) rate.
+    #[inline]
+    pub fn fixed_rate(&self) -> T {
+        self.fixed_rate
+    }
+
+    /// Returns the start (base index observation) date.
+    #[inline]
+    pub fn start_date(&self) -> Date {
+        self.start_date
+    }
+
+    /// Returns the maturity and payment date.
+    #[inline]
+    pub fn maturity_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    /// Returns the referenced index.
+    #[inline]
+    pub fn index(&self) -> &CpiIndex {
+        &self.index
+    }
+
+    /// Returns the settlement currency.
+    #[inline]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns the swap direction.
+    #[inline]
+    pub fn direction(&self) -> InflationSwapDirection {
+        self.direction
+    }
+
+    /// Returns the fixed leg compounding period `τ` in years (30/360).
+    ///
+    /// Exact whole years for anniversary dates.
+    pub fn tenor_years(&self) -> T {
+        T::from(
+            DayCountConvention::Thirty360.year_fraction_dates(self.start_date, self.maturity_date),
+        )
+        .unwrap()
+    }
+
+    /// Returns the fixed leg payment amount, `N × [(1 + K)^τ - 1]`.
+    pub fn fixed_payment(&self) -> T {
+        self.notional * ((T::one() + self.fixed_rate).powf(self.tenor_years()) - T::one())
+    }
+
+    /// Returns the inflation leg payment for given reference index values.
+    pub fn inflation_payment(&self, base_index: T, final_index: T) -> T {
+        self.notional * (final_index / base_index - T::one())
+    }
+}
+
+impl<T: Float> InstrumentTrait<T> for ZeroCouponInflationSwap<T> {
+    /// Inflation swap payoff is not spot-based; returns zero.
+    fn payoff(&self, _spot: T) -> T {
+        T::zero()
+    }
+
+    fn expiry(&self) -> T {
+        T::from(
+            DayCountConvention::ActualActual365
+                .year_fraction_dates(self.start_date, self.maturity_date),
+        )
+        .unwrap_or_else(T::zero)
+    }
+
+    fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn notional(&self) -> T {
+        self.notional
+    }
+
+    fn type_name(&self) -> &'static str {
+        "ZeroCouponInflationSwap"
+    }
+}
+
+/// Year-on-year inflation swap.
+///
+/// # Examples
+///
+/// ```
+/// use pricer_models::instruments::inflation::{
+///     CpiIndex, InflationIndex, InflationSwapDirection, YearOnYearInflationSwap,
+/// };
+/// use pricer_models::schedules::{Frequency, ScheduleBuilder};
+/// use pricer_core::types::time::{Date, DayCountConvention};
+///
+/// let schedule = ScheduleBuilder::new()
+///     .start(Date::from_ymd(2024, 3, 15).unwrap())
+///     .end(Date::from_ymd(2029, 3, 15).unwrap())
+///     .frequency(Frequency::Annual)
+///     .day_count(DayCountConvention::Thirty360)
+///     .build()
+///     .unwrap();
+///
+/// let swap = YearOnYearInflationSwap::new(
+///     10_000_000.0,
+///     0.024,
+///     schedule,
+///     CpiIndex::standard(InflationIndex::UsCpi),
+///     InflationSwapDirection::ReceiveFixed,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(swap.num_periods(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct YearOnYearInflationSwap<T: Float> {
+    notional: T,
+    fixed_rate: T,
+    schedule: Schedule,
+    index: CpiIndex,
+    currency: Currency,
+    direction: InflationSwapDirection,
+}
+
+impl<T: Float> YearOnYearInflationSwap<T> {
+    /// Create a year-on-year inflation swap settling in the index currency.
+    ///
+    /// Both legs share the schedule; each period's inflation is observed
+    /// between the reference index at its accrual start and end dates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InstrumentError::InvalidNotional` for a non-positive notional.
+    pub fn new(
+        notional: T,
+        fixed_rate: T,
+        schedule: Schedule,
+        index: CpiIndex,
+        direction: InflationSwapDirection,
+    ) -> Result<Self, InstrumentError> {
+        validate_notional(notional)?;
+        Ok(Self {
+            notional,
+            fixed_rate,
+            schedule,
+            index,
+            currency: index.index().currency(),
+            direction,
+        })
+    }
+
+    /// Override the settlement currency.
+    pub fn with_currency(mut self, currency: Currency) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Returns the notional amount.
+    #[inline]
+    pub fn notional(&self) -> T {
+        self.notional
+    }
+
+    /// Returns the fixed rate.
+    #[inline]
+    pub fn fixed_rate(&self) -> T {
+        self.fixed_rate
+    }
+
+    /// Returns the payment schedule.
+    #[inline]
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns the referenced index.
+    #[inline]
+    pub fn index(&self) -> &CpiIndex {
+        &self.index
+    }
+
+    /// Returns the settlement currency.
+    #[inline]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns the swap direction.
+    #[inline]
+    pub fn direction(&self) -> InflationSwapDirection {
+        self.direction
+    }
+
+    /// Returns the number of periods.
+    #[inline]
+    pub fn num_periods(&self) -> usize {
+        self.schedule.len()
+    }
+}
+
+impl<T: Float> InstrumentTrait<T> for YearOnYearInflationSwap<T> {
+    /// Inflation swap payoff is not spot-based; returns zero.
+    fn payoff(&self, _spot: T) -> T {
+        T::zero()
+    }
+
+    fn expiry(&self) -> T {
+        T::from(
+            DayCountConvention::ActualActual365
+                .year_fraction_dates(self.schedule.start_date(), self.schedule.end_date()),
+        )
+        .unwrap_or_else(T::zero)
+    }
+
+    fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn notional(&self) -> T {
+        self.notional
+    }
+
+    fn type_name(&self) -> &'static str {
+        "YearOnYearInflationSwap"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::inflation::InflationIndex;
+    use crate::schedules::{Frequency, ScheduleBuilder};
+
+    fn d(y: i32, m: u32, day: u32) -> Date {
+        Date::from_ymd(y, m, day).unwrap()
+    }
+
+    fn zcis() -> ZeroCouponInflationSwap<f64> {
+        ZeroCouponInflationSwap::new(
+            1_000_000.0,
+            0.02,
+            d(2024, 3, 15),
+            d(2034, 3, 15),
+            CpiIndex::standard(InflationIndex::UkRpi),
+            InflationSwapDirection::PayFixed,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_direction_multipliers() {
+        let pay = InflationSwapDirection::PayFixed;
+        assert_eq!(pay.fixed_multiplier::<f64>(), -1.0);
+        assert_eq!(pay.inflation_multiplier::<f64>(), 1.0);
+        assert_eq!(
+            InflationSwapDirection::ReceiveFixed.inflation_multiplier::<f64>(),
+            -1.0
+        );
+        assert_eq!(format!("{}", pay), "Pay Fixed");
+    }
+
+    #[test]
+    fn test_zcis_construction() {
+        let swap = zcis();
+        assert_eq!(swap.currency(), Currency::GBP);
+        assert_eq!(swap.index().lag_months(), 2);
+        assert!((swap.tenor_years() - 10.0).abs() < 1e-12);
+        assert_eq!(swap.type_name(), "ZeroCouponInflationSwap");
+        assert_eq!(swap.payoff(100.0), 0.0);
+        assert!((swap.expiry() - 10.0).abs() < 0.01);
+        assert_eq!(swap.with_currency(Currency::USD).currency(), Currency::USD);
+    }
+
+    #[test]
+    fn test_zcis_validation() {
+        let index = CpiIndex::standard(InflationIndex::UkRpi);
+        let dir = InflationSwapDirection::PayFixed;
+        assert!(
+            ZeroCouponInflationSwap::new(0.0, 0.02, d(2024, 1, 1), d(2025, 1, 1), index, dir)
+                .is_err()
+        );
+        assert!(
+            ZeroCouponInflationSwap::new(1.0, 0.02, d(2025, 1, 1), d(2024, 1, 1), index, dir)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_zcis_payments() {
+        let swap = zcis();
+        let fixed = swap.fixed_payment();
+        assert!((fixed - 1_000_000.0 * (1.02_f64.powi(10) - 1.0)).abs() < 1e-6);
+        assert!((swap.inflation_payment(100.0, 125.0) - 250_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_yoy_construction() {
+        let schedule = ScheduleBuilder::new()
+            .start(d(2024, 3, 15))
+            .end(d(2027, 3, 15))
+            .frequency(Frequency::Annual)
+            .day_count(DayCountConvention::Thirty360)
+            .build()
+            .unwrap();
+        let swap = YearOnYearInflationSwap::new(
+            1_000_000.0,
+            0.02,
+            schedule,
+            CpiIndex::standard(InflationIndex::UsCpi),
+            InflationSwapDirection::ReceiveFixed,
+        )
+        .unwrap();
+        assert_eq!(swap.num_periods(), 3);
+        assert_eq!(swap.currency(), Currency::USD);
+        assert_eq!(swap.type_name(), "YearOnYearInflationSwap");
+        assert!((swap.expiry() - 3.0).abs() < 0.01);
+    }
+}
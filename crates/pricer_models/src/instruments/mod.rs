@@ -16,6 +16,7 @@
 //! - `equity`: Equity derivatives (VanillaOption, Forward) - default
 //! - `rates`: Interest rate derivatives (IRS, Swaption, Cap/Floor)
 //! - `credit`: Credit derivatives (CDS)
+//! - `inflation`: Inflation derivatives (zero-coupon and year-on-year swaps)
 //! - `fx`: FX derivatives (FxOption, FxForward)
 //! - `commodity`: Commodity derivatives
 //! - `exotic`: Exotic derivatives (VarianceSwap, Cliquet, etc.)
@@ -67,6 +68,9 @@ pub mod rates;
 #[cfg(feature = "credit")]
 pub mod credit;
 
+#[cfg(feature = "inflation")]
+pub mod inflation;
+
 #[cfg(feature = "fx")]
 pub mod fx;
 
@@ -96,6 +100,9 @@ pub use rates::RatesInstrument;
 #[cfg(feature = "credit")]
 pub use credit::CreditInstrument;
 
+#[cfg(feature = "inflation")]
+pub use inflation::InflationInstrument;
+
 #[cfg(feature = "fx")]
 pub use fx::FxInstrument;
 
@@ -271,6 +278,7 @@ impl<T: Float> Instrument<T> {
 /// - `Equity`: Equity derivatives (vanilla options, forwards)
 /// - `Rates`: Interest rate derivatives (IRS, swaptions, caps/floors)
 /// - `Credit`: Credit derivatives (CDS)
+/// - `Inflation`: Inflation derivatives (zero-coupon and year-on-year swaps)
 /// - `Fx`: FX derivatives (FX options, FX forwards)
 /// - `Commodity`: Commodity derivatives
 /// - `Exotic`: Exotic derivatives (variance swaps, cliquets, etc.)
@@ -281,6 +289,7 @@ impl<T: Float> Instrument<T> {
 /// - `equity` (default): Enables `Equity` variant
 /// - `rates`: Enables `Rates` variant
 /// - `credit`: Enables `Credit` variant
+/// - `inflation`: Enables `Inflation` variant
 /// - `fx`: Enables `Fx` variant
 /// - `commodity`: Enables `Commodity` variant
 /// - `exotic`: Enables `Exotic` variant
@@ -320,6 +329,11 @@ pub enum InstrumentEnum<T: Float> {
     #[cfg(feature = "credit")]
     Credit(CreditInstrument<T>),
 
+    /// Inflation derivatives (zero-coupon and year-on-year swaps).
+    /// Requires `inflation` feature.
+    #[cfg(feature = "inflation")]
+    Inflation(InflationInstrument<T>),
+
     /// FX derivatives (options, forwards).
     /// Requires `fx` feature.
     #[cfg(feature = "fx")]
@@ -344,6 +358,8 @@ impl<T: Float> InstrumentEnum<T> {
             InstrumentEnum::Rates(rates) => rates.payoff(spot),
             #[cfg(feature = "credit")]
             InstrumentEnum::Credit(credit) => credit.payoff(spot),
+            #[cfg(feature = "inflation")]
+            InstrumentEnum::Inflation(inflation) => inflation.payoff(spot),
             #[cfg(feature = "fx")]
             InstrumentEnum::Fx(fx) => fx.payoff(spot),
         }
@@ -359,6 +375,8 @@ impl<T: Float> InstrumentEnum<T> {
             InstrumentEnum::Rates(rates) => rates.expiry(),
             #[cfg(feature = "credit")]
             InstrumentEnum::Credit(credit) => credit.expiry(),
+            #[cfg(feature = "inflation")]
+            InstrumentEnum::Inflation(inflation) => inflation.expiry(),
             #[cfg(feature = "fx")]
             InstrumentEnum::Fx(fx) => fx.expiry(),
         }
@@ -374,6 +392,8 @@ impl<T: Float> InstrumentEnum<T> {
             InstrumentEnum::Rates(rates) => rates.currency(),
             #[cfg(feature = "credit")]
             InstrumentEnum::Credit(credit) => credit.currency(),
+            #[cfg(feature = "inflation")]
+            InstrumentEnum::Inflation(inflation) => inflation.currency(),
             #[cfg(feature = "fx")]
             InstrumentEnum::Fx(fx) => fx.currency(),
         }
@@ -388,6 +408,8 @@ impl<T: Float> InstrumentEnum<T> {
             InstrumentEnum::Rates(_) => AssetClass::Rates,
             #[cfg(feature = "credit")]
             InstrumentEnum::Credit(_) => AssetClass::Credit,
+            #[cfg(feature = "inflation")]
+            InstrumentEnum::Inflation(_) => AssetClass::Inflation,
             #[cfg(feature = "fx")]
             InstrumentEnum::Fx(_) => AssetClass::Fx,
         }
@@ -414,6 +436,13 @@ impl<T: Float> InstrumentEnum<T> {
         matches!(self, InstrumentEnum::Credit(_))
     }
 
+    /// Return whether this is an inflation instrument.
+    #[cfg(feature = "inflation")]
+    #[inline]
+    pub fn is_inflation(&self) -> bool {
+        matches!(self, InstrumentEnum::Inflation(_))
+    }
+
     /// Return a reference to the equity instrument if this is an Equity variant.
     #[cfg(feature = "equity")]
     pub fn as_equity(&self) -> Option<&EquityInstrument<T>> {
@@ -444,6 +473,16 @@ impl<T: Float> InstrumentEnum<T> {
         }
     }
 
+    /// Return a reference to the inflation instrument if this is an Inflation variant.
+    #[cfg(feature = "inflation")]
+    pub fn as_inflation(&self) -> Option<&InflationInstrument<T>> {
+        match self {
+            InstrumentEnum::Inflation(inflation) => Some(inflation),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
     /// Return whether this is an FX instrument.
     #[cfg(feature = "fx")]
     #[inline]
@@ -486,6 +525,8 @@ impl<T: Float> InstrumentTrait<T> for InstrumentEnum<T> {
             InstrumentEnum::Rates(rates) => rates.type_name(),
             #[cfg(feature = "credit")]
             InstrumentEnum::Credit(credit) => credit.type_name(),
+            #[cfg(feature = "inflation")]
+            InstrumentEnum::Inflation(inflation) => inflation.type_name(),
             #[cfg(feature = "fx")]
             InstrumentEnum::Fx(fx) => fx.type_name(),
         }
@@ -514,6 +555,13 @@ impl<T: Float> From<CreditInstrument<T>> for InstrumentEnum<T> {
     }
 }
 
+#[cfg(feature = "inflation")]
+impl<T: Float> From<InflationInstrument<T>> for InstrumentEnum<T> {
+    fn from(inflation: InflationInstrument<T>) -> Self {
+        InstrumentEnum::Inflation(inflation)
+    }
+}
+
 #[cfg(feature = "fx")]
 impl<T: Float> From<FxInstrument<T>> for InstrumentEnum<T> {
     fn from(fx: FxInstrument<T>) -> Self {
@@ -533,6 +581,8 @@ pub enum AssetClass {
     Rates,
     /// Credit derivatives (CDS, credit indices).
     Credit,
+    /// Inflation derivatives (CPI/HICP/RPI-linked swaps).
+    Inflation,
     /// FX derivatives (currency options, forwards).
     Fx,
     /// Commodity derivatives (energy, metals, agriculture).
@@ -547,6 +597,7 @@ impl std::fmt::Display for AssetClass {
             AssetClass::Equity => write!(f, "Equity"),
             AssetClass::Rates => write!(f, "Rates"),
             AssetClass::Credit => write!(f, "Credit"),
+            AssetClass::Inflation => write!(f, "Inflation"),
             AssetClass::Fx => write!(f, "FX"),
             AssetClass::Commodity => write!(f, "Commodity"),
             AssetClass::Exotic => write!(f, "Exotic"),
@@ -832,6 +883,7 @@ mod tests {
         assert_eq!(format!("{}", AssetClass::Equity), "Equity");
         assert_eq!(format!("{}", AssetClass::Rates), "Rates");
         assert_eq!(format!("{}", AssetClass::Credit), "Credit");
+        assert_eq!(format!("{}", AssetClass::Inflation), "Inflation");
         assert_eq!(format!("{}", AssetClass::Fx), "FX");
         assert_eq!(format!("{}", AssetClass::Commodity), "Commodity");
         assert_eq!(format!("{}", AssetClass::Exotic), "Exotic");
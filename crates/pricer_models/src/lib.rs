@@ -27,6 +27,9 @@ pub mod instruments;
 pub mod models;
 pub mod schedules;
 
+#[cfg(test)]
+pub(crate) mod test_utils;
+
 #[cfg(test)]
 mod tests {
     #[test]
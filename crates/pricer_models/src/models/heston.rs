@@ -27,6 +27,8 @@
 //!
 //! Andersen (2008) のQuadratic Exponential離散化スキームを使用。
 //! psi値に基づいて二次スキームと指数スキームを滑らかに切り替える。
+//! 価格は分散の増分を通じて相関を取り込み、分散と独立な乱数で更新する
+//! （検証用の参照価格は [`analytical::fourier`](crate::analytical::fourier)）。
 //!
 //! ## 使用例
 //!
@@ -50,6 +52,7 @@
 //! assert!(p.satisfies_feller()); // Feller条件をチェック
 //! ```
 
+use crate::analytical::distributions::norm_cdf;
 use pricer_core::math::smoothing::{smooth_indicator, smooth_max, smooth_sqrt};
 use pricer_core::traits::priceable::Differentiable;
 use pricer_core::traits::Float;
@@ -836,43 +839,52 @@ impl<T: Float> HestonModel<T> {
         (dw_s, dw_v)
     }
 
-    /// QE価格ステップ（中間点規則）
+    /// QE価格ステップ（Andersen 2008, γ1 = γ2 = 1/2）
     ///
-    /// 分散の中間点を使用して価格を更新する。
+    /// 分散の増分を通じて相関を取り込み、分散とは独立な正規乱数で価格を更新する。
     ///
     /// # Arguments
     /// * `s_current` - 現在の価格
     /// * `v_current` - 現在の分散
     /// * `v_next` - 次の分散
     /// * `dt` - タイムステップ
-    /// * `dw_s` - 価格用ブラウン運動増分
+    /// * `z` - 分散の乱数と独立な標準正規乱数
     ///
     /// # Returns
     /// 次の価格 S_{t+dt}
     ///
     /// # Mathematical Background
-    /// Mid-point discretization for log-price:
-    /// ln(S_{t+dt}) = ln(S_t) + (r - V_avg/2) * dt + sqrt(V_avg * dt) * dW_S
-    /// where V_avg = (V_t + V_{t+dt}) / 2
-    pub fn qe_price_step(&self, s_current: T, v_current: T, v_next: T, dt: T, dw_s: T) -> T {
-        let rate = self.params.rate;
+    /// Integrating the variance SDE gives
+    /// ∫√V dW_V = (V_{t+dt} − V_t − κθdt + κ∫V dt) / ξ, so
+    /// ln(S_{t+dt}) = ln(S_t) + r·dt + K0 + K1·V_t + K2·V_{t+dt} + √(K3·V_t + K4·V_{t+dt})·Z
+    /// with K0 = −ρκθdt/ξ, K1 = dt/2·(κρ/ξ − 1/2) − ρ/ξ,
+    /// K2 = dt/2·(κρ/ξ − 1/2) + ρ/ξ and K3 = K4 = dt/2·(1 − ρ²).
+    /// A shock correlated with the variance draw would instead add a drift
+    /// of order ξρ that does not vanish as dt → 0.
+    pub fn qe_price_step(&self, s_current: T, v_current: T, v_next: T, dt: T, z: T) -> T {
+        let HestonParams {
+            rate,
+            kappa,
+            theta,
+            xi,
+            rho,
+            ..
+        } = self.params;
         let eps = self.params.smoothing_epsilon;
-
-        // Mid-point variance averaging
-        let two = T::from(2.0).unwrap_or(T::one() + T::one());
-        let v_avg = (v_current + v_next) / two;
-
-        // Ensure positive variance using smooth_max
-        let v_avg_safe = smooth_max(v_avg, eps, eps);
-
-        // Log-price dynamics:
-        // d(ln S) = (r - V/2) * dt + sqrt(V) * dW_S
-        let drift = (rate - v_avg_safe / two) * dt;
-        let volatility = smooth_sqrt(v_avg_safe, eps);
-        let diffusion = volatility * dt.sqrt() * dw_s;
-
-        // S_{t+dt} = S_t * exp(drift + diffusion)
-        let log_return = drift + diffusion;
+        let half = T::from(0.5).unwrap_or(T::one() / (T::one() + T::one()));
+
+        let rho_over_xi = rho / xi;
+        let k0 = -rho_over_xi * kappa * theta * dt;
+        let k_drift = half * dt * (kappa * rho_over_xi - half);
+        let k1 = k_drift - rho_over_xi;
+        let k2 = k_drift + rho_over_xi;
+        let k3 = half * dt * (T::one() - rho * rho);
+
+        // Conditional variance of the independent part; smooth_max keeps
+        // it non-negative at |rho| = 1
+        let variance = smooth_max(k3 * (v_current + v_next), T::zero(), eps);
+        let log_return =
+            rate * dt + k0 + k1 * v_current + k2 * v_next + smooth_sqrt(variance, eps) * z;
         let s_next = s_current * log_return.exp();
 
         // Ensure positive price using smooth_max
@@ -887,8 +899,8 @@ impl<T: Float> HestonModel<T> {
     /// * `s_current` - 現在の価格
     /// * `v_current` - 現在の分散
     /// * `dt` - タイムステップ
-    /// * `z1` - 標準正規乱数（価格用）
-    /// * `z2` - 標準正規乱数（分散用）
+    /// * `z1` - 標準正規乱数（価格用、`uv` と独立）
+    /// * `z2` - 未使用（分散は `uv` から生成）
     /// * `uv` - 一様乱数（QEスキーム用）
     ///
     /// # Returns
@@ -905,15 +917,12 @@ impl<T: Float> HestonModel<T> {
     /// assert!(s_next > 0.0);
     /// assert!(v_next >= 0.0);
     /// ```
-    pub fn qe_step(&self, s_current: T, v_current: T, dt: T, z1: T, z2: T, uv: T) -> (T, T) {
+    pub fn qe_step(&self, s_current: T, v_current: T, dt: T, z1: T, _z2: T, uv: T) -> (T, T) {
         // Step 1: Update variance using QE scheme
         let v_next = self.qe_variance_step(v_current, dt, uv);
 
-        // Step 2: Generate correlated Brownian motion
-        let (dw_s, _dw_v) = self.generate_correlated_brownian(z1, z2);
-
-        // Step 3: Update price using mid-point rule
-        let s_next = self.qe_price_step(s_current, v_current, v_next, dt, dw_s);
+        // Step 2: Update price; correlation enters through the variance
+        let s_next = self.qe_price_step(s_current, v_current, v_next, dt, z1);
 
         (s_next, v_next)
     }
@@ -958,13 +967,12 @@ impl<T: Float> HestonModel<T> {
 
         // Clamp u to (eps, 1-eps) to avoid infinities
         let one = T::one();
-        let u_clamped = smooth_max(smooth_max(u, eps, eps), one - eps, eps);
-        let u_safe = if u_clamped > one - eps {
+        let u_safe = if u > one - eps {
             one - eps
-        } else if u_clamped < eps {
+        } else if u < eps {
             eps
         } else {
-            u_clamped
+            u
         };
 
         let y = u_safe - half;
@@ -1054,6 +1062,7 @@ impl<T: Float + Default> StochasticModel<T> for HestonModel<T> {
     /// * `state` - 現在の状態 (price, variance)
     /// * `dt` - タイムステップ
     /// * `dw` - 乱数スライス: [z1 (normal), z2 (normal), uv (uniform)]
+    ///   (uv を省略した場合は z2 から uv = Φ(z2) を生成)
     /// * `params` - モデルパラメータ
     ///
     /// # Returns
@@ -1068,10 +1077,10 @@ impl<T: Float + Default> StochasticModel<T> for HestonModel<T> {
         // dw[2] = uv (uniform for QE scheme)
         let z1 = dw.first().copied().unwrap_or(T::zero());
         let z2 = dw.get(1).copied().unwrap_or(T::zero());
-        let uv = dw
-            .get(2)
-            .copied()
-            .unwrap_or(T::from(0.5).unwrap_or(T::zero()));
+        // uv が無い場合 (dw が brownian_dim = 2 要素) は z2 から uv = Φ(z2) を生成。
+        // 価格との相関は qe_price_step が分散の増分から取り込むため、
+        // uv は z1 と独立でなければならない
+        let uv = dw.get(2).copied().unwrap_or_else(|| norm_cdf(z2));
 
         // QEステップを実行
         let model = HestonModel {
@@ -2149,9 +2158,9 @@ mod tests {
         assert!(v_next >= 0.0_f32, "f32 variance should be non-negative");
     }
 
-    // テスト: 中間点規則（mid-point rule）による価格更新
+    // テスト: Andersen (2008) の価格ステップ
     #[test]
-    fn test_qe_price_mid_point_rule() {
+    fn test_qe_price_step_andersen() {
         let params = HestonParams::new(100.0_f64, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
         let model = HestonModel::new(params).unwrap();
 
@@ -2159,26 +2168,66 @@ mod tests {
         let v_current = 0.04_f64;
         let v_next = 0.05_f64;
         let dt = 1.0 / 252.0;
-        let dw_s = 0.5_f64;
-
-        // Mid-point rule uses average variance: (v_current + v_next) / 2
-        let s_next = model.qe_price_step(s0, v_current, v_next, dt, dw_s);
-
-        // Price should follow log-normal dynamics
-        assert!(s_next > 0.0, "Price should be positive");
+        let z = 0.5_f64;
+
+        let s_next = model.qe_price_step(s0, v_current, v_next, dt, z);
+
+        let (kappa, theta, xi, rho, rate) = (1.5_f64, 0.04, 0.3, -0.7, 0.05);
+        let k0 = -rho * kappa * theta * dt / xi;
+        let k1 = 0.5 * dt * (kappa * rho / xi - 0.5) - rho / xi;
+        let k2 = 0.5 * dt * (kappa * rho / xi - 0.5) + rho / xi;
+        let k3 = 0.5 * dt * (1.0 - rho * rho);
+        let expected = s0
+            * (rate * dt
+                + k0
+                + k1 * v_current
+                + k2 * v_next
+                + (k3 * (v_current + v_next)).sqrt() * z)
+                .exp();
+        // smooth_sqrt differs from sqrt by O(epsilon)
+        assert!(
+            (s_next - expected).abs() < 1e-5,
+            "expected {}, got {}",
+            expected,
+            s_next
+        );
 
-        // Verify mid-point averaging is used
-        let v_avg = (v_current + v_next) / 2.0;
-        let rate = 0.05_f64;
-        let expected_drift = (rate - 0.5 * v_avg) * dt;
-        let expected_diffusion = v_avg.sqrt() * (dt.sqrt()) * dw_s;
-        let expected_s_next = s0 * (expected_drift + expected_diffusion).exp();
+        // Rising variance moves the price against a negative correlation
+        let s_flat = model.qe_price_step(s0, v_current, v_current, dt, z);
+        assert!(s_next < s_flat);
+    }
 
+    // テスト: 価格過程の割引期待値がスポットに一致（マルチンゲール性）
+    #[test]
+    fn test_qe_discounted_price_is_martingale() {
+        let params = HestonParams::new(100.0_f64, 0.04, 0.04, 1.5, 0.6, -0.7, 0.05, 1.0).unwrap();
+        let model = HestonModel::new(params).unwrap();
+        let (n_paths, n_steps) = (20_000, 50);
+        let dt = 1.0 / n_steps as f64;
+
+        // xorshift64* uniforms in (0, 1)
+        let mut rng_state = 0x9E37_79B9_7F4A_7C15_u64;
+        let mut uniform = || {
+            rng_state ^= rng_state >> 12;
+            rng_state ^= rng_state << 25;
+            rng_state ^= rng_state >> 27;
+            ((rng_state.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 + 0.5)
+                / (1u64 << 53) as f64
+        };
+        let mut sum = 0.0;
+        for _ in 0..n_paths {
+            let (mut s, mut v) = (100.0, 0.04);
+            for _ in 0..n_steps {
+                let z1 = model.inverse_normal_cdf(uniform());
+                (s, v) = model.qe_step(s, v, dt, z1, 0.0, uniform());
+            }
+            sum += s;
+        }
+        let discounted_mean = (-0.05_f64).exp() * sum / n_paths as f64;
         assert!(
-            (s_next - expected_s_next).abs() < 1e-7,
-            "Price should follow mid-point rule: expected {}, got {}",
-            expected_s_next,
-            s_next
+            (discounted_mean - 100.0).abs() < 1.0,
+            "E[S_T]e^(-rT) = {}",
+            discounted_mean
         );
     }
 
@@ -2211,6 +2260,26 @@ mod tests {
         assert_eq!(state.second, 0.04, "Initial variance should be v0");
     }
 
+    // テスト: uv 省略時は z2 から分散が更新され、相関は価格ステップが担う
+    #[test]
+    fn test_heston_evolve_step_two_normals_correlated_variance() {
+        let params = HestonParams::new(100.0_f64, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+        let state = HestonModel::initial_state(&params);
+        let dt = 1.0 / 52.0;
+
+        // z1 は価格のみを動かす
+        let up = HestonModel::evolve_step(state, dt, &[1.0, 0.0], &params);
+        let down = HestonModel::evolve_step(state, dt, &[-1.0, 0.0], &params);
+        assert!(up.first > down.first);
+        assert_eq!(up.second, down.second);
+
+        // rho < 0: 分散の上昇ショックは価格を下げる
+        let z2_up = HestonModel::evolve_step(state, dt, &[0.0, 1.0], &params);
+        let z2_down = HestonModel::evolve_step(state, dt, &[0.0, -1.0], &params);
+        assert!(z2_up.second > z2_down.second);
+        assert!(z2_up.first < z2_down.first);
+    }
+
     // テスト: brownian_dimが2を返す（価格と分散の2次元ブラウン運動）
     #[test]
     fn test_heston_stochastic_model_brownian_dim() {
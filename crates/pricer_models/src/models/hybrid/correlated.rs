@@ -74,6 +74,25 @@ pub enum CorrelationError {
         /// Invalid value
         value: f64,
     },
+    /// Repair weight is not positive
+    InvalidWeight {
+        /// Index of the invalid weight
+        index: usize,
+        /// Value of the weight
+        value: f64,
+    },
+    /// Factor index outside the matrix
+    IndexOutOfRange {
+        /// Invalid index
+        index: usize,
+        /// Matrix dimension
+        dim: usize,
+    },
+    /// Eigenvalue floor of a repair is not positive
+    InvalidEigenvalueFloor {
+        /// Invalid floor
+        value: f64,
+    },
 }
 
 impl std::fmt::Display for CorrelationError {
@@ -106,6 +125,19 @@ impl std::fmt::Display for CorrelationError {
                     i, j, value
                 )
             }
+            CorrelationError::InvalidWeight { index, value } => {
+                write!(
+                    f,
+                    "Weight at index {} is {}, must be positive",
+                    index, value
+                )
+            }
+            CorrelationError::IndexOutOfRange { index, dim } => {
+                write!(f, "Index {} is out of range for dimension {}", index, dim)
+            }
+            CorrelationError::InvalidEigenvalueFloor { value } => {
+                write!(f, "Eigenvalue floor is {}, must be positive", value)
+            }
         }
     }
 }
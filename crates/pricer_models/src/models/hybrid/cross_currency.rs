@@ -0,0 +1,976 @@
+//! Cross-currency hybrid scenario generator for exposure simulation.
+//!
+//! This module assembles a multi-currency hybrid model under the domestic
+//! risk-neutral measure:
+//!
+//! - A Hull-White short rate per currency, fitted exactly to its initial
+//!   curve: `r_c(t) = x_c(t) + φ_c(t)`, `dx_c = -a_c x_c dt + σ_c dW_c`
+//! - A lognormal FX rate per foreign currency (domestic units per foreign
+//!   unit): `dX_k / X_k = (r_d - r_k) dt + σ_X dW_X`
+//! - Optional lognormal equities in any currency:
+//!   `dS / S = (r_c - q) dt + σ_S dW_S`
+//! - Optional CIR default intensities:
+//!   `dλ = κ (θ - λ) dt + ξ √λ dW_λ`
+//!
+//! All Brownian drivers are correlated through [`CorrelatedModels`].
+//! Foreign rates and foreign equities carry the quanto drift adjustment
+//! `-ρ_{·,X} σ σ_X` of the change to the domestic measure.
+//!
+//! ## Factor Ordering
+//!
+//! Correlation matrices use the order of [`HybridFactor::index`]: the
+//! domestic rate, then a (rate, FX) pair per foreign currency, then the
+//! equities, then the credit intensities.
+//!
+//! ## Exposure Simulation
+//!
+//! [`CrossCurrencyModel::simulate_path`] returns the state on each date of
+//! an exposure grid (with internal sub-stepping), and
+//! [`CrossCurrencyModel::exposure_values`] revalues a trade on every path
+//! and date, producing the `[scenario][time]` layout used by exposure
+//! aggregation. The state exposes bond prices, FX rates, equity levels,
+//! survival probabilities and the domestic numeraire for revaluation.
+//!
+//! ## Usage
+//!
+//! ```
+//! use pricer_models::models::hybrid::cross_currency::{
+//!     CrossCurrencyModelBuilder, FxFactor, HullWhiteFactor, HybridFactor,
+//! };
+//! use pricer_core::market_data::curves::CurveEnum;
+//!
+//! let usd = HullWhiteFactor::new(0.05, 0.01, CurveEnum::flat(0.04)).unwrap();
+//! let eur = HullWhiteFactor::new(0.03, 0.008, CurveEnum::flat(0.02)).unwrap();
+//! let eurusd = FxFactor::new(1.10, 0.09).unwrap();
+//!
+//! let model = CrossCurrencyModelBuilder::new(usd)
+//!     .foreign(eur, eurusd)
+//!     .correlate(HybridFactor::ForeignRate(0), HybridFactor::Fx(0), -0.2)
+//!     .build()
+//!     .unwrap();
+//!
+//! // Value of a EUR zero-coupon bond in USD on each exposure date of one path
+//! let grid = [0.5, 1.0, 1.5, 2.0];
+//! let path = model.simulate_path(&grid, &mut || 0.1).unwrap();
+//! for state in &path {
+//!     let value = model.fx(state, 0) * model.zero_coupon_bond(state, 1, 3.0).unwrap();
+//!     assert!(value > 0.0);
+//! }
+//! ```
+
+use pricer_core::market_data::curves::{CurveEnum, YieldCurve};
+use pricer_core::market_data::error::MarketDataError;
+use thiserror::Error;
+
+use super::correlated::{CorrelatedModels, CorrelationError, CorrelationMatrix};
+
+/// Bump for the finite-difference instantaneous forward rate.
+const FORWARD_BUMP: f64 = 1e-4;
+
+/// Default largest simulation step.
+const DEFAULT_MAX_STEP: f64 = 1.0 / 12.0;
+
+/// Cross-currency hybrid model errors.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum HybridError {
+    /// A factor parameter is invalid.
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+
+    /// A factor reference is outside the model.
+    #[error("Unknown factor: {0:?}")]
+    UnknownFactor(HybridFactor),
+
+    /// The exposure grid is invalid.
+    #[error("Invalid time grid: {0}")]
+    InvalidGrid(String),
+
+    /// The correlation matrix is invalid.
+    #[error("Invalid correlation: {0}")]
+    Correlation(#[from] CorrelationError),
+
+    /// An initial curve failed to return a discount factor.
+    #[error("Initial curve error: {0}")]
+    Curve(#[from] MarketDataError),
+}
+
+/// Hull-White one-factor short rate of one currency.
+#[derive(Clone, Debug)]
+pub struct HullWhiteFactor {
+    /// Mean reversion speed (a > 0)
+    pub mean_reversion: f64,
+    /// Short rate volatility (σ > 0)
+    pub volatility: f64,
+    /// Initial discount curve
+    pub curve: CurveEnum<f64>,
+}
+
+impl HullWhiteFactor {
+    /// Create a Hull-White factor with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::InvalidParameter` unless the mean reversion
+    /// and volatility are positive and finite.
+    pub fn new(
+        mean_reversion: f64,
+        volatility: f64,
+        curve: CurveEnum<f64>,
+    ) -> Result<Self, HybridError> {
+        if !(mean_reversion > 0.0 && mean_reversion.is_finite()) {
+            return Err(HybridError::InvalidParameter(format!(
+                "Hull-White mean reversion {mean_reversion} (must be positive)"
+            )));
+        }
+        if !(volatility > 0.0 && volatility.is_finite()) {
+            return Err(HybridError::InvalidParameter(format!(
+                "Hull-White volatility {volatility} (must be positive)"
+            )));
+        }
+        Ok(Self {
+            mean_reversion,
+            volatility,
+            curve,
+        })
+    }
+
+    /// `B(t, T) = (1 - e^{-a (T - t)}) / a`.
+    fn b_factor(&self, tau: f64) -> f64 {
+        (1.0 - (-self.mean_reversion * tau).exp()) / self.mean_reversion
+    }
+
+    /// Variance of `∫_t^T x(u) du` given `x(t)`.
+    fn integrated_variance(&self, tau: f64) -> f64 {
+        let a = self.mean_reversion;
+        let s2 = self.volatility * self.volatility;
+        s2 / (a * a)
+            * (tau + 2.0 / a * (-a * tau).exp() - 0.5 / a * (-2.0 * a * tau).exp() - 1.5 / a)
+    }
+
+    /// Curve-fitting shift `φ(t) = f(0, t) + σ² (1 - e^{-a t})² / (2 a²)`.
+    pub fn phi(&self, t: f64) -> Result<f64, HybridError> {
+        let lower = (t - FORWARD_BUMP).max(0.0);
+        let upper = t + FORWARD_BUMP;
+        let forward = (self.curve.discount_factor(lower)?.ln()
+            - self.curve.discount_factor(upper)?.ln())
+            / (upper - lower);
+        let a = self.mean_reversion;
+        let g = (1.0 - (-a * t).exp()) / a;
+        Ok(forward + 0.5 * self.volatility * self.volatility * g * g)
+    }
+
+    /// Zero-coupon bond `P(t, T)` given the factor `x(t)`.
+    ///
+    /// Reprices the initial curve at `t = 0`, `x = 0`.
+    pub fn zero_coupon_bond(&self, t: f64, maturity: f64, x: f64) -> Result<f64, HybridError> {
+        if maturity <= t {
+            return Ok(1.0);
+        }
+        let p_t = self.curve.discount_factor(t)?;
+        let p_maturity = self.curve.discount_factor(maturity)?;
+        let convexity = 0.5
+            * (self.integrated_variance(maturity - t) - self.integrated_variance(maturity)
+                + self.integrated_variance(t));
+        Ok(p_maturity / p_t * (convexity - self.b_factor(maturity - t) * x).exp())
+    }
+}
+
+/// Lognormal FX rate of a foreign currency, quoted in domestic units per
+/// foreign unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FxFactor {
+    /// Spot FX rate (> 0)
+    pub spot: f64,
+    /// FX volatility (> 0)
+    pub volatility: f64,
+}
+
+impl FxFactor {
+    /// Create an FX factor with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::InvalidParameter` unless spot and volatility
+    /// are positive and finite.
+    pub fn new(spot: f64, volatility: f64) -> Result<Self, HybridError> {
+        if !(spot > 0.0 && spot.is_finite() && volatility > 0.0 && volatility.is_finite()) {
+            return Err(HybridError::InvalidParameter(format!(
+                "FX spot {spot}, volatility {volatility} (must be positive)"
+            )));
+        }
+        Ok(Self { spot, volatility })
+    }
+}
+
+/// Lognormal equity denominated in one of the model currencies.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquityFactor {
+    /// Spot price in its own currency (> 0)
+    pub spot: f64,
+    /// Continuous dividend yield
+    pub dividend_yield: f64,
+    /// Equity volatility (> 0)
+    pub volatility: f64,
+    /// Currency: 0 is domestic, `k + 1` is foreign currency `k`
+    pub currency: usize,
+}
+
+impl EquityFactor {
+    /// Create an equity factor with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::InvalidParameter` unless spot and volatility
+    /// are positive and the dividend yield is finite.
+    pub fn new(
+        spot: f64,
+        dividend_yield: f64,
+        volatility: f64,
+        currency: usize,
+    ) -> Result<Self, HybridError> {
+        if !(spot > 0.0
+            && spot.is_finite()
+            && dividend_yield.is_finite()
+            && volatility > 0.0
+            && volatility.is_finite())
+        {
+            return Err(HybridError::InvalidParameter(format!(
+                "equity spot {spot}, dividend yield {dividend_yield}, volatility {volatility}"
+            )));
+        }
+        Ok(Self {
+            spot,
+            dividend_yield,
+            volatility,
+            currency,
+        })
+    }
+}
+
+/// CIR default intensity of one reference entity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CreditFactor {
+    /// Mean reversion speed (κ > 0)
+    pub mean_reversion: f64,
+    /// Long-term intensity (θ ≥ 0)
+    pub long_term_mean: f64,
+    /// Intensity volatility (ξ ≥ 0)
+    pub volatility: f64,
+    /// Initial intensity (λ₀ ≥ 0)
+    pub initial_intensity: f64,
+}
+
+impl CreditFactor {
+    /// Create a CIR credit factor with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::InvalidParameter` unless `κ > 0` and the other
+    /// parameters are non-negative and finite.
+    pub fn new(
+        mean_reversion: f64,
+        long_term_mean: f64,
+        volatility: f64,
+        initial_intensity: f64,
+    ) -> Result<Self, HybridError> {
+        let non_negative = [long_term_mean, volatility, initial_intensity]
+            .iter()
+            .all(|x| *x >= 0.0 && x.is_finite());
+        if !(mean_reversion > 0.0 && mean_reversion.is_finite() && non_negative) {
+            return Err(HybridError::InvalidParameter(format!(
+                "CIR intensity kappa {mean_reversion}, theta {long_term_mean}, \
+                 xi {volatility}, lambda0 {initial_intensity}"
+            )));
+        }
+        Ok(Self {
+            mean_reversion,
+            long_term_mean,
+            volatility,
+            initial_intensity,
+        })
+    }
+
+    /// Survival probability `E[exp(-∫_0^t λ du)]` in closed form.
+    pub fn survival_probability(&self, t: f64) -> f64 {
+        let (kappa, theta, xi) = (self.mean_reversion, self.long_term_mean, self.volatility);
+        let h = (kappa * kappa + 2.0 * xi * xi).sqrt();
+        let growth = (h * t).exp() - 1.0;
+        let denominator = 2.0 * h + (kappa + h) * growth;
+        let b = 2.0 * growth / denominator;
+        let log_a = if xi > 0.0 {
+            2.0 * kappa * theta / (xi * xi)
+                * ((2.0 * h).ln() + 0.5 * (kappa + h) * t - denominator.ln())
+        } else {
+            // Deterministic limit: θ (t - B)
+            -theta * (t - b)
+        };
+        (log_a - b * self.initial_intensity).exp()
+    }
+}
+
+/// Reference to one Brownian driver of the hybrid model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HybridFactor {
+    /// Domestic short rate
+    DomesticRate,
+    /// Short rate of foreign currency `k`
+    ForeignRate(usize),
+    /// FX rate of foreign currency `k`
+    Fx(usize),
+    /// Equity `i`
+    Equity(usize),
+    /// Credit intensity `i`
+    Credit(usize),
+}
+
+impl HybridFactor {
+    /// Position in the correlation matrix of a model with `n_foreign`
+    /// foreign currencies and `n_equities` equities.
+    pub fn index(&self, n_foreign: usize, n_equities: usize) -> usize {
+        match *self {
+            HybridFactor::DomesticRate => 0,
+            HybridFactor::ForeignRate(k) => 1 + 2 * k,
+            HybridFactor::Fx(k) => 2 + 2 * k,
+            HybridFactor::Equity(i) => 1 + 2 * n_foreign + i,
+            HybridFactor::Credit(i) => 1 + 2 * n_foreign + n_equities + i,
+        }
+    }
+}
+
+/// Builder for [`CrossCurrencyModel`].
+#[derive(Clone, Debug)]
+pub struct CrossCurrencyModelBuilder {
+    domestic: HullWhiteFactor,
+    foreign: Vec<(HullWhiteFactor, FxFactor)>,
+    equities: Vec<EquityFactor>,
+    credits: Vec<CreditFactor>,
+    correlations: Vec<(HybridFactor, HybridFactor, f64)>,
+    matrix: Option<CorrelationMatrix<f64>>,
+    max_step: f64,
+}
+
+impl CrossCurrencyModelBuilder {
+    /// Start a model with its domestic short rate.
+    pub fn new(domestic: HullWhiteFactor) -> Self {
+        Self {
+            domestic,
+            foreign: Vec::new(),
+            equities: Vec::new(),
+            credits: Vec::new(),
+            correlations: Vec::new(),
+            matrix: None,
+            max_step: DEFAULT_MAX_STEP,
+        }
+    }
+
+    /// Add a foreign currency with its short rate and FX rate.
+    pub fn foreign(mut self, rate: HullWhiteFactor, fx: FxFactor) -> Self {
+        self.foreign.push((rate, fx));
+        self
+    }
+
+    /// Add an equity.
+    pub fn equity(mut self, equity: EquityFactor) -> Self {
+        self.equities.push(equity);
+        self
+    }
+
+    /// Add a credit intensity.
+    pub fn credit(mut self, credit: CreditFactor) -> Self {
+        self.credits.push(credit);
+        self
+    }
+
+    /// Set the correlation of two factors (zero unless set).
+    pub fn correlate(mut self, first: HybridFactor, second: HybridFactor, rho: f64) -> Self {
+        self.correlations.push((first, second, rho));
+        self
+    }
+
+    /// Use a full correlation matrix in [`HybridFactor::index`] order
+    /// instead of pairwise correlations.
+    pub fn correlation_matrix(mut self, matrix: CorrelationMatrix<f64>) -> Self {
+        self.matrix = Some(matrix);
+        self
+    }
+
+    /// Set the largest simulation step between exposure dates.
+    pub fn max_step(mut self, max_step: f64) -> Self {
+        self.max_step = max_step;
+        self
+    }
+
+    /// Validate the factors and correlation and build the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError` for an equity in an unknown currency, a
+    /// correlation naming an unknown factor, a matrix of the wrong size or
+    /// not positive definite, or a non-positive maximum step.
+    pub fn build(self) -> Result<CrossCurrencyModel, HybridError> {
+        let n_foreign = self.foreign.len();
+        let n_equities = self.equities.len();
+        let dim = 1 + 2 * n_foreign + n_equities + self.credits.len();
+
+        if !(self.max_step > 0.0 && self.max_step.is_finite()) {
+            return Err(HybridError::InvalidGrid(format!(
+                "maximum step {} (must be positive)",
+                self.max_step
+            )));
+        }
+        if let Some(equity) = self.equities.iter().find(|e| e.currency > n_foreign) {
+            return Err(HybridError::InvalidParameter(format!(
+                "equity currency {} (model has {} currencies)",
+                equity.currency,
+                n_foreign + 1
+            )));
+        }
+
+        let matrix = match self.matrix {
+            Some(matrix) if matrix.dim() != dim => {
+                return Err(CorrelationError::InvalidDimensions {
+                    expected: dim * dim,
+                    got: matrix.dim() * matrix.dim(),
+                }
+                .into())
+            }
+            Some(matrix) => matrix,
+            None => {
+                let mut data = vec![0.0; dim * dim];
+                for i in 0..dim {
+                    data[i * dim + i] = 1.0;
+                }
+                for &(first, second, rho) in &self.correlations {
+                    let i = self.checked_index(first)?;
+                    let j = self.checked_index(second)?;
+                    data[i * dim + j] = rho;
+                    data[j * dim + i] = rho;
+                }
+                CorrelationMatrix::new(&data, dim)?
+            }
+        };
+        let drivers = CorrelatedModels::new(matrix.clone())?;
+
+        Ok(CrossCurrencyModel {
+            domestic: self.domestic,
+            foreign: self.foreign,
+            equities: self.equities,
+            credits: self.credits,
+            correlation: matrix,
+            drivers,
+            max_step: self.max_step,
+        })
+    }
+
+    /// Matrix index of `factor`, checked against the factors added so far.
+    fn checked_index(&self, factor: HybridFactor) -> Result<usize, HybridError> {
+        let exists = match factor {
+            HybridFactor::DomesticRate => true,
+            HybridFactor::ForeignRate(k) | HybridFactor::Fx(k) => k < self.foreign.len(),
+            HybridFactor::Equity(i) => i < self.equities.len(),
+            HybridFactor::Credit(i) => i < self.credits.len(),
+        };
+        if !exists {
+            return Err(HybridError::UnknownFactor(factor));
+        }
+        Ok(factor.index(self.foreign.len(), self.equities.len()))
+    }
+}
+
+/// Simulation state of the cross-currency hybrid model.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrossCurrencyState {
+    /// Current time
+    pub time: f64,
+    /// Hull-White factors `x_c`; 0 is domestic, `k + 1` is foreign `k`
+    pub rate_factors: Vec<f64>,
+    /// Log FX rates per foreign currency
+    pub log_fx: Vec<f64>,
+    /// Log equity prices
+    pub log_equities: Vec<f64>,
+    /// Default intensities
+    pub intensities: Vec<f64>,
+    /// `∫_0^t λ du` per credit factor
+    pub integrated_intensities: Vec<f64>,
+    /// `∫_0^t r_d du`
+    pub integrated_rate: f64,
+}
+
+/// Cross-currency hybrid model under the domestic risk-neutral measure.
+///
+/// Built with [`CrossCurrencyModelBuilder`].
+#[derive(Clone, Debug)]
+pub struct CrossCurrencyModel {
+    domestic: HullWhiteFactor,
+    foreign: Vec<(HullWhiteFactor, FxFactor)>,
+    equities: Vec<EquityFactor>,
+    credits: Vec<CreditFactor>,
+    correlation: CorrelationMatrix<f64>,
+    drivers: CorrelatedModels<f64>,
+    max_step: f64,
+}
+
+impl CrossCurrencyModel {
+    /// Number of foreign currencies.
+    pub fn n_foreign(&self) -> usize {
+        self.foreign.len()
+    }
+
+    /// Number of equities.
+    pub fn n_equities(&self) -> usize {
+        self.equities.len()
+    }
+
+    /// Number of credit factors.
+    pub fn n_credits(&self) -> usize {
+        self.credits.len()
+    }
+
+    /// Number of standard normals per step.
+    pub fn brownian_dim(&self) -> usize {
+        self.correlation.dim()
+    }
+
+    /// Correlation of all drivers.
+    pub fn correlation(&self) -> &CorrelationMatrix<f64> {
+        &self.correlation
+    }
+
+    /// Largest simulation step.
+    pub fn max_step(&self) -> f64 {
+        self.max_step
+    }
+
+    /// Short rate factor of currency `c` (0 is domestic).
+    pub fn rate_factor(&self, c: usize) -> &HullWhiteFactor {
+        if c == 0 {
+            &self.domestic
+        } else {
+            &self.foreign[c - 1].0
+        }
+    }
+
+    /// Correlation of two factors.
+    fn rho(&self, first: HybridFactor, second: HybridFactor) -> f64 {
+        let (n_f, n_e) = (self.n_foreign(), self.n_equities());
+        self.correlation
+            .get(first.index(n_f, n_e), second.index(n_f, n_e))
+    }
+
+    /// State at time zero.
+    pub fn initial_state(&self) -> CrossCurrencyState {
+        CrossCurrencyState {
+            time: 0.0,
+            rate_factors: vec![0.0; 1 + self.n_foreign()],
+            log_fx: self.foreign.iter().map(|(_, fx)| fx.spot.ln()).collect(),
+            log_equities: self.equities.iter().map(|e| e.spot.ln()).collect(),
+            intensities: self.credits.iter().map(|c| c.initial_intensity).collect(),
+            integrated_intensities: vec![0.0; self.n_credits()],
+            integrated_rate: 0.0,
+        }
+    }
+
+    /// Short rates of all currencies at time `t` for factors `x`.
+    fn short_rates(&self, t: f64, x: &[f64]) -> Result<Vec<f64>, HybridError> {
+        x.iter()
+            .enumerate()
+            .map(|(c, xc)| Ok(self.rate_factor(c).phi(t)? + xc))
+            .collect()
+    }
+
+    /// Evolve `state` by `dt` with independent standard normals `z`
+    /// (length [`brownian_dim`](Self::brownian_dim)).
+    ///
+    /// Rate factors use the exact Ornstein-Uhlenbeck transition; FX and
+    /// equities integrate the rate drift by the trapezoid rule; intensities
+    /// use full-truncation Euler.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::Curve` if an initial curve fails.
+    pub fn evolve(
+        &self,
+        state: &mut CrossCurrencyState,
+        dt: f64,
+        z: &[f64],
+    ) -> Result<(), HybridError> {
+        let (n_f, n_e) = (self.n_foreign(), self.n_equities());
+        let t = state.time;
+        let sqrt_dt = dt.sqrt();
+        let mut w = z[..self.brownian_dim()].to_vec();
+        self.drivers.correlate_inplace(&mut w);
+
+        let rates_start = self.short_rates(t, &state.rate_factors)?;
+
+        // Hull-White factors, with the quanto drift for foreign currencies
+        for c in 0..=n_f {
+            let hw = self.rate_factor(c);
+            let (a, sigma) = (hw.mean_reversion, hw.volatility);
+            let decay = (-a * dt).exp();
+            let sd = sigma * ((1.0 - decay * decay) / (2.0 * a)).sqrt();
+            let (driver, quanto) = if c == 0 {
+                (HybridFactor::DomesticRate, 0.0)
+            } else {
+                let k = c - 1;
+                let fx_vol = self.foreign[k].1.volatility;
+                let rho = self.rho(HybridFactor::ForeignRate(k), HybridFactor::Fx(k));
+                (HybridFactor::ForeignRate(k), rho * sigma * fx_vol)
+            };
+            state.rate_factors[c] = state.rate_factors[c] * decay - quanto * (1.0 - decay) / a
+                + sd * w[driver.index(n_f, n_e)];
+        }
+        let rates_end = self.short_rates(t + dt, &state.rate_factors)?;
+        let average_rate = |c: usize| 0.5 * (rates_start[c] + rates_end[c]);
+
+        // FX rates: d ln X = (r_d - r_f - σ²/2) dt + σ dW
+        for (k, (_, fx)) in self.foreign.iter().enumerate() {
+            let vol = fx.volatility;
+            let w_fx = w[HybridFactor::Fx(k).index(n_f, n_e)];
+            state.log_fx[k] += (average_rate(0) - average_rate(k + 1) - 0.5 * vol * vol) * dt
+                + vol * sqrt_dt * w_fx;
+        }
+
+        // Equities, with the quanto drift for foreign denominations
+        for (i, equity) in self.equities.iter().enumerate() {
+            let vol = equity.volatility;
+            let quanto = match equity.currency {
+                0 => 0.0,
+                c => {
+                    let fx_vol = self.foreign[c - 1].1.volatility;
+                    self.rho(HybridFactor::Equity(i), HybridFactor::Fx(c - 1)) * vol * fx_vol
+                }
+            };
+            let drift = average_rate(equity.currency) - equity.dividend_yield - quanto;
+            let w_eq = w[HybridFactor::Equity(i).index(n_f, n_e)];
+            state.log_equities[i] += (drift - 0.5 * vol * vol) * dt + vol * sqrt_dt * w_eq;
+        }
+
+        // CIR intensities by full truncation
+        for (i, credit) in self.credits.iter().enumerate() {
+            let start = state.intensities[i].max(0.0);
+            let w_cr = w[HybridFactor::Credit(i).index(n_f, n_e)];
+            let next = state.intensities[i]
+                + credit.mean_reversion * (credit.long_term_mean - start) * dt
+                + credit.volatility * start.sqrt() * sqrt_dt * w_cr;
+            state.integrated_intensities[i] += 0.5 * (start + next.max(0.0)) * dt;
+            state.intensities[i] = next;
+        }
+
+        state.integrated_rate += average_rate(0) * dt;
+        state.time = t + dt;
+        Ok(())
+    }
+
+    /// Simulate one path and return the state on each date of `grid`.
+    ///
+    /// Steps between exposure dates are split so that none exceeds
+    /// [`max_step`](Self::max_step). `normals` supplies independent
+    /// standard normals.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::InvalidGrid` unless the grid is non-negative
+    /// and strictly increasing, or `HybridError::Curve` if a curve fails.
+    pub fn simulate_path(
+        &self,
+        grid: &[f64],
+        normals: &mut impl FnMut() -> f64,
+    ) -> Result<Vec<CrossCurrencyState>, HybridError> {
+        validate_grid(grid)?;
+        let mut state = self.initial_state();
+        let mut z = vec![0.0; self.brownian_dim()];
+        let mut states = Vec::with_capacity(grid.len());
+        for &date in grid {
+            let span = date - state.time;
+            let steps = (span / self.max_step).ceil() as usize;
+            for _ in 0..steps {
+                z.iter_mut().for_each(|zi| *zi = normals());
+                self.evolve(&mut state, span / steps as f64, &z)?;
+            }
+            state.time = date;
+            states.push(state.clone());
+        }
+        Ok(states)
+    }
+
+    /// Simulate `n_paths` paths and revalue a trade on each exposure date.
+    ///
+    /// `revalue(model, state)` returns the trade value in domestic currency.
+    /// The result is `values[scenario][time]`, the layout consumed by
+    /// exposure aggregation; multiply by [`deflator`](Self::deflator) inside
+    /// `revalue` for discounted exposures.
+    ///
+    /// # Errors
+    ///
+    /// As [`simulate_path`](Self::simulate_path).
+    pub fn exposure_values(
+        &self,
+        grid: &[f64],
+        n_paths: usize,
+        normals: &mut impl FnMut() -> f64,
+        mut revalue: impl FnMut(&Self, &CrossCurrencyState) -> f64,
+    ) -> Result<Vec<Vec<f64>>, HybridError> {
+        (0..n_paths)
+            .map(|_| {
+                Ok(self
+                    .simulate_path(grid, normals)?
+                    .iter()
+                    .map(|state| revalue(self, state))
+                    .collect())
+            })
+            .collect()
+    }
+
+    /// Short rate of currency `c` (0 is domestic).
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::Curve` if the initial curve fails.
+    pub fn short_rate(&self, state: &CrossCurrencyState, c: usize) -> Result<f64, HybridError> {
+        Ok(self.rate_factor(c).phi(state.time)? + state.rate_factors[c])
+    }
+
+    /// Zero-coupon bond of currency `c` maturing at `maturity`, in that
+    /// currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HybridError::Curve` if the initial curve fails.
+    pub fn zero_coupon_bond(
+        &self,
+        state: &CrossCurrencyState,
+        c: usize,
+        maturity: f64,
+    ) -> Result<f64, HybridError> {
+        self.rate_factor(c)
+            .zero_coupon_bond(state.time, maturity, state.rate_factors[c])
+    }
+
+    /// FX rate of foreign currency `k` in domestic units.
+    pub fn fx(&self, state: &CrossCurrencyState, k: usize) -> f64 {
+        state.log_fx[k].exp()
+    }
+
+    /// Price of equity `i` in its own currency.
+    pub fn equity(&self, state: &CrossCurrencyState, i: usize) -> f64 {
+        state.log_equities[i].exp()
+    }
+
+    /// Survival probability `exp(-∫_0^t λ_i du)` of credit `i` conditional
+    /// on the intensity path.
+    pub fn survival(&self, state: &CrossCurrencyState, i: usize) -> f64 {
+        (-state.integrated_intensities[i]).exp()
+    }
+
+    /// Domestic bank account `exp(∫_0^t r_d du)`.
+    pub fn numeraire(&self, state: &CrossCurrencyState) -> f64 {
+        state.integrated_rate.exp()
+    }
+
+    /// Stochastic discount factor `1 / numeraire` to today.
+    pub fn deflator(&self, state: &CrossCurrencyState) -> f64 {
+        (-state.integrated_rate).exp()
+    }
+}
+
+/// Checks that the exposure grid is non-negative and strictly increasing.
+fn validate_grid(grid: &[f64]) -> Result<(), HybridError> {
+    let increasing = grid.windows(2).all(|w| w[1] > w[0]);
+    if grid.is_empty() || grid[0] < 0.0 || !increasing || !grid.iter().all(|t| t.is_finite()) {
+        return Err(HybridError::InvalidGrid(format!(
+            "{grid:?} (need non-negative, strictly increasing dates)"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::normals;
+    use pricer_core::market_data::curves::{CurveInterpolation, InterpolatedCurve};
+
+    fn usd() -> HullWhiteFactor {
+        let times = [0.5, 1.0, 2.0, 5.0, 10.0];
+        let rates = [0.045, 0.043, 0.04, 0.038, 0.039];
+        let curve = CurveEnum::Interpolated(
+            InterpolatedCurve::new(&times, &rates, CurveInterpolation::Linear, true).unwrap(),
+        );
+        HullWhiteFactor::new(0.05, 0.012, curve).unwrap()
+    }
+
+    fn eur() -> HullWhiteFactor {
+        HullWhiteFactor::new(0.03, 0.009, CurveEnum::flat(0.025)).unwrap()
+    }
+
+    /// USD domestic, EUR foreign, a EUR equity and a credit intensity.
+    fn model() -> CrossCurrencyModel {
+        CrossCurrencyModelBuilder::new(usd())
+            .foreign(eur(), FxFactor::new(1.1, 0.1).unwrap())
+            .equity(EquityFactor::new(50.0, 0.02, 0.25, 1).unwrap())
+            .credit(CreditFactor::new(0.5, 0.02, 0.08, 0.01).unwrap())
+            .correlate(
+                HybridFactor::DomesticRate,
+                HybridFactor::ForeignRate(0),
+                0.5,
+            )
+            .correlate(HybridFactor::ForeignRate(0), HybridFactor::Fx(0), -0.4)
+            .correlate(HybridFactor::DomesticRate, HybridFactor::Fx(0), 0.2)
+            .correlate(HybridFactor::Equity(0), HybridFactor::Fx(0), 0.3)
+            .correlate(HybridFactor::Equity(0), HybridFactor::ForeignRate(0), -0.1)
+            .correlate(HybridFactor::Credit(0), HybridFactor::Equity(0), -0.3)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_factor_validation() {
+        assert!(HullWhiteFactor::new(0.0, 0.01, CurveEnum::flat(0.03)).is_err());
+        assert!(HullWhiteFactor::new(0.1, -0.01, CurveEnum::flat(0.03)).is_err());
+        assert!(FxFactor::new(0.0, 0.1).is_err());
+        assert!(EquityFactor::new(100.0, 0.0, 0.0, 0).is_err());
+        assert!(CreditFactor::new(0.0, 0.02, 0.1, 0.01).is_err());
+        assert!(CreditFactor::new(0.5, -0.02, 0.1, 0.01).is_err());
+
+        // Equity in a currency that does not exist
+        let equity = EquityFactor::new(100.0, 0.0, 0.2, 1).unwrap();
+        assert!(CrossCurrencyModelBuilder::new(usd())
+            .equity(equity)
+            .build()
+            .is_err());
+        // Correlation with an unknown factor
+        assert!(matches!(
+            CrossCurrencyModelBuilder::new(usd())
+                .correlate(HybridFactor::DomesticRate, HybridFactor::Fx(0), 0.3)
+                .build(),
+            Err(HybridError::UnknownFactor(HybridFactor::Fx(0)))
+        ));
+        // Matrix of the wrong size or not positive definite
+        let matrix = CorrelationMatrix::identity(2);
+        assert!(CrossCurrencyModelBuilder::new(usd())
+            .correlation_matrix(matrix)
+            .build()
+            .is_err());
+        assert!(matches!(
+            CrossCurrencyModelBuilder::new(usd())
+                .foreign(eur(), FxFactor::new(1.1, 0.1).unwrap())
+                .correlate(
+                    HybridFactor::DomesticRate,
+                    HybridFactor::ForeignRate(0),
+                    0.9
+                )
+                .correlate(HybridFactor::DomesticRate, HybridFactor::Fx(0), 0.9)
+                .correlate(HybridFactor::ForeignRate(0), HybridFactor::Fx(0), -0.9)
+                .build(),
+            Err(HybridError::Correlation(
+                CorrelationError::NotPositiveDefinite
+            ))
+        ));
+        assert!(model().simulate_path(&[1.0, 1.0], &mut normals(1)).is_err());
+        assert!(model().simulate_path(&[], &mut normals(1)).is_err());
+    }
+
+    #[test]
+    fn test_factor_layout() {
+        let model = model();
+        assert_eq!(model.brownian_dim(), 5);
+        assert_eq!(HybridFactor::Fx(0).index(1, 1), 2);
+        assert_eq!(HybridFactor::Equity(0).index(1, 1), 3);
+        assert_eq!(HybridFactor::Credit(0).index(1, 1), 4);
+        assert_eq!(model.correlation().get(1, 2), -0.4);
+        assert_eq!(model.correlation().get(4, 3), -0.3);
+    }
+
+    #[test]
+    fn test_bonds_reprice_initial_curves() {
+        let model = model();
+        let state = model.initial_state();
+        for c in 0..2 {
+            for maturity in [0.5, 1.0, 3.0, 7.5] {
+                let bond = model.zero_coupon_bond(&state, c, maturity).unwrap();
+                let curve = model
+                    .rate_factor(c)
+                    .curve
+                    .discount_factor(maturity)
+                    .unwrap();
+                assert!((bond - curve).abs() < 1e-14);
+            }
+        }
+        assert_eq!(model.deflator(&state), 1.0);
+        assert!((model.fx(&state, 0) - 1.1).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_credit_survival_closed_form() {
+        let credit = CreditFactor::new(0.5, 0.02, 0.08, 0.01).unwrap();
+        assert!((credit.survival_probability(0.0) - 1.0).abs() < 1e-14);
+        // Deterministic limit: λ' = κ (θ - λ)
+        let deterministic = CreditFactor::new(0.5, 0.02, 0.0, 0.01).unwrap();
+        let t: f64 = 3.0;
+        let integral = 0.02 * t + (0.01 - 0.02) * (1.0 - (-0.5 * t).exp()) / 0.5;
+        assert!((deterministic.survival_probability(t) - (-integral).exp()).abs() < 1e-12);
+        assert!(credit.survival_probability(5.0) > deterministic.survival_probability(5.0));
+    }
+
+    #[test]
+    fn test_monte_carlo_martingales() {
+        let model = model();
+        let grid = [1.0, 2.0, 3.0];
+        let paths = 20_000;
+        let usd_bond = 5.0;
+        let eur_bond = 4.0;
+        // Columns: domestic bond, foreign bond in USD, foreign equity in USD,
+        // survival
+        let mut sums = vec![[0.0; 4]; grid.len()];
+        let values = model
+            .exposure_values(&grid, paths, &mut normals(7), |model, state| {
+                let k = grid.iter().position(|t| *t == state.time).unwrap();
+                let deflator = model.deflator(state);
+                let fx = model.fx(state, 0);
+                sums[k][0] += deflator * model.zero_coupon_bond(state, 0, usd_bond).unwrap();
+                sums[k][1] += deflator * fx * model.zero_coupon_bond(state, 1, eur_bond).unwrap();
+                sums[k][2] += deflator * fx * model.equity(state, 0) * (0.02 * state.time).exp();
+                sums[k][3] += model.survival(state, 0);
+                deflator * fx
+            })
+            .unwrap();
+        assert_eq!(values.len(), paths);
+        assert_eq!(values[0].len(), grid.len());
+
+        let usd_curve = &model.rate_factor(0).curve;
+        let eur_curve = &model.rate_factor(1).curve;
+        let credit = CreditFactor::new(0.5, 0.02, 0.08, 0.01).unwrap();
+        for (k, &t) in grid.iter().enumerate() {
+            let mean = |m: usize| sums[k][m] / paths as f64;
+            let expected_usd = usd_curve.discount_factor(usd_bond).unwrap();
+            let expected_eur = 1.1 * eur_curve.discount_factor(eur_bond).unwrap();
+            let expected_equity = 1.1 * 50.0;
+            let expected_survival = credit.survival_probability(t);
+            assert!(
+                (mean(0) - expected_usd).abs() < 2e-3 * expected_usd,
+                "t = {t}: USD bond {} vs {expected_usd}",
+                mean(0)
+            );
+            assert!(
+                (mean(1) - expected_eur).abs() < 5e-3 * expected_eur,
+                "t = {t}: EUR bond {} vs {expected_eur}",
+                mean(1)
+            );
+            assert!(
+                (mean(2) - expected_equity).abs() < 1.5e-2 * expected_equity,
+                "t = {t}: EUR equity {} vs {expected_equity}",
+                mean(2)
+            );
+            assert!(
+                (mean(3) - expected_survival).abs() < 1e-3,
+                "t = {t}: survival {} vs {expected_survival}",
+                mean(3)
+            );
+        }
+
+        // Discounted FX forward exposure is a martingale: mean of deflated FX
+        let discounted_fx: f64 = values.iter().map(|v| v[2]).sum::<f64>() / paths as f64;
+        let expected = 1.1 * eur_curve.discount_factor(3.0).unwrap();
+        assert!((discounted_fx - expected).abs() < 5e-3 * expected);
+    }
+}
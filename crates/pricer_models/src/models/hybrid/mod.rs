@@ -3,11 +3,16 @@
 //! This module provides models for multi-factor processes:
 //! - [`CorrelatedModels`]: Correlate multiple stochastic models via Cholesky decomposition
 //! - [`CorrelationMatrix`]: Validated correlation matrix with Cholesky factorization
+//! - [`CrossCurrencyModel`]: Multi-currency Hull-White / FX / equity / credit
+//!   scenario generator for exposure simulation
+//! - [`nearest_correlation`](nearest_correlation::nearest_correlation) /
+//!   [`clip_eigenvalues`]: Repair of indefinite correlation matrices
 //!
 //! # Feature Flag
 //!
 //! This module is available when the `exotic` feature is enabled
-//! (hybrid models are typically used for exotic derivatives).
+//! (hybrid models are typically used for exotic derivatives), and with
+//! `rates` for the correlated forwards of the LIBOR market model.
 //!
 //! # Correlated Brownian Motions
 //!
@@ -34,6 +39,15 @@
 //! ```
 
 pub mod correlated;
+pub mod cross_currency;
+pub mod nearest_correlation;
 
 // Re-export main types
 pub use correlated::{CholeskyFactor, CorrelatedModels, CorrelationError, CorrelationMatrix};
+pub use cross_currency::{
+    CreditFactor, CrossCurrencyModel, CrossCurrencyModelBuilder, CrossCurrencyState, EquityFactor,
+    FxFactor, HullWhiteFactor, HybridError, HybridFactor,
+};
+pub use nearest_correlation::{
+    clip_eigenvalues, nearest_correlation, NearestCorrelationConfig, RepairedCorrelation,
+};
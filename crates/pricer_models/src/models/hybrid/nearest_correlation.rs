@@ -0,0 +1,567 @@
+//! Nearest correlation matrix repair.
+//!
+//! Estimated correlation matrices (pairwise estimates, missing data,
+//! stressed or hand-edited entries) are often indefinite, so
+//! [`CorrelationMatrix::cholesky`] fails. This module maps such a matrix to
+//! a nearby positive definite correlation matrix:
+//!
+//! - [`nearest_correlation`]: Higham's alternating projections with
+//!   Dykstra's correction, minimising the weighted Frobenius distance
+//!   `‖W^{1/2} (A - X) W^{1/2}‖_F` for a diagonal weight `W`, optionally
+//!   keeping blocks of entries fixed
+//! - [`clip_eigenvalues`]: one spectral projection with eigenvalues floored
+//!   at a minimum and rescaled to a unit diagonal; cheaper but not optimal
+//!
+//! Both report the (unweighted) Frobenius distance between input and
+//! output, so the size of the repair can be monitored.
+//!
+//! ## Usage
+//!
+//! ```
+//! use pricer_models::models::hybrid::nearest_correlation::{
+//!     nearest_correlation, NearestCorrelationConfig,
+//! };
+//!
+//! // Pairwise estimates that are jointly inconsistent
+//! let estimate = [
+//!     1.0_f64, 0.9, 0.7,
+//!     0.9, 1.0, -0.3,
+//!     0.7, -0.3, 1.0,
+//! ];
+//! let repaired = nearest_correlation(&estimate, 3, &NearestCorrelationConfig::default()).unwrap();
+//! assert!(repaired.converged);
+//! assert!(repaired.matrix.cholesky().is_ok());
+//! assert!(repaired.frobenius_distance > 0.0);
+//! ```
+//!
+//! ## References
+//!
+//! - Higham, N. J. (2002). "Computing the nearest correlation matrix - a
+//!   problem from finance". IMA Journal of Numerical Analysis, 22(3).
+
+use super::correlated::{CorrelationError, CorrelationMatrix};
+
+/// Largest number of Jacobi sweeps of the eigenvalue decomposition.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Symmetry tolerance of input matrices.
+const SYMMETRY_TOLERANCE: f64 = 1e-10;
+
+/// Settings of [`nearest_correlation`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearestCorrelationConfig {
+    /// Positive diagonal weights `W` (one per row); equal weights if `None`.
+    /// Larger weights keep the corresponding rows closer to the input.
+    pub weights: Option<Vec<f64>>,
+    /// Blocks of indices whose mutual correlations are kept at their input
+    /// values. Each block must itself be positive definite.
+    pub fixed_blocks: Vec<Vec<usize>>,
+    /// Positive eigenvalue floor of the result, keeping it positive
+    /// definite. Should exceed `tolerance` times the matrix norm, the
+    /// distance by which the final unit-diagonal iterate may undershoot it.
+    pub min_eigenvalue: f64,
+    /// Convergence tolerance on the change between iterations, relative to
+    /// the matrix norm.
+    pub tolerance: f64,
+    /// Maximum number of alternating projections.
+    pub max_iterations: usize,
+}
+
+impl Default for NearestCorrelationConfig {
+    fn default() -> Self {
+        Self {
+            weights: None,
+            fixed_blocks: Vec::new(),
+            min_eigenvalue: 1e-8,
+            tolerance: 1e-10,
+            max_iterations: 10_000,
+        }
+    }
+}
+
+impl NearestCorrelationConfig {
+    /// Set diagonal weights.
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Keep the correlations within `block` at their input values.
+    pub fn with_fixed_block(mut self, block: Vec<usize>) -> Self {
+        self.fixed_blocks.push(block);
+        self
+    }
+
+    /// Set the eigenvalue floor.
+    pub fn with_min_eigenvalue(mut self, min_eigenvalue: f64) -> Self {
+        self.min_eigenvalue = min_eigenvalue;
+        self
+    }
+}
+
+/// Repaired correlation matrix with diagnostics.
+#[derive(Clone, Debug)]
+pub struct RepairedCorrelation {
+    /// Positive definite correlation matrix
+    pub matrix: CorrelationMatrix<f64>,
+    /// Frobenius norm of the change `‖output - input‖_F`
+    pub frobenius_distance: f64,
+    /// Number of projections performed (one for eigenvalue clipping)
+    pub iterations: usize,
+    /// Whether the alternating projections met the tolerance
+    pub converged: bool,
+}
+
+/// Nearest correlation matrix by Higham's alternating projections.
+///
+/// Alternates between the projection onto positive semidefinite matrices
+/// (with eigenvalues floored at `config.min_eigenvalue`, in the weighted
+/// norm) and the projection onto symmetric matrices with a unit diagonal
+/// and the fixed blocks at their input values, applying Dykstra's
+/// correction to the former.
+///
+/// # Arguments
+///
+/// * `data` - Symmetric input matrix in row-major order (`dim * dim`)
+/// * `dim` - Matrix dimension
+/// * `config` - Weights, fixed blocks and convergence settings
+///
+/// # Errors
+///
+/// Returns `CorrelationError` if the input has the wrong size, is not
+/// symmetric or not finite, a weight or the eigenvalue floor is not
+/// positive, a block index is out of range, or the result is not positive definite (for example because
+/// the fixed blocks are inconsistent).
+pub fn nearest_correlation(
+    data: &[f64],
+    dim: usize,
+    config: &NearestCorrelationConfig,
+) -> Result<RepairedCorrelation, CorrelationError> {
+    validate_input(data, dim)?;
+    validate_floor(config.min_eigenvalue)?;
+    let weights = match &config.weights {
+        Some(weights) => {
+            if weights.len() != dim {
+                return Err(CorrelationError::InvalidDimensions {
+                    expected: dim,
+                    got: weights.len(),
+                });
+            }
+            if let Some((index, &value)) = weights
+                .iter()
+                .enumerate()
+                .find(|(_, w)| !(**w > 0.0 && w.is_finite()))
+            {
+                return Err(CorrelationError::InvalidWeight { index, value });
+            }
+            weights.clone()
+        }
+        None => vec![1.0; dim],
+    };
+    let mut fixed = vec![false; dim * dim];
+    for block in &config.fixed_blocks {
+        if let Some(&index) = block.iter().find(|&&i| i >= dim) {
+            return Err(CorrelationError::IndexOutOfRange { index, dim });
+        }
+        for &i in block {
+            for &j in block {
+                fixed[i * dim + j] = true;
+            }
+        }
+    }
+
+    let sqrt_w: Vec<f64> = weights.iter().map(|w| w.sqrt()).collect();
+    let norm = frobenius(data).max(1.0);
+    let mut x = data.to_vec();
+    let mut correction = vec![0.0; dim * dim];
+    let mut iterations = 0;
+    let mut converged = false;
+
+    while iterations < config.max_iterations {
+        iterations += 1;
+        // Dykstra-corrected projection onto the eigenvalue-floored cone
+        let r: Vec<f64> = x.iter().zip(&correction).map(|(x, c)| x - c).collect();
+        let y = weighted_psd_projection(&r, dim, &sqrt_w, config.min_eigenvalue);
+        for ((c, y), r) in correction.iter_mut().zip(&y).zip(&r) {
+            *c = y - r;
+        }
+        // Projection onto unit diagonal and fixed entries
+        let mut next = y.clone();
+        for i in 0..dim {
+            for j in 0..dim {
+                let idx = i * dim + j;
+                if i == j {
+                    next[idx] = 1.0;
+                } else if fixed[idx] {
+                    next[idx] = data[idx];
+                }
+            }
+        }
+        let change = frobenius_difference(&next, &x);
+        let gap = frobenius_difference(&next, &y);
+        x = next;
+        if change.max(gap) <= config.tolerance * norm {
+            converged = true;
+            break;
+        }
+    }
+
+    finish(x, data, dim, iterations, converged)
+}
+
+/// Eigenvalue clipping: floor the eigenvalues at `min_eigenvalue` and
+/// rescale to a unit diagonal.
+///
+/// A single spectral projection; the result is positive definite but in
+/// general not the nearest correlation matrix.
+///
+/// # Errors
+///
+/// Returns `CorrelationError` if the input has the wrong size, is not
+/// symmetric or not finite, or `min_eigenvalue` is not positive.
+pub fn clip_eigenvalues(
+    data: &[f64],
+    dim: usize,
+    min_eigenvalue: f64,
+) -> Result<RepairedCorrelation, CorrelationError> {
+    validate_input(data, dim)?;
+    validate_floor(min_eigenvalue)?;
+    let clipped = weighted_psd_projection(data, dim, &vec![1.0; dim], min_eigenvalue);
+    let scale: Vec<f64> = (0..dim)
+        .map(|i| 1.0 / clipped[i * dim + i].sqrt())
+        .collect();
+    let mut x = clipped;
+    for i in 0..dim {
+        for j in 0..dim {
+            x[i * dim + j] = if i == j {
+                1.0
+            } else {
+                x[i * dim + j] * scale[i] * scale[j]
+            };
+        }
+    }
+    finish(x, data, dim, 1, true)
+}
+
+impl CorrelationMatrix<f64> {
+    /// Nearest correlation matrix by Higham's alternating projections.
+    ///
+    /// See [`nearest_correlation`].
+    pub fn nearest_correlation(
+        &self,
+        config: &NearestCorrelationConfig,
+    ) -> Result<RepairedCorrelation, CorrelationError> {
+        nearest_correlation(&self.to_vec(), self.dim(), config)
+    }
+
+    /// Positive definite repair by eigenvalue clipping.
+    ///
+    /// See [`clip_eigenvalues`].
+    pub fn clip_eigenvalues(
+        &self,
+        min_eigenvalue: f64,
+    ) -> Result<RepairedCorrelation, CorrelationError> {
+        clip_eigenvalues(&self.to_vec(), self.dim(), min_eigenvalue)
+    }
+
+    /// Elements in row-major order.
+    fn to_vec(&self) -> Vec<f64> {
+        let n = self.dim();
+        (0..n * n).map(|k| self.get(k / n, k % n)).collect()
+    }
+}
+
+/// Symmetrises the result, checks it and measures the distance moved.
+fn finish(
+    mut x: Vec<f64>,
+    data: &[f64],
+    dim: usize,
+    iterations: usize,
+    converged: bool,
+) -> Result<RepairedCorrelation, CorrelationError> {
+    for i in 0..dim {
+        x[i * dim + i] = 1.0;
+        for j in 0..i {
+            let value = (0.5 * (x[i * dim + j] + x[j * dim + i])).clamp(-1.0, 1.0);
+            x[i * dim + j] = value;
+            x[j * dim + i] = value;
+        }
+    }
+    let matrix = CorrelationMatrix::new(&x, dim)?;
+    matrix.cholesky()?;
+    Ok(RepairedCorrelation {
+        frobenius_distance: frobenius_difference(&x, data),
+        matrix,
+        iterations,
+        converged,
+    })
+}
+
+/// Checks size, finiteness and symmetry of an input matrix.
+fn validate_input(data: &[f64], dim: usize) -> Result<(), CorrelationError> {
+    if data.len() != dim * dim {
+        return Err(CorrelationError::InvalidDimensions {
+            expected: dim * dim,
+            got: data.len(),
+        });
+    }
+    for i in 0..dim {
+        for j in 0..=i {
+            let (a, b) = (data[i * dim + j], data[j * dim + i]);
+            if !(a.is_finite() && b.is_finite()) || (a - b).abs() > SYMMETRY_TOLERANCE {
+                return Err(CorrelationError::NotSymmetric { i, j });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the eigenvalue floor is positive.
+fn validate_floor(min_eigenvalue: f64) -> Result<(), CorrelationError> {
+    if !(min_eigenvalue > 0.0 && min_eigenvalue.is_finite()) {
+        return Err(CorrelationError::InvalidEigenvalueFloor {
+            value: min_eigenvalue,
+        });
+    }
+    Ok(())
+}
+
+/// Projection onto `{X : λ_min(W^{1/2} X W^{1/2}) ≥ floor}` in the
+/// weighted norm.
+fn weighted_psd_projection(data: &[f64], dim: usize, sqrt_w: &[f64], floor: f64) -> Vec<f64> {
+    let mut scaled = data.to_vec();
+    for i in 0..dim {
+        for j in 0..dim {
+            scaled[i * dim + j] *= sqrt_w[i] * sqrt_w[j];
+        }
+    }
+    let (values, vectors) = symmetric_eigen(&scaled, dim);
+    let mut out = vec![0.0; dim * dim];
+    for (k, &value) in values.iter().enumerate() {
+        let value = value.max(floor);
+        for i in 0..dim {
+            let vi = vectors[i * dim + k] * value;
+            for j in 0..dim {
+                out[i * dim + j] += vi * vectors[j * dim + k];
+            }
+        }
+    }
+    for i in 0..dim {
+        for j in 0..dim {
+            out[i * dim + j] /= sqrt_w[i] * sqrt_w[j];
+        }
+    }
+    out
+}
+
+/// Eigenvalues and eigenvectors (columns, row-major) of a symmetric matrix
+/// by cyclic Jacobi rotations.
+fn symmetric_eigen(data: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = data.to_vec();
+    let mut v = vec![0.0; n * n];
+    for i in 0..n {
+        v[i * n + i] = 1.0;
+    }
+    let scale = frobenius(data).max(f64::MIN_POSITIVE);
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i * n + j] * a[i * n + j])
+            .sum::<f64>()
+            .sqrt();
+        if off <= 1e-15 * scale {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+                let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    ((0..n).map(|i| a[i * n + i]).collect(), v)
+}
+
+/// Frobenius norm.
+fn frobenius(data: &[f64]) -> f64 {
+    data.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Frobenius norm of `a - b`.
+fn frobenius_difference(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Indefinite pairwise estimate (λ_min < 0).
+    fn indefinite() -> [f64; 16] {
+        [
+            1.0, 0.9, 0.7, 0.3, //
+            0.9, 1.0, -0.3, 0.4, //
+            0.7, -0.3, 1.0, 0.6, //
+            0.3, 0.4, 0.6, 1.0,
+        ]
+    }
+
+    fn min_eigenvalue(matrix: &CorrelationMatrix<f64>) -> f64 {
+        let n = matrix.dim();
+        let data: Vec<f64> = (0..n * n).map(|k| matrix.get(k / n, k % n)).collect();
+        symmetric_eigen(&data, n)
+            .0
+            .into_iter()
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_symmetric_eigen_reconstructs() {
+        let a = indefinite();
+        let (values, vectors) = symmetric_eigen(&a, 4);
+        for i in 0..4 {
+            for j in 0..4 {
+                let rebuilt: f64 = (0..4)
+                    .map(|k| vectors[i * 4 + k] * values[k] * vectors[j * 4 + k])
+                    .sum();
+                assert!((rebuilt - a[i * 4 + j]).abs() < 1e-12);
+            }
+        }
+        assert!(values.iter().any(|v| *v < 0.0));
+        assert!((values.iter().sum::<f64>() - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_nearest_correlation_repairs_indefinite_matrix() {
+        let data = indefinite();
+        assert!(CorrelationMatrix::new(&data, 4)
+            .unwrap()
+            .cholesky()
+            .is_err());
+
+        let repaired = nearest_correlation(&data, 4, &NearestCorrelationConfig::default()).unwrap();
+        assert!(repaired.converged);
+        assert!(repaired.matrix.cholesky().is_ok());
+        assert!(min_eigenvalue(&repaired.matrix) > 0.0);
+
+        // Nearest in Frobenius norm: never further than eigenvalue clipping
+        let clipped = clip_eigenvalues(&data, 4, 1e-8).unwrap();
+        assert!(clipped.matrix.cholesky().is_ok());
+        assert!(repaired.frobenius_distance <= clipped.frobenius_distance + 1e-8);
+        assert!(repaired.frobenius_distance > 0.0);
+    }
+
+    #[test]
+    fn test_valid_matrix_is_unchanged() {
+        let data = [1.0, 0.5, 0.2, 0.5, 1.0, 0.3, 0.2, 0.3, 1.0];
+        let matrix = CorrelationMatrix::new(&data, 3).unwrap();
+        let repaired = matrix
+            .nearest_correlation(&NearestCorrelationConfig::default())
+            .unwrap();
+        assert!(repaired.frobenius_distance < 1e-12);
+        let clipped = matrix.clip_eigenvalues(1e-8).unwrap();
+        assert!(clipped.frobenius_distance < 1e-12);
+    }
+
+    #[test]
+    fn test_higham_example() {
+        // Higham (2002), section 4: the nearest correlation matrix of
+        // [[1, 1, 0], [1, 1, 1], [0, 1, 1]] has off-diagonals 0.7607, 0.1573
+        let data = [1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0];
+        let repaired = nearest_correlation(&data, 3, &NearestCorrelationConfig::default()).unwrap();
+        assert!(repaired.converged);
+        let m = &repaired.matrix;
+        assert!((m.get(0, 1) - 0.7607).abs() < 1e-4);
+        assert!((m.get(1, 2) - 0.7607).abs() < 1e-4);
+        assert!((m.get(0, 2) - 0.1573).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_weights_and_fixed_blocks() {
+        let data = indefinite();
+        let plain = nearest_correlation(&data, 4, &NearestCorrelationConfig::default()).unwrap();
+
+        // Heavier weight on factor 0 keeps its row closer to the input
+        let weighted = nearest_correlation(
+            &data,
+            4,
+            &NearestCorrelationConfig::default().with_weights(vec![10.0, 1.0, 1.0, 1.0]),
+        )
+        .unwrap();
+        let row_change = |r: &RepairedCorrelation| -> f64 {
+            (1..4)
+                .map(|j| (r.matrix.get(0, j) - data[j]).powi(2))
+                .sum::<f64>()
+        };
+        assert!(weighted.converged);
+        assert!(row_change(&weighted) < row_change(&plain));
+
+        // Fixed block {2, 3} keeps ρ_23 exactly
+        let fixed = nearest_correlation(
+            &data,
+            4,
+            &NearestCorrelationConfig::default().with_fixed_block(vec![2, 3]),
+        )
+        .unwrap();
+        assert!(fixed.converged);
+        assert!((fixed.matrix.get(2, 3) - 0.6).abs() < 1e-12);
+        assert!(fixed.matrix.cholesky().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let data = indefinite();
+        let config = NearestCorrelationConfig::default();
+        assert!(matches!(
+            nearest_correlation(&data[..15], 4, &config),
+            Err(CorrelationError::InvalidDimensions { .. })
+        ));
+        let mut asymmetric = data;
+        asymmetric[1] = 0.1;
+        assert!(matches!(
+            nearest_correlation(&asymmetric, 4, &config),
+            Err(CorrelationError::NotSymmetric { .. })
+        ));
+        assert!(matches!(
+            nearest_correlation(
+                &data,
+                4,
+                &config.clone().with_weights(vec![1.0, 0.0, 1.0, 1.0])
+            ),
+            Err(CorrelationError::InvalidWeight { index: 1, .. })
+        ));
+        assert!(matches!(
+            nearest_correlation(&data, 4, &config.with_fixed_block(vec![1, 4])),
+            Err(CorrelationError::IndexOutOfRange { index: 4, dim: 4 })
+        ));
+        assert!(matches!(
+            clip_eigenvalues(&data, 4, 0.0),
+            Err(CorrelationError::InvalidEigenvalueFloor { .. })
+        ));
+    }
+}
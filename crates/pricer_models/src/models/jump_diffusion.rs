@@ -0,0 +1,632 @@
+//! ジャンプ拡散モデル実装（Merton・Bates）
+//!
+//! Merton (1976) ジャンプ拡散モデル:
+//! ```text
+//! dS/S = (r - λk) dt + σ dW + (J - 1) dN
+//! ```
+//! Bates (1996) モデルはHestonの確率ボラティリティに同じジャンプを加える:
+//! ```text
+//! dS/S = (r - λk) dt + √v dW_S + (J - 1) dN
+//! dv = κ(θ - v) dt + ξ√v dW_v
+//! ```
+//! ここで:
+//! - N = 強度λのポアソン過程
+//! - ln J ~ N(μ_J, δ_J²)（対数正規ジャンプ）
+//! - k = E[J - 1] = exp(μ_J + δ_J²/2) - 1（フォワードを保つドリフト補正）
+//!
+//! ## 複合ポアソン・サンプリング
+//!
+//! 各ステップのジャンプ回数 n は、一様乱数 u = Φ(z_n) から平均 λΔt の
+//! ポアソン分布の逆関数法で生成する。n 回のジャンプの対数サイズの和は
+//! N(nμ_J, nδ_J²) に従うため、正規乱数 z_J を1つ用いて
+//! `nμ_J + √n δ_J z_J` と厳密にサンプリングできる。
+//!
+//! ## 高速価格計算
+//!
+//! ヨーロピアンオプションは特性関数
+//! [`MertonCf`](crate::analytical::fourier::MertonCf) /
+//! [`BatesCf`](crate::analytical::fourier::BatesCf) とCOS法で評価できる。
+//!
+//! ## 使用例
+//!
+//! ```
+//! use pricer_models::models::jump_diffusion::{MertonModel, MertonParams};
+//! use pricer_models::models::stochastic::StochasticModel;
+//!
+//! // S0=100, r=5%, σ=15%, λ=0.5, μ_J=-10%, δ_J=20%
+//! let params = MertonParams::new(100.0, 0.05, 0.15, 0.5, -0.1, 0.2).unwrap();
+//! let state = MertonModel::initial_state(&params);
+//!
+//! // dw = [拡散, ジャンプ回数, ジャンプサイズ]
+//! let next = MertonModel::evolve_step(state, 1.0 / 252.0, &[0.1, 0.0, 0.0], &params);
+//! assert!(next.0 > 0.0);
+//! ```
+
+use crate::analytical::distributions::norm_cdf;
+use pricer_core::traits::priceable::Differentiable;
+use pricer_core::traits::Float;
+use thiserror::Error;
+
+use super::heston::{HestonError, HestonModel, HestonParams};
+use super::stochastic::{SingleState, StochasticModel, TwoFactorState};
+
+/// 1ステップあたりのジャンプ回数の上限（逆関数法の打ち切り）
+const MAX_JUMPS_PER_STEP: u32 = 64;
+
+/// ジャンプ拡散モデルエラー型
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::jump_diffusion::JumpDiffusionError;
+///
+/// let err = JumpDiffusionError::InvalidIntensity(-1.0);
+/// assert!(format!("{}", err).contains("-1"));
+/// ```
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum JumpDiffusionError {
+    /// 無効なスポット価格（正でなければならない）
+    #[error("無効なスポット価格: S0 = {0} (正の値が必要)")]
+    InvalidSpot(f64),
+
+    /// 無効な拡散ボラティリティ（非負でなければならない）
+    #[error("無効なボラティリティ: sigma = {0} (非負の値が必要)")]
+    InvalidVolatility(f64),
+
+    /// 無効なジャンプ強度（非負でなければならない）
+    #[error("無効なジャンプ強度: lambda = {0} (非負の値が必要)")]
+    InvalidIntensity(f64),
+
+    /// 無効なジャンプ平均（有限でなければならない）
+    #[error("無効なジャンプ平均: mu_J = {0} (有限の値が必要)")]
+    InvalidJumpMean(f64),
+
+    /// 無効なジャンプボラティリティ（非負でなければならない）
+    #[error("無効なジャンプボラティリティ: delta_J = {0} (非負の値が必要)")]
+    InvalidJumpVol(f64),
+
+    /// Heston部分のパラメータエラー
+    #[error("Hestonパラメータエラー: {0}")]
+    Heston(#[from] HestonError),
+}
+
+/// 対数正規ジャンプのパラメータを検証
+fn validate_jumps<T: Float>(intensity: T, mean: T, vol: T) -> Result<(), JumpDiffusionError> {
+    let to_f64 = |x: T| x.to_f64().unwrap_or(f64::NAN);
+    if !(intensity >= T::zero() && intensity.is_finite()) {
+        return Err(JumpDiffusionError::InvalidIntensity(to_f64(intensity)));
+    }
+    if !mean.is_finite() {
+        return Err(JumpDiffusionError::InvalidJumpMean(to_f64(mean)));
+    }
+    if !(vol >= T::zero() && vol.is_finite()) {
+        return Err(JumpDiffusionError::InvalidJumpVol(to_f64(vol)));
+    }
+    Ok(())
+}
+
+/// ジャンプ補正項 k = exp(μ_J + δ_J²/2) - 1
+#[inline]
+fn jump_compensator<T: Float>(mean: T, vol: T) -> T {
+    let half = T::from(0.5).unwrap_or(T::zero());
+    (mean + half * vol * vol).exp() - T::one()
+}
+
+/// 平均 `mean` のポアソン分布の逆関数法
+///
+/// `u` 以上の累積確率を与える最小の回数を返す（上限 [`MAX_JUMPS_PER_STEP`]）。
+fn poisson_inverse(mean: f64, u: f64) -> u32 {
+    let mut probability = (-mean).exp();
+    let mut cdf = probability;
+    let mut count = 0;
+    while u > cdf && count < MAX_JUMPS_PER_STEP {
+        count += 1;
+        probability *= mean / f64::from(count);
+        cdf += probability;
+    }
+    count
+}
+
+/// 1ステップ分のジャンプによる対数リターンを複合ポアソン分布からサンプリング
+///
+/// `z_count` からジャンプ回数 n を、`z_size` から対数サイズの和
+/// `nμ_J + √n δ_J z_size` を生成する。
+fn sample_log_jump<T: Float>(intensity: T, mean: T, vol: T, dt: T, z_count: T, z_size: T) -> T {
+    let expected = (intensity * dt).to_f64().unwrap_or(0.0);
+    if expected <= 0.0 {
+        return T::zero();
+    }
+    let u = norm_cdf(z_count).to_f64().unwrap_or(0.0);
+    let count = poisson_inverse(expected, u);
+    if count == 0 {
+        return T::zero();
+    }
+    let n = T::from(count).unwrap_or(T::zero());
+    n * mean + n.sqrt() * vol * z_size
+}
+
+/// Merton ジャンプ拡散モデルパラメータ
+///
+/// # 型パラメータ
+///
+/// * `T` - Float型（f64またはAD互換のDualNumber）
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::jump_diffusion::MertonParams;
+///
+/// let params = MertonParams::new(100.0, 0.05, 0.15, 0.5, -0.1, 0.2);
+/// assert!(params.is_ok());
+///
+/// // 負のジャンプ強度は無効
+/// assert!(MertonParams::new(100.0, 0.05, 0.15, -0.5, -0.1, 0.2).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MertonParams<T: Float> {
+    /// スポット価格 (S0)
+    pub spot: T,
+    /// リスクフリーレート
+    pub rate: T,
+    /// 拡散ボラティリティ (sigma)
+    pub volatility: T,
+    /// ジャンプ強度 (lambda, 年率)
+    pub jump_intensity: T,
+    /// 対数ジャンプサイズの平均 (mu_J)
+    pub jump_mean: T,
+    /// 対数ジャンプサイズのボラティリティ (delta_J)
+    pub jump_vol: T,
+}
+
+impl<T: Float> MertonParams<T> {
+    /// 新しいMertonパラメータを作成（検証付き）
+    ///
+    /// # 引数
+    ///
+    /// * `spot` - スポット価格（正でなければならない）
+    /// * `rate` - リスクフリーレート
+    /// * `volatility` - 拡散ボラティリティ（非負）
+    /// * `jump_intensity` - ジャンプ強度（非負）
+    /// * `jump_mean` - 対数ジャンプサイズの平均
+    /// * `jump_vol` - 対数ジャンプサイズのボラティリティ（非負）
+    pub fn new(
+        spot: T,
+        rate: T,
+        volatility: T,
+        jump_intensity: T,
+        jump_mean: T,
+        jump_vol: T,
+    ) -> Result<Self, JumpDiffusionError> {
+        let params = Self {
+            spot,
+            rate,
+            volatility,
+            jump_intensity,
+            jump_mean,
+            jump_vol,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// パラメータを検証
+    pub fn validate(&self) -> Result<(), JumpDiffusionError> {
+        if !(self.spot > T::zero() && self.spot.is_finite()) {
+            return Err(JumpDiffusionError::InvalidSpot(
+                self.spot.to_f64().unwrap_or(f64::NAN),
+            ));
+        }
+        if !(self.volatility >= T::zero() && self.volatility.is_finite()) {
+            return Err(JumpDiffusionError::InvalidVolatility(
+                self.volatility.to_f64().unwrap_or(f64::NAN),
+            ));
+        }
+        validate_jumps(self.jump_intensity, self.jump_mean, self.jump_vol)
+    }
+
+    /// ジャンプ補正項 k = E[J - 1]
+    pub fn jump_compensator(&self) -> T {
+        jump_compensator(self.jump_mean, self.jump_vol)
+    }
+}
+
+/// Bates モデルパラメータ（Heston + 対数正規ジャンプ）
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::heston::HestonParams;
+/// use pricer_models::models::jump_diffusion::BatesParams;
+///
+/// let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+/// let params = BatesParams::new(heston, 0.5, -0.1, 0.2);
+/// assert!(params.is_ok());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BatesParams<T: Float> {
+    /// 拡散部分のHestonパラメータ
+    pub heston: HestonParams<T>,
+    /// ジャンプ強度 (lambda, 年率)
+    pub jump_intensity: T,
+    /// 対数ジャンプサイズの平均 (mu_J)
+    pub jump_mean: T,
+    /// 対数ジャンプサイズのボラティリティ (delta_J)
+    pub jump_vol: T,
+}
+
+impl<T: Float> BatesParams<T> {
+    /// 新しいBatesパラメータを作成（検証付き）
+    ///
+    /// # 引数
+    ///
+    /// * `heston` - 拡散部分のHestonパラメータ
+    /// * `jump_intensity` - ジャンプ強度（非負）
+    /// * `jump_mean` - 対数ジャンプサイズの平均
+    /// * `jump_vol` - 対数ジャンプサイズのボラティリティ（非負）
+    pub fn new(
+        heston: HestonParams<T>,
+        jump_intensity: T,
+        jump_mean: T,
+        jump_vol: T,
+    ) -> Result<Self, JumpDiffusionError> {
+        let params = Self {
+            heston,
+            jump_intensity,
+            jump_mean,
+            jump_vol,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// パラメータを検証
+    pub fn validate(&self) -> Result<(), JumpDiffusionError> {
+        self.heston.validate()?;
+        validate_jumps(self.jump_intensity, self.jump_mean, self.jump_vol)
+    }
+
+    /// ジャンプ補正項 k = E[J - 1]
+    pub fn jump_compensator(&self) -> T {
+        jump_compensator(self.jump_mean, self.jump_vol)
+    }
+}
+
+/// Merton ジャンプ拡散モデル
+///
+/// `dw` スライスは3要素を期待:
+/// - `dw[0]`: 拡散用の標準正規乱数
+/// - `dw[1]`: ジャンプ回数用の標準正規乱数（Φで一様乱数に変換）
+/// - `dw[2]`: ジャンプサイズ用の標準正規乱数
+#[derive(Clone, Debug)]
+pub struct MertonModel<T: Float> {
+    params: MertonParams<T>,
+}
+
+impl<T: Float> MertonModel<T> {
+    /// 新しいMertonモデルを作成
+    pub fn new(params: MertonParams<T>) -> Result<Self, JumpDiffusionError> {
+        params.validate()?;
+        Ok(Self { params })
+    }
+
+    /// モデルパラメータへの参照を取得
+    pub fn params(&self) -> &MertonParams<T> {
+        &self.params
+    }
+}
+
+impl<T: Float> Differentiable for MertonModel<T> {}
+
+impl<T: Float + Default> StochasticModel<T> for MertonModel<T> {
+    type State = SingleState<T>;
+    type Params = MertonParams<T>;
+
+    /// 対数空間の厳密解に複合ポアソンジャンプを加えて1ステップ進める
+    fn evolve_step(state: Self::State, dt: T, dw: &[T], params: &Self::Params) -> Self::State {
+        let z = dw.first().copied().unwrap_or(T::zero());
+        let z_count = dw.get(1).copied().unwrap_or(T::zero());
+        let z_size = dw.get(2).copied().unwrap_or(T::zero());
+
+        let half = T::from(0.5).unwrap_or(T::zero());
+        let sigma = params.volatility;
+        let drift = (params.rate
+            - params.jump_intensity * params.jump_compensator()
+            - half * sigma * sigma)
+            * dt;
+        let diffusion = sigma * dt.sqrt() * z;
+        let jump = sample_log_jump(
+            params.jump_intensity,
+            params.jump_mean,
+            params.jump_vol,
+            dt,
+            z_count,
+            z_size,
+        );
+
+        SingleState(state.0 * (drift + diffusion + jump).exp())
+    }
+
+    fn initial_state(params: &Self::Params) -> Self::State {
+        SingleState(params.spot)
+    }
+
+    /// 拡散1次元 + ジャンプ回数1次元 + ジャンプサイズ1次元
+    fn brownian_dim() -> usize {
+        3
+    }
+
+    fn model_name() -> &'static str {
+        "Merton"
+    }
+
+    fn num_factors() -> usize {
+        1
+    }
+}
+
+/// Bates モデル（Heston + 対数正規ジャンプ）
+///
+/// 拡散部分はHestonのQEスキームで進め、価格にジャンプと補正ドリフトを乗じる。
+/// `dw` スライスは4要素を期待:
+/// - `dw[0]`, `dw[1]`: Hestonの価格・分散用の標準正規乱数
+/// - `dw[2]`: ジャンプ回数用の標準正規乱数
+/// - `dw[3]`: ジャンプサイズ用の標準正規乱数
+///
+/// # 使用例
+///
+/// ```
+/// use pricer_models::models::heston::HestonParams;
+/// use pricer_models::models::jump_diffusion::{BatesModel, BatesParams};
+/// use pricer_models::models::stochastic::StochasticModel;
+///
+/// let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+/// let params = BatesParams::new(heston, 0.5, -0.1, 0.2).unwrap();
+/// let state = BatesModel::initial_state(&params);
+///
+/// let next = BatesModel::evolve_step(state, 1.0 / 252.0, &[0.5, 0.0, 0.0, 0.0], &params);
+/// assert!(next.first > 0.0);
+/// assert!(next.second >= 0.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BatesModel<T: Float> {
+    params: BatesParams<T>,
+}
+
+impl<T: Float> BatesModel<T> {
+    /// 新しいBatesモデルを作成
+    pub fn new(params: BatesParams<T>) -> Result<Self, JumpDiffusionError> {
+        params.validate()?;
+        Ok(Self { params })
+    }
+
+    /// モデルパラメータへの参照を取得
+    pub fn params(&self) -> &BatesParams<T> {
+        &self.params
+    }
+}
+
+impl<T: Float> Differentiable for BatesModel<T> {}
+
+impl<T: Float + Default> StochasticModel<T> for BatesModel<T> {
+    type State = TwoFactorState<T>;
+    type Params = BatesParams<T>;
+
+    fn evolve_step(state: Self::State, dt: T, dw: &[T], params: &Self::Params) -> Self::State {
+        let z1 = dw.first().copied().unwrap_or(T::zero());
+        let z2 = dw.get(1).copied().unwrap_or(T::zero());
+        let z_count = dw.get(2).copied().unwrap_or(T::zero());
+        let z_size = dw.get(3).copied().unwrap_or(T::zero());
+
+        let diffused = HestonModel::evolve_step(state, dt, &[z1, z2], &params.heston);
+        let compensation = -params.jump_intensity * params.jump_compensator() * dt;
+        let jump = sample_log_jump(
+            params.jump_intensity,
+            params.jump_mean,
+            params.jump_vol,
+            dt,
+            z_count,
+            z_size,
+        );
+
+        TwoFactorState {
+            first: diffused.first * (compensation + jump).exp(),
+            second: diffused.second,
+        }
+    }
+
+    fn initial_state(params: &Self::Params) -> Self::State {
+        HestonModel::initial_state(&params.heston)
+    }
+
+    /// Heston 2次元 + ジャンプ回数1次元 + ジャンプサイズ1次元
+    fn brownian_dim() -> usize {
+        4
+    }
+
+    fn model_name() -> &'static str {
+        "Bates"
+    }
+
+    fn num_factors() -> usize {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::fourier::{BatesCf, CosPricer, FourierMarket, MertonCf};
+    use crate::test_utils::normals;
+
+    fn heston_params() -> HestonParams<f64> {
+        HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap()
+    }
+
+    // テスト: パラメータ検証
+    #[test]
+    fn test_params_validation() {
+        assert!(MertonParams::new(100.0, 0.05, 0.15, 0.5, -0.1, 0.2).is_ok());
+        assert_eq!(
+            MertonParams::new(-1.0, 0.05, 0.15, 0.5, -0.1, 0.2),
+            Err(JumpDiffusionError::InvalidSpot(-1.0))
+        );
+        assert_eq!(
+            MertonParams::new(100.0, 0.05, -0.15, 0.5, -0.1, 0.2),
+            Err(JumpDiffusionError::InvalidVolatility(-0.15))
+        );
+        assert!(matches!(
+            MertonParams::new(100.0, 0.05, 0.15, 0.5, f64::NAN, 0.2),
+            Err(JumpDiffusionError::InvalidJumpMean(_))
+        ));
+        assert_eq!(
+            MertonParams::new(100.0, 0.05, 0.15, -0.5, -0.1, 0.2),
+            Err(JumpDiffusionError::InvalidIntensity(-0.5))
+        );
+        assert_eq!(
+            BatesParams::new(heston_params(), 0.5, -0.1, -0.2),
+            Err(JumpDiffusionError::InvalidJumpVol(-0.2))
+        );
+        let mut bad_heston = heston_params();
+        bad_heston.rho = 1.5;
+        assert_eq!(
+            BatesParams::new(bad_heston, 0.5, -0.1, 0.2),
+            Err(JumpDiffusionError::Heston(HestonError::InvalidRho(1.5)))
+        );
+    }
+
+    // テスト: ポアソン逆関数法
+    #[test]
+    fn test_poisson_inverse() {
+        assert_eq!(poisson_inverse(0.5, 0.0), 0);
+        assert_eq!(poisson_inverse(0.5, (-0.5_f64).exp() - 1e-12), 0);
+        assert_eq!(poisson_inverse(0.5, (-0.5_f64).exp() + 1e-12), 1);
+        assert!(poisson_inverse(0.5, 1.0) <= MAX_JUMPS_PER_STEP);
+        assert_eq!(poisson_inverse(1e3, 0.5), MAX_JUMPS_PER_STEP);
+        // 平均の検証: E[N] = Σ P(N > n)
+        let n = 100_000;
+        let mean: f64 = (0..n)
+            .map(|i| f64::from(poisson_inverse(2.0, (i as f64 + 0.5) / n as f64)))
+            .sum::<f64>()
+            / n as f64;
+        assert!((mean - 2.0).abs() < 1e-3, "mean = {}", mean);
+    }
+
+    // テスト: ジャンプ無しのMertonはGBMと一致
+    #[test]
+    fn test_merton_without_jumps_is_gbm() {
+        let params = MertonParams::new(100.0, 0.05, 0.2, 0.0, -0.1, 0.2).unwrap();
+        let dt = 0.01;
+        let next = MertonModel::evolve_step(SingleState(100.0), dt, &[0.7, 3.0, 1.0], &params);
+        let expected = 100.0 * ((0.05 - 0.02) * dt + 0.2 * dt.sqrt() * 0.7_f64).exp();
+        assert!((next.0 - expected).abs() < 1e-12);
+    }
+
+    // テスト: 大きなジャンプ回数乱数で価格がジャンプする
+    #[test]
+    fn test_jump_moves_price() {
+        let params = MertonParams::new(100.0, 0.0, 0.0, 1.0, -0.2, 0.0).unwrap();
+        let dt = 0.01;
+        let calm = MertonModel::evolve_step(SingleState(100.0), dt, &[0.0, -3.0, 0.0], &params);
+        let jumped = MertonModel::evolve_step(SingleState(100.0), dt, &[0.0, 3.0, 0.0], &params);
+        // ジャンプ無しでは補正ドリフトのみ（μ_J < 0 なので上昇）
+        assert!(calm.0 > 100.0);
+        assert!((jumped.0 / calm.0 - (-0.2_f64).exp()).abs() < 1e-12);
+    }
+
+    // テスト: MCによるMertonのコール価格が特性関数のCOS価格と一致
+    #[test]
+    fn test_merton_mc_matches_cos() {
+        let params = MertonParams::new(100.0, 0.05, 0.15, 1.0, -0.1, 0.15).unwrap();
+        let (n_paths, n_steps) = (40_000, 20);
+        let dt = 1.0 / n_steps as f64;
+        let mut normal = normals(0x9E37_79B9_7F4A_7C15);
+
+        let payoffs: Vec<f64> = (0..n_paths)
+            .map(|_| {
+                let mut state = MertonModel::initial_state(&params);
+                for _ in 0..n_steps {
+                    let dw = [normal(), normal(), normal()];
+                    state = MertonModel::evolve_step(state, dt, &dw, &params);
+                }
+                (state.0 - 100.0).max(0.0) * (-0.05_f64).exp()
+            })
+            .collect();
+        let mean = payoffs.iter().sum::<f64>() / n_paths as f64;
+        let variance =
+            payoffs.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n_paths - 1) as f64;
+        let std_err = (variance / n_paths as f64).sqrt();
+
+        let market = FourierMarket::new(100.0, 0.05, 0.0).unwrap();
+        let reference = CosPricer::default()
+            .price(
+                &MertonCf::from_params(&params).unwrap(),
+                &market,
+                100.0,
+                1.0,
+                true,
+            )
+            .unwrap();
+        assert!(
+            (mean - reference).abs() < 4.0 * std_err,
+            "MC {} ± {} vs COS {}",
+            mean,
+            std_err,
+            reference
+        );
+    }
+
+    // テスト: MCによるBatesのプット価格が特性関数のCOS価格と一致
+    #[test]
+    fn test_bates_mc_matches_cos() {
+        let params = BatesParams::new(heston_params(), 0.8, -0.15, 0.1).unwrap();
+        let (n_paths, n_steps) = (40_000, 50);
+        let dt = 1.0 / n_steps as f64;
+        let mut normal = normals(0xD1B5_4A32_D192_ED03);
+
+        let payoffs: Vec<f64> = (0..n_paths)
+            .map(|_| {
+                let mut state = BatesModel::initial_state(&params);
+                for _ in 0..n_steps {
+                    let dw = [normal(), normal(), normal(), normal()];
+                    state = BatesModel::evolve_step(state, dt, &dw, &params);
+                }
+                (90.0 - state.first).max(0.0) * (-0.05_f64).exp()
+            })
+            .collect();
+        let mean = payoffs.iter().sum::<f64>() / n_paths as f64;
+        let variance =
+            payoffs.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n_paths - 1) as f64;
+        let std_err = (variance / n_paths as f64).sqrt();
+
+        let market = FourierMarket::new(100.0, 0.05, 0.0).unwrap();
+        let reference = CosPricer::default()
+            .price(
+                &BatesCf::from_params(&params).unwrap(),
+                &market,
+                90.0,
+                1.0,
+                false,
+            )
+            .unwrap();
+        assert!(
+            (mean - reference).abs() < 4.0 * std_err,
+            "MC {} ± {} vs COS {}",
+            mean,
+            std_err,
+            reference
+        );
+    }
+
+    // テスト: StochasticModelトレイトのメタデータ
+    #[test]
+    fn test_model_metadata() {
+        assert_eq!(MertonModel::<f64>::brownian_dim(), 3);
+        assert_eq!(MertonModel::<f64>::model_name(), "Merton");
+        assert_eq!(BatesModel::<f64>::brownian_dim(), 4);
+        assert_eq!(BatesModel::<f64>::num_factors(), 2);
+        let bates = BatesModel::new(BatesParams::new(heston_params(), 0.5, -0.1, 0.2).unwrap());
+        assert!(bates.is_ok());
+        let state = BatesModel::initial_state(bates.unwrap().params());
+        assert_eq!((state.first, state.second), (100.0, 0.04));
+    }
+}
@@ -5,14 +5,22 @@
 //! - `StochasticModelEnum`: Static dispatch enum for Enzyme compatibility
 //! - `GBMModel`: Geometric Brownian Motion model
 //! - `HestonModel`: Heston stochastic volatility model
+//! - `MertonModel` / `BatesModel`: Lognormal jump diffusion, without and
+//!   with Heston stochastic volatility
+//! - `SABRModel` / `ArbitrageFreeSABR`: (Shifted) SABR via the Hagan
+//!   expansion, or via the arbitrage-free effective density PDE
+//! - `RoughBergomiModel`: Rough Bergomi paths by the hybrid scheme for the
+//!   Volterra variance driver
+//! - `SlvModel`: Stochastic-local volatility on Heston variance, with the
+//!   leverage function calibrated to a local vol surface by the particle method
 //!
 //! ## Model Categories
 //!
 //! Models are organized by category (enabled via feature flags):
 //! - `equity`: Equity models (GBM) - default
-//! - `rates`: Interest rate models (Hull-White, CIR)
+//! - `rates`: Interest rate models (Hull-White, CIR, G2++, LMM)
 //! - `exotic`: Advanced models (Heston, SABR)
-//! - `hybrid`: Multi-factor and correlated models
+//! - `hybrid`: Multi-factor, correlated and cross-currency hybrid models
 //!
 //! ## Design Philosophy
 //!
@@ -42,8 +50,12 @@
 // Core model infrastructure (always available)
 pub mod gbm;
 pub mod heston;
+pub mod jump_diffusion;
 pub mod model_enum;
+pub mod rough_bergomi;
 pub mod sabr;
+pub mod sabr_pde;
+pub mod slv;
 pub mod stochastic;
 
 // Model category submodules (feature-gated)
@@ -53,7 +65,7 @@ pub mod equity;
 #[cfg(feature = "rates")]
 pub mod rates;
 
-#[cfg(feature = "exotic")]
+#[cfg(any(feature = "exotic", feature = "rates"))]
 pub mod hybrid;
 
 // Re-export core trait types
@@ -65,8 +77,20 @@ pub use gbm::{GBMModel, GBMParams};
 // Re-export Heston model
 pub use heston::{HestonError, HestonModel, HestonParams};
 
+// Re-export jump diffusion models
+pub use jump_diffusion::{BatesModel, BatesParams, JumpDiffusionError, MertonModel, MertonParams};
+
+// Re-export rough Bergomi model
+pub use rough_bergomi::{
+    RoughBergomiError, RoughBergomiModel, RoughBergomiParams, RoughBergomiPath,
+};
+
 // Re-export SABR model
 pub use sabr::{SABRError, SABRModel, SABRParams};
+pub use sabr_pde::{ArbitrageFreeSABR, SABRPdeConfig};
+
+// Re-export stochastic-local volatility model
+pub use slv::{LeverageCalibrator, LeverageFunction, SlvError, SlvModel, SlvParams, SlvPath};
 
 // Re-export enum types for static dispatch
 pub use model_enum::{ModelParams, ModelState, StochasticModelEnum};
@@ -30,12 +30,15 @@ use pricer_core::traits::Float;
 
 use super::gbm::{GBMModel, GBMParams};
 use super::heston::{HestonModel, HestonParams};
+use super::jump_diffusion::{BatesModel, BatesParams, MertonModel, MertonParams};
 use super::sabr::{SABRModel, SABRParams};
 use super::stochastic::{SingleState, StochasticState, TwoFactorState};
 
 // Import rate models when rates feature is enabled
 #[cfg(feature = "rates")]
-use super::rates::{CIRModel, CIRParams, HullWhiteModel, HullWhiteParams};
+use super::rates::{
+    CIRModel, CIRParams, G2PlusPlusModel, G2PlusPlusParams, HullWhiteModel, HullWhiteParams,
+};
 
 /// Unified state type for all models.
 ///
@@ -105,12 +108,19 @@ pub enum ModelParams<T: Float> {
     Heston(HestonParams<T>),
     /// SABR stochastic volatility model parameters
     SABR(SABRParams<T>),
+    /// Merton jump diffusion model parameters
+    Merton(MertonParams<T>),
+    /// Bates (Heston with jumps) model parameters
+    Bates(BatesParams<T>),
     /// Hull-White model parameters (requires `rates` feature)
     #[cfg(feature = "rates")]
     HullWhite(HullWhiteParams<T>),
     /// CIR model parameters (requires `rates` feature)
     #[cfg(feature = "rates")]
     CIR(CIRParams<T>),
+    /// G2++ model parameters (requires `rates` feature)
+    #[cfg(feature = "rates")]
+    G2PlusPlus(G2PlusPlusParams<T>),
 }
 
 impl<T: Float> ModelParams<T> {
@@ -123,55 +133,138 @@ impl<T: Float> ModelParams<T> {
             ModelParams::GBM(p) => p.spot,
             ModelParams::Heston(p) => p.spot,
             ModelParams::SABR(p) => p.forward,
+            ModelParams::Merton(p) => p.spot,
+            ModelParams::Bates(p) => p.heston.spot,
             #[cfg(feature = "rates")]
             ModelParams::HullWhite(p) => p.initial_short_rate,
             #[cfg(feature = "rates")]
             ModelParams::CIR(p) => p.initial_rate,
+            #[cfg(feature = "rates")]
+            ModelParams::G2PlusPlus(p) => p.phi(T::zero()).unwrap_or_else(|_| T::nan()),
         }
     }
 
     /// Get the rate parameter.
     ///
     /// For interest rate models, this returns the mean reversion speed.
-    /// For Heston, this returns the risk-free rate.
+    /// For Heston and the jump diffusion models, this returns the risk-free
+    /// rate.
     /// For SABR, returns zero (no drift term).
     pub fn rate(&self) -> T {
         match self {
             ModelParams::GBM(p) => p.rate,
             ModelParams::Heston(p) => p.rate,
             ModelParams::SABR(_) => T::zero(), // SABR is driftless
+            ModelParams::Merton(p) => p.rate,
+            ModelParams::Bates(p) => p.heston.rate,
             #[cfg(feature = "rates")]
             ModelParams::HullWhite(p) => p.mean_reversion,
             #[cfg(feature = "rates")]
             ModelParams::CIR(p) => p.mean_reversion,
+            #[cfg(feature = "rates")]
+            ModelParams::G2PlusPlus(p) => p.a,
         }
     }
 
     /// Get the volatility parameter (primary volatility for all models).
     ///
-    /// For Heston, this returns the initial volatility (sqrt of v0).
+    /// For Heston and Bates, this returns the initial volatility (sqrt of v0).
     /// For SABR, this returns alpha (instantaneous volatility).
+    /// For Merton, this returns the diffusive volatility.
     pub fn volatility(&self) -> T {
         match self {
             ModelParams::GBM(p) => p.volatility,
             ModelParams::Heston(p) => p.v0.sqrt(),
             ModelParams::SABR(p) => p.alpha,
+            ModelParams::Merton(p) => p.volatility,
+            ModelParams::Bates(p) => p.heston.v0.sqrt(),
             #[cfg(feature = "rates")]
             ModelParams::HullWhite(p) => p.volatility,
             #[cfg(feature = "rates")]
             ModelParams::CIR(p) => p.volatility,
+            #[cfg(feature = "rates")]
+            ModelParams::G2PlusPlus(p) => p.sigma,
         }
     }
 
-    /// Get initial variance for Heston model (returns None for other models).
+    /// Get initial variance for Heston and Bates models (returns None for
+    /// other models).
     /// For SABR, returns alpha squared as an approximate variance.
     pub fn initial_variance(&self) -> Option<T> {
         match self {
             ModelParams::Heston(p) => Some(p.v0),
+            ModelParams::Bates(p) => Some(p.heston.v0),
             ModelParams::SABR(p) => Some(p.alpha * p.alpha),
             _ => None,
         }
     }
+
+    /// Return a copy with the spot replaced.
+    ///
+    /// Sets the same quantity that [`spot`](Self::spot) reads: the forward
+    /// for SABR and the initial short rate for interest rate models. G2++,
+    /// whose initial short rate is fixed by its curve, is returned unchanged.
+    pub fn with_spot(&self, spot: T) -> Self {
+        let mut params = self.clone();
+        match &mut params {
+            ModelParams::GBM(p) => p.spot = spot,
+            ModelParams::Heston(p) => p.spot = spot,
+            ModelParams::SABR(p) => p.forward = spot,
+            ModelParams::Merton(p) => p.spot = spot,
+            ModelParams::Bates(p) => p.heston.spot = spot,
+            #[cfg(feature = "rates")]
+            ModelParams::HullWhite(p) => p.initial_short_rate = spot,
+            #[cfg(feature = "rates")]
+            ModelParams::CIR(p) => p.initial_rate = spot,
+            // The initial short rate of G2++ is fixed by its curve
+            #[cfg(feature = "rates")]
+            ModelParams::G2PlusPlus(_) => {}
+        }
+        params
+    }
+
+    /// Return a copy with the primary volatility replaced.
+    ///
+    /// Sets the same quantity that [`volatility`](Self::volatility) reads:
+    /// `v0 = volatility²` for Heston and Bates, alpha for SABR and the
+    /// diffusive volatility for Merton.
+    pub fn with_volatility(&self, volatility: T) -> Self {
+        let mut params = self.clone();
+        match &mut params {
+            ModelParams::GBM(p) => p.volatility = volatility,
+            ModelParams::Heston(p) => p.v0 = volatility * volatility,
+            ModelParams::SABR(p) => p.alpha = volatility,
+            ModelParams::Merton(p) => p.volatility = volatility,
+            ModelParams::Bates(p) => p.heston.v0 = volatility * volatility,
+            #[cfg(feature = "rates")]
+            ModelParams::HullWhite(p) => p.volatility = volatility,
+            #[cfg(feature = "rates")]
+            ModelParams::CIR(p) => p.volatility = volatility,
+            #[cfg(feature = "rates")]
+            ModelParams::G2PlusPlus(p) => p.sigma = volatility,
+        }
+        params
+    }
+
+    /// Return a copy with the risk-free drift rate replaced.
+    ///
+    /// Returns the parameters unchanged for SABR, which is driftless, and
+    /// `None` for interest rate models, whose drift is not a single rate.
+    pub fn with_rate(&self, rate: T) -> Option<Self> {
+        let mut params = self.clone();
+        match &mut params {
+            ModelParams::GBM(p) => p.rate = rate,
+            ModelParams::Heston(p) => p.rate = rate,
+            ModelParams::SABR(_) => {}
+            ModelParams::Merton(p) => p.rate = rate,
+            ModelParams::Bates(p) => p.heston.rate = rate,
+            #[cfg(feature = "rates")]
+            ModelParams::HullWhite(_) | ModelParams::CIR(_) | ModelParams::G2PlusPlus(_) => {
+                return None
+            }
+        }
+        Some(params)
+    }
 }
 
 /// Static dispatch enum for stochastic models.
@@ -184,8 +277,11 @@ impl<T: Float> ModelParams<T> {
 /// - `GBM`: Geometric Brownian Motion (1-factor) - always available
 /// - `Heston`: Heston stochastic volatility model (2-factor) - always available
 /// - `SABR`: SABR stochastic volatility model (2-factor) - always available
+/// - `Merton`: Merton lognormal jump diffusion (1-factor) - always available
+/// - `Bates`: Heston with lognormal jumps (2-factor) - always available
 /// - `HullWhite`: Hull-White 1F interest rate model - requires `rates` feature
 /// - `CIR`: Cox-Ingersoll-Ross interest rate model - requires `rates` feature
+/// - `G2PlusPlus`: G2++ two-factor interest rate model - requires `rates` feature
 ///
 /// # Example
 ///
@@ -198,8 +294,7 @@ impl<T: Float> ModelParams<T> {
 ///     StochasticModelEnum::GBM(_) => println!("Using GBM model"),
 ///     StochasticModelEnum::Heston(_) => println!("Using Heston model"),
 ///     StochasticModelEnum::SABR(_) => println!("Using SABR model"),
-///     #[cfg(feature = "rates")]
-///     _ => println!("Using rate model"),
+///     _ => println!("Using another model"),
 /// }
 /// ```
 #[derive(Clone, Debug)]
@@ -210,12 +305,19 @@ pub enum StochasticModelEnum<T: Float> {
     Heston(HestonModel<T>),
     /// SABR stochastic volatility model (2-factor)
     SABR(SABRModel<T>),
+    /// Merton jump diffusion model (equity)
+    Merton(MertonModel<T>),
+    /// Bates model: Heston with lognormal jumps (equity, 2-factor)
+    Bates(BatesModel<T>),
     /// Hull-White one-factor model (rates) - requires `rates` feature
     #[cfg(feature = "rates")]
     HullWhite(HullWhiteModel<T>),
     /// Cox-Ingersoll-Ross model (rates) - requires `rates` feature
     #[cfg(feature = "rates")]
     CIR(CIRModel<T>),
+    /// G2++ two-factor Gaussian model (rates) - requires `rates` feature
+    #[cfg(feature = "rates")]
+    G2PlusPlus(G2PlusPlusModel<T>),
 }
 
 impl<T: Float + Default> Default for StochasticModelEnum<T> {
@@ -254,6 +356,24 @@ impl<T: Float + Default> StochasticModelEnum<T> {
         SABRModel::new(params).ok().map(StochasticModelEnum::SABR)
     }
 
+    /// Create a new Merton jump diffusion model with given parameters.
+    ///
+    /// # Returns
+    /// `Some(StochasticModelEnum::Merton)` if parameters are valid, `None` otherwise
+    pub fn merton(params: MertonParams<T>) -> Option<Self> {
+        MertonModel::new(params)
+            .ok()
+            .map(StochasticModelEnum::Merton)
+    }
+
+    /// Create a new Bates model with given parameters.
+    ///
+    /// # Returns
+    /// `Some(StochasticModelEnum::Bates)` if parameters are valid, `None` otherwise
+    pub fn bates(params: BatesParams<T>) -> Option<Self> {
+        BatesModel::new(params).ok().map(StochasticModelEnum::Bates)
+    }
+
     /// Create a new Hull-White model (requires `rates` feature).
     #[cfg(feature = "rates")]
     pub fn hull_white() -> Self {
@@ -266,16 +386,26 @@ impl<T: Float + Default> StochasticModelEnum<T> {
         StochasticModelEnum::CIR(CIRModel::new())
     }
 
+    /// Create a new G2++ model (requires `rates` feature).
+    #[cfg(feature = "rates")]
+    pub fn g2pp() -> Self {
+        StochasticModelEnum::G2PlusPlus(G2PlusPlusModel::new())
+    }
+
     /// Get the model name.
     pub fn model_name(&self) -> &'static str {
         match self {
             StochasticModelEnum::GBM(_) => GBMModel::<T>::model_name(),
             StochasticModelEnum::Heston(_) => HestonModel::<T>::model_name(),
             StochasticModelEnum::SABR(_) => SABRModel::<T>::model_name(),
+            StochasticModelEnum::Merton(_) => MertonModel::<T>::model_name(),
+            StochasticModelEnum::Bates(_) => BatesModel::<T>::model_name(),
             #[cfg(feature = "rates")]
             StochasticModelEnum::HullWhite(_) => HullWhiteModel::<T>::model_name(),
             #[cfg(feature = "rates")]
             StochasticModelEnum::CIR(_) => CIRModel::<T>::model_name(),
+            #[cfg(feature = "rates")]
+            StochasticModelEnum::G2PlusPlus(_) => G2PlusPlusModel::<T>::model_name(),
         }
     }
 
@@ -285,10 +415,14 @@ impl<T: Float + Default> StochasticModelEnum<T> {
             StochasticModelEnum::GBM(_) => GBMModel::<T>::brownian_dim(),
             StochasticModelEnum::Heston(_) => HestonModel::<T>::brownian_dim(),
             StochasticModelEnum::SABR(_) => SABRModel::<T>::brownian_dim(),
+            StochasticModelEnum::Merton(_) => MertonModel::<T>::brownian_dim(),
+            StochasticModelEnum::Bates(_) => BatesModel::<T>::brownian_dim(),
             #[cfg(feature = "rates")]
             StochasticModelEnum::HullWhite(_) => HullWhiteModel::<T>::brownian_dim(),
             #[cfg(feature = "rates")]
             StochasticModelEnum::CIR(_) => CIRModel::<T>::brownian_dim(),
+            #[cfg(feature = "rates")]
+            StochasticModelEnum::G2PlusPlus(_) => G2PlusPlusModel::<T>::brownian_dim(),
         }
     }
 
@@ -298,10 +432,14 @@ impl<T: Float + Default> StochasticModelEnum<T> {
             StochasticModelEnum::GBM(_) => false,
             StochasticModelEnum::Heston(_) => true, // Heston is a 2-factor model
             StochasticModelEnum::SABR(_) => true,   // SABR is a 2-factor model
+            StochasticModelEnum::Merton(_) => false,
+            StochasticModelEnum::Bates(_) => true,
             #[cfg(feature = "rates")]
             StochasticModelEnum::HullWhite(_) => false,
             #[cfg(feature = "rates")]
             StochasticModelEnum::CIR(_) => false,
+            #[cfg(feature = "rates")]
+            StochasticModelEnum::G2PlusPlus(_) => true,
         }
     }
 
@@ -311,10 +449,14 @@ impl<T: Float + Default> StochasticModelEnum<T> {
             StochasticModelEnum::GBM(_) => false,
             StochasticModelEnum::Heston(_) => false,
             StochasticModelEnum::SABR(_) => false, // SABR is not a rate model
+            StochasticModelEnum::Merton(_) => false,
+            StochasticModelEnum::Bates(_) => false,
             #[cfg(feature = "rates")]
             StochasticModelEnum::HullWhite(_) => true,
             #[cfg(feature = "rates")]
             StochasticModelEnum::CIR(_) => true,
+            #[cfg(feature = "rates")]
+            StochasticModelEnum::G2PlusPlus(_) => true,
         }
     }
 
@@ -322,18 +464,42 @@ impl<T: Float + Default> StochasticModelEnum<T> {
     ///
     /// # Returns
     ///
-    /// - 1 for single-factor models (GBM, Hull-White, CIR)
-    /// - 2 for two-factor models (G2++, Heston, SABR)
+    /// - 1 for single-factor models (GBM, Merton, Hull-White, CIR)
+    /// - 2 for two-factor models (G2++, Heston, Bates, SABR)
     /// - n for multi-factor models (correlated hybrid models)
     pub fn num_factors(&self) -> usize {
         match self {
             StochasticModelEnum::GBM(_) => GBMModel::<T>::num_factors(),
             StochasticModelEnum::Heston(_) => HestonModel::<T>::num_factors(),
             StochasticModelEnum::SABR(_) => SABRModel::<T>::num_factors(),
+            StochasticModelEnum::Merton(_) => MertonModel::<T>::num_factors(),
+            StochasticModelEnum::Bates(_) => BatesModel::<T>::num_factors(),
             #[cfg(feature = "rates")]
             StochasticModelEnum::HullWhite(_) => HullWhiteModel::<T>::num_factors(),
             #[cfg(feature = "rates")]
             StochasticModelEnum::CIR(_) => CIRModel::<T>::num_factors(),
+            #[cfg(feature = "rates")]
+            StochasticModelEnum::G2PlusPlus(_) => G2PlusPlusModel::<T>::num_factors(),
+        }
+    }
+
+    /// Check whether `params` are parameters of this model.
+    ///
+    /// [`initial_state`](Self::initial_state) and
+    /// [`evolve_step`](Self::evolve_step) silently fall back to default or
+    /// unchanged states for mismatched parameters; callers can check first.
+    pub fn accepts(&self, params: &ModelParams<T>) -> bool {
+        match (self, params) {
+            (StochasticModelEnum::GBM(_), ModelParams::GBM(_))
+            | (StochasticModelEnum::Heston(_), ModelParams::Heston(_))
+            | (StochasticModelEnum::SABR(_), ModelParams::SABR(_))
+            | (StochasticModelEnum::Merton(_), ModelParams::Merton(_))
+            | (StochasticModelEnum::Bates(_), ModelParams::Bates(_)) => true,
+            #[cfg(feature = "rates")]
+            (StochasticModelEnum::HullWhite(_), ModelParams::HullWhite(_))
+            | (StochasticModelEnum::CIR(_), ModelParams::CIR(_))
+            | (StochasticModelEnum::G2PlusPlus(_), ModelParams::G2PlusPlus(_)) => true,
+            _ => false,
         }
     }
 
@@ -349,6 +515,12 @@ impl<T: Float + Default> StochasticModelEnum<T> {
             (StochasticModelEnum::SABR(_), ModelParams::SABR(p)) => {
                 ModelState::TwoFactor(SABRModel::initial_state(p))
             }
+            (StochasticModelEnum::Merton(_), ModelParams::Merton(p)) => {
+                ModelState::Single(MertonModel::initial_state(p))
+            }
+            (StochasticModelEnum::Bates(_), ModelParams::Bates(p)) => {
+                ModelState::TwoFactor(BatesModel::initial_state(p))
+            }
             #[cfg(feature = "rates")]
             (StochasticModelEnum::HullWhite(_), ModelParams::HullWhite(p)) => {
                 ModelState::Single(HullWhiteModel::initial_state(p))
@@ -357,6 +529,10 @@ impl<T: Float + Default> StochasticModelEnum<T> {
             (StochasticModelEnum::CIR(_), ModelParams::CIR(p)) => {
                 ModelState::Single(CIRModel::initial_state(p))
             }
+            #[cfg(feature = "rates")]
+            (StochasticModelEnum::G2PlusPlus(_), ModelParams::G2PlusPlus(p)) => {
+                ModelState::TwoFactor(G2PlusPlusModel::initial_state(p))
+            }
             #[allow(unreachable_patterns)]
             _ => ModelState::default(),
         }
@@ -389,6 +565,12 @@ impl<T: Float + Default> StochasticModelEnum<T> {
             (StochasticModelEnum::SABR(_), ModelState::TwoFactor(s), ModelParams::SABR(p)) => {
                 ModelState::TwoFactor(SABRModel::evolve_step(*s, dt, dw, p))
             }
+            (StochasticModelEnum::Merton(_), ModelState::Single(s), ModelParams::Merton(p)) => {
+                ModelState::Single(MertonModel::evolve_step(*s, dt, dw, p))
+            }
+            (StochasticModelEnum::Bates(_), ModelState::TwoFactor(s), ModelParams::Bates(p)) => {
+                ModelState::TwoFactor(BatesModel::evolve_step(*s, dt, dw, p))
+            }
             #[cfg(feature = "rates")]
             (
                 StochasticModelEnum::HullWhite(_),
@@ -399,6 +581,12 @@ impl<T: Float + Default> StochasticModelEnum<T> {
             (StochasticModelEnum::CIR(_), ModelState::Single(s), ModelParams::CIR(p)) => {
                 ModelState::Single(CIRModel::evolve_step(*s, dt, dw, p))
             }
+            #[cfg(feature = "rates")]
+            (
+                StochasticModelEnum::G2PlusPlus(_),
+                ModelState::TwoFactor(s),
+                ModelParams::G2PlusPlus(p),
+            ) => ModelState::TwoFactor(G2PlusPlusModel::evolve_step(*s, dt, dw, p)),
             #[allow(unreachable_patterns)]
             _ => state, // Return unchanged state for mismatched types
         }
@@ -604,6 +792,70 @@ mod tests {
         assert_eq!(params.volatility(), 0.2);
     }
 
+    #[test]
+    fn test_model_enum_accepts_matching_params() {
+        let gbm = StochasticModelEnum::<f64>::gbm();
+        let heston_params =
+            HestonParams::new(100.0_f64, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+        let heston = StochasticModelEnum::heston(heston_params).unwrap();
+
+        let gbm_params = ModelParams::GBM(GBMParams::new(100.0, 0.05, 0.2).unwrap());
+        assert!(gbm.accepts(&gbm_params));
+        assert!(!gbm.accepts(&ModelParams::Heston(heston_params)));
+        assert!(heston.accepts(&ModelParams::Heston(heston_params)));
+        assert!(!heston.accepts(&gbm_params));
+    }
+
+    #[test]
+    fn test_model_params_setters() {
+        let params = ModelParams::GBM(GBMParams::new(100.0, 0.05, 0.2).unwrap());
+        assert_eq!(params.with_spot(101.0).spot(), 101.0);
+        assert_eq!(params.with_volatility(0.25).volatility(), 0.25);
+        assert_eq!(params.with_rate(0.06).unwrap().rate(), 0.06);
+
+        let heston = ModelParams::Heston(
+            HestonParams::new(100.0_f64, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap(),
+        );
+        let bumped = heston.with_volatility(0.3);
+        assert!((bumped.volatility() - 0.3).abs() < 1e-15);
+        assert!((bumped.initial_variance().unwrap() - 0.09).abs() < 1e-15);
+
+        let sabr = ModelParams::SABR(SABRParams::new(100.0_f64, 0.2, 0.4, -0.3, 0.5, 1.0).unwrap());
+        assert_eq!(sabr.with_rate(0.07).unwrap().rate(), 0.0);
+    }
+
+    #[test]
+    fn test_model_enum_jump_diffusion() {
+        let merton_params = MertonParams::new(100.0_f64, 0.05, 0.15, 0.5, -0.1, 0.2).unwrap();
+        let merton = StochasticModelEnum::merton(merton_params).unwrap();
+        let heston_params =
+            HestonParams::new(100.0_f64, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+        let bates_params = BatesParams::new(heston_params, 0.5, -0.1, 0.2).unwrap();
+        let bates = StochasticModelEnum::bates(bates_params).unwrap();
+
+        assert_eq!(merton.model_name(), "Merton");
+        assert_eq!(merton.brownian_dim(), 3);
+        assert!(!merton.is_two_factor());
+        assert_eq!(bates.model_name(), "Bates");
+        assert_eq!(bates.brownian_dim(), 4);
+        assert!(bates.is_two_factor());
+        assert!(!merton.accepts(&ModelParams::Bates(bates_params)));
+
+        let params = ModelParams::Bates(bates_params);
+        let path = bates.generate_path(&params, 10, 0.01, &[0.1; 40]);
+        assert_eq!(path.len(), 11);
+        assert!(path
+            .iter()
+            .all(|s| s.price() > 0.0 && s.variance().unwrap() >= 0.0));
+
+        let bumped = params.with_volatility(0.3).with_rate(0.02).unwrap();
+        assert!((bumped.initial_variance().unwrap() - 0.09).abs() < 1e-15);
+        assert_eq!(bumped.rate(), 0.02);
+        let merton_bumped = ModelParams::Merton(merton_params).with_spot(90.0);
+        assert_eq!(merton_bumped.spot(), 90.0);
+        assert_eq!(merton_bumped.volatility(), 0.15);
+    }
+
     #[test]
     fn test_model_enum_clone() {
         let model1 = StochasticModelEnum::<f64>::gbm();
@@ -888,6 +1140,38 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_model_enum_g2pp() {
+            use pricer_core::market_data::curves::CurveEnum;
+
+            let model = StochasticModelEnum::<f64>::g2pp();
+            assert_eq!(model.model_name(), "G2++");
+            assert_eq!(model.brownian_dim(), 2);
+            assert_eq!(model.num_factors(), 2);
+            assert!(model.is_two_factor());
+            assert!(model.is_rate_model());
+
+            let params = ModelParams::G2PlusPlus(
+                G2PlusPlusParams::new(0.5, 0.01, 0.05, 0.008, -0.7, CurveEnum::flat(0.03)).unwrap(),
+            );
+            assert!(model.accepts(&params));
+            assert!((params.spot() - 0.03).abs() < 1e-10); // φ(0)
+            assert_eq!(params.rate(), 0.5);
+            assert_eq!(params.with_volatility(0.02).volatility(), 0.02);
+            assert!(params.with_rate(0.01).is_none());
+
+            let state = model.initial_state(&params);
+            assert!(matches!(state, ModelState::TwoFactor(_)));
+            let next = model.evolve_step(state, 0.25, &[1.0, -1.0], &params);
+            match next {
+                ModelState::TwoFactor(s) => {
+                    assert!(s.first > 0.0);
+                    assert!(s.second < 0.0);
+                }
+                _ => panic!("Expected two-factor state"),
+            }
+        }
+
         #[test]
         fn test_model_params_hull_white_accessors() {
             let params = ModelParams::HullWhite(
@@ -0,0 +1,788 @@
+//! G2++ two-factor Gaussian short-rate model.
+//!
+//! The short rate is the sum of two correlated Ornstein-Uhlenbeck factors
+//! and a deterministic shift:
+//! ```text
+//! r(t) = x(t) + y(t) + φ(t)
+//! dx(t) = -a * x(t) * dt + sigma * dW1(t),   x(0) = 0
+//! dy(t) = -b * y(t) * dt + eta * dW2(t),     y(0) = 0
+//! dW1 * dW2 = rho * dt
+//! ```
+//! where φ(t) fits the initial discount curve `P^M(0, T)` exactly:
+//! ```text
+//! φ(t) = f^M(0, t) + sigma²/(2a²) (1 - e^{-at})² + eta²/(2b²) (1 - e^{-bt})²
+//!        + rho sigma eta / (ab) (1 - e^{-at}) (1 - e^{-bt})
+//! ```
+//!
+//! ## Key Properties
+//!
+//! - **Decorrelated rates**: With `rho` close to -1 the two factors move
+//!   short and long rates differently, which a one-factor model cannot do
+//! - **Analytical tractability**: Zero-coupon bonds, bond options and
+//!   European swaptions (Brigo-Mercurio one-dimensional integral) in
+//!   closed form
+//! - **Exact simulation**: `(x, y)` is Gaussian with time-homogeneous
+//!   transitions, so [`G2PlusPlusModel`] steps it without discretisation
+//!   error
+//!
+//! ## Zero-Coupon Bonds
+//!
+//! ```text
+//! P(t, T) = P^M(0,T)/P^M(0,t) * exp(½[V(t,T) - V(0,T) + V(0,t)] - B_a(t,T) x - B_b(t,T) y)
+//! B_z(t, T) = (1 - e^{-z(T-t)}) / z
+//! ```
+//! with `V(t, T)` the variance of `∫_t^T (x + y) du`.
+//!
+//! ## Usage
+//!
+//! ```
+//! use pricer_models::models::rates::g2pp::G2PlusPlusParams;
+//! use pricer_core::market_data::curves::CurveEnum;
+//!
+//! let params =
+//!     G2PlusPlusParams::new(0.5_f64, 0.01, 0.05, 0.008, -0.7, CurveEnum::flat(0.03)).unwrap();
+//!
+//! // The model reprices the initial curve
+//! let p = params.zero_coupon_bond(0.0, 5.0, 0.0, 0.0).unwrap();
+//! assert!((p - (-0.15_f64).exp()).abs() < 1e-12);
+//!
+//! // 1y into 5y annual ATM-ish payer swaption
+//! let payments: Vec<f64> = (2..=6).map(f64::from).collect();
+//! let price = params.swaption(1.0, &payments, 0.03, true).unwrap();
+//! assert!(price > 0.0);
+//! ```
+
+use pricer_core::market_data::curves::{CurveEnum, YieldCurve};
+use pricer_core::market_data::error::MarketDataError;
+use pricer_core::traits::priceable::Differentiable;
+use pricer_core::traits::Float;
+use thiserror::Error;
+
+use crate::analytical::distributions::{norm_cdf, norm_pdf};
+use crate::models::stochastic::{StochasticModel, TwoFactorState};
+
+/// Step for the central difference giving the instantaneous forward rate.
+const FORWARD_BUMP: f64 = 1e-4;
+
+/// Standard deviations of `x` covered by the swaption integral.
+const SWAPTION_STD_DEVS: f64 = 10.0;
+
+/// Simpson intervals of the swaption integral.
+const SWAPTION_INTERVALS: usize = 400;
+
+/// G2++ model errors.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum G2PlusPlusError {
+    /// Mean reversion speed must be positive and finite.
+    #[error("Invalid mean reversion: {0} (must be positive)")]
+    InvalidMeanReversion(f64),
+
+    /// Volatility must be positive and finite.
+    #[error("Invalid volatility: {0} (must be positive)")]
+    InvalidVolatility(f64),
+
+    /// Correlation must lie in the open interval (-1, 1).
+    #[error("Invalid correlation: rho = {0} (must be in (-1, 1))")]
+    InvalidCorrelation(f64),
+
+    /// Times must be finite and ordered.
+    #[error("Invalid time: {0}")]
+    InvalidTime(String),
+
+    /// Strike must be finite.
+    #[error("Invalid strike: {0}")]
+    InvalidStrike(f64),
+
+    /// The initial curve failed to return a discount factor.
+    #[error("Initial curve error: {0}")]
+    Curve(#[from] MarketDataError),
+}
+
+/// G2++ model parameters.
+///
+/// # Type Parameters
+///
+/// * `T` - Float type (f64 or DualNumber for AD compatibility)
+///
+/// # Fields
+///
+/// * `a`, `sigma` - Mean reversion and volatility of the first factor `x`
+/// * `b`, `eta` - Mean reversion and volatility of the second factor `y`
+/// * `rho` - Correlation of the two Brownian motions
+/// * `initial_curve` - Initial discount curve fitted by φ(t)
+#[derive(Clone, Debug)]
+pub struct G2PlusPlusParams<T: Float> {
+    /// Mean reversion speed of `x` (a > 0)
+    pub a: T,
+    /// Volatility of `x` (sigma > 0)
+    pub sigma: T,
+    /// Mean reversion speed of `y` (b > 0)
+    pub b: T,
+    /// Volatility of `y` (eta > 0)
+    pub eta: T,
+    /// Correlation of the factors (-1 < rho < 1)
+    pub rho: T,
+    /// Initial discount curve
+    pub initial_curve: CurveEnum<T>,
+}
+
+impl<T: Float> G2PlusPlusParams<T> {
+    /// Create new G2++ parameters with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `G2PlusPlusError` if a mean reversion or volatility is not
+    /// positive or `rho` is outside (-1, 1).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pricer_models::models::rates::g2pp::{G2PlusPlusError, G2PlusPlusParams};
+    /// use pricer_core::market_data::curves::CurveEnum;
+    ///
+    /// let curve = CurveEnum::flat(0.03_f64);
+    /// assert!(G2PlusPlusParams::new(0.5, 0.01, 0.05, 0.008, -0.7, curve.clone()).is_ok());
+    /// assert_eq!(
+    ///     G2PlusPlusParams::new(0.5, 0.01, 0.05, 0.008, -1.0, curve).unwrap_err(),
+    ///     G2PlusPlusError::InvalidCorrelation(-1.0)
+    /// );
+    /// ```
+    pub fn new(
+        a: T,
+        sigma: T,
+        b: T,
+        eta: T,
+        rho: T,
+        initial_curve: CurveEnum<T>,
+    ) -> Result<Self, G2PlusPlusError> {
+        let params = Self {
+            a,
+            sigma,
+            b,
+            eta,
+            rho,
+            initial_curve,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// Validate the parameters.
+    pub fn validate(&self) -> Result<(), G2PlusPlusError> {
+        let to_f64 = |x: T| x.to_f64().unwrap_or(f64::NAN);
+        for k in [self.a, self.b] {
+            if !(k > T::zero() && k.is_finite()) {
+                return Err(G2PlusPlusError::InvalidMeanReversion(to_f64(k)));
+            }
+        }
+        for s in [self.sigma, self.eta] {
+            if !(s > T::zero() && s.is_finite()) {
+                return Err(G2PlusPlusError::InvalidVolatility(to_f64(s)));
+            }
+        }
+        if self.rho.is_nan() || self.rho.abs() >= T::one() {
+            return Err(G2PlusPlusError::InvalidCorrelation(to_f64(self.rho)));
+        }
+        Ok(())
+    }
+
+    /// `B_z(τ) = (1 - e^{-zτ}) / z`.
+    #[inline]
+    fn b_factor(z: T, tau: T) -> T {
+        (T::one() - (-z * tau).exp()) / z
+    }
+
+    /// Variance `V(t, T)` of `∫_t^T (x + y) du` given the state at `t`.
+    pub fn bond_variance(&self, t: T, maturity: T) -> T {
+        let tau = maturity - t;
+        let (a, b) = (self.a, self.b);
+        let two = T::from(2.0).unwrap();
+        let three = T::from(3.0).unwrap();
+        let single = |k: T, s: T| {
+            s * s / (k * k)
+                * (tau + two / k * (-k * tau).exp()
+                    - (-two * k * tau).exp() / (two * k)
+                    - three / (two * k))
+        };
+        let cross = two * self.rho * self.sigma * self.eta / (a * b)
+            * (tau + ((-a * tau).exp() - T::one()) / a + ((-b * tau).exp() - T::one()) / b
+                - ((-(a + b) * tau).exp() - T::one()) / (a + b));
+        single(a, self.sigma) + single(b, self.eta) + cross
+    }
+
+    /// Zero-coupon bond price `P(t, T)` given the factors `x`, `y` at `t`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `G2PlusPlusError::Curve` if the initial curve rejects `t`
+    /// or `maturity`.
+    pub fn zero_coupon_bond(&self, t: T, maturity: T, x: T, y: T) -> Result<T, G2PlusPlusError> {
+        let p_t = self.initial_curve.discount_factor(t)?;
+        let p_m = self.initial_curve.discount_factor(maturity)?;
+        let half = T::from(0.5).unwrap();
+        let convexity = half
+            * (self.bond_variance(t, maturity) - self.bond_variance(T::zero(), maturity)
+                + self.bond_variance(T::zero(), t));
+        let tau = maturity - t;
+        Ok(p_m / p_t
+            * (convexity - Self::b_factor(self.a, tau) * x - Self::b_factor(self.b, tau) * y).exp())
+    }
+
+    /// Deterministic shift φ(t).
+    ///
+    /// The instantaneous forward `f^M(0, t)` is taken by a central
+    /// difference of `ln P^M(0, t)` (one-sided near zero).
+    ///
+    /// # Errors
+    ///
+    /// Returns `G2PlusPlusError::Curve` if the initial curve fails.
+    pub fn phi(&self, t: T) -> Result<T, G2PlusPlusError> {
+        let h = T::from(FORWARD_BUMP).unwrap();
+        let lower = if t > h { t - h } else { T::zero() };
+        let upper = t + h;
+        let forward = (self.initial_curve.discount_factor(lower)?.ln()
+            - self.initial_curve.discount_factor(upper)?.ln())
+            / (upper - lower);
+        let (a, b) = (self.a, self.b);
+        let two = T::from(2.0).unwrap();
+        let ga = T::one() - (-a * t).exp();
+        let gb = T::one() - (-b * t).exp();
+        Ok(forward
+            + self.sigma * self.sigma / (two * a * a) * ga * ga
+            + self.eta * self.eta / (two * b * b) * gb * gb
+            + self.rho * self.sigma * self.eta / (a * b) * ga * gb)
+    }
+
+    /// Short rate `r(t) = x + y + φ(t)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `G2PlusPlusError::Curve` if the initial curve fails.
+    pub fn short_rate(&self, t: T, x: T, y: T) -> Result<T, G2PlusPlusError> {
+        Ok(x + y + self.phi(t)?)
+    }
+
+    /// Deterministic discount factor `exp(-∫_s^t φ(u) du)`.
+    ///
+    /// Pathwise discounting from `s` to `t` is this factor times
+    /// `exp(-∫_s^t (x + y) du)`; it equals
+    /// `P^M(0,t)/P^M(0,s) * exp(-½[V(0,t) - V(0,s)])` exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns `G2PlusPlusError::Curve` if the initial curve fails.
+    pub fn shift_discount(&self, s: T, t: T) -> Result<T, G2PlusPlusError> {
+        let p_s = self.initial_curve.discount_factor(s)?;
+        let p_t = self.initial_curve.discount_factor(t)?;
+        let half = T::from(0.5).unwrap();
+        Ok(p_t / p_s
+            * (-half * (self.bond_variance(T::zero(), t) - self.bond_variance(T::zero(), s))).exp())
+    }
+}
+
+impl G2PlusPlusParams<f64> {
+    /// European option on a zero-coupon bond.
+    ///
+    /// Returns the price of the option expiring at `expiry` on the bond
+    /// maturing at `maturity`, per unit face.
+    ///
+    /// # Errors
+    ///
+    /// Returns `G2PlusPlusError` if `0 < expiry < maturity` fails, the
+    /// strike is not positive, or the initial curve fails.
+    pub fn bond_option(
+        &self,
+        expiry: f64,
+        maturity: f64,
+        strike: f64,
+        is_call: bool,
+    ) -> Result<f64, G2PlusPlusError> {
+        if !(expiry > 0.0 && maturity > expiry && maturity.is_finite()) {
+            return Err(G2PlusPlusError::InvalidTime(format!(
+                "expiry {expiry}, maturity {maturity} (need 0 < expiry < maturity)"
+            )));
+        }
+        if !(strike > 0.0 && strike.is_finite()) {
+            return Err(G2PlusPlusError::InvalidStrike(strike));
+        }
+        let (a, b, sigma, eta, rho) = (self.a, self.b, self.sigma, self.eta, self.rho);
+        let tau = maturity - expiry;
+        let ga = 1.0 - (-a * tau).exp();
+        let gb = 1.0 - (-b * tau).exp();
+        let variance =
+            sigma * sigma / (2.0 * a.powi(3)) * ga * ga * (1.0 - (-2.0 * a * expiry).exp())
+                + eta * eta / (2.0 * b.powi(3)) * gb * gb * (1.0 - (-2.0 * b * expiry).exp())
+                + 2.0 * rho * sigma * eta / (a * b * (a + b))
+                    * ga
+                    * gb
+                    * (1.0 - (-(a + b) * expiry).exp());
+        let std = variance.sqrt();
+
+        let p_t = self.initial_curve.discount_factor(expiry)?;
+        let p_m = self.initial_curve.discount_factor(maturity)?;
+        let d1 = (p_m / (strike * p_t)).ln() / std + 0.5 * std;
+        let d2 = d1 - std;
+        Ok(if is_call {
+            p_m * norm_cdf(d1) - strike * p_t * norm_cdf(d2)
+        } else {
+            strike * p_t * norm_cdf(-d2) - p_m * norm_cdf(-d1)
+        })
+    }
+
+    /// European swaption per unit notional (Brigo-Mercurio, Theorem 4.2.3).
+    ///
+    /// The underlying swap starts at `expiry` and exchanges the fixed
+    /// rate `strike` at `payment_times` `t_1 < … < t_n`, with accruals
+    /// `t_i - t_{i-1}` and `t_0 = expiry`, against floating. Under the
+    /// `expiry`-forward measure the price is a one-dimensional integral
+    /// over `x(expiry)` of a closed-form conditional expectation in `y`,
+    /// evaluated by Simpson's rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns `G2PlusPlusError` if `expiry` is not positive, the payment
+    /// times are empty or not strictly increasing after `expiry`, the
+    /// strike is not finite, or the initial curve fails.
+    pub fn swaption(
+        &self,
+        expiry: f64,
+        payment_times: &[f64],
+        strike: f64,
+        is_payer: bool,
+    ) -> Result<f64, G2PlusPlusError> {
+        if !(expiry > 0.0 && expiry.is_finite()) {
+            return Err(G2PlusPlusError::InvalidTime(format!(
+                "expiry {expiry} (must be positive)"
+            )));
+        }
+        let mut previous = expiry;
+        for &t in payment_times {
+            if !(t > previous && t.is_finite()) {
+                return Err(G2PlusPlusError::InvalidTime(format!(
+                    "payment times {payment_times:?} (must be increasing after expiry {expiry})"
+                )));
+            }
+            previous = t;
+        }
+        if payment_times.is_empty() {
+            return Err(G2PlusPlusError::InvalidTime(
+                "at least one payment time is required".to_string(),
+            ));
+        }
+        if !strike.is_finite() {
+            return Err(G2PlusPlusError::InvalidStrike(strike));
+        }
+
+        let (a, b, sigma, eta, rho) = (self.a, self.b, self.sigma, self.eta, self.rho);
+        let t = expiry;
+        let omega = if is_payer { 1.0 } else { -1.0 };
+        let p_t = self.initial_curve.discount_factor(t)?;
+
+        // Moments of (x, y) at expiry under the expiry-forward measure
+        let ab = rho * sigma * eta;
+        let mu_x = -(sigma * sigma / (a * a) + ab / (a * b)) * (1.0 - (-a * t).exp())
+            + sigma * sigma / (2.0 * a * a) * (1.0 - (-2.0 * a * t).exp())
+            + ab / (b * (a + b)) * (1.0 - (-(a + b) * t).exp());
+        let mu_y = -(eta * eta / (b * b) + ab / (a * b)) * (1.0 - (-b * t).exp())
+            + eta * eta / (2.0 * b * b) * (1.0 - (-2.0 * b * t).exp())
+            + ab / (a * (a + b)) * (1.0 - (-(a + b) * t).exp());
+        let sd_x = sigma * ((1.0 - (-2.0 * a * t).exp()) / (2.0 * a)).sqrt();
+        let sd_y = eta * ((1.0 - (-2.0 * b * t).exp()) / (2.0 * b)).sqrt();
+        let rho_xy = ab / ((a + b) * sd_x * sd_y) * (1.0 - (-(a + b) * t).exp());
+        let root = (1.0 - rho_xy * rho_xy).sqrt();
+
+        // Coupons c_i A(T, t_i) and loadings B_a, B_b for each payment
+        let mut coupons = Vec::with_capacity(payment_times.len());
+        let mut previous = t;
+        for (i, &ti) in payment_times.iter().enumerate() {
+            let mut c = strike * (ti - previous);
+            if i == payment_times.len() - 1 {
+                c += 1.0;
+            }
+            previous = ti;
+            let p_i = self.initial_curve.discount_factor(ti)?;
+            let ln_a = 0.5
+                * (self.bond_variance(t, ti) - self.bond_variance(0.0, ti)
+                    + self.bond_variance(0.0, t));
+            coupons.push((
+                c * p_i / p_t * ln_a.exp(),
+                Self::b_factor(a, ti - t),
+                Self::b_factor(b, ti - t),
+            ));
+        }
+
+        let mut lambdas = vec![0.0; coupons.len()];
+        let mut y_bar = mu_y;
+        let mut integrand = |x: f64| -> f64 {
+            for (lambda, &(ca, ba, _)) in lambdas.iter_mut().zip(&coupons) {
+                *lambda = ca * (-ba * x).exp();
+            }
+            y_bar = critical_y(&lambdas, &coupons, y_bar);
+            let z = (x - mu_x) / sd_x;
+            let h1 = (y_bar - mu_y) / (sd_y * root) - rho_xy * z / root;
+            let mut value = norm_cdf(-omega * h1);
+            for (lambda, &(_, _, bb)) in lambdas.iter().zip(&coupons) {
+                let kappa = -bb * (mu_y - 0.5 * root * root * sd_y * sd_y * bb + rho_xy * sd_y * z);
+                let h2 = h1 + bb * sd_y * root;
+                value -= lambda * kappa.exp() * norm_cdf(-omega * h2);
+            }
+            norm_pdf(z) / sd_x * value
+        };
+
+        let lower = mu_x - SWAPTION_STD_DEVS * sd_x;
+        let h = 2.0 * SWAPTION_STD_DEVS * sd_x / SWAPTION_INTERVALS as f64;
+        let mut sum = integrand(lower) + integrand(lower + h * SWAPTION_INTERVALS as f64);
+        for i in 1..SWAPTION_INTERVALS {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            sum += weight * integrand(lower + h * i as f64);
+        }
+        Ok((omega * p_t * sum * h / 3.0).max(0.0))
+    }
+}
+
+/// Solves `Σ λ_i e^{-B_b,i ȳ} = 1` for `ȳ`, starting from `guess`.
+///
+/// The sum is convex and strictly decreasing in `ȳ`, so Newton steps
+/// from any start converge once the bracket is kept.
+fn critical_y(lambdas: &[f64], coupons: &[(f64, f64, f64)], guess: f64) -> f64 {
+    let value_and_slope = |y: f64| -> (f64, f64) {
+        lambdas
+            .iter()
+            .zip(coupons)
+            .fold((-1.0, 0.0), |(v, d), (l, &(_, _, bb))| {
+                let term = l * (-bb * y).exp();
+                (v + term, d - bb * term)
+            })
+    };
+    let (mut lo, mut hi) = (f64::NEG_INFINITY, f64::INFINITY);
+    let mut y = if guess.is_finite() { guess } else { 0.0 };
+    for _ in 0..100 {
+        let (value, slope) = value_and_slope(y);
+        if value > 0.0 {
+            lo = y;
+        } else {
+            hi = y;
+        }
+        if value.abs() < 1e-15 {
+            break;
+        }
+        let mut next = y - value / slope;
+        if !(next > lo && next < hi) {
+            next = if lo.is_finite() && hi.is_finite() {
+                0.5 * (lo + hi)
+            } else if lo.is_finite() {
+                lo + 1.0
+            } else {
+                hi - 1.0
+            };
+        }
+        if (next - y).abs() < 1e-15 {
+            y = next;
+            break;
+        }
+        y = next;
+    }
+    y
+}
+
+/// G2++ two-factor model for Monte Carlo simulation.
+///
+/// The state is `(x, y)`; the short rate follows from
+/// [`G2PlusPlusParams::short_rate`]. Each step draws the exact Gaussian
+/// transition of the two Ornstein-Uhlenbeck factors from two independent
+/// standard normals, so any step size is exact and no simulation time
+/// needs to be tracked.
+#[derive(Clone, Debug, Default)]
+pub struct G2PlusPlusModel<T: Float> {
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: Float> G2PlusPlusModel<T> {
+    /// Create a new G2++ model instance.
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: Float> Differentiable for G2PlusPlusModel<T> {}
+
+impl<T: Float + Default> StochasticModel<T> for G2PlusPlusModel<T> {
+    type State = TwoFactorState<T>;
+    type Params = G2PlusPlusParams<T>;
+
+    /// Exact transition over `dt`:
+    /// ```text
+    /// x' = x e^{-a dt} + s_x z1
+    /// y' = y e^{-b dt} + s_y (ρ' z1 + sqrt(1 - ρ'²) z2)
+    /// ```
+    /// with `s_x² = sigma² (1 - e^{-2a dt}) / (2a)` and `ρ'` the
+    /// correlation of the two increments.
+    fn evolve_step(state: Self::State, dt: T, dw: &[T], params: &Self::Params) -> Self::State {
+        let (a, b) = (params.a, params.b);
+        let two = T::from(2.0).unwrap();
+        let sd_x = params.sigma * ((T::one() - (-two * a * dt).exp()) / (two * a)).sqrt();
+        let sd_y = params.eta * ((T::one() - (-two * b * dt).exp()) / (two * b)).sqrt();
+        let covariance =
+            params.rho * params.sigma * params.eta * (T::one() - (-(a + b) * dt).exp()) / (a + b);
+        let corr = if sd_x > T::zero() && sd_y > T::zero() {
+            covariance / (sd_x * sd_y)
+        } else {
+            params.rho
+        };
+        let z2 = corr * dw[0] + (T::one() - corr * corr).max(T::zero()).sqrt() * dw[1];
+        TwoFactorState {
+            first: state.first * (-a * dt).exp() + sd_x * dw[0],
+            second: state.second * (-b * dt).exp() + sd_y * z2,
+        }
+    }
+
+    fn initial_state(_params: &Self::Params) -> Self::State {
+        TwoFactorState {
+            first: T::zero(),
+            second: T::zero(),
+        }
+    }
+
+    fn brownian_dim() -> usize {
+        2
+    }
+
+    fn model_name() -> &'static str {
+        "G2++"
+    }
+
+    fn num_factors() -> usize {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::normals;
+    use pricer_core::market_data::curves::{CurveInterpolation, InterpolatedCurve};
+
+    fn upward_curve() -> CurveEnum<f64> {
+        let times = [0.5, 1.0, 2.0, 5.0, 10.0, 20.0];
+        let rates = [0.02, 0.022, 0.025, 0.03, 0.034, 0.036];
+        CurveEnum::Interpolated(
+            InterpolatedCurve::new(&times, &rates, CurveInterpolation::Linear, true).unwrap(),
+        )
+    }
+
+    fn params() -> G2PlusPlusParams<f64> {
+        G2PlusPlusParams::new(0.5, 0.012, 0.05, 0.008, -0.7, upward_curve()).unwrap()
+    }
+
+    /// Hull-White European payer swaption by Jamshidian on a flat curve.
+    fn hull_white_payer(a: f64, sigma: f64, rate: f64, expiry: f64, pays: &[f64], k: f64) -> f64 {
+        let b = |tau: f64| (1.0 - (-a * tau).exp()) / a;
+        let x_var = sigma * sigma * (1.0 - (-2.0 * a * expiry).exp()) / (2.0 * a);
+        let p0 = |t: f64| (-rate * t).exp();
+        let bond = |t: f64, x: f64| {
+            let bt = b(t - expiry);
+            p0(t) / p0(expiry) * (-bt * x - 0.5 * bt * bt * x_var).exp()
+        };
+        let mut previous = expiry;
+        let coupons: Vec<(f64, f64)> = pays
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| {
+                let mut c = k * (t - previous);
+                previous = t;
+                if i == pays.len() - 1 {
+                    c += 1.0;
+                }
+                (t, c)
+            })
+            .collect();
+        let (mut lo, mut hi) = (-1.0, 1.0);
+        for _ in 0..200 {
+            let mid = 0.5 * (lo + hi);
+            let sum: f64 = coupons.iter().map(|&(t, c)| c * bond(t, mid)).sum();
+            if sum > 1.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let x_star = 0.5 * (lo + hi);
+        coupons
+            .iter()
+            .map(|&(t, c)| {
+                let strike = bond(t, x_star);
+                let sigma_p = x_var.sqrt() * b(t - expiry);
+                let h = (p0(t) / (p0(expiry) * strike)).ln() / sigma_p + 0.5 * sigma_p;
+                c * (strike * p0(expiry) * norm_cdf(-h + sigma_p) - p0(t) * norm_cdf(-h))
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_params_validation() {
+        let curve = CurveEnum::flat(0.03);
+        assert!(G2PlusPlusParams::new(0.5, 0.01, 0.05, 0.008, -0.7, curve.clone()).is_ok());
+        assert_eq!(
+            G2PlusPlusParams::new(0.0, 0.01, 0.05, 0.008, -0.7, curve.clone()).unwrap_err(),
+            G2PlusPlusError::InvalidMeanReversion(0.0)
+        );
+        assert_eq!(
+            G2PlusPlusParams::new(0.5, 0.01, 0.05, -0.008, -0.7, curve.clone()).unwrap_err(),
+            G2PlusPlusError::InvalidVolatility(-0.008)
+        );
+        assert!(matches!(
+            G2PlusPlusParams::new(0.5, 0.01, 0.05, 0.008, f64::NAN, curve),
+            Err(G2PlusPlusError::InvalidCorrelation(_))
+        ));
+    }
+
+    #[test]
+    fn test_zero_coupon_bond_fits_curve() {
+        let p = params();
+        for t in [0.5, 1.0, 3.0, 7.5, 15.0] {
+            let expected = p.initial_curve.discount_factor(t).unwrap();
+            assert!((p.zero_coupon_bond(0.0, t, 0.0, 0.0).unwrap() - expected).abs() < 1e-14);
+        }
+        // Bonds fall as either factor rises
+        let base = p.zero_coupon_bond(1.0, 5.0, 0.0, 0.0).unwrap();
+        assert!(p.zero_coupon_bond(1.0, 5.0, 0.01, 0.0).unwrap() < base);
+        assert!(p.zero_coupon_bond(1.0, 5.0, 0.0, 0.01).unwrap() < base);
+    }
+
+    #[test]
+    fn test_bond_option_put_call_parity() {
+        let p = params();
+        let (expiry, maturity) = (2.0, 7.0);
+        let p_t = p.initial_curve.discount_factor(expiry).unwrap();
+        let p_m = p.initial_curve.discount_factor(maturity).unwrap();
+        let strike = p_m / p_t;
+        let call = p.bond_option(expiry, maturity, strike, true).unwrap();
+        let put = p.bond_option(expiry, maturity, strike, false).unwrap();
+        assert!(call > 0.0);
+        assert!((call - put - (p_m - strike * p_t)).abs() < 1e-14);
+        assert!(p.bond_option(3.0, 2.0, 0.9, true).is_err());
+    }
+
+    #[test]
+    fn test_swaption_reduces_to_hull_white() {
+        // A negligible second factor leaves Hull-White with (a, sigma)
+        let (a, sigma, rate) = (0.1, 0.01, 0.03);
+        let p = G2PlusPlusParams::new(a, sigma, 0.3, 1e-7, 0.0, CurveEnum::flat(rate)).unwrap();
+        let payments: Vec<f64> = (2..=6).map(f64::from).collect();
+        for strike in [0.02, 0.03, 0.04] {
+            let g2 = p.swaption(1.0, &payments, strike, true).unwrap();
+            let hw = hull_white_payer(a, sigma, rate, 1.0, &payments, strike);
+            // Both sides carry the ~1e-7 error of norm_cdf per coupon
+            assert!((g2 - hw).abs() < 2e-6, "strike {strike}: {g2} vs {hw}");
+        }
+    }
+
+    #[test]
+    fn test_swaption_payer_receiver_parity() {
+        let p = params();
+        let expiry = 2.0;
+        let payments: Vec<f64> = (1..=10).map(|i| expiry + 0.5 * i as f64).collect();
+        let strike = 0.032;
+        let payer = p.swaption(expiry, &payments, strike, true).unwrap();
+        let receiver = p.swaption(expiry, &payments, strike, false).unwrap();
+
+        let df = |t: f64| p.initial_curve.discount_factor(t).unwrap();
+        let annuity: f64 = payments.iter().map(|&t| 0.5 * df(t)).sum();
+        let swap = df(expiry) - df(*payments.last().unwrap()) - strike * annuity;
+        assert!((payer - receiver - swap).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_single_payment_swaption_is_bond_option() {
+        // A one-period payer swaption is (1 + Kτ) puts on the bond at 1/(1 + Kτ)
+        let p = params();
+        let (expiry, pay, strike) = (1.5, 2.5, 0.03);
+        let swaption = p.swaption(expiry, &[pay], strike, true).unwrap();
+        let c = 1.0 + strike;
+        let put = c * p.bond_option(expiry, pay, 1.0 / c, false).unwrap();
+        assert!((swaption - put).abs() < 1e-7, "{swaption} vs {put}");
+    }
+
+    #[test]
+    fn test_monte_carlo_reprices_swaption_and_curve() {
+        let p = params();
+        let expiry = 2.0;
+        let payments: Vec<f64> = (3..=7).map(f64::from).collect();
+        let strike = 0.03;
+        let analytic = p.swaption(expiry, &payments, strike, true).unwrap();
+
+        // Exact factor steps with trapezoidal ∫(x + y) on a fine grid
+        let n_steps = 100;
+        let dt = expiry / n_steps as f64;
+        let n_paths = 40_000;
+        let mut z = normals(11);
+        let (mut sum, mut sum_sq, mut df_sum) = (0.0, 0.0, 0.0);
+        for _ in 0..n_paths {
+            let mut state = G2PlusPlusModel::initial_state(&p);
+            let mut integral = 0.0;
+            for _ in 0..n_steps {
+                let next = G2PlusPlusModel::evolve_step(state, dt, &[z(), z()], &p);
+                integral += 0.5 * dt * (state.first + state.second + next.first + next.second);
+                state = next;
+            }
+            let discount = p.shift_discount(0.0, expiry).unwrap() * (-integral).exp();
+            let bond = |t: f64| {
+                p.zero_coupon_bond(expiry, t, state.first, state.second)
+                    .unwrap()
+            };
+            let mut previous = expiry;
+            let fixed: f64 = payments
+                .iter()
+                .map(|&t| {
+                    let c = strike * (t - previous) * bond(t);
+                    previous = t;
+                    c
+                })
+                .sum();
+            let payoff = discount * (1.0 - bond(*payments.last().unwrap()) - fixed).max(0.0);
+            sum += payoff;
+            sum_sq += payoff * payoff;
+            df_sum += discount;
+        }
+        let n = n_paths as f64;
+        let mean = sum / n;
+        let se = ((sum_sq / n - mean * mean) / n).sqrt();
+        assert!(
+            (mean - analytic).abs() < 4.0 * se,
+            "MC {mean} ± {se} vs analytic {analytic}"
+        );
+        let df = p.initial_curve.discount_factor(expiry).unwrap();
+        assert!((df_sum / n - df).abs() < 2e-4);
+    }
+
+    #[test]
+    fn test_phi_matches_short_rate_drift() {
+        // On a flat curve φ(0) = r and φ rises with the convexity terms
+        let p = G2PlusPlusParams::new(0.5, 0.01, 0.05, 0.008, 0.3, CurveEnum::flat(0.03)).unwrap();
+        assert!((p.phi(0.0).unwrap() - 0.03).abs() < 1e-10);
+        assert!(p.phi(10.0).unwrap() > 0.03);
+        assert!((p.short_rate(0.0, 0.001, -0.002).unwrap() - 0.029).abs() < 1e-10);
+        // exp(-∫φ) = P(0,t) exp(-½V(0,t))
+        let v = p.bond_variance(0.0, 5.0);
+        assert!((p.shift_discount(0.0, 5.0).unwrap() - (-0.15 - 0.5 * v).exp()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_model_properties() {
+        assert_eq!(G2PlusPlusModel::<f64>::model_name(), "G2++");
+        assert_eq!(G2PlusPlusModel::<f64>::brownian_dim(), 2);
+        assert_eq!(G2PlusPlusModel::<f64>::num_factors(), 2);
+
+        // With zero shocks the factors decay at their own mean reversion
+        let p = params();
+        let start = TwoFactorState {
+            first: 0.01,
+            second: -0.02,
+        };
+        let next = G2PlusPlusModel::evolve_step(start, 2.0, &[0.0, 0.0], &p);
+        assert!((next.first - 0.01 * (-1.0_f64).exp()).abs() < 1e-15);
+        assert!((next.second + 0.02 * (-0.1_f64).exp()).abs() < 1e-15);
+    }
+}
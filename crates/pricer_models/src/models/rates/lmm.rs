@@ -0,0 +1,1187 @@
+//! LIBOR / forward market model (LMM) with an optional SABR volatility layer.
+//!
+//! The model evolves the simply compounded forward rates
+//! `F_i(t) = F(t; T_i, T_{i+1})` of a tenor structure `T_0 < … < T_N`
+//! directly. Each displaced forward `X_i = F_i + δ` follows
+//! ```text
+//! dX_i(t) = μ_i(t) dt + V(t) σ_i(t) X_i(0)^{1-β} X_i(t)^β dW_i(t),   dW_i dW_j = ρ_ij dt
+//! ```
+//! with the Rebonato instantaneous volatility and correlation
+//! ```text
+//! σ_i(t) = k_i [(a + b (T_i - t)) e^{-c (T_i - t)} + d]
+//! ρ_ij   = ρ∞ + (1 - ρ∞) e^{-β_ρ |T_i - T_j|}
+//! ```
+//! Without the SABR layer `V ≡ 1` and `β = 1`, giving the displaced
+//! lognormal LMM. With [`SabrLmm`], `V` is a driftless lognormal process
+//! `dV = ν V dZ`, `V(0) = 1`, correlated with every forward by `ρ`, and
+//! the forwards are CEV with exponent `β`. Normalising by `X_i(0)^{1-β}`
+//! keeps `σ_i` a lognormal-equivalent volatility for every `β`, so the
+//! SABR `α` of forward `i` is `σ_i X_i(0)^{1-β}`.
+//!
+//! ## Measures
+//!
+//! - [`LmmMeasure::Spot`]: numeraire is the discretely rebalanced bank
+//!   account rolling over the tenor dates;
+//!   `μ_i = s_i Σ_{j=q(t)}^{i} τ_j ρ_ij s_j / (1 + τ_j F_j)`
+//! - [`LmmMeasure::Terminal`]: numeraire is the bond maturing at `T_N`;
+//!   `μ_i = -s_i Σ_{j=i+1}^{N-1} τ_j ρ_ij s_j / (1 + τ_j F_j)`
+//!
+//! where `s_i = V σ_i X_i(0)^{1-β} X_i^β` and `q(t)` is the first forward not yet fixed.
+//! The state-dependent drift is integrated by predictor-corrector: the
+//! drift at the start of a step and at the predicted end are averaged.
+//! The measure-change drift of `V` is neglected, as in the closed-form
+//! SABR-LMM approximations; it vanishes for the last forward under the
+//! terminal measure.
+//!
+//! ## Closed Forms
+//!
+//! - Caplets: displaced Black with the root-mean-square volatility of
+//!   `σ_i` (or Hagan's SABR formula with that `α` when the SABR layer is
+//!   set)
+//! - Swaptions: Rebonato's frozen-weights approximation
+//!   `σ_S² T_a = Σ w_i w_j X_i X_j ∫_0^{T_a} σ_i σ_j ρ_ij dt / (S + δ)²`
+//!   with `w_i = τ_i P(0, T_{i+1}) / A`
+//!
+//! ## Usage
+//!
+//! ```
+//! use pricer_models::models::rates::lmm::{
+//!     LiborMarketModel, LmmMeasure, LmmParams, RebonatoCorrelation, RebonatoVolatility,
+//! };
+//! use pricer_core::market_data::curves::CurveEnum;
+//!
+//! let tenor: Vec<f64> = (2..=12).map(|i| 0.5 * i as f64).collect();
+//! let params = LmmParams::from_curve(
+//!     tenor,
+//!     &CurveEnum::flat(0.03),
+//!     0.0,
+//!     RebonatoVolatility::new(0.05, 0.1, 0.8, 0.15).unwrap(),
+//!     RebonatoCorrelation::new(0.1, 0.4).unwrap(),
+//! )
+//! .unwrap();
+//!
+//! // 1y into 5y semi-annual payer swaption at the money
+//! let atm = params.swap_rate(0, 10).unwrap();
+//! let price = params.swaption(0, 10, atm, true).unwrap();
+//! assert!(price > 0.0);
+//!
+//! let model = LiborMarketModel::new(params, LmmMeasure::Spot).unwrap();
+//! assert_eq!(model.brownian_dim(), 10);
+//! ```
+
+use pricer_core::market_data::curves::YieldCurve;
+use pricer_core::market_data::error::MarketDataError;
+use thiserror::Error;
+
+use crate::analytical::black76_price;
+use crate::models::hybrid::{CholeskyFactor, CorrelationError, CorrelationMatrix};
+use crate::models::sabr::{SABRModel, SABRParams};
+
+/// Tolerance when comparing simulation times with tenor dates.
+const TIME_TOLERANCE: f64 = 1e-10;
+
+/// Panel length of the Gauss-Legendre covariance integrals.
+const PANEL_LENGTH: f64 = 0.5;
+
+/// Five-point Gauss-Legendre nodes and weights on [-1, 1].
+const GAUSS_LEGENDRE_5: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// LIBOR market model errors.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum LmmError {
+    /// Tenor dates must be increasing, non-negative and at least two.
+    #[error("Invalid tenor structure: {0}")]
+    InvalidTenor(String),
+
+    /// Displaced forward must be positive and finite.
+    #[error("Invalid forward {index}: {value} (forward + displacement must be positive)")]
+    InvalidForward {
+        /// Forward index
+        index: usize,
+        /// Forward value
+        value: f64,
+    },
+
+    /// Displacement must be non-negative and finite.
+    #[error("Invalid displacement: {0} (must be non-negative)")]
+    InvalidDisplacement(f64),
+
+    /// Volatility parameters are invalid.
+    #[error("Invalid volatility: {0}")]
+    InvalidVolatility(String),
+
+    /// Correlation parameters are invalid.
+    #[error("Invalid correlation: {0}")]
+    InvalidCorrelation(String),
+
+    /// The joint correlation matrix is invalid or cannot be factorised.
+    #[error("Correlation matrix error: {0}")]
+    Correlation(#[from] CorrelationError),
+
+    /// Forward or swap indices are outside the tenor structure.
+    #[error("Invalid index: {0}")]
+    InvalidIndex(String),
+
+    /// Displaced strike must be positive.
+    #[error("Invalid strike: {0} (strike + displacement must be positive)")]
+    InvalidStrike(f64),
+
+    /// The SABR volatility approximation failed.
+    #[error("SABR approximation failed: {0}")]
+    Sabr(String),
+
+    /// The initial curve failed to return a discount factor.
+    #[error("Initial curve error: {0}")]
+    Curve(#[from] MarketDataError),
+}
+
+/// Rebonato parametric instantaneous volatility
+/// `σ(τ) = (a + b τ) e^{-c τ} + d` of a forward with time to fixing `τ`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RebonatoVolatility {
+    /// Short-end level adjustment
+    pub a: f64,
+    /// Hump slope
+    pub b: f64,
+    /// Hump decay (c ≥ 0)
+    pub c: f64,
+    /// Long-end level (d ≥ 0)
+    pub d: f64,
+}
+
+impl RebonatoVolatility {
+    /// Create a Rebonato volatility with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::InvalidVolatility` if a parameter is not finite,
+    /// `c` or `d` is negative, or `σ(0) = a + d` is not positive.
+    pub fn new(a: f64, b: f64, c: f64, d: f64) -> Result<Self, LmmError> {
+        if ![a, b, c, d].iter().all(|x| x.is_finite()) || c < 0.0 || d < 0.0 || a + d <= 0.0 {
+            return Err(LmmError::InvalidVolatility(format!(
+                "a = {a}, b = {b}, c = {c}, d = {d} (need c >= 0, d >= 0, a + d > 0)"
+            )));
+        }
+        Ok(Self { a, b, c, d })
+    }
+
+    /// Create a constant volatility `σ(τ) = sigma`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::InvalidVolatility` if `sigma` is not positive.
+    pub fn flat(sigma: f64) -> Result<Self, LmmError> {
+        Self::new(0.0, 0.0, 0.0, sigma)
+    }
+
+    /// Volatility at time to fixing `tau`.
+    #[inline]
+    pub fn value(&self, tau: f64) -> f64 {
+        (self.a + self.b * tau) * (-self.c * tau).exp() + self.d
+    }
+
+    /// `∫_{t0}^{t1} σ(T_i - u) σ(T_j - u) du`, with the upper limit capped at
+    /// the earlier fixing date.
+    pub fn covariance(&self, t0: f64, t1: f64, ti: f64, tj: f64) -> f64 {
+        let upper = t1.min(ti).min(tj);
+        if upper <= t0 {
+            return 0.0;
+        }
+        let panels = ((upper - t0) / PANEL_LENGTH).ceil().max(1.0) as usize;
+        let h = (upper - t0) / panels as f64;
+        let mut sum = 0.0;
+        for p in 0..panels {
+            let mid = t0 + (p as f64 + 0.5) * h;
+            for &(x, w) in &GAUSS_LEGENDRE_5 {
+                let u = mid + 0.5 * h * x;
+                sum += w * self.value(ti - u) * self.value(tj - u);
+            }
+        }
+        0.5 * h * sum
+    }
+}
+
+/// Rebonato two-parameter correlation
+/// `ρ_ij = ρ∞ + (1 - ρ∞) e^{-β |T_i - T_j|}`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RebonatoCorrelation {
+    /// Decorrelation speed (β > 0)
+    pub beta: f64,
+    /// Long-distance correlation (0 ≤ ρ∞ < 1)
+    pub long_correlation: f64,
+}
+
+impl RebonatoCorrelation {
+    /// Create a Rebonato correlation with validation.
+    ///
+    /// The resulting matrix is positive definite for `β > 0` and
+    /// `0 ≤ ρ∞ < 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::InvalidCorrelation` otherwise.
+    pub fn new(beta: f64, long_correlation: f64) -> Result<Self, LmmError> {
+        if !(beta > 0.0 && beta.is_finite() && (0.0..1.0).contains(&long_correlation)) {
+            return Err(LmmError::InvalidCorrelation(format!(
+                "beta = {beta}, long_correlation = {long_correlation} \
+                 (need beta > 0, 0 <= long_correlation < 1)"
+            )));
+        }
+        Ok(Self {
+            beta,
+            long_correlation,
+        })
+    }
+
+    /// Correlation of the forwards fixing at `ti` and `tj`.
+    #[inline]
+    pub fn value(&self, ti: f64, tj: f64) -> f64 {
+        let rho_inf = self.long_correlation;
+        rho_inf + (1.0 - rho_inf) * (-self.beta * (ti - tj).abs()).exp()
+    }
+}
+
+/// Stochastic volatility layer of the SABR-LMM.
+///
+/// The forwards share one volatility multiplier `V` with `dV = ν V dZ`,
+/// `V(0) = 1`, correlated with each forward by `rho`, and are CEV with
+/// exponent `beta` in the displaced forward.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SabrLmm {
+    /// CEV exponent (0 ≤ β ≤ 1)
+    pub beta: f64,
+    /// Volatility of volatility (ν ≥ 0)
+    pub nu: f64,
+    /// Forward-volatility correlation (-1 < ρ < 1)
+    pub rho: f64,
+}
+
+impl SabrLmm {
+    /// Create a SABR volatility layer with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::InvalidVolatility` if `beta` is outside [0, 1],
+    /// `nu` is negative or `rho` is outside (-1, 1).
+    pub fn new(beta: f64, nu: f64, rho: f64) -> Result<Self, LmmError> {
+        if !((0.0..=1.0).contains(&beta) && nu >= 0.0 && nu.is_finite() && rho.abs() < 1.0) {
+            return Err(LmmError::InvalidVolatility(format!(
+                "SABR beta = {beta}, nu = {nu}, rho = {rho}"
+            )));
+        }
+        Ok(Self { beta, nu, rho })
+    }
+
+    /// Take `beta`, `nu` and `rho` from a SABR smile.
+    ///
+    /// The smile's `alpha` corresponds to the root-mean-square `σ_i`
+    /// times `X_i(0)^{1-β}` and is matched through the forward scalings
+    /// `k_i` of [`LmmParams`]; its shift should equal the model
+    /// displacement.
+    pub fn from_sabr(params: &SABRParams<f64>) -> Self {
+        Self {
+            beta: params.beta,
+            nu: params.nu,
+            rho: params.rho,
+        }
+    }
+}
+
+/// Numeraire of the simulation measure.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LmmMeasure {
+    /// Discretely rebalanced bank account (spot LIBOR measure)
+    #[default]
+    Spot,
+    /// Zero-coupon bond maturing at the last tenor date
+    Terminal,
+}
+
+/// LIBOR market model parameters on a tenor structure.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LmmParams {
+    tenor: Vec<f64>,
+    forwards: Vec<f64>,
+    start_discount: f64,
+    displacement: f64,
+    volatility: RebonatoVolatility,
+    scaling: Vec<f64>,
+    correlation: RebonatoCorrelation,
+    sabr: Option<SabrLmm>,
+}
+
+impl LmmParams {
+    /// Create LMM parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `tenor` - Tenor dates `T_0 < … < T_N` in years (`T_0 ≥ 0`)
+    /// * `forwards` - Initial forwards `F_i(0)` for `[T_i, T_{i+1}]`
+    /// * `start_discount` - Discount factor `P(0, T_0)`
+    /// * `displacement` - Displacement `δ ≥ 0`
+    /// * `volatility` - Rebonato volatility shared by all forwards
+    /// * `correlation` - Rebonato correlation
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError` if the tenor is not increasing, the forward
+    /// count is not `N`, a displaced forward is not positive, or the
+    /// displacement or start discount is invalid.
+    pub fn new(
+        tenor: Vec<f64>,
+        forwards: Vec<f64>,
+        start_discount: f64,
+        displacement: f64,
+        volatility: RebonatoVolatility,
+        correlation: RebonatoCorrelation,
+    ) -> Result<Self, LmmError> {
+        if tenor.len() < 2
+            || tenor[0] < 0.0
+            || !tenor.iter().all(|t| t.is_finite())
+            || tenor.windows(2).any(|w| w[1] <= w[0])
+        {
+            return Err(LmmError::InvalidTenor(format!(
+                "{tenor:?} (need at least two increasing non-negative dates)"
+            )));
+        }
+        if forwards.len() != tenor.len() - 1 {
+            return Err(LmmError::InvalidTenor(format!(
+                "{} forwards for {} tenor dates",
+                forwards.len(),
+                tenor.len()
+            )));
+        }
+        if !(displacement >= 0.0 && displacement.is_finite()) {
+            return Err(LmmError::InvalidDisplacement(displacement));
+        }
+        for (index, &value) in forwards.iter().enumerate() {
+            if !(value + displacement > 0.0 && value.is_finite()) {
+                return Err(LmmError::InvalidForward { index, value });
+            }
+        }
+        if !(start_discount > 0.0 && start_discount.is_finite()) {
+            return Err(LmmError::InvalidTenor(format!(
+                "start discount {start_discount} (must be positive)"
+            )));
+        }
+        let n = forwards.len();
+        Ok(Self {
+            tenor,
+            forwards,
+            start_discount,
+            displacement,
+            volatility,
+            scaling: vec![1.0; n],
+            correlation,
+            sabr: None,
+        })
+    }
+
+    /// Create LMM parameters with forwards and `P(0, T_0)` from a curve.
+    ///
+    /// # Errors
+    ///
+    /// As [`new`](Self::new), and `LmmError::Curve` if the curve fails.
+    pub fn from_curve<C: YieldCurve<f64>>(
+        tenor: Vec<f64>,
+        curve: &C,
+        displacement: f64,
+        volatility: RebonatoVolatility,
+        correlation: RebonatoCorrelation,
+    ) -> Result<Self, LmmError> {
+        let discounts = tenor
+            .iter()
+            .map(|&t| curve.discount_factor(t))
+            .collect::<Result<Vec<_>, _>>()?;
+        let forwards = tenor
+            .windows(2)
+            .zip(discounts.windows(2))
+            .map(|(t, p)| (p[0] / p[1] - 1.0) / (t[1] - t[0]))
+            .collect();
+        let start_discount = discounts.first().copied().unwrap_or(1.0);
+        Self::new(
+            tenor,
+            forwards,
+            start_discount,
+            displacement,
+            volatility,
+            correlation,
+        )
+    }
+
+    /// Set per-forward volatility scalings `k_i`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::InvalidVolatility` unless there is one positive
+    /// scaling per forward.
+    pub fn with_scaling(mut self, scaling: Vec<f64>) -> Result<Self, LmmError> {
+        if scaling.len() != self.forwards.len()
+            || !scaling.iter().all(|&k| k > 0.0 && k.is_finite())
+        {
+            return Err(LmmError::InvalidVolatility(format!(
+                "scaling {scaling:?} (need {} positive values)",
+                self.forwards.len()
+            )));
+        }
+        self.scaling = scaling;
+        Ok(self)
+    }
+
+    /// Replace the Rebonato volatility.
+    pub fn with_volatility(mut self, volatility: RebonatoVolatility) -> Self {
+        self.volatility = volatility;
+        self
+    }
+
+    /// Replace the Rebonato correlation.
+    pub fn with_correlation(mut self, correlation: RebonatoCorrelation) -> Self {
+        self.correlation = correlation;
+        self
+    }
+
+    /// Add the SABR stochastic volatility layer.
+    pub fn with_sabr(mut self, sabr: SabrLmm) -> Self {
+        self.sabr = Some(sabr);
+        self
+    }
+
+    /// Tenor dates `T_0 … T_N`.
+    pub fn tenor(&self) -> &[f64] {
+        &self.tenor
+    }
+
+    /// Initial forwards.
+    pub fn forwards(&self) -> &[f64] {
+        &self.forwards
+    }
+
+    /// Number of forwards `N`.
+    pub fn n_forwards(&self) -> usize {
+        self.forwards.len()
+    }
+
+    /// Accrual fraction `τ_i = T_{i+1} - T_i`.
+    pub fn accrual(&self, i: usize) -> f64 {
+        self.tenor[i + 1] - self.tenor[i]
+    }
+
+    /// Displacement `δ`.
+    pub fn displacement(&self) -> f64 {
+        self.displacement
+    }
+
+    /// Rebonato volatility.
+    pub fn volatility(&self) -> &RebonatoVolatility {
+        &self.volatility
+    }
+
+    /// Rebonato correlation.
+    pub fn correlation(&self) -> &RebonatoCorrelation {
+        &self.correlation
+    }
+
+    /// Volatility scalings `k_i`.
+    pub fn scaling(&self) -> &[f64] {
+        &self.scaling
+    }
+
+    /// SABR volatility layer, if set.
+    pub fn sabr(&self) -> Option<&SabrLmm> {
+        self.sabr.as_ref()
+    }
+
+    /// Discount factor `P(0, T_k)` for `k = 0 … N`.
+    pub fn discount_factor(&self, k: usize) -> f64 {
+        self.forwards[..k]
+            .iter()
+            .enumerate()
+            .fold(self.start_discount, |p, (j, f)| {
+                p / (1.0 + self.accrual(j) * f)
+            })
+    }
+
+    /// Correlation of forwards `i` and `j`.
+    pub fn correlation_of(&self, i: usize, j: usize) -> f64 {
+        self.correlation.value(self.tenor[i], self.tenor[j])
+    }
+
+    /// Instantaneous volatility `σ_i(t)`; zero once forward `i` has fixed.
+    pub fn instantaneous_volatility(&self, i: usize, t: f64) -> f64 {
+        let ti = self.tenor[i];
+        if t >= ti {
+            0.0
+        } else {
+            self.scaling[i] * self.volatility.value(ti - t)
+        }
+    }
+
+    /// Integrated covariance `∫_{t0}^{t1} σ_i σ_j ρ_ij dt` of the log
+    /// displaced forwards (without the SABR multiplier).
+    pub fn covariance(&self, i: usize, j: usize, t0: f64, t1: f64) -> f64 {
+        self.scaling[i]
+            * self.scaling[j]
+            * self.correlation_of(i, j)
+            * self
+                .volatility
+                .covariance(t0, t1, self.tenor[i], self.tenor[j])
+    }
+
+    /// Checks a forward index with a fixing date after today.
+    fn check_forward(&self, i: usize) -> Result<(), LmmError> {
+        if i >= self.n_forwards() || self.tenor[i] <= 0.0 {
+            return Err(LmmError::InvalidIndex(format!(
+                "forward {i} (need a forward fixing after today, below {})",
+                self.n_forwards()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks a swap from `T_start` to `T_end` starting after today.
+    fn check_swap(&self, start: usize, end: usize) -> Result<(), LmmError> {
+        if start >= end || end > self.n_forwards() || self.tenor[start] <= 0.0 {
+            return Err(LmmError::InvalidIndex(format!(
+                "swap {start}..{end} (need start < end <= {} and a start after today)",
+                self.n_forwards()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the displaced strike is positive.
+    fn check_strike(&self, strike: f64) -> Result<(), LmmError> {
+        if !(strike + self.displacement > 0.0 && strike.is_finite()) {
+            return Err(LmmError::InvalidStrike(strike));
+        }
+        Ok(())
+    }
+
+    /// Displaced Black volatility from the root-mean-square volatility,
+    /// or Hagan's SABR volatility with the matching `α` when the SABR
+    /// layer is set.
+    fn smile_volatility(
+        &self,
+        forward: f64,
+        strike: f64,
+        expiry: f64,
+        rms: f64,
+    ) -> Result<f64, LmmError> {
+        let Some(sabr) = self.sabr else {
+            return Ok(rms);
+        };
+        let alpha = rms * (forward + self.displacement).powf(1.0 - sabr.beta);
+        let params = SABRParams::new_shifted(
+            forward,
+            alpha,
+            sabr.nu,
+            sabr.rho,
+            sabr.beta,
+            expiry,
+            self.displacement,
+        )
+        .map_err(|e| LmmError::Sabr(e.to_string()))?;
+        SABRModel::new(params)
+            .and_then(|model| model.implied_vol(strike))
+            .map_err(|e| LmmError::Sabr(e.to_string()))
+    }
+
+    /// Caplet volatility (displaced Black) of forward `i` at `strike`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError` for an index without a future fixing, a strike
+    /// with `K + δ ≤ 0`, or a failed SABR approximation.
+    pub fn caplet_volatility(&self, i: usize, strike: f64) -> Result<f64, LmmError> {
+        self.check_forward(i)?;
+        self.check_strike(strike)?;
+        let expiry = self.tenor[i];
+        let rms = (self.covariance(i, i, 0.0, expiry) / expiry).sqrt();
+        self.smile_volatility(self.forwards[i], strike, expiry, rms)
+    }
+
+    /// Caplet (`is_call`) or floorlet on forward `i`, per unit notional.
+    ///
+    /// # Errors
+    ///
+    /// As [`caplet_volatility`](Self::caplet_volatility).
+    pub fn caplet(&self, i: usize, strike: f64, is_call: bool) -> Result<f64, LmmError> {
+        let vol = self.caplet_volatility(i, strike)?;
+        let shift = self.displacement;
+        Ok(self.accrual(i)
+            * self.discount_factor(i + 1)
+            * black76_price(
+                self.forwards[i] + shift,
+                strike + shift,
+                self.tenor[i],
+                vol,
+                is_call,
+            ))
+    }
+
+    /// Annuity `Σ τ_i P(0, T_{i+1})` of a swap from `T_start` to `T_end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::InvalidIndex` unless `start < end ≤ N`.
+    pub fn annuity(&self, start: usize, end: usize) -> Result<f64, LmmError> {
+        if start >= end || end > self.n_forwards() {
+            return Err(LmmError::InvalidIndex(format!("swap {start}..{end}")));
+        }
+        Ok((start..end)
+            .map(|i| self.accrual(i) * self.discount_factor(i + 1))
+            .sum())
+    }
+
+    /// Forward swap rate from `T_start` to `T_end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::InvalidIndex` unless `start < end ≤ N`.
+    pub fn swap_rate(&self, start: usize, end: usize) -> Result<f64, LmmError> {
+        let annuity = self.annuity(start, end)?;
+        Ok((self.discount_factor(start) - self.discount_factor(end)) / annuity)
+    }
+
+    /// Rebonato swaption volatility (displaced Black) at `strike`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError` for an invalid swap, a strike with `K + δ ≤ 0`
+    /// or a failed SABR approximation.
+    pub fn swaption_volatility(
+        &self,
+        start: usize,
+        end: usize,
+        strike: f64,
+    ) -> Result<f64, LmmError> {
+        self.check_swap(start, end)?;
+        self.check_strike(strike)?;
+        let expiry = self.tenor[start];
+        let annuity = self.annuity(start, end)?;
+        let swap_rate = self.swap_rate(start, end)?;
+        let shift = self.displacement;
+
+        let weighted: Vec<f64> = (start..end)
+            .map(|i| {
+                self.accrual(i) * self.discount_factor(i + 1) / annuity * (self.forwards[i] + shift)
+            })
+            .collect();
+        let mut variance = 0.0;
+        for (p, i) in (start..end).enumerate() {
+            for (q, j) in (start..end).enumerate() {
+                variance += weighted[p] * weighted[q] * self.covariance(i, j, 0.0, expiry);
+            }
+        }
+        let rms = (variance / expiry).sqrt() / (swap_rate + shift);
+        self.smile_volatility(swap_rate, strike, expiry, rms)
+    }
+
+    /// European swaption on the swap from `T_start` to `T_end`, per unit
+    /// notional.
+    ///
+    /// # Errors
+    ///
+    /// As [`swaption_volatility`](Self::swaption_volatility).
+    pub fn swaption(
+        &self,
+        start: usize,
+        end: usize,
+        strike: f64,
+        is_payer: bool,
+    ) -> Result<f64, LmmError> {
+        let vol = self.swaption_volatility(start, end, strike)?;
+        let shift = self.displacement;
+        Ok(self.annuity(start, end)?
+            * black76_price(
+                self.swap_rate(start, end)? + shift,
+                strike + shift,
+                self.tenor[start],
+                vol,
+                is_payer,
+            ))
+    }
+}
+
+/// Simulation state of the LIBOR market model.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LmmState {
+    /// Current time
+    pub time: f64,
+    /// Forwards `F_i(t)`; fixed forwards keep their fixing
+    pub forwards: Vec<f64>,
+    /// SABR volatility multiplier `V(t)` (one without the SABR layer)
+    pub volatility: f64,
+    /// `Π (1 + τ_j F_j(T_j))` over the accrual periods already completed
+    rollup: f64,
+}
+
+/// LIBOR market model simulator.
+///
+/// Steps forwards under the spot or terminal measure with
+/// predictor-corrector drift. Time grids should contain the tenor dates
+/// so that forwards fix, and deflators are read, exactly on them.
+#[derive(Clone, Debug)]
+pub struct LiborMarketModel {
+    params: LmmParams,
+    measure: LmmMeasure,
+    /// Correlation of the forwards (and volatility)
+    correlation: CorrelationMatrix<f64>,
+    /// Lower Cholesky factor of `correlation`
+    cholesky: CholeskyFactor<f64>,
+}
+
+impl LiborMarketModel {
+    /// Create a simulator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LmmError::Correlation` if the joint correlation of
+    /// forwards and the SABR volatility is not positive definite.
+    pub fn new(params: LmmParams, measure: LmmMeasure) -> Result<Self, LmmError> {
+        let n = params.n_forwards();
+        let dim = n + usize::from(params.sabr.is_some());
+        let mut matrix = vec![0.0; dim * dim];
+        for i in 0..dim {
+            for j in 0..dim {
+                matrix[i * dim + j] = match (i < n, j < n) {
+                    (true, true) => params.correlation_of(i, j),
+                    (false, false) => 1.0,
+                    _ => params.sabr.map_or(0.0, |s| s.rho),
+                };
+            }
+        }
+        let correlation = CorrelationMatrix::new(&matrix, dim)?;
+        let cholesky = correlation.cholesky()?;
+        Ok(Self {
+            params,
+            measure,
+            correlation,
+            cholesky,
+        })
+    }
+
+    /// Model parameters.
+    pub fn params(&self) -> &LmmParams {
+        &self.params
+    }
+
+    /// Simulation measure.
+    pub fn measure(&self) -> LmmMeasure {
+        self.measure
+    }
+
+    /// Number of standard normals per step: one per forward, plus one for
+    /// the SABR volatility.
+    pub fn brownian_dim(&self) -> usize {
+        self.cholesky.dim()
+    }
+
+    /// State at time zero.
+    pub fn initial_state(&self) -> LmmState {
+        LmmState {
+            time: 0.0,
+            forwards: self.params.forwards.clone(),
+            volatility: 1.0,
+            rollup: 1.0,
+        }
+    }
+
+    /// Volatility of `ln X_i`: `V σ_i (X_i / X_i(0))^{β-1}`, with `V σ_i`
+    /// given in `vols`.
+    fn log_volatility(&self, forwards: &[f64], vols: &[f64], i: usize) -> f64 {
+        match self.params.sabr {
+            Some(sabr) if sabr.beta != 1.0 => {
+                let shift = self.params.displacement;
+                let ratio = (forwards[i] + shift) / (self.params.forwards[i] + shift);
+                vols[i] * ratio.powf(sabr.beta - 1.0)
+            }
+            _ => vols[i],
+        }
+    }
+
+    /// Log drifts `μ_i / X_i` of the alive forwards from `first`.
+    fn log_drifts(&self, forwards: &[f64], vols: &[f64], first: usize, out: &mut [f64]) {
+        let p = &self.params;
+        let n = p.n_forwards();
+        let shift = p.displacement;
+        let log_vols: Vec<f64> = (0..n)
+            .map(|j| {
+                if j < first {
+                    0.0
+                } else {
+                    self.log_volatility(forwards, vols, j)
+                }
+            })
+            .collect();
+        // s_j τ_j / (1 + τ_j F_j) for each alive forward
+        let terms: Vec<f64> = (0..n)
+            .map(|j| {
+                let tau = p.accrual(j);
+                log_vols[j] * (forwards[j] + shift) * tau / (1.0 + tau * forwards[j])
+            })
+            .collect();
+        for i in first..n {
+            let range = match self.measure {
+                LmmMeasure::Spot => first..i + 1,
+                LmmMeasure::Terminal => i + 1..n,
+            };
+            let sum: f64 = range.map(|j| self.correlation.get(i, j) * terms[j]).sum();
+            out[i] = match self.measure {
+                LmmMeasure::Spot => log_vols[i] * sum,
+                LmmMeasure::Terminal => -log_vols[i] * sum,
+            };
+        }
+    }
+
+    /// Evolve `state` by `dt` with independent standard normals `z`
+    /// (length [`brownian_dim`](Self::brownian_dim)).
+    pub fn evolve(&self, state: &mut LmmState, dt: f64, z: &[f64]) {
+        let p = &self.params;
+        let n = p.n_forwards();
+        let t = state.time;
+        let shift = p.displacement;
+
+        // Correlated increments
+        let w = self.cholesky.transform(z);
+
+        // Effective volatility over the step, including the SABR multiplier
+        let first = p.tenor[..n].partition_point(|&ti| ti <= t + TIME_TOLERANCE);
+        let vols: Vec<f64> = (0..n)
+            .map(|i| {
+                if i < first {
+                    0.0
+                } else {
+                    state.volatility * (p.covariance(i, i, t, t + dt) / dt).sqrt()
+                }
+            })
+            .collect();
+
+        // Predictor-corrector on the log displaced forwards
+        let sqrt_dt = dt.sqrt();
+        let mut drift_start = vec![0.0; n];
+        self.log_drifts(&state.forwards, &vols, first, &mut drift_start);
+        let step = |forwards: &[f64], drift: &[f64], i: usize| -> f64 {
+            let s = self.log_volatility(forwards, &vols, i);
+            let x =
+                (forwards[i] + shift) * ((drift[i] - 0.5 * s * s) * dt + s * sqrt_dt * w[i]).exp();
+            x - shift
+        };
+        let mut predicted = state.forwards.clone();
+        for (i, f) in predicted.iter_mut().enumerate().skip(first) {
+            *f = step(&state.forwards, &drift_start, i);
+        }
+        let mut drift_end = vec![0.0; n];
+        self.log_drifts(&predicted, &vols, first, &mut drift_end);
+        let average: Vec<f64> = drift_start
+            .iter()
+            .zip(&drift_end)
+            .map(|(a, b)| 0.5 * (a + b))
+            .collect();
+        let mut next = state.forwards.clone();
+        for (i, f) in next.iter_mut().enumerate().skip(first) {
+            *f = step(&state.forwards, &average, i);
+        }
+        state.forwards = next;
+
+        if let Some(sabr) = p.sabr {
+            let nu = sabr.nu;
+            state.volatility *= (nu * sqrt_dt * w[n] - 0.5 * nu * nu * dt).exp();
+        }
+
+        // Roll the bank account over accrual periods ending in this step
+        let end = t + dt;
+        for j in 0..n {
+            let pay = p.tenor[j + 1];
+            if pay > t + TIME_TOLERANCE && pay <= end + TIME_TOLERANCE {
+                state.rollup *= 1.0 + p.accrual(j) * state.forwards[j];
+            }
+        }
+        state.time = end;
+    }
+
+    /// Zero-coupon bond `P(T_m, T_k)` at a state on tenor date `T_m ≤ T_k`.
+    pub fn zero_coupon_bond(&self, state: &LmmState, k: usize) -> f64 {
+        let p = &self.params;
+        let m = p
+            .tenor
+            .partition_point(|&ti| ti < state.time - TIME_TOLERANCE);
+        (m..k).fold(1.0, |bond, j| {
+            bond / (1.0 + p.accrual(j) * state.forwards[j])
+        })
+    }
+
+    /// Today's value of one unit paid at tenor date `T_k`, given a state
+    /// on `T_k` (the inverse numeraire scaled to today).
+    ///
+    /// Averaging `deflator × payoff` over paths prices a payoff fixed at
+    /// `T_k`.
+    pub fn deflator(&self, state: &LmmState, k: usize) -> f64 {
+        let p = &self.params;
+        match self.measure {
+            LmmMeasure::Spot => p.start_discount / state.rollup,
+            LmmMeasure::Terminal => {
+                let n = p.n_forwards();
+                (k..n).fold(p.discount_factor(n), |deflator, j| {
+                    deflator * (1.0 + p.accrual(j) * state.forwards[j])
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::normals;
+    use pricer_core::market_data::curves::CurveEnum;
+
+    /// Annual forwards fixing at 1y … 5y on a flat 3% curve.
+    fn params(displacement: f64) -> LmmParams {
+        LmmParams::from_curve(
+            (1..=6).map(f64::from).collect(),
+            &CurveEnum::flat(0.03),
+            displacement,
+            RebonatoVolatility::new(0.05, 0.1, 0.8, 0.15).unwrap(),
+            RebonatoCorrelation::new(0.15, 0.3).unwrap(),
+        )
+        .unwrap()
+    }
+
+    /// Simulates to each tenor date, calling `visit(model, state, k)` on `T_k`.
+    fn simulate(
+        model: &LiborMarketModel,
+        paths: usize,
+        steps_per_period: usize,
+        mut visit: impl FnMut(&LiborMarketModel, &LmmState, usize),
+    ) {
+        let tenor = model.params().tenor().to_vec();
+        let mut normal = normals(42);
+        let mut z = vec![0.0; model.brownian_dim()];
+        for _ in 0..paths {
+            let mut state = model.initial_state();
+            let mut t = 0.0;
+            for (k, &tk) in tenor.iter().enumerate() {
+                let dt = (tk - t) / steps_per_period as f64;
+                for _ in 0..steps_per_period {
+                    z.iter_mut().for_each(|zi| *zi = normal());
+                    model.evolve(&mut state, dt, &z);
+                }
+                t = tk;
+                visit(model, &state, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_params_validation() {
+        let vol = RebonatoVolatility::flat(0.2).unwrap();
+        let corr = RebonatoCorrelation::new(0.1, 0.5).unwrap();
+        assert!(LmmParams::new(vec![1.0], vec![], 1.0, 0.0, vol, corr).is_err());
+        assert!(LmmParams::new(vec![1.0, 1.0], vec![0.03], 1.0, 0.0, vol, corr).is_err());
+        assert!(LmmParams::new(vec![1.0, 2.0], vec![0.03, 0.03], 1.0, 0.0, vol, corr).is_err());
+        assert!(matches!(
+            LmmParams::new(vec![1.0, 2.0], vec![-0.01], 1.0, 0.0, vol, corr),
+            Err(LmmError::InvalidForward { index: 0, .. })
+        ));
+        assert!(LmmParams::new(vec![1.0, 2.0], vec![-0.01], 1.0, 0.02, vol, corr).is_ok());
+        assert!(LmmParams::new(vec![1.0, 2.0], vec![0.03], 1.0, -0.01, vol, corr).is_err());
+        assert!(RebonatoVolatility::new(0.1, 0.1, -0.5, 0.1).is_err());
+        assert!(RebonatoVolatility::new(-0.2, 0.1, 0.5, 0.1).is_err());
+        assert!(RebonatoCorrelation::new(0.0, 0.5).is_err());
+        assert!(RebonatoCorrelation::new(0.1, 1.0).is_err());
+        assert!(SabrLmm::new(1.5, 0.3, 0.0).is_err());
+        assert!(SabrLmm::new(0.5, 0.3, -1.0).is_err());
+
+        let p = params(0.0);
+        assert!(p.clone().with_scaling(vec![1.0; 4]).is_err());
+        assert!(p
+            .clone()
+            .with_scaling(vec![1.0, 1.0, 0.0, 1.0, 1.0])
+            .is_err());
+        assert!(p.caplet(5, 0.03, true).is_err());
+        assert!(p.caplet(0, -0.01, true).is_err());
+        assert!(p.swaption(2, 2, 0.03, true).is_err());
+        assert!(p.swaption(0, 6, 0.03, true).is_err());
+    }
+
+    #[test]
+    fn test_curve_and_swap_rate() {
+        let p = params(0.0);
+        for k in 0..=5 {
+            let expected = (-0.03 * (k as f64 + 1.0)).exp();
+            assert!((p.discount_factor(k) - expected).abs() < 1e-14);
+        }
+        // Flat continuous rate gives flat annual forwards and swap rates
+        let forward = 0.03_f64.exp() - 1.0;
+        assert!(p.forwards().iter().all(|f| (f - forward).abs() < 1e-12));
+        assert!((p.swap_rate(1, 5).unwrap() - forward).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_caplet_volatility_is_rms() {
+        let flat = params(0.0).with_volatility(RebonatoVolatility::flat(0.2).unwrap());
+        for i in 0..5 {
+            assert!((flat.caplet_volatility(i, 0.04).unwrap() - 0.2).abs() < 1e-12);
+        }
+
+        let p = params(0.0);
+        let expiry = p.tenor()[2];
+        let v = p.volatility();
+        // Closed-form integral of σ(τ)² with σ = (a + bτ)e^{-cτ} + d over [0, T]
+        let n = 20_000;
+        let h = expiry / n as f64;
+        let integral: f64 = (0..n)
+            .map(|m| v.value(expiry - (m as f64 + 0.5) * h).powi(2) * h)
+            .sum();
+        let rms = (integral / expiry).sqrt();
+        assert!((p.caplet_volatility(2, 0.03).unwrap() - rms).abs() < 1e-8);
+
+        // Caplet-floorlet parity
+        let k = 0.035;
+        let parity = p.accrual(2) * p.discount_factor(3) * (p.forwards()[2] - k);
+        let diff = p.caplet(2, k, true).unwrap() - p.caplet(2, k, false).unwrap();
+        assert!((diff - parity).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_single_period_swaption_is_caplet() {
+        let p = params(0.01);
+        for i in 0..5 {
+            let k = 0.032;
+            let swaption = p.swaption(i, i + 1, k, true).unwrap();
+            let caplet = p.caplet(i, k, true).unwrap();
+            assert!((swaption - caplet).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_monte_carlo_bonds_and_caplets() {
+        let strike = 0.032;
+        for measure in [LmmMeasure::Spot, LmmMeasure::Terminal] {
+            let model = LiborMarketModel::new(params(0.01), measure).unwrap();
+            let p = model.params().clone();
+            let paths = 10_000;
+            let mut bonds = [0.0; 6];
+            let mut caplets = [0.0; 5];
+            simulate(&model, paths, 4, |model, state, k| {
+                let deflator = model.deflator(state, k);
+                bonds[k] += deflator;
+                if k > 0 {
+                    let i = k - 1;
+                    let payoff = p.accrual(i) * (state.forwards[i] - strike).max(0.0);
+                    caplets[i] += deflator * payoff;
+                }
+            });
+            for (k, bond) in bonds.iter().enumerate() {
+                let mc = bond / paths as f64;
+                let expected = p.discount_factor(k);
+                assert!(
+                    (mc - expected).abs() < 2e-3 * expected,
+                    "{measure:?} P(0, T_{k}): {mc} vs {expected}"
+                );
+            }
+            for (i, caplet) in caplets.iter().enumerate().skip(1) {
+                let mc = caplet / paths as f64;
+                let expected = p.caplet(i, strike, true).unwrap();
+                assert!(
+                    (mc - expected).abs() < 0.04 * expected,
+                    "{measure:?} caplet {i}: {mc} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rebonato_swaption_against_monte_carlo() {
+        let model = LiborMarketModel::new(params(0.0), LmmMeasure::Spot).unwrap();
+        let p = model.params().clone();
+        let (start, end) = (1, 5);
+        let strike = p.swap_rate(start, end).unwrap();
+        let paths = 10_000;
+        let mut sum = 0.0;
+        simulate(&model, paths, 4, |model, state, k| {
+            if k == start {
+                let annuity: f64 = (start..end)
+                    .map(|i| p.accrual(i) * model.zero_coupon_bond(state, i + 1))
+                    .sum();
+                let floating = 1.0 - model.zero_coupon_bond(state, end);
+                sum += model.deflator(state, k) * (floating - strike * annuity).max(0.0);
+            }
+        });
+        let mc = sum / paths as f64;
+        let rebonato = p.swaption(start, end, strike, true).unwrap();
+        assert!(
+            (mc - rebonato).abs() < 0.03 * rebonato,
+            "MC {mc} vs Rebonato {rebonato}"
+        );
+    }
+
+    #[test]
+    fn test_sabr_lmm_last_caplet_matches_hagan() {
+        let smile = SABRParams::new_shifted(0.03, 0.02, 0.4, -0.3, 0.5, 5.0, 0.01).unwrap();
+        let sabr = SabrLmm::from_sabr(&smile);
+        let p = params(0.01)
+            .with_volatility(RebonatoVolatility::flat(0.2).unwrap())
+            .with_sabr(sabr);
+        let model = LiborMarketModel::new(p, LmmMeasure::Terminal).unwrap();
+        assert_eq!(model.brownian_dim(), 6);
+        let p = model.params().clone();
+
+        let strikes = [0.02, 0.03, 0.045];
+        let paths = 20_000;
+        let mut sums = [0.0; 3];
+        simulate(&model, paths, 10, |model, state, k| {
+            if k == 4 {
+                let deflator = model.deflator(state, 5) * p.accrual(4);
+                for (sum, k) in sums.iter_mut().zip(strikes) {
+                    *sum += deflator * (state.forwards[4] - k).max(0.0);
+                }
+            }
+        });
+        for (sum, k) in sums.iter().zip(strikes) {
+            let mc = sum / paths as f64;
+            let hagan = p.caplet(4, k, true).unwrap();
+            assert!(
+                (mc - hagan).abs() < 0.04 * hagan,
+                "strike {k}: MC {mc} vs Hagan {hagan}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_correlation_and_model_properties() {
+        let corr = RebonatoCorrelation::new(0.2, 0.4).unwrap();
+        assert_eq!(corr.value(3.0, 3.0), 1.0);
+        assert!(corr.value(1.0, 2.0) > corr.value(1.0, 5.0));
+        assert!(corr.value(1.0, 100.0) > 0.4);
+
+        let model = LiborMarketModel::new(params(0.0), LmmMeasure::Terminal).unwrap();
+        assert_eq!(model.brownian_dim(), 5);
+        assert_eq!(model.measure(), LmmMeasure::Terminal);
+        let state = model.initial_state();
+        assert_eq!(state.volatility, 1.0);
+        assert!(
+            (model.zero_coupon_bond(&state, 3) * model.params().discount_factor(0)
+                - model.params().discount_factor(3))
+            .abs()
+                < 1e-14
+        );
+
+        // Perfectly correlated forwards and volatility cannot be factorised
+        let sabr = SabrLmm::new(0.5, 0.3, 0.999_999).unwrap();
+        let p = params(0.0)
+            .with_correlation(RebonatoCorrelation::new(0.01, 0.0).unwrap())
+            .with_sabr(sabr);
+        assert!(matches!(
+            LiborMarketModel::new(p, LmmMeasure::Spot),
+            Err(LmmError::Correlation(CorrelationError::NotPositiveDefinite))
+        ));
+    }
+}
@@ -3,6 +3,9 @@
 //! This module provides stochastic models for interest rate processes:
 //! - [`HullWhiteModel`]: Hull-White one-factor model for short rate dynamics
 //! - [`CIRModel`]: Cox-Ingersoll-Ross model with mean reversion (future implementation)
+//! - [`G2PlusPlusModel`]: Two-factor Gaussian G2++ model with correlated factors
+//! - [`LiborMarketModel`]: Displaced-diffusion LIBOR market model with an
+//!   optional SABR volatility layer
 //!
 //! # Feature Flag
 //!
@@ -23,10 +26,35 @@
 //! ```text
 //! dr(t) = a * (b - r(t)) * dt + sigma * sqrt(r(t)) * dW(t)
 //! ```
+//!
+//! ## G2++
+//!
+//! The G2++ model adds two correlated Ornstein-Uhlenbeck factors to a
+//! curve-fitting shift:
+//! ```text
+//! r(t) = x(t) + y(t) + φ(t)
+//! dx(t) = -a * x(t) * dt + sigma * dW1(t)
+//! dy(t) = -b * y(t) * dt + eta * dW2(t),   dW1 * dW2 = rho * dt
+//! ```
+//!
+//! ## LIBOR Market Model
+//!
+//! The LMM evolves the displaced forwards `X_i = F_i + δ` of a tenor
+//! structure with Rebonato volatility and correlation:
+//! ```text
+//! dX_i(t) = mu_i(t) * dt + sigma_i(t) * X_i(t) * dW_i(t),   dW_i * dW_j = rho_ij * dt
+//! ```
 
 pub mod cir;
+pub mod g2pp;
 pub mod hull_white;
+pub mod lmm;
 
 // Re-export main types
 pub use cir::{CIRModel, CIRParams};
+pub use g2pp::{G2PlusPlusError, G2PlusPlusModel, G2PlusPlusParams};
 pub use hull_white::{HullWhiteModel, HullWhiteParams, ThetaFunction};
+pub use lmm::{
+    LiborMarketModel, LmmError, LmmMeasure, LmmParams, LmmState, RebonatoCorrelation,
+    RebonatoVolatility, SabrLmm,
+};
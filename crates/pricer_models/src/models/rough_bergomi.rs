@@ -0,0 +1,547 @@
+//! ラフ・ベルゴミ (rough Bergomi) モデル実装
+//!
+//! Bayer, Friz, Gatheral (2016) のモデル:
+//! ```text
+//! dS/S = (r - q) dt + √V dZ,   dZ = ρ dW + √(1 - ρ²) dW⊥
+//! V_t = ξ₀ exp(η √(2H) Y_t - ½ η² t^{2H})
+//! Y_t = ∫₀ᵗ (t - s)^{H-½} dW_s
+//! ```
+//! ここで:
+//! - ξ₀ = フォワード分散（フラット）
+//! - η = ボラティリティのボラティリティ
+//! - H = ハースト指数（0 < H ≤ ½、H < ½ でラフ）
+//!
+//! 分散は非マルコフ過程のため [`StochasticModel`](super::StochasticModel)
+//! の1ステップ更新には載らず、パス全体をまとめて生成する。
+//! `H = ½` かつ `η = 0` でブラック・ショールズに一致する。
+//!
+//! ## ハイブリッド・スキーム
+//!
+//! Volterra過程 Y は Bennedsen, Lunde, Pakkanen (2017) のハイブリッド・
+//! スキーム（κ = 1）で離散化する。直近1区間のカーネルは
+//! `Ŵ_i = ∫_{t_i}^{t_{i+1}} (t_{i+1} - s)^α dW_s`（α = H - ½）として
+//! ブラウン増分 ΔW_i と同時正規で厳密にサンプリングし、それ以前の区間は
+//! 最適評価点 b_k でのカーネル値 `(b_k Δ)^α` による和で近似する:
+//! ```text
+//! Y_i ≈ Ŵ_{i-1} + Σ_{k=2}^{i} (b_k Δ)^α ΔW_{i-k}
+//! b_k = ((k^{α+1} - (k-1)^{α+1}) / (α+1))^{1/α}
+//! ```
+//! 1パスあたりの計算量は O(n²)。
+//!
+//! ## 乱数レイアウト
+//!
+//! 1ステップあたり3個の標準正規乱数 `[ΔW, Ŵ, W⊥]` を用いる:
+//! `normals[(path * n_steps + step) * 3 + factor]`。
+//!
+//! ## 使用例
+//!
+//! ```
+//! use pricer_models::models::rough_bergomi::{RoughBergomiModel, RoughBergomiParams};
+//!
+//! // S0=100, r=3%, q=0, ξ₀=0.04, η=1.9, ρ=-0.9, H=0.07
+//! let params = RoughBergomiParams::new(100.0, 0.03, 0.0, 0.04, 1.9, -0.9, 0.07).unwrap();
+//! let model = RoughBergomiModel::new(params, 0.5, 50).unwrap();
+//!
+//! let normals = vec![0.0; model.normals_per_path()];
+//! let path = model.simulate_path(&normals).unwrap();
+//! assert_eq!(path.spot.len(), 51);
+//! assert!(path.variance.iter().all(|&v| v > 0.0));
+//! ```
+
+use thiserror::Error;
+
+/// 1ステップあたりの正規乱数の個数 `[ΔW, Ŵ, W⊥]`
+pub const NORMALS_PER_STEP: usize = 3;
+
+/// ラフ・ベルゴミモデルエラー型
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::rough_bergomi::RoughBergomiError;
+///
+/// let err = RoughBergomiError::InvalidHurst(0.7);
+/// assert!(format!("{}", err).contains("0.7"));
+/// ```
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum RoughBergomiError {
+    /// 無効なスポット価格（正でなければならない）
+    #[error("無効なスポット価格: S0 = {0} (正の値が必要)")]
+    InvalidSpot(f64),
+
+    /// 無効なフォワード分散（正でなければならない）
+    #[error("無効なフォワード分散: xi0 = {0} (正の値が必要)")]
+    InvalidForwardVariance(f64),
+
+    /// 無効なボラティリティのボラティリティ（非負でなければならない）
+    #[error("無効なvol of vol: eta = {0} (非負の値が必要)")]
+    InvalidVolOfVol(f64),
+
+    /// 無効な相関（[-1, 1]でなければならない）
+    #[error("無効な相関: rho = {0} ([-1, 1]の範囲が必要)")]
+    InvalidCorrelation(f64),
+
+    /// 無効なハースト指数（(0, ½]でなければならない）
+    #[error("無効なハースト指数: H = {0} ((0, 0.5]の範囲が必要)")]
+    InvalidHurst(f64),
+
+    /// 無効な満期または時間ステップ数
+    #[error("無効な時間グリッド: 満期 = {expiry}, ステップ数 = {n_steps}")]
+    InvalidGrid {
+        /// 満期（年）
+        expiry: f64,
+        /// 時間ステップ数
+        n_steps: usize,
+    },
+
+    /// 乱数の個数がパスの整数倍でない、または0本
+    #[error("乱数の個数が不正: {len} (1パスあたり {per_path} 個の整数倍が必要)")]
+    InvalidNormals {
+        /// 与えられた乱数の個数
+        len: usize,
+        /// 1パスあたりの乱数の個数
+        per_path: usize,
+    },
+}
+
+/// ラフ・ベルゴミモデルパラメータ
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::rough_bergomi::RoughBergomiParams;
+///
+/// assert!(RoughBergomiParams::new(100.0, 0.03, 0.0, 0.04, 1.9, -0.9, 0.07).is_ok());
+///
+/// // H > ½ は無効
+/// assert!(RoughBergomiParams::new(100.0, 0.03, 0.0, 0.04, 1.9, -0.9, 0.7).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoughBergomiParams {
+    /// スポット価格 (S0)
+    pub spot: f64,
+    /// リスクフリーレート
+    pub rate: f64,
+    /// 配当利回り
+    pub dividend: f64,
+    /// フォワード分散 (xi0)
+    pub xi0: f64,
+    /// ボラティリティのボラティリティ (eta)
+    pub eta: f64,
+    /// スポットと分散の相関 (rho)
+    pub rho: f64,
+    /// ハースト指数 (H)
+    pub hurst: f64,
+}
+
+impl RoughBergomiParams {
+    /// 新しいラフ・ベルゴミパラメータを作成（検証付き）
+    ///
+    /// # 引数
+    ///
+    /// * `spot` - スポット価格（正）
+    /// * `rate` - リスクフリーレート
+    /// * `dividend` - 配当利回り
+    /// * `xi0` - フォワード分散（正）
+    /// * `eta` - ボラティリティのボラティリティ（非負）
+    /// * `rho` - 相関（[-1, 1]）
+    /// * `hurst` - ハースト指数（(0, ½]）
+    pub fn new(
+        spot: f64,
+        rate: f64,
+        dividend: f64,
+        xi0: f64,
+        eta: f64,
+        rho: f64,
+        hurst: f64,
+    ) -> Result<Self, RoughBergomiError> {
+        let params = Self {
+            spot,
+            rate,
+            dividend,
+            xi0,
+            eta,
+            rho,
+            hurst,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// パラメータを検証
+    pub fn validate(&self) -> Result<(), RoughBergomiError> {
+        if !(self.spot > 0.0 && self.spot.is_finite()) {
+            return Err(RoughBergomiError::InvalidSpot(self.spot));
+        }
+        if !(self.xi0 > 0.0 && self.xi0.is_finite()) {
+            return Err(RoughBergomiError::InvalidForwardVariance(self.xi0));
+        }
+        if !(self.eta >= 0.0 && self.eta.is_finite()) {
+            return Err(RoughBergomiError::InvalidVolOfVol(self.eta));
+        }
+        if self.rho.is_nan() || self.rho.abs() > 1.0 {
+            return Err(RoughBergomiError::InvalidCorrelation(self.rho));
+        }
+        if !(self.hurst > 0.0 && self.hurst <= 0.5) {
+            return Err(RoughBergomiError::InvalidHurst(self.hurst));
+        }
+        Ok(())
+    }
+
+    /// フォワード価格 F(T) = S0·exp((r - q)T)
+    #[inline]
+    pub fn forward(&self, expiry: f64) -> f64 {
+        self.spot * ((self.rate - self.dividend) * expiry).exp()
+    }
+}
+
+/// シミュレーションされた1本のパス（時点 t_0, ..., t_n）
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoughBergomiPath {
+    /// スポット価格
+    pub spot: Vec<f64>,
+    /// 瞬間分散 V
+    pub variance: Vec<f64>,
+}
+
+/// ハイブリッド・スキームによるラフ・ベルゴミのパス生成器
+///
+/// 満期までの一様グリッド上でカーネル重みと (ΔW, Ŵ) の共分散を
+/// 事前計算する。
+#[derive(Clone, Debug)]
+pub struct RoughBergomiModel {
+    /// モデルパラメータ
+    params: RoughBergomiParams,
+    /// 満期（年）
+    expiry: f64,
+    /// 時間ステップ数
+    n_steps: usize,
+    /// 時間刻み Δ
+    dt: f64,
+    /// ラグ k ≥ 2 のカーネル重み (b_k Δ)^α（添字 k）
+    kernel: Vec<f64>,
+    /// Ŵ の ΔW への回帰係数 Cov(ΔW, Ŵ) / Var(ΔW)
+    hat_loading: f64,
+    /// Ŵ の残差標準偏差
+    hat_residual: f64,
+}
+
+impl RoughBergomiModel {
+    /// 満期 `expiry` を `n_steps` ステップに分割したパス生成器を作成
+    ///
+    /// # エラー
+    ///
+    /// パラメータが不正な場合、または満期が正でない・ステップ数が0の場合
+    pub fn new(
+        params: RoughBergomiParams,
+        expiry: f64,
+        n_steps: usize,
+    ) -> Result<Self, RoughBergomiError> {
+        params.validate()?;
+        if !(expiry > 0.0 && expiry.is_finite()) || n_steps == 0 {
+            return Err(RoughBergomiError::InvalidGrid { expiry, n_steps });
+        }
+        let dt = expiry / n_steps as f64;
+        let alpha = params.hurst - 0.5;
+
+        let kernel = (0..=n_steps)
+            .map(|k| {
+                if k < 2 || alpha.abs() < 1e-12 {
+                    1.0
+                } else {
+                    let k = k as f64;
+                    let b = ((k.powf(alpha + 1.0) - (k - 1.0).powf(alpha + 1.0)) / (alpha + 1.0))
+                        .powf(1.0 / alpha);
+                    (b * dt).powf(alpha)
+                }
+            })
+            .collect();
+
+        // Var(ΔW) = Δ, Cov(ΔW, Ŵ) = Δ^{α+1}/(α+1), Var(Ŵ) = Δ^{2α+1}/(2α+1)
+        let covariance = dt.powf(alpha + 1.0) / (alpha + 1.0);
+        let hat_variance = dt.powf(2.0 * alpha + 1.0) / (2.0 * alpha + 1.0);
+        let hat_loading = covariance / dt;
+        let hat_residual = (hat_variance - hat_loading * covariance).max(0.0).sqrt();
+
+        Ok(Self {
+            params,
+            expiry,
+            n_steps,
+            dt,
+            kernel,
+            hat_loading,
+            hat_residual,
+        })
+    }
+
+    /// モデルパラメータ
+    #[inline]
+    pub fn params(&self) -> &RoughBergomiParams {
+        &self.params
+    }
+
+    /// 満期（年）
+    #[inline]
+    pub fn expiry(&self) -> f64 {
+        self.expiry
+    }
+
+    /// 時間ステップ数
+    #[inline]
+    pub fn n_steps(&self) -> usize {
+        self.n_steps
+    }
+
+    /// 1パスあたりの正規乱数の個数
+    #[inline]
+    pub fn normals_per_path(&self) -> usize {
+        NORMALS_PER_STEP * self.n_steps
+    }
+
+    /// 1本のパスを生成
+    ///
+    /// # エラー
+    ///
+    /// `z` の長さが [`normals_per_path`](Self::normals_per_path) と
+    /// 異なる場合
+    pub fn simulate_path(&self, z: &[f64]) -> Result<RoughBergomiPath, RoughBergomiError> {
+        self.check_normals(z.len(), false)?;
+        let mut increments = vec![0.0; self.n_steps];
+        let mut path = RoughBergomiPath {
+            spot: vec![0.0; self.n_steps + 1],
+            variance: vec![0.0; self.n_steps + 1],
+        };
+        self.fill_path(z, 1.0, &mut increments, &mut path);
+        Ok(path)
+    }
+
+    /// ヨーロピアンオプションのモンテカルロ価格（割引後）
+    ///
+    /// `normals` の各行（1パス分）を符号反転した対称パスとあわせて
+    /// 2本のパスとして用いる（対称変量法）。同じ乱数を与えれば価格は
+    /// パラメータについて滑らかになり、キャリブレーションに使える。
+    ///
+    /// # エラー
+    ///
+    /// `normals` の長さが1パス分の正の整数倍でない場合
+    pub fn price_european(
+        &self,
+        strikes: &[f64],
+        is_call: bool,
+        normals: &[f64],
+    ) -> Result<Vec<f64>, RoughBergomiError> {
+        self.check_normals(normals.len(), true)?;
+        let n_paths = normals.len() / self.normals_per_path();
+        let mut increments = vec![0.0; self.n_steps];
+        let mut path = RoughBergomiPath {
+            spot: vec![0.0; self.n_steps + 1],
+            variance: vec![0.0; self.n_steps + 1],
+        };
+
+        let mut sums = vec![0.0; strikes.len()];
+        for z in normals.chunks_exact(self.normals_per_path()) {
+            for sign in [1.0, -1.0] {
+                self.fill_path(z, sign, &mut increments, &mut path);
+                let terminal = path.spot[self.n_steps];
+                for (sum, &strike) in sums.iter_mut().zip(strikes) {
+                    *sum += if is_call {
+                        (terminal - strike).max(0.0)
+                    } else {
+                        (strike - terminal).max(0.0)
+                    };
+                }
+            }
+        }
+
+        let discount = (-self.params.rate * self.expiry).exp() / (2 * n_paths) as f64;
+        Ok(sums.into_iter().map(|sum| sum * discount).collect())
+    }
+
+    /// 乱数の個数を検証（`multiple` なら正の整数倍を許す）
+    fn check_normals(&self, len: usize, multiple: bool) -> Result<(), RoughBergomiError> {
+        let per_path = self.normals_per_path();
+        let valid = if multiple {
+            len > 0 && len % per_path == 0
+        } else {
+            len == per_path
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(RoughBergomiError::InvalidNormals { len, per_path })
+        }
+    }
+
+    /// 乱数 `sign·z` からパスを生成（`increments` は ΔW の作業領域）
+    fn fill_path(&self, z: &[f64], sign: f64, increments: &mut [f64], path: &mut RoughBergomiPath) {
+        let p = &self.params;
+        let sqrt_dt = self.dt.sqrt();
+        let orthogonal = (1.0 - p.rho * p.rho).max(0.0).sqrt();
+        let scale = p.eta * (2.0 * p.hurst).sqrt();
+        let drift = (p.rate - p.dividend) * self.dt;
+
+        path.spot[0] = p.spot;
+        path.variance[0] = p.xi0;
+        let mut log_spot = p.spot.ln();
+        for (i, step) in z.chunks_exact(NORMALS_PER_STEP).enumerate() {
+            let (z_w, z_hat, z_perp) = (sign * step[0], sign * step[1], sign * step[2]);
+            let dw = sqrt_dt * z_w;
+            increments[i] = dw;
+
+            // スポットは区間左端の分散で更新
+            let v = path.variance[i];
+            log_spot +=
+                drift - 0.5 * v * self.dt + v.sqrt() * (p.rho * dw + orthogonal * sqrt_dt * z_perp);
+            path.spot[i + 1] = log_spot.exp();
+
+            // Y_{i+1} = Ŵ_i + Σ_{k=2}^{i+1} (b_k Δ)^α ΔW_{i+1-k}
+            let hat = self.hat_loading * dw + self.hat_residual * z_hat;
+            let tail: f64 = increments[..i]
+                .iter()
+                .rev()
+                .zip(&self.kernel[2..])
+                .map(|(&w, &g)| g * w)
+                .sum();
+            let t = (i + 1) as f64 * self.dt;
+            path.variance[i + 1] =
+                p.xi0 * (scale * (hat + tail) - 0.5 * p.eta * p.eta * t.powf(2.0 * p.hurst)).exp();
+        }
+    }
+
+    /// 離散化された Y_{t_n} の分散（厳密値は t^{2H} / (2H)）
+    #[cfg(test)]
+    fn volterra_variance(&self) -> f64 {
+        let alpha = self.params.hurst - 0.5;
+        let hat = self.dt.powf(2.0 * alpha + 1.0) / (2.0 * alpha + 1.0);
+        hat + self.kernel[2..]
+            .iter()
+            .map(|g| g * g * self.dt)
+            .sum::<f64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::BlackScholes;
+    use crate::test_utils::normals;
+    use approx::assert_relative_eq;
+
+    fn draws(model: &RoughBergomiModel, n_paths: usize, seed: u64) -> Vec<f64> {
+        let mut normal = normals(seed);
+        (0..n_paths * model.normals_per_path())
+            .map(|_| normal())
+            .collect()
+    }
+
+    fn params() -> RoughBergomiParams {
+        RoughBergomiParams::new(100.0, 0.03, 0.01, 0.04, 1.0, -0.9, 0.1).unwrap()
+    }
+
+    // テスト: パラメータ検証
+    #[test]
+    fn test_params_validation() {
+        let p = params();
+        let with = |f: fn(&mut RoughBergomiParams)| {
+            let mut q = p;
+            f(&mut q);
+            q.validate()
+        };
+        assert_eq!(
+            with(|q| q.spot = -1.0),
+            Err(RoughBergomiError::InvalidSpot(-1.0))
+        );
+        assert_eq!(
+            with(|q| q.xi0 = 0.0),
+            Err(RoughBergomiError::InvalidForwardVariance(0.0))
+        );
+        assert_eq!(
+            with(|q| q.eta = -0.1),
+            Err(RoughBergomiError::InvalidVolOfVol(-0.1))
+        );
+        assert_eq!(
+            with(|q| q.rho = 1.5),
+            Err(RoughBergomiError::InvalidCorrelation(1.5))
+        );
+        assert_eq!(
+            with(|q| q.hurst = 0.0),
+            Err(RoughBergomiError::InvalidHurst(0.0))
+        );
+        assert!(matches!(
+            RoughBergomiModel::new(p, 1.0, 0),
+            Err(RoughBergomiError::InvalidGrid { .. })
+        ));
+        let model = RoughBergomiModel::new(p, 1.0, 10).unwrap();
+        assert_eq!(
+            model.simulate_path(&[0.0; 29]),
+            Err(RoughBergomiError::InvalidNormals {
+                len: 29,
+                per_path: 30
+            })
+        );
+        assert!(model.price_european(&[100.0], true, &[]).is_err());
+    }
+
+    // テスト: ハイブリッド・スキームの Volterra 分散
+    #[test]
+    fn test_volterra_variance() {
+        for hurst in [0.05, 0.1, 0.3, 0.5] {
+            let mut p = params();
+            p.hurst = hurst;
+            let model = RoughBergomiModel::new(p, 1.0, 200).unwrap();
+            assert_relative_eq!(
+                model.volterra_variance(),
+                1.0 / (2.0 * hurst),
+                max_relative = 1e-2
+            );
+        }
+    }
+
+    // テスト: η = 0 でブラック・ショールズに一致
+    #[test]
+    fn test_black_scholes_limit() {
+        let mut p = params();
+        p.eta = 0.0;
+        let model = RoughBergomiModel::new(p, 1.0, 8).unwrap();
+        let z = draws(&model, 20_000, 7);
+        let strikes = [90.0, 100.0, 110.0];
+        let calls = model.price_european(&strikes, true, &z).unwrap();
+        let bs = BlackScholes::new(p.spot, p.rate, p.xi0.sqrt())
+            .unwrap()
+            .with_dividend_yield(p.dividend);
+        for (&strike, &call) in strikes.iter().zip(&calls) {
+            assert_relative_eq!(call, bs.price_call(strike, 1.0), max_relative = 1e-2);
+        }
+    }
+
+    // テスト: フォワードと分散の期待値
+    #[test]
+    fn test_forward_and_variance_martingale() {
+        let model = RoughBergomiModel::new(params(), 0.5, 50).unwrap();
+        let z = draws(&model, 4_000, 11);
+        let (mut spot, mut variance) = (0.0, 0.0);
+        for row in z.chunks_exact(model.normals_per_path()) {
+            let path = model.simulate_path(row).unwrap();
+            spot += path.spot[50];
+            variance += path.variance[50];
+        }
+        assert_relative_eq!(spot / 4_000.0, params().forward(0.5), max_relative = 1e-2);
+        assert_relative_eq!(variance / 4_000.0, params().xi0, max_relative = 5e-2);
+    }
+
+    // テスト: 負の相関でダウンサイド・スキュー
+    #[test]
+    fn test_negative_correlation_skew() {
+        let mut flat = params();
+        flat.rho = 0.0;
+        let skewed = RoughBergomiModel::new(params(), 0.1, 20).unwrap();
+        let flat = RoughBergomiModel::new(flat, 0.1, 20).unwrap();
+        let z = draws(&skewed, 4_000, 3);
+        let put_skewed = skewed.price_european(&[90.0], false, &z).unwrap()[0];
+        let put_flat = flat.price_european(&[90.0], false, &z).unwrap()[0];
+        let call_skewed = skewed.price_european(&[110.0], true, &z).unwrap()[0];
+        let call_flat = flat.price_european(&[110.0], true, &z).unwrap()[0];
+        assert!(put_skewed > put_flat);
+        assert!(call_skewed < call_flat);
+    }
+}
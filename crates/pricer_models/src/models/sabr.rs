@@ -19,6 +19,13 @@
 //! 本実装ではHagan et al. (2002) のインプライドボラティリティ近似公式を使用。
 //! ATM近傍では展開公式を使用して数値安定性を確保。
 //!
+//! ## シフトSABR
+//!
+//! `SABRParams::shift` (変位パラメータ) を指定すると F + shift がSABR過程に
+//! 従い、ゼロ近傍・マイナス金利のフォワード/ストライクを扱える。
+//! Hagan公式は低ストライクで負の密度を生じ得るため、裁定のない
+//! 価格が必要な場合は [`crate::models::sabr_pde`] のPDE版を使用する。
+//!
 //! ## 使用例
 //!
 //! ```
@@ -56,9 +63,11 @@ use crate::models::stochastic::{StochasticModel, TwoFactorState};
 /// - `InvalidBeta`: ベータが[0, 1]の範囲外
 /// - `InvalidRho`: 相関が(-1, 1)の範囲外
 /// - `InvalidMaturity`: 満期が正でない
+/// - `InvalidShift`: シフトが負または非有限
 /// - `InvalidStrike`: ストライクが正でない
 /// - `NegativeImpliedVol`: 負のインプライドボラティリティが計算された
 /// - `NumericalInstability`: 数値計算の不安定性
+/// - `InvalidPdeConfig`: 裁定なしSABRのPDE設定が無効
 /// - `NonFinite`: NaNまたは無限大が検出された
 ///
 /// # 例
@@ -71,10 +80,14 @@ use crate::models::stochastic::{StochasticModel, TwoFactorState};
 /// ```
 #[derive(Error, Debug, Clone, PartialEq)]
 pub enum SABRError {
-    /// 無効なフォワード価格（正でなければならない）
-    #[error("無効なフォワード価格: F = {0} (正の値が必要)")]
+    /// 無効なフォワード価格（シフト後に正でなければならない）
+    #[error("無効なフォワード価格: F = {0} (F + shift > 0 が必要)")]
     InvalidForward(f64),
 
+    /// 無効なシフト（非負かつ有限でなければならない）
+    #[error("無効なシフト: shift = {0} (非負の有限値が必要)")]
+    InvalidShift(f64),
+
     /// 無効な初期ボラティリティ（正でなければならない）
     #[error("無効な初期ボラティリティ: alpha = {0} (正の値が必要)")]
     InvalidAlpha(f64),
@@ -104,7 +117,7 @@ pub enum SABRError {
     InvalidEpsilon(f64),
 
     /// 無効なストライク価格（正でなければならない）
-    #[error("無効なストライク価格: K = {0} (正の値が必要)")]
+    #[error("無効なストライク価格: K = {0} (K + shift > 0 が必要)")]
     InvalidStrike(f64),
 
     /// 負のインプライドボラティリティが計算された
@@ -115,6 +128,10 @@ pub enum SABRError {
     #[error("数値的不安定性: {0}")]
     NumericalInstability(String),
 
+    /// 無効なPDEグリッド設定
+    #[error("無効なPDE設定: {0}")]
+    InvalidPdeConfig(String),
+
     /// NaNまたは無限大が検出された
     #[error("{0}でNaNまたはInfinityが検出されました")]
     NonFinite(String),
@@ -128,7 +145,7 @@ pub enum SABRError {
 ///
 /// # フィールド
 ///
-/// * `forward` - フォワード価格 (F > 0)
+/// * `forward` - フォワード価格 (F + shift > 0)
 /// * `alpha` - 初期ボラティリティ (alpha > 0)
 /// * `nu` - ボラティリティのボラティリティ (nu >= 0)
 /// * `rho` - 相関係数 (-1 < rho < 1)
@@ -136,6 +153,14 @@ pub enum SABRError {
 /// * `maturity` - 満期までの時間 (T > 0)
 /// * `atm_threshold` - ATM近傍判定閾値
 /// * `smoothing_epsilon` - smooth approximation用のepsilon
+/// * `shift` - 変位パラメータ (shift >= 0)
+///
+/// # シフトSABR
+///
+/// `shift > 0` の場合、F + shift がSABR過程に従う（Shifted SABR）。
+/// EUR/JPY/CHF等のゼロ近傍・マイナス金利でも F > -shift, K > -shift で
+/// 評価可能となり、インプライドボラティリティはシフト後の
+/// フォワード・ストライクに対するBlackボラティリティとなる。
 ///
 /// # 例
 ///
@@ -163,6 +188,8 @@ pub struct SABRParams<T: Float> {
     pub atm_threshold: T,
     /// smooth approximation epsilon
     pub smoothing_epsilon: T,
+    /// 変位パラメータ (shift): 0 = 通常のSABR
+    pub shift: T,
 }
 
 impl<T: Float> SABRParams<T> {
@@ -212,6 +239,58 @@ impl<T: Float> SABRParams<T> {
             maturity,
             atm_threshold: T::from(1e-4).unwrap_or(T::zero()),
             smoothing_epsilon: T::from(1e-8).unwrap_or(T::zero()),
+            shift: T::zero(),
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// シフト付きSABRパラメータを作成（検証付き）
+    ///
+    /// フォワードがゼロ以下の場合でも `forward + shift > 0` であれば有効。
+    ///
+    /// # 引数
+    ///
+    /// * `forward` - フォワード価格（シフト前）
+    /// * `alpha` - 初期ボラティリティ（正でなければならない）
+    /// * `nu` - vol-of-vol（非負でなければならない）
+    /// * `rho` - 相関係数（-1から1の開区間）
+    /// * `beta` - CEVパラメータ（0から1の範囲）
+    /// * `maturity` - 満期（正でなければならない）
+    /// * `shift` - 変位パラメータ（非負でなければならない）
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use pricer_models::models::sabr::SABRParams;
+    ///
+    /// // -0.5% のフォワードはシフトなしでは無効
+    /// assert!(SABRParams::new(-0.005, 0.02, 0.3, -0.2, 0.5, 1.0).is_err());
+    ///
+    /// // 3%シフトでマイナス金利を扱う
+    /// let params = SABRParams::new_shifted(-0.005, 0.02, 0.3, -0.2, 0.5, 1.0, 0.03);
+    /// assert!(params.is_ok());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_shifted(
+        forward: T,
+        alpha: T,
+        nu: T,
+        rho: T,
+        beta: T,
+        maturity: T,
+        shift: T,
+    ) -> Result<Self, SABRError> {
+        let params = Self {
+            forward,
+            alpha,
+            nu,
+            rho,
+            beta,
+            maturity,
+            atm_threshold: T::from(1e-4).unwrap_or(T::zero()),
+            smoothing_epsilon: T::from(1e-8).unwrap_or(T::zero()),
+            shift,
         };
         params.validate()?;
         Ok(params)
@@ -255,14 +334,51 @@ impl<T: Float> SABRParams<T> {
         Ok(self)
     }
 
+    /// 変位パラメータ（シフト）を設定
+    ///
+    /// # 引数
+    ///
+    /// * `shift` - シフト（非負かつ有限でなければならない）
+    ///
+    /// # 戻り値
+    ///
+    /// 更新されたパラメータ。シフト後のフォワードが正でない場合はエラー
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use pricer_models::models::sabr::SABRParams;
+    ///
+    /// let params = SABRParams::new(0.01_f64, 0.02, 0.3, -0.2, 0.5, 1.0)
+    ///     .unwrap()
+    ///     .with_shift(0.03)
+    ///     .unwrap();
+    /// assert!((params.shifted_forward() - 0.04).abs() < 1e-15);
+    /// ```
+    pub fn with_shift(mut self, shift: T) -> Result<Self, SABRError> {
+        if shift < T::zero() || !shift.is_finite() {
+            return Err(SABRError::InvalidShift(shift.to_f64().unwrap_or(f64::NAN)));
+        }
+        self.shift = shift;
+        self.validate()?;
+        Ok(self)
+    }
+
     /// パラメータを検証
     ///
     /// # 戻り値
     ///
     /// パラメータが有効な場合は`Ok(())`、無効な場合は`Err(SABRError)`
     pub fn validate(&self) -> Result<(), SABRError> {
-        // フォワード価格は正でなければならない
-        if self.forward <= T::zero() {
+        // シフトは非負でなければならない
+        if self.shift < T::zero() || !self.shift.is_finite() {
+            return Err(SABRError::InvalidShift(
+                self.shift.to_f64().unwrap_or(f64::NAN),
+            ));
+        }
+
+        // シフト後のフォワード価格は正でなければならない
+        if self.forward + self.shift <= T::zero() {
             return Err(SABRError::InvalidForward(
                 self.forward.to_f64().unwrap_or(f64::NAN),
             ));
@@ -325,6 +441,16 @@ impl<T: Float> SABRParams<T> {
         self.forward
     }
 
+    /// シフトを取得
+    pub fn shift(&self) -> T {
+        self.shift
+    }
+
+    /// シフト後のフォワード価格 F + shift を取得
+    pub fn shifted_forward(&self) -> T {
+        self.forward + self.shift
+    }
+
     /// 初期ボラティリティを取得
     pub fn alpha(&self) -> T {
         self.alpha
@@ -472,7 +598,7 @@ impl<T: Float> SABRModel<T> {
         }
 
         // General case (0 < beta < 1)
-        let f = self.params.forward;
+        let f = self.params.shifted_forward();
         let beta = self.params.beta;
         let one_minus_beta = one - beta;
 
@@ -509,7 +635,7 @@ impl<T: Float> SABRModel<T> {
     ///
     /// # 引数
     ///
-    /// * `strike` - ストライク価格（正でなければならない）
+    /// * `strike` - ストライク価格（K + shift > 0 でなければならない）
     ///
     /// # 戻り値
     ///
@@ -517,32 +643,33 @@ impl<T: Float> SABRModel<T> {
     ///
     /// # エラー
     ///
-    /// - `InvalidStrike`: ストライクが正でない
+    /// - `InvalidStrike`: シフト後のストライクが正でない
     /// - `NegativeImpliedVol`: 負のボラティリティが計算された
     /// - `NonFinite`: NaN/Infinityが検出された
     pub fn implied_vol(&self, strike: T) -> Result<T, SABRError> {
-        // ストライク検証
-        if strike <= T::zero() {
+        // ストライク検証（シフト後に正でなければならない）
+        let k = strike + self.params.shift;
+        if k <= T::zero() {
             return Err(SABRError::InvalidStrike(
                 strike.to_f64().unwrap_or(f64::NAN),
             ));
         }
 
-        let f = self.params.forward;
-        let log_fk = smooth_log(f / strike, self.params.smoothing_epsilon);
+        let f = self.params.shifted_forward();
+        let log_fk = smooth_log(f / k, self.params.smoothing_epsilon);
 
         // ATM近傍判定
         let vol = if log_fk.abs() < self.params.atm_threshold {
-            self.implied_vol_atm_expansion(strike)
+            self.implied_vol_atm_expansion(k)
         } else if self.params.is_normal() {
             // Normal SABR (beta=0) specialized formula
-            self.implied_vol_normal(strike)
+            self.implied_vol_normal(k)
         } else if self.params.is_lognormal() {
             // Lognormal SABR (beta=1) specialized formula
-            self.implied_vol_lognormal(strike)
+            self.implied_vol_lognormal(k)
         } else {
             // General Hagan formula
-            self.implied_vol_hagan(strike)
+            self.implied_vol_hagan(k)
         };
 
         // 結果の検証
@@ -598,7 +725,7 @@ impl<T: Float> SABRModel<T> {
         }
 
         // General case (0 < beta < 1)
-        let f = self.params.forward;
+        let f = self.params.shifted_forward();
         let beta = self.params.beta;
         let one_minus_beta = one - beta;
 
@@ -631,7 +758,7 @@ impl<T: Float> SABRModel<T> {
     /// 完全なHagan et al. (2002) 公式:
     /// σ_B(K,F) = α / [(FK)^((1-β)/2) * D] × (z/x(z)) × [1 + expansion * T]
     fn implied_vol_hagan(&self, strike: T) -> T {
-        let f = self.params.forward;
+        let f = self.params.shifted_forward();
         let k = strike;
         let alpha = self.params.alpha;
         let beta = self.params.beta;
@@ -744,7 +871,7 @@ impl<T: Float> SABRModel<T> {
     ///
     /// # 引数
     ///
-    /// * `strike` - ストライク価格（正でなければならない）
+    /// * `strike` - ストライク価格（K + shift > 0 でなければならない）
     /// * `floor` - 最小ボラティリティフロア値（通常は小さな正の値、例: 0.0001）
     ///
     /// # 戻り値
@@ -753,7 +880,7 @@ impl<T: Float> SABRModel<T> {
     ///
     /// # エラー
     ///
-    /// - `InvalidStrike`: ストライクが正でない
+    /// - `InvalidStrike`: シフト後のストライクが正でない
     /// - `NonFinite`: NaN/Infinityが検出された
     ///
     /// # 例
@@ -770,28 +897,29 @@ impl<T: Float> SABRModel<T> {
     /// assert!(iv.unwrap() >= 0.0001);
     /// ```
     pub fn implied_vol_with_floor(&self, strike: T, floor: T) -> Result<T, SABRError> {
-        // ストライク検証
-        if strike <= T::zero() {
+        // ストライク検証（シフト後に正でなければならない）
+        let k = strike + self.params.shift;
+        if k <= T::zero() {
             return Err(SABRError::InvalidStrike(
                 strike.to_f64().unwrap_or(f64::NAN),
             ));
         }
 
-        let f = self.params.forward;
-        let log_fk = smooth_log(f / strike, self.params.smoothing_epsilon);
+        let f = self.params.shifted_forward();
+        let log_fk = smooth_log(f / k, self.params.smoothing_epsilon);
 
         // ATM近傍判定
         let vol = if log_fk.abs() < self.params.atm_threshold {
-            self.implied_vol_atm_expansion(strike)
+            self.implied_vol_atm_expansion(k)
         } else if self.params.is_normal() {
             // Normal SABR (beta=0) specialized formula
-            self.implied_vol_normal(strike)
+            self.implied_vol_normal(k)
         } else if self.params.is_lognormal() {
             // Lognormal SABR (beta=1) specialized formula
-            self.implied_vol_lognormal(strike)
+            self.implied_vol_lognormal(k)
         } else {
             // General Hagan formula
-            self.implied_vol_hagan(strike)
+            self.implied_vol_hagan(k)
         };
 
         // 結果の検証
@@ -815,7 +943,7 @@ impl<T: Float> SABRModel<T> {
     ///
     /// Normal SABRでは結果は「Normal volatility」（絶対値単位）となる。
     fn implied_vol_normal(&self, strike: T) -> T {
-        let f = self.params.forward;
+        let f = self.params.shifted_forward();
         let k = strike;
         let alpha = self.params.alpha;
         let nu = self.params.nu;
@@ -885,7 +1013,7 @@ impl<T: Float> SABRModel<T> {
     ///
     /// Lognormal SABRでは結果は「Black volatility」（対数単位）となる。
     fn implied_vol_lognormal(&self, strike: T) -> T {
-        let f = self.params.forward;
+        let f = self.params.shifted_forward();
         let k = strike;
         let alpha = self.params.alpha;
         let nu = self.params.nu;
@@ -1003,6 +1131,8 @@ impl<T: Float> Differentiable for SABRModel<T> {}
 ///
 /// - beta = 0: Normal SABR (F^0 = 1)
 /// - beta = 1: Lognormal SABR (F^1 = F)
+///
+/// シフト付きの場合は F を F + shift に置き換え、F >= -shift で吸収する。
 impl<T: Float + Default> StochasticModel<T> for SABRModel<T> {
     type State = TwoFactorState<T>;
     type Params = SABRParams<T>;
@@ -1014,6 +1144,7 @@ impl<T: Float + Default> StochasticModel<T> for SABRModel<T> {
         let f = state.first;
         let alpha = state.second;
 
+        let shift = params.shift;
         let beta = params.beta;
         let nu = params.nu;
         let rho = params.rho;
@@ -1041,11 +1172,11 @@ impl<T: Float + Default> StochasticModel<T> for SABRModel<T> {
             // beta = 0: F^0 = 1 (Normal SABR)
             one
         } else if params.is_lognormal() {
-            // beta = 1: F^1 = F (Lognormal SABR)
-            smooth_max(f, eps, eps)
+            // beta = 1: (F+s)^1 = F+s (Lognormal SABR)
+            smooth_max(f + shift, eps, eps)
         } else {
-            // 一般の beta: F^beta を smooth_pow で計算
-            smooth_pow(smooth_max(f, eps, eps), beta, eps)
+            // 一般の beta: (F+s)^beta を smooth_pow で計算
+            smooth_pow(smooth_max(f + shift, eps, eps), beta, eps)
         };
 
         // Forward の更新
@@ -1062,13 +1193,13 @@ impl<T: Float + Default> StochasticModel<T> for SABRModel<T> {
         // Requirement 3.3: volatility >= 0
         let alpha_next = smooth_max(alpha_next_raw, eps, eps);
 
-        // Forward の非負制約（beta > 0 の場合のみ必要）
+        // Forward の下限制約 F >= -shift（beta > 0 の場合のみ必要）
         let f_next_safe = if params.is_normal() {
             // Normal SABR では負の forward が許容される場合もあるが、
             // 一般的には absorption を適用
             f_next
         } else {
-            smooth_max(f_next, eps, eps)
+            smooth_max(f_next + shift, eps, eps) - shift
         };
 
         TwoFactorState {
@@ -1393,6 +1524,95 @@ mod tests {
         assert!(matches!(params, Err(SABRError::InvalidEpsilon(_))));
     }
 
+    // ----------------------------------------------------------------
+    // Shifted SABR tests
+    // ----------------------------------------------------------------
+
+    #[test]
+    fn test_sabr_params_default_shift_zero() {
+        let params = SABRParams::new(0.03, 0.04, 0.4, -0.3, 0.5, 1.0).unwrap();
+        assert_eq!(params.shift(), 0.0);
+        assert_eq!(params.shifted_forward(), 0.03);
+    }
+
+    #[test]
+    fn test_sabr_params_new_shifted_negative_forward() {
+        assert!(matches!(
+            SABRParams::new(-0.005, 0.02, 0.4, -0.3, 0.5, 1.0),
+            Err(SABRError::InvalidForward(_))
+        ));
+
+        let params = SABRParams::new_shifted(-0.005, 0.02, 0.4, -0.3, 0.5, 1.0, 0.03).unwrap();
+        assert_eq!(params.forward(), -0.005);
+        assert!((params.shifted_forward() - 0.025).abs() < 1e-15);
+
+        // シフトが足りない場合は無効
+        assert!(matches!(
+            SABRParams::new_shifted(-0.005, 0.02, 0.4, -0.3, 0.5, 1.0, 0.004),
+            Err(SABRError::InvalidForward(_))
+        ));
+    }
+
+    #[test]
+    fn test_sabr_params_with_shift_invalid() {
+        let params = SABRParams::new(0.03, 0.04, 0.4, -0.3, 0.5, 1.0).unwrap();
+        assert!(matches!(
+            params.with_shift(-0.01),
+            Err(SABRError::InvalidShift(_))
+        ));
+        assert!(matches!(
+            params.with_shift(f64::INFINITY),
+            Err(SABRError::InvalidShift(_))
+        ));
+        assert!(params.with_shift(0.02).is_ok());
+    }
+
+    #[test]
+    fn test_sabr_shifted_implied_vol_equals_unshifted_in_shifted_coordinates() {
+        // F = -0.5%, s = 3% は F = 2.5% の通常SABRと同じBlackボラティリティ
+        let shifted = SABRParams::new_shifted(-0.005, 0.02, 0.4, -0.3, 0.5, 2.0, 0.03).unwrap();
+        let plain = SABRParams::new(0.025, 0.02, 0.4, -0.3, 0.5, 2.0).unwrap();
+        let shifted_model = SABRModel::new(shifted).unwrap();
+        let plain_model = SABRModel::new(plain).unwrap();
+
+        for k in [-0.02, -0.01, -0.005, 0.0, 0.01] {
+            let v1 = shifted_model.implied_vol(k).unwrap();
+            let v2 = plain_model.implied_vol(k + 0.03).unwrap();
+            assert!((v1 - v2).abs() < 1e-14, "K={}: {} vs {}", k, v1, v2);
+        }
+        assert!((shifted_model.atm_vol() - plain_model.atm_vol()).abs() < 1e-14);
+
+        // K + s <= 0 は無効
+        assert!(matches!(
+            shifted_model.implied_vol(-0.03),
+            Err(SABRError::InvalidStrike(_))
+        ));
+        assert!(shifted_model.implied_vol_with_floor(-0.031, 1e-4).is_err());
+    }
+
+    #[test]
+    fn test_sabr_shifted_evolve_step_floor_at_minus_shift() {
+        use crate::models::stochastic::StochasticModel;
+        use crate::models::stochastic::TwoFactorState;
+
+        let params = SABRParams::new_shifted(-0.005, 0.02, 0.4, -0.3, 0.5, 1.0, 0.03).unwrap();
+        let state = SABRModel::initial_state(&params);
+        assert_eq!(state.first, -0.005);
+
+        // 大きな負のショックでも F >= -shift に留まる
+        let next = SABRModel::evolve_step(
+            TwoFactorState {
+                first: -0.029,
+                second: 0.5,
+            },
+            1.0,
+            &[-10.0, 0.0],
+            &params,
+        );
+        assert!(next.first >= -0.03 - 1e-12);
+        assert!(next.first < -0.029);
+    }
+
     // ----------------------------------------------------------------
     // SABRParams accessor tests
     // ----------------------------------------------------------------
@@ -0,0 +1,695 @@
+//! 裁定なしSABR (Arbitrage-free SABR) 実装
+//!
+//! Hagan公式は低ストライクで負の確率密度（バタフライ裁定）を生じる。
+//! 本モジュールはHagan, Kumar, Lesniewski, Woodward (2014)
+//! "Arbitrage-Free SABR" の有効フォワード方程式を有限差分で解き、
+//! 非負の密度から価格とインプライドボラティリティを求める:
+//! ```text
+//! ∂Q/∂T = ∂²/∂F² [M(F, T) Q]
+//! M(F, T) = ½ C(F)² (α² + 2ρναy + ν²y²) exp(ρναΓ(F)T)
+//! C(F) = (F + s)^β
+//! y(F) = ∫_f^F dF'/C(F')
+//! Γ(F) = (C(F) - C(f)) / (F - f)
+//! ```
+//! ここで s はシフト（[`SABRParams::shift`]）。
+//!
+//! ## 離散化
+//!
+//! - セル中心の一様グリッド。フォワード f はセル中心に配置
+//! - 両端は吸収境界（ゴーストセルで M·Q = 0）。吸収された確率は
+//!   境界上の点質量 P_L, P_R として保持される
+//! - 時間方向はL安定なTR-BDF2
+//!
+//! この離散化は確率質量とフォワードを丸め誤差の範囲で保存する。
+//!
+//! ## 使用例
+//!
+//! ```
+//! use pricer_models::models::sabr::SABRParams;
+//! use pricer_models::models::sabr_pde::{ArbitrageFreeSABR, SABRPdeConfig};
+//!
+//! // マイナス金利のフォワード (-0.2%) を2%シフトで評価
+//! let params = SABRParams::new_shifted(-0.002, 0.01, 0.4, -0.3, 0.5, 5.0, 0.02).unwrap();
+//! let model = ArbitrageFreeSABR::new(params, SABRPdeConfig::default()).unwrap();
+//!
+//! // 密度は非負、負のストライクでもインプライドボラティリティが得られる
+//! assert!(model.density().iter().all(|&q| q >= 0.0));
+//! assert!(model.implied_vol(-0.01).unwrap() > 0.0);
+//! ```
+
+use pricer_core::math::solvers::{BrentSolver, SolverConfig};
+
+use crate::analytical::black76_price;
+use crate::models::sabr::{SABRError, SABRParams};
+
+/// TR-BDF2の中間段の時間比率 γ = 2 - √2
+const TR_BDF2_GAMMA: f64 = 2.0 - std::f64::consts::SQRT_2;
+
+/// 裁定なしSABRのPDEグリッド設定
+///
+/// # フィールド
+///
+/// * `grid_points` - フォワード方向のセル数（10以上）
+/// * `time_steps` - 時間ステップ数（1以上）
+/// * `num_std` - グリッド幅（正規化ボラティリティの標準偏差の倍数）
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::sabr_pde::SABRPdeConfig;
+///
+/// let config = SABRPdeConfig::default().with_grid_points(400).with_time_steps(50);
+/// assert_eq!(config.grid_points, 400);
+/// assert_eq!(config.time_steps, 50);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SABRPdeConfig {
+    /// フォワード方向のセル数
+    pub grid_points: usize,
+    /// 時間ステップ数
+    pub time_steps: usize,
+    /// グリッド幅（標準偏差の倍数）
+    pub num_std: f64,
+}
+
+impl Default for SABRPdeConfig {
+    fn default() -> Self {
+        Self {
+            grid_points: 500,
+            time_steps: 100,
+            num_std: 5.0,
+        }
+    }
+}
+
+impl SABRPdeConfig {
+    /// セル数を設定
+    pub fn with_grid_points(mut self, grid_points: usize) -> Self {
+        self.grid_points = grid_points;
+        self
+    }
+
+    /// 時間ステップ数を設定
+    pub fn with_time_steps(mut self, time_steps: usize) -> Self {
+        self.time_steps = time_steps;
+        self
+    }
+
+    /// グリッド幅（標準偏差の倍数）を設定
+    pub fn with_num_std(mut self, num_std: f64) -> Self {
+        self.num_std = num_std;
+        self
+    }
+
+    /// 設定を検証
+    ///
+    /// # 戻り値
+    ///
+    /// 設定が有効な場合は`Ok(())`、無効な場合は`Err(SABRError::InvalidPdeConfig)`
+    pub fn validate(&self) -> Result<(), SABRError> {
+        if self.grid_points < 10 {
+            return Err(SABRError::InvalidPdeConfig(format!(
+                "grid_points = {} (10以上が必要)",
+                self.grid_points
+            )));
+        }
+        if self.time_steps == 0 {
+            return Err(SABRError::InvalidPdeConfig(
+                "time_steps = 0 (1以上が必要)".to_string(),
+            ));
+        }
+        if !(self.num_std > 0.0 && self.num_std.is_finite()) {
+            return Err(SABRError::InvalidPdeConfig(format!(
+                "num_std = {} (正の値が必要)",
+                self.num_std
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 裁定なしSABRモデル（PDEによる有効密度）
+///
+/// 構築時に満期 `params.maturity` までPDEを解き、シフト後フォワード空間の
+/// 密度と境界の吸収質量を保持する。価格は割引前（フォワード測度）で、
+/// ストライクはシフト前の値で指定する。
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::sabr::{SABRModel, SABRParams};
+/// use pricer_models::models::sabr_pde::{ArbitrageFreeSABR, SABRPdeConfig};
+///
+/// let params = SABRParams::new(0.03, 0.04, 0.4, -0.3, 0.5, 1.0).unwrap();
+/// let pde = ArbitrageFreeSABR::new(params, SABRPdeConfig::default()).unwrap();
+/// let hagan = SABRModel::new(params).unwrap();
+///
+/// // ATMではHagan公式とほぼ一致する
+/// let diff = pde.implied_vol(0.03).unwrap() - hagan.implied_vol(0.03).unwrap();
+/// assert!(diff.abs() < 1e-3);
+///
+/// // 確率質量は保存される
+/// assert!((pde.total_mass() - 1.0).abs() < 1e-10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArbitrageFreeSABR {
+    /// モデルパラメータ
+    params: SABRParams<f64>,
+    /// PDE設定
+    config: SABRPdeConfig,
+    /// シフト後フォワード空間のグリッド下限
+    lower: f64,
+    /// セル幅
+    h: f64,
+    /// セル中心の密度 Q
+    density: Vec<f64>,
+    /// 下限境界の吸収質量 P_L
+    left_mass: f64,
+    /// 上限境界の吸収質量 P_R
+    right_mass: f64,
+}
+
+impl ArbitrageFreeSABR {
+    /// 満期までPDEを解いて裁定なしSABRモデルを作成
+    ///
+    /// # 引数
+    ///
+    /// * `params` - SABRパラメータ（シフトを含む）
+    /// * `config` - PDEグリッド設定
+    ///
+    /// # エラー
+    ///
+    /// パラメータまたは設定が無効な場合、もしくは密度が有限でない場合はエラー
+    pub fn new(params: SABRParams<f64>, config: SABRPdeConfig) -> Result<Self, SABRError> {
+        params.validate()?;
+        config.validate()?;
+
+        let (lower, h, centres, i0) = build_grid(&params, &config);
+        let coeffs = DiffusionCoefficients::new(&params, &centres);
+
+        let n = centres.len();
+        let mut q = vec![0.0; n];
+        q[i0] = 1.0 / h;
+        let mut p_left = 0.0;
+        let mut p_right = 0.0;
+
+        let dt = params.maturity / config.time_steps as f64;
+        let gamma = TR_BDF2_GAMMA;
+        let bdf_new = 1.0 / (gamma * (2.0 - gamma));
+        let bdf_old = (1.0 - gamma) * (1.0 - gamma) / (gamma * (2.0 - gamma));
+        let bdf_dt = (1.0 - gamma) / (2.0 - gamma) * dt;
+
+        let mut m_old = coeffs.at(0.0);
+        for step in 0..config.time_steps {
+            let t = step as f64 * dt;
+            let m_mid = coeffs.at(t + gamma * dt);
+            let m_new = coeffs.at(t + dt);
+
+            // 台形則で t + γdt まで
+            let half = 0.5 * gamma * dt;
+            let lq = apply_operator(&m_old, &q, h);
+            let rhs: Vec<f64> = q.iter().zip(&lq).map(|(qi, li)| qi + half * li).collect();
+            let (fl_old, fr_old) = boundary_flux(&m_old, &q, h);
+            let q_mid = solve_implicit(&m_mid, &rhs, h, half);
+            let (fl_mid, fr_mid) = boundary_flux(&m_mid, &q_mid, h);
+            let p_left_mid = p_left + half * (fl_old + fl_mid);
+            let p_right_mid = p_right + half * (fr_old + fr_mid);
+
+            // BDF2で t + dt まで
+            let rhs: Vec<f64> = q_mid
+                .iter()
+                .zip(&q)
+                .map(|(qm, qo)| bdf_new * qm - bdf_old * qo)
+                .collect();
+            let q_new = solve_implicit(&m_new, &rhs, h, bdf_dt);
+            let (fl_new, fr_new) = boundary_flux(&m_new, &q_new, h);
+            p_left = bdf_new * p_left_mid - bdf_old * p_left + bdf_dt * fl_new;
+            p_right = bdf_new * p_right_mid - bdf_old * p_right + bdf_dt * fr_new;
+
+            q = q_new;
+            m_old = m_new;
+        }
+
+        if q.iter().any(|x| !x.is_finite()) || !p_left.is_finite() || !p_right.is_finite() {
+            return Err(SABRError::NonFinite(
+                "arbitrage-free SABR density".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            params,
+            config,
+            lower,
+            h,
+            density: q,
+            left_mass: p_left,
+            right_mass: p_right,
+        })
+    }
+
+    /// パラメータへの参照を取得
+    pub fn params(&self) -> &SABRParams<f64> {
+        &self.params
+    }
+
+    /// PDE設定を取得
+    pub fn config(&self) -> &SABRPdeConfig {
+        &self.config
+    }
+
+    /// セル中心のフォワード（シフト前）を取得
+    pub fn grid(&self) -> Vec<f64> {
+        let shift = self.params.shift;
+        (0..self.density.len())
+            .map(|i| self.centre(i) - shift)
+            .collect()
+    }
+
+    /// セル中心の確率密度を取得
+    pub fn density(&self) -> &[f64] {
+        &self.density
+    }
+
+    /// 下限境界（F = -shift 等）に吸収された確率質量
+    pub fn left_mass(&self) -> f64 {
+        self.left_mass
+    }
+
+    /// 上限境界に吸収された確率質量
+    pub fn right_mass(&self) -> f64 {
+        self.right_mass
+    }
+
+    /// 密度と吸収質量の合計（理論値は1）
+    pub fn total_mass(&self) -> f64 {
+        self.density.iter().sum::<f64>() * self.h + self.left_mass + self.right_mass
+    }
+
+    /// 満期における分布のフォワード期待値（シフト前、理論値はフォワード）
+    pub fn mean_forward(&self) -> f64 {
+        let interior: f64 = self
+            .density
+            .iter()
+            .enumerate()
+            .map(|(i, q)| q * self.h * self.centre(i))
+            .sum();
+        interior + self.left_mass * self.lower + self.right_mass * self.upper()
+            - self.params.shift * self.total_mass()
+    }
+
+    /// 割引前のコール価格 E[(F_T - K)^+]
+    ///
+    /// # 引数
+    ///
+    /// * `strike` - ストライク（シフト前）
+    pub fn call_price(&self, strike: f64) -> f64 {
+        let k = strike + self.params.shift;
+        let half_h = 0.5 * self.h;
+        let interior: f64 = self
+            .density
+            .iter()
+            .enumerate()
+            .map(|(i, q)| {
+                let centre = self.centre(i);
+                let (left, right) = (centre - half_h, centre + half_h);
+                if k <= left {
+                    q * self.h * (centre - k)
+                } else if k < right {
+                    0.5 * q * (right - k) * (right - k)
+                } else {
+                    0.0
+                }
+            })
+            .sum();
+        interior
+            + self.left_mass * (self.lower - k).max(0.0)
+            + self.right_mass * (self.upper() - k).max(0.0)
+    }
+
+    /// 割引前のプット価格 E[(K - F_T)^+]
+    ///
+    /// # 引数
+    ///
+    /// * `strike` - ストライク（シフト前）
+    pub fn put_price(&self, strike: f64) -> f64 {
+        let k = strike + self.params.shift;
+        let half_h = 0.5 * self.h;
+        let interior: f64 = self
+            .density
+            .iter()
+            .enumerate()
+            .map(|(i, q)| {
+                let centre = self.centre(i);
+                let (left, right) = (centre - half_h, centre + half_h);
+                if k >= right {
+                    q * self.h * (k - centre)
+                } else if k > left {
+                    0.5 * q * (k - left) * (k - left)
+                } else {
+                    0.0
+                }
+            })
+            .sum();
+        interior
+            + self.left_mass * (k - self.lower).max(0.0)
+            + self.right_mass * (k - self.upper()).max(0.0)
+    }
+
+    /// シフト付きBlackインプライドボラティリティ
+    ///
+    /// OTMオプション価格から F + shift, K + shift に対するBlack
+    /// ボラティリティを逆算する（[`SABRModel::implied_vol`]と同じ定義）。
+    ///
+    /// [`SABRModel::implied_vol`]: crate::models::sabr::SABRModel::implied_vol
+    ///
+    /// # 引数
+    ///
+    /// * `strike` - ストライク（シフト前、K + shift > 0 でなければならない）
+    ///
+    /// # エラー
+    ///
+    /// - `InvalidStrike`: シフト後のストライクが正でない
+    /// - `NumericalInstability`: 価格が無裁定範囲外で逆算できない
+    pub fn implied_vol(&self, strike: f64) -> Result<f64, SABRError> {
+        let f = self.params.shifted_forward();
+        let k = strike + self.params.shift;
+        if k <= 0.0 || !k.is_finite() {
+            return Err(SABRError::InvalidStrike(strike));
+        }
+
+        let is_call = k >= f;
+        let price = if is_call {
+            self.call_price(strike)
+        } else {
+            self.put_price(strike)
+        };
+        let t = self.params.maturity;
+
+        // フォワードで正規化した価格で解く
+        let target = price / f;
+        let objective = |vol: f64| black76_price(f, k, t, vol, is_call) / f - target;
+        let (lo, hi) = (1e-8, 10.0);
+        if objective(lo) >= 0.0 || objective(hi) <= 0.0 {
+            return Err(SABRError::NumericalInstability(format!(
+                "ストライク {} の価格 {:e} からインプライドボラティリティを逆算できません",
+                strike, price
+            )));
+        }
+
+        BrentSolver::new(SolverConfig::new(1e-15, 200))
+            .find_root(objective, lo, hi)
+            .map_err(|e| SABRError::NumericalInstability(e.to_string()))
+    }
+
+    /// セル中心（シフト後）
+    fn centre(&self, i: usize) -> f64 {
+        self.lower + (i as f64 + 0.5) * self.h
+    }
+
+    /// グリッド上限（シフト後）
+    fn upper(&self) -> f64 {
+        self.lower + self.density.len() as f64 * self.h
+    }
+}
+
+/// 拡散係数 M(F, t) = M₀(F) exp(ρναΓ(F)t) の時間に依存しない部分
+struct DiffusionCoefficients {
+    base: Vec<f64>,
+    growth: Vec<f64>,
+}
+
+impl DiffusionCoefficients {
+    fn new(params: &SABRParams<f64>, centres: &[f64]) -> Self {
+        let (alpha, beta, rho, nu) = (params.alpha, params.beta, params.rho, params.nu);
+        let f = params.shifted_forward();
+        let c_f = f.powf(beta);
+
+        let mut base = Vec::with_capacity(centres.len());
+        let mut growth = Vec::with_capacity(centres.len());
+        for &x in centres {
+            let c = x.powf(beta);
+            let y = local_time(x, f, beta);
+            let gamma = if (x - f).abs() > 1e-12 * f {
+                (c - c_f) / (x - f)
+            } else {
+                beta * f.powf(beta - 1.0)
+            };
+            base.push(0.5 * c * c * (alpha * alpha + 2.0 * rho * nu * alpha * y + nu * nu * y * y));
+            growth.push(rho * nu * alpha * gamma);
+        }
+        Self { base, growth }
+    }
+
+    fn at(&self, t: f64) -> Vec<f64> {
+        self.base
+            .iter()
+            .zip(&self.growth)
+            .map(|(m, g)| m * (g * t).exp())
+            .collect()
+    }
+}
+
+/// y(F) = ∫_f^F dF'/F'^β（シフト後の変数）
+fn local_time(x: f64, f: f64, beta: f64) -> f64 {
+    if beta == 1.0 {
+        (x / f).ln()
+    } else {
+        (x.powf(1.0 - beta) - f.powf(1.0 - beta)) / (1.0 - beta)
+    }
+}
+
+/// y から F への逆写像（シフト後）。β < 1 で F + s < 0 となる場合は0
+fn forward_from_local_time(y: f64, f: f64, beta: f64) -> f64 {
+    if beta == 1.0 {
+        f * y.exp()
+    } else if beta == 0.0 {
+        f + y
+    } else {
+        let u = f.powf(1.0 - beta) + (1.0 - beta) * y;
+        u.max(0.0).powf(1.0 / (1.0 - beta))
+    }
+}
+
+/// グリッドを構築し (下限, セル幅, セル中心, フォワードのセル番号) を返す
+///
+/// z = ±num_std·√T を y(z) = (α/ν)(sinh νz + ρ(cosh νz - 1)) で写像して
+/// 両端を決め、フォワードがセル中心に来るようにセル幅を調整する。
+fn build_grid(params: &SABRParams<f64>, config: &SABRPdeConfig) -> (f64, f64, Vec<f64>, usize) {
+    let (alpha, beta, rho, nu) = (params.alpha, params.beta, params.rho, params.nu);
+    let f = params.shifted_forward();
+    let z_max = config.num_std * params.maturity.sqrt();
+    let y_of_z = |z: f64| {
+        if nu > 1e-12 {
+            alpha / nu * ((nu * z).sinh() + rho * ((nu * z).cosh() - 1.0))
+        } else {
+            alpha * z
+        }
+    };
+
+    let lower = forward_from_local_time(y_of_z(-z_max), f, beta);
+    let upper = forward_from_local_time(y_of_z(z_max), f, beta);
+
+    let n = config.grid_points;
+    let h = (upper - lower) / n as f64;
+    let i0 = (((f - lower) / h).floor() as usize).clamp(1, n - 2);
+    let h = (f - lower) / (i0 as f64 + 0.5);
+    let centres = (0..n).map(|i| lower + (i as f64 + 0.5) * h).collect();
+    (lower, h, centres, i0)
+}
+
+/// 吸収境界付きの L(Q)_i = (U_{i+1} - 2U_i + U_{i-1}) / h², U = M·Q
+fn apply_operator(m: &[f64], q: &[f64], h: f64) -> Vec<f64> {
+    let n = q.len();
+    let u: Vec<f64> = m.iter().zip(q).map(|(mi, qi)| mi * qi).collect();
+    let h2 = h * h;
+    (0..n)
+        .map(|i| {
+            // ゴーストセル: U_{-1} = -U_0, U_n = -U_{n-1}
+            let prev = if i == 0 { -u[0] } else { u[i - 1] };
+            let next = if i + 1 == n { -u[n - 1] } else { u[i + 1] };
+            (next - 2.0 * u[i] + prev) / h2
+        })
+        .collect()
+}
+
+/// 両境界への確率フラックス (dP_L/dt, dP_R/dt)
+fn boundary_flux(m: &[f64], q: &[f64], h: f64) -> (f64, f64) {
+    let n = q.len();
+    (2.0 * m[0] * q[0] / h, 2.0 * m[n - 1] * q[n - 1] / h)
+}
+
+/// (I - c·L) Q = rhs をThomas法で解く
+fn solve_implicit(m: &[f64], rhs: &[f64], h: f64, c: f64) -> Vec<f64> {
+    let n = rhs.len();
+    let r = c / (h * h);
+
+    let lower = |i: usize| -r * m[i - 1];
+    let upper = |i: usize| -r * m[i + 1];
+    let diag = |i: usize| {
+        let ghost = if i == 0 || i + 1 == n { 3.0 } else { 2.0 };
+        1.0 + ghost * r * m[i]
+    };
+
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+    c_prime[0] = upper(0) / diag(0);
+    d_prime[0] = rhs[0] / diag(0);
+    for i in 1..n {
+        let denom = diag(i) - lower(i) * c_prime[i - 1];
+        if i + 1 < n {
+            c_prime[i] = upper(i) / denom;
+        }
+        d_prime[i] = (rhs[i] - lower(i) * d_prime[i - 1]) / denom;
+    }
+
+    let mut x = vec![0.0; n];
+    x[n - 1] = d_prime[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = d_prime[i] - c_prime[i] * x[i + 1];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::sabr::SABRModel;
+
+    fn typical() -> SABRParams<f64> {
+        SABRParams::new(0.03, 0.04, 0.4, -0.3, 0.5, 1.0).unwrap()
+    }
+
+    /// Hagan公式が低ストライクで負の密度を生む長期パラメータ
+    fn negative_rate() -> SABRParams<f64> {
+        SABRParams::new_shifted(-0.002, 0.01, 0.5, -0.3, 0.5, 10.0, 0.02).unwrap()
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(SABRPdeConfig::default().validate().is_ok());
+        let bad = SABRPdeConfig::default().with_grid_points(5);
+        assert!(matches!(
+            ArbitrageFreeSABR::new(typical(), bad),
+            Err(SABRError::InvalidPdeConfig(_))
+        ));
+        assert!(SABRPdeConfig::default()
+            .with_time_steps(0)
+            .validate()
+            .is_err());
+        assert!(SABRPdeConfig::default()
+            .with_num_std(f64::NAN)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_density_non_negative_and_mass_conserved() {
+        for params in [typical(), negative_rate()] {
+            let model = ArbitrageFreeSABR::new(params, SABRPdeConfig::default()).unwrap();
+            assert!(model.density().iter().all(|&q| q >= 0.0));
+            assert!(model.left_mass() >= 0.0 && model.right_mass() >= 0.0);
+            assert!((model.total_mass() - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_forward_conserved() {
+        for params in [typical(), negative_rate()] {
+            let model = ArbitrageFreeSABR::new(params, SABRPdeConfig::default()).unwrap();
+            assert!((model.mean_forward() - params.forward).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let model = ArbitrageFreeSABR::new(negative_rate(), SABRPdeConfig::default()).unwrap();
+        for k in [-0.015, -0.005, 0.0, 0.01, 0.05] {
+            let parity = model.call_price(k) - model.put_price(k) - (-0.002 - k);
+            assert!(parity.abs() < 1e-10, "K={}: {}", k, parity);
+        }
+    }
+
+    #[test]
+    fn test_call_prices_convex_in_strike() {
+        let model = ArbitrageFreeSABR::new(negative_rate(), SABRPdeConfig::default()).unwrap();
+        let strikes: Vec<f64> = (0..80).map(|i| -0.0195 + 0.001 * i as f64).collect();
+        let prices: Vec<f64> = strikes.iter().map(|&k| model.call_price(k)).collect();
+        for w in prices.windows(3) {
+            assert!(w[0] - 2.0 * w[1] + w[2] >= -1e-14);
+        }
+    }
+
+    #[test]
+    fn test_hagan_density_negative_where_pde_is_not() {
+        // Hagan公式から作ったコール価格は低ストライクで凸性が崩れる
+        let params = negative_rate();
+        let hagan = SABRModel::new(params).unwrap();
+        let f = params.shifted_forward();
+        let t = params.maturity;
+        let hagan_call =
+            |k: f64| black76_price(f, k + 0.02, t, hagan.implied_vol(k).unwrap(), true);
+        let dk = 1e-4;
+        let min_butterfly = (2..150)
+            .map(|i| -0.02 + 1e-4 * i as f64)
+            .map(|k| hagan_call(k - dk) - 2.0 * hagan_call(k) + hagan_call(k + dk))
+            .fold(f64::INFINITY, f64::min);
+        assert!(min_butterfly < 0.0);
+
+        let model = ArbitrageFreeSABR::new(params, SABRPdeConfig::default()).unwrap();
+        assert!(model.density().iter().all(|&q| q >= 0.0));
+    }
+
+    #[test]
+    fn test_implied_vol_close_to_hagan_near_atm() {
+        let params = typical();
+        let hagan = SABRModel::new(params).unwrap();
+        let config = SABRPdeConfig::default().with_grid_points(800);
+        let model = ArbitrageFreeSABR::new(params, config).unwrap();
+        for k in [0.025, 0.03, 0.035] {
+            let diff = model.implied_vol(k).unwrap() - hagan.implied_vol(k).unwrap();
+            assert!(diff.abs() < 1e-3, "K={}: {}", k, diff);
+        }
+    }
+
+    #[test]
+    fn test_negative_strike_vols() {
+        let model = ArbitrageFreeSABR::new(negative_rate(), SABRPdeConfig::default()).unwrap();
+        for k in [-0.015, -0.01, -0.005, 0.0, 0.005] {
+            let vol = model.implied_vol(k).unwrap();
+            assert!(vol.is_finite() && vol > 0.0);
+        }
+        assert!(matches!(
+            model.implied_vol(-0.02),
+            Err(SABRError::InvalidStrike(_))
+        ));
+    }
+
+    #[test]
+    fn test_lognormal_and_normal_beta() {
+        for beta in [0.0, 1.0] {
+            let params = SABRParams::new(0.03, 0.2, 0.3, -0.2, beta, 1.0).unwrap();
+            let params = if beta == 0.0 {
+                SABRParams {
+                    alpha: 0.006,
+                    ..params
+                }
+            } else {
+                params
+            };
+            let model = ArbitrageFreeSABR::new(params, SABRPdeConfig::default()).unwrap();
+            assert!(model.density().iter().all(|&q| q >= 0.0));
+            assert!((model.total_mass() - 1.0).abs() < 1e-10);
+            assert!((model.mean_forward() - 0.03).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_grid_is_unshifted() {
+        let params = negative_rate();
+        let model = ArbitrageFreeSABR::new(params, SABRPdeConfig::default()).unwrap();
+        let grid = model.grid();
+        assert_eq!(grid.len(), model.density().len());
+        assert!(grid[0] > -0.02);
+        assert!(grid.iter().any(|&x| (x - params.forward).abs() < 1e-12));
+    }
+}
@@ -0,0 +1,825 @@
+//! 確率局所ボラティリティ (SLV) モデル実装
+//!
+//! Hestonの分散過程にレバレッジ関数 L(t, S) を掛けたモデル:
+//! ```text
+//! dS/S = (r - q) dt + L(t, S) √v dW_S
+//! dv = κ(θ - v) dt + η ξ √v dW_v
+//! d⟨W_S, W_v⟩ = ρ dt
+//! ```
+//! ここで η ∈ (0, 1] は混合率 (mixing fraction)。η → 0 で分散は決定的
+//! となり局所ボラティリティモデルに、η = 1 かつ L ≡ 1 で [`HestonModel`]
+//! に一致する。η はフォワード・スマイルの動態（バリアやタッチの価格）を
+//! 調整し、レバレッジはどの η でもバニラを局所ボラティリティに合わせる。
+//!
+//! ## 離散化
+//!
+//! 分散は [`HestonModel`] のQEスキーム（vol of vol は ηξ）で更新する。
+//! スポットはステップ内でレバレッジを固定し、Andersen (2008) と同様に
+//! 分散の増分から相関部分を取り出す:
+//! ```text
+//! ∫√v dW_v ≈ (v_{t+Δ} - v_t - κ(θ - v̄)Δ) / (ηξ),   v̄ = (v_t + v_{t+Δ}) / 2
+//! ln S_{t+Δ} = ln S_t + (r - q)Δ - ½L²v̄Δ + L(ρ ∫√v dW_v + √((1 - ρ²) v̄ Δ) Z)
+//! ```
+//! L ≡ 1 では [`HestonModel::qe_price_step`] と同じ更新になる。
+//!
+//! ## レバレッジのキャリブレーション（粒子法）
+//!
+//! 局所ボラティリティ σ_LV に対し、Gyöngyの定理からバニラが一致する条件は
+//! ```text
+//! L(t, S)² = σ_LV(t, S)² / E[v_t | S_t = S]
+//! ```
+//! [`LeverageCalibrator`] は Guyon, Henry-Labordère (2012) の粒子法で、
+//! 各時点の条件付き期待値を粒子のNadaraya-Watsonカーネル回帰で推定し、
+//! 求めたレバレッジで粒子を次の時点へ進める。
+//!
+//! ## 使用例
+//!
+//! ```
+//! use pricer_models::models::heston::HestonParams;
+//! use pricer_models::models::slv::{LeverageFunction, SlvModel, SlvParams};
+//!
+//! let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.5, -0.7, 0.03, 1.0).unwrap();
+//! let params = SlvParams::new(heston, 0.01, 0.6).unwrap();
+//! let model = SlvModel::new(params, LeverageFunction::flat(1.2).unwrap()).unwrap();
+//!
+//! // 1ステップあたり [Z_S, Z_v] の2個の正規乱数
+//! let path = model.simulate_path(1.0, &[0.1; 2 * 50]).unwrap();
+//! assert_eq!(path.spot.len(), 51);
+//! assert!(path.spot.iter().all(|&s| s > 0.0));
+//! ```
+
+use thiserror::Error;
+
+use super::heston::{HestonError, HestonModel, HestonParams};
+use crate::analytical::distributions::norm_cdf;
+
+/// 1ステップあたりの正規乱数の個数 `[Z_S, Z_v]`
+pub const SLV_NORMALS_PER_STEP: usize = 2;
+
+/// 時刻比較の許容誤差
+const TIME_TOLERANCE: f64 = 1e-12;
+
+/// 条件付き分散期待値の下限（レバレッジの発散を防ぐ）
+const VARIANCE_FLOOR: f64 = 1e-8;
+
+/// カーネル回帰の打ち切り幅（バンド幅の倍数）
+const KERNEL_CUTOFF: f64 = 4.0;
+
+/// SLVモデルエラー型
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::slv::SlvError;
+///
+/// let err = SlvError::InvalidMixing(1.5);
+/// assert!(format!("{}", err).contains("1.5"));
+/// ```
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SlvError {
+    /// 無効な混合率（(0, 1]でなければならない）
+    #[error("無効な混合率: eta = {0} ((0, 1]の範囲が必要)")]
+    InvalidMixing(f64),
+
+    /// 無効な配当利回り（有限でなければならない）
+    #[error("無効な配当利回り: q = {0} (有限の値が必要)")]
+    InvalidDividend(f64),
+
+    /// 無効なレバレッジ関数のグリッドまたは値
+    #[error("無効なレバレッジ関数: {0}")]
+    InvalidLeverage(String),
+
+    /// 無効な局所ボラティリティ（正かつ有限でなければならない）
+    #[error("無効な局所ボラティリティ: sigma({time}, {spot}) = {value}")]
+    InvalidLocalVol {
+        /// 時刻
+        time: f64,
+        /// スポット
+        spot: f64,
+        /// 局所ボラティリティの値
+        value: f64,
+    },
+
+    /// 無効なシミュレーション設定
+    #[error("無効なシミュレーション設定: {0}")]
+    InvalidSimulation(String),
+
+    /// Heston部分のパラメータエラー
+    #[error("Hestonパラメータエラー: {0}")]
+    Heston(#[from] HestonError),
+}
+
+/// レバレッジ関数 L(t, S)
+///
+/// 時刻 `times[k]` ごとのスポット・グリッド上の値を持つ。時間方向は
+/// 区分定数（`times[k] ≤ t < times[k+1]` で `k` 行目）、スポット方向は
+/// 線形補間でグリッド外は端の値で外挿する。
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::slv::LeverageFunction;
+///
+/// let leverage = LeverageFunction::new(
+///     vec![0.0, 0.5],
+///     vec![80.0, 120.0],
+///     vec![1.2, 0.8, 1.0, 1.0],
+/// )
+/// .unwrap();
+/// assert!((leverage.value(0.2, 100.0) - 1.0).abs() < 1e-12);
+/// assert!((leverage.value(0.2, 60.0) - 1.2).abs() < 1e-12);
+/// assert!((leverage.value(0.7, 60.0) - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeverageFunction {
+    /// 時刻グリッド（狭義単調増加、先頭は0）
+    times: Vec<f64>,
+    /// スポット・グリッド（狭義単調増加、正）
+    spots: Vec<f64>,
+    /// 値（`values[k * spots.len() + j]` = L(times[k], spots[j])）
+    values: Vec<f64>,
+}
+
+impl LeverageFunction {
+    /// グリッドと値からレバレッジ関数を作成
+    ///
+    /// # エラー
+    ///
+    /// グリッドが空・非単調、先頭時刻が0でない、スポットが正でない、
+    /// 値の個数が一致しない、または値が正かつ有限でない場合
+    pub fn new(times: Vec<f64>, spots: Vec<f64>, values: Vec<f64>) -> Result<Self, SlvError> {
+        let increasing = |grid: &[f64]| {
+            !grid.is_empty()
+                && grid.iter().all(|x| x.is_finite())
+                && grid.windows(2).all(|w| w[1] > w[0])
+        };
+        if !increasing(&times) || times[0].abs() > TIME_TOLERANCE {
+            return Err(SlvError::InvalidLeverage(
+                "時刻グリッドは0から始まる狭義単調増加列が必要".to_string(),
+            ));
+        }
+        if !increasing(&spots) || spots[0] <= 0.0 {
+            return Err(SlvError::InvalidLeverage(
+                "スポット・グリッドは正の狭義単調増加列が必要".to_string(),
+            ));
+        }
+        if values.len() != times.len() * spots.len() {
+            return Err(SlvError::InvalidLeverage(format!(
+                "値の個数 {} が {} × {} と一致しない",
+                values.len(),
+                times.len(),
+                spots.len()
+            )));
+        }
+        if let Some(&bad) = values.iter().find(|v| !(v.is_finite() && **v > 0.0)) {
+            return Err(SlvError::InvalidLeverage(format!("値 {bad} は正でない")));
+        }
+        Ok(Self {
+            times,
+            spots,
+            values,
+        })
+    }
+
+    /// 定数のレバレッジ関数
+    ///
+    /// # エラー
+    ///
+    /// `value` が正かつ有限でない場合
+    pub fn flat(value: f64) -> Result<Self, SlvError> {
+        Self::new(vec![0.0], vec![1.0], vec![value])
+    }
+
+    /// 時刻グリッド
+    #[inline]
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// スポット・グリッド
+    #[inline]
+    pub fn spots(&self) -> &[f64] {
+        &self.spots
+    }
+
+    /// 時刻 `times[k]` の行
+    #[inline]
+    pub fn row(&self, k: usize) -> &[f64] {
+        let n = self.spots.len();
+        &self.values[k * n..(k + 1) * n]
+    }
+
+    /// L(t, S) を評価
+    pub fn value(&self, time: f64, spot: f64) -> f64 {
+        let k = self
+            .times
+            .partition_point(|&t| t <= time + TIME_TOLERANCE)
+            .max(1)
+            - 1;
+        interpolate(&self.spots, self.row(k), spot)
+    }
+}
+
+/// 線形補間（グリッド外は端の値）
+fn interpolate(grid: &[f64], values: &[f64], x: f64) -> f64 {
+    let j = grid.partition_point(|&g| g <= x);
+    if j == 0 {
+        values[0]
+    } else if j == grid.len() {
+        values[grid.len() - 1]
+    } else {
+        let w = (x - grid[j - 1]) / (grid[j] - grid[j - 1]);
+        values[j - 1] + w * (values[j] - values[j - 1])
+    }
+}
+
+/// SLVモデルパラメータ
+///
+/// # 例
+///
+/// ```
+/// use pricer_models::models::heston::HestonParams;
+/// use pricer_models::models::slv::SlvParams;
+///
+/// let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.5, -0.7, 0.03, 1.0).unwrap();
+/// assert!(SlvParams::new(heston, 0.01, 0.6).is_ok());
+///
+/// // 混合率0は無効（純粋な局所ボラティリティは η → 0 の極限）
+/// assert!(SlvParams::new(heston, 0.01, 0.0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SlvParams {
+    /// Hestonパラメータ（`rate` は国内金利）
+    pub heston: HestonParams<f64>,
+    /// 配当利回り（FXでは外国金利）
+    pub dividend: f64,
+    /// 混合率 (eta)
+    pub mixing: f64,
+}
+
+impl SlvParams {
+    /// 新しいSLVパラメータを作成（検証付き）
+    ///
+    /// # 引数
+    ///
+    /// * `heston` - Hestonパラメータ
+    /// * `dividend` - 配当利回りまたは外国金利
+    /// * `mixing` - 混合率（(0, 1]）
+    pub fn new(heston: HestonParams<f64>, dividend: f64, mixing: f64) -> Result<Self, SlvError> {
+        let params = Self {
+            heston,
+            dividend,
+            mixing,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// パラメータを検証
+    pub fn validate(&self) -> Result<(), SlvError> {
+        self.heston.validate()?;
+        if !self.dividend.is_finite() {
+            return Err(SlvError::InvalidDividend(self.dividend));
+        }
+        if !(self.mixing > 0.0 && self.mixing <= 1.0) {
+            return Err(SlvError::InvalidMixing(self.mixing));
+        }
+        Ok(())
+    }
+
+    /// 分散過程の実効的なvol of vol ηξ
+    #[inline]
+    pub fn effective_xi(&self) -> f64 {
+        self.mixing * self.heston.xi
+    }
+
+    /// フォワード価格 F(T) = S0·exp((r - q)T)
+    #[inline]
+    pub fn forward(&self, expiry: f64) -> f64 {
+        self.heston.spot * ((self.heston.rate - self.dividend) * expiry).exp()
+    }
+}
+
+/// シミュレーションされた1本のパス（時点 t_0, ..., t_n）
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlvPath {
+    /// スポット価格
+    pub spot: Vec<f64>,
+    /// 分散 v
+    pub variance: Vec<f64>,
+}
+
+/// 確率局所ボラティリティ・モデル
+#[derive(Clone, Debug)]
+pub struct SlvModel {
+    /// モデルパラメータ
+    params: SlvParams,
+    /// レバレッジ関数
+    leverage: LeverageFunction,
+    /// vol of vol を ηξ とした分散過程
+    variance: HestonModel<f64>,
+}
+
+impl SlvModel {
+    /// パラメータとレバレッジ関数からモデルを作成
+    ///
+    /// # エラー
+    ///
+    /// パラメータが不正な場合
+    pub fn new(params: SlvParams, leverage: LeverageFunction) -> Result<Self, SlvError> {
+        params.validate()?;
+        let mut heston = params.heston;
+        heston.xi = params.effective_xi();
+        Ok(Self {
+            params,
+            leverage,
+            variance: HestonModel::new(heston)?,
+        })
+    }
+
+    /// モデルパラメータ
+    #[inline]
+    pub fn params(&self) -> &SlvParams {
+        &self.params
+    }
+
+    /// レバレッジ関数
+    #[inline]
+    pub fn leverage(&self) -> &LeverageFunction {
+        &self.leverage
+    }
+
+    /// 瞬間ボラティリティ L(t, S)·√v
+    #[inline]
+    pub fn local_volatility(&self, time: f64, spot: f64, variance: f64) -> f64 {
+        self.leverage.value(time, spot) * variance.max(0.0).sqrt()
+    }
+
+    /// 1ステップの状態遷移
+    ///
+    /// # 引数
+    ///
+    /// * `time` - ステップ開始時刻
+    /// * `dt` - タイムステップ
+    /// * `spot`, `variance` - 現在の状態
+    /// * `z_spot`, `z_variance` - 独立な標準正規乱数
+    ///
+    /// # 戻り値
+    ///
+    /// (S_{t+Δ}, v_{t+Δ})
+    pub fn evolve_step(
+        &self,
+        time: f64,
+        dt: f64,
+        spot: f64,
+        variance: f64,
+        z_spot: f64,
+        z_variance: f64,
+    ) -> (f64, f64) {
+        let leverage = self.leverage.value(time, spot);
+        self.step_with_leverage(leverage, dt, spot, variance, z_spot, z_variance)
+    }
+
+    /// レバレッジ `leverage` を固定した1ステップ
+    fn step_with_leverage(
+        &self,
+        leverage: f64,
+        dt: f64,
+        spot: f64,
+        variance: f64,
+        z_spot: f64,
+        z_variance: f64,
+    ) -> (f64, f64) {
+        let h = &self.params.heston;
+        let v_next = self
+            .variance
+            .qe_variance_step(variance, dt, norm_cdf(z_variance));
+        let v_bar = 0.5 * (variance + v_next);
+        let integrated =
+            (v_next - variance - h.kappa * (h.theta - v_bar) * dt) / self.params.effective_xi();
+        let orthogonal = ((1.0 - h.rho * h.rho).max(0.0) * v_bar * dt).sqrt();
+        let log_return = (h.rate - self.params.dividend) * dt
+            - 0.5 * leverage * leverage * v_bar * dt
+            + leverage * (h.rho * integrated + orthogonal * z_spot);
+        (spot * log_return.exp(), v_next)
+    }
+
+    /// 満期 `maturity` までのパスを生成し、スポットと各ステップの瞬間
+    /// ボラティリティを書き込む
+    ///
+    /// `z` は1ステップあたり `[Z_S, Z_v]`、`spots` は `n + 1` 個、
+    /// `volatilities` は `n` 個（ステップ開始時の L(t, S)·√v）。
+    ///
+    /// # エラー
+    ///
+    /// 満期が正でない、またはバッファの長さが一致しない場合
+    pub fn simulate_into(
+        &self,
+        maturity: f64,
+        z: &[f64],
+        spots: &mut [f64],
+        volatilities: &mut [f64],
+    ) -> Result<(), SlvError> {
+        let n_steps = z.len() / SLV_NORMALS_PER_STEP;
+        if !(maturity > 0.0 && maturity.is_finite())
+            || n_steps == 0
+            || z.len() % SLV_NORMALS_PER_STEP != 0
+            || spots.len() != n_steps + 1
+            || volatilities.len() != n_steps
+        {
+            return Err(SlvError::InvalidSimulation(format!(
+                "満期 {maturity}, 乱数 {} 個, スポット {} 個, ボラティリティ {} 個",
+                z.len(),
+                spots.len(),
+                volatilities.len()
+            )));
+        }
+        let dt = maturity / n_steps as f64;
+        spots[0] = self.params.heston.spot;
+        let mut variance = self.params.heston.v0;
+        for (i, step) in z.chunks_exact(SLV_NORMALS_PER_STEP).enumerate() {
+            let time = i as f64 * dt;
+            let leverage = self.leverage.value(time, spots[i]);
+            volatilities[i] = leverage * variance.max(0.0).sqrt();
+            let (s, v) =
+                self.step_with_leverage(leverage, dt, spots[i], variance, step[0], step[1]);
+            spots[i + 1] = s;
+            variance = v;
+        }
+        Ok(())
+    }
+
+    /// 満期 `maturity` までの1本のパスを生成
+    ///
+    /// # エラー
+    ///
+    /// 満期が正でない、または `z` が空か奇数個の場合
+    pub fn simulate_path(&self, maturity: f64, z: &[f64]) -> Result<SlvPath, SlvError> {
+        let n_steps = z.len() / SLV_NORMALS_PER_STEP;
+        if !(maturity > 0.0 && maturity.is_finite())
+            || n_steps == 0
+            || z.len() % SLV_NORMALS_PER_STEP != 0
+        {
+            return Err(SlvError::InvalidSimulation(format!(
+                "満期 {maturity}, 乱数 {} 個",
+                z.len()
+            )));
+        }
+        let dt = maturity / n_steps as f64;
+        let mut path = SlvPath {
+            spot: Vec::with_capacity(n_steps + 1),
+            variance: Vec::with_capacity(n_steps + 1),
+        };
+        let (mut s, mut v) = (self.params.heston.spot, self.params.heston.v0);
+        path.spot.push(s);
+        path.variance.push(v);
+        for (i, step) in z.chunks_exact(SLV_NORMALS_PER_STEP).enumerate() {
+            (s, v) = self.evolve_step(i as f64 * dt, dt, s, v, step[0], step[1]);
+            path.spot.push(s);
+            path.variance.push(v);
+        }
+        Ok(path)
+    }
+}
+
+/// 粒子法によるレバレッジ関数のキャリブレーション
+///
+/// # フィールド
+///
+/// * `n_particles` - 粒子数（デフォルト 10,000）
+/// * `n_steps` - 満期までの時間ステップ数（デフォルト 100）
+/// * `grid_points` - スポット・グリッドの点数（デフォルト 50）
+/// * `grid_width` - グリッド幅（対数スポットの標準偏差の倍数、デフォルト 4）
+/// * `bandwidth` - カーネルのバンド幅係数 κ（デフォルト 0.5）。バンド幅は
+///   `h = κ σ_LV(0, S0) S0 √max(t, 0.15) N^{-1/5}`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LeverageCalibrator {
+    /// 粒子数
+    pub n_particles: usize,
+    /// 時間ステップ数
+    pub n_steps: usize,
+    /// スポット・グリッドの点数
+    pub grid_points: usize,
+    /// グリッド幅（標準偏差の倍数）
+    pub grid_width: f64,
+    /// カーネルのバンド幅係数
+    pub bandwidth: f64,
+}
+
+impl Default for LeverageCalibrator {
+    fn default() -> Self {
+        Self {
+            n_particles: 10_000,
+            n_steps: 100,
+            grid_points: 50,
+            grid_width: 4.0,
+            bandwidth: 0.5,
+        }
+    }
+}
+
+impl LeverageCalibrator {
+    /// デフォルト設定のキャリブレータを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 粒子数を設定
+    pub fn with_particles(mut self, n: usize) -> Self {
+        self.n_particles = n;
+        self
+    }
+
+    /// 時間ステップ数を設定
+    pub fn with_steps(mut self, n: usize) -> Self {
+        self.n_steps = n;
+        self
+    }
+
+    /// スポット・グリッドの点数を設定
+    pub fn with_grid_points(mut self, n: usize) -> Self {
+        self.grid_points = n;
+        self
+    }
+
+    /// カーネルのバンド幅係数を設定
+    pub fn with_bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = bandwidth;
+        self
+    }
+
+    /// 局所ボラティリティ `local_vol(t, S)` にバニラが一致するレバレッジを
+    /// 粒子法で求め、SLVモデルを返す
+    ///
+    /// 粒子は1ステップあたり2個の正規乱数を `normals` から消費する。
+    ///
+    /// # エラー
+    ///
+    /// パラメータや設定が不正な場合、または局所ボラティリティが
+    /// 正かつ有限でない場合
+    ///
+    /// # 例
+    ///
+    /// ```
+    /// use pricer_models::models::heston::HestonParams;
+    /// use pricer_models::models::slv::{LeverageCalibrator, SlvParams};
+    ///
+    /// let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.5, -0.7, 0.03, 1.0).unwrap();
+    /// let params = SlvParams::new(heston, 0.0, 0.5).unwrap();
+    ///
+    /// // 決定的な乱数列（実運用では疑似乱数または準乱数を用いる）
+    /// let mut k = 0u32;
+    /// let mut normals = || {
+    ///     k += 1;
+    ///     ((k as f64 * 0.618_034).fract() - 0.5) * 3.0
+    /// };
+    /// let model = LeverageCalibrator::new()
+    ///     .with_particles(500)
+    ///     .with_steps(10)
+    ///     .calibrate(params, 1.0, |_t, _s| 0.2, &mut normals)
+    ///     .unwrap();
+    /// // 初期時点: L(0, S0) = σ_LV / √v0
+    /// assert!((model.leverage().value(0.0, 100.0) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn calibrate(
+        &self,
+        params: SlvParams,
+        maturity: f64,
+        local_vol: impl Fn(f64, f64) -> f64,
+        normals: &mut impl FnMut() -> f64,
+    ) -> Result<SlvModel, SlvError> {
+        params.validate()?;
+        if !(maturity > 0.0 && maturity.is_finite())
+            || self.n_particles < 2
+            || self.n_steps == 0
+            || self.grid_points < 2
+            || !(self.grid_width > 0.0 && self.bandwidth > 0.0)
+        {
+            return Err(SlvError::InvalidSimulation(format!(
+                "{self:?}, 満期 {maturity}"
+            )));
+        }
+        let spot = params.heston.spot;
+        let sigma = checked_local_vol(&local_vol, 0.0, spot)?;
+
+        // 対数一様なスポット・グリッド
+        let half_width = self.grid_width * sigma * maturity.sqrt();
+        let grid: Vec<f64> = (0..self.grid_points)
+            .map(|j| {
+                let x = -half_width + 2.0 * half_width * j as f64 / (self.grid_points - 1) as f64;
+                spot * x.exp()
+            })
+            .collect();
+
+        // 粒子法ではレバレッジを逐次決めるため、仮のモデルで時間発展させる
+        let stepper = SlvModel::new(params, LeverageFunction::flat(1.0)?)?;
+        let dt = maturity / self.n_steps as f64;
+        let scale = (self.n_particles as f64).powf(-0.2);
+        let mut particles = vec![(spot, params.heston.v0); self.n_particles];
+        let mut times = Vec::with_capacity(self.n_steps);
+        let mut values = Vec::with_capacity(self.n_steps * self.grid_points);
+
+        for k in 0..self.n_steps {
+            let time = k as f64 * dt;
+            particles.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let h = self.bandwidth * sigma * spot * time.max(0.15).sqrt() * scale;
+            let expectations = conditional_variance(&particles, &grid, h);
+
+            let row: Vec<f64> = grid
+                .iter()
+                .zip(&expectations)
+                .map(|(&s, &v)| {
+                    checked_local_vol(&local_vol, time, s)
+                        .map(|lv| lv / v.max(VARIANCE_FLOOR).sqrt())
+                })
+                .collect::<Result<_, _>>()?;
+
+            for (s, v) in particles.iter_mut() {
+                let leverage = interpolate(&grid, &row, *s);
+                let (z_spot, z_variance) = (normals(), normals());
+                (*s, *v) = stepper.step_with_leverage(leverage, dt, *s, *v, z_spot, z_variance);
+            }
+            times.push(time);
+            values.extend(row);
+        }
+
+        SlvModel::new(params, LeverageFunction::new(times, grid, values)?)
+    }
+}
+
+/// 局所ボラティリティを評価して検証
+fn checked_local_vol(
+    local_vol: &impl Fn(f64, f64) -> f64,
+    time: f64,
+    spot: f64,
+) -> Result<f64, SlvError> {
+    let value = local_vol(time, spot);
+    if value.is_finite() && value > 0.0 {
+        Ok(value)
+    } else {
+        Err(SlvError::InvalidLocalVol { time, spot, value })
+    }
+}
+
+/// スポット順に並んだ粒子 `(S, v)` から、グリッド各点の E[v | S] を
+/// ガウス・カーネル回帰で推定
+///
+/// 近傍に粒子の無いグリッド点は、粒子のある最も近いグリッド点の値で
+/// 外挿する（全く無ければ粒子全体の平均）。
+fn conditional_variance(particles: &[(f64, f64)], grid: &[f64], h: f64) -> Vec<f64> {
+    let estimates: Vec<Option<f64>> = grid
+        .iter()
+        .map(|&x| {
+            let lo = particles.partition_point(|p| p.0 < x - KERNEL_CUTOFF * h);
+            let hi = particles.partition_point(|p| p.0 <= x + KERNEL_CUTOFF * h);
+            let (weight, weighted) =
+                particles[lo..hi]
+                    .iter()
+                    .fold((0.0, 0.0), |(w, wv), &(s, v)| {
+                        let k = (-0.5 * ((s - x) / h).powi(2)).exp();
+                        (w + k, wv + k * v)
+                    });
+            (weight > 0.0).then(|| weighted / weight)
+        })
+        .collect();
+
+    let mean = particles.iter().map(|p| p.1).sum::<f64>() / particles.len() as f64;
+    (0..grid.len())
+        .map(|j| {
+            let nearest = (0..grid.len())
+                .filter(|&i| estimates[i].is_some())
+                .min_by_key(|&i| i.abs_diff(j));
+            nearest.and_then(|i| estimates[i]).unwrap_or(mean)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::fourier::{CosPricer, FourierMarket, HestonCf};
+    use crate::analytical::BlackScholes;
+    use crate::test_utils::normals;
+    use approx::assert_relative_eq;
+
+    fn heston() -> HestonParams<f64> {
+        HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.6, -0.7, 0.03, 1.0).unwrap()
+    }
+
+    /// 満期 `maturity` のヨーロピアン・コールのモンテカルロ価格（割引後）
+    fn mc_calls(model: &SlvModel, strikes: &[f64], maturity: f64, n_paths: usize) -> Vec<f64> {
+        let n_steps = 50;
+        let mut normal = normals(99);
+        let mut z = vec![0.0; SLV_NORMALS_PER_STEP * n_steps];
+        let mut sums = vec![0.0; strikes.len()];
+        for _ in 0..n_paths {
+            z.iter_mut().for_each(|x| *x = normal());
+            let terminal = *model
+                .simulate_path(maturity, &z)
+                .unwrap()
+                .spot
+                .last()
+                .unwrap();
+            for (sum, &k) in sums.iter_mut().zip(strikes) {
+                *sum += (terminal - k).max(0.0);
+            }
+        }
+        let discount = (-model.params().heston.rate * maturity).exp();
+        sums.iter().map(|s| s * discount / n_paths as f64).collect()
+    }
+
+    // テスト: レバレッジ関数の検証と補間
+    #[test]
+    fn test_leverage_function() {
+        let leverage =
+            LeverageFunction::new(vec![0.0, 1.0], vec![90.0, 110.0], vec![1.0, 2.0, 3.0, 3.0])
+                .unwrap();
+        assert_relative_eq!(leverage.value(0.5, 100.0), 1.5);
+        assert_relative_eq!(leverage.value(1.0, 100.0), 3.0);
+        assert_relative_eq!(leverage.value(-1.0, 200.0), 2.0);
+        assert_eq!(leverage.row(1), &[3.0, 3.0]);
+
+        assert!(LeverageFunction::new(vec![0.5], vec![100.0], vec![1.0]).is_err());
+        assert!(LeverageFunction::new(vec![0.0], vec![100.0, 90.0], vec![1.0, 1.0]).is_err());
+        assert!(LeverageFunction::new(vec![0.0], vec![100.0], vec![1.0, 1.0]).is_err());
+        assert!(LeverageFunction::flat(0.0).is_err());
+        assert_eq!(
+            SlvParams::new(heston(), 0.0, 1.5),
+            Err(SlvError::InvalidMixing(1.5))
+        );
+        assert!(matches!(
+            SlvParams::new(heston(), f64::NAN, 0.5),
+            Err(SlvError::InvalidDividend(_))
+        ));
+    }
+
+    // テスト: L ≡ 1, η = 1 でHestonに一致
+    #[test]
+    fn test_unit_leverage_matches_heston() {
+        let params = SlvParams::new(heston(), 0.0, 1.0).unwrap();
+        let model = SlvModel::new(params, LeverageFunction::flat(1.0).unwrap()).unwrap();
+        let strikes = [90.0, 100.0, 110.0];
+        let calls = mc_calls(&model, &strikes, 1.0, 20_000);
+
+        let cf = HestonCf::from_params(&heston()).unwrap();
+        let market = FourierMarket::new(100.0, 0.03, 0.0).unwrap();
+        for (&k, &call) in strikes.iter().zip(&calls) {
+            let exact = CosPricer::default()
+                .price(&cf, &market, k, 1.0, true)
+                .unwrap();
+            assert_relative_eq!(call, exact, max_relative = 3e-2);
+        }
+    }
+
+    // テスト: 粒子法でキャリブレーションしたSLVは局所ボラティリティのバニラを再現
+    #[test]
+    fn test_particle_calibration_reprices_local_vol() {
+        let sigma = 0.25;
+        let params = SlvParams::new(heston(), 0.01, 0.7).unwrap();
+        let model = LeverageCalibrator::new()
+            .with_particles(5_000)
+            .with_steps(50)
+            .calibrate(params, 1.0, |_t, _s| sigma, &mut normals(5))
+            .unwrap();
+
+        // 初期時点は σ_LV / √v0。ρ < 0 では低スポットで E[v|S] が大きく、L は小さい
+        assert_relative_eq!(
+            model.leverage().value(0.0, 100.0),
+            sigma / 0.2,
+            epsilon = 1e-10
+        );
+        assert!(model.leverage().value(0.9, 60.0) < model.leverage().value(0.9, 140.0));
+
+        let strikes = [85.0, 100.0, 115.0];
+        let calls = mc_calls(&model, &strikes, 1.0, 20_000);
+        let bs = BlackScholes::new(100.0, 0.03, sigma)
+            .unwrap()
+            .with_dividend_yield(0.01);
+        for (&k, &call) in strikes.iter().zip(&calls) {
+            assert_relative_eq!(call, bs.price_call(k, 1.0), max_relative = 2e-2);
+        }
+    }
+
+    // テスト: 不正な入力
+    #[test]
+    fn test_invalid_inputs() {
+        let params = SlvParams::new(heston(), 0.0, 0.5).unwrap();
+        let model = SlvModel::new(params, LeverageFunction::flat(1.0).unwrap()).unwrap();
+        assert!(model.simulate_path(1.0, &[0.0; 3]).is_err());
+        assert!(model.simulate_path(0.0, &[0.0; 4]).is_err());
+        let (mut spots, mut vols) = (vec![0.0; 3], vec![0.0; 1]);
+        assert!(model
+            .simulate_into(1.0, &[0.0; 4], &mut spots, &mut vols)
+            .is_err());
+
+        let calibrator = LeverageCalibrator::new().with_particles(10).with_steps(2);
+        assert!(matches!(
+            calibrator.calibrate(params, 1.0, |_t, s| 100.0 - s, &mut normals(1)),
+            Err(SlvError::InvalidLocalVol { .. })
+        ));
+        assert!(calibrator
+            .with_grid_points(1)
+            .calibrate(params, 1.0, |_t, _s| 0.2, &mut normals(1))
+            .is_err());
+    }
+}
@@ -0,0 +1,19 @@
+//! Shared helpers for unit tests.
+
+/// Deterministic standard normals from xorshift64* and Box-Muller.
+///
+/// Independent of the pricing RNGs so that model tests do not change when
+/// those are tuned.
+pub(crate) fn normals(seed: u64) -> impl FnMut() -> f64 {
+    let mut state = seed;
+    let mut uniform = move || {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        ((state.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    };
+    move || {
+        let (u1, u2) = (uniform(), uniform());
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
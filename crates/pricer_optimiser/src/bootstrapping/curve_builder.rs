@@ -2,6 +2,7 @@
 
 use crate::bootstrapping::BootstrapResult;
 use crate::error::OptimiserError;
+use num_traits::Float;
 
 /// Configuration for curve bootstrapping.
 #[derive(Debug, Clone)]
@@ -37,7 +38,6 @@ pub enum InterpolationMethod {
 
 /// Curve bootstrapper for yield curve construction.
 pub struct CurveBootstrapper {
-    #[allow(dead_code)] // Will be used when bootstrap implementation is completed
     config: BootstrapConfig,
 }
 
@@ -54,6 +54,11 @@ impl CurveBootstrapper {
         Self { config }
     }
 
+    /// Return the bootstrapper configuration.
+    pub fn config(&self) -> &BootstrapConfig {
+        &self.config
+    }
+
     /// Bootstrap a discount curve from swap rates.
     ///
     /// # Arguments
@@ -69,6 +74,33 @@ impl CurveBootstrapper {
         pillars: &[f64],
         swap_rates: &[f64],
     ) -> Result<BootstrapResult, OptimiserError> {
+        let discount_factors = self.bootstrap_generic(pillars, swap_rates)?;
+
+        Ok(BootstrapResult {
+            discount_factors,
+            pillars: pillars.to_vec(),
+            residual: 0.0,
+        })
+    }
+
+    /// Bootstrap discount factors generically over the quote type.
+    ///
+    /// Running this with an AD type (e.g. `FloatDual`) propagates quote
+    /// sensitivities through the stripping algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `pillars` - Pillar dates in years from today
+    /// * `swap_rates` - Market-observed swap rates
+    ///
+    /// # Returns
+    ///
+    /// Discount factors at each pillar.
+    pub fn bootstrap_generic<T: Float>(
+        &self,
+        pillars: &[f64],
+        swap_rates: &[T],
+    ) -> Result<Vec<T>, OptimiserError> {
         if pillars.len() != swap_rates.len() {
             return Err(OptimiserError::InvalidMarketData(
                 "Pillars and swap rates must have the same length".to_string(),
@@ -82,36 +114,35 @@ impl CurveBootstrapper {
             });
         }
 
+        let one = T::one();
+        let time = |t: f64| T::from(t).unwrap();
+
         // Simple bootstrapping (iterative stripping)
-        let mut discount_factors = Vec::with_capacity(pillars.len());
+        let mut discount_factors: Vec<T> = Vec::with_capacity(pillars.len());
 
         for (i, (&t, &rate)) in pillars.iter().zip(swap_rates.iter()).enumerate() {
             let df = if i == 0 {
                 // First pillar: df = 1 / (1 + r * t)
-                1.0 / (1.0 + rate * t)
+                one / (one + rate * time(t))
             } else {
                 // Subsequent pillars: solve for df using previous discount factors
-                let mut sum = 0.0;
+                let mut sum = T::zero();
                 for j in 0..i {
                     let dt = if j == 0 {
                         pillars[j]
                     } else {
                         pillars[j] - pillars[j - 1]
                     };
-                    sum += rate * dt * discount_factors[j];
+                    sum = sum + rate * time(dt) * discount_factors[j];
                 }
                 let dt_last = t - pillars[i - 1];
-                (1.0 - sum) / (1.0 + rate * dt_last)
+                (one - sum) / (one + rate * time(dt_last))
             };
 
             discount_factors.push(df);
         }
 
-        Ok(BootstrapResult {
-            discount_factors,
-            pillars: pillars.to_vec(),
-            residual: 0.0,
-        })
+        Ok(discount_factors)
     }
 }
 
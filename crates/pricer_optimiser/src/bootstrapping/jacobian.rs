@@ -0,0 +1,395 @@
+//! Curve Jacobian and bucketed par-rate sensitivities.
+//!
+//! The bootstrapper maps market quotes `q` to pillar zero rates `z`. This
+//! module differentiates that map with forward-mode AD ([`FloatDual`]),
+//! giving the Jacobian `J[i][j] = ∂z_i/∂q_j`, and uses it to express risk
+//! per input instrument:
+//!
+//! ```text
+//! ∂PV/∂q_j = Σ_i ∂PV/∂z_i · ∂z_i/∂q_j      (par delta = Jᵀ · zero delta)
+//! ```
+//!
+//! [`CurveBootstrapper::bucketed_delta`] goes one step further and pushes the
+//! seeded quote through an [`InterpolatedCurve<FloatDual>`], so any pricing
+//! function written against `YieldCurve<T>` is differentiated directly
+//! against the quotes it depends on.
+
+use num_traits::Float;
+use pricer_core::market_data::curves::{CurveInterpolation, InterpolatedCurve};
+use pricer_core::market_data::error::MarketDataError;
+use pricer_core::types::dual::FloatDual;
+
+use super::curve_builder::{CurveBootstrapper, InterpolationMethod};
+use crate::error::OptimiserError;
+
+/// One basis point in decimal rate units.
+const ONE_BP: f64 = 1e-4;
+
+/// Jacobian of bootstrapped zero rates with respect to input quotes.
+#[derive(Debug, Clone)]
+pub struct CurveJacobian {
+    /// Pillar times in years
+    pub pillars: Vec<f64>,
+    /// Continuously compounded zero rates at each pillar
+    pub zero_rates: Vec<f64>,
+    /// `matrix[i][j] = ∂z_i/∂q_j` (rows: pillars, columns: quotes)
+    pub matrix: Vec<Vec<f64>>,
+}
+
+impl CurveJacobian {
+    /// Number of pillars (and quotes).
+    pub fn len(&self) -> usize {
+        self.pillars.len()
+    }
+
+    /// Whether the Jacobian is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pillars.is_empty()
+    }
+
+    /// Map zero-rate sensitivities to par-rate sensitivities.
+    ///
+    /// # Arguments
+    ///
+    /// * `zero_deltas` - `∂PV/∂z_i` for each pillar
+    ///
+    /// # Returns
+    ///
+    /// `∂PV/∂q_j` for each input quote, i.e. `Jᵀ · zero_deltas`.
+    pub fn par_deltas(&self, zero_deltas: &[f64]) -> Result<Vec<f64>, OptimiserError> {
+        if zero_deltas.len() != self.len() {
+            return Err(OptimiserError::InvalidMarketData(format!(
+                "Expected {} zero-rate sensitivities, got {}",
+                self.len(),
+                zero_deltas.len()
+            )));
+        }
+
+        let n = self.len();
+        Ok((0..n)
+            .map(|j| (0..n).map(|i| zero_deltas[i] * self.matrix[i][j]).sum())
+            .collect())
+    }
+}
+
+/// Sensitivities of a present value to each bootstrapping quote.
+#[derive(Debug, Clone)]
+pub struct BucketedDelta {
+    /// Pillar times in years, one per input quote
+    pub pillars: Vec<f64>,
+    /// Present value at the unbumped quotes
+    pub value: f64,
+    /// `∂PV/∂q_j` per unit quote move
+    pub deltas: Vec<f64>,
+}
+
+impl BucketedDelta {
+    /// Sensitivity per one basis point move of each quote (key-rate DV01).
+    pub fn dv01(&self) -> Vec<f64> {
+        self.deltas.iter().map(|d| d * ONE_BP).collect()
+    }
+
+    /// Sum of all bucketed DV01s, comparable to a parallel par-rate bump.
+    pub fn parallel_dv01(&self) -> f64 {
+        self.deltas.iter().sum::<f64>() * ONE_BP
+    }
+}
+
+impl CurveBootstrapper {
+    /// Bootstrap and return the Jacobian of zero rates with respect to quotes.
+    ///
+    /// Each column is obtained from one forward-mode sweep with the
+    /// corresponding quote seeded as the active variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `pillars` - Pillar dates in years from today
+    /// * `swap_rates` - Market-observed swap rates
+    pub fn jacobian(
+        &self,
+        pillars: &[f64],
+        swap_rates: &[f64],
+    ) -> Result<CurveJacobian, OptimiserError> {
+        let n = pillars.len();
+        let mut matrix = vec![vec![0.0; n]; n];
+        let mut zero_rates = vec![0.0; n];
+
+        for j in 0..n {
+            let zeros =
+                zero_rates_from_dfs(pillars, &self.bootstrap_seeded(pillars, swap_rates, j)?);
+            for (i, z) in zeros.iter().enumerate() {
+                matrix[i][j] = z.eps();
+                zero_rates[i] = z.re();
+            }
+        }
+
+        Ok(CurveJacobian {
+            pillars: pillars.to_vec(),
+            zero_rates,
+            matrix,
+        })
+    }
+
+    /// Build an [`InterpolatedCurve`] on bootstrapped zero rates.
+    ///
+    /// The curve uses the configured interpolation and allows flat
+    /// extrapolation outside the pillar range.
+    pub fn build_curve<T: Float>(
+        &self,
+        pillars: &[f64],
+        swap_rates: &[T],
+    ) -> Result<InterpolatedCurve<T>, OptimiserError> {
+        let dfs = self.bootstrap_generic(pillars, swap_rates)?;
+        let tenors: Vec<T> = pillars.iter().map(|&t| T::from(t).unwrap()).collect();
+        let zeros = zero_rates_from_dfs(pillars, &dfs);
+        InterpolatedCurve::new(&tenors, &zeros, self.curve_interpolation()?, true)
+            .map_err(market_data_error)
+    }
+
+    /// Bucketed par-rate deltas of an arbitrary present value.
+    ///
+    /// For each quote, the curve is rebuilt with that quote seeded as the
+    /// active [`FloatDual`] variable and `pv` is evaluated on the resulting
+    /// [`InterpolatedCurve<FloatDual>`]. The dual part of the result is the
+    /// exact derivative `∂PV/∂q_j`, with no bump size to tune.
+    ///
+    /// # Arguments
+    ///
+    /// * `pillars` - Pillar dates in years from today
+    /// * `swap_rates` - Market-observed swap rates
+    /// * `pv` - Present value as a function of the bootstrapped curve
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pricer_core::market_data::curves::YieldCurve;
+    /// use pricer_optimiser::bootstrapping::CurveBootstrapper;
+    ///
+    /// let bootstrapper = CurveBootstrapper::new();
+    /// let pillars = [1.0, 2.0, 5.0];
+    /// let quotes = [0.02, 0.025, 0.03];
+    ///
+    /// // Zero-coupon bond paying 1 million at 3 years
+    /// let delta = bootstrapper
+    ///     .bucketed_delta(&pillars, &quotes, |curve| {
+    ///         Ok(curve.discount_factor(3.0.into())? * 1_000_000.0.into())
+    ///     })
+    ///     .unwrap();
+    ///
+    /// // The 2Y and 5Y quotes bracket the 3Y cash flow and dominate
+    /// assert!(delta.dv01()[1] < -100.0 && delta.dv01()[2] < -100.0);
+    ///
+    /// // The 1Y quote still moves it through the sequential bootstrap: a
+    /// // lower 1Y discount factor is offset by higher stripped 2Y and 5Y ones
+    /// assert!(delta.dv01()[0] > 0.0 && delta.dv01()[0] < 10.0);
+    /// ```
+    pub fn bucketed_delta<F>(
+        &self,
+        pillars: &[f64],
+        swap_rates: &[f64],
+        pv: F,
+    ) -> Result<BucketedDelta, OptimiserError>
+    where
+        F: Fn(&InterpolatedCurve<FloatDual>) -> Result<FloatDual, MarketDataError>,
+    {
+        let n = pillars.len();
+        let mut deltas = Vec::with_capacity(n);
+        let mut value = 0.0;
+
+        for j in 0..n {
+            let seeded = seed_quotes(swap_rates, j);
+            let curve = self.build_curve(pillars, &seeded)?;
+            let result = pv(&curve).map_err(market_data_error)?;
+            value = result.re();
+            deltas.push(result.eps());
+        }
+
+        Ok(BucketedDelta {
+            pillars: pillars.to_vec(),
+            value,
+            deltas,
+        })
+    }
+
+    /// Bootstrap with quote `j` seeded as the active dual variable.
+    fn bootstrap_seeded(
+        &self,
+        pillars: &[f64],
+        swap_rates: &[f64],
+        j: usize,
+    ) -> Result<Vec<FloatDual>, OptimiserError> {
+        self.bootstrap_generic(pillars, &seed_quotes(swap_rates, j))
+    }
+
+    /// Map the bootstrapper's interpolation onto the curve's.
+    fn curve_interpolation(&self) -> Result<CurveInterpolation, OptimiserError> {
+        match self.config().interpolation {
+            InterpolationMethod::Linear => Ok(CurveInterpolation::Linear),
+            InterpolationMethod::LogLinear => Ok(CurveInterpolation::LogLinear),
+            InterpolationMethod::CubicSpline => Err(OptimiserError::InvalidMarketData(
+                "Cubic spline interpolation is not supported by InterpolatedCurve".to_string(),
+            )),
+        }
+    }
+}
+
+/// Lift quotes to dual numbers with the `j`-th quote active.
+fn seed_quotes(swap_rates: &[f64], j: usize) -> Vec<FloatDual> {
+    swap_rates
+        .iter()
+        .enumerate()
+        .map(|(k, &q)| {
+            if k == j {
+                FloatDual::variable(q)
+            } else {
+                FloatDual::constant(q)
+            }
+        })
+        .collect()
+}
+
+/// Convert discount factors to continuously compounded zero rates.
+fn zero_rates_from_dfs<T: Float>(pillars: &[f64], dfs: &[T]) -> Vec<T> {
+    pillars
+        .iter()
+        .zip(dfs.iter())
+        .map(|(&t, &df)| -df.ln() / T::from(t).unwrap())
+        .collect()
+}
+
+fn market_data_error(e: MarketDataError) -> OptimiserError {
+    OptimiserError::InvalidMarketData(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use pricer_core::market_data::curves::YieldCurve;
+
+    const PILLARS: [f64; 4] = [0.5, 1.0, 2.0, 5.0];
+    const QUOTES: [f64; 4] = [0.02, 0.025, 0.03, 0.035];
+
+    fn bumped_zeros(j: usize, h: f64) -> Vec<f64> {
+        let mut quotes = QUOTES.to_vec();
+        quotes[j] += h;
+        let dfs = CurveBootstrapper::new()
+            .bootstrap(&PILLARS, &quotes)
+            .unwrap();
+        zero_rates_from_dfs(&PILLARS, &dfs.discount_factors)
+    }
+
+    #[test]
+    fn test_jacobian_matches_finite_difference() {
+        let jac = CurveBootstrapper::new()
+            .jacobian(&PILLARS, &QUOTES)
+            .unwrap();
+        let h = 1e-7;
+
+        for j in 0..PILLARS.len() {
+            let up = bumped_zeros(j, h);
+            let down = bumped_zeros(j, -h);
+            for i in 0..PILLARS.len() {
+                let fd = (up[i] - down[i]) / (2.0 * h);
+                assert_relative_eq!(jac.matrix[i][j], fd, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobian_is_lower_triangular() {
+        // Sequential stripping: z_i depends only on q_0..=q_i
+        let jac = CurveBootstrapper::new()
+            .jacobian(&PILLARS, &QUOTES)
+            .unwrap();
+        for i in 0..PILLARS.len() {
+            for j in (i + 1)..PILLARS.len() {
+                assert_eq!(jac.matrix[i][j], 0.0);
+            }
+            assert!(jac.matrix[i][i] > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_par_deltas_length_mismatch() {
+        let jac = CurveBootstrapper::new()
+            .jacobian(&PILLARS, &QUOTES)
+            .unwrap();
+        assert!(jac.par_deltas(&[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn test_bucketed_delta_matches_jacobian_chain_rule() {
+        // A zero-coupon bond at a pillar depends on that pillar's zero rate only:
+        // PV = exp(-z_k t_k)  =>  ∂PV/∂z_k = -t_k PV
+        let bootstrapper = CurveBootstrapper::new();
+        let k = 2;
+        let t = PILLARS[k];
+
+        let delta = bootstrapper
+            .bucketed_delta(&PILLARS, &QUOTES, |curve| curve.discount_factor(t.into()))
+            .unwrap();
+
+        let jac = bootstrapper.jacobian(&PILLARS, &QUOTES).unwrap();
+        let mut zero_deltas = vec![0.0; PILLARS.len()];
+        zero_deltas[k] = -t * delta.value;
+        let expected = jac.par_deltas(&zero_deltas).unwrap();
+
+        for (ad, chain) in delta.deltas.iter().zip(expected.iter()) {
+            assert_relative_eq!(*ad, *chain, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_bucketed_delta_matches_bump_and_revalue() {
+        let bootstrapper = CurveBootstrapper::with_config(crate::bootstrapping::BootstrapConfig {
+            interpolation: InterpolationMethod::Linear,
+            ..Default::default()
+        });
+        let pv_f64 = |quotes: &[f64]| {
+            let curve = bootstrapper.build_curve(&PILLARS, quotes).unwrap();
+            // Annual coupon bond maturing at 4.5 years
+            [1.0, 2.0, 3.0, 4.0, 4.5]
+                .iter()
+                .map(|&t| 0.03 * curve.discount_factor(t).unwrap())
+                .sum::<f64>()
+                + curve.discount_factor(4.5).unwrap()
+        };
+
+        let delta = bootstrapper
+            .bucketed_delta(&PILLARS, &QUOTES, |curve| {
+                let mut pv = curve.discount_factor(4.5.into())?;
+                for t in [1.0, 2.0, 3.0, 4.0, 4.5] {
+                    pv = pv + FloatDual::constant(0.03) * curve.discount_factor(t.into())?;
+                }
+                Ok(pv)
+            })
+            .unwrap();
+
+        assert_relative_eq!(delta.value, pv_f64(&QUOTES), epsilon = 1e-12);
+
+        let h = 1e-6;
+        for j in 0..PILLARS.len() {
+            let mut up = QUOTES.to_vec();
+            let mut down = QUOTES.to_vec();
+            up[j] += h;
+            down[j] -= h;
+            let fd = (pv_f64(&up) - pv_f64(&down)) / (2.0 * h);
+            assert_relative_eq!(delta.deltas[j], fd, epsilon = 1e-5);
+        }
+
+        // Key-rate DV01s add up to the parallel par-rate DV01
+        let parallel =
+            (pv_f64(&QUOTES.map(|q| q + h)) - pv_f64(&QUOTES.map(|q| q - h))) / (2.0 * h) * ONE_BP;
+        assert_relative_eq!(delta.parallel_dv01(), parallel, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_cubic_spline_not_supported() {
+        let bootstrapper = CurveBootstrapper::with_config(crate::bootstrapping::BootstrapConfig {
+            interpolation: InterpolationMethod::CubicSpline,
+            ..Default::default()
+        });
+        assert!(bootstrapper.build_curve(&PILLARS, &QUOTES).is_err());
+    }
+}
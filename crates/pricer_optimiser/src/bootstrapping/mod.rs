@@ -1,11 +1,14 @@
 //! Yield curve bootstrapping from OIS/Swap rates.
 //!
 //! This module implements multi-curve stripping logic to construct
-//! yield curves from market-observed swap rates.
+//! yield curves from market-observed swap rates, and the curve Jacobian
+//! used to express risk as bucketed par-rate sensitivities.
 
 mod curve_builder;
+mod jacobian;
 
-pub use curve_builder::{BootstrapConfig, CurveBootstrapper};
+pub use curve_builder::{BootstrapConfig, CurveBootstrapper, InterpolationMethod};
+pub use jacobian::{BucketedDelta, CurveJacobian};
 
 /// Result of curve bootstrapping.
 #[derive(Debug, Clone)]
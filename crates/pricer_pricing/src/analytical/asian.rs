@@ -88,7 +88,7 @@ pub struct GeometricAsianResult<T: Float> {
 ///
 /// Uses the Abramowitz and Stegun approximation for the error function.
 #[inline]
-fn norm_cdf<T: Float>(x: T) -> T {
+pub(crate) fn norm_cdf<T: Float>(x: T) -> T {
     let one = T::one();
     let zero = T::zero();
     let half = T::from(0.5).unwrap();
@@ -13,6 +13,13 @@
 //! # Key Relationship
 //!
 //! **In-Out Parity**: Knock-In + Knock-Out = Vanilla
+//!
+//! # Discrete Monitoring
+//!
+//! [`discrete_barrier_price`] applies the Broadie-Glasserman-Kou (1997)
+//! continuity correction: a barrier monitored every `Δt` is priced as a
+//! continuous barrier shifted away from the spot by `exp(±βσ√Δt)`, with
+//! `β = −ζ(½)/√(2π) ≈ 0.5826`.
 
 use num_traits::Float;
 
@@ -464,6 +471,47 @@ fn compute_up_price<T: Float>(
     }
 }
 
+/// Broadie-Glasserman-Kou constant `β = −ζ(½)/√(2π)`.
+pub const BGK_BETA: f64 = 0.582_597_157_939_010_6;
+
+/// Shifts a discretely monitored barrier to the continuously monitored
+/// barrier with approximately the same price.
+///
+/// Up barriers move up and down barriers move down by the factor
+/// `exp(βσ√Δt)`, where `Δt` is the monitoring interval. Passing a negative
+/// interval applies the inverse shift, which maps a continuous barrier to
+/// the discrete barrier that mimics it.
+#[inline]
+pub fn discrete_barrier_shift<T: Float>(
+    barrier: T,
+    volatility: T,
+    interval: T,
+    direction: BarrierDirection,
+) -> T {
+    let beta = T::from(BGK_BETA).unwrap();
+    let shift = beta * volatility * interval.abs().sqrt();
+    let shift = if interval < T::zero() { -shift } else { shift };
+    match direction {
+        BarrierDirection::Up => barrier * shift.exp(),
+        BarrierDirection::Down => barrier * (-shift).exp(),
+    }
+}
+
+/// Price a barrier option monitored at intervals of `interval` years
+/// (e.g. `1/252` for daily closes), using the continuity correction.
+pub fn discrete_barrier_price<T: Float>(params: &BarrierParams<T>, interval: T) -> T {
+    let shifted = BarrierParams {
+        barrier: discrete_barrier_shift(
+            params.barrier,
+            params.volatility,
+            interval,
+            params.barrier_type.direction,
+        ),
+        ..*params
+    };
+    barrier_price(&shifted)
+}
+
 // Convenience functions
 /// Price a down-and-out call option.
 #[inline]
@@ -786,4 +834,28 @@ mod tests {
         let p = down_out_call(100.0_f32, 100.0, 90.0, 0.05, 0.0, 0.2, 1.0);
         assert!(p > 0.0);
     }
+
+    #[test]
+    fn test_discrete_monitoring_shift() {
+        let up = discrete_barrier_shift(120.0, 0.2, 1.0 / 252.0, BarrierDirection::Up);
+        let down = discrete_barrier_shift(80.0, 0.2, 1.0 / 252.0, BarrierDirection::Down);
+        assert!(up > 120.0 && down < 80.0);
+        let back = discrete_barrier_shift(up, 0.2, -1.0 / 252.0, BarrierDirection::Up);
+        assert_relative_eq!(back, 120.0, epsilon = 1e-12);
+
+        // Discrete monitoring knocks out less often than continuous
+        let params = BarrierParams::new(
+            100.0,
+            100.0,
+            90.0,
+            0.05,
+            0.0,
+            0.2,
+            1.0,
+            BarrierType::down_out_call(),
+        );
+        let discrete = discrete_barrier_price(&params, 1.0 / 12.0);
+        assert!(discrete > barrier_price(&params));
+        assert_relative_eq!(discrete_barrier_price(&params, 0.0), barrier_price(&params));
+    }
 }
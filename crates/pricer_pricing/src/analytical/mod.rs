@@ -7,6 +7,7 @@
 //!
 //! - **Geometric Average Asian Options**: Kemna-Vorst (1990) closed-form
 //! - **Barrier Options**: Merton (1973) / Rubinstein-Reiner (1991) formulas
+//!   with the Broadie-Glasserman-Kou discrete monitoring correction
 //!
 //! # Usage
 //!
@@ -44,7 +45,7 @@ pub use asian::{
 };
 
 pub use barrier::{
-    barrier_price, down_in_call, down_in_put, down_out_call, down_out_put, up_in_call, up_in_put,
-    up_out_call, up_out_put, BarrierDirection, BarrierParams, BarrierResult, BarrierType,
-    KnockType, OptionType,
+    barrier_price, discrete_barrier_price, discrete_barrier_shift, down_in_call, down_in_put,
+    down_out_call, down_out_put, up_in_call, up_in_put, up_out_call, up_out_put, BarrierDirection,
+    BarrierParams, BarrierResult, BarrierType, KnockType, OptionType, BGK_BETA,
 };
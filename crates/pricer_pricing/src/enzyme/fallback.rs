@@ -2,8 +2,9 @@
 //!
 //! This module provides the bridge between the Enzyme AD module and the
 //! existing Greeks calculation infrastructure. When the `enzyme-ad` feature
-//! is disabled, all AD-based Greeks computations fall back to bump-and-revalue
-//! finite difference methods.
+//! is disabled, reverse-mode requests fall back to tape-based AD on stable
+//! Rust (with `l1l2-integration`) and all other AD-based Greeks
+//! computations fall back to bump-and-revalue finite difference methods.
 //!
 //! # Mode Resolution
 //!
@@ -16,7 +17,7 @@
 //! | EnzymeOnly | Enzyme AD | panic! |
 //! | FiniteDifference | BumpRevalue | BumpRevalue |
 //! | ForwardMode | Enzyme Forward | BumpRevalue |
-//! | ReverseMode | Enzyme Reverse | BumpRevalue |
+//! | ReverseMode | Enzyme Reverse | TapeAAD (BumpRevalue without `l1l2-integration`) |
 //!
 //! # Usage
 //!
@@ -160,6 +161,8 @@ impl FallbackResolver {
             EnzymeGreeksMode::ReverseMode => {
                 if enzyme_available {
                     ResolvedMode::enzyme(EnzymeGreeksMode::ReverseMode)
+                } else if cfg!(feature = "l1l2-integration") {
+                    ResolvedMode::fallback(CoreGreeksMode::TapeAAD)
                 } else {
                     ResolvedMode::fallback(CoreGreeksMode::BumpRevalue)
                 }
@@ -401,6 +404,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_mode_reverse_uses_tape() {
+        let resolver = FallbackResolver::default();
+        let resolved = resolver.resolve_mode(EnzymeGreeksMode::ReverseMode);
+
+        #[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+        assert_eq!(
+            resolved.method,
+            ComputationMethod::Fallback(CoreGreeksMode::TapeAAD)
+        );
+
+        #[cfg(all(not(feature = "enzyme-ad"), not(feature = "l1l2-integration")))]
+        assert_eq!(
+            resolved.method,
+            ComputationMethod::Fallback(CoreGreeksMode::BumpRevalue)
+        );
+
+        #[cfg(feature = "enzyme-ad")]
+        assert!(resolved.uses_enzyme());
+    }
+
     #[test]
     fn test_resolve_mode_enzyme_only_without_enzyme() {
         let resolver = FallbackResolver::new(FallbackConfig {
@@ -2,8 +2,9 @@
 //!
 //! This module provides the `GreeksEnzyme` trait for integrating Enzyme
 //! automatic differentiation with the Monte Carlo pricer. When the `enzyme-ad`
-//! feature is enabled, it uses LLVM-level AD; otherwise, it falls back to
-//! finite difference approximations.
+//! feature is enabled, it uses LLVM-level AD; otherwise, reverse mode records
+//! the estimator on the `AReal` tape (with `l1l2-integration`) and the other
+//! modes fall back to finite difference approximations.
 //!
 //! # Usage
 //!
@@ -28,7 +29,15 @@
 //! ```
 
 use crate::greeks::GreeksResult;
+#[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+use crate::mc::PayoffType;
 use crate::mc::{GbmParams, MonteCarloPricer, PayoffParams, PricingResult};
+#[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+use num_traits::Float;
+#[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+use pricer_core::math::smoothing::smooth_max;
+#[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+use pricer_core::types::tape::{AReal, Tape, TapeError};
 
 /// Mode for Greeks computation.
 ///
@@ -187,6 +196,8 @@ impl EnzymeGreeksResult {
             } else {
                 None
             },
+            variance_reduction_factor: None,
+            ..Default::default()
         }
     }
 
@@ -456,7 +467,9 @@ impl MonteCarloPricer {
     /// Computes Greeks using reverse mode AD.
     ///
     /// In a full Enzyme implementation, this would compute all Greeks
-    /// in a single reverse pass. Currently falls back to finite differences.
+    /// in a single reverse pass. Without Enzyme, the first-order Greeks
+    /// come from the `AReal` tape (`l1l2-integration`) or from finite
+    /// differences.
     fn compute_greeks_reverse(
         &mut self,
         gbm: GbmParams,
@@ -470,12 +483,55 @@ impl MonteCarloPricer {
             // For now, use finite differences as placeholder.
             self.compute_greeks_fd(gbm, payoff, discount_factor)
         }
-        #[cfg(not(feature = "enzyme-ad"))]
+        #[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+        {
+            self.compute_greeks_tape(gbm, payoff, discount_factor)
+        }
+        #[cfg(all(not(feature = "enzyme-ad"), not(feature = "l1l2-integration")))]
         {
             self.compute_greeks_fd(gbm, payoff, discount_factor)
         }
     }
 
+    /// Computes Greeks by recording the estimator on the `AReal` tape.
+    ///
+    /// Delta, Vega, Theta and Rho come from one backward sweep over the
+    /// pathwise estimator on the same normals as the price; Gamma is
+    /// still a finite difference. Theta and Rho follow the conventions of
+    /// the finite difference path. Falls back to finite differences if
+    /// the tape exceeds its memory limit.
+    #[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+    fn compute_greeks_tape(
+        &mut self,
+        gbm: GbmParams,
+        payoff: PayoffParams,
+        discount_factor: f64,
+    ) -> EnzymeGreeksResult {
+        let seed = self.rng.seed();
+        let base_result = self.price_european(gbm, payoff, discount_factor);
+
+        self.reset_with_seed(seed);
+        let normal_sums = self.gbm_normal_sums();
+        let n_steps = self.config().n_steps();
+        let Ok([delta, vega, theta, rho]) =
+            tape_sensitivities(gbm, payoff, discount_factor, n_steps, &normal_sums)
+        else {
+            return self.compute_greeks_fd(gbm, payoff, discount_factor);
+        };
+
+        let gamma = self.compute_gamma_fd(gbm, payoff, discount_factor);
+
+        EnzymeGreeksResult::new(
+            base_result.price,
+            base_result.std_error,
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+        )
+    }
+
     /// Computes Delta using finite differences (central difference).
     fn compute_delta_fd(
         &mut self,
@@ -629,6 +685,58 @@ impl MonteCarloPricer {
     }
 }
 
+/// Records the discounted pathwise estimator on the tape and returns
+/// `[delta, vega, theta, rho]` from one backward sweep.
+///
+/// Each path's payoff is collapsed to a single node, so the tape grows
+/// by a constant number of nodes per path. The discount factor moves
+/// with the rate as in the Rho bump and is held fixed in time as in the
+/// Theta bump.
+#[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+fn tape_sensitivities(
+    gbm: GbmParams,
+    payoff: PayoffParams,
+    discount_factor: f64,
+    n_steps: usize,
+    normal_sums: &[f64],
+) -> Result<[f64; 4], TapeError> {
+    Tape::reset();
+    let spot = Tape::variable(gbm.spot);
+    let volatility = Tape::variable(gbm.volatility);
+    let rate = Tape::variable(gbm.rate);
+    let maturity = Tape::variable(gbm.maturity);
+
+    let half = AReal::constant(0.5);
+    let drift = (rate - half * volatility * volatility) * maturity;
+    let vol_sqrt_dt = volatility * (maturity / AReal::constant(n_steps as f64)).sqrt();
+    let strike = AReal::constant(payoff.strike);
+    let epsilon = AReal::constant(payoff.smoothing_epsilon);
+    let zero = AReal::constant(0.0);
+
+    let mut total = zero;
+    for &z in normal_sums {
+        let start = Tape::position();
+        let terminal = spot * (drift + vol_sqrt_dt * AReal::constant(z)).exp();
+        let intrinsic = match payoff.payoff_type {
+            PayoffType::Call => terminal - strike,
+            PayoffType::Put => strike - terminal,
+        };
+        let value = smooth_max(intrinsic, zero, epsilon);
+        total = total + Tape::collapse(start, &[spot, drift, vol_sqrt_dt], value)?;
+    }
+
+    let rate_shift = rate - AReal::constant(gbm.rate);
+    let discount = AReal::constant(discount_factor) * (-rate_shift * maturity).exp();
+    let price = total / AReal::constant(normal_sums.len() as f64) * discount;
+
+    let adjoints = Tape::gradient(price);
+    Tape::reset();
+    let gradient = adjoints?.wrt_all(&[spot, volatility, rate, maturity]);
+
+    // Theta is -dV/dT and Rho is scaled to a 1% rate move
+    Ok([gradient[0], gradient[1], -gradient[3], gradient[2] * 0.01])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -812,6 +920,28 @@ mod tests {
         assert!(rho > -1.0 && rho < 1.0); // Reasonable range for scaled rho
     }
 
+    #[test]
+    #[cfg(all(not(feature = "enzyme-ad"), feature = "l1l2-integration"))]
+    fn test_reverse_mode_tape_matches_finite_differences() {
+        let (gbm, payoff, df) = standard_params();
+
+        let tape =
+            create_pricer().price_with_enzyme_greeks(gbm, payoff, df, GreeksMode::ReverseMode);
+        let fd =
+            create_pricer().price_with_enzyme_greeks(gbm, payoff, df, GreeksMode::FiniteDifference);
+
+        assert_eq!(tape.price, fd.price);
+        assert!((tape.delta - fd.delta).abs() < 5e-3);
+        assert!((tape.vega - fd.vega).abs() < 0.5);
+        assert!((tape.theta - fd.theta).abs() < 0.1);
+        assert!((tape.rho - fd.rho).abs() < 5e-3);
+        assert_eq!(tape.gamma, fd.gamma);
+
+        // Black-Scholes: delta 0.6368, vega 37.52
+        assert!((tape.delta - 0.6368).abs() < 0.02);
+        assert!((tape.vega - 37.52).abs() < 1.5);
+    }
+
     #[test]
     fn test_greeks_conversion_from() {
         let enzyme_result = EnzymeGreeksResult::new(10.5, 0.05, 0.55, 0.02, 25.0, -10.0, 15.0);
@@ -0,0 +1,186 @@
+//! Finite-difference solver configuration.
+
+use super::grid::MIN_INTERVALS;
+use crate::mc::ConfigError;
+
+/// Finite-difference grid and time-stepping configuration.
+///
+/// # Examples
+///
+/// ```rust
+/// use pricer_pricing::fd::FdConfig;
+///
+/// let config = FdConfig::builder()
+///     .space_intervals(200)
+///     .time_steps(100)
+///     .rannacher_steps(2)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(config.variance_intervals(), 50);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FdConfig {
+    space_intervals: usize,
+    variance_intervals: usize,
+    time_steps: usize,
+    rannacher_steps: usize,
+    concentration: f64,
+}
+
+impl Default for FdConfig {
+    /// 200 spot (or rate) intervals, 50 variance intervals, 100 time
+    /// steps, 2 Rannacher steps and concentration 0.1.
+    fn default() -> Self {
+        Self {
+            space_intervals: 200,
+            variance_intervals: 50,
+            time_steps: 100,
+            rannacher_steps: 2,
+            concentration: 0.1,
+        }
+    }
+}
+
+impl FdConfig {
+    /// Creates a new configuration builder.
+    #[inline]
+    pub fn builder() -> FdConfigBuilder {
+        FdConfigBuilder::default()
+    }
+
+    /// Returns the number of intervals in the spot (or short-rate)
+    /// direction.
+    #[inline]
+    pub fn space_intervals(&self) -> usize {
+        self.space_intervals
+    }
+
+    /// Returns the number of intervals in the variance direction (2D only).
+    #[inline]
+    pub fn variance_intervals(&self) -> usize {
+        self.variance_intervals
+    }
+
+    /// Returns the number of time steps to maturity (event dates add
+    /// further steps).
+    #[inline]
+    pub fn time_steps(&self) -> usize {
+        self.time_steps
+    }
+
+    /// Returns the number of Crank-Nicolson steps replaced by two implicit
+    /// half steps after maturity and every non-smooth event.
+    #[inline]
+    pub fn rannacher_steps(&self) -> usize {
+        self.rannacher_steps
+    }
+
+    /// Returns the node clustering width relative to the grid width.
+    #[inline]
+    pub fn concentration(&self) -> f64 {
+        self.concentration
+    }
+
+    /// Validates the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if a grid has fewer than
+    /// four intervals, there are no time steps, or the concentration is
+    /// not positive.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (name, n) in [
+            ("space_intervals", self.space_intervals),
+            ("variance_intervals", self.variance_intervals),
+        ] {
+            if n < MIN_INTERVALS {
+                return Err(ConfigError::InvalidParameter {
+                    name,
+                    value: format!("{n} (minimum {MIN_INTERVALS})"),
+                });
+            }
+        }
+        if self.time_steps == 0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "time_steps",
+                value: "0".to_string(),
+            });
+        }
+        if !self.concentration.is_finite() || self.concentration <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "concentration",
+                value: self.concentration.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`FdConfig`]; unset fields keep their defaults.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FdConfigBuilder {
+    config: FdConfig,
+}
+
+impl FdConfigBuilder {
+    /// Sets the number of spot (or short-rate) intervals.
+    #[inline]
+    pub fn space_intervals(mut self, space_intervals: usize) -> Self {
+        self.config.space_intervals = space_intervals;
+        self
+    }
+
+    /// Sets the number of variance intervals of two-dimensional grids.
+    #[inline]
+    pub fn variance_intervals(mut self, variance_intervals: usize) -> Self {
+        self.config.variance_intervals = variance_intervals;
+        self
+    }
+
+    /// Sets the number of time steps to maturity.
+    #[inline]
+    pub fn time_steps(mut self, time_steps: usize) -> Self {
+        self.config.time_steps = time_steps;
+        self
+    }
+
+    /// Sets the number of Rannacher start-up steps (0 for pure
+    /// Crank-Nicolson).
+    #[inline]
+    pub fn rannacher_steps(mut self, rannacher_steps: usize) -> Self {
+        self.config.rannacher_steps = rannacher_steps;
+        self
+    }
+
+    /// Sets the node clustering width relative to the grid width.
+    #[inline]
+    pub fn concentration(mut self, concentration: f64) -> Self {
+        self.config.concentration = concentration;
+        self
+    }
+
+    /// Builds the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if any setting is invalid (see
+    /// [`FdConfig::validate`]).
+    pub fn build(self) -> Result<FdConfig, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_validation() {
+        assert_eq!(FdConfig::builder().build().unwrap(), FdConfig::default());
+        assert!(FdConfig::builder().space_intervals(3).build().is_err());
+        assert!(FdConfig::builder().time_steps(0).build().is_err());
+        assert!(FdConfig::builder().concentration(0.0).build().is_err());
+    }
+}
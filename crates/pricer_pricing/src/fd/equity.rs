@@ -0,0 +1,577 @@
+//! One-dimensional equity PDE: Black-Scholes and local volatility.
+//!
+//! The option value `V(t, S)` solves
+//!
+//! ```text
+//! ∂V/∂t + ½σ(t,S)²S²·∂²V/∂S² + (r − q)S·∂V/∂S − rV = 0
+//! ```
+//!
+//! on `[0, S_max]`, or between the barriers of a knock-out option. Discrete
+//! dividends come from the `pricer_core` equity market (with the
+//! `l1l2-integration` feature) and are applied as the jump condition
+//! `V(t_d⁻, S) = V(t_d⁺, S(1 − ρ) − D)` at the ex-date, so the spot drops by
+//! the cash amount `D` or the proportional ratio `ρ`.
+
+use super::config::FdConfig;
+use super::grid::{time_grid, Grid1D};
+use super::solver::{solve_backward, Boundary};
+use super::{FdExercise, FdResult};
+use crate::mc::{ConfigError, PayoffParams, PayoffType};
+#[cfg(feature = "l1l2-integration")]
+use pricer_core::market_data::curves::YieldCurve;
+#[cfg(feature = "l1l2-integration")]
+use pricer_core::market_data::equity::{Dividend, DividendSchedule, EquityMarket};
+
+/// Number of standard deviations covered by the default spot grid.
+const SPOT_GRID_STD_DEVS: f64 = 5.0;
+
+/// Local volatility `σ(t, S)`.
+///
+/// Implemented for `f64` (constant Black-Scholes volatility) and for any
+/// closure `Fn(t, S) -> σ`.
+pub trait LocalVolatility {
+    /// Volatility at time `time` and spot `spot`.
+    fn volatility(&self, time: f64, spot: f64) -> f64;
+}
+
+impl LocalVolatility for f64 {
+    #[inline]
+    fn volatility(&self, _time: f64, _spot: f64) -> f64 {
+        *self
+    }
+}
+
+impl<F: Fn(f64, f64) -> f64> LocalVolatility for F {
+    #[inline]
+    fn volatility(&self, time: f64, spot: f64) -> f64 {
+        self(time, spot)
+    }
+}
+
+/// Equity diffusion for the one-dimensional PDE.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::fd::{EquityPde, FdConfig, FdOption, FdPricer};
+/// use pricer_pricing::mc::PayoffParams;
+///
+/// // Local volatility with a simple skew
+/// let local_vol = |_t: f64, s: f64| 0.2 * (100.0 / s).powf(0.3);
+/// let model = EquityPde::new(100.0, 0.05, 0.0, local_vol);
+///
+/// let pricer = FdPricer::new(FdConfig::default()).unwrap();
+/// let result = pricer
+///     .price_equity(&model, &FdOption::american(PayoffParams::put(100.0), 1.0))
+///     .unwrap();
+/// assert!(result.price > 0.0);
+/// assert!(result.delta < 0.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquityPde<V = f64> {
+    /// Spot price.
+    pub spot: f64,
+    /// Continuously compounded risk-free rate.
+    pub rate: f64,
+    /// Continuous dividend yield (including repo and borrow).
+    pub dividend_yield: f64,
+    /// Local volatility surface; `f64` for Black-Scholes.
+    pub volatility: V,
+    /// Discrete dividends, applied as jumps at their ex-dates.
+    #[cfg(feature = "l1l2-integration")]
+    pub dividends: DividendSchedule<f64>,
+}
+
+impl<V: LocalVolatility> EquityPde<V> {
+    /// Creates the model without discrete dividends.
+    pub fn new(spot: f64, rate: f64, dividend_yield: f64, volatility: V) -> Self {
+        Self {
+            spot,
+            rate,
+            dividend_yield,
+            volatility,
+            #[cfg(feature = "l1l2-integration")]
+            dividends: DividendSchedule::new(),
+        }
+    }
+
+    /// Creates the model from an equity market for options expiring at
+    /// `maturity`.
+    ///
+    /// The discount curve and the continuous carry (dividend yield curve,
+    /// repo and borrow) are flattened to their average rates over
+    /// `[0, maturity]`; the market's cash and proportional dividends are
+    /// kept as jumps at their ex-dates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `maturity` is not
+    /// positive or the market curves cannot be evaluated at it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_core::market_data::curves::CurveEnum;
+    /// use pricer_core::market_data::equity::{DividendSchedule, EquityMarket};
+    /// use pricer_pricing::fd::{EquityPde, FdConfig, FdOption, FdPricer};
+    /// use pricer_pricing::mc::PayoffParams;
+    ///
+    /// let market = EquityMarket::new(100.0, CurveEnum::flat(0.05))
+    ///     .unwrap()
+    ///     .with_dividends(DividendSchedule::new().with_cash(0.5, 2.0).unwrap());
+    /// let model = EquityPde::from_market(&market, 1.0, 0.2).unwrap();
+    ///
+    /// let pricer = FdPricer::new(FdConfig::default()).unwrap();
+    /// let result = pricer
+    ///     .price_equity(&model, &FdOption::american(PayoffParams::put(100.0), 1.0))
+    ///     .unwrap();
+    /// assert!(result.price > 0.0);
+    /// ```
+    #[cfg(feature = "l1l2-integration")]
+    pub fn from_market(
+        market: &EquityMarket<f64>,
+        maturity: f64,
+        volatility: V,
+    ) -> Result<Self, ConfigError> {
+        if !maturity.is_finite() || maturity <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "maturity",
+                value: maturity.to_string(),
+            });
+        }
+        let market_error =
+            |e: pricer_core::market_data::MarketDataError| ConfigError::InvalidParameter {
+                name: "market",
+                value: e.to_string(),
+            };
+
+        let df_r = market.discount_factor(maturity).map_err(market_error)?;
+        let df_q = market
+            .dividend_curve()
+            .discount_factor(maturity)
+            .map_err(market_error)?;
+        let carry = market.repo_spread() - market.borrow_cost();
+        Ok(Self {
+            spot: market.spot(),
+            rate: -df_r.ln() / maturity,
+            dividend_yield: -df_q.ln() / maturity - carry,
+            volatility,
+            dividends: market.dividends().clone(),
+        })
+    }
+}
+
+/// Dividend jumps strictly before `maturity` as `(time, ratio, amount)`,
+/// mapping the cum-dividend spot `S` to `S(1 − ratio) − amount`.
+fn dividend_jumps<V>(model: &EquityPde<V>, maturity: f64) -> Vec<(f64, f64, f64)> {
+    #[cfg(feature = "l1l2-integration")]
+    {
+        model
+            .dividends
+            .between(0.0, maturity)
+            .filter(|d| d.ex_time() < maturity)
+            .map(|d| match *d {
+                Dividend::Cash { ex_time, amount } => (ex_time, 0.0, amount),
+                Dividend::Proportional { ex_time, ratio } => (ex_time, ratio, 0.0),
+            })
+            .collect()
+    }
+    #[cfg(not(feature = "l1l2-integration"))]
+    {
+        let _ = (model, maturity);
+        Vec::new()
+    }
+}
+
+/// Continuously monitored knock-out barriers.
+///
+/// The option dies with a `rebate` paid at the hitting time when the
+/// spot touches either barrier. Knock-in options follow from in-out
+/// parity with the vanilla price.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KnockOut {
+    /// Lower barrier, if any.
+    pub lower: Option<f64>,
+    /// Upper barrier, if any.
+    pub upper: Option<f64>,
+    /// Rebate paid on knock-out.
+    pub rebate: f64,
+}
+
+impl KnockOut {
+    /// Down-and-out barrier.
+    #[inline]
+    pub fn down(barrier: f64, rebate: f64) -> Self {
+        Self {
+            lower: Some(barrier),
+            upper: None,
+            rebate,
+        }
+    }
+
+    /// Up-and-out barrier.
+    #[inline]
+    pub fn up(barrier: f64, rebate: f64) -> Self {
+        Self {
+            lower: None,
+            upper: Some(barrier),
+            rebate,
+        }
+    }
+
+    /// Double knock-out barrier.
+    #[inline]
+    pub fn double(lower: f64, upper: f64, rebate: f64) -> Self {
+        Self {
+            lower: Some(lower),
+            upper: Some(upper),
+            rebate,
+        }
+    }
+}
+
+/// Vanilla call or put with optional early exercise and knock-out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FdOption {
+    /// Strike and call/put type.
+    pub payoff: PayoffParams,
+    /// Maturity in years.
+    pub maturity: f64,
+    /// Exercise rights.
+    pub exercise: FdExercise,
+    /// Continuous knock-out barriers, if any.
+    pub knock_out: Option<KnockOut>,
+}
+
+impl FdOption {
+    /// European option.
+    pub fn european(payoff: PayoffParams, maturity: f64) -> Self {
+        Self {
+            payoff,
+            maturity,
+            exercise: FdExercise::European,
+            knock_out: None,
+        }
+    }
+
+    /// American option.
+    pub fn american(payoff: PayoffParams, maturity: f64) -> Self {
+        Self {
+            exercise: FdExercise::American,
+            ..Self::european(payoff, maturity)
+        }
+    }
+
+    /// Bermudan option exercisable at `exercise_dates` and at maturity.
+    pub fn bermudan(payoff: PayoffParams, maturity: f64, exercise_dates: Vec<f64>) -> Self {
+        Self {
+            exercise: FdExercise::Bermudan(exercise_dates),
+            ..Self::european(payoff, maturity)
+        }
+    }
+
+    /// Adds continuous knock-out barriers.
+    pub fn with_knock_out(mut self, knock_out: KnockOut) -> Self {
+        self.knock_out = Some(knock_out);
+        self
+    }
+
+    /// Hard intrinsic value.
+    #[inline]
+    pub(crate) fn intrinsic(&self, spot: f64) -> f64 {
+        match self.payoff.payoff_type {
+            PayoffType::Call => (spot - self.payoff.strike).max(0.0),
+            PayoffType::Put => (self.payoff.strike - spot).max(0.0),
+        }
+    }
+
+    /// Checks maturity, strike, barriers against `spot`, and exercise
+    /// dates.
+    pub(crate) fn validate(&self, spot: f64) -> Result<(), ConfigError> {
+        if !self.maturity.is_finite() || self.maturity <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "maturity",
+                value: self.maturity.to_string(),
+            });
+        }
+        if !self.payoff.strike.is_finite() || self.payoff.strike <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "strike",
+                value: self.payoff.strike.to_string(),
+            });
+        }
+        if let Some(knock_out) = self.knock_out {
+            let lower = knock_out.lower.unwrap_or(0.0);
+            let upper = knock_out.upper.unwrap_or(f64::INFINITY);
+            if !(lower < spot && spot < upper) {
+                return Err(ConfigError::InvalidParameter {
+                    name: "knock_out",
+                    value: format!("spot {spot} is not strictly between the barriers"),
+                });
+            }
+        }
+        self.exercise.validate(self.maturity)
+    }
+
+    /// Spot grid bounds and boundary types for `spot` and reference
+    /// volatility `sigma`.
+    pub(crate) fn spot_domain(
+        &self,
+        spot: f64,
+        drift: f64,
+        sigma: f64,
+    ) -> ([f64; 2], [Boundary; 2], Vec<f64>) {
+        let reach = SPOT_GRID_STD_DEVS * sigma.max(0.05) * self.maturity.sqrt()
+            + drift.abs() * self.maturity;
+        let s_max = spot.max(self.payoff.strike) * reach.exp();
+        let (lower, lower_boundary) = match self.knock_out.and_then(|k| k.lower) {
+            Some(barrier) => (barrier, Boundary::Fixed),
+            None => (0.0, Boundary::Natural),
+        };
+        let (upper, upper_boundary) = match self.knock_out.and_then(|k| k.upper) {
+            Some(barrier) => (barrier, Boundary::Fixed),
+            None => (s_max, Boundary::Natural),
+        };
+        let mut points = vec![self.payoff.strike, spot];
+        points.extend(
+            self.knock_out
+                .iter()
+                .flat_map(|k| k.lower.into_iter().chain(k.upper)),
+        );
+        ([lower, upper], [lower_boundary, upper_boundary], points)
+    }
+
+    /// Terminal values on `nodes`, with barrier nodes set to the rebate.
+    pub(crate) fn terminal_values(&self, nodes: &[f64], boundaries: [Boundary; 2]) -> Vec<f64> {
+        let mut values: Vec<f64> = nodes.iter().map(|&s| self.intrinsic(s)).collect();
+        let rebate = self.knock_out.map_or(0.0, |k| k.rebate);
+        if boundaries[0] == Boundary::Fixed {
+            values[0] = rebate;
+        }
+        if boundaries[1] == Boundary::Fixed {
+            *values.last_mut().unwrap() = rebate;
+        }
+        values
+    }
+}
+
+/// Prices `option` under `model` on a one-dimensional grid.
+pub(crate) fn price<V: LocalVolatility>(
+    config: &FdConfig,
+    model: &EquityPde<V>,
+    option: &FdOption,
+) -> Result<FdResult, ConfigError> {
+    option.validate(model.spot)?;
+    if !model.spot.is_finite() || model.spot <= 0.0 {
+        return Err(ConfigError::InvalidParameter {
+            name: "spot",
+            value: model.spot.to_string(),
+        });
+    }
+    let (r, q) = (model.rate, model.dividend_yield);
+    let sigma = model.volatility.volatility(0.0, model.spot);
+    let ([lower, upper], boundaries, points) = option.spot_domain(model.spot, r - q, sigma);
+    let grid = Grid1D::concentrated(
+        lower,
+        upper,
+        config.space_intervals(),
+        &points,
+        config.concentration(),
+    )?;
+    let nodes = grid.nodes();
+
+    let mut events = option.exercise.dates().to_vec();
+    let dividends = dividend_jumps(model, option.maturity);
+    events.extend(dividends.iter().map(|d| d.0));
+    let times = time_grid(option.maturity, config.time_steps(), &events);
+
+    let rebate = option.knock_out.map_or(0.0, |k| k.rebate);
+    let mut shifted = vec![0.0; nodes.len()];
+    let event = |t: f64, values: &mut [f64]| {
+        let mut restart = false;
+        for &(_, ratio, amount) in dividends.iter().filter(|d| (d.0 - t).abs() < 1e-12) {
+            for (i, &s) in nodes.iter().enumerate() {
+                let ex = s * (1.0 - ratio) - amount;
+                shifted[i] = match grid.linear(values, ex) {
+                    Some(v) => v,
+                    // Below a knock-out barrier: knocked out by the drop
+                    None if boundaries[0] == Boundary::Fixed => rebate,
+                    None => values[0],
+                };
+            }
+            values.copy_from_slice(&shifted);
+            restart = true;
+        }
+        if option.exercise.is_exercise_time(t) {
+            for (v, &s) in values.iter_mut().zip(nodes) {
+                *v = v.max(option.intrinsic(s));
+            }
+            restart |= option.exercise != FdExercise::American;
+        }
+        restart
+    };
+
+    let solution = solve_backward(
+        &grid,
+        boundaries,
+        &times,
+        config.rannacher_steps(),
+        |t, s| {
+            let vol = model.volatility.volatility(t, s);
+            (0.5 * vol * vol * s * s, (r - q) * s, r)
+        },
+        option.terminal_values(nodes, boundaries),
+        event,
+    );
+
+    Ok(FdResult::from_grid(&grid, &solution, model.spot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::asian::norm_cdf;
+    use crate::analytical::barrier::down_out_call;
+    use approx::assert_relative_eq;
+
+    fn black_scholes(spot: f64, strike: f64, r: f64, q: f64, sigma: f64, t: f64) -> [f64; 4] {
+        let sqrt_t = t.sqrt();
+        let d1 = ((spot / strike).ln() + (r - q + 0.5 * sigma * sigma) * t) / (sigma * sqrt_t);
+        let d2 = d1 - sigma * sqrt_t;
+        let df_q = (-q * t).exp();
+        let pdf = (-0.5 * d1 * d1).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let call = spot * df_q * norm_cdf(d1) - strike * (-r * t).exp() * norm_cdf(d2);
+        let put = call - spot * df_q + strike * (-r * t).exp();
+        let delta = df_q * norm_cdf(d1);
+        let gamma = df_q * pdf / (spot * sigma * sqrt_t);
+        [call, put, delta, gamma]
+    }
+
+    fn pricer() -> super::super::FdPricer {
+        super::super::FdPricer::new(FdConfig::default()).unwrap()
+    }
+
+    #[test]
+    fn test_european_matches_black_scholes() {
+        let model = EquityPde::new(100.0, 0.05, 0.02, 0.2);
+        let [call, put, delta, gamma] = black_scholes(100.0, 105.0, 0.05, 0.02, 0.2, 1.0);
+
+        let result = pricer()
+            .price_equity(&model, &FdOption::european(PayoffParams::call(105.0), 1.0))
+            .unwrap();
+        assert_relative_eq!(result.price, call, max_relative = 1e-3);
+        assert_relative_eq!(result.delta, delta, max_relative = 1e-3);
+        assert_relative_eq!(result.gamma, gamma, max_relative = 1e-2);
+        assert!(result.theta < 0.0);
+
+        let result = pricer()
+            .price_equity(&model, &FdOption::european(PayoffParams::put(105.0), 1.0))
+            .unwrap();
+        assert_relative_eq!(result.price, put, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn test_american_put() {
+        // S=36, K=40, r=0.06, sigma=0.2, T=1: binomial value 4.4867
+        let model = EquityPde::new(36.0, 0.06, 0.0, 0.2);
+        let fine = FdConfig::builder()
+            .space_intervals(400)
+            .time_steps(400)
+            .build()
+            .unwrap();
+        let result = super::super::FdPricer::new(fine)
+            .unwrap()
+            .price_equity(&model, &FdOption::american(PayoffParams::put(40.0), 1.0))
+            .unwrap();
+        assert!((result.price - 4.4867).abs() < 2e-3, "{}", result.price);
+
+        // Bermudan lies between European and American
+        let bermudan = pricer()
+            .price_equity(
+                &model,
+                &FdOption::bermudan(PayoffParams::put(40.0), 1.0, vec![0.25, 0.5, 0.75]),
+            )
+            .unwrap();
+        let [_, european, _, _] = black_scholes(36.0, 40.0, 0.06, 0.0, 0.2, 1.0);
+        assert!(bermudan.price > european + 0.1);
+        assert!(bermudan.price < result.price);
+    }
+
+    #[test]
+    fn test_down_and_out_call() {
+        let model = EquityPde::new(100.0, 0.05, 0.0, 0.25);
+        let option = FdOption::european(PayoffParams::call(100.0), 1.0)
+            .with_knock_out(KnockOut::down(90.0, 0.0));
+        let result = pricer().price_equity(&model, &option).unwrap();
+        let exact = down_out_call(100.0, 100.0, 90.0, 0.05, 0.0, 0.25, 1.0);
+        assert_relative_eq!(result.price, exact, max_relative = 2e-3);
+
+        let knocked = KnockOut::down(101.0, 0.0);
+        let option = FdOption::european(PayoffParams::call(100.0), 1.0).with_knock_out(knocked);
+        assert!(pricer().price_equity(&model, &option).is_err());
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_market_dividends_lower_call() {
+        use pricer_core::market_data::curves::CurveEnum;
+
+        // A dividend just after time zero is a spot shift of its amount
+        let cash = DividendSchedule::new().with_cash(1e-3, 3.0).unwrap();
+        let market = EquityMarket::new(100.0, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_dividends(cash);
+        let model = EquityPde::from_market(&market, 1.0, 0.2).unwrap();
+        let option = FdOption::european(PayoffParams::call(100.0), 1.0);
+        let result = pricer().price_equity(&model, &option).unwrap();
+        let [call, _, delta, _] = black_scholes(97.0, 100.0, 0.05, 0.0, 0.2, 1.0);
+        assert_relative_eq!(result.price, call, max_relative = 5e-3);
+        assert_relative_eq!(result.delta, delta, max_relative = 1e-2);
+
+        // ...and a proportional dividend scales the spot
+        let proportional = DividendSchedule::new()
+            .with_proportional(1e-3, 0.03)
+            .unwrap();
+        let market = market.with_dividends(proportional);
+        let model = EquityPde::from_market(&market, 1.0, 0.2).unwrap();
+        let result = pricer().price_equity(&model, &option).unwrap();
+        assert_relative_eq!(result.price, call, max_relative = 5e-3);
+        assert_relative_eq!(result.delta, 0.97 * delta, max_relative = 1e-2);
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_from_market_carry_matches_flat_rates() {
+        use pricer_core::market_data::curves::CurveEnum;
+
+        let market = EquityMarket::new(100.0, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_dividend_yield(0.03)
+            .with_repo_spread(0.005)
+            .with_borrow_cost(0.015);
+        let model = EquityPde::from_market(&market, 2.0, 0.2).unwrap();
+        assert_relative_eq!(model.rate, 0.05, epsilon = 1e-12);
+        assert_relative_eq!(model.dividend_yield, 0.04, epsilon = 1e-12);
+        assert_relative_eq!(
+            model.spot * ((model.rate - model.dividend_yield) * 2.0).exp(),
+            market.forward(2.0).unwrap(),
+            max_relative = 1e-12
+        );
+        assert!(EquityPde::from_market(&market, 0.0, 0.2).is_err());
+    }
+
+    #[test]
+    fn test_constant_local_vol_matches_black_scholes() {
+        let option = FdOption::european(PayoffParams::put(95.0), 0.5);
+        let flat = pricer()
+            .price_equity(&EquityPde::new(100.0, 0.03, 0.0, 0.3), &option)
+            .unwrap();
+        let local = pricer()
+            .price_equity(
+                &EquityPde::new(100.0, 0.03, 0.0, |_: f64, _: f64| 0.3),
+                &option,
+            )
+            .unwrap();
+        assert_relative_eq!(flat.price, local.price, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,367 @@
+//! Two-factor G2++ short-rate PDE.
+//!
+//! With `r(t) = x(t) + y(t) + φ(t)`, `dx = −a·x·dt + σ·dW₁`,
+//! `dy = −b·y·dt + η·dW₂` and `dW₁·dW₂ = ρ·dt`, the value `V(t, x, y)` of
+//! a claim solves
+//!
+//! ```text
+//! ∂V/∂t + ½σ²·V_xx + ρση·V_xy + ½η²·V_yy − a·x·V_x − b·y·V_y − (x + y + φ(t))·V = 0
+//! ```
+//!
+//! The deterministic shift `φ(t)` only discounts, so the spatial operator
+//! is time-homogeneous: it is assembled once and stepped by Douglas ADI
+//! (see [`adi`](super::adi)), and each step is multiplied by the exact
+//! factor `exp(−∫φ)`. With a flat initial curve at `r₀` this factor is
+//! `e^{−r₀Δt}·exp(−½[V(0, t₁) − V(0, t₀)])`, with `V(t, T)` the variance
+//! of `∫_t^T (x + y) du`, so today's discount factors are fitted exactly.
+
+use super::adi::{slice_at, AdiOperator, Work};
+use super::config::FdConfig;
+use super::grid::{time_grid, Grid1D};
+use super::solver::{Boundary, Solution, Tridiagonal};
+use super::{FdExercise, FdResult};
+use crate::mc::ConfigError;
+
+/// Standard deviations of each factor covered by the grid.
+const FACTOR_GRID_STD_DEVS: f64 = 7.0;
+
+/// G2++ model fitted to a flat initial curve.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::fd::{FdConfig, FdExercise, FdPricer, G2PlusPlusPde};
+///
+/// let model = G2PlusPlusPde::new(0.5, 0.01, 0.05, 0.008, -0.7, 0.03).unwrap();
+/// let config = FdConfig::builder()
+///     .space_intervals(60)
+///     .variance_intervals(60)
+///     .time_steps(50)
+///     .build()
+///     .unwrap();
+/// let pricer = FdPricer::new(config).unwrap();
+///
+/// // 1y put on a 5y zero-coupon bond
+/// let strike = 0.89;
+/// let put = pricer
+///     .price_g2pp(&model, 1.0, &FdExercise::European, |t, x, y| {
+///         (strike - model.bond(t, 5.0, x, y)).max(0.0)
+///     })
+///     .unwrap();
+/// assert!(put.price > 0.0);
+/// assert!(put.delta > 0.0); // the put gains as rates rise
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct G2PlusPlusPde {
+    a: f64,
+    sigma: f64,
+    b: f64,
+    eta: f64,
+    rho: f64,
+    initial_rate: f64,
+}
+
+impl G2PlusPlusPde {
+    /// Creates a G2++ model with factor mean reversions `a`, `b`,
+    /// volatilities `σ`, `η`, correlation `ρ` and flat initial curve at
+    /// `initial_rate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if a mean reversion or
+    /// volatility is not positive, `|ρ| ≥ 1` or any input is not finite.
+    pub fn new(
+        a: f64,
+        sigma: f64,
+        b: f64,
+        eta: f64,
+        rho: f64,
+        initial_rate: f64,
+    ) -> Result<Self, ConfigError> {
+        for (name, value, valid) in [
+            ("a", a, a > 0.0),
+            ("sigma", sigma, sigma > 0.0),
+            ("b", b, b > 0.0),
+            ("eta", eta, eta > 0.0),
+            ("rho", rho, rho.abs() < 1.0),
+            ("initial_rate", initial_rate, true),
+        ] {
+            if !value.is_finite() || !valid {
+                return Err(ConfigError::InvalidParameter {
+                    name,
+                    value: value.to_string(),
+                });
+            }
+        }
+        Ok(Self {
+            a,
+            sigma,
+            b,
+            eta,
+            rho,
+            initial_rate,
+        })
+    }
+
+    /// Returns the initial short rate.
+    #[inline]
+    pub fn initial_rate(&self) -> f64 {
+        self.initial_rate
+    }
+
+    /// Zero-coupon bond price `P(t, T)` given the factors `x`, `y` at `t`.
+    pub fn bond(&self, t: f64, maturity: f64, x: f64, y: f64) -> f64 {
+        let tau = maturity - t;
+        let convexity = 0.5
+            * (self.variance(t, maturity) - self.variance(0.0, maturity) + self.variance(0.0, t));
+        (-self.initial_rate * tau + convexity
+            - b_factor(self.a, tau) * x
+            - b_factor(self.b, tau) * y)
+            .exp()
+    }
+
+    /// Variance `V(t, T)` of `∫_t^T (x + y) du` given the factors at `t`.
+    fn variance(&self, t: f64, maturity: f64) -> f64 {
+        let tau = maturity - t;
+        let (a, b) = (self.a, self.b);
+        let single = |k: f64, s: f64| {
+            s * s / (k * k)
+                * (tau + 2.0 / k * (-k * tau).exp() - (-2.0 * k * tau).exp() / (2.0 * k) - 1.5 / k)
+        };
+        let cross = 2.0 * self.rho * self.sigma * self.eta / (a * b)
+            * (tau - b_factor(a, tau) - b_factor(b, tau) + b_factor(a + b, tau));
+        single(a, self.sigma) + single(b, self.eta) + cross
+    }
+
+    /// Discount factor `exp(−∫_{t₀}^{t₁} φ(u) du)` of the shift.
+    fn shift_discount(&self, t0: f64, t1: f64) -> f64 {
+        (-self.initial_rate * (t1 - t0) - 0.5 * (self.variance(0.0, t1) - self.variance(0.0, t0)))
+            .exp()
+    }
+
+    /// Standard deviations of `x(t)` and `y(t)`.
+    fn factor_std_devs(&self, t: f64) -> (f64, f64) {
+        let sd = |k: f64, s: f64| s * ((1.0 - (-2.0 * k * t).exp()) / (2.0 * k)).sqrt();
+        (sd(self.a, self.sigma), sd(self.b, self.eta))
+    }
+}
+
+/// `B_k(τ) = (1 − e^{−kτ}) / k`.
+#[inline]
+fn b_factor(k: f64, tau: f64) -> f64 {
+    (1.0 - (-k * tau).exp()) / k
+}
+
+/// G2++ operator on the tensor grid `x × y`.
+fn operator<'a>(model: &G2PlusPlusPde, x_grid: &'a Grid1D, y_grid: &'a Grid1D) -> AdiOperator<'a> {
+    let (x, y) = (x_grid.nodes(), y_grid.nodes());
+    let (nx, ny) = (x.len(), y.len());
+    let (a, b) = (model.a, model.b);
+
+    // Every x line carries the same operator, and likewise for y
+    let mut a1 = Tridiagonal::zeros(nx);
+    a1.assemble(x_grid, [Boundary::Natural; 2], |j| {
+        (0.5 * model.sigma * model.sigma, -a * x[j], x[j])
+    });
+    let mut a2 = Tridiagonal::zeros(ny);
+    a2.assemble(y_grid, [Boundary::Natural; 2], |k| {
+        (0.5 * model.eta * model.eta, -b * y[k], y[k])
+    });
+
+    let mut mixed = vec![0.0; nx * ny];
+    for k in 1..ny - 1 {
+        for j in 1..nx - 1 {
+            mixed[k * nx + j] = model.rho * model.sigma * model.eta;
+        }
+    }
+
+    AdiOperator::new(x_grid, y_grid, vec![a1; ny], vec![a2; nx], mixed)
+}
+
+/// Prices a short-rate claim on an `x × y` grid.
+pub(crate) fn price(
+    config: &FdConfig,
+    model: &G2PlusPlusPde,
+    maturity: f64,
+    exercise: &FdExercise,
+    exercise_value: impl Fn(f64, f64, f64) -> f64,
+) -> Result<FdResult, ConfigError> {
+    if !maturity.is_finite() || maturity <= 0.0 {
+        return Err(ConfigError::InvalidParameter {
+            name: "maturity",
+            value: maturity.to_string(),
+        });
+    }
+    exercise.validate(maturity)?;
+
+    let (sd_x, sd_y) = model.factor_std_devs(maturity);
+    let factor_grid = |sd: f64, intervals: usize| {
+        let half_width = FACTOR_GRID_STD_DEVS * sd;
+        Grid1D::concentrated(
+            -half_width,
+            half_width,
+            intervals,
+            &[0.0],
+            config.concentration(),
+        )
+    };
+    let x_grid = factor_grid(sd_x, config.space_intervals())?;
+    let y_grid = factor_grid(sd_y, config.variance_intervals())?;
+    let (x, y) = (x_grid.nodes(), y_grid.nodes());
+    let nx = x.len();
+
+    let operator = operator(model, &x_grid, &y_grid);
+    let exercise_value = &exercise_value;
+    let mut values: Vec<f64> = y
+        .iter()
+        .flat_map(|&yk| x.iter().map(move |&xj| exercise_value(maturity, xj, yk)))
+        .collect();
+    let mut work = Work::new(nx, y.len());
+
+    let times = time_grid(maturity, config.time_steps(), exercise.dates());
+    let mut next_values = values.clone();
+    let mut remaining = config.rannacher_steps();
+    for k in (0..times.len() - 1).rev() {
+        let (t0, t1) = (times[k], times[k + 1]);
+        operator.step(t1 - t0, remaining > 0, &mut values, &mut work);
+        remaining = remaining.saturating_sub(1);
+        let discount = model.shift_discount(t0, t1);
+        values.iter_mut().for_each(|v| *v *= discount);
+
+        if exercise.is_exercise_time(t0) {
+            for (i, v) in values.iter_mut().enumerate() {
+                *v = v.max(exercise_value(t0, x[i % nx], y[i / nx]));
+            }
+            if *exercise != FdExercise::American {
+                remaining = config.rannacher_steps();
+            }
+        }
+        if k == 1 {
+            next_values.copy_from_slice(&values);
+        }
+    }
+
+    // Interpolate in y at zero, then take x Greeks on the slice
+    let solution = Solution {
+        values: slice_at(&x_grid, &y_grid, &values, 0.0),
+        next_values: slice_at(&x_grid, &y_grid, &next_values, 0.0),
+        next_time: times[1],
+    };
+    Ok(FdResult::from_grid(&x_grid, &solution, 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::asian::norm_cdf;
+    use crate::fd::{FdPricer, HullWhitePde};
+    use approx::assert_relative_eq;
+
+    fn model() -> G2PlusPlusPde {
+        G2PlusPlusPde::new(0.5, 0.012, 0.05, 0.008, -0.7, 0.03).unwrap()
+    }
+
+    fn pricer() -> FdPricer {
+        let config = FdConfig::builder()
+            .space_intervals(80)
+            .variance_intervals(80)
+            .time_steps(100)
+            .build()
+            .unwrap();
+        FdPricer::new(config).unwrap()
+    }
+
+    /// Closed-form put on a zero-coupon bond (Brigo-Mercurio 4.31).
+    fn bond_put(model: &G2PlusPlusPde, expiry: f64, bond_maturity: f64, strike: f64) -> f64 {
+        let (a, b, sigma, eta, rho) = (model.a, model.b, model.sigma, model.eta, model.rho);
+        let tau = bond_maturity - expiry;
+        let (ba, bb) = (b_factor(a, tau), b_factor(b, tau));
+        let variance = sigma * sigma * ba * ba * b_factor(2.0 * a, expiry)
+            + eta * eta * bb * bb * b_factor(2.0 * b, expiry)
+            + 2.0 * rho * sigma * eta * ba * bb * b_factor(a + b, expiry);
+        let std = variance.sqrt();
+        let p_t = (-model.initial_rate * expiry).exp();
+        let p_s = (-model.initial_rate * bond_maturity).exp();
+        let d1 = (p_s / (strike * p_t)).ln() / std + 0.5 * std;
+        strike * p_t * norm_cdf(std - d1) - p_s * norm_cdf(-d1)
+    }
+
+    #[test]
+    fn test_zero_coupon_bond_matches_curve() {
+        let g2 = model();
+        let result = pricer()
+            .price_g2pp(&g2, 5.0, &FdExercise::European, |_, _, _| 1.0)
+            .unwrap();
+        assert_relative_eq!(result.price, (-0.15_f64).exp(), max_relative = 1e-4);
+        // dP/dx = -B_a(0, T) P
+        assert_relative_eq!(
+            result.delta,
+            -b_factor(0.5, 5.0) * result.price,
+            max_relative = 1e-2
+        );
+        assert_relative_eq!(
+            g2.bond(0.0, 5.0, 0.0, 0.0),
+            (-0.15_f64).exp(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_bond_option_matches_closed_form() {
+        let g2 = model();
+        let strike = (-0.03 * 4.0_f64).exp();
+        let put = pricer()
+            .price_g2pp(&g2, 1.0, &FdExercise::European, |t, x, y| {
+                (strike - g2.bond(t, 5.0, x, y)).max(0.0)
+            })
+            .unwrap();
+        let expected = bond_put(&g2, 1.0, 5.0, strike);
+        assert_relative_eq!(put.price, expected, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn test_bermudan_exceeds_european_and_matches_hull_white() {
+        // Right to deliver the 5y bond for 0.88 on any exercise date
+        let dates = vec![0.5, 1.0, 1.5];
+        let g2 = model();
+        let value = |t: f64, x: f64, y: f64| (0.88 - g2.bond(t, 5.0, x, y)).max(0.0);
+        let european = pricer()
+            .price_g2pp(&g2, 2.0, &FdExercise::European, value)
+            .unwrap();
+        let bermudan = pricer()
+            .price_g2pp(&g2, 2.0, &FdExercise::Bermudan(dates.clone()), value)
+            .unwrap();
+        assert!(bermudan.price > european.price + 1e-4);
+
+        // A negligible second factor leaves one-factor Hull-White
+        let (a, sigma) = (0.1, 0.01);
+        let degenerate = G2PlusPlusPde::new(a, sigma, 0.3, 1e-6, 0.0, 0.03).unwrap();
+        let hw = HullWhitePde::new(a, sigma, 0.03).unwrap();
+        let g2_price = pricer()
+            .price_g2pp(
+                &degenerate,
+                2.0,
+                &FdExercise::Bermudan(dates.clone()),
+                |t, x, y| (0.88 - degenerate.bond(t, 5.0, x, y)).max(0.0),
+            )
+            .unwrap();
+        let hw_price = FdPricer::new(FdConfig::default())
+            .unwrap()
+            .price_hull_white(&hw, 2.0, &FdExercise::Bermudan(dates), |t, r| {
+                (0.88 - hw.bond(t, 5.0, r)).max(0.0)
+            })
+            .unwrap();
+        assert_relative_eq!(g2_price.price, hw_price.price, max_relative = 2e-3);
+    }
+
+    #[test]
+    fn test_rejects_invalid_inputs() {
+        assert!(G2PlusPlusPde::new(0.0, 0.01, 0.05, 0.008, -0.7, 0.03).is_err());
+        assert!(G2PlusPlusPde::new(0.5, 0.01, 0.05, -0.008, -0.7, 0.03).is_err());
+        assert!(G2PlusPlusPde::new(0.5, 0.01, 0.05, 0.008, 1.0, 0.03).is_err());
+        assert!(G2PlusPlusPde::new(0.5, 0.01, 0.05, 0.008, -0.7, f64::NAN).is_err());
+        assert!(pricer()
+            .price_g2pp(&model(), 0.0, &FdExercise::European, |_, _, _| 1.0)
+            .is_err());
+    }
+}
@@ -0,0 +1,309 @@
+//! Spatial and temporal grids for the finite-difference engine.
+//!
+//! Spatial grids may be non-uniform: [`Grid1D::concentrated`] places nodes
+//! with density
+//!
+//! ```text
+//! f(x) = 1 + Σ_k 1 / (1 + ((x − c_k) / (α·w))²)
+//! ```
+//!
+//! around critical points `c_k` (strike, barrier, spot), where `w` is the
+//! grid width and `α` the concentration. Derivatives use the standard
+//! three-point stencils for non-uniform spacing, which stay second-order
+//! accurate on smoothly varying grids.
+
+use crate::mc::ConfigError;
+
+/// Minimum number of intervals of a spatial grid.
+pub const MIN_INTERVALS: usize = 4;
+
+/// Strictly increasing spatial grid.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::fd::Grid1D;
+///
+/// let grid = Grid1D::concentrated(0.0, 400.0, 100, &[100.0], 0.1).unwrap();
+/// let nodes = grid.nodes();
+/// assert_eq!(nodes.len(), 101);
+/// assert_eq!(nodes[0], 0.0);
+/// assert_eq!(nodes[100], 400.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid1D {
+    nodes: Vec<f64>,
+}
+
+impl Grid1D {
+    /// Creates a grid from explicit nodes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if there are fewer than
+    /// `MIN_INTERVALS + 1` nodes or they are not finite and strictly
+    /// increasing.
+    pub fn from_nodes(nodes: Vec<f64>) -> Result<Self, ConfigError> {
+        if nodes.len() < MIN_INTERVALS + 1
+            || nodes.iter().any(|x| !x.is_finite())
+            || nodes.windows(2).any(|w| w[1] <= w[0])
+        {
+            return Err(ConfigError::InvalidParameter {
+                name: "grid",
+                value: format!(
+                    "{} nodes (needs at least {} finite, strictly increasing)",
+                    nodes.len(),
+                    MIN_INTERVALS + 1
+                ),
+            });
+        }
+        Ok(Self { nodes })
+    }
+
+    /// Creates a uniform grid of `n_intervals` intervals on `[lower, upper]`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`from_nodes`](Self::from_nodes).
+    pub fn uniform(lower: f64, upper: f64, n_intervals: usize) -> Result<Self, ConfigError> {
+        let h = (upper - lower) / n_intervals as f64;
+        let mut nodes: Vec<f64> = (0..=n_intervals).map(|i| lower + h * i as f64).collect();
+        if let Some(last) = nodes.last_mut() {
+            *last = upper;
+        }
+        Self::from_nodes(nodes)
+    }
+
+    /// Creates a grid of `n_intervals` intervals on `[lower, upper]`,
+    /// concentrated around `points`.
+    ///
+    /// `concentration` is the width of each cluster relative to the grid
+    /// width; smaller values cluster more tightly. Points outside the
+    /// grid are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `concentration` is not
+    /// positive, or as [`from_nodes`](Self::from_nodes).
+    pub fn concentrated(
+        lower: f64,
+        upper: f64,
+        n_intervals: usize,
+        points: &[f64],
+        concentration: f64,
+    ) -> Result<Self, ConfigError> {
+        if !concentration.is_finite() || concentration <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "concentration",
+                value: concentration.to_string(),
+            });
+        }
+        let width = upper - lower;
+        if !width.is_finite() || width <= 0.0 {
+            return Self::from_nodes(vec![lower, upper]);
+        }
+        let scale = concentration * width;
+        let centres: Vec<f64> = points
+            .iter()
+            .copied()
+            .filter(|&c| c >= lower && c <= upper)
+            .collect();
+        let density = |x: f64| {
+            1.0 + centres
+                .iter()
+                .map(|&c| 1.0 / (1.0 + ((x - c) / scale).powi(2)))
+                .sum::<f64>()
+        };
+
+        // Cumulative density on a fine mesh (trapezoidal rule), inverted
+        // by linear interpolation at equally spaced levels
+        let n_fine = 20 * n_intervals + 1_000;
+        let h = width / n_fine as f64;
+        let mut cumulative = Vec::with_capacity(n_fine + 1);
+        cumulative.push(0.0);
+        let mut previous = density(lower);
+        for i in 1..=n_fine {
+            let current = density(lower + h * i as f64);
+            cumulative.push(cumulative[i - 1] + 0.5 * h * (previous + current));
+            previous = current;
+        }
+        let total = cumulative[n_fine];
+
+        let mut nodes = Vec::with_capacity(n_intervals + 1);
+        nodes.push(lower);
+        let mut j = 0;
+        for i in 1..n_intervals {
+            let level = total * i as f64 / n_intervals as f64;
+            while cumulative[j + 1] < level {
+                j += 1;
+            }
+            let w = (level - cumulative[j]) / (cumulative[j + 1] - cumulative[j]);
+            nodes.push(lower + h * (j as f64 + w));
+        }
+        nodes.push(upper);
+        Self::from_nodes(nodes)
+    }
+
+    /// Returns the grid nodes.
+    #[inline]
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    /// Returns the number of nodes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the grid has no nodes (never, for a valid grid).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Index of the centre of the three-node stencil closest to `x`.
+    pub(crate) fn stencil_centre(&self, x: f64) -> usize {
+        let upper = self.nodes.partition_point(|&node| node < x);
+        let nearest = if upper == self.nodes.len()
+            || (upper > 0 && x - self.nodes[upper - 1] < self.nodes[upper] - x)
+        {
+            upper - 1
+        } else {
+            upper
+        };
+        nearest.clamp(1, self.nodes.len() - 2)
+    }
+
+    /// First- and second-derivative weights of the three-node stencil
+    /// centred at interior node `i`.
+    pub(crate) fn weights(&self, i: usize) -> ([f64; 3], [f64; 3]) {
+        stencil_weights(
+            self.nodes[i - 1],
+            self.nodes[i],
+            self.nodes[i + 1],
+            self.nodes[i],
+        )
+    }
+
+    /// Value, first and second derivative at `x` from the quadratic
+    /// through the three nodes nearest to `x`.
+    pub(crate) fn quadratic(&self, values: &[f64], x: f64) -> (f64, f64, f64) {
+        let i = self.stencil_centre(x);
+        let (x0, x1, x2) = (self.nodes[i - 1], self.nodes[i], self.nodes[i + 1]);
+        let (v0, v1, v2) = (values[i - 1], values[i], values[i + 1]);
+
+        let l0 = (x - x1) * (x - x2) / ((x0 - x1) * (x0 - x2));
+        let l1 = (x - x0) * (x - x2) / ((x1 - x0) * (x1 - x2));
+        let l2 = (x - x0) * (x - x1) / ((x2 - x0) * (x2 - x1));
+        let (d1, d2) = stencil_weights(x0, x1, x2, x);
+
+        (
+            l0 * v0 + l1 * v1 + l2 * v2,
+            d1[0] * v0 + d1[1] * v1 + d1[2] * v2,
+            d2[0] * v0 + d2[1] * v1 + d2[2] * v2,
+        )
+    }
+
+    /// Linear interpolation of `values` at `x`, or `None` outside the grid.
+    pub(crate) fn linear(&self, values: &[f64], x: f64) -> Option<f64> {
+        let n = self.nodes.len();
+        if x < self.nodes[0] || x > self.nodes[n - 1] {
+            return None;
+        }
+        let j = self
+            .nodes
+            .partition_point(|&node| node <= x)
+            .clamp(1, n - 1);
+        let (x0, x1) = (self.nodes[j - 1], self.nodes[j]);
+        let w = (x - x0) / (x1 - x0);
+        Some((1.0 - w) * values[j - 1] + w * values[j])
+    }
+}
+
+/// Derivative weights at `x` of the quadratic through `(x0, x1, x2)`.
+fn stencil_weights(x0: f64, x1: f64, x2: f64, x: f64) -> ([f64; 3], [f64; 3]) {
+    let d0 = (x0 - x1) * (x0 - x2);
+    let d1 = (x1 - x0) * (x1 - x2);
+    let d2 = (x2 - x0) * (x2 - x1);
+    (
+        [
+            (2.0 * x - x1 - x2) / d0,
+            (2.0 * x - x0 - x2) / d1,
+            (2.0 * x - x0 - x1) / d2,
+        ],
+        [2.0 / d0, 2.0 / d1, 2.0 / d2],
+    )
+}
+
+/// Time grid from 0 to `maturity` with `n_steps` uniform steps and every
+/// event time in `(0, maturity)` inserted.
+///
+/// A uniform node closer than a quarter step to an event is replaced by
+/// the event, so no tiny steps arise.
+pub(crate) fn time_grid(maturity: f64, n_steps: usize, events: &[f64]) -> Vec<f64> {
+    let dt = maturity / n_steps as f64;
+    let mut times: Vec<f64> = (0..=n_steps).map(|i| dt * i as f64).collect();
+    times[n_steps] = maturity;
+    for &event in events {
+        if event <= 0.0 || event >= maturity {
+            continue;
+        }
+        let nearest = (event / dt).round() as usize;
+        let replaceable =
+            nearest != 0 && nearest < n_steps && times[nearest] == dt * nearest as f64;
+        if replaceable && (times[nearest] - event).abs() < 0.25 * dt {
+            times[nearest] = event;
+        } else if !times.iter().any(|&t| (t - event).abs() < 1e-12) {
+            times.push(event);
+        }
+    }
+    times.sort_by(f64::total_cmp);
+    times.dedup_by(|a, b| (*a - *b).abs() < 1e-12);
+    times
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_concentrated_grid_is_denser_at_centre() {
+        let grid = Grid1D::concentrated(0.0, 400.0, 100, &[100.0], 0.05).unwrap();
+        let nodes = grid.nodes();
+        let i = grid.stencil_centre(100.0);
+        let near = nodes[i + 1] - nodes[i];
+        let far = nodes[100] - nodes[99];
+        assert!(near < 0.6 * far, "near {near}, far {far}");
+        assert!(nodes.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    #[test]
+    fn test_quadratic_is_exact_for_parabola() {
+        let grid = Grid1D::concentrated(0.0, 10.0, 20, &[3.0], 0.2).unwrap();
+        let values: Vec<f64> = grid.nodes().iter().map(|x| 2.0 * x * x - x + 1.0).collect();
+        let (v, d1, d2) = grid.quadratic(&values, 4.3);
+        assert_relative_eq!(v, 2.0 * 4.3 * 4.3 - 4.3 + 1.0, epsilon = 1e-10);
+        assert_relative_eq!(d1, 4.0 * 4.3 - 1.0, epsilon = 1e-10);
+        assert_relative_eq!(d2, 4.0, epsilon = 1e-8);
+        assert_eq!(grid.linear(&values, 11.0), None);
+    }
+
+    #[test]
+    fn test_time_grid_contains_events() {
+        let times = time_grid(1.0, 10, &[0.33, 0.5, 1.5]);
+        assert_eq!(times[0], 0.0);
+        assert_eq!(*times.last().unwrap(), 1.0);
+        assert!(times.contains(&0.33));
+        assert!(times.contains(&0.5));
+        assert!(times.windows(2).all(|w| w[1] > w[0]));
+        assert!(times.windows(2).all(|w| w[1] - w[0] > 0.02));
+    }
+
+    #[test]
+    fn test_rejects_short_grid() {
+        assert!(Grid1D::uniform(0.0, 1.0, 2).is_err());
+        assert!(Grid1D::from_nodes(vec![0.0, 1.0, 1.0, 2.0, 3.0]).is_err());
+    }
+}
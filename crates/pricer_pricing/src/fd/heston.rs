@@ -0,0 +1,300 @@
+//! Two-dimensional Heston PDE with Douglas ADI time stepping.
+//!
+//! The option value `V(t, S, v)` solves
+//!
+//! ```text
+//! ∂V/∂t + ½vS²·V_SS + ρξvS·V_Sv + ½ξ²v·V_vv
+//!       + (r − q)S·V_S + κ(θ − v)·V_v − rV = 0
+//! ```
+//!
+//! It is solved by Douglas ADI (see [`adi`](super::adi)) with `A₁` the spot
+//! direction and `A₂` the variance direction, each carrying half of the
+//! discounting.
+
+use super::adi::{slice_at, AdiOperator, Work};
+use super::config::FdConfig;
+use super::equity::FdOption;
+use super::grid::{time_grid, Grid1D};
+use super::solver::{Boundary, Tridiagonal};
+use super::FdResult;
+use crate::mc::ConfigError;
+
+/// Heston model for the two-dimensional PDE.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::fd::{FdConfig, FdOption, FdPricer, HestonPde};
+/// use pricer_pricing::mc::PayoffParams;
+///
+/// let model = HestonPde {
+///     spot: 100.0,
+///     v0: 0.04,
+///     kappa: 1.5,
+///     theta: 0.04,
+///     vol_of_vol: 0.3,
+///     rho: -0.7,
+///     rate: 0.05,
+///     dividend_yield: 0.0,
+/// };
+/// let config = FdConfig::builder()
+///     .space_intervals(80)
+///     .variance_intervals(40)
+///     .time_steps(50)
+///     .build()
+///     .unwrap();
+/// let result = FdPricer::new(config)
+///     .unwrap()
+///     .price_heston(&model, &FdOption::american(PayoffParams::put(100.0), 1.0))
+///     .unwrap();
+/// assert!(result.price > 5.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HestonPde {
+    /// Spot price.
+    pub spot: f64,
+    /// Initial variance.
+    pub v0: f64,
+    /// Mean reversion speed κ.
+    pub kappa: f64,
+    /// Long-run variance θ.
+    pub theta: f64,
+    /// Volatility of variance ξ.
+    pub vol_of_vol: f64,
+    /// Spot-variance correlation ρ.
+    pub rho: f64,
+    /// Continuously compounded risk-free rate.
+    pub rate: f64,
+    /// Continuous dividend yield.
+    pub dividend_yield: f64,
+}
+
+impl HestonPde {
+    /// Checks parameter domains.
+    fn validate(&self) -> Result<(), ConfigError> {
+        let checks = [
+            ("spot", self.spot, self.spot > 0.0),
+            ("v0", self.v0, self.v0 >= 0.0),
+            ("kappa", self.kappa, self.kappa > 0.0),
+            ("theta", self.theta, self.theta > 0.0),
+            ("vol_of_vol", self.vol_of_vol, self.vol_of_vol > 0.0),
+            ("rho", self.rho, self.rho.abs() <= 1.0),
+            ("rate", self.rate, true),
+            ("dividend_yield", self.dividend_yield, true),
+        ];
+        for (name, value, valid) in checks {
+            if !value.is_finite() || !valid {
+                return Err(ConfigError::InvalidParameter {
+                    name,
+                    value: value.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Heston operator on the tensor grid `S × v`.
+fn operator<'a>(
+    model: &HestonPde,
+    s_grid: &'a Grid1D,
+    v_grid: &'a Grid1D,
+    s_boundaries: [Boundary; 2],
+) -> AdiOperator<'a> {
+    let (s, v) = (s_grid.nodes(), v_grid.nodes());
+    let (ns, nv) = (s.len(), v.len());
+    let (r, q) = (model.rate, model.dividend_yield);
+    let xi = model.vol_of_vol;
+
+    let a1 = v
+        .iter()
+        .map(|&vk| {
+            let mut op = Tridiagonal::zeros(ns);
+            op.assemble(s_grid, s_boundaries, |j| {
+                (0.5 * vk * s[j] * s[j], (r - q) * s[j], 0.5 * r)
+            });
+            op
+        })
+        .collect();
+
+    let fixed = |j: usize| {
+        (j == 0 && s_boundaries[0] == Boundary::Fixed)
+            || (j == ns - 1 && s_boundaries[1] == Boundary::Fixed)
+    };
+    let a2 = (0..ns)
+        .map(|j| {
+            let mut op = Tridiagonal::zeros(nv);
+            if !fixed(j) {
+                op.assemble(v_grid, [Boundary::Natural; 2], |k| {
+                    (
+                        0.5 * xi * xi * v[k],
+                        model.kappa * (model.theta - v[k]),
+                        0.5 * r,
+                    )
+                });
+            }
+            op
+        })
+        .collect();
+
+    // ρξvS (zero where the mixed term is not applied)
+    let mut mixed = vec![0.0; ns * nv];
+    for k in 1..nv - 1 {
+        for j in 1..ns - 1 {
+            mixed[k * ns + j] = model.rho * xi * v[k] * s[j];
+        }
+    }
+
+    AdiOperator::new(s_grid, v_grid, a1, a2, mixed)
+}
+
+/// Prices `option` under `model` on an `S × v` grid.
+pub(crate) fn price(
+    config: &FdConfig,
+    model: &HestonPde,
+    option: &FdOption,
+) -> Result<FdResult, ConfigError> {
+    model.validate()?;
+    option.validate(model.spot)?;
+
+    let (r, q) = (model.rate, model.dividend_yield);
+    let reference_vol = model.v0.max(model.theta).sqrt();
+    let ([lower, upper], s_boundaries, points) =
+        option.spot_domain(model.spot, r - q, reference_vol);
+    let s_grid = Grid1D::concentrated(
+        lower,
+        upper,
+        config.space_intervals(),
+        &points,
+        config.concentration(),
+    )?;
+    let v_max = (10.0 * model.v0.max(model.theta)).max(1.0);
+    let v_grid = Grid1D::concentrated(
+        0.0,
+        v_max,
+        config.variance_intervals(),
+        &[0.0, model.v0],
+        config.concentration(),
+    )?;
+    let (ns, nv) = (s_grid.len(), v_grid.len());
+
+    let operator = operator(model, &s_grid, &v_grid, s_boundaries);
+    let terminal = option.terminal_values(s_grid.nodes(), s_boundaries);
+    let mut values: Vec<f64> = (0..nv).flat_map(|_| terminal.iter().copied()).collect();
+    let mut work = Work::new(ns, nv);
+
+    let times = time_grid(
+        option.maturity,
+        config.time_steps(),
+        option.exercise.dates(),
+    );
+    let mut next_values = values.clone();
+    let mut remaining = config.rannacher_steps();
+    for k in (0..times.len() - 1).rev() {
+        let (t0, t1) = (times[k], times[k + 1]);
+        let dt = t1 - t0;
+        operator.step(dt, remaining > 0, &mut values, &mut work);
+        remaining = remaining.saturating_sub(1);
+
+        if option.exercise.is_exercise_time(t0) {
+            for (i, v) in values.iter_mut().enumerate() {
+                *v = v.max(option.intrinsic(s_grid.nodes()[i % ns]));
+            }
+            if option.exercise != super::FdExercise::American {
+                remaining = config.rannacher_steps();
+            }
+        }
+        if k == 1 {
+            next_values.copy_from_slice(&values);
+        }
+    }
+
+    // Interpolate in variance at v0, then take spot Greeks on the slice
+    let solution = super::solver::Solution {
+        values: slice_at(&s_grid, &v_grid, &values, model.v0),
+        next_values: slice_at(&s_grid, &v_grid, &next_values, model.v0),
+        next_time: times[1],
+    };
+    Ok(FdResult::from_grid(&s_grid, &solution, model.spot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fd::{FdPricer, KnockOut};
+    use crate::mc::PayoffParams;
+    use approx::assert_relative_eq;
+
+    fn model(vol_of_vol: f64) -> HestonPde {
+        HestonPde {
+            spot: 100.0,
+            v0: 0.04,
+            kappa: 1.5,
+            theta: 0.04,
+            vol_of_vol,
+            rho: -0.7,
+            rate: 0.05,
+            dividend_yield: 0.01,
+        }
+    }
+
+    fn pricer() -> FdPricer {
+        let config = FdConfig::builder()
+            .space_intervals(100)
+            .variance_intervals(50)
+            .time_steps(50)
+            .build()
+            .unwrap();
+        FdPricer::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_low_vol_of_vol_matches_black_scholes() {
+        // theta = v0 and negligible vol-of-vol: Black-Scholes with sigma = 0.2
+        let result = pricer()
+            .price_heston(
+                &model(1e-3),
+                &FdOption::european(PayoffParams::call(100.0), 1.0),
+            )
+            .unwrap();
+        let equity = pricer()
+            .price_equity(
+                &crate::fd::EquityPde::new(100.0, 0.05, 0.01, 0.2),
+                &FdOption::european(PayoffParams::call(100.0), 1.0),
+            )
+            .unwrap();
+        assert_relative_eq!(result.price, equity.price, max_relative = 2e-3);
+        assert_relative_eq!(result.delta, equity.delta, max_relative = 5e-3);
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let heston = model(0.3);
+        let call = pricer()
+            .price_heston(&heston, &FdOption::european(PayoffParams::call(110.0), 1.0))
+            .unwrap();
+        let put = pricer()
+            .price_heston(&heston, &FdOption::european(PayoffParams::put(110.0), 1.0))
+            .unwrap();
+        let forward = 100.0 * (-0.01_f64).exp() - 110.0 * (-0.05_f64).exp();
+        assert_relative_eq!(call.price - put.price, forward, epsilon = 2e-2);
+        assert_relative_eq!(call.delta - put.delta, (-0.01_f64).exp(), epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_american_and_barrier() {
+        let heston = model(0.3);
+        let european = pricer()
+            .price_heston(&heston, &FdOption::european(PayoffParams::put(100.0), 1.0))
+            .unwrap();
+        let american = pricer()
+            .price_heston(&heston, &FdOption::american(PayoffParams::put(100.0), 1.0))
+            .unwrap();
+        assert!(american.price > european.price);
+
+        let knock_out = FdOption::european(PayoffParams::put(100.0), 1.0)
+            .with_knock_out(KnockOut::down(80.0, 0.0));
+        let barrier = pricer().price_heston(&heston, &knock_out).unwrap();
+        assert!(barrier.price > 0.0 && barrier.price < european.price);
+    }
+}
@@ -0,0 +1,236 @@
+//! One-factor Hull-White short-rate PDE.
+//!
+//! Under `dr = (θ(t) − a·r)·dt + σ·dW` the value `V(t, r)` of a claim
+//! solves
+//!
+//! ```text
+//! ∂V/∂t + ½σ²·∂²V/∂r² + (θ(t) − a·r)·∂V/∂r − rV = 0
+//! ```
+//!
+//! With a flat initial curve at `r₀`, `θ(t) = a·r₀ + σ²/(2a)·(1 − e^{−2at})`
+//! fits today's discount factors `P(0, T) = e^{−r₀T}` exactly.
+
+use super::config::FdConfig;
+use super::grid::{time_grid, Grid1D};
+use super::solver::{solve_backward, Boundary};
+use super::{FdExercise, FdResult};
+use crate::mc::ConfigError;
+
+/// Standard deviations of the short rate covered by the grid.
+const RATE_GRID_STD_DEVS: f64 = 7.0;
+
+/// Hull-White model fitted to a flat initial curve.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::fd::{FdConfig, FdExercise, FdPricer, HullWhitePde};
+///
+/// let model = HullWhitePde::new(0.1, 0.01, 0.03).unwrap();
+/// let pricer = FdPricer::new(FdConfig::default()).unwrap();
+///
+/// // 1y call on a 5y zero-coupon bond
+/// let strike = 0.89;
+/// let call = pricer
+///     .price_hull_white(&model, 1.0, &FdExercise::European, |t, r| {
+///         (model.bond(t, 5.0, r) - strike).max(0.0)
+///     })
+///     .unwrap();
+/// assert!(call.price > 0.0);
+/// assert!(call.delta < 0.0); // bond prices fall as rates rise
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HullWhitePde {
+    mean_reversion: f64,
+    volatility: f64,
+    initial_rate: f64,
+}
+
+impl HullWhitePde {
+    /// Creates a Hull-White model with mean reversion `a`, volatility `σ`
+    /// and flat initial curve at `initial_rate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `a` or `σ` is not
+    /// positive or any input is not finite.
+    pub fn new(
+        mean_reversion: f64,
+        volatility: f64,
+        initial_rate: f64,
+    ) -> Result<Self, ConfigError> {
+        for (name, value, valid) in [
+            ("mean_reversion", mean_reversion, mean_reversion > 0.0),
+            ("volatility", volatility, volatility > 0.0),
+            ("initial_rate", initial_rate, true),
+        ] {
+            if !value.is_finite() || !valid {
+                return Err(ConfigError::InvalidParameter {
+                    name,
+                    value: value.to_string(),
+                });
+            }
+        }
+        Ok(Self {
+            mean_reversion,
+            volatility,
+            initial_rate,
+        })
+    }
+
+    /// Returns the initial short rate.
+    #[inline]
+    pub fn initial_rate(&self) -> f64 {
+        self.initial_rate
+    }
+
+    /// Zero-coupon bond price `P(t, T)` given short rate `r` at `t`.
+    pub fn bond(&self, t: f64, maturity: f64, r: f64) -> f64 {
+        let (a, sigma, r0) = (self.mean_reversion, self.volatility, self.initial_rate);
+        let b = (1.0 - (-a * (maturity - t)).exp()) / a;
+        let ln_a = -r0 * (maturity - t) + b * r0
+            - sigma * sigma / (4.0 * a) * (1.0 - (-2.0 * a * t).exp()) * b * b;
+        (ln_a - b * r).exp()
+    }
+
+    /// Drift level `θ(t)` fitting the flat initial curve.
+    fn theta(&self, t: f64) -> f64 {
+        let (a, sigma) = (self.mean_reversion, self.volatility);
+        a * self.initial_rate + sigma * sigma / (2.0 * a) * (1.0 - (-2.0 * a * t).exp())
+    }
+
+    /// Standard deviation of `r(t)`.
+    fn rate_std_dev(&self, t: f64) -> f64 {
+        let a = self.mean_reversion;
+        self.volatility * ((1.0 - (-2.0 * a * t).exp()) / (2.0 * a)).sqrt()
+    }
+}
+
+/// Prices a short-rate claim on an `r` grid.
+pub(crate) fn price(
+    config: &FdConfig,
+    model: &HullWhitePde,
+    maturity: f64,
+    exercise: &FdExercise,
+    exercise_value: impl Fn(f64, f64) -> f64,
+) -> Result<FdResult, ConfigError> {
+    if !maturity.is_finite() || maturity <= 0.0 {
+        return Err(ConfigError::InvalidParameter {
+            name: "maturity",
+            value: maturity.to_string(),
+        });
+    }
+    exercise.validate(maturity)?;
+
+    let r0 = model.initial_rate;
+    let half_width = RATE_GRID_STD_DEVS * model.rate_std_dev(maturity) + 0.02;
+    let grid = Grid1D::concentrated(
+        r0 - half_width,
+        r0 + half_width,
+        config.space_intervals(),
+        &[r0],
+        config.concentration(),
+    )?;
+    let nodes = grid.nodes();
+    let boundaries = [Boundary::Natural; 2];
+    let times = time_grid(maturity, config.time_steps(), exercise.dates());
+
+    let event = |t: f64, values: &mut [f64]| {
+        if !exercise.is_exercise_time(t) {
+            return false;
+        }
+        for (v, &r) in values.iter_mut().zip(nodes) {
+            *v = v.max(exercise_value(t, r));
+        }
+        *exercise != FdExercise::American
+    };
+
+    let sigma = model.volatility;
+    let a = model.mean_reversion;
+    let solution = solve_backward(
+        &grid,
+        boundaries,
+        &times,
+        config.rannacher_steps(),
+        |t, r| (0.5 * sigma * sigma, model.theta(t) - a * r, r),
+        nodes.iter().map(|&r| exercise_value(maturity, r)).collect(),
+        event,
+    );
+
+    Ok(FdResult::from_grid(&grid, &solution, r0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::asian::norm_cdf;
+    use crate::fd::FdPricer;
+    use approx::assert_relative_eq;
+
+    fn model() -> HullWhitePde {
+        HullWhitePde::new(0.1, 0.01, 0.03).unwrap()
+    }
+
+    fn pricer() -> FdPricer {
+        FdPricer::new(FdConfig::default()).unwrap()
+    }
+
+    /// Closed-form call on a zero-coupon bond (Brigo-Mercurio 3.40).
+    fn bond_call(model: &HullWhitePde, expiry: f64, bond_maturity: f64, strike: f64) -> f64 {
+        let a = model.mean_reversion;
+        let b = (1.0 - (-a * (bond_maturity - expiry)).exp()) / a;
+        let sigma_p = model.rate_std_dev(expiry) * b;
+        let p_t = (-model.initial_rate * expiry).exp();
+        let p_s = (-model.initial_rate * bond_maturity).exp();
+        let h = (p_s / (p_t * strike)).ln() / sigma_p + 0.5 * sigma_p;
+        p_s * norm_cdf(h) - strike * p_t * norm_cdf(h - sigma_p)
+    }
+
+    #[test]
+    fn test_zero_coupon_bond_matches_curve() {
+        let result = pricer()
+            .price_hull_white(&model(), 5.0, &FdExercise::European, |_, _| 1.0)
+            .unwrap();
+        assert_relative_eq!(result.price, (-0.15_f64).exp(), max_relative = 1e-5);
+        // dP/dr = -B(0, T) P
+        let b = (1.0 - (-0.5_f64).exp()) / 0.1;
+        assert_relative_eq!(result.delta, -b * result.price, max_relative = 1e-3);
+        assert_relative_eq!(
+            model().bond(0.0, 5.0, 0.03),
+            (-0.15_f64).exp(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_bond_option_matches_closed_form() {
+        let hw = model();
+        let forward = (-0.03 * 4.0_f64).exp();
+        let call = pricer()
+            .price_hull_white(&hw, 1.0, &FdExercise::European, |t, r| {
+                (hw.bond(t, 5.0, r) - forward).max(0.0)
+            })
+            .unwrap();
+        let expected = bond_call(&hw, 1.0, 5.0, forward);
+        assert_relative_eq!(call.price, expected, max_relative = 5e-3);
+    }
+
+    #[test]
+    fn test_bermudan_exceeds_european() {
+        // Receiver-style right: pay 1 to receive the 5y bond's value at par
+        let hw = model();
+        let dates = vec![0.5, 1.0, 1.5];
+        let value = |t: f64, r: f64| (hw.bond(t, 5.0, r) - 0.88).max(0.0);
+        let european = pricer()
+            .price_hull_white(&hw, 2.0, &FdExercise::European, value)
+            .unwrap();
+        let bermudan = pricer()
+            .price_hull_white(&hw, 2.0, &FdExercise::Bermudan(dates), value)
+            .unwrap();
+        let american = pricer()
+            .price_hull_white(&hw, 2.0, &FdExercise::American, value)
+            .unwrap();
+        assert!(bermudan.price > european.price);
+        assert!(american.price >= bermudan.price - 1e-10);
+    }
+}
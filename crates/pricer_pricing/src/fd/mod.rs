@@ -0,0 +1,46 @@
+//! Finite-difference PDE pricing.
+//!
+//! This module solves the pricing PDE backward from maturity on a
+//! spatial grid, as a deterministic alternative to the Monte Carlo engine
+//! in [`mc`](crate::mc) for low-dimensional models.
+//!
+//! # Key Components
+//!
+//! - [`FdPricer`]: entry point for all models
+//! - [`EquityPde`]: Black-Scholes or local volatility ([`LocalVolatility`])
+//!   with discrete dividends from an equity market, solved by
+//!   Crank-Nicolson with Rannacher start-up
+//! - [`HestonPde`]: two-dimensional Heston PDE, solved by Douglas ADI
+//! - [`HullWhitePde`]: one-factor Hull-White short-rate PDE
+//! - [`G2PlusPlusPde`]: two-factor G2++ short-rate PDE, solved by Douglas
+//!   ADI like Heston
+//! - [`Grid1D`]: non-uniform grids concentrated at strikes and barriers
+//!
+//! # Contracts
+//!
+//! [`FdOption`] covers European, American and Bermudan exercise
+//! ([`FdExercise`]) and continuous knock-out barriers with rebate
+//! ([`KnockOut`]).
+//!
+//! # Output
+//!
+//! [`FdResult`] reports the price with delta, gamma and theta read
+//! directly off the grid, so they carry no simulation noise.
+
+mod adi;
+mod config;
+mod equity;
+mod g2pp;
+mod grid;
+mod heston;
+mod hull_white;
+mod pricer;
+mod solver;
+
+pub use config::{FdConfig, FdConfigBuilder};
+pub use equity::{EquityPde, FdOption, KnockOut, LocalVolatility};
+pub use g2pp::G2PlusPlusPde;
+pub use grid::Grid1D;
+pub use heston::HestonPde;
+pub use hull_white::HullWhitePde;
+pub use pricer::{FdExercise, FdPricer, FdResult};
@@ -0,0 +1,228 @@
+//! Finite-difference pricer entry point and result types.
+
+use super::config::FdConfig;
+use super::equity::{self, EquityPde, FdOption, LocalVolatility};
+use super::g2pp::{self, G2PlusPlusPde};
+use super::grid::Grid1D;
+use super::heston::{self, HestonPde};
+use super::hull_white::{self, HullWhitePde};
+use super::solver::Solution;
+use crate::mc::ConfigError;
+
+/// Exercise style of a finite-difference contract.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FdExercise {
+    /// Exercise at maturity only.
+    European,
+    /// Exercise at any time up to maturity (applied at every time step).
+    American,
+    /// Exercise on the given dates (year fractions) and at maturity.
+    Bermudan(Vec<f64>),
+}
+
+impl FdExercise {
+    /// Exercise dates before maturity; empty unless Bermudan.
+    pub fn dates(&self) -> &[f64] {
+        match self {
+            Self::Bermudan(dates) => dates,
+            _ => &[],
+        }
+    }
+
+    /// Returns true if early exercise is allowed at time `t`.
+    pub(crate) fn is_exercise_time(&self, t: f64) -> bool {
+        match self {
+            Self::European => false,
+            Self::American => true,
+            Self::Bermudan(dates) => dates.iter().any(|&d| (d - t).abs() < 1e-12),
+        }
+    }
+
+    /// Checks that Bermudan dates are increasing and in `(0, maturity]`.
+    pub(crate) fn validate(&self, maturity: f64) -> Result<(), ConfigError> {
+        let dates = self.dates();
+        let valid = dates
+            .iter()
+            .all(|&d| d.is_finite() && d > 0.0 && d <= maturity)
+            && dates.windows(2).all(|w| w[1] > w[0]);
+        if valid {
+            Ok(())
+        } else {
+            Err(ConfigError::InvalidParameter {
+                name: "exercise_dates",
+                value: format!("{dates:?} (must be increasing in (0, {maturity}])"),
+            })
+        }
+    }
+}
+
+/// Finite-difference price and grid Greeks.
+///
+/// Greeks are with respect to the grid variable: spot for equity models,
+/// short rate for Hull-White and the first factor `x` for G2++.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FdResult {
+    /// Present value.
+    pub price: f64,
+    /// First derivative with respect to the grid variable.
+    pub delta: f64,
+    /// Second derivative with respect to the grid variable.
+    pub gamma: f64,
+    /// Time derivative `∂V/∂t` at time zero (per year).
+    pub theta: f64,
+}
+
+impl FdResult {
+    /// Reads price, delta and gamma at `x` and theta from the first time
+    /// step.
+    pub(crate) fn from_grid(grid: &Grid1D, solution: &Solution, x: f64) -> Self {
+        let (price, delta, gamma) = grid.quadratic(&solution.values, x);
+        let (next, _, _) = grid.quadratic(&solution.next_values, x);
+        Self {
+            price,
+            delta,
+            gamma,
+            theta: (next - price) / solution.next_time,
+        }
+    }
+}
+
+/// Finite-difference pricer.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::fd::{EquityPde, FdConfig, FdOption, FdPricer};
+/// use pricer_pricing::mc::PayoffParams;
+///
+/// let pricer = FdPricer::new(FdConfig::default()).unwrap();
+/// let model = EquityPde::new(100.0, 0.05, 0.0, 0.2);
+///
+/// let european = pricer
+///     .price_equity(&model, &FdOption::european(PayoffParams::put(100.0), 1.0))
+///     .unwrap();
+/// let american = pricer
+///     .price_equity(&model, &FdOption::american(PayoffParams::put(100.0), 1.0))
+///     .unwrap();
+///
+/// assert!((european.price - 5.5735).abs() < 1e-2);
+/// assert!(american.price > european.price);
+/// assert!(american.delta < 0.0 && american.gamma > 0.0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FdPricer {
+    config: FdConfig,
+}
+
+impl FdPricer {
+    /// Creates a pricer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if `config` is invalid.
+    pub fn new(config: FdConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+
+    /// Returns the configuration.
+    #[inline]
+    pub fn config(&self) -> &FdConfig {
+        &self.config
+    }
+
+    /// Prices `option` under Black-Scholes or local volatility.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` for a non-positive spot,
+    /// negative dividends, a spot outside the knock-out barriers or
+    /// invalid exercise dates.
+    pub fn price_equity<V: LocalVolatility>(
+        &self,
+        model: &EquityPde<V>,
+        option: &FdOption,
+    ) -> Result<FdResult, ConfigError> {
+        equity::price(&self.config, model, option)
+    }
+
+    /// Prices `option` under Heston by ADI on a spot-variance grid.
+    ///
+    /// Greeks are spot Greeks at the initial variance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` for invalid model
+    /// parameters or an invalid option (see
+    /// [`price_equity`](Self::price_equity)).
+    pub fn price_heston(
+        &self,
+        model: &HestonPde,
+        option: &FdOption,
+    ) -> Result<FdResult, ConfigError> {
+        heston::price(&self.config, model, option)
+    }
+
+    /// Prices a short-rate claim under Hull-White.
+    ///
+    /// `exercise_value(t, r)` is the payoff at `maturity` and, for
+    /// American or Bermudan exercise, the value of exercising at `t`.
+    /// Greeks are with respect to the initial short rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` for a non-positive maturity
+    /// or invalid exercise dates.
+    pub fn price_hull_white(
+        &self,
+        model: &HullWhitePde,
+        maturity: f64,
+        exercise: &FdExercise,
+        exercise_value: impl Fn(f64, f64) -> f64,
+    ) -> Result<FdResult, ConfigError> {
+        hull_white::price(&self.config, model, maturity, exercise, exercise_value)
+    }
+
+    /// Prices a short-rate claim under G2++ by ADI on an `x × y` grid.
+    ///
+    /// `exercise_value(t, x, y)` is the payoff at `maturity` and, for
+    /// American or Bermudan exercise, the value of exercising at `t`. The
+    /// `x` grid uses the configured space intervals and the `y` grid the
+    /// variance intervals. Greeks are with respect to `x` at `x = y = 0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` for a non-positive maturity
+    /// or invalid exercise dates.
+    pub fn price_g2pp(
+        &self,
+        model: &G2PlusPlusPde,
+        maturity: f64,
+        exercise: &FdExercise,
+        exercise_value: impl Fn(f64, f64, f64) -> f64,
+    ) -> Result<FdResult, ConfigError> {
+        g2pp::price(&self.config, model, maturity, exercise, exercise_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exercise_validation() {
+        assert!(FdExercise::European.validate(1.0).is_ok());
+        assert!(FdExercise::Bermudan(vec![0.25, 0.5, 1.0])
+            .validate(1.0)
+            .is_ok());
+        assert!(FdExercise::Bermudan(vec![0.5, 0.25]).validate(1.0).is_err());
+        assert!(FdExercise::Bermudan(vec![0.0, 0.5]).validate(1.0).is_err());
+        assert!(FdExercise::Bermudan(vec![1.5]).validate(1.0).is_err());
+
+        let bermudan = FdExercise::Bermudan(vec![0.5]);
+        assert!(bermudan.is_exercise_time(0.5));
+        assert!(!bermudan.is_exercise_time(0.25));
+        assert!(FdExercise::American.is_exercise_time(0.25));
+        assert!(!FdExercise::European.is_exercise_time(0.25));
+    }
+}
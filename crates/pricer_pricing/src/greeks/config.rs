@@ -11,6 +11,7 @@
 ///
 /// * `BumpRevalue` - Finite differences (bump-and-revalue)
 /// * `NumDual` - Forward-mode AD using num-dual library
+/// * `TapeAAD` - Tape-based reverse-mode AD on stable Rust
 /// * `EnzymeAAD` - Enzyme LLVM-level AAD (requires `enzyme-ad` feature)
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum GreeksMode {
@@ -29,6 +30,14 @@ pub enum GreeksMode {
     /// Requires the `num-dual-mode` feature.
     NumDual,
 
+    /// Tape-based reverse-mode AD using `pricer_core::types::tape::AReal`.
+    ///
+    /// Records the valuation on a thread-local tape and computes all
+    /// first-order Greeks in one backward sweep. Works on stable Rust
+    /// with pricers generic over `Float`. Used by reverse-mode Monte Carlo
+    /// Greeks when Enzyme is unavailable (`l1l2-integration`).
+    TapeAAD,
+
     /// Enzyme LLVM-level automatic differentiation.
     ///
     /// Uses Enzyme's reverse-mode AD for efficient computation
@@ -71,7 +80,7 @@ pub enum GreeksMode {
 /// ```
 #[derive(Clone, Debug)]
 pub struct GreeksConfig {
-    /// Calculation mode (BumpRevalue, NumDual, or EnzymeAAD).
+    /// Calculation mode (BumpRevalue, NumDual, TapeAAD, or EnzymeAAD).
     pub mode: GreeksMode,
 
     /// Relative bump for spot price (default: 0.01 = 1%).
@@ -0,0 +1,339 @@
+//! Cox-Ross-Rubinstein binomial tree for equity options with dividends.
+//!
+//! The tree is built on the driftless escrowed process `X` of the
+//! [`EquityMarket`] (see `pricer_core::market_data::equity`):
+//!
+//! ```text
+//! X(0)      = S*(T)
+//! u = e^(σ√Δt),  d = 1/u,  p = (1 - d) / (u - d)
+//! S(tᵢ)     = G(tᵢ)·(X(tᵢ) + Σ_{tᵢ < t_k ≤ T} D_k / G(t_k))
+//! ```
+//!
+//! so the lattice recombines despite cash dividends, reprices the market
+//! forward exactly, and converges to [`EquityBlackScholes`] for European
+//! exercise. Values are rolled back with discount factor ratios from the
+//! market's discount curve, comparing against intrinsic value wherever
+//! early exercise is permitted.
+//!
+//! [`EquityBlackScholes`]: pricer_models::analytical::EquityBlackScholes
+
+use num_traits::Float;
+use pricer_core::market_data::equity::EquityMarket;
+use pricer_models::instruments::{ExerciseStyle, PayoffType, VanillaOption};
+
+use crate::mc::ConfigError;
+
+/// Maps a market data failure to a configuration error.
+fn market_error(e: impl std::fmt::Display) -> ConfigError {
+    ConfigError::InvalidParameter {
+        name: "market",
+        value: e.to_string(),
+    }
+}
+
+/// CRR binomial tree over an [`EquityMarket`].
+///
+/// # Type Parameters
+/// * `T` - Floating-point type implementing `Float` (e.g., `f64`, `Dual64`)
+///
+/// # Examples
+/// ```
+/// use pricer_core::market_data::curves::CurveEnum;
+/// use pricer_core::market_data::equity::{DividendSchedule, EquityMarket};
+/// use pricer_models::instruments::ExerciseStyle;
+/// use pricer_pricing::lattice::EquityBinomialTree;
+///
+/// let market = EquityMarket::new(100.0_f64, CurveEnum::flat(0.05))
+///     .unwrap()
+///     .with_dividends(DividendSchedule::new().with_cash(0.5, 5.0).unwrap());
+/// let tree = EquityBinomialTree::new(&market, 0.2, 200).unwrap();
+///
+/// let european = tree.price(100.0, 1.0, true, &ExerciseStyle::European).unwrap();
+/// let american = tree.price(100.0, 1.0, true, &ExerciseStyle::American).unwrap();
+///
+/// // Early exercise ahead of a large dividend has value
+/// assert!(american > european);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EquityBinomialTree<'a, T: Float> {
+    /// Equity market data
+    market: &'a EquityMarket<T>,
+    /// Volatility of the escrowed process (σ)
+    volatility: T,
+    /// Number of time steps
+    n_steps: usize,
+}
+
+impl<'a, T: Float> EquityBinomialTree<'a, T> {
+    /// Creates a new tree.
+    ///
+    /// # Arguments
+    /// * `market` - Equity market (spot, curves, dividends)
+    /// * `volatility` - Volatility of the escrowed process (must be positive)
+    /// * `n_steps` - Number of time steps (must be positive)
+    ///
+    /// # Errors
+    /// Returns `ConfigError::InvalidParameter` if `volatility <= 0` or
+    /// `n_steps == 0`.
+    pub fn new(
+        market: &'a EquityMarket<T>,
+        volatility: T,
+        n_steps: usize,
+    ) -> Result<Self, ConfigError> {
+        if volatility <= T::zero() {
+            return Err(ConfigError::InvalidParameter {
+                name: "volatility",
+                value: volatility.to_f64().unwrap_or(0.0).to_string(),
+            });
+        }
+        if n_steps == 0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "n_steps",
+                value: "binomial tree requires at least one time step".to_string(),
+            });
+        }
+        Ok(Self {
+            market,
+            volatility,
+            n_steps,
+        })
+    }
+
+    /// Returns the number of time steps.
+    #[inline]
+    pub fn n_steps(&self) -> usize {
+        self.n_steps
+    }
+
+    /// Prices a call or put with the given exercise style.
+    ///
+    /// Bermudan exercise dates are snapped to the nearest tree step.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::InvalidParameter` for Asian exercise, a
+    /// non-positive `expiry`, or a market that cannot produce forwards or
+    /// discount factors.
+    pub fn price(
+        &self,
+        strike: T,
+        expiry: T,
+        is_call: bool,
+        exercise: &ExerciseStyle<T>,
+    ) -> Result<T, ConfigError> {
+        if exercise.is_asian() {
+            return Err(ConfigError::InvalidParameter {
+                name: "exercise",
+                value: "Asian exercise is not supported".to_string(),
+            });
+        }
+        if expiry <= T::zero() {
+            return Err(ConfigError::InvalidParameter {
+                name: "expiry",
+                value: expiry.to_f64().unwrap_or(0.0).to_string(),
+            });
+        }
+
+        let n = self.n_steps;
+        let dt = expiry / T::from(n).unwrap();
+        let u = (self.volatility * dt.sqrt()).exp();
+        let d = T::one() / u;
+        let p = (T::one() - d) / (u - d);
+        let q = T::one() - p;
+
+        let intrinsic = |spot: T| {
+            if is_call {
+                (spot - strike).max(T::zero())
+            } else {
+                (strike - spot).max(T::zero())
+            }
+        };
+
+        // Per-step market quantities
+        let mut growth = Vec::with_capacity(n + 1);
+        let mut escrow = Vec::with_capacity(n + 1);
+        let mut discount = Vec::with_capacity(n + 1);
+        for i in 0..=n {
+            let t = if i == n {
+                expiry
+            } else {
+                dt * T::from(i).unwrap()
+            };
+            growth.push(self.market.growth_factor(t).map_err(market_error)?);
+            escrow.push(
+                self.market
+                    .cash_dividend_escrow(t, expiry)
+                    .map_err(market_error)?,
+            );
+            discount.push(self.market.discount_factor(t).map_err(market_error)?);
+        }
+        let exercisable = self.exercise_steps(exercise, dt);
+        let x0 = self.market.escrowed_spot(expiry).map_err(market_error)?;
+
+        // Terminal values: X = X0·u^j·d^(n-j)
+        let mut values: Vec<T> = (0..=n)
+            .map(|j| {
+                let x = x0 * u.powi(2 * j as i32 - n as i32);
+                intrinsic(growth[n] * x)
+            })
+            .collect();
+
+        for i in (0..n).rev() {
+            let step_df = discount[i + 1] / discount[i];
+            for j in 0..=i {
+                let continuation = step_df * (p * values[j + 1] + q * values[j]);
+                values[j] = if exercisable[i] {
+                    let x = x0 * u.powi(2 * j as i32 - i as i32);
+                    continuation.max(intrinsic(growth[i] * (x + escrow[i])))
+                } else {
+                    continuation
+                };
+            }
+        }
+
+        Ok(values[0])
+    }
+
+    /// Flags the steps (before expiry) at which early exercise is allowed.
+    fn exercise_steps(&self, exercise: &ExerciseStyle<T>, dt: T) -> Vec<bool> {
+        let n = self.n_steps;
+        match exercise {
+            ExerciseStyle::American => vec![true; n],
+            ExerciseStyle::Bermudan { exercise_dates } => {
+                let mut steps = vec![false; n];
+                for &date in exercise_dates {
+                    let step = (date / dt).round().to_usize().unwrap_or(n);
+                    if step < n {
+                        steps[step] = true;
+                    }
+                }
+                steps
+            }
+            _ => vec![false; n],
+        }
+    }
+
+    /// Prices a vanilla option, honouring its exercise style.
+    ///
+    /// # Errors
+    /// As [`price`](Self::price), and `ConfigError::InvalidParameter` for
+    /// digital payoffs.
+    pub fn price_option(&self, option: &VanillaOption<T>) -> Result<T, ConfigError> {
+        let is_call = match option.payoff_type() {
+            PayoffType::Call => true,
+            PayoffType::Put => false,
+            PayoffType::DigitalCall | PayoffType::DigitalPut => {
+                return Err(ConfigError::InvalidParameter {
+                    name: "payoff",
+                    value: "digital payoffs are not supported".to_string(),
+                });
+            }
+        };
+        let price = self.price(
+            option.strike(),
+            option.expiry(),
+            is_call,
+            option.exercise_style(),
+        )?;
+        Ok(option.notional() * price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use pricer_core::market_data::curves::CurveEnum;
+    use pricer_core::market_data::equity::DividendSchedule;
+    use pricer_models::analytical::EquityBlackScholes;
+    use pricer_models::instruments::InstrumentParams;
+
+    fn market_with_dividends() -> EquityMarket<f64> {
+        EquityMarket::new(100.0, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_dividend_yield(0.01)
+            .with_dividends(
+                DividendSchedule::new()
+                    .with_cash(0.25, 2.0)
+                    .unwrap()
+                    .with_cash(0.75, 2.0)
+                    .unwrap(),
+            )
+    }
+
+    #[test]
+    fn test_european_converges_to_analytic() {
+        let market = market_with_dividends();
+        let tree = EquityBinomialTree::new(&market, 0.25, 1000).unwrap();
+        let analytic = EquityBlackScholes::new(&market, 0.25).unwrap();
+
+        for (strike, is_call) in [(90.0, true), (100.0, true), (110.0, false)] {
+            let lattice = tree
+                .price(strike, 1.0, is_call, &ExerciseStyle::European)
+                .unwrap();
+            let exact = if is_call {
+                analytic.price_call(strike, 1.0).unwrap()
+            } else {
+                analytic.price_put(strike, 1.0).unwrap()
+            };
+            assert_relative_eq!(lattice, exact, epsilon = 2e-2);
+        }
+    }
+
+    #[test]
+    fn test_american_call_without_dividends_equals_european() {
+        let market = EquityMarket::new(100.0, CurveEnum::flat(0.05)).unwrap();
+        let tree = EquityBinomialTree::new(&market, 0.2, 200).unwrap();
+        let european = tree
+            .price(100.0, 1.0, true, &ExerciseStyle::European)
+            .unwrap();
+        let american = tree
+            .price(100.0, 1.0, true, &ExerciseStyle::American)
+            .unwrap();
+        assert_relative_eq!(american, european, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_early_exercise_premium_ordering() {
+        let market = market_with_dividends();
+        let tree = EquityBinomialTree::new(&market, 0.25, 400).unwrap();
+        let bermudan = ExerciseStyle::bermudan(vec![0.25, 0.5, 0.75]);
+
+        for is_call in [true, false] {
+            let european = tree
+                .price(100.0, 1.0, is_call, &ExerciseStyle::European)
+                .unwrap();
+            let berm = tree.price(100.0, 1.0, is_call, &bermudan).unwrap();
+            let american = tree
+                .price(100.0, 1.0, is_call, &ExerciseStyle::American)
+                .unwrap();
+            assert!(european <= berm + 1e-12);
+            assert!(berm <= american + 1e-12);
+        }
+
+        // Deep in-the-money put is worth at least intrinsic
+        let deep_put = tree
+            .price(150.0, 1.0, false, &ExerciseStyle::American)
+            .unwrap();
+        assert!(deep_put >= 50.0 - 1e-12);
+    }
+
+    #[test]
+    fn test_price_option_and_validation() {
+        let market = market_with_dividends();
+        let tree = EquityBinomialTree::new(&market, 0.25, 100).unwrap();
+        let params = InstrumentParams::new(100.0, 1.0, 10.0).unwrap();
+        let option = VanillaOption::new(params, PayoffType::Put, ExerciseStyle::American, 1e-6);
+        assert_relative_eq!(
+            tree.price_option(&option).unwrap(),
+            10.0 * tree
+                .price(100.0, 1.0, false, &ExerciseStyle::American)
+                .unwrap(),
+            epsilon = 1e-10
+        );
+
+        let asian = ExerciseStyle::asian(0.0, 1.0, 12);
+        assert!(tree.price(100.0, 1.0, true, &asian).is_err());
+        assert!(EquityBinomialTree::new(&market, 0.25, 0).is_err());
+        assert!(EquityBinomialTree::new(&market, -0.1, 10).is_err());
+    }
+}
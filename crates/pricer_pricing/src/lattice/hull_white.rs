@@ -0,0 +1,662 @@
+//! One-factor Hull-White trinomial tree.
+//!
+//! The short rate is `r(t) = x(t) + α(t)` with
+//! `dx = −a x dt + σ(t) dW`, `x(0) = 0`. The tree discretises `x` on
+//! levels `t₀ = 0 < t₁ < … < t_N`, with node spacing on level `i + 1`
+//!
+//! ```text
+//! Δx_{i+1} = √(3 V_i),   V_i = ∫_{t_i}^{t_{i+1}} σ(s)² e^{−2a(t_{i+1} − s)} ds
+//! ```
+//!
+//! so that the exact conditional variance of `x` is matched on every step,
+//! including steps that straddle a volatility breakpoint. A node `x` on
+//! level `i` branches to `k − 1, k, k + 1` around `k = round(M / Δx_{i+1})`,
+//! `M = x e^{−aΔt}`, with `η = (M − kΔx_{i+1}) / Δx_{i+1}` and
+//!
+//! ```text
+//! p_u = 1/6 + (η² + η)/2,   p_m = 2/3 − η²,   p_d = 1/6 + (η² − η)/2
+//! ```
+//!
+//! which stay positive because `|η| ≤ ½`.
+//!
+//! The shift `α_i` is fitted by Arrow-Debreu forward induction: given the
+//! state prices `Q_{i,j}`,
+//!
+//! ```text
+//! α_i = ln(Σ_j Q_{i,j} e^{−x_{i,j}Δt_i} / P(0, t_{i+1})) / Δt_i
+//! Q_{i+1,k} = Σ_j Q_{i,j} p_{j→k} e^{−(α_i + x_{i,j})Δt_i}
+//! ```
+//!
+//! so that `Σ_k Q_{i+1,k} = P(0, t_{i+1})` and the tree reprices the
+//! initial curve exactly on every level.
+
+use crate::mc::ConfigError;
+
+/// Tolerance for matching contract dates to tree levels.
+pub(crate) const TIME_TOLERANCE: f64 = 1e-10;
+
+/// Piecewise-constant volatility `σ(t)`.
+///
+/// `values[0]` applies before `times[0]`, `values[i]` on
+/// `[times[i − 1], times[i])` and the last value after the last
+/// breakpoint.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::lattice::PiecewiseVolatility;
+///
+/// let vol = PiecewiseVolatility::new(vec![1.0, 5.0], vec![0.012, 0.010, 0.008]).unwrap();
+/// assert_eq!(vol.value(0.5), 0.012);
+/// assert_eq!(vol.value(1.0), 0.010);
+/// assert_eq!(vol.value(10.0), 0.008);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PiecewiseVolatility {
+    times: Vec<f64>,
+    values: Vec<f64>,
+}
+
+impl PiecewiseVolatility {
+    /// Creates a constant volatility.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `σ` is not positive and
+    /// finite.
+    pub fn constant(volatility: f64) -> Result<Self, ConfigError> {
+        Self::new(Vec::new(), vec![volatility])
+    }
+
+    /// Creates a volatility with breakpoints `times` and one more value
+    /// than breakpoints.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the breakpoints are not
+    /// positive and strictly increasing, the lengths do not match, or a
+    /// value is not positive and finite.
+    pub fn new(times: Vec<f64>, values: Vec<f64>) -> Result<Self, ConfigError> {
+        if values.len() != times.len() + 1 {
+            return Err(ConfigError::InvalidParameter {
+                name: "volatility",
+                value: format!(
+                    "{} values for {} breakpoints (need one more value than breakpoints)",
+                    values.len(),
+                    times.len()
+                ),
+            });
+        }
+        let mut previous = 0.0;
+        for &t in &times {
+            if !t.is_finite() || t <= previous {
+                return Err(ConfigError::InvalidParameter {
+                    name: "volatility_times",
+                    value: format!("{times:?} (must be positive and strictly increasing)"),
+                });
+            }
+            previous = t;
+        }
+        if let Some(&v) = values.iter().find(|v| !v.is_finite() || **v <= 0.0) {
+            return Err(ConfigError::InvalidParameter {
+                name: "volatility",
+                value: v.to_string(),
+            });
+        }
+        Ok(Self { times, values })
+    }
+
+    /// Returns the breakpoints.
+    #[inline]
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Returns the volatility on each interval.
+    #[inline]
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns `σ(t)`.
+    pub fn value(&self, t: f64) -> f64 {
+        let i = self.times.partition_point(|&b| b <= t);
+        self.values[i]
+    }
+
+    /// Conditional variance `∫ₛᵉ σ(u)² e^{−2a(e − u)} du` of `x(e)` given
+    /// `x(s)`.
+    pub(crate) fn decayed_variance(&self, mean_reversion: f64, start: f64, end: f64) -> f64 {
+        let a = mean_reversion;
+        let weight = |u: f64| (-2.0 * a * (end - u)).exp();
+        let mut variance = 0.0;
+        let mut lower = start;
+        for (i, &sigma) in self.values.iter().enumerate() {
+            let upper = self.times.get(i).map_or(end, |&b| b.min(end));
+            if upper > lower {
+                variance += sigma * sigma * (weight(upper) - weight(lower)) / (2.0 * a);
+                lower = upper;
+            }
+            if lower >= end {
+                break;
+            }
+        }
+        variance
+    }
+}
+
+/// Branching from one node to three adjacent nodes on the next level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Branch {
+    /// Index of the down child on the next level.
+    down: usize,
+    /// Probabilities of the down, middle and up children.
+    probabilities: [f64; 3],
+}
+
+/// One time level of the tree.
+#[derive(Clone, Debug, PartialEq)]
+struct Level {
+    /// Node spacing `Δx`.
+    dx: f64,
+    /// Integer position of the first node, `x = (first + m)·Δx`.
+    first: i64,
+    /// Number of nodes.
+    width: usize,
+    /// Branching of each node (empty on the last level).
+    branches: Vec<Branch>,
+    /// Short rate `α_i + x` of each node (empty on the last level).
+    rates: Vec<f64>,
+    /// One-step discount factor `e^{−rΔt}` of each node.
+    discounts: Vec<f64>,
+    /// Arrow-Debreu state prices `Q_{i,j}`.
+    state_prices: Vec<f64>,
+}
+
+/// Hull-White trinomial tree fitted to an initial discount curve.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::lattice::{HullWhiteTree, PiecewiseVolatility};
+///
+/// // Upward-sloping curve, zero rate 2% + 0.2% per year
+/// let curve = |t: f64| (-(0.02 + 0.002 * t) * t).exp();
+/// let vol = PiecewiseVolatility::constant(0.01).unwrap();
+/// let tree = HullWhiteTree::new(0.05, &vol, &[1.0, 5.0, 10.0], 12, curve).unwrap();
+///
+/// // Zero-coupon bonds reprice the curve on every level
+/// for (level, &t) in tree.times().iter().enumerate() {
+///     assert!((tree.discount_factor(level) - curve(t)).abs() < 1e-12);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HullWhiteTree {
+    mean_reversion: f64,
+    times: Vec<f64>,
+    levels: Vec<Level>,
+}
+
+impl HullWhiteTree {
+    /// Builds the tree with mean reversion `a` and volatility `σ(t)` up to
+    /// the last of `event_times`, fitted to `discount(t) = P(0, t)`.
+    ///
+    /// Every event time is a level of the tree; each interval between
+    /// consecutive events is split into `⌈Δt · steps_per_year⌉` equal
+    /// steps.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `a` is not positive,
+    /// `steps_per_year` is zero, an event time is negative or not finite,
+    /// no event time is positive, or a discount factor is not positive and
+    /// finite.
+    pub fn new<F>(
+        mean_reversion: f64,
+        volatility: &PiecewiseVolatility,
+        event_times: &[f64],
+        steps_per_year: usize,
+        discount: F,
+    ) -> Result<Self, ConfigError>
+    where
+        F: Fn(f64) -> f64,
+    {
+        if !mean_reversion.is_finite() || mean_reversion <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "mean_reversion",
+                value: mean_reversion.to_string(),
+            });
+        }
+        let times = tree_times(event_times, steps_per_year)?;
+        let discounts = times[1..]
+            .iter()
+            .map(|&t| {
+                let p = discount(t);
+                if p.is_finite() && p > 0.0 {
+                    Ok(p)
+                } else {
+                    Err(ConfigError::InvalidParameter {
+                        name: "discount",
+                        value: format!("P(0, {t}) = {p}"),
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let a = mean_reversion;
+        let mut levels = Vec::with_capacity(times.len());
+        let mut current = Level {
+            dx: 0.0,
+            first: 0,
+            width: 1,
+            branches: Vec::new(),
+            rates: Vec::new(),
+            discounts: Vec::new(),
+            state_prices: vec![1.0],
+        };
+
+        for (i, &target) in discounts.iter().enumerate() {
+            let dt = times[i + 1] - times[i];
+            let decay = (-a * dt).exp();
+            let dx = (3.0 * volatility.decayed_variance(a, times[i], times[i + 1])).sqrt();
+            let x = |m: usize| (current.first + m as i64) as f64 * current.dx;
+
+            // Branching around the conditional mean
+            let centres: Vec<(i64, f64)> = (0..current.width)
+                .map(|m| {
+                    let mean = x(m) * decay / dx;
+                    let k = mean.round();
+                    (k as i64, mean - k)
+                })
+                .collect();
+            let first = centres.iter().map(|c| c.0).min().unwrap_or(0) - 1;
+            let last = centres.iter().map(|c| c.0).max().unwrap_or(0) + 1;
+            current.branches = centres
+                .iter()
+                .map(|&(k, eta)| {
+                    let eta2 = eta * eta;
+                    Branch {
+                        down: (k - 1 - first) as usize,
+                        probabilities: [
+                            1.0 / 6.0 + 0.5 * (eta2 - eta),
+                            2.0 / 3.0 - eta2,
+                            1.0 / 6.0 + 0.5 * (eta2 + eta),
+                        ],
+                    }
+                })
+                .collect();
+
+            // Arrow-Debreu fit of α_i
+            let sum: f64 = (0..current.width)
+                .map(|m| current.state_prices[m] * (-x(m) * dt).exp())
+                .sum();
+            let alpha = (sum / target).ln() / dt;
+            current.rates = (0..current.width).map(|m| alpha + x(m)).collect();
+            current.discounts = current.rates.iter().map(|r| (-r * dt).exp()).collect();
+
+            let width = (last - first + 1) as usize;
+            let mut state_prices = vec![0.0; width];
+            for (m, branch) in current.branches.iter().enumerate() {
+                let q = current.state_prices[m] * current.discounts[m];
+                for (c, p) in branch.probabilities.iter().enumerate() {
+                    state_prices[branch.down + c] += q * p;
+                }
+            }
+
+            let next = Level {
+                dx,
+                first,
+                width,
+                branches: Vec::new(),
+                rates: Vec::new(),
+                discounts: Vec::new(),
+                state_prices,
+            };
+            levels.push(std::mem::replace(&mut current, next));
+        }
+        levels.push(current);
+
+        Ok(Self {
+            mean_reversion,
+            times,
+            levels,
+        })
+    }
+
+    /// Builds the tree fitted to a [`YieldCurve`](pricer_core::market_data::curves::YieldCurve).
+    ///
+    /// See [`HullWhiteTree::new`] for the time grid.
+    ///
+    /// # Errors
+    ///
+    /// As [`HullWhiteTree::new`], and `ConfigError::InvalidParameter` if
+    /// the curve fails to return a discount factor.
+    #[cfg(feature = "l1l2-integration")]
+    pub fn from_curve<C>(
+        mean_reversion: f64,
+        volatility: &PiecewiseVolatility,
+        event_times: &[f64],
+        steps_per_year: usize,
+        curve: &C,
+    ) -> Result<Self, ConfigError>
+    where
+        C: pricer_core::market_data::curves::YieldCurve<f64>,
+    {
+        // Surface the first curve error instead of a NaN discount factor
+        let times = tree_times(event_times, steps_per_year)?;
+        for &t in &times {
+            curve
+                .discount_factor(t)
+                .map_err(|e| ConfigError::InvalidParameter {
+                    name: "curve",
+                    value: e.to_string(),
+                })?;
+        }
+        Self::new(
+            mean_reversion,
+            volatility,
+            event_times,
+            steps_per_year,
+            |t| curve.discount_factor(t).unwrap_or(f64::NAN),
+        )
+    }
+
+    /// Returns the mean reversion `a`.
+    #[inline]
+    pub fn mean_reversion(&self) -> f64 {
+        self.mean_reversion
+    }
+
+    /// Returns the level times `t₀ = 0 < t₁ < … < t_N`.
+    #[inline]
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Returns the number of steps `N`.
+    #[inline]
+    pub fn n_steps(&self) -> usize {
+        self.times.len() - 1
+    }
+
+    /// Returns the state `x` of each node on `level`, lowest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level > N`.
+    pub fn states(&self, level: usize) -> Vec<f64> {
+        let l = &self.levels[level];
+        (0..l.width)
+            .map(|m| (l.first + m as i64) as f64 * l.dx)
+            .collect()
+    }
+
+    /// Returns the short rate `α_i + x` of each node on `level`, which
+    /// applies over `[t_i, t_{i+1})`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level ≥ N`.
+    pub fn short_rates(&self, level: usize) -> &[f64] {
+        assert!(level < self.n_steps(), "no short rate on the last level");
+        &self.levels[level].rates
+    }
+
+    /// Returns the Arrow-Debreu state prices on `level`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level > N`.
+    #[inline]
+    pub fn state_prices(&self, level: usize) -> &[f64] {
+        &self.levels[level].state_prices
+    }
+
+    /// Returns the tree discount factor `Σ_j Q_{i,j}` to `t_i`, which
+    /// equals the initial curve's `P(0, t_i)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level > N`.
+    pub fn discount_factor(&self, level: usize) -> f64 {
+        self.levels[level].state_prices.iter().sum()
+    }
+
+    /// Returns the level at time `t`, if `t` is a level of the tree.
+    pub fn level_of(&self, t: f64) -> Option<usize> {
+        let i = self.times.partition_point(|&s| s < t - TIME_TOLERANCE);
+        (i < self.times.len() && (self.times[i] - t).abs() <= TIME_TOLERANCE).then_some(i)
+    }
+
+    /// Discounts node values on `level + 1` back to `level`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level ≥ N` or `values` does not have one entry per node
+    /// on `level + 1`.
+    pub fn rollback(&self, level: usize, values: &[f64]) -> Vec<f64> {
+        let l = &self.levels[level];
+        assert_eq!(
+            values.len(),
+            self.levels[level + 1].width,
+            "one value per node on the next level"
+        );
+        l.branches
+            .iter()
+            .zip(&l.discounts)
+            .map(|(branch, df)| {
+                let children = &values[branch.down..branch.down + 3];
+                df * branch
+                    .probabilities
+                    .iter()
+                    .zip(children)
+                    .map(|(p, v)| p * v)
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+
+    /// Returns the number of nodes on `level`.
+    #[inline]
+    pub(crate) fn width(&self, level: usize) -> usize {
+        self.levels[level].width
+    }
+}
+
+/// Merges `event_times` into a grid from zero with at least
+/// `steps_per_year` steps per year.
+fn tree_times(event_times: &[f64], steps_per_year: usize) -> Result<Vec<f64>, ConfigError> {
+    if steps_per_year == 0 {
+        return Err(ConfigError::InvalidParameter {
+            name: "steps_per_year",
+            value: "0".to_string(),
+        });
+    }
+    if event_times.iter().any(|t| !t.is_finite() || *t < 0.0) {
+        return Err(ConfigError::InvalidParameter {
+            name: "event_times",
+            value: format!("{event_times:?} (must be finite and non-negative)"),
+        });
+    }
+    let mut events = event_times.to_vec();
+    events.sort_by(f64::total_cmp);
+    if events.last().is_none_or(|&t| t <= TIME_TOLERANCE) {
+        return Err(ConfigError::InvalidParameter {
+            name: "event_times",
+            value: format!("{event_times:?} (need a positive time)"),
+        });
+    }
+
+    let mut times = vec![0.0];
+    for t in events {
+        let start = *times.last().unwrap_or(&0.0);
+        if t - start <= TIME_TOLERANCE {
+            continue;
+        }
+        let n = ((t - start) * steps_per_year as f64).ceil().max(1.0) as usize;
+        times.extend((1..n).map(|k| start + (t - start) * k as f64 / n as f64));
+        times.push(t);
+    }
+    Ok(times)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::asian::norm_cdf;
+    use approx::assert_relative_eq;
+
+    const A: f64 = 0.1;
+    const SIGMA: f64 = 0.01;
+
+    /// Humped zero curve.
+    fn curve(t: f64) -> f64 {
+        let zero = 0.02 + 0.01 * (1.0 - (-t / 3.0).exp()) - 0.002 * t * (-t / 5.0).exp();
+        (-zero * t).exp()
+    }
+
+    fn tree(event_times: &[f64], steps_per_year: usize) -> HullWhiteTree {
+        let vol = PiecewiseVolatility::constant(SIGMA).unwrap();
+        HullWhiteTree::new(A, &vol, event_times, steps_per_year, curve).unwrap()
+    }
+
+    /// Zero-coupon bond paying 1 at the last level, rolled back to zero.
+    fn zero_bond(tree: &HullWhiteTree, maturity: usize) -> f64 {
+        let mut values = vec![1.0; tree.width(maturity)];
+        for level in (0..maturity).rev() {
+            values = tree.rollback(level, &values);
+        }
+        values[0]
+    }
+
+    #[test]
+    fn test_tree_times_include_events() {
+        let tree = tree(&[0.25, 1.0, 1.0, 2.5], 4);
+        let times = tree.times();
+        assert_eq!(times[0], 0.0);
+        assert!(times.windows(2).all(|w| w[1] > w[0]));
+        for t in [0.25, 1.0, 2.5] {
+            assert!(tree.level_of(t).is_some());
+        }
+        assert!(tree.level_of(0.3).is_none());
+        // 0.25 → 1 step, 0.75 → 3 steps, 1.5 → 6 steps
+        assert_eq!(tree.n_steps(), 10);
+    }
+
+    #[test]
+    fn test_reprices_initial_curve_exactly() {
+        let tree = tree(&[30.0], 4);
+        for (level, &t) in tree.times().iter().enumerate() {
+            assert_relative_eq!(tree.discount_factor(level), curve(t), max_relative = 1e-12);
+        }
+        // Backward induction agrees with forward induction
+        for level in [1, 20, 120] {
+            let t = tree.times()[level];
+            assert_relative_eq!(zero_bond(&tree, level), curve(t), max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_probabilities_are_valid() {
+        let tree = tree(&[10.0], 12);
+        for level in &tree.levels[..tree.n_steps()] {
+            for branch in &level.branches {
+                assert!(branch.probabilities.iter().all(|&p| p > 0.0));
+                assert_relative_eq!(branch.probabilities.iter().sum::<f64>(), 1.0);
+            }
+        }
+        // Mean reversion bounds the tree width
+        let widths: Vec<usize> = (0..=tree.n_steps()).map(|i| tree.width(i)).collect();
+        assert!(widths[widths.len() - 1] == widths[widths.len() - 13]);
+    }
+
+    #[test]
+    fn test_state_variance_matches_time_dependent_sigma() {
+        let vol = PiecewiseVolatility::new(vec![1.0, 2.5], vec![0.015, 0.005, 0.01]).unwrap();
+        let tree = HullWhiteTree::new(A, &vol, &[4.0], 50, curve).unwrap();
+
+        // Forward-measure moments of x(T) from the state prices
+        let n = tree.n_steps();
+        let q = tree.state_prices(n);
+        let x = tree.states(n);
+        let p: f64 = q.iter().sum();
+        let mean: f64 = q.iter().zip(&x).map(|(q, x)| q * x).sum::<f64>() / p;
+        let var: f64 = q
+            .iter()
+            .zip(&x)
+            .map(|(q, x)| q * (x - mean).powi(2))
+            .sum::<f64>()
+            / p;
+
+        // Var[x(T)] = ∫ σ(u)² e^{−2a(T−u)} du
+        let exact = (0.015_f64.powi(2) * ((-2.0 * A * 3.0_f64).exp() - (-2.0 * A * 4.0_f64).exp())
+            + 0.005_f64.powi(2) * ((-2.0 * A * 1.5_f64).exp() - (-2.0 * A * 3.0_f64).exp())
+            + 0.01_f64.powi(2) * (1.0 - (-2.0 * A * 1.5_f64).exp()))
+            / (2.0 * A);
+        assert_relative_eq!(
+            vol.decayed_variance(A, 0.0, 4.0),
+            exact,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(var, exact, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn test_european_bond_option_matches_closed_form() {
+        // 2y put on a 7y zero-coupon bond
+        let (expiry, maturity) = (2.0, 7.0);
+        let tree = tree(&[expiry, maturity], 50);
+        let (e, m) = (
+            tree.level_of(expiry).unwrap(),
+            tree.level_of(maturity).unwrap(),
+        );
+
+        let mut bond = vec![1.0; tree.width(m)];
+        for level in (e..m).rev() {
+            bond = tree.rollback(level, &bond);
+        }
+        let strike = curve(maturity) / curve(expiry);
+        let mut put: Vec<f64> = bond.iter().map(|b| (strike - b).max(0.0)).collect();
+        for level in (0..e).rev() {
+            put = tree.rollback(level, &put);
+        }
+
+        // ZBP(0, T, S, K) in Hull-White
+        let b = (1.0 - (-A * (maturity - expiry)).exp()) / A;
+        let sigma_p = SIGMA * b * ((1.0 - (-2.0 * A * expiry).exp()) / (2.0 * A)).sqrt();
+        let h = (curve(maturity) / (curve(expiry) * strike)).ln() / sigma_p + 0.5 * sigma_p;
+        let exact =
+            strike * curve(expiry) * norm_cdf(-h + sigma_p) - curve(maturity) * norm_cdf(-h);
+        assert_relative_eq!(put[0], exact, max_relative = 5e-3);
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_from_yield_curve() {
+        use pricer_core::market_data::curves::FlatCurve;
+
+        let vol = PiecewiseVolatility::constant(SIGMA).unwrap();
+        let curve = FlatCurve::new(0.03);
+        let tree = HullWhiteTree::from_curve(A, &vol, &[5.0], 12, &curve).unwrap();
+        let n = tree.n_steps();
+        assert_relative_eq!(
+            tree.discount_factor(n),
+            (-0.15_f64).exp(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_inputs() {
+        let vol = PiecewiseVolatility::constant(SIGMA).unwrap();
+        assert!(HullWhiteTree::new(0.0, &vol, &[1.0], 10, curve).is_err());
+        assert!(HullWhiteTree::new(A, &vol, &[1.0], 0, curve).is_err());
+        assert!(HullWhiteTree::new(A, &vol, &[-1.0, 1.0], 10, curve).is_err());
+        assert!(HullWhiteTree::new(A, &vol, &[0.0], 10, curve).is_err());
+        assert!(HullWhiteTree::new(A, &vol, &[1.0], 10, |_| f64::NAN).is_err());
+
+        assert!(PiecewiseVolatility::constant(-0.01).is_err());
+        assert!(PiecewiseVolatility::new(vec![1.0], vec![0.01]).is_err());
+        assert!(PiecewiseVolatility::new(vec![2.0, 1.0], vec![0.01; 3]).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! Recombining lattices for early-exercise equity options and short-rate
+//! models.
+//!
+//! This module prices early-exercise options and callable interest-rate
+//! products by backward induction on a tree, as a deterministic
+//! alternative to the regression-based [`lsmc`](crate::lsmc) engine and to
+//! the [`fd`](crate::fd) PDE solvers.
+//!
+//! # Key Components
+//!
+//! - `EquityBinomialTree`: Cox-Ross-Rubinstein tree over an equity market
+//!   with discrete dividends, for European, American and Bermudan exercise
+//!   (requires `l1l2-integration`)
+//! - [`HullWhiteTree`]: one-factor Hull-White trinomial tree fitted
+//!   exactly to an initial discount curve by Arrow-Debreu forward
+//!   induction
+//! - [`PiecewiseVolatility`]: piecewise-constant `σ(t)`
+//!
+//! # Contracts
+//!
+//! - [`BermudanSwaption`](crate::lsmc::BermudanSwaption), shared with the
+//!   LSMC engine
+//! - [`CancellableSwap`]: a swap that one side may terminate on given
+//!   dates ([`CancellationRight`])
+//! - [`CallableBond`]: a fixed-coupon bond the issuer may redeem early
+
+#[cfg(feature = "l1l2-integration")]
+mod binomial;
+mod hull_white;
+mod products;
+
+#[cfg(feature = "l1l2-integration")]
+pub use binomial::EquityBinomialTree;
+pub use hull_white::{HullWhiteTree, PiecewiseVolatility};
+pub use products::{CallableBond, CancellableSwap, CancellationRight};
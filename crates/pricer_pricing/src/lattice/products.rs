@@ -0,0 +1,550 @@
+//! Callable interest-rate contracts priced by backward induction on a
+//! [`HullWhiteTree`].
+//!
+//! Fixed legs are rolled back as a running value `U` of the remaining
+//! fixed coupons plus notional. On a reset date `Tᵢ` the floating leg plus
+//! notional is worth par, so the payer swap entered at `Tᵢ` is worth
+//! `1 − U(Tᵢ)` per unit notional at every node.
+
+use super::hull_white::HullWhiteTree;
+use crate::lsmc::BermudanSwaption;
+use crate::mc::ConfigError;
+
+/// Side holding the right to terminate a [`CancellableSwap`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CancellationRight {
+    /// We may cancel (a callable swap from the holder's view).
+    Holder,
+    /// The counterparty may cancel.
+    Counterparty,
+}
+
+/// Swap that one side may terminate on given schedule dates.
+///
+/// Cancelling at `schedule[j]` removes all periods after `schedule[j]`,
+/// which is the same as entering the opposite swap over those periods.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CancellableSwap {
+    /// Swap schedule `T₀ < T₁ < … < Tₙ` in years, with `T₀ ≥ 0`.
+    pub schedule: Vec<f64>,
+    /// Fixed rate.
+    pub fixed_rate: f64,
+    /// Notional.
+    pub notional: f64,
+    /// Payer (pay fixed) if true, receiver otherwise.
+    pub is_payer: bool,
+    /// Cancellation dates in years; each must be a schedule date strictly
+    /// between `T₀` and `Tₙ`.
+    pub cancellation_times: Vec<f64>,
+    /// Side holding the cancellation right.
+    pub right: CancellationRight,
+}
+
+/// Fixed-coupon bond the issuer may redeem on given schedule dates.
+///
+/// The bond pays `coupon · τᵢ · notional` at each `Tᵢ`, `i ≥ 1`, and the
+/// notional at `Tₙ`. Calling at `Tⱼ` pays the coupon due at `Tⱼ` plus
+/// `call_price · notional` and cancels all later payments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallableBond {
+    /// Accrual schedule `T₀ < T₁ < … < Tₙ` in years, with `T₀ ≥ 0`.
+    pub schedule: Vec<f64>,
+    /// Annual coupon rate.
+    pub coupon: f64,
+    /// Notional.
+    pub notional: f64,
+    /// Call dates in years; each must be a schedule date strictly between
+    /// `T₀` and `Tₙ`. Empty for a straight bond.
+    pub call_times: Vec<f64>,
+    /// Call price per unit notional for each call date.
+    pub call_prices: Vec<f64>,
+}
+
+impl HullWhiteTree {
+    /// Prices a Bermudan swaption.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_pricing::lattice::{HullWhiteTree, PiecewiseVolatility};
+    /// use pricer_pricing::lsmc::BermudanSwaption;
+    ///
+    /// // 1y into 5y annual payer, exercisable every year
+    /// let schedule: Vec<f64> = (1..=6).map(f64::from).collect();
+    /// let swaption = BermudanSwaption {
+    ///     exercise_times: schedule[..5].to_vec(),
+    ///     schedule,
+    ///     strike: 0.03,
+    ///     notional: 1.0,
+    ///     is_payer: true,
+    /// };
+    ///
+    /// let vol = PiecewiseVolatility::constant(0.01).unwrap();
+    /// let tree =
+    ///     HullWhiteTree::new(0.05, &vol, &swaption.schedule, 24, |t| (-0.03 * t).exp()).unwrap();
+    /// let price = tree.price_bermudan_swaption(&swaption).unwrap();
+    /// assert!(price > 0.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the schedule is not a
+    /// strictly increasing set of tree levels with at least one period,
+    /// or an exercise date is not a schedule date before the last.
+    pub fn price_bermudan_swaption(&self, swaption: &BermudanSwaption) -> Result<f64, ConfigError> {
+        let schedule = &swaption.schedule;
+        let levels = self.schedule_levels(schedule)?;
+        let n = schedule.len() - 1;
+        let exercise = schedule_flags(schedule, &swaption.exercise_times, 0..n, "exercise_times")?;
+        let sign = if swaption.is_payer { 1.0 } else { -1.0 };
+
+        let mut level = levels[n];
+        let mut fixed = vec![0.0; self.width(level)];
+        let mut option = vec![0.0; self.width(level)];
+        for i in (0..=n).rev() {
+            while level > levels[i] {
+                level -= 1;
+                fixed = self.rollback(level, &fixed);
+                option = self.rollback(level, &option);
+            }
+            if exercise[i] {
+                for (v, u) in option.iter_mut().zip(&fixed) {
+                    *v = v.max(swaption.notional * sign * (1.0 - u));
+                }
+            }
+            if i > 0 {
+                let cash = fixed_cash_flow(schedule, i, swaption.strike);
+                fixed.iter_mut().for_each(|u| *u += cash);
+            }
+        }
+        Ok(self.rollback_to_zero(level, option))
+    }
+
+    /// Prices a cancellable swap from the holder's side.
+    ///
+    /// The value is the plain swap plus (holder's right) or minus
+    /// (counterparty's right) a Bermudan swaption on the opposite swap
+    /// exercisable on the cancellation dates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the schedule is not a
+    /// strictly increasing set of tree levels with at least one period,
+    /// or a cancellation date is not a schedule date strictly between the
+    /// first and the last.
+    pub fn price_cancellable_swap(&self, swap: &CancellableSwap) -> Result<f64, ConfigError> {
+        let schedule = &swap.schedule;
+        let levels = self.schedule_levels(schedule)?;
+        let n = schedule.len() - 1;
+        schedule_flags(
+            schedule,
+            &swap.cancellation_times,
+            1..n,
+            "cancellation_times",
+        )?;
+
+        // Forward swap from today's curve, which the tree reprices exactly
+        let fixed: f64 = (1..=n)
+            .map(|i| {
+                fixed_cash_flow(schedule, i, swap.fixed_rate) * self.discount_factor(levels[i])
+            })
+            .sum();
+        let payer = self.discount_factor(levels[0]) - fixed;
+        let underlying = swap.notional * if swap.is_payer { payer } else { -payer };
+
+        let option = self.price_bermudan_swaption(&BermudanSwaption {
+            exercise_times: swap.cancellation_times.clone(),
+            schedule: schedule.clone(),
+            strike: swap.fixed_rate,
+            notional: swap.notional,
+            is_payer: !swap.is_payer,
+        })?;
+        Ok(match swap.right {
+            CancellationRight::Holder => underlying + option,
+            CancellationRight::Counterparty => underlying - option,
+        })
+    }
+
+    /// Prices a callable bond from the bondholder's side.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the schedule is not a
+    /// strictly increasing set of tree levels with at least one period,
+    /// a call date is not a schedule date strictly between the first and
+    /// the last, or the call prices do not match the call dates or are
+    /// not positive and finite.
+    pub fn price_callable_bond(&self, bond: &CallableBond) -> Result<f64, ConfigError> {
+        let schedule = &bond.schedule;
+        let levels = self.schedule_levels(schedule)?;
+        let n = schedule.len() - 1;
+        let callable = schedule_flags(schedule, &bond.call_times, 1..n, "call_times")?;
+        if bond.call_prices.len() != bond.call_times.len()
+            || bond.call_prices.iter().any(|p| !p.is_finite() || *p <= 0.0)
+        {
+            return Err(ConfigError::InvalidParameter {
+                name: "call_prices",
+                value: format!(
+                    "{:?} (need one positive price per call date)",
+                    bond.call_prices
+                ),
+            });
+        }
+
+        let mut call_prices = bond.call_prices.iter();
+        let mut level = levels[n];
+        let mut value = vec![0.0; self.width(level)];
+        for i in (0..=n).rev() {
+            while level > levels[i] {
+                level -= 1;
+                value = self.rollback(level, &value);
+            }
+            if callable[i] {
+                // Call dates are increasing, so take prices from the back
+                let price = bond.notional * call_prices.next_back().copied().unwrap_or(f64::NAN);
+                value.iter_mut().for_each(|v| *v = v.min(price));
+            }
+            if i > 0 {
+                let cash = bond.notional * fixed_cash_flow(schedule, i, bond.coupon);
+                value.iter_mut().for_each(|v| *v += cash);
+            }
+        }
+        Ok(self.rollback_to_zero(level, value))
+    }
+
+    /// Maps a swap schedule to tree levels.
+    fn schedule_levels(&self, schedule: &[f64]) -> Result<Vec<usize>, ConfigError> {
+        if schedule.len() < 2 || schedule.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(ConfigError::InvalidParameter {
+                name: "schedule",
+                value: format!("{schedule:?} (needs at least one increasing period)"),
+            });
+        }
+        schedule
+            .iter()
+            .map(|&t| {
+                self.level_of(t)
+                    .ok_or_else(|| ConfigError::InvalidParameter {
+                        name: "schedule",
+                        value: format!("{t} is not a tree time (pass the schedule as event times)"),
+                    })
+            })
+            .collect()
+    }
+
+    /// Rolls node values on `level` back to the root.
+    fn rollback_to_zero(&self, level: usize, mut values: Vec<f64>) -> f64 {
+        for l in (0..level).rev() {
+            values = self.rollback(l, &values);
+        }
+        values[0]
+    }
+}
+
+/// Fixed cash flow per unit notional at `schedule[i]`, including the
+/// notional on the last date.
+fn fixed_cash_flow(schedule: &[f64], i: usize, rate: f64) -> f64 {
+    let coupon = rate * (schedule[i] - schedule[i - 1]);
+    if i == schedule.len() - 1 {
+        coupon + 1.0
+    } else {
+        coupon
+    }
+}
+
+/// Flags the schedule dates listed in `times`, which must be strictly
+/// increasing and fall on schedule indices in `allowed`.
+fn schedule_flags(
+    schedule: &[f64],
+    times: &[f64],
+    allowed: std::ops::Range<usize>,
+    name: &'static str,
+) -> Result<Vec<bool>, ConfigError> {
+    if times.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(ConfigError::InvalidParameter {
+            name,
+            value: format!("{times:?} (must be strictly increasing)"),
+        });
+    }
+    let mut flags = vec![false; schedule.len()];
+    for &t in times {
+        let index = allowed
+            .clone()
+            .find(|&i| (schedule[i] - t).abs() <= super::hull_white::TIME_TOLERANCE)
+            .ok_or_else(|| ConfigError::InvalidParameter {
+                name,
+                value: format!("{t} is not an eligible date in {schedule:?}"),
+            })?;
+        flags[index] = true;
+    }
+    Ok(flags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::asian::norm_cdf;
+    use crate::lattice::PiecewiseVolatility;
+    use crate::lsmc::{HullWhiteSwaption, LsmcConfig, LsmcPricer};
+    use approx::assert_relative_eq;
+
+    const A: f64 = 0.05;
+    const SIGMA: f64 = 0.01;
+
+    /// Upward-sloping zero curve.
+    fn curve(t: f64) -> f64 {
+        (-(0.02 + 0.002 * t) * t).exp()
+    }
+
+    fn tree_with(vol: &PiecewiseVolatility, schedule: &[f64]) -> HullWhiteTree {
+        HullWhiteTree::new(A, vol, schedule, 48, curve).unwrap()
+    }
+
+    fn tree(schedule: &[f64]) -> HullWhiteTree {
+        tree_with(&PiecewiseVolatility::constant(SIGMA).unwrap(), schedule)
+    }
+
+    /// 1y into 5y annual swap schedule.
+    fn schedule() -> Vec<f64> {
+        (1..=6).map(f64::from).collect()
+    }
+
+    fn swaption(exercise_times: &[f64], strike: f64, is_payer: bool) -> BermudanSwaption {
+        BermudanSwaption {
+            exercise_times: exercise_times.to_vec(),
+            schedule: schedule(),
+            strike,
+            notional: 100.0,
+            is_payer,
+        }
+    }
+
+    /// Jamshidian's decomposition of a European payer swaption on the
+    /// fitted curve.
+    fn jamshidian_payer(schedule: &[f64], strike: f64, notional: f64) -> f64 {
+        let t0 = schedule[0];
+        let b = |tau: f64| (1.0 - (-A * tau).exp()) / A;
+        let x_var = SIGMA * SIGMA * (1.0 - (-2.0 * A * t0).exp()) / (2.0 * A);
+        let bond = |t: f64, x: f64| {
+            let bt = b(t - t0);
+            curve(t) / curve(t0) * (-bt * x - 0.5 * bt * bt * x_var).exp()
+        };
+        let coupons: Vec<(f64, f64)> = (1..schedule.len())
+            .map(|i| (schedule[i], fixed_cash_flow(schedule, i, strike)))
+            .collect();
+        let bond_sum = |x: f64| -> f64 { coupons.iter().map(|&(t, c)| c * bond(t, x)).sum() };
+
+        let (mut lo, mut hi) = (-1.0, 1.0);
+        for _ in 0..200 {
+            let mid = 0.5 * (lo + hi);
+            if bond_sum(mid) > 1.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let x_star = 0.5 * (lo + hi);
+
+        let value: f64 = coupons
+            .iter()
+            .map(|&(t, c)| {
+                let k = bond(t, x_star);
+                let sigma_p = x_var.sqrt() * b(t - t0);
+                let h = (curve(t) / (curve(t0) * k)).ln() / sigma_p + 0.5 * sigma_p;
+                c * (k * curve(t0) * norm_cdf(-h + sigma_p) - curve(t) * norm_cdf(-h))
+            })
+            .sum();
+        notional * value
+    }
+
+    #[test]
+    fn test_european_swaption_matches_jamshidian() {
+        let tree = tree(&schedule());
+        for strike in [0.025, 0.03, 0.035] {
+            let price = tree
+                .price_bermudan_swaption(&swaption(&[1.0], strike, true))
+                .unwrap();
+            let exact = jamshidian_payer(&schedule(), strike, 100.0);
+            assert_relative_eq!(price, exact, max_relative = 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_bermudan_dominates_co_terminal_europeans() {
+        let schedule = schedule();
+        let tree = tree(&schedule);
+        let exercise = &schedule[..5];
+        let bermudan = tree
+            .price_bermudan_swaption(&swaption(exercise, 0.03, false))
+            .unwrap();
+        for &t in exercise {
+            let european = tree
+                .price_bermudan_swaption(&swaption(&[t], 0.03, false))
+                .unwrap();
+            assert!(bermudan > european, "{bermudan} vs {european} at {t}");
+        }
+    }
+
+    #[test]
+    fn test_bermudan_matches_lsmc_on_flat_curve() {
+        let rate = 0.03;
+        let schedule = schedule();
+        let contract = swaption(&schedule[..5], rate, true);
+        let vol = PiecewiseVolatility::constant(SIGMA).unwrap();
+        let tree = HullWhiteTree::new(A, &vol, &schedule, 48, |t| (-rate * t).exp()).unwrap();
+        let lattice = tree.price_bermudan_swaption(&contract).unwrap();
+
+        let model = HullWhiteSwaption::new(A, SIGMA, rate, contract).unwrap();
+        let config = LsmcConfig::builder()
+            .n_paths(20_000)
+            .n_pricing_paths(100_000)
+            .seed(3)
+            .build()
+            .unwrap();
+        let lsmc = LsmcPricer::new(config).unwrap().price(&model).unwrap();
+        // LSMC is a lower bound with a small regression bias
+        assert!(
+            (lattice - lsmc.price).abs() < 4.0 * lsmc.std_error + 0.01 * lattice,
+            "tree {lattice} vs LSMC {} (se {})",
+            lsmc.price,
+            lsmc.std_error
+        );
+    }
+
+    #[test]
+    fn test_time_dependent_volatility() {
+        let schedule = schedule();
+        let contract = swaption(&[1.0], 0.03, true);
+        let flat = tree(&schedule).price_bermudan_swaption(&contract).unwrap();
+
+        // Only volatility before expiry matters for a European option
+        let before = PiecewiseVolatility::new(vec![1.0], vec![2.0 * SIGMA, SIGMA]).unwrap();
+        let after = PiecewiseVolatility::new(vec![1.0], vec![SIGMA, 2.0 * SIGMA]).unwrap();
+        let high = tree_with(&before, &schedule)
+            .price_bermudan_swaption(&contract)
+            .unwrap();
+        let same = tree_with(&after, &schedule)
+            .price_bermudan_swaption(&contract)
+            .unwrap();
+        assert!(high > 1.5 * flat);
+        assert_relative_eq!(same, flat, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn test_callable_bond_is_straight_bond_less_receiver_bermudan() {
+        let schedule = schedule();
+        let tree = tree(&schedule);
+        let straight = CallableBond {
+            schedule: schedule.clone(),
+            coupon: 0.03,
+            notional: 100.0,
+            call_times: Vec::new(),
+            call_prices: Vec::new(),
+        };
+        let price = tree.price_callable_bond(&straight).unwrap();
+        let exact: f64 = (1..schedule.len())
+            .map(|i| 100.0 * fixed_cash_flow(&schedule, i, 0.03) * curve(schedule[i]))
+            .sum();
+        assert_relative_eq!(price, exact, max_relative = 1e-12);
+
+        // Calling at par is the issuer's receiver swaption
+        let call_times = schedule[1..5].to_vec();
+        let callable = CallableBond {
+            call_prices: vec![1.0; call_times.len()],
+            call_times: call_times.clone(),
+            ..straight
+        };
+        let called = tree.price_callable_bond(&callable).unwrap();
+        let option = tree
+            .price_bermudan_swaption(&swaption(&call_times, 0.03, false))
+            .unwrap();
+        assert!(called < price);
+        assert_relative_eq!(called, price - option, max_relative = 1e-10);
+
+        // A call price above any attainable bond value is never exercised
+        let expensive = CallableBond {
+            call_prices: vec![10.0; call_times.len()],
+            ..callable
+        };
+        assert_relative_eq!(
+            tree.price_callable_bond(&expensive).unwrap(),
+            price,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_cancellable_swap_bounds() {
+        let schedule = schedule();
+        let tree = tree(&schedule);
+        let swap = |right, fixed_rate, cancellation_times: Vec<f64>| CancellableSwap {
+            schedule: schedule.clone(),
+            fixed_rate,
+            notional: 100.0,
+            is_payer: true,
+            cancellation_times,
+            right,
+        };
+        let plain = tree
+            .price_cancellable_swap(&swap(CancellationRight::Holder, 0.03, Vec::new()))
+            .unwrap();
+        let cancel_times = schedule[1..5].to_vec();
+        let holder = tree
+            .price_cancellable_swap(&swap(CancellationRight::Holder, 0.03, cancel_times.clone()))
+            .unwrap();
+        let counterparty = tree
+            .price_cancellable_swap(&swap(
+                CancellationRight::Counterparty,
+                0.03,
+                cancel_times.clone(),
+            ))
+            .unwrap();
+        assert!(holder > plain && plain > counterparty);
+        assert!(holder > 0.0);
+
+        // Deep in-the-money payer swap: the holder never cancels
+        let deep = swap(CancellationRight::Holder, -0.05, Vec::new());
+        let deep_plain = tree.price_cancellable_swap(&deep).unwrap();
+        let deep_holder = tree
+            .price_cancellable_swap(&CancellableSwap {
+                cancellation_times: cancel_times,
+                ..deep
+            })
+            .unwrap();
+        assert_relative_eq!(deep_holder, deep_plain, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_rejects_invalid_contracts() {
+        let tree = tree(&schedule());
+        // Schedule dates off the tree
+        let off = BermudanSwaption {
+            schedule: vec![1.0, 1.51, 2.0],
+            ..swaption(&[1.0], 0.03, true)
+        };
+        assert!(tree.price_bermudan_swaption(&off).is_err());
+        // Exercise on the last date
+        assert!(tree
+            .price_bermudan_swaption(&swaption(&[6.0], 0.03, true))
+            .is_err());
+        // Cancellation on the first date
+        let swap = CancellableSwap {
+            schedule: schedule(),
+            fixed_rate: 0.03,
+            notional: 1.0,
+            is_payer: true,
+            cancellation_times: vec![1.0],
+            right: CancellationRight::Holder,
+        };
+        assert!(tree.price_cancellable_swap(&swap).is_err());
+        // Missing call price
+        let bond = CallableBond {
+            schedule: schedule(),
+            coupon: 0.03,
+            notional: 1.0,
+            call_times: vec![2.0, 3.0],
+            call_prices: vec![1.0],
+        };
+        assert!(tree.price_callable_bond(&bond).is_err());
+    }
+}
@@ -129,6 +129,15 @@ pub mod greeks;
 // Thread-local buffer pool for allocation-free simulation
 pub mod pool;
 
+// Early exercise: Longstaff-Schwartz with Andersen-Broadie upper bounds
+pub mod lsmc;
+
+// Finite-difference PDE engine (Crank-Nicolson, ADI)
+pub mod fd;
+
+// Lattices: equity binomial (CRR) and short-rate trinomial (Hull-White)
+pub mod lattice;
+
 // Re-export commonly used items for convenience
 pub use enzyme::{gradient, gradient_with_step, ADMode, Activity};
 pub use greeks::{GreeksConfig, GreeksMode, GreeksResult};
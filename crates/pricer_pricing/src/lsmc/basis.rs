@@ -0,0 +1,181 @@
+//! Regression basis functions for Longstaff-Schwartz.
+//!
+//! The continuation value is regressed on polynomials of the (normalised)
+//! regressors. With `d` regressors and degree `p`, the basis holds every
+//! product `P_{k₁}(x₁)·…·P_{k_d}(x_d)` with `k₁ + … + k_d ≤ p`, so cross
+//! terms such as spot × variance under Heston are included.
+
+/// Polynomial family of the regression basis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BasisFamily {
+    /// Monomials `1, x, x², …`.
+    #[default]
+    Monomial,
+    /// Laguerre polynomials `L₀ = 1, L₁ = 1 − x, …` (Longstaff-Schwartz).
+    Laguerre,
+    /// Probabilists' Hermite polynomials `He₀ = 1, He₁ = x, He₂ = x² − 1, …`.
+    Hermite,
+}
+
+impl BasisFamily {
+    /// Evaluates `P₀(x)..=P_degree(x)` into `out` by the three-term
+    /// recurrence of the family.
+    fn evaluate(self, x: f64, out: &mut [f64]) {
+        if out.is_empty() {
+            return;
+        }
+        out[0] = 1.0;
+        if out.len() == 1 {
+            return;
+        }
+        out[1] = match self {
+            Self::Monomial | Self::Hermite => x,
+            Self::Laguerre => 1.0 - x,
+        };
+        for k in 1..out.len() - 1 {
+            let kf = k as f64;
+            out[k + 1] = match self {
+                Self::Monomial => x * out[k],
+                Self::Laguerre => ((2.0 * kf + 1.0 - x) * out[k] - kf * out[k - 1]) / (kf + 1.0),
+                Self::Hermite => x * out[k] - kf * out[k - 1],
+            };
+        }
+    }
+}
+
+/// Regression basis: polynomial family and maximum total degree.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::lsmc::{BasisFamily, BasisFunctions};
+///
+/// let basis = BasisFunctions::new(BasisFamily::Laguerre, 3);
+/// // Constant, linear, quadratic and cubic terms of one regressor
+/// assert_eq!(basis.n_terms(1), 4);
+/// // Ten terms of total degree ≤ 3 in two regressors
+/// assert_eq!(basis.n_terms(2), 10);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasisFunctions {
+    /// Polynomial family.
+    pub family: BasisFamily,
+    /// Maximum total degree of the basis terms.
+    pub degree: usize,
+}
+
+impl Default for BasisFunctions {
+    /// Monomials up to degree 3.
+    fn default() -> Self {
+        Self::new(BasisFamily::Monomial, 3)
+    }
+}
+
+impl BasisFunctions {
+    /// Creates a basis of `family` polynomials up to total `degree`.
+    #[inline]
+    pub fn new(family: BasisFamily, degree: usize) -> Self {
+        Self { family, degree }
+    }
+
+    /// Number of basis terms for `n_regressors` regressors:
+    /// `C(degree + n_regressors, n_regressors)`.
+    pub fn n_terms(&self, n_regressors: usize) -> usize {
+        (1..=n_regressors).fold(1, |acc, k| acc * (self.degree + k) / k)
+    }
+
+    /// Evaluates every basis term at `x` into `out`.
+    ///
+    /// `scratch` must hold `(degree + 1) × x.len()` values and `out`
+    /// [`n_terms(x.len())`](Self::n_terms) values. Terms are ordered by
+    /// the multi-index, last regressor fastest.
+    pub fn evaluate(&self, x: &[f64], scratch: &mut [f64], out: &mut [f64]) {
+        let width = self.degree + 1;
+        for (i, &xi) in x.iter().enumerate() {
+            self.family
+                .evaluate(xi, &mut scratch[i * width..(i + 1) * width]);
+        }
+        let mut index = 0;
+        self.fill_terms(x.len(), 0, self.degree, 1.0, scratch, out, &mut index);
+        debug_assert_eq!(index, out.len());
+    }
+
+    /// Recursively multiplies univariate terms of the remaining regressors
+    /// while the remaining degree allows.
+    #[allow(clippy::too_many_arguments)]
+    fn fill_terms(
+        &self,
+        n_regressors: usize,
+        regressor: usize,
+        remaining: usize,
+        product: f64,
+        scratch: &[f64],
+        out: &mut [f64],
+        index: &mut usize,
+    ) {
+        if regressor == n_regressors {
+            out[*index] = product;
+            *index += 1;
+            return;
+        }
+        let row = &scratch[regressor * (self.degree + 1)..];
+        for (k, &term) in row.iter().enumerate().take(remaining + 1) {
+            self.fill_terms(
+                n_regressors,
+                regressor + 1,
+                remaining - k,
+                product * term,
+                scratch,
+                out,
+                index,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_univariate_families() {
+        let x = 0.7;
+        let mut out = [0.0; 4];
+
+        BasisFamily::Monomial.evaluate(x, &mut out);
+        assert_relative_eq!(out[3], x * x * x, epsilon = 1e-14);
+
+        BasisFamily::Laguerre.evaluate(x, &mut out);
+        assert_relative_eq!(out[2], 0.5 * (x * x - 4.0 * x + 2.0), epsilon = 1e-14);
+        assert_relative_eq!(
+            out[3],
+            (-x * x * x + 9.0 * x * x - 18.0 * x + 6.0) / 6.0,
+            epsilon = 1e-14
+        );
+
+        BasisFamily::Hermite.evaluate(x, &mut out);
+        assert_relative_eq!(out[2], x * x - 1.0, epsilon = 1e-14);
+        assert_relative_eq!(out[3], x * x * x - 3.0 * x, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn test_multivariate_terms() {
+        let basis = BasisFunctions::new(BasisFamily::Monomial, 2);
+        let x = [2.0, 3.0];
+        let mut scratch = [0.0; 6];
+        let mut out = vec![0.0; basis.n_terms(2)];
+        basis.evaluate(&x, &mut scratch, &mut out);
+        // 1, y, y², x, xy, x²
+        assert_eq!(out, vec![1.0, 3.0, 9.0, 2.0, 6.0, 4.0]);
+    }
+
+    #[test]
+    fn test_n_terms() {
+        let basis = BasisFunctions::new(BasisFamily::Hermite, 4);
+        assert_eq!(basis.n_terms(0), 1);
+        assert_eq!(basis.n_terms(1), 5);
+        assert_eq!(basis.n_terms(2), 15);
+        assert_eq!(basis.n_terms(3), 35);
+    }
+}
@@ -0,0 +1,240 @@
+//! Longstaff-Schwartz configuration.
+
+use super::basis::BasisFunctions;
+use crate::mc::config::MAX_PATHS;
+use crate::mc::ConfigError;
+
+/// Nested simulation sizes for the Andersen-Broadie upper bound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DualityConfig {
+    /// Number of outer paths along which the martingale is built.
+    pub outer_paths: usize,
+    /// Number of sub-paths per exercise date estimating the continuation
+    /// value of the exercise policy.
+    pub inner_paths: usize,
+}
+
+impl DualityConfig {
+    /// Creates a duality configuration.
+    #[inline]
+    pub fn new(outer_paths: usize, inner_paths: usize) -> Self {
+        Self {
+            outer_paths,
+            inner_paths,
+        }
+    }
+}
+
+/// Longstaff-Schwartz simulation configuration.
+///
+/// The exercise policy is fitted on `n_paths` regression paths and then
+/// priced on `n_pricing_paths` independent paths, so the reported price
+/// is a low-biased estimate of the true value.
+///
+/// # Examples
+///
+/// ```rust
+/// use pricer_pricing::lsmc::{BasisFamily, BasisFunctions, DualityConfig, LsmcConfig};
+///
+/// let config = LsmcConfig::builder()
+///     .n_paths(20_000)
+///     .basis(BasisFunctions::new(BasisFamily::Laguerre, 3))
+///     .duality(DualityConfig::new(500, 100))
+///     .seed(42)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(config.n_pricing_paths(), 20_000);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LsmcConfig {
+    n_paths: usize,
+    n_pricing_paths: usize,
+    basis: BasisFunctions,
+    seed: Option<u64>,
+    duality: Option<DualityConfig>,
+}
+
+impl LsmcConfig {
+    /// Creates a new configuration builder.
+    #[inline]
+    pub fn builder() -> LsmcConfigBuilder {
+        LsmcConfigBuilder::default()
+    }
+
+    /// Returns the number of regression paths.
+    #[inline]
+    pub fn n_paths(&self) -> usize {
+        self.n_paths
+    }
+
+    /// Returns the number of independent pricing paths.
+    #[inline]
+    pub fn n_pricing_paths(&self) -> usize {
+        self.n_pricing_paths
+    }
+
+    /// Returns the regression basis.
+    #[inline]
+    pub fn basis(&self) -> BasisFunctions {
+        self.basis
+    }
+
+    /// Returns the random seed, if set.
+    #[inline]
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Returns the upper bound configuration, if enabled.
+    #[inline]
+    pub fn duality(&self) -> Option<DualityConfig> {
+        self.duality
+    }
+
+    /// Validates the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if a path count is outside
+    /// [1, 10_000_000] or the basis degree is zero.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (name, n) in [
+            ("n_paths", self.n_paths),
+            ("n_pricing_paths", self.n_pricing_paths),
+        ] {
+            if n == 0 || n > MAX_PATHS {
+                return Err(ConfigError::InvalidParameter {
+                    name,
+                    value: n.to_string(),
+                });
+            }
+        }
+        if self.basis.degree == 0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "degree",
+                value: "0 (the basis needs at least linear terms)".to_string(),
+            });
+        }
+        if let Some(duality) = self.duality {
+            for (name, n) in [
+                ("outer_paths", duality.outer_paths),
+                ("inner_paths", duality.inner_paths),
+            ] {
+                if n == 0 || n > MAX_PATHS {
+                    return Err(ConfigError::InvalidParameter {
+                        name,
+                        value: n.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`LsmcConfig`].
+#[derive(Clone, Debug, Default)]
+pub struct LsmcConfigBuilder {
+    n_paths: Option<usize>,
+    n_pricing_paths: Option<usize>,
+    basis: BasisFunctions,
+    seed: Option<u64>,
+    duality: Option<DualityConfig>,
+}
+
+impl LsmcConfigBuilder {
+    /// Sets the number of regression paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_paths` - Number of paths in [1, 10_000_000]
+    #[inline]
+    pub fn n_paths(mut self, n_paths: usize) -> Self {
+        self.n_paths = Some(n_paths);
+        self
+    }
+
+    /// Sets the number of independent pricing paths.
+    ///
+    /// Defaults to the number of regression paths.
+    #[inline]
+    pub fn n_pricing_paths(mut self, n_pricing_paths: usize) -> Self {
+        self.n_pricing_paths = Some(n_pricing_paths);
+        self
+    }
+
+    /// Sets the regression basis (default: monomials up to degree 3).
+    #[inline]
+    pub fn basis(mut self, basis: BasisFunctions) -> Self {
+        self.basis = basis;
+        self
+    }
+
+    /// Sets the seed for reproducibility.
+    #[inline]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Enables the Andersen-Broadie upper bound.
+    #[inline]
+    pub fn duality(mut self, duality: DualityConfig) -> Self {
+        self.duality = Some(duality);
+        self
+    }
+
+    /// Builds the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if `n_paths` is not set or any setting is
+    /// invalid (see [`LsmcConfig::validate`]).
+    pub fn build(self) -> Result<LsmcConfig, ConfigError> {
+        let n_paths = self.n_paths.ok_or(ConfigError::InvalidParameter {
+            name: "n_paths",
+            value: "must be specified".to_string(),
+        })?;
+        let config = LsmcConfig {
+            n_paths,
+            n_pricing_paths: self.n_pricing_paths.unwrap_or(n_paths),
+            basis: self.basis,
+            seed: self.seed,
+            duality: self.duality,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsmc::BasisFamily;
+
+    #[test]
+    fn test_builder_defaults() {
+        let config = LsmcConfig::builder().n_paths(1_000).build().unwrap();
+        assert_eq!(config.n_pricing_paths(), 1_000);
+        assert_eq!(config.basis(), BasisFunctions::default());
+        assert_eq!(config.seed(), None);
+        assert_eq!(config.duality(), None);
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid() {
+        assert!(LsmcConfig::builder().build().is_err());
+        assert!(LsmcConfig::builder().n_paths(0).build().is_err());
+        assert!(LsmcConfig::builder()
+            .n_paths(100)
+            .basis(BasisFunctions::new(BasisFamily::Hermite, 0))
+            .build()
+            .is_err());
+        assert!(LsmcConfig::builder()
+            .n_paths(100)
+            .duality(DualityConfig::new(100, 0))
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,446 @@
+//! Early-exercise equity options.
+//!
+//! - [`GbmExercise`]: vanilla call or put under GBM with exact transitions
+//! - `ModelExercise`: vanilla call or put under any equity
+//!   `StochasticModelEnum` (Heston, SABR, GBM), stepped with the model's
+//!   `evolve_step` (requires `l1l2-integration`)
+
+use super::pricer::{validate_exercise_times, ExerciseModel};
+use crate::mc::{ConfigError, GbmParams, PayoffParams, PayoffType};
+use crate::rng::PricerRng;
+
+#[cfg(feature = "l1l2-integration")]
+use pricer_models::instruments::ExerciseStyle;
+#[cfg(feature = "l1l2-integration")]
+use pricer_models::models::model_enum::{ModelParams, ModelState, StochasticModelEnum};
+
+/// Hard intrinsic value `max(±(S − K), 0)`.
+#[inline]
+fn intrinsic(payoff: &PayoffParams, spot: f64) -> f64 {
+    match payoff.payoff_type {
+        PayoffType::Call => (spot - payoff.strike).max(0.0),
+        PayoffType::Put => (payoff.strike - spot).max(0.0),
+    }
+}
+
+/// `n_dates` equally spaced exercise dates up to `maturity`, approximating
+/// continuous (American) exercise.
+fn american_times(maturity: f64, n_dates: usize) -> Vec<f64> {
+    (1..=n_dates)
+        .map(|i| maturity * i as f64 / n_dates as f64)
+        .collect()
+}
+
+/// Call or put with early exercise under GBM.
+///
+/// The spot is stepped exactly between exercise dates, so no time
+/// discretisation error enters beyond the choice of exercise dates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GbmExercise {
+    gbm: GbmParams,
+    payoff: PayoffParams,
+    times: Vec<f64>,
+}
+
+impl GbmExercise {
+    /// Creates an option exercisable at `exercise_times` (Bermudan; a
+    /// single date gives a European option).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the exercise dates are
+    /// empty, not positive or not strictly increasing.
+    pub fn new(
+        gbm: GbmParams,
+        payoff: PayoffParams,
+        exercise_times: Vec<f64>,
+    ) -> Result<Self, ConfigError> {
+        validate_exercise_times(&exercise_times)?;
+        Ok(Self {
+            gbm,
+            payoff,
+            times: exercise_times,
+        })
+    }
+
+    /// Creates an American option approximated by `n_dates` equally
+    /// spaced exercise dates up to `gbm.maturity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `n_dates` is zero or the
+    /// maturity is not positive.
+    pub fn american(
+        gbm: GbmParams,
+        payoff: PayoffParams,
+        n_dates: usize,
+    ) -> Result<Self, ConfigError> {
+        Self::new(gbm, payoff, american_times(gbm.maturity, n_dates))
+    }
+}
+
+impl ExerciseModel for GbmExercise {
+    type State = f64;
+
+    fn exercise_times(&self) -> &[f64] {
+        &self.times
+    }
+
+    fn initial_state(&self) -> f64 {
+        self.gbm.spot
+    }
+
+    fn advance(&self, spot: f64, date: usize, rng: &mut PricerRng) -> (f64, f64) {
+        let start = if date == 0 { 0.0 } else { self.times[date - 1] };
+        let dt = self.times[date] - start;
+        let sigma = self.gbm.volatility;
+        let drift = (self.gbm.rate - 0.5 * sigma * sigma) * dt;
+        let next = spot * (drift + sigma * dt.sqrt() * rng.gen_normal()).exp();
+        (next, (-self.gbm.rate * dt).exp())
+    }
+
+    fn exercise_value(&self, spot: &f64, _date: usize) -> f64 {
+        intrinsic(&self.payoff, *spot)
+    }
+
+    fn n_regressors(&self) -> usize {
+        1
+    }
+
+    fn regressors(&self, spot: &f64, _date: usize, out: &mut [f64]) {
+        out[0] = spot / self.payoff.strike;
+    }
+
+    fn level(&self, spot: &f64, _date: usize) -> f64 {
+        *spot
+    }
+}
+
+/// Maps an instrument's exercise style to LSMC exercise dates.
+///
+/// - `European`: the maturity only
+/// - `American`: `american_dates` equally spaced dates up to maturity
+/// - `Bermudan`: the given dates up to maturity
+///
+/// # Errors
+///
+/// Returns `ConfigError::InvalidParameter` for `Asian` exercise, Bermudan
+/// dates after maturity, or dates that are not positive and strictly
+/// increasing.
+#[cfg(feature = "l1l2-integration")]
+pub fn exercise_times(
+    style: &ExerciseStyle<f64>,
+    maturity: f64,
+    american_dates: usize,
+) -> Result<Vec<f64>, ConfigError> {
+    let times = match style {
+        ExerciseStyle::European => vec![maturity],
+        ExerciseStyle::American => american_times(maturity, american_dates),
+        ExerciseStyle::Bermudan { exercise_dates } => {
+            if exercise_dates.iter().any(|&t| t > maturity) {
+                return Err(ConfigError::InvalidParameter {
+                    name: "exercise_dates",
+                    value: format!("{exercise_dates:?} (after maturity {maturity})"),
+                });
+            }
+            exercise_dates.clone()
+        }
+        ExerciseStyle::Asian { .. } => {
+            return Err(ConfigError::InvalidParameter {
+                name: "exercise",
+                value: "Asian exercise has no early exercise rights".to_string(),
+            });
+        }
+    };
+    validate_exercise_times(&times)?;
+    Ok(times)
+}
+
+/// Call or put with early exercise under any equity `StochasticModelEnum`.
+///
+/// Between exercise dates the state is stepped with the model's
+/// `evolve_step` over `steps_per_interval` sub-steps and discounted at the
+/// model rate. The regressors are the price over the strike and, for
+/// two-factor models, the second factor (Heston variance, SABR
+/// volatility) over its initial value.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_models::instruments::ExerciseStyle;
+/// use pricer_models::models::heston::HestonParams;
+/// use pricer_models::models::model_enum::{ModelParams, StochasticModelEnum};
+/// use pricer_pricing::lsmc::{exercise_times, LsmcConfig, LsmcPricer, ModelExercise};
+/// use pricer_pricing::mc::PayoffParams;
+///
+/// let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+/// let model = StochasticModelEnum::heston(heston).unwrap();
+/// let params = ModelParams::Heston(heston);
+///
+/// let times = exercise_times(&ExerciseStyle::American, 1.0, 20).unwrap();
+/// let option = ModelExercise::new(&model, &params, PayoffParams::put(100.0), times)
+///     .unwrap()
+///     .with_steps_per_interval(2);
+///
+/// let config = LsmcConfig::builder().n_paths(2_000).seed(1).build().unwrap();
+/// let result = LsmcPricer::new(config).unwrap().price(&option).unwrap();
+/// assert!(result.price > 0.0);
+/// ```
+#[cfg(feature = "l1l2-integration")]
+#[derive(Clone, Debug)]
+pub struct ModelExercise<'a> {
+    model: &'a StochasticModelEnum<f64>,
+    params: &'a ModelParams<f64>,
+    payoff: PayoffParams,
+    times: Vec<f64>,
+    steps_per_interval: usize,
+    initial: ModelState<f64>,
+}
+
+#[cfg(feature = "l1l2-integration")]
+impl<'a> ModelExercise<'a> {
+    /// Creates an option on the first state component of `model`,
+    /// exercisable at `exercise_times`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `params` do not belong to
+    /// `model`, `model` is a short-rate model, or the exercise dates are
+    /// invalid.
+    pub fn new(
+        model: &'a StochasticModelEnum<f64>,
+        params: &'a ModelParams<f64>,
+        payoff: PayoffParams,
+        exercise_times: Vec<f64>,
+    ) -> Result<Self, ConfigError> {
+        if !model.accepts(params) {
+            return Err(ConfigError::InvalidParameter {
+                name: "params",
+                value: format!(
+                    "parameters do not belong to the {} model",
+                    model.model_name()
+                ),
+            });
+        }
+        if model.is_rate_model() {
+            return Err(ConfigError::InvalidParameter {
+                name: "model",
+                value: format!(
+                    "{} is a short-rate model; use HullWhiteSwaption for rate exercise",
+                    model.model_name()
+                ),
+            });
+        }
+        validate_exercise_times(&exercise_times)?;
+        Ok(Self {
+            model,
+            params,
+            payoff,
+            times: exercise_times,
+            steps_per_interval: 1,
+            initial: model.initial_state(params),
+        })
+    }
+
+    /// Sets the number of `evolve_step` sub-steps between consecutive
+    /// exercise dates (default 1; zero is treated as 1).
+    #[inline]
+    pub fn with_steps_per_interval(mut self, steps_per_interval: usize) -> Self {
+        self.steps_per_interval = steps_per_interval.max(1);
+        self
+    }
+}
+
+#[cfg(feature = "l1l2-integration")]
+impl ExerciseModel for ModelExercise<'_> {
+    type State = ModelState<f64>;
+
+    fn exercise_times(&self) -> &[f64] {
+        &self.times
+    }
+
+    fn initial_state(&self) -> ModelState<f64> {
+        self.initial
+    }
+
+    fn advance(
+        &self,
+        mut state: ModelState<f64>,
+        date: usize,
+        rng: &mut PricerRng,
+    ) -> (ModelState<f64>, f64) {
+        let start = if date == 0 { 0.0 } else { self.times[date - 1] };
+        let interval = self.times[date] - start;
+        let dt = interval / self.steps_per_interval as f64;
+        let mut dw = [0.0; 2];
+        let dw = &mut dw[..self.model.brownian_dim()];
+        for _ in 0..self.steps_per_interval {
+            rng.fill_normal(dw);
+            state = self.model.evolve_step(state, dt, dw, self.params);
+        }
+        (state, (-self.params.rate() * interval).exp())
+    }
+
+    fn exercise_value(&self, state: &ModelState<f64>, _date: usize) -> f64 {
+        intrinsic(&self.payoff, state.price())
+    }
+
+    fn n_regressors(&self) -> usize {
+        self.initial.dimension()
+    }
+
+    fn regressors(&self, state: &ModelState<f64>, _date: usize, out: &mut [f64]) {
+        out[0] = state.price() / self.payoff.strike;
+        for (i, x) in out.iter_mut().enumerate().skip(1) {
+            let initial = self.initial.get(i).unwrap_or(1.0);
+            let value = state.get(i).unwrap_or(0.0);
+            *x = if initial > 0.0 {
+                value / initial
+            } else {
+                value
+            };
+        }
+    }
+
+    fn level(&self, state: &ModelState<f64>, _date: usize) -> f64 {
+        state.price()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsmc::{LsmcConfig, LsmcPricer};
+
+    #[test]
+    fn test_single_date_matches_black_scholes() {
+        // European put S=36, K=40, r=0.06, sigma=0.2, T=1
+        let gbm = GbmParams::new(36.0, 0.06, 0.2, 1.0);
+        let option = GbmExercise::new(gbm, PayoffParams::put(40.0), vec![1.0]).unwrap();
+        let config = LsmcConfig::builder()
+            .n_paths(1_000)
+            .n_pricing_paths(100_000)
+            .seed(9)
+            .build()
+            .unwrap();
+        let result = LsmcPricer::new(config).unwrap().price(&option).unwrap();
+        assert!(
+            (result.price - 3.844).abs() < 4.0 * result.std_error + 1e-3,
+            "{} (se {})",
+            result.price,
+            result.std_error
+        );
+    }
+
+    #[test]
+    fn test_american_call_without_dividends_is_european() {
+        // Early exercise of a call on a non-dividend stock is never optimal
+        let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+        let config = LsmcConfig::builder()
+            .n_paths(10_000)
+            .n_pricing_paths(50_000)
+            .seed(4)
+            .build()
+            .unwrap();
+        let option = GbmExercise::american(gbm, PayoffParams::call(100.0), 10).unwrap();
+        let result = LsmcPricer::new(config).unwrap().price(&option).unwrap();
+        assert!(
+            (result.price - 10.4506).abs() < 4.0 * result.std_error + 0.05,
+            "{} (se {})",
+            result.price,
+            result.std_error
+        );
+    }
+
+    #[test]
+    fn test_american_rejects_zero_dates() {
+        let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+        assert!(GbmExercise::american(gbm, PayoffParams::put(100.0), 0).is_err());
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_exercise_style_mapping() {
+        let american = exercise_times(&ExerciseStyle::American, 1.0, 4).unwrap();
+        assert_eq!(american, vec![0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(
+            exercise_times(&ExerciseStyle::European, 2.0, 4).unwrap(),
+            vec![2.0]
+        );
+        let bermudan = ExerciseStyle::Bermudan {
+            exercise_dates: vec![0.5, 1.0],
+        };
+        assert_eq!(exercise_times(&bermudan, 1.0, 4).unwrap(), vec![0.5, 1.0]);
+        assert!(exercise_times(&bermudan, 0.75, 4).is_err());
+        let asian = ExerciseStyle::Asian {
+            averaging_start: 0.0,
+            averaging_end: 1.0,
+            num_observations: 12,
+        };
+        assert!(exercise_times(&asian, 1.0, 4).is_err());
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_gbm_matches_gbm_exercise() {
+        use pricer_models::models::gbm::GBMParams;
+
+        let model = StochasticModelEnum::gbm();
+        let params = ModelParams::GBM(GBMParams::new(36.0, 0.06, 0.2).unwrap());
+        let times = exercise_times(&ExerciseStyle::American, 1.0, 20).unwrap();
+        let option = ModelExercise::new(&model, &params, PayoffParams::put(40.0), times).unwrap();
+        let config = LsmcConfig::builder()
+            .n_paths(10_000)
+            .seed(2)
+            .build()
+            .unwrap();
+        let result = LsmcPricer::new(config).unwrap().price(&option).unwrap();
+        assert!(
+            (result.price - 4.46).abs() < 4.0 * result.std_error + 0.05,
+            "{} (se {})",
+            result.price,
+            result.std_error
+        );
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_heston_american_put_exceeds_european() {
+        use pricer_models::models::heston::HestonParams;
+
+        let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+        let model = StochasticModelEnum::heston(heston).unwrap();
+        let params = ModelParams::Heston(heston);
+        let config = LsmcConfig::builder()
+            .n_paths(10_000)
+            .n_pricing_paths(40_000)
+            .seed(8)
+            .build()
+            .unwrap();
+
+        let european = ModelExercise::new(&model, &params, PayoffParams::put(120.0), vec![1.0])
+            .unwrap()
+            .with_steps_per_interval(20);
+        let times = exercise_times(&ExerciseStyle::American, 1.0, 20).unwrap();
+        let american =
+            ModelExercise::new(&model, &params, PayoffParams::put(120.0), times).unwrap();
+        assert_eq!(american.n_regressors(), 2);
+
+        let mut pricer = LsmcPricer::new(config).unwrap();
+        let e = pricer.price(&european).unwrap();
+        let a = pricer.price(&american).unwrap();
+        assert!(a.price > e.price + a.std_error + e.std_error);
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_rejects_foreign_params() {
+        use pricer_models::models::gbm::GBMParams;
+        use pricer_models::models::heston::HestonParams;
+
+        let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+        let model = StochasticModelEnum::heston(heston).unwrap();
+        let params = ModelParams::GBM(GBMParams::new(100.0, 0.05, 0.2).unwrap());
+        assert!(ModelExercise::new(&model, &params, PayoffParams::put(100.0), vec![1.0]).is_err());
+    }
+}
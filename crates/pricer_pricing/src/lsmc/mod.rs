@@ -0,0 +1,35 @@
+//! Least-squares Monte Carlo (Longstaff-Schwartz) for early exercise.
+//!
+//! This module prices American and Bermudan rights that the European and
+//! path-dependent Monte Carlo engine in [`mc`](crate::mc) cannot handle.
+//!
+//! # Key Components
+//!
+//! - [`LsmcPricer`]: backward-induction regression, independent lower-bound
+//!   pricing and the Andersen-Broadie duality upper bound
+//! - [`ExerciseModel`]: a simulated process with exercise values on a set
+//!   of exercise dates
+//! - [`BasisFunctions`]: monomial, Laguerre or Hermite regression basis
+//! - [`GbmExercise`], `ModelExercise` (any equity `StochasticModelEnum`,
+//!   requires `l1l2-integration`) and [`HullWhiteSwaption`]
+//!
+//! # Output
+//!
+//! [`LsmcResult`] reports the lower-bound price with its standard error,
+//! the upper bound when [`DualityConfig`] is set, and the estimated
+//! exercise boundary at every exercise date.
+
+mod basis;
+mod config;
+mod equity;
+mod pricer;
+mod regression;
+mod swaption;
+
+pub use basis::{BasisFamily, BasisFunctions};
+pub use config::{DualityConfig, LsmcConfig, LsmcConfigBuilder};
+pub use equity::GbmExercise;
+#[cfg(feature = "l1l2-integration")]
+pub use equity::{exercise_times, ModelExercise};
+pub use pricer::{ExerciseModel, ExercisePoint, LsmcPricer, LsmcResult};
+pub use swaption::{BermudanSwaption, HullWhiteSwaption};
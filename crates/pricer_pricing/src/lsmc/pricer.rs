@@ -0,0 +1,581 @@
+//! Longstaff-Schwartz engine with Andersen-Broadie upper bounds.
+//!
+//! # Algorithm
+//!
+//! 1. **Regression**: `n_paths` paths are simulated to every exercise
+//!    date. Walking backwards, the discounted realised cashflow of each
+//!    in-the-money path is regressed on the basis functions of its state;
+//!    the path exercises when the exercise value reaches the fitted
+//!    continuation value.
+//! 2. **Pricing**: the fitted policy is applied to `n_pricing_paths`
+//!    independent paths. Because the policy is sub-optimal and independent
+//!    of the pricing paths, the estimate is biased low.
+//! 3. **Duality** (optional): along outer paths the lower-bound value
+//!    process `L_k` is estimated with nested sub-paths and turned into the
+//!    martingale
+//!    `π_k = L_k − Σ_{j<k} 1{exercise at j}·(Q_j − h_j)`, `π₀ = L₀`,
+//!    where `Q_j` is the continuation value and `h_j` the exercise value.
+//!    The upper bound is `L₀ + E[max_k (h_k − π_k)]` (Andersen & Broadie,
+//!    2004).
+//!
+//! The gap between the two bounds measures the quality of the regression:
+//! a tight interval means the basis captures the exercise decision.
+
+use super::basis::BasisFunctions;
+use super::config::{DualityConfig, LsmcConfig};
+use super::regression::{fitted, LeastSquares};
+use crate::mc::ConfigError;
+use crate::rng::PricerRng;
+
+/// A simulated process together with the exercise rights written on it.
+///
+/// The engine only needs to step the state from one exercise date to the
+/// next, so models with exact transitions (GBM, Hull-White) need no time
+/// discretisation, and discretised models choose their own sub-steps.
+pub trait ExerciseModel {
+    /// Simulation state at an exercise date.
+    type State: Copy;
+
+    /// Exercise dates in years, strictly increasing and positive.
+    fn exercise_times(&self) -> &[f64];
+
+    /// State at time zero.
+    fn initial_state(&self) -> Self::State;
+
+    /// Steps `state` from the previous exercise date (time zero for
+    /// `date = 0`) to exercise date `date`.
+    ///
+    /// Returns the new state and the pathwise discount factor over the
+    /// interval.
+    fn advance(&self, state: Self::State, date: usize, rng: &mut PricerRng) -> (Self::State, f64);
+
+    /// Undiscounted exercise value at `date`; zero when out of the money.
+    fn exercise_value(&self, state: &Self::State, date: usize) -> f64;
+
+    /// Number of regressors describing the state.
+    fn n_regressors(&self) -> usize;
+
+    /// Writes the regressors of `state` into `out`.
+    ///
+    /// Regressors should be normalised to order one (e.g. spot over
+    /// strike) so that the polynomial basis stays well conditioned.
+    fn regressors(&self, state: &Self::State, date: usize, out: &mut [f64]);
+
+    /// Observable in which the exercise boundary is reported (spot price,
+    /// short rate).
+    fn level(&self, state: &Self::State, date: usize) -> f64;
+}
+
+/// Exercise boundary at one exercise date.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExercisePoint {
+    /// Exercise date in years.
+    pub time: f64,
+    /// Critical level of [`ExerciseModel::level`] separating exercise
+    /// from continuation, or `None` if no regression path exercises.
+    ///
+    /// Estimated as the threshold that misclassifies the fewest regression
+    /// paths; for multi-factor states it is a projection of the boundary.
+    pub level: Option<f64>,
+}
+
+/// Result of Longstaff-Schwartz pricing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LsmcResult {
+    /// Lower-bound price from the independent pricing paths.
+    pub price: f64,
+    /// Standard error of the lower-bound price.
+    pub std_error: f64,
+    /// Andersen-Broadie upper bound, if duality is enabled.
+    pub upper_bound: Option<f64>,
+    /// Standard error of the upper bound.
+    pub upper_std_error: Option<f64>,
+    /// Exercise boundary per exercise date.
+    pub exercise_boundary: Vec<ExercisePoint>,
+}
+
+impl LsmcResult {
+    /// Returns the midpoint of the lower and upper bounds, or the lower
+    /// bound if no upper bound was computed.
+    #[inline]
+    pub fn point_estimate(&self) -> f64 {
+        self.upper_bound
+            .map_or(self.price, |upper| 0.5 * (self.price + upper))
+    }
+}
+
+/// Longstaff-Schwartz pricer for options with early exercise.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::lsmc::{GbmExercise, LsmcConfig, LsmcPricer};
+/// use pricer_pricing::mc::{GbmParams, PayoffParams};
+///
+/// // American put, approximated by 50 exercise dates
+/// let gbm = GbmParams::new(36.0, 0.06, 0.2, 1.0);
+/// let model = GbmExercise::american(gbm, PayoffParams::put(40.0), 50).unwrap();
+///
+/// let config = LsmcConfig::builder().n_paths(10_000).seed(42).build().unwrap();
+/// let mut pricer = LsmcPricer::new(config).unwrap();
+/// let result = pricer.price(&model).unwrap();
+///
+/// assert!((result.price - 4.478).abs() < 0.1);
+/// ```
+pub struct LsmcPricer {
+    config: LsmcConfig,
+    rng: PricerRng,
+}
+
+impl LsmcPricer {
+    /// Creates a pricer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if the configuration is invalid.
+    pub fn new(config: LsmcConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+        let rng = PricerRng::from_seed(config.seed().unwrap_or(0));
+        Ok(Self { config, rng })
+    }
+
+    /// Returns the configuration.
+    #[inline]
+    pub fn config(&self) -> &LsmcConfig {
+        &self.config
+    }
+
+    /// Resets the random number generator to the configured seed.
+    pub fn reset(&mut self) {
+        self.rng = PricerRng::from_seed(self.config.seed().unwrap_or(0));
+    }
+
+    /// Prices the exercise rights of `model`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the exercise dates are
+    /// empty, not positive or not strictly increasing.
+    pub fn price<M: ExerciseModel>(&mut self, model: &M) -> Result<LsmcResult, ConfigError> {
+        validate_exercise_times(model.exercise_times())?;
+
+        let (mut policy, exercise_boundary) = self.fit_policy(model);
+        let (price, std_error) = self.lower_bound(model, &mut policy);
+        let (upper_bound, upper_std_error) = match self.config.duality() {
+            Some(duality) => {
+                let (gap, gap_error) = self.duality_gap(model, &mut policy, duality);
+                (
+                    Some(price + gap),
+                    Some((std_error * std_error + gap_error * gap_error).sqrt()),
+                )
+            }
+            None => (None, None),
+        };
+
+        Ok(LsmcResult {
+            price,
+            std_error,
+            upper_bound,
+            upper_std_error,
+            exercise_boundary,
+        })
+    }
+
+    /// Runs the backward induction on regression paths.
+    fn fit_policy<M: ExerciseModel>(&mut self, model: &M) -> (ExercisePolicy, Vec<ExercisePoint>) {
+        let times = model.exercise_times();
+        let n_dates = times.len();
+        let n_paths = self.config.n_paths();
+        let mut regressors = Regressors::new(self.config.basis(), model.n_regressors());
+
+        let mut states = Vec::with_capacity(n_paths * n_dates);
+        let mut discounts = Vec::with_capacity(n_paths * n_dates);
+        for _ in 0..n_paths {
+            let mut state = model.initial_state();
+            for date in 0..n_dates {
+                let (next, df) = model.advance(state, date, &mut self.rng);
+                states.push(next);
+                discounts.push(df);
+                state = next;
+            }
+        }
+
+        let mut values = vec![0.0; n_paths];
+        let mut decisions = Vec::with_capacity(n_paths);
+        let mut boundary = vec![
+            ExercisePoint {
+                time: 0.0,
+                level: None,
+            };
+            n_dates
+        ];
+        let mut coefficients = vec![Vec::new(); n_dates - 1];
+
+        let last = n_dates - 1;
+        let mut cashflows: Vec<f64> = (0..n_paths)
+            .map(|p| model.exercise_value(&states[p * n_dates + last], last))
+            .collect();
+        for (p, &cashflow) in cashflows.iter().enumerate() {
+            let state = &states[p * n_dates + last];
+            decisions.push((model.level(state, last), cashflow > 0.0));
+        }
+        boundary[last] = ExercisePoint {
+            time: times[last],
+            level: separating_level(&mut decisions),
+        };
+
+        for date in (0..last).rev() {
+            let mut least_squares = LeastSquares::new(regressors.n_terms());
+            for p in 0..n_paths {
+                cashflows[p] *= discounts[p * n_dates + date + 1];
+                let state = &states[p * n_dates + date];
+                values[p] = model.exercise_value(state, date);
+                if values[p] > 0.0 {
+                    least_squares.add(regressors.row(model, state, date), cashflows[p]);
+                }
+            }
+            let beta = least_squares.solve();
+
+            decisions.clear();
+            for p in 0..n_paths {
+                let state = &states[p * n_dates + date];
+                let exercise = values[p] > 0.0
+                    && values[p] >= fitted(&beta, regressors.row(model, state, date));
+                if exercise {
+                    cashflows[p] = values[p];
+                }
+                decisions.push((model.level(state, date), exercise));
+            }
+            boundary[date] = ExercisePoint {
+                time: times[date],
+                level: separating_level(&mut decisions),
+            };
+            coefficients[date] = beta;
+        }
+
+        (
+            ExercisePolicy {
+                regressors,
+                coefficients,
+            },
+            boundary,
+        )
+    }
+
+    /// Prices the fitted policy on independent paths.
+    fn lower_bound<M: ExerciseModel>(
+        &mut self,
+        model: &M,
+        policy: &mut ExercisePolicy,
+    ) -> (f64, f64) {
+        let n_paths = self.config.n_pricing_paths();
+        let mut stats = SampleStats::default();
+        for _ in 0..n_paths {
+            stats.push(policy.run(model, model.initial_state(), 0, &mut self.rng));
+        }
+        stats.mean_and_std_error()
+    }
+
+    /// Estimates `E[max_k (h_k − π_k)]` along outer paths.
+    fn duality_gap<M: ExerciseModel>(
+        &mut self,
+        model: &M,
+        policy: &mut ExercisePolicy,
+        duality: DualityConfig,
+    ) -> (f64, f64) {
+        let n_dates = model.exercise_times().len();
+        let mut stats = SampleStats::default();
+
+        for _ in 0..duality.outer_paths {
+            let mut state = model.initial_state();
+            let mut discount = 1.0;
+            // Σ_{j<k} 1{exercise at j}·(Q_j − h_j), discounted to time zero
+            let mut correction = 0.0;
+            let mut max_gap = f64::NEG_INFINITY;
+
+            for date in 0..n_dates {
+                let (next, df) = model.advance(state, date, &mut self.rng);
+                state = next;
+                discount *= df;
+
+                let value = model.exercise_value(&state, date);
+                let exercise = policy.exercise(model, &state, date, value);
+                let continuation = if date + 1 < n_dates {
+                    let mut inner = SampleStats::default();
+                    for _ in 0..duality.inner_paths {
+                        inner.push(policy.run(model, state, date + 1, &mut self.rng));
+                    }
+                    discount * inner.mean_and_std_error().0
+                } else {
+                    0.0
+                };
+                let exercised = discount * value;
+
+                let lower = if exercise { exercised } else { continuation };
+                max_gap = max_gap.max(exercised - (lower - correction));
+                if exercise {
+                    correction += continuation - exercised;
+                }
+            }
+            stats.push(max_gap);
+        }
+        stats.mean_and_std_error()
+    }
+}
+
+/// Basis evaluation buffers for one regressor dimension.
+#[derive(Clone, Debug)]
+struct Regressors {
+    basis: BasisFunctions,
+    x: Vec<f64>,
+    scratch: Vec<f64>,
+    row: Vec<f64>,
+}
+
+impl Regressors {
+    fn new(basis: BasisFunctions, n_regressors: usize) -> Self {
+        Self {
+            basis,
+            x: vec![0.0; n_regressors],
+            scratch: vec![0.0; (basis.degree + 1) * n_regressors],
+            row: vec![0.0; basis.n_terms(n_regressors)],
+        }
+    }
+
+    #[inline]
+    fn n_terms(&self) -> usize {
+        self.row.len()
+    }
+
+    /// Evaluates the basis at the regressors of `state`.
+    fn row<M: ExerciseModel>(&mut self, model: &M, state: &M::State, date: usize) -> &[f64] {
+        model.regressors(state, date, &mut self.x);
+        self.basis
+            .evaluate(&self.x, &mut self.scratch, &mut self.row);
+        &self.row
+    }
+}
+
+/// Fitted exercise rule: regression coefficients per exercise date except
+/// the last, where any in-the-money option is exercised.
+#[derive(Clone, Debug)]
+struct ExercisePolicy {
+    regressors: Regressors,
+    coefficients: Vec<Vec<f64>>,
+}
+
+impl ExercisePolicy {
+    /// Decides whether to exercise `state` with exercise value `value`.
+    fn exercise<M: ExerciseModel>(
+        &mut self,
+        model: &M,
+        state: &M::State,
+        date: usize,
+        value: f64,
+    ) -> bool {
+        if value <= 0.0 {
+            return false;
+        }
+        match self.coefficients.get(date) {
+            Some(beta) => value >= fitted(beta, self.regressors.row(model, state, date)),
+            None => true,
+        }
+    }
+
+    /// Follows the policy from `state` through dates `first..`, returning
+    /// the cashflow discounted to the time of `state`.
+    fn run<M: ExerciseModel>(
+        &mut self,
+        model: &M,
+        mut state: M::State,
+        first: usize,
+        rng: &mut PricerRng,
+    ) -> f64 {
+        let mut discount = 1.0;
+        for date in first..model.exercise_times().len() {
+            let (next, df) = model.advance(state, date, rng);
+            state = next;
+            discount *= df;
+            let value = model.exercise_value(&state, date);
+            if self.exercise(model, &state, date, value) {
+                return discount * value;
+            }
+        }
+        0.0
+    }
+}
+
+/// Running sum and sum of squares of i.i.d. samples.
+#[derive(Clone, Copy, Debug, Default)]
+struct SampleStats {
+    n: usize,
+    sum: f64,
+    sum_sq: f64,
+}
+
+impl SampleStats {
+    #[inline]
+    fn push(&mut self, x: f64) {
+        self.n += 1;
+        self.sum += x;
+        self.sum_sq += x * x;
+    }
+
+    fn mean_and_std_error(&self) -> (f64, f64) {
+        let n = self.n as f64;
+        let mean = self.sum / n;
+        let variance = ((self.sum_sq - n * mean * mean) / (n - 1.0).max(1.0)).max(0.0);
+        (mean, (variance / n).sqrt())
+    }
+}
+
+/// Finds the level threshold that best separates exercised from
+/// continued paths, trying both "exercise below" (puts) and "exercise
+/// above" (calls). Returns `None` if no path exercises.
+fn separating_level(decisions: &mut [(f64, bool)]) -> Option<f64> {
+    let total_exercised = decisions.iter().filter(|d| d.1).count();
+    if total_exercised == 0 {
+        return None;
+    }
+    decisions.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let n = decisions.len();
+    // Errors when exercising exactly the paths with index < split
+    // (exercise below) or ≥ split (exercise above)
+    let mut best = (usize::MAX, 0.0);
+    let mut exercised_below = 0;
+    for split in 0..=n {
+        let continued_below = split - exercised_below;
+        let exercised_above = total_exercised - exercised_below;
+        let continued_above = (n - split) - exercised_above;
+
+        let errors_below = continued_below + exercised_above;
+        if split > 0 && errors_below < best.0 {
+            best = (errors_below, decisions[split - 1].0);
+        }
+        let errors_above = exercised_below + continued_above;
+        if split < n && errors_above < best.0 {
+            best = (errors_above, decisions[split].0);
+        }
+
+        if split < n && decisions[split].1 {
+            exercised_below += 1;
+        }
+    }
+    Some(best.1)
+}
+
+/// Checks that exercise dates are non-empty, positive, finite and
+/// strictly increasing.
+pub(crate) fn validate_exercise_times(times: &[f64]) -> Result<(), ConfigError> {
+    if times.is_empty() {
+        return Err(ConfigError::InvalidParameter {
+            name: "exercise_times",
+            value: "at least one exercise date is required".to_string(),
+        });
+    }
+    let mut previous = 0.0;
+    for &t in times {
+        if !t.is_finite() || t <= previous {
+            return Err(ConfigError::InvalidParameter {
+                name: "exercise_times",
+                value: format!("{times:?} (must be positive and strictly increasing)"),
+            });
+        }
+        previous = t;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsmc::GbmExercise;
+    use crate::mc::{GbmParams, PayoffParams};
+    use approx::assert_relative_eq;
+
+    fn american_put(n_dates: usize) -> GbmExercise {
+        let gbm = GbmParams::new(36.0, 0.06, 0.2, 1.0);
+        GbmExercise::american(gbm, PayoffParams::put(40.0), n_dates).unwrap()
+    }
+
+    #[test]
+    fn test_american_put_longstaff_schwartz() {
+        // Longstaff-Schwartz (2001), Table 1: American 4.478, European 3.844
+        let config = LsmcConfig::builder()
+            .n_paths(20_000)
+            .seed(7)
+            .build()
+            .unwrap();
+        let mut pricer = LsmcPricer::new(config).unwrap();
+        let result = pricer.price(&american_put(50)).unwrap();
+
+        assert!(
+            (result.price - 4.478).abs() < 4.0 * result.std_error + 0.02,
+            "LSMC {} (se {})",
+            result.price,
+            result.std_error
+        );
+        assert!(result.price > 3.844 + 0.5);
+        assert_eq!(result.upper_bound, None);
+    }
+
+    #[test]
+    fn test_duality_brackets_price() {
+        let config = LsmcConfig::builder()
+            .n_paths(10_000)
+            .duality(DualityConfig::new(200, 100))
+            .seed(3)
+            .build()
+            .unwrap();
+        let mut pricer = LsmcPricer::new(config).unwrap();
+        let result = pricer.price(&american_put(20)).unwrap();
+
+        let upper = result.upper_bound.unwrap();
+        let upper_error = result.upper_std_error.unwrap();
+        assert!(upper >= result.price - 1e-12);
+        assert!(upper - result.price < 0.25, "gap {}", upper - result.price);
+        assert!(upper_error >= result.std_error);
+        // Longstaff-Schwartz (2001) finite-difference value lies in between
+        assert!(result.price - 3.0 * result.std_error < 4.478);
+        assert!(upper + 3.0 * upper_error > 4.478);
+    }
+
+    #[test]
+    fn test_put_boundary_below_strike() {
+        let config = LsmcConfig::builder()
+            .n_paths(10_000)
+            .seed(5)
+            .build()
+            .unwrap();
+        let mut pricer = LsmcPricer::new(config).unwrap();
+        let result = pricer.price(&american_put(10)).unwrap();
+
+        assert_eq!(result.exercise_boundary.len(), 10);
+        let last = result.exercise_boundary[9];
+        assert_relative_eq!(last.time, 1.0, epsilon = 1e-12);
+        assert!(last.level.unwrap() <= 40.0);
+        // The critical spot rises towards the strike as expiry approaches
+        let early = result.exercise_boundary[2].level.unwrap();
+        assert!(early < last.level.unwrap());
+        assert!(early > 25.0);
+    }
+
+    #[test]
+    fn test_separating_level() {
+        let mut decisions = vec![(1.0, true), (2.0, true), (3.0, false), (4.0, false)];
+        assert_eq!(separating_level(&mut decisions), Some(2.0));
+        let mut decisions = vec![(1.0, false), (2.0, false), (3.0, true), (4.0, true)];
+        assert_eq!(separating_level(&mut decisions), Some(3.0));
+        let mut decisions = vec![(1.0, false), (2.0, false)];
+        assert_eq!(separating_level(&mut decisions), None);
+    }
+
+    #[test]
+    fn test_rejects_invalid_exercise_times() {
+        assert!(validate_exercise_times(&[]).is_err());
+        assert!(validate_exercise_times(&[0.0, 1.0]).is_err());
+        assert!(validate_exercise_times(&[0.5, 0.5]).is_err());
+        assert!(validate_exercise_times(&[0.5, 1.0]).is_ok());
+    }
+}
@@ -0,0 +1,119 @@
+//! Least-squares regression of continuation values.
+
+/// Accumulates the normal equations `AᵀA β = Aᵀy` row by row, so the
+/// design matrix is never stored.
+#[derive(Clone, Debug)]
+pub(crate) struct LeastSquares {
+    n_terms: usize,
+    gram: Vec<f64>,
+    rhs: Vec<f64>,
+    n_rows: usize,
+}
+
+impl LeastSquares {
+    /// Creates an empty system for `n_terms` coefficients.
+    pub fn new(n_terms: usize) -> Self {
+        Self {
+            n_terms,
+            gram: vec![0.0; n_terms * n_terms],
+            rhs: vec![0.0; n_terms],
+            n_rows: 0,
+        }
+    }
+
+    /// Adds the observation `y ≈ βᵀ row`.
+    pub fn add(&mut self, row: &[f64], y: f64) {
+        let n = self.n_terms;
+        for i in 0..n {
+            self.rhs[i] += row[i] * y;
+            for j in 0..=i {
+                self.gram[i * n + j] += row[i] * row[j];
+            }
+        }
+        self.n_rows += 1;
+    }
+
+    /// Solves for the coefficients by Cholesky factorisation.
+    ///
+    /// A ridge of `1e-10 × mean diagonal` keeps collinear bases (e.g.
+    /// few in-the-money paths) solvable. Returns zero coefficients when
+    /// there are fewer observations than terms, which makes the fitted
+    /// continuation value zero and exercise optimal whenever in the money.
+    pub fn solve(&self) -> Vec<f64> {
+        let n = self.n_terms;
+        if self.n_rows < n {
+            return vec![0.0; n];
+        }
+
+        let mean_diag = (0..n).map(|i| self.gram[i * n + i]).sum::<f64>() / n as f64;
+        let ridge = 1e-10 * mean_diag.max(f64::MIN_POSITIVE);
+
+        // Lower-triangular Cholesky factor of the (ridged) Gram matrix
+        let mut l = vec![0.0; n * n];
+        for i in 0..n {
+            for j in 0..=i {
+                let mut sum = self.gram[i * n + j];
+                if i == j {
+                    sum += ridge;
+                }
+                for k in 0..j {
+                    sum -= l[i * n + k] * l[j * n + k];
+                }
+                if i == j {
+                    if sum <= 0.0 {
+                        return vec![0.0; n];
+                    }
+                    l[i * n + i] = sum.sqrt();
+                } else {
+                    l[i * n + j] = sum / l[j * n + j];
+                }
+            }
+        }
+
+        // Forward substitution L z = Aᵀy, then back substitution Lᵀ β = z
+        let mut z = vec![0.0; n];
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|k| l[i * n + k] * z[k]).sum();
+            z[i] = (self.rhs[i] - sum) / l[i * n + i];
+        }
+        let mut beta = vec![0.0; n];
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|k| l[k * n + i] * beta[k]).sum();
+            beta[i] = (z[i] - sum) / l[i * n + i];
+        }
+        beta
+    }
+}
+
+/// Evaluates the fitted value `βᵀ row`.
+#[inline]
+pub(crate) fn fitted(beta: &[f64], row: &[f64]) -> f64 {
+    beta.iter().zip(row).map(|(b, x)| b * x).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_recovers_quadratic() {
+        let mut ls = LeastSquares::new(3);
+        for i in 0..20 {
+            let x = i as f64 * 0.1;
+            ls.add(&[1.0, x, x * x], 2.0 - 3.0 * x + 0.5 * x * x);
+        }
+        let beta = ls.solve();
+        assert_relative_eq!(beta[0], 2.0, epsilon = 1e-6);
+        assert_relative_eq!(beta[1], -3.0, epsilon = 1e-6);
+        assert_relative_eq!(beta[2], 0.5, epsilon = 1e-6);
+        assert_relative_eq!(fitted(&beta, &[1.0, 1.0, 1.0]), -0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_underdetermined_returns_zero() {
+        let mut ls = LeastSquares::new(3);
+        ls.add(&[1.0, 0.5, 0.25], 1.0);
+        assert_eq!(ls.solve(), vec![0.0; 3]);
+    }
+}
@@ -0,0 +1,394 @@
+//! Bermudan swaptions under the one-factor Hull-White model.
+//!
+//! The short rate is `r(t) = x(t) + α(t)` with
+//! `dx = −a x dt + σ dW`, `x(0) = 0`, and `α` fitted to a flat initial
+//! curve `P(0, T) = e^{−r₀T}`. Between exercise dates `x` and its integral
+//! `∫x` are drawn exactly from their joint Gaussian law, so both the state
+//! and the pathwise discount factor carry no discretisation error.
+//!
+//! Bond prices at an exercise date follow in closed form:
+//!
+//! ```text
+//! P(t, T) = P(0,T)/P(0,t) · exp(½[V(t,T) − V(0,T) + V(0,t)] − B(t,T)·x(t))
+//! B(t, T) = (1 − e^{−a(T−t)}) / a
+//! V(t, T) = σ²/a² · [τ + 2/a·e^{−aτ} − 1/(2a)·e^{−2aτ} − 3/(2a)],  τ = T − t
+//! ```
+
+use super::pricer::{validate_exercise_times, ExerciseModel};
+use crate::mc::ConfigError;
+use crate::rng::PricerRng;
+
+/// Tolerance for matching exercise dates to the swap schedule.
+const SCHEDULE_TOLERANCE: f64 = 1e-10;
+
+/// Bermudan swaption contract.
+///
+/// Exercising at `schedule[j]` enters the swap over the remaining periods
+/// `schedule[j]..schedule[n]`, exchanging the fixed rate `strike` on
+/// accrual `τᵢ = Tᵢ − Tᵢ₋₁` for floating.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BermudanSwaption {
+    /// Exercise dates in years; each must be a schedule date other than
+    /// the last.
+    pub exercise_times: Vec<f64>,
+    /// Swap schedule `T₀ < T₁ < … < Tₙ` in years.
+    pub schedule: Vec<f64>,
+    /// Fixed rate.
+    pub strike: f64,
+    /// Notional.
+    pub notional: f64,
+    /// Payer (pay fixed) if true, receiver otherwise.
+    pub is_payer: bool,
+}
+
+/// Bermudan swaption priced under Hull-White.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::lsmc::{BermudanSwaption, HullWhiteSwaption, LsmcConfig, LsmcPricer};
+///
+/// // 1y into 5y annual payer, exercisable every year
+/// let schedule: Vec<f64> = (1..=6).map(f64::from).collect();
+/// let swaption = BermudanSwaption {
+///     exercise_times: schedule[..5].to_vec(),
+///     schedule,
+///     strike: 0.03,
+///     notional: 1.0,
+///     is_payer: true,
+/// };
+/// let model = HullWhiteSwaption::new(0.05, 0.01, 0.03, swaption).unwrap();
+///
+/// let config = LsmcConfig::builder().n_paths(5_000).seed(1).build().unwrap();
+/// let result = LsmcPricer::new(config).unwrap().price(&model).unwrap();
+/// assert!(result.price > 0.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HullWhiteSwaption {
+    mean_reversion: f64,
+    volatility: f64,
+    flat_rate: f64,
+    swaption: BermudanSwaption,
+    /// Schedule index of the swap start for each exercise date.
+    start_index: Vec<usize>,
+}
+
+impl HullWhiteSwaption {
+    /// Creates the swaption under Hull-White with mean reversion `a`,
+    /// volatility `σ` and flat continuously compounded rate `r₀`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `a` or `σ` is not
+    /// positive, the schedule is not strictly increasing with at least
+    /// one period, or an exercise date is not a schedule date before the
+    /// last.
+    pub fn new(
+        mean_reversion: f64,
+        volatility: f64,
+        flat_rate: f64,
+        swaption: BermudanSwaption,
+    ) -> Result<Self, ConfigError> {
+        for (name, value) in [
+            ("mean_reversion", mean_reversion),
+            ("volatility", volatility),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(ConfigError::InvalidParameter {
+                    name,
+                    value: value.to_string(),
+                });
+            }
+        }
+        if !flat_rate.is_finite() {
+            return Err(ConfigError::InvalidParameter {
+                name: "flat_rate",
+                value: flat_rate.to_string(),
+            });
+        }
+
+        let schedule = &swaption.schedule;
+        if schedule.len() < 2 || schedule.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(ConfigError::InvalidParameter {
+                name: "schedule",
+                value: format!("{schedule:?} (needs at least one increasing period)"),
+            });
+        }
+        validate_exercise_times(&swaption.exercise_times)?;
+        let start_index = swaption
+            .exercise_times
+            .iter()
+            .map(|&t| {
+                schedule[..schedule.len() - 1]
+                    .iter()
+                    .position(|&s| (s - t).abs() <= SCHEDULE_TOLERANCE)
+                    .ok_or_else(|| ConfigError::InvalidParameter {
+                        name: "exercise_times",
+                        value: format!("{t} is not a swap start date in {schedule:?}"),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            mean_reversion,
+            volatility,
+            flat_rate,
+            swaption,
+            start_index,
+        })
+    }
+
+    /// `B(t, T)` for `τ = T − t`.
+    #[inline]
+    fn b(&self, tau: f64) -> f64 {
+        (1.0 - (-self.mean_reversion * tau).exp()) / self.mean_reversion
+    }
+
+    /// Variance `V(t, T)` of `∫ₜᵀ x` for `τ = T − t`.
+    fn v(&self, tau: f64) -> f64 {
+        let a = self.mean_reversion;
+        let s2 = self.volatility * self.volatility;
+        s2 / (a * a) * (tau - 2.0 * self.b(tau) + (1.0 - (-2.0 * a * tau).exp()) / (2.0 * a))
+    }
+
+    /// Zero-coupon bond `P(t, T)` given `x(t)`.
+    fn bond(&self, t: f64, maturity: f64, x: f64) -> f64 {
+        let tau = maturity - t;
+        let convexity = 0.5 * (self.v(tau) - self.v(maturity) + self.v(t));
+        (-self.flat_rate * tau + convexity - self.b(tau) * x).exp()
+    }
+
+    /// Standard deviation of `x(t)`.
+    fn x_std(&self, t: f64) -> f64 {
+        let a = self.mean_reversion;
+        self.volatility * ((1.0 - (-2.0 * a * t).exp()) / (2.0 * a)).sqrt()
+    }
+
+    /// Exercise date time of `date`.
+    #[inline]
+    fn time(&self, date: usize) -> f64 {
+        self.swaption.exercise_times[date]
+    }
+
+    /// Value per unit notional of the swap entered at `date`, from the
+    /// payer's side.
+    fn payer_swap(&self, x: f64, date: usize) -> f64 {
+        let schedule = &self.swaption.schedule;
+        let t = self.time(date);
+        let n = schedule.len() - 1;
+        let annuity: f64 = (self.start_index[date] + 1..=n)
+            .map(|i| (schedule[i] - schedule[i - 1]) * self.bond(t, schedule[i], x))
+            .sum();
+        1.0 - self.bond(t, schedule[n], x) - self.swaption.strike * annuity
+    }
+}
+
+impl ExerciseModel for HullWhiteSwaption {
+    /// The shifted short rate `x(t) = r(t) − α(t)`.
+    type State = f64;
+
+    fn exercise_times(&self) -> &[f64] {
+        &self.swaption.exercise_times
+    }
+
+    fn initial_state(&self) -> f64 {
+        0.0
+    }
+
+    fn advance(&self, x: f64, date: usize, rng: &mut PricerRng) -> (f64, f64) {
+        let start = if date == 0 { 0.0 } else { self.time(date - 1) };
+        let end = self.time(date);
+        let dt = end - start;
+        let a = self.mean_reversion;
+        let s2 = self.volatility * self.volatility;
+
+        // Joint law of (x(end), ∫ x over [start, end]) given x(start)
+        let decay = (-a * dt).exp();
+        let x_var = s2 / (2.0 * a) * (1.0 - decay * decay);
+        let i_var = self.v(dt);
+        let cov = s2 / (2.0 * a * a) * (1.0 - decay) * (1.0 - decay);
+        let x_sd = x_var.sqrt();
+        let i_sd = i_var.sqrt();
+        let rho = (cov / (x_sd * i_sd)).clamp(-1.0, 1.0);
+
+        let z1 = rng.gen_normal();
+        let z2 = rng.gen_normal();
+        let next = x * decay + x_sd * z1;
+        let integral = x * self.b(dt) + i_sd * (rho * z1 + (1.0 - rho * rho).sqrt() * z2);
+
+        // exp(−∫α) = P(0,end)/P(0,start) · exp(−½[V(0,end) − V(0,start)])
+        let deterministic = -self.flat_rate * dt - 0.5 * (self.v(end) - self.v(start));
+        (next, (deterministic - integral).exp())
+    }
+
+    fn exercise_value(&self, x: &f64, date: usize) -> f64 {
+        let swap = self.payer_swap(*x, date);
+        let value = if self.swaption.is_payer { swap } else { -swap };
+        self.swaption.notional * value.max(0.0)
+    }
+
+    fn n_regressors(&self) -> usize {
+        1
+    }
+
+    fn regressors(&self, x: &f64, date: usize, out: &mut [f64]) {
+        out[0] = x / self.x_std(self.time(date));
+    }
+
+    /// Short rate `r(t) = x(t) + α(t)`.
+    fn level(&self, x: &f64, date: usize) -> f64 {
+        let a = self.mean_reversion;
+        let t = self.time(date);
+        let g = 1.0 - (-a * t).exp();
+        x + self.flat_rate + self.volatility * self.volatility / (2.0 * a * a) * g * g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytical::asian::norm_cdf;
+    use crate::lsmc::{LsmcConfig, LsmcPricer};
+
+    const A: f64 = 0.05;
+    const SIGMA: f64 = 0.01;
+    const RATE: f64 = 0.03;
+
+    fn annual(first: usize, exercise: &[f64], strike: f64) -> HullWhiteSwaption {
+        let schedule: Vec<f64> = (first..=first + 5).map(|i| i as f64).collect();
+        let swaption = BermudanSwaption {
+            exercise_times: exercise.to_vec(),
+            schedule,
+            strike,
+            notional: 100.0,
+            is_payer: true,
+        };
+        HullWhiteSwaption::new(A, SIGMA, RATE, swaption).unwrap()
+    }
+
+    /// Jamshidian's decomposition of a European payer swaption into puts
+    /// on zero-coupon bonds.
+    fn jamshidian_payer(model: &HullWhiteSwaption) -> f64 {
+        let schedule = &model.swaption.schedule;
+        let t0 = schedule[0];
+        let coupons: Vec<(f64, f64)> = (1..schedule.len())
+            .map(|i| {
+                let mut c = model.swaption.strike * (schedule[i] - schedule[i - 1]);
+                if i == schedule.len() - 1 {
+                    c += 1.0;
+                }
+                (schedule[i], c)
+            })
+            .collect();
+        let bond_sum =
+            |x: f64| -> f64 { coupons.iter().map(|&(t, c)| c * model.bond(t0, t, x)).sum() };
+
+        // Bisection for x* with Σ cᵢ P(T₀, Tᵢ; x*) = 1
+        let (mut lo, mut hi) = (-1.0, 1.0);
+        for _ in 0..200 {
+            let mid = 0.5 * (lo + hi);
+            if bond_sum(mid) > 1.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let x_star = 0.5 * (lo + hi);
+
+        let p0 = |t: f64| (-RATE * t).exp();
+        let value: f64 = coupons
+            .iter()
+            .map(|&(t, c)| {
+                let strike = model.bond(t0, t, x_star);
+                let sigma_p = model.x_std(t0) * model.b(t - t0);
+                let h = (p0(t) / (p0(t0) * strike)).ln() / sigma_p + 0.5 * sigma_p;
+                c * (strike * p0(t0) * norm_cdf(-h + sigma_p) - p0(t) * norm_cdf(-h))
+            })
+            .sum();
+        model.swaption.notional * value
+    }
+
+    #[test]
+    fn test_discount_factor_reprices_curve() {
+        let model = annual(1, &[1.0, 2.0, 3.0], 0.03);
+        let mut rng = PricerRng::from_seed(17);
+        let n = 100_000;
+        let mut sum = 0.0;
+        for _ in 0..n {
+            let mut x = 0.0;
+            let mut discount = 1.0;
+            for date in 0..3 {
+                let (next, df) = model.advance(x, date, &mut rng);
+                x = next;
+                discount *= df;
+            }
+            sum += discount;
+        }
+        let mean = sum / n as f64;
+        assert!((mean - (-RATE * 3.0).exp()).abs() < 5e-4, "E[D] = {mean}");
+    }
+
+    #[test]
+    fn test_single_exercise_matches_jamshidian() {
+        let model = annual(1, &[1.0], 0.03);
+        let exact = jamshidian_payer(&model);
+        let config = LsmcConfig::builder()
+            .n_paths(1_000)
+            .n_pricing_paths(100_000)
+            .seed(21)
+            .build()
+            .unwrap();
+        let result = LsmcPricer::new(config).unwrap().price(&model).unwrap();
+        assert!(
+            (result.price - exact).abs() < 4.0 * result.std_error,
+            "LSMC {} vs Jamshidian {exact} (se {})",
+            result.price,
+            result.std_error
+        );
+    }
+
+    #[test]
+    fn test_bermudan_exceeds_european_with_bounds() {
+        use crate::lsmc::DualityConfig;
+
+        let exercise = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let bermudan = annual(1, &exercise, 0.03);
+        let european = jamshidian_payer(&annual(1, &[1.0], 0.03));
+        let config = LsmcConfig::builder()
+            .n_paths(10_000)
+            .duality(DualityConfig::new(200, 100))
+            .seed(5)
+            .build()
+            .unwrap();
+        let result = LsmcPricer::new(config).unwrap().price(&bermudan).unwrap();
+
+        assert!(result.price > european);
+        let upper = result.upper_bound.unwrap();
+        assert!(upper >= result.price - 1e-12);
+        assert!(
+            (upper - result.price) / result.price < 0.05,
+            "bounds [{}, {upper}]",
+            result.price
+        );
+        // A payer exercises when rates are high
+        let level = result.exercise_boundary[0].level.unwrap();
+        assert!(level > RATE - 0.01);
+    }
+
+    #[test]
+    fn test_rejects_off_schedule_exercise() {
+        let swaption = BermudanSwaption {
+            exercise_times: vec![1.5],
+            schedule: vec![1.0, 2.0, 3.0],
+            strike: 0.03,
+            notional: 1.0,
+            is_payer: false,
+        };
+        assert!(HullWhiteSwaption::new(A, SIGMA, RATE, swaption.clone()).is_err());
+        let last = BermudanSwaption {
+            exercise_times: vec![3.0],
+            ..swaption
+        };
+        assert!(HullWhiteSwaption::new(A, SIGMA, RATE, last).is_err());
+    }
+}
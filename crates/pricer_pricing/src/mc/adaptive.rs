@@ -0,0 +1,233 @@
+//! Adaptive path count and convergence diagnostics.
+//!
+//! Instead of fixing the number of paths up front, an adaptive simulation
+//! states the accuracy it needs. The configured `n_paths` becomes the
+//! batch size: batches are simulated until the standard error of the
+//! pooled estimate meets the [`ErrorTarget`], or the path or wall-clock
+//! budget of the [`AdaptiveConfig`] is spent.
+//!
+//! The [`ConvergenceTrace`] returned in
+//! [`PricingResult::convergence`](super::PricingResult::convergence)
+//! records the estimate and its standard error after every batch, and
+//! whether the target was met.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pricer_pricing::mc::{
+//!     AdaptiveConfig, GbmParams, MonteCarloConfig, MonteCarloPricer, PayoffParams,
+//! };
+//!
+//! let config = MonteCarloConfig::builder()
+//!     .n_paths(5_000)
+//!     .n_steps(10)
+//!     .seed(42)
+//!     .build()
+//!     .unwrap();
+//! let mut pricer = MonteCarloPricer::new(config).unwrap();
+//!
+//! let adaptive = AdaptiveConfig::absolute(0.05).with_max_paths(1_000_000);
+//! let result = pricer
+//!     .price_european_adaptive(
+//!         GbmParams::default(),
+//!         PayoffParams::call(100.0),
+//!         (-0.05_f64).exp(),
+//!         &adaptive,
+//!     )
+//!     .unwrap();
+//!
+//! let trace = result.convergence.as_ref().unwrap();
+//! assert!(trace.converged);
+//! assert!(result.std_error <= 0.05);
+//! assert_eq!(trace.n_paths() % 5_000, 0);
+//! ```
+
+use std::time::Duration;
+
+use super::config::MAX_PATHS;
+use super::error::ConfigError;
+
+/// Standard error target of an adaptive simulation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorTarget {
+    /// Stop once the standard error is at most this value.
+    Absolute(f64),
+    /// Stop once the standard error is at most this fraction of the
+    /// absolute price.
+    Relative(f64),
+}
+
+impl ErrorTarget {
+    /// Returns true if `std_error` meets the target for `price`.
+    #[inline]
+    pub fn is_met(&self, price: f64, std_error: f64) -> bool {
+        match *self {
+            Self::Absolute(tolerance) => std_error <= tolerance,
+            Self::Relative(tolerance) => std_error <= tolerance * price.abs(),
+        }
+    }
+
+    /// Returns the tolerance.
+    #[inline]
+    fn tolerance(&self) -> f64 {
+        match *self {
+            Self::Absolute(tolerance) | Self::Relative(tolerance) => tolerance,
+        }
+    }
+}
+
+/// Stopping rule of an adaptive simulation.
+///
+/// Batches of the configured `n_paths` are simulated until `target` is
+/// met. Simulation also stops before a batch that would exceed
+/// `max_paths`, or once `max_time` has elapsed; the trace then reports
+/// that the target was not met.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use pricer_pricing::mc::{AdaptiveConfig, ErrorTarget};
+///
+/// let adaptive = AdaptiveConfig::relative(1e-3)
+///     .with_max_paths(2_000_000)
+///     .with_max_time(Duration::from_secs(5));
+/// assert_eq!(adaptive.target, ErrorTarget::Relative(1e-3));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdaptiveConfig {
+    /// Standard error target.
+    pub target: ErrorTarget,
+    /// Maximum total number of paths (default [`MAX_PATHS`]).
+    pub max_paths: usize,
+    /// Optional wall-clock budget, checked after each batch.
+    pub max_time: Option<Duration>,
+}
+
+impl AdaptiveConfig {
+    /// Targets an absolute standard error.
+    pub fn absolute(tolerance: f64) -> Self {
+        Self::new(ErrorTarget::Absolute(tolerance))
+    }
+
+    /// Targets a standard error relative to the absolute price.
+    pub fn relative(tolerance: f64) -> Self {
+        Self::new(ErrorTarget::Relative(tolerance))
+    }
+
+    /// Creates a stopping rule for `target` with the default budget.
+    pub fn new(target: ErrorTarget) -> Self {
+        Self {
+            target,
+            max_paths: MAX_PATHS,
+            max_time: None,
+        }
+    }
+
+    /// Sets the maximum total number of paths.
+    pub fn with_max_paths(mut self, max_paths: usize) -> Self {
+        self.max_paths = max_paths;
+        self
+    }
+
+    /// Sets the wall-clock budget.
+    pub fn with_max_time(mut self, max_time: Duration) -> Self {
+        self.max_time = Some(max_time);
+        self
+    }
+
+    /// Checks the target and that the path budget fits one batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the tolerance is not
+    /// positive and finite, or `max_paths` is below `batch_paths`.
+    pub(crate) fn validate(&self, batch_paths: usize) -> Result<(), ConfigError> {
+        let tolerance = self.target.tolerance();
+        if !tolerance.is_finite() || tolerance <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "target",
+                value: format!("{:?} (tolerance must be positive)", self.target),
+            });
+        }
+        if self.max_paths < batch_paths {
+            return Err(ConfigError::InvalidParameter {
+                name: "max_paths",
+                value: format!(
+                    "{} (below the batch of {batch_paths} paths)",
+                    self.max_paths
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Pooled estimate after one batch of an adaptive simulation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConvergencePoint {
+    /// Total number of paths simulated so far.
+    pub n_paths: usize,
+    /// Price estimate from all paths so far.
+    pub price: f64,
+    /// Standard error of `price`.
+    pub std_error: f64,
+}
+
+impl ConvergencePoint {
+    /// Returns the 95% confidence interval `(lower, upper)`.
+    #[inline]
+    pub fn confidence_interval_95(&self) -> (f64, f64) {
+        let half_width = 1.96 * self.std_error;
+        (self.price - half_width, self.price + half_width)
+    }
+}
+
+/// Convergence history of an adaptive simulation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConvergenceTrace {
+    /// Pooled estimate after each batch, in order.
+    pub points: Vec<ConvergencePoint>,
+    /// Whether the final estimate meets the target.
+    pub converged: bool,
+}
+
+impl ConvergenceTrace {
+    /// Returns the total number of paths simulated.
+    #[inline]
+    pub fn n_paths(&self) -> usize {
+        self.points.last().map_or(0, |point| point.n_paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_targets_and_validation() {
+        assert!(ErrorTarget::Absolute(0.1).is_met(10.0, 0.1));
+        assert!(!ErrorTarget::Absolute(0.1).is_met(10.0, 0.11));
+        assert!(ErrorTarget::Relative(0.01).is_met(-10.0, 0.1));
+        assert!(!ErrorTarget::Relative(0.01).is_met(5.0, 0.1));
+
+        assert!(AdaptiveConfig::absolute(0.1).validate(1000).is_ok());
+        assert!(AdaptiveConfig::absolute(0.0).validate(1000).is_err());
+        assert!(AdaptiveConfig::relative(f64::NAN).validate(1000).is_err());
+        assert!(AdaptiveConfig::absolute(0.1)
+            .with_max_paths(500)
+            .validate(1000)
+            .is_err());
+
+        let point = ConvergencePoint {
+            n_paths: 100,
+            price: 1.0,
+            std_error: 0.5,
+        };
+        let (lower, upper) = point.confidence_interval_95();
+        assert_relative_eq!(lower, 0.02, epsilon = 1e-12);
+        assert_relative_eq!(upper, 1.98, epsilon = 1e-12);
+        assert_eq!(ConvergenceTrace::default().n_paths(), 0);
+    }
+}
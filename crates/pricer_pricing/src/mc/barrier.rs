@@ -0,0 +1,800 @@
+//! Barrier monitoring for Monte Carlo paths.
+//!
+//! Checking a barrier only at the simulated time steps misses crossings
+//! between steps, so knock-out prices are biased upward against continuous
+//! monitoring. [`BarrierOption`] makes the monitoring explicit:
+//!
+//! - **Continuous** monitoring with a [`BarrierCorrection`]:
+//!   - *Brownian bridge*: given the log-spots `x₀`, `x₁` at the ends of a
+//!     step of length `Δt`, the probability of crossing the barrier `h`
+//!     in between is `exp(−2(h − x₀)(h − x₁) / (σ²Δt))`, and each path is
+//!     weighted by its survival probability
+//!   - *Continuity correction*: the barrier is checked at the steps after
+//!     moving it towards the spot by `exp(βσ√Δt)` (Broadie-Glasserman-Kou)
+//! - **Discrete** monitoring on a [`MonitoringSchedule`], e.g. daily
+//!   closes. Observation dates between simulated steps are sampled exactly
+//!   from the Brownian bridge between the step endpoints.
+//!
+//! Single and double barriers, knock-in and knock-out, and rebates paid at
+//! the hit or at expiry are supported. [`TouchOption`] pays a fixed amount
+//! on (one-touch) or without (no-touch) a hit, with the same monitoring.
+//!
+//! Paths from models without a constant volatility, such as stochastic-local
+//! volatility, pass the instantaneous volatility of each step so that the
+//! Brownian bridge uses the local variance of the step.
+
+use super::error::ConfigError;
+use super::paths::GbmParams;
+use super::payoff::{compute_payoff, PayoffParams};
+use crate::analytical::barrier::BGK_BETA;
+use crate::analytical::KnockType;
+use crate::rng::PricerRng;
+
+/// Tolerance for matching observation dates to simulation steps.
+const TIME_TOLERANCE: f64 = 1e-10;
+
+/// Barrier observation dates in years from today.
+///
+/// # Examples
+///
+/// ```rust
+/// use pricer_pricing::mc::MonitoringSchedule;
+///
+/// let schedule = MonitoringSchedule::daily(0.5, 252.0).unwrap();
+/// assert_eq!(schedule.len(), 126);
+/// assert_eq!(schedule.times().last(), Some(&0.5));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitoringSchedule {
+    times: Vec<f64>,
+}
+
+impl MonitoringSchedule {
+    /// Creates a schedule from observation times.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `times` is empty, not
+    /// strictly increasing, or contains non-positive or non-finite values.
+    pub fn new(times: Vec<f64>) -> Result<Self, ConfigError> {
+        if times.is_empty()
+            || times.iter().any(|t| !t.is_finite() || *t <= 0.0)
+            || times.windows(2).any(|w| w[1] <= w[0])
+        {
+            return Err(ConfigError::InvalidParameter {
+                name: "monitoring_times",
+                value: format!("{times:?} (must be positive and strictly increasing)"),
+            });
+        }
+        Ok(Self { times })
+    }
+
+    /// Creates `n` equally spaced observations ending at `maturity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `n` is zero or
+    /// `maturity` is not positive.
+    pub fn uniform(maturity: f64, n: usize) -> Result<Self, ConfigError> {
+        if n == 0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "n_observations",
+                value: "0".to_string(),
+            });
+        }
+        let times = (1..=n)
+            .map(|i| {
+                if i == n {
+                    maturity
+                } else {
+                    maturity * i as f64 / n as f64
+                }
+            })
+            .collect();
+        Self::new(times)
+    }
+
+    /// Creates daily observations at `1 / closes_per_year` spacing up to
+    /// and including `maturity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `closes_per_year` or
+    /// `maturity` is not positive.
+    pub fn daily(maturity: f64, closes_per_year: f64) -> Result<Self, ConfigError> {
+        if !closes_per_year.is_finite() || closes_per_year <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "closes_per_year",
+                value: closes_per_year.to_string(),
+            });
+        }
+        let dt = 1.0 / closes_per_year;
+        let mut times: Vec<f64> = (1..)
+            .map(|i| i as f64 * dt)
+            .take_while(|&t| t < maturity - TIME_TOLERANCE)
+            .collect();
+        times.push(maturity);
+        Self::new(times)
+    }
+
+    /// Creates observations at every business day close after `start` up
+    /// to and including `end`.
+    ///
+    /// Business days are Monday to Friday, excluding `holidays`. Times are
+    /// year fractions from `start` under `day_count`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if there is no business day
+    /// in `(start, end]`.
+    #[cfg(feature = "l1l2-integration")]
+    pub fn business_days(
+        start: pricer_core::types::time::Date,
+        end: pricer_core::types::time::Date,
+        holidays: &[pricer_core::types::time::Date],
+        day_count: pricer_core::types::time::DayCountConvention,
+    ) -> Result<Self, ConfigError> {
+        use chrono::{Datelike, Weekday};
+
+        let holidays: Vec<_> = holidays.iter().map(|date| date.into_inner()).collect();
+        let (start, end) = (start.into_inner(), end.into_inner());
+
+        let mut times = Vec::new();
+        let mut date = start;
+        while date < end {
+            let Some(next) = date.succ_opt() else {
+                break;
+            };
+            date = next;
+            let weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+            if !weekend && !holidays.contains(&date) {
+                times.push(day_count.year_fraction(start, date));
+            }
+        }
+        Self::new(times)
+    }
+
+    /// Returns the observation times.
+    #[inline]
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Returns the number of observations.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+
+    /// Returns true if there are no observations (never, for a valid
+    /// schedule).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+}
+
+/// Treatment of continuous monitoring between simulated steps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BarrierCorrection {
+    /// Check the barrier at the simulated steps only (biased).
+    None,
+    /// Check a barrier shifted towards the spot by `exp(βσ√Δt)`.
+    ContinuityCorrection,
+    /// Weight each path by its Brownian-bridge survival probability.
+    #[default]
+    BrownianBridge,
+}
+
+/// When the barrier is observed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BarrierMonitoring {
+    /// Continuously, with the given treatment between simulated steps.
+    Continuous(BarrierCorrection),
+    /// On the dates of a schedule only.
+    Discrete(MonitoringSchedule),
+}
+
+impl Default for BarrierMonitoring {
+    fn default() -> Self {
+        Self::Continuous(BarrierCorrection::default())
+    }
+}
+
+/// When a rebate is paid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RebateTiming {
+    /// When the barrier is hit (knock-out only).
+    AtHit,
+    /// At expiry.
+    AtExpiry,
+}
+
+/// Rebate paid when a knock-out option is knocked out, or when a knock-in
+/// option expires without being knocked in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rebate {
+    /// Rebate amount.
+    pub amount: f64,
+    /// Payment time.
+    pub timing: RebateTiming,
+}
+
+/// Single or double barrier option on a European payoff.
+///
+/// # Examples
+///
+/// ```rust
+/// use pricer_pricing::mc::{
+///     BarrierMonitoring, BarrierOption, MonitoringSchedule, PayoffParams, RebateTiming,
+/// };
+///
+/// // Double knock-out call observed at daily closes, rebate 1.0 at the hit
+/// let option = BarrierOption::knock_out(PayoffParams::call(100.0), Some(80.0), Some(130.0))
+///     .with_rebate(1.0, RebateTiming::AtHit)
+///     .with_monitoring(BarrierMonitoring::Discrete(
+///         MonitoringSchedule::daily(1.0, 252.0).unwrap(),
+///     ));
+/// assert!(option.validate(100.0, 1.0).is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarrierOption {
+    /// Payoff at expiry while the option is alive.
+    pub payoff: PayoffParams,
+    /// Lower barrier, if any.
+    pub lower: Option<f64>,
+    /// Upper barrier, if any.
+    pub upper: Option<f64>,
+    /// Knock-in or knock-out.
+    pub knock: KnockType,
+    /// Rebate, if any.
+    pub rebate: Option<Rebate>,
+    /// Barrier monitoring.
+    pub monitoring: BarrierMonitoring,
+}
+
+impl BarrierOption {
+    /// Creates a knock-out option, continuously monitored with the
+    /// Brownian-bridge correction.
+    pub fn knock_out(payoff: PayoffParams, lower: Option<f64>, upper: Option<f64>) -> Self {
+        Self {
+            payoff,
+            lower,
+            upper,
+            knock: KnockType::Out,
+            rebate: None,
+            monitoring: BarrierMonitoring::default(),
+        }
+    }
+
+    /// Creates a knock-in option, continuously monitored with the
+    /// Brownian-bridge correction.
+    pub fn knock_in(payoff: PayoffParams, lower: Option<f64>, upper: Option<f64>) -> Self {
+        Self {
+            knock: KnockType::In,
+            ..Self::knock_out(payoff, lower, upper)
+        }
+    }
+
+    /// Sets the rebate.
+    pub fn with_rebate(mut self, amount: f64, timing: RebateTiming) -> Self {
+        self.rebate = Some(Rebate { amount, timing });
+        self
+    }
+
+    /// Sets the monitoring.
+    pub fn with_monitoring(mut self, monitoring: BarrierMonitoring) -> Self {
+        self.monitoring = monitoring;
+        self
+    }
+
+    /// Validates the option against the initial `spot` and `maturity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if there is no barrier, the
+    /// spot is not strictly between the barriers, the rebate is negative
+    /// or paid at the hit of a knock-in, or observations fall after
+    /// maturity.
+    pub fn validate(&self, spot: f64, maturity: f64) -> Result<(), ConfigError> {
+        validate_barriers(self.lower, self.upper, &self.monitoring, spot, maturity)?;
+        if let Some(rebate) = self.rebate {
+            validate_amount("rebate", rebate.amount)?;
+            if self.knock == KnockType::In && rebate.timing == RebateTiming::AtHit {
+                return Err(ConfigError::InvalidParameter {
+                    name: "rebate",
+                    value: "knock-in rebates are paid at expiry".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether a [`TouchOption`] pays on a hit or without one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TouchType {
+    /// Pays if either barrier is hit.
+    OneTouch,
+    /// Pays at expiry if no barrier is hit.
+    NoTouch,
+}
+
+/// Single or double barrier one-touch or no-touch option.
+///
+/// # Examples
+///
+/// ```rust
+/// use pricer_pricing::mc::{RebateTiming, TouchOption};
+///
+/// // One-touch paying 1.0 at the hit of 110, and a double no-touch
+/// let one_touch = TouchOption::one_touch(1.0, None, Some(110.0), RebateTiming::AtHit);
+/// let no_touch = TouchOption::no_touch(1.0, Some(90.0), Some(110.0));
+/// assert!(one_touch.validate(100.0, 1.0).is_ok());
+/// assert!(no_touch.validate(100.0, 1.0).is_ok());
+/// assert!(no_touch.validate(120.0, 1.0).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TouchOption {
+    /// Lower barrier, if any.
+    pub lower: Option<f64>,
+    /// Upper barrier, if any.
+    pub upper: Option<f64>,
+    /// Cash amount paid.
+    pub amount: f64,
+    /// One-touch or no-touch.
+    pub touch: TouchType,
+    /// Payment time (no-touch options pay at expiry).
+    pub payment: RebateTiming,
+    /// Barrier monitoring.
+    pub monitoring: BarrierMonitoring,
+}
+
+impl TouchOption {
+    /// Creates a one-touch option, continuously monitored with the
+    /// Brownian-bridge correction.
+    pub fn one_touch(
+        amount: f64,
+        lower: Option<f64>,
+        upper: Option<f64>,
+        payment: RebateTiming,
+    ) -> Self {
+        Self {
+            lower,
+            upper,
+            amount,
+            touch: TouchType::OneTouch,
+            payment,
+            monitoring: BarrierMonitoring::default(),
+        }
+    }
+
+    /// Creates a no-touch option paying at expiry, continuously monitored
+    /// with the Brownian-bridge correction.
+    pub fn no_touch(amount: f64, lower: Option<f64>, upper: Option<f64>) -> Self {
+        Self {
+            touch: TouchType::NoTouch,
+            payment: RebateTiming::AtExpiry,
+            ..Self::one_touch(amount, lower, upper, RebateTiming::AtExpiry)
+        }
+    }
+
+    /// Sets the monitoring.
+    pub fn with_monitoring(mut self, monitoring: BarrierMonitoring) -> Self {
+        self.monitoring = monitoring;
+        self
+    }
+
+    /// Validates the option against the initial `spot` and `maturity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if there is no barrier, the
+    /// spot is not strictly between the barriers, the amount is negative,
+    /// a no-touch pays at the hit, or observations fall after maturity.
+    pub fn validate(&self, spot: f64, maturity: f64) -> Result<(), ConfigError> {
+        validate_barriers(self.lower, self.upper, &self.monitoring, spot, maturity)?;
+        validate_amount("amount", self.amount)?;
+        if self.touch == TouchType::NoTouch && self.payment == RebateTiming::AtHit {
+            return Err(ConfigError::InvalidParameter {
+                name: "payment",
+                value: "no-touch options pay at expiry".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `spot` lies strictly between the barriers and that no
+/// observation falls after `maturity`.
+fn validate_barriers(
+    lower: Option<f64>,
+    upper: Option<f64>,
+    monitoring: &BarrierMonitoring,
+    spot: f64,
+    maturity: f64,
+) -> Result<(), ConfigError> {
+    if lower.is_none() && upper.is_none() {
+        return Err(ConfigError::InvalidParameter {
+            name: "barrier",
+            value: "no lower or upper barrier".to_string(),
+        });
+    }
+    let lower = lower.unwrap_or(0.0);
+    let upper = upper.unwrap_or(f64::INFINITY);
+    if lower.is_nan() || upper.is_nan() || !(lower < spot && spot < upper) {
+        return Err(ConfigError::InvalidParameter {
+            name: "barrier",
+            value: format!("spot {spot} is not strictly between {lower} and {upper}"),
+        });
+    }
+    if let BarrierMonitoring::Discrete(schedule) = monitoring {
+        if schedule.times().last().copied().unwrap_or(0.0) > maturity + TIME_TOLERANCE {
+            return Err(ConfigError::InvalidParameter {
+                name: "monitoring_times",
+                value: format!("observation after maturity {maturity}"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a cash amount is finite and non-negative.
+fn validate_amount(name: &'static str, amount: f64) -> Result<(), ConfigError> {
+    if !amount.is_finite() || amount < 0.0 {
+        return Err(ConfigError::InvalidParameter {
+            name,
+            value: amount.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Contract valued by a [`BarrierEvaluator`].
+#[derive(Clone, Copy)]
+enum Contract<'a> {
+    Barrier(&'a BarrierOption),
+    Touch(&'a TouchOption),
+}
+
+/// Evaluates a [`BarrierOption`] or [`TouchOption`] on paths of a uniform
+/// time grid.
+///
+/// The Brownian bridge uses the GBM volatility, or the per-step
+/// volatilities passed to [`evaluate_with_volatilities`](Self::evaluate_with_volatilities).
+pub(crate) struct BarrierEvaluator<'a> {
+    contract: Contract<'a>,
+    monitoring: &'a BarrierMonitoring,
+    gbm: GbmParams,
+    dt: f64,
+    /// Whether payments at the hit are accrued.
+    accrue_hits: bool,
+    /// Log barriers (±∞ when absent), shifted for the continuity correction.
+    log_lower: f64,
+    log_upper: f64,
+    /// Observation dates strictly inside each step.
+    interior: Vec<Vec<f64>>,
+    /// Whether the end of each step is an observation date.
+    observed_end: Vec<bool>,
+}
+
+impl<'a> BarrierEvaluator<'a> {
+    pub(crate) fn new(option: &'a BarrierOption, gbm: GbmParams, n_steps: usize) -> Self {
+        let accrue_hits = matches!(
+            option.rebate,
+            Some(Rebate {
+                timing: RebateTiming::AtHit,
+                ..
+            })
+        );
+        Self::with_contract(
+            Contract::Barrier(option),
+            (option.lower, option.upper),
+            &option.monitoring,
+            accrue_hits,
+            gbm,
+            n_steps,
+        )
+    }
+
+    pub(crate) fn for_touch(option: &'a TouchOption, gbm: GbmParams, n_steps: usize) -> Self {
+        let accrue_hits =
+            option.touch == TouchType::OneTouch && option.payment == RebateTiming::AtHit;
+        Self::with_contract(
+            Contract::Touch(option),
+            (option.lower, option.upper),
+            &option.monitoring,
+            accrue_hits,
+            gbm,
+            n_steps,
+        )
+    }
+
+    fn with_contract(
+        contract: Contract<'a>,
+        (lower, upper): (Option<f64>, Option<f64>),
+        monitoring: &'a BarrierMonitoring,
+        accrue_hits: bool,
+        gbm: GbmParams,
+        n_steps: usize,
+    ) -> Self {
+        let dt = gbm.maturity / n_steps as f64;
+        let shift = match monitoring {
+            BarrierMonitoring::Continuous(BarrierCorrection::ContinuityCorrection) => {
+                BGK_BETA * gbm.volatility * dt.sqrt()
+            }
+            _ => 0.0,
+        };
+        let log_lower = lower.map_or(f64::NEG_INFINITY, |b| b.ln() + shift);
+        let log_upper = upper.map_or(f64::INFINITY, |b| b.ln() - shift);
+
+        let mut interior = vec![Vec::new(); n_steps];
+        let mut observed_end = vec![true; n_steps];
+        if let BarrierMonitoring::Discrete(schedule) = monitoring {
+            observed_end.fill(false);
+            for &t in schedule.times() {
+                let end = (t / dt).round() as usize;
+                if end >= 1 && (t - end as f64 * dt).abs() < TIME_TOLERANCE * gbm.maturity {
+                    observed_end[end.min(n_steps) - 1] = true;
+                } else {
+                    let step = ((t / dt).floor() as usize).min(n_steps - 1);
+                    interior[step].push(t);
+                }
+            }
+        }
+
+        Self {
+            contract,
+            monitoring,
+            gbm,
+            dt,
+            accrue_hits,
+            log_lower,
+            log_upper,
+            interior,
+            observed_end,
+        }
+    }
+
+    #[inline]
+    fn outside(&self, x: f64) -> bool {
+        x <= self.log_lower || x >= self.log_upper
+    }
+
+    /// Probability that the Brownian bridge from `x0` to `x1` with
+    /// variance `variance` over one step crosses either barrier
+    /// (first-order for double barriers).
+    fn crossing_probability(&self, x0: f64, x1: f64, variance: f64) -> f64 {
+        let mut p = 0.0;
+        if self.log_upper.is_finite() {
+            p += (-2.0 * (self.log_upper - x0) * (self.log_upper - x1) / variance).exp();
+        }
+        if self.log_lower.is_finite() {
+            p += (-2.0 * (x0 - self.log_lower) * (x1 - self.log_lower) / variance).exp();
+        }
+        p.min(1.0)
+    }
+
+    /// Probability of surviving step `step` from `x0` to `x1` with
+    /// volatility `volatility` over the step.
+    fn survival(&self, step: usize, x0: f64, x1: f64, volatility: f64, rng: &mut PricerRng) -> f64 {
+        let variance = volatility * volatility;
+        match self.monitoring {
+            BarrierMonitoring::Continuous(correction) => {
+                if self.outside(x1) {
+                    0.0
+                } else if *correction == BarrierCorrection::BrownianBridge {
+                    1.0 - self.crossing_probability(x0, x1, variance * self.dt)
+                } else {
+                    1.0
+                }
+            }
+            BarrierMonitoring::Discrete(_) => {
+                // Sample the observations inside the step from the bridge
+                let end = (step + 1) as f64 * self.dt;
+                let (mut t, mut x) = (step as f64 * self.dt, x0);
+                for &tau in &self.interior[step] {
+                    let w = (tau - t) / (end - t);
+                    let sd = (variance * (tau - t) * (end - tau) / (end - t)).sqrt();
+                    x += w * (x1 - x) + sd * rng.gen_normal();
+                    t = tau;
+                    if self.outside(x) {
+                        return 0.0;
+                    }
+                }
+                if self.observed_end[step] && self.outside(x1) {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+
+    /// Undiscounted value at maturity of the contract on `path`.
+    ///
+    /// Payments at the hit are accrued to maturity at the GBM rate.
+    pub(crate) fn evaluate(&self, path: &[f64], rng: &mut PricerRng) -> f64 {
+        self.evaluate_with_volatilities(path, None, rng)
+    }
+
+    /// As [`evaluate`](Self::evaluate), with the Brownian bridge of each
+    /// step using `volatilities[step]` instead of the GBM volatility.
+    pub(crate) fn evaluate_with_volatilities(
+        &self,
+        path: &[f64],
+        volatilities: Option<&[f64]>,
+        rng: &mut PricerRng,
+    ) -> f64 {
+        let n_steps = path.len() - 1;
+        let (alive, hit_accrual) = self.knock_profile(path, volatilities, rng);
+        match self.contract {
+            Contract::Barrier(option) => {
+                let vanilla = compute_payoff(path[n_steps], option.payoff);
+                let (hit_rebate, expiry_rebate) =
+                    option.rebate.map_or((0.0, 0.0), |r| match r.timing {
+                        RebateTiming::AtHit => (r.amount * hit_accrual, 0.0),
+                        RebateTiming::AtExpiry => (0.0, r.amount),
+                    });
+                match option.knock {
+                    KnockType::Out => alive * vanilla + hit_rebate + (1.0 - alive) * expiry_rebate,
+                    KnockType::In => (1.0 - alive) * vanilla + alive * expiry_rebate,
+                }
+            }
+            Contract::Touch(option) => match (option.touch, option.payment) {
+                (TouchType::OneTouch, RebateTiming::AtHit) => option.amount * hit_accrual,
+                (TouchType::OneTouch, RebateTiming::AtExpiry) => option.amount * (1.0 - alive),
+                (TouchType::NoTouch, _) => option.amount * alive,
+            },
+        }
+    }
+
+    /// Survival probability to maturity and, if payments at the hit are
+    /// accrued, the expected accrual factor to maturity of a unit paid at
+    /// the hit.
+    fn knock_profile(
+        &self,
+        path: &[f64],
+        volatilities: Option<&[f64]>,
+        rng: &mut PricerRng,
+    ) -> (f64, f64) {
+        let n_steps = path.len() - 1;
+        let mut alive = 1.0;
+        let mut hit_accrual = 0.0;
+        let mut x0 = path[0].ln();
+        for step in 0..n_steps {
+            let x1 = path[step + 1].ln();
+            let volatility = volatilities.map_or(self.gbm.volatility, |v| v[step]);
+            let survival = self.survival(step, x0, x1, volatility, rng);
+            if self.accrue_hits {
+                let remaining = self.gbm.maturity - (step + 1) as f64 * self.dt;
+                hit_accrual += alive * (1.0 - survival) * (self.gbm.rate * remaining).exp();
+            }
+            alive *= survival;
+            if alive == 0.0 {
+                break;
+            }
+            x0 = x1;
+        }
+        (alive, hit_accrual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_schedules() {
+        let uniform = MonitoringSchedule::uniform(1.0, 4).unwrap();
+        assert_eq!(uniform.times(), &[0.25, 0.5, 0.75, 1.0]);
+        let daily = MonitoringSchedule::daily(1.0, 252.0).unwrap();
+        assert_eq!(daily.len(), 252);
+        assert_relative_eq!(daily.times()[0], 1.0 / 252.0);
+        assert!(MonitoringSchedule::new(vec![0.5, 0.25]).is_err());
+        assert!(MonitoringSchedule::new(vec![]).is_err());
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_business_day_schedule() {
+        use pricer_core::types::time::{Date, DayCountConvention};
+
+        // Fri 2024-03-29 to Fri 2024-04-12 with Easter Monday off
+        let start = Date::from_ymd(2024, 3, 29).unwrap();
+        let end = Date::from_ymd(2024, 4, 12).unwrap();
+        let holidays = [Date::from_ymd(2024, 4, 1).unwrap()];
+        let schedule = MonitoringSchedule::business_days(
+            start,
+            end,
+            &holidays,
+            DayCountConvention::ActualActual365,
+        )
+        .unwrap();
+        assert_eq!(schedule.len(), 9);
+        assert_relative_eq!(schedule.times()[0], 4.0 / 365.0);
+        assert_relative_eq!(*schedule.times().last().unwrap(), 14.0 / 365.0);
+
+        // Wed 2025-12-31 to Mon 2026-01-05 across the year end
+        let schedule = MonitoringSchedule::business_days(
+            Date::from_ymd(2025, 12, 31).unwrap(),
+            Date::from_ymd(2026, 1, 5).unwrap(),
+            &[Date::from_ymd(2026, 1, 1).unwrap()],
+            DayCountConvention::ActualActual365,
+        )
+        .unwrap();
+        assert_eq!(schedule.times(), &[2.0 / 365.0, 5.0 / 365.0]);
+    }
+
+    #[test]
+    fn test_validation() {
+        let call = PayoffParams::call(100.0);
+        assert!(BarrierOption::knock_out(call, None, None)
+            .validate(100.0, 1.0)
+            .is_err());
+        assert!(BarrierOption::knock_out(call, Some(105.0), None)
+            .validate(100.0, 1.0)
+            .is_err());
+        assert!(BarrierOption::knock_in(call, None, Some(120.0))
+            .with_rebate(1.0, RebateTiming::AtHit)
+            .validate(100.0, 1.0)
+            .is_err());
+        let late = BarrierMonitoring::Discrete(MonitoringSchedule::uniform(2.0, 2).unwrap());
+        assert!(BarrierOption::knock_out(call, None, Some(120.0))
+            .with_monitoring(late)
+            .validate(100.0, 1.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_crossing_probability_and_rebate() {
+        let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+        let option = BarrierOption::knock_out(PayoffParams::call(100.0), None, Some(120.0))
+            .with_rebate(2.0, RebateTiming::AtExpiry);
+        let evaluator = BarrierEvaluator::new(&option, gbm, 1);
+        let mut rng = PricerRng::from_seed(1);
+
+        // Bridge from 100 to 110 over one year: crossing probability
+        // exp(-2 ln(1.2) ln(12/11) / 0.04)
+        let p = (-2.0 * 1.2_f64.ln() * (12.0_f64 / 11.0).ln() / 0.04).exp();
+        let value = evaluator.evaluate(&[100.0, 110.0], &mut rng);
+        let vanilla = compute_payoff(110.0, option.payoff);
+        assert_relative_eq!(value, (1.0 - p) * vanilla + p * 2.0, epsilon = 1e-10);
+
+        // Terminal beyond the barrier: knocked out with certainty
+        assert_relative_eq!(evaluator.evaluate(&[100.0, 125.0], &mut rng), 2.0);
+    }
+
+    #[test]
+    fn test_touch_values_and_step_volatilities() {
+        let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+        let one_touch = TouchOption::one_touch(3.0, None, Some(120.0), RebateTiming::AtExpiry);
+        let no_touch = TouchOption::no_touch(3.0, None, Some(120.0));
+        let mut rng = PricerRng::from_seed(1);
+        let p = (-2.0 * 1.2_f64.ln() * (12.0_f64 / 11.0).ln() / 0.04).exp();
+
+        let touched = BarrierEvaluator::for_touch(&one_touch, gbm, 1);
+        let untouched = BarrierEvaluator::for_touch(&no_touch, gbm, 1);
+        assert_relative_eq!(
+            touched.evaluate(&[100.0, 110.0], &mut rng),
+            3.0 * p,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            untouched.evaluate(&[100.0, 110.0], &mut rng),
+            3.0 * (1.0 - p),
+            epsilon = 1e-12
+        );
+
+        // The bridge uses the step volatility when given
+        let q = (-2.0 * 1.2_f64.ln() * (12.0_f64 / 11.0).ln() / 0.09).exp();
+        let value = touched.evaluate_with_volatilities(&[100.0, 110.0], Some(&[0.3]), &mut rng);
+        assert_relative_eq!(value, 3.0 * q, epsilon = 1e-12);
+
+        // Paid at the hit of the last step: no accrual left
+        let at_hit = TouchOption::one_touch(3.0, None, Some(120.0), RebateTiming::AtHit);
+        let at_hit = BarrierEvaluator::for_touch(&at_hit, gbm, 1);
+        assert_relative_eq!(at_hit.evaluate(&[100.0, 125.0], &mut rng), 3.0);
+    }
+}
@@ -4,6 +4,9 @@
 //! pricing simulations with automatic differentiation support.
 
 use super::error::ConfigError;
+use super::qmc::QmcConfig;
+use super::variance_reduction::VarianceReduction;
+use crate::rng::Scrambling;
 
 /// Maximum number of simulation paths allowed.
 pub const MAX_PATHS: usize = 10_000_000;
@@ -41,6 +44,33 @@ pub enum AdMode {
     Reverse,
 }
 
+/// Source of the random variates driving the simulation.
+///
+/// - `PseudoRandom`: i.i.d. normals from the seeded PRNG
+/// - `QuasiRandom`: randomised Sobol points mapped to Brownian increments
+/// - `CounterBased`: one Philox stream per path, reproducible in parallel
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SamplingMethod {
+    /// Pseudo-random normals (standard Monte Carlo).
+    #[default]
+    PseudoRandom,
+
+    /// Sobol quasi-random points (randomised quasi-Monte Carlo).
+    ///
+    /// With more than one randomisation the standard error is estimated
+    /// from the spread of the independent randomisation estimates.
+    QuasiRandom(QmcConfig),
+
+    /// Pseudo-random normals from a Philox stream per path.
+    ///
+    /// Path `i` draws from [`PhiloxRng::new(seed, i)`](crate::rng::PhiloxRng),
+    /// so its variates do not depend on how paths are split across threads:
+    /// [`MonteCarloPricer::price_european_parallel`](super::MonteCarloPricer::price_european_parallel)
+    /// is bit-identical for any thread count. Every pricing call restarts
+    /// the streams, giving common random numbers across calls.
+    CounterBased,
+}
+
 /// Monte Carlo simulation configuration.
 ///
 /// Immutable configuration specifying simulation parameters.
@@ -72,6 +102,10 @@ pub struct MonteCarloConfig {
     ad_mode: AdMode,
     /// Optional seed for reproducibility.
     seed: Option<u64>,
+    /// Source of the random variates.
+    sampling: SamplingMethod,
+    /// Variance reduction techniques.
+    variance_reduction: VarianceReduction,
 }
 
 impl MonteCarloConfig {
@@ -117,6 +151,18 @@ impl MonteCarloConfig {
         self.seed
     }
 
+    /// Returns the sampling method.
+    #[inline]
+    pub fn sampling(&self) -> SamplingMethod {
+        self.sampling
+    }
+
+    /// Returns the variance reduction techniques.
+    #[inline]
+    pub fn variance_reduction(&self) -> VarianceReduction {
+        self.variance_reduction
+    }
+
     /// Validates the configuration.
     ///
     /// # Errors
@@ -124,6 +170,11 @@ impl MonteCarloConfig {
     /// Returns `ConfigError` if:
     /// - `n_paths` is 0 or greater than 10,000,000
     /// - `n_steps` is 0 or greater than 10,000
+    /// - quasi-random sampling uses no randomisations, more randomisations
+    ///   than paths, or several randomisations without scrambling
+    /// - antithetic variates are combined with quasi-random sampling or an
+    ///   odd number of paths
+    /// - the importance sampling drift shift is not finite
     pub fn validate(&self) -> Result<(), ConfigError> {
         if self.n_paths == 0 || self.n_paths > MAX_PATHS {
             return Err(ConfigError::InvalidPathCount(self.n_paths));
@@ -131,6 +182,46 @@ impl MonteCarloConfig {
         if self.n_steps == 0 || self.n_steps > MAX_STEPS {
             return Err(ConfigError::InvalidStepCount(self.n_steps));
         }
+        if let SamplingMethod::QuasiRandom(qmc) = self.sampling {
+            if qmc.randomisations == 0 || qmc.randomisations > self.n_paths {
+                return Err(ConfigError::InvalidParameter {
+                    name: "randomisations",
+                    value: format!("{} (must be in [1, n_paths])", qmc.randomisations),
+                });
+            }
+            if qmc.scrambling == Scrambling::None && qmc.randomisations > 1 {
+                return Err(ConfigError::InvalidParameter {
+                    name: "randomisations",
+                    value: format!(
+                        "{} (unscrambled Sobol points admit a single randomisation)",
+                        qmc.randomisations
+                    ),
+                });
+            }
+        }
+        let vr = self.variance_reduction;
+        if vr.antithetic {
+            if let SamplingMethod::QuasiRandom(_) = self.sampling {
+                return Err(ConfigError::InvalidParameter {
+                    name: "antithetic",
+                    value: "not supported with quasi-random sampling".to_string(),
+                });
+            }
+            if self.n_paths % 2 != 0 {
+                return Err(ConfigError::InvalidParameter {
+                    name: "antithetic",
+                    value: format!("{} paths (must be even)", self.n_paths),
+                });
+            }
+        }
+        if let Some(shift) = vr.drift_shift {
+            if !shift.is_finite() {
+                return Err(ConfigError::InvalidParameter {
+                    name: "drift_shift",
+                    value: shift.to_string(),
+                });
+            }
+        }
         Ok(())
     }
 }
@@ -158,6 +249,8 @@ pub struct MonteCarloConfigBuilder {
     n_steps: Option<usize>,
     ad_mode: AdMode,
     seed: Option<u64>,
+    sampling: SamplingMethod,
+    variance_reduction: VarianceReduction,
 }
 
 impl MonteCarloConfigBuilder {
@@ -205,6 +298,29 @@ impl MonteCarloConfigBuilder {
         self
     }
 
+    /// Sets the sampling method.
+    ///
+    /// # Arguments
+    ///
+    /// * `sampling` - Pseudo-random or quasi-random sampling
+    #[inline]
+    pub fn sampling(mut self, sampling: SamplingMethod) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Sets the variance reduction techniques.
+    ///
+    /// # Arguments
+    ///
+    /// * `variance_reduction` - Antithetic, control variate and importance
+    ///   sampling settings
+    #[inline]
+    pub fn variance_reduction(mut self, variance_reduction: VarianceReduction) -> Self {
+        self.variance_reduction = variance_reduction;
+        self
+    }
+
     /// Builds the configuration.
     ///
     /// # Errors
@@ -228,6 +344,8 @@ impl MonteCarloConfigBuilder {
             n_steps,
             ad_mode: self.ad_mode,
             seed: self.seed,
+            sampling: self.sampling,
+            variance_reduction: self.variance_reduction,
         };
 
         config.validate()?;
@@ -341,4 +459,95 @@ mod tests {
     fn test_ad_mode_default() {
         assert_eq!(AdMode::default(), AdMode::NoAd);
     }
+
+    #[test]
+    fn test_sampling_default_pseudo_random() {
+        let config = MonteCarloConfig::builder()
+            .n_paths(1000)
+            .n_steps(10)
+            .build()
+            .unwrap();
+        assert_eq!(config.sampling(), SamplingMethod::PseudoRandom);
+    }
+
+    #[test]
+    fn test_sampling_quasi_random_validation() {
+        let builder = MonteCarloConfig::builder().n_paths(1000).n_steps(10);
+        let qmc = QmcConfig::default();
+
+        let config = builder
+            .clone()
+            .sampling(SamplingMethod::QuasiRandom(qmc))
+            .build()
+            .unwrap();
+        assert_eq!(config.sampling(), SamplingMethod::QuasiRandom(qmc));
+
+        for invalid in [
+            qmc.with_randomisations(0),
+            qmc.with_randomisations(1001),
+            QmcConfig::new(Scrambling::None, qmc.construction).with_randomisations(2),
+        ] {
+            let result = builder
+                .clone()
+                .sampling(SamplingMethod::QuasiRandom(invalid))
+                .build();
+            assert!(matches!(
+                result,
+                Err(ConfigError::InvalidParameter {
+                    name: "randomisations",
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_variance_reduction_validation() {
+        let builder = MonteCarloConfig::builder().n_paths(1000).n_steps(10);
+        let antithetic = VarianceReduction::default().with_antithetic();
+
+        let config = builder
+            .clone()
+            .variance_reduction(antithetic)
+            .build()
+            .unwrap();
+        assert!(config.variance_reduction().antithetic);
+
+        let odd = builder
+            .clone()
+            .n_paths(999)
+            .variance_reduction(antithetic)
+            .build();
+        assert!(matches!(
+            odd,
+            Err(ConfigError::InvalidParameter {
+                name: "antithetic",
+                ..
+            })
+        ));
+
+        let qmc = builder
+            .clone()
+            .sampling(SamplingMethod::QuasiRandom(QmcConfig::default()))
+            .variance_reduction(antithetic)
+            .build();
+        assert!(matches!(
+            qmc,
+            Err(ConfigError::InvalidParameter {
+                name: "antithetic",
+                ..
+            })
+        ));
+
+        let shift = builder
+            .variance_reduction(VarianceReduction::default().with_drift_shift(f64::NAN))
+            .build();
+        assert!(matches!(
+            shift,
+            Err(ConfigError::InvalidParameter {
+                name: "drift_shift",
+                ..
+            })
+        ));
+    }
 }
@@ -0,0 +1,225 @@
+//! Discrete dividend and carry adjustment for Monte Carlo paths.
+//!
+//! Equity paths with dividend yield, repo, borrow and discrete dividends
+//! are simulated with the escrowed dividend model: a driftless GBM `X`
+//! started at the escrowed spot `S*(T)` is mapped back to the stock on the
+//! time grid by
+//!
+//! ```text
+//! S(tᵢ) = G(tᵢ)·(X(tᵢ) + Σ_{tᵢ < t_k ≤ T} D_k / G(t_k))
+//! ```
+//!
+//! where `G` is the carry growth factor including proportional dividends.
+//! The simulated terminal distribution then matches the market forward
+//! and the analytical escrowed Black-Scholes price.
+//!
+//! With the `l1l2-integration` feature, [`DividendAdjustment::from_market`]
+//! builds the adjustment from a `pricer_core` equity market.
+
+use super::error::ConfigError;
+
+/// Per-step mapping from the escrowed process to the stock price.
+///
+/// Holds plain `f64` values on the simulation grid `tᵢ = i·T/n`,
+/// `i = 0..=n`, so the inner loop stays allocation-free.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DividendAdjustment {
+    /// Escrowed spot S*(T), the start of the driftless process.
+    escrowed_spot: f64,
+    /// Maturity T of the simulation grid.
+    maturity: f64,
+    /// Growth factor G(tᵢ) per grid point.
+    growth: Vec<f64>,
+    /// Escrow Σ_{tᵢ < t_k ≤ T} D_k / G(t_k) per grid point.
+    escrow: Vec<f64>,
+}
+
+impl DividendAdjustment {
+    /// Creates an adjustment from precomputed grid values.
+    ///
+    /// # Arguments
+    ///
+    /// * `escrowed_spot` - Escrowed spot S*(T)
+    /// * `maturity` - Maturity T of the grid
+    /// * `growth` - Growth factors G(tᵢ), one per grid point
+    /// * `escrow` - Remaining escrowed dividends, one per grid point
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the escrowed spot or
+    /// maturity is non-positive, or the grids are shorter than two points
+    /// or of different lengths.
+    pub fn new(
+        escrowed_spot: f64,
+        maturity: f64,
+        growth: Vec<f64>,
+        escrow: Vec<f64>,
+    ) -> Result<Self, ConfigError> {
+        if escrowed_spot.is_nan() || escrowed_spot <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "escrowed_spot",
+                value: format!("{} must be positive", escrowed_spot),
+            });
+        }
+        if maturity.is_nan() || maturity <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "maturity",
+                value: format!("{} must be positive", maturity),
+            });
+        }
+        if growth.len() < 2 || growth.len() != escrow.len() {
+            return Err(ConfigError::InvalidParameter {
+                name: "growth",
+                value: format!(
+                    "grid lengths {} and {} must match and cover at least one step",
+                    growth.len(),
+                    escrow.len()
+                ),
+            });
+        }
+        Ok(Self {
+            escrowed_spot,
+            maturity,
+            growth,
+            escrow,
+        })
+    }
+
+    /// Creates the adjustment for a continuous dividend yield only.
+    ///
+    /// Equivalent to simulating GBM with drift `r - q`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` for non-positive spot or
+    /// maturity, and `ConfigError::InvalidStepCount` if `n_steps == 0`.
+    pub fn continuous(
+        spot: f64,
+        rate: f64,
+        dividend_yield: f64,
+        maturity: f64,
+        n_steps: usize,
+    ) -> Result<Self, ConfigError> {
+        if n_steps == 0 {
+            return Err(ConfigError::InvalidStepCount(n_steps));
+        }
+        let dt = maturity / n_steps as f64;
+        let growth = (0..=n_steps)
+            .map(|i| ((rate - dividend_yield) * dt * i as f64).exp())
+            .collect();
+        Self::new(spot, maturity, growth, vec![0.0; n_steps + 1])
+    }
+
+    /// Builds the adjustment from an equity market on an `n_steps` grid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the market cannot
+    /// produce forwards to `maturity` (e.g. dividends exhaust spot), and
+    /// `ConfigError::InvalidStepCount` if `n_steps == 0`.
+    #[cfg(feature = "l1l2-integration")]
+    pub fn from_market(
+        market: &pricer_core::market_data::equity::EquityMarket<f64>,
+        maturity: f64,
+        n_steps: usize,
+    ) -> Result<Self, ConfigError> {
+        if n_steps == 0 {
+            return Err(ConfigError::InvalidStepCount(n_steps));
+        }
+        let market_error =
+            |e: pricer_core::market_data::MarketDataError| ConfigError::InvalidParameter {
+                name: "market",
+                value: e.to_string(),
+            };
+
+        let dt = maturity / n_steps as f64;
+        let mut growth = Vec::with_capacity(n_steps + 1);
+        let mut escrow = Vec::with_capacity(n_steps + 1);
+        for i in 0..=n_steps {
+            let t = if i == n_steps {
+                maturity
+            } else {
+                dt * i as f64
+            };
+            growth.push(market.growth_factor(t).map_err(market_error)?);
+            escrow.push(
+                market
+                    .cash_dividend_escrow(t, maturity)
+                    .map_err(market_error)?,
+            );
+        }
+        let escrowed_spot = market.escrowed_spot(maturity).map_err(market_error)?;
+        Self::new(escrowed_spot, maturity, growth, escrow)
+    }
+
+    /// Returns the escrowed spot S*(T).
+    #[inline]
+    pub fn escrowed_spot(&self) -> f64 {
+        self.escrowed_spot
+    }
+
+    /// Returns the grid maturity.
+    #[inline]
+    pub fn maturity(&self) -> f64 {
+        self.maturity
+    }
+
+    /// Returns the number of time steps in the grid.
+    #[inline]
+    pub fn n_steps(&self) -> usize {
+        self.growth.len() - 1
+    }
+
+    /// Returns the forward F(T) = G(T)·S*(T).
+    #[inline]
+    pub fn forward(&self) -> f64 {
+        self.growth[self.n_steps()] * self.escrowed_spot
+    }
+
+    /// Maps escrowed-process paths to stock price paths in place.
+    ///
+    /// `paths` is row-major with `n_steps + 1` points per path, as produced
+    /// by [`generate_gbm_paths`](super::generate_gbm_paths).
+    pub fn apply(&self, paths: &mut [f64], n_paths: usize) {
+        let n_points = self.growth.len();
+        for path in paths.chunks_exact_mut(n_points).take(n_paths) {
+            for ((s, &g), &e) in path.iter_mut().zip(&self.growth).zip(&self.escrow) {
+                *s = g * (*s + e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_continuous_grid() {
+        let adj = DividendAdjustment::continuous(100.0, 0.05, 0.02, 1.0, 4).unwrap();
+        assert_eq!(adj.n_steps(), 4);
+        assert_relative_eq!(adj.forward(), 100.0 * 0.03_f64.exp(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_apply_maps_escrowed_paths() {
+        let adj =
+            DividendAdjustment::new(95.0, 1.0, vec![1.0, 1.1, 1.2], vec![5.0, 0.0, 0.0]).unwrap();
+        let mut paths = vec![95.0, 100.0, 90.0, 95.0, 80.0, 110.0];
+        adj.apply(&mut paths, 2);
+        let expected = [100.0, 110.0, 108.0, 100.0, 88.0, 132.0];
+        for (s, e) in paths.iter().zip(expected) {
+            assert_relative_eq!(*s, e, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(DividendAdjustment::new(0.0, 1.0, vec![1.0, 1.0], vec![0.0, 0.0]).is_err());
+        assert!(DividendAdjustment::new(1.0, 0.0, vec![1.0, 1.0], vec![0.0, 0.0]).is_err());
+        assert!(DividendAdjustment::new(1.0, 1.0, vec![1.0], vec![0.0]).is_err());
+        assert!(DividendAdjustment::new(1.0, 1.0, vec![1.0, 1.0], vec![0.0]).is_err());
+        assert!(DividendAdjustment::continuous(100.0, 0.05, 0.0, 1.0, 0).is_err());
+    }
+}
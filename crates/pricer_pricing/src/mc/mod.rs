@@ -12,6 +12,20 @@
 //! - Smooth payoff functions for AD compatibility
 //! - Greeks via bump-and-revalue (placeholder for Enzyme AD)
 //! - Manual tangent propagation for Delta (forward-mode AD prototype)
+//! - Discrete dividends, repo and borrow via the escrowed dividend model
+//! - Sobol quasi-Monte Carlo with Brownian bridge or PCA path construction
+//! - Any `StochasticModelEnum` model (Heston, SABR, Hull-White, CIR) via
+//!   `generate_model_paths` (requires `l1l2-integration`)
+//! - Antithetic variates, control variates and drift-shift importance
+//!   sampling via [`VarianceReduction`]
+//! - Single and double barriers with Brownian-bridge or continuity
+//!   corrections, discrete monitoring schedules and rebates via
+//!   [`BarrierOption`], and one-touch/no-touch options via [`TouchOption`],
+//!   under GBM or stochastic-local volatility (requires `l1l2-integration`)
+//! - Thread-count independent parallel pricing with per-path Philox
+//!   streams via [`SamplingMethod::CounterBased`]
+//! - Adaptive path counts to a standard error target with convergence
+//!   traces via [`AdaptiveConfig`], and standard errors for Greeks
 //!
 //! Phase 4 will integrate actual Enzyme `#[autodiff]` macros.
 //!
@@ -23,7 +37,7 @@
 //! ├── PathWorkspace     (pre-allocated buffers)
 //! ├── PricerRng         (random number generation)
 //! └── Orchestration
-//!     ├── generate_gbm_paths()
+//!     ├── generate_gbm_paths() / generate_model_paths()
 //!     ├── compute_payoffs()
 //!     └── Greeks computation
 //! ```
@@ -143,29 +157,48 @@
 //! println!("Price: {:.4}, Delta: {:.4}", price, delta);
 //! ```
 
+pub mod adaptive;
+pub mod barrier;
 pub mod config;
+pub mod dividends;
 pub mod error;
+#[cfg(feature = "l1l2-integration")]
+pub mod model_paths;
+pub mod path_construction;
 pub mod paths;
 pub mod payoff;
 pub mod pricer;
 pub mod pricer_checkpoint;
+pub mod qmc;
 pub mod thread_local;
+pub mod variance_reduction;
 pub mod workspace;
 pub mod workspace_checkpoint;
 
 // Re-exports for convenient access
-pub use config::{AdMode, MonteCarloConfig, MonteCarloConfigBuilder};
+pub use adaptive::{AdaptiveConfig, ConvergencePoint, ConvergenceTrace, ErrorTarget};
+pub use barrier::{
+    BarrierCorrection, BarrierMonitoring, BarrierOption, MonitoringSchedule, Rebate, RebateTiming,
+    TouchOption, TouchType,
+};
+pub use config::{AdMode, MonteCarloConfig, MonteCarloConfigBuilder, SamplingMethod};
+pub use dividends::DividendAdjustment;
 pub use error::ConfigError;
+#[cfg(feature = "l1l2-integration")]
+pub use model_paths::generate_model_paths;
+pub use path_construction::{BrownianConstruction, PathConstruction};
 pub use paths::{generate_gbm_paths, GbmParams};
 pub use payoff::{
     asian_arithmetic_call_smooth, asian_arithmetic_put_smooth, compute_payoff, compute_payoffs,
     european_call_smooth, european_put_smooth, soft_plus, soft_plus_derivative, PayoffParams,
     PayoffType,
 };
-pub use pricer::{Greek, MonteCarloPricer, PricingResult};
+pub use pricer::{Greek, GreekStdErrors, MonteCarloPricer, PricingResult, PARALLEL_BLOCK_PATHS};
+pub use qmc::{QmcConfig, QmcSampler};
 pub use thread_local::{
-    current_thread_index, DefaultWorkspaceFactory, ParallelWorkspaces, ThreadLocalWorkspacePool,
-    WorkspaceFactory,
+    current_thread_index, DefaultWorkspaceFactory, ParallelWorkspaces, PooledWorkspace,
+    ThreadLocalWorkspacePool, WorkspaceFactory,
 };
+pub use variance_reduction::VarianceReduction;
 pub use workspace::PathWorkspace;
 pub use workspace_checkpoint::CheckpointWorkspace;
@@ -0,0 +1,147 @@
+//! Path generation for arbitrary stochastic models.
+//!
+//! [`generate_model_paths`] simulates any [`StochasticModelEnum`] through
+//! its `evolve_step`, so multi-factor models (Heston with its QE scheme,
+//! SABR) and short-rate models (Hull-White, CIR) run on the same engine as
+//! GBM.
+//!
+//! # Memory Layout
+//!
+//! - Randoms hold `brownian_dim` standard normals per step:
+//!   `randoms[(path_idx * n_steps + step) * brownian_dim + factor]`
+//! - Paths hold the first state component (price, forward or short rate)
+//!   in the usual layout `paths[path_idx * (n_steps + 1) + step_idx]`, so
+//!   the payoff and [`PathObserver`](crate::path_dependent::PathObserver)
+//!   machinery apply unchanged. Secondary state such as the Heston
+//!   variance is carried along each path but not stored.
+
+use pricer_models::models::model_enum::{ModelParams, StochasticModelEnum};
+
+use super::workspace::PathWorkspace;
+
+/// Generates paths of `model` into the workspace.
+///
+/// The workspace must have been sized with
+/// [`ensure_capacity_with_factors`](PathWorkspace::ensure_capacity_with_factors)
+/// for `model.brownian_dim()` factors and its randoms filled with standard
+/// normals.
+///
+/// # Arguments
+///
+/// * `workspace` - Pre-allocated workspace with filled randoms
+/// * `model` - Stochastic model to simulate
+/// * `params` - Parameters of `model`
+/// * `maturity` - Simulation horizon in years
+/// * `n_paths` - Number of paths
+/// * `n_steps` - Number of time steps
+///
+/// # Examples
+///
+/// ```rust
+/// use pricer_models::models::gbm::GBMParams;
+/// use pricer_models::models::model_enum::{ModelParams, StochasticModelEnum};
+/// use pricer_pricing::mc::{generate_model_paths, PathWorkspace};
+///
+/// let model = StochasticModelEnum::<f64>::gbm();
+/// let params = ModelParams::GBM(GBMParams::new(100.0, 0.05, 0.2).unwrap());
+///
+/// let mut workspace = PathWorkspace::new(2, 4);
+/// workspace.ensure_capacity_with_factors(2, 4, model.brownian_dim());
+/// workspace.randoms_mut().fill(0.0);
+/// generate_model_paths(&mut workspace, &model, &params, 1.0, 2, 4);
+///
+/// assert_eq!(workspace.paths()[0], 100.0);
+/// ```
+pub fn generate_model_paths(
+    workspace: &mut PathWorkspace,
+    model: &StochasticModelEnum<f64>,
+    params: &ModelParams<f64>,
+    maturity: f64,
+    n_paths: usize,
+    n_steps: usize,
+) {
+    let n_factors = model.brownian_dim();
+    debug_assert!(n_paths <= workspace.capacity_paths());
+    debug_assert_eq!(workspace.size_factors(), n_factors);
+
+    let dt = maturity / n_steps as f64;
+    let initial = model.initial_state(params);
+
+    let (paths, randoms) = workspace.paths_mut_and_randoms();
+    let n_steps_plus_1 = n_steps + 1;
+    let row_len = n_steps * n_factors;
+
+    for path_idx in 0..n_paths {
+        let path_offset = path_idx * n_steps_plus_1;
+        let row = &randoms[path_idx * row_len..(path_idx + 1) * row_len];
+
+        let mut state = initial;
+        paths[path_offset] = state.price();
+
+        for (step, dw) in row.chunks_exact(n_factors).enumerate() {
+            state = model.evolve_step(state, dt, dw, params);
+            paths[path_offset + step + 1] = state.price();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mc::{generate_gbm_paths, GbmParams};
+    use pricer_models::models::gbm::GBMParams;
+    use pricer_models::models::heston::HestonParams;
+
+    #[test]
+    fn test_gbm_model_matches_gbm_paths() {
+        let (n_paths, n_steps) = (8, 12);
+        let randoms: Vec<f64> = (0..n_paths * n_steps)
+            .map(|i| ((i as f64) * 0.37).sin())
+            .collect();
+
+        let mut expected = PathWorkspace::new(n_paths, n_steps);
+        expected.randoms_mut().copy_from_slice(&randoms);
+        generate_gbm_paths(
+            &mut expected,
+            GbmParams::new(100.0, 0.05, 0.2, 1.0),
+            n_paths,
+            n_steps,
+        );
+
+        let model = StochasticModelEnum::gbm();
+        let params = ModelParams::GBM(GBMParams::new(100.0, 0.05, 0.2).unwrap());
+        let mut workspace = PathWorkspace::new(n_paths, n_steps);
+        workspace.ensure_capacity_with_factors(n_paths, n_steps, 1);
+        workspace.randoms_mut().copy_from_slice(&randoms);
+        generate_model_paths(&mut workspace, &model, &params, 1.0, n_paths, n_steps);
+
+        for (a, b) in workspace.paths().iter().zip(expected.paths()) {
+            assert!((a - b).abs() < 1e-10 * b.abs());
+        }
+    }
+
+    #[test]
+    fn test_heston_paths_use_both_factors() {
+        let (n_paths, n_steps) = (2, 10);
+        let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+        let model = StochasticModelEnum::heston(heston).unwrap();
+        let params = ModelParams::Heston(heston);
+
+        let mut workspace = PathWorkspace::new(n_paths, n_steps);
+        workspace.ensure_capacity_with_factors(n_paths, n_steps, 2);
+        // Paths share price shocks and differ only in the variance shocks
+        for (i, z) in workspace.randoms_mut().iter_mut().enumerate() {
+            let path_idx = i / (2 * n_steps);
+            *z = match (i % 2, path_idx) {
+                (0, _) => 0.5,
+                (_, 0) => 1.5,
+                _ => -1.5,
+            };
+        }
+        generate_model_paths(&mut workspace, &model, &params, 1.0, n_paths, n_steps);
+
+        let paths = workspace.paths();
+        assert_eq!(paths[0], 100.0);
+        assert_ne!(paths[n_steps], paths[2 * n_steps + 1]);
+    }
+}
@@ -0,0 +1,291 @@
+//! Brownian path construction for quasi-Monte Carlo.
+//!
+//! QMC points are most uniform in their leading coordinates, so the
+//! construction of a Brownian path from a vector of normal variates matters:
+//! assigning the leading coordinates to the directions carrying most of the
+//! path variance lowers the effective dimension of the integrand.
+//!
+//! - [`PathConstruction::Incremental`]: each normal drives one increment
+//! - [`PathConstruction::BrownianBridge`]: the first normal fixes the
+//!   terminal value, later ones fill in midpoints recursively
+//! - [`PathConstruction::Pca`]: principal components of the Brownian
+//!   covariance, in decreasing order of variance
+//!
+//! All constructions are orthogonal maps on a uniform time grid, so they
+//! turn i.i.d. standard normals into i.i.d. standard normal increments and
+//! the path generators can consume their output unchanged.
+
+use std::f64::consts::PI;
+
+/// Method used to build Brownian increments from normal variates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PathConstruction {
+    /// Sequential increments (standard Euler ordering).
+    #[default]
+    Incremental,
+    /// Brownian bridge (terminal value first, then bisection).
+    BrownianBridge,
+    /// Principal component analysis of the path covariance.
+    Pca,
+}
+
+/// Precomputed Brownian path construction for a uniform time grid.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::mc::{BrownianConstruction, PathConstruction};
+///
+/// let bridge = BrownianConstruction::new(PathConstruction::BrownianBridge, 4);
+/// let normals = [1.0, 0.0, 0.0, 0.0];
+/// let mut increments = [0.0; 4];
+/// bridge.transform(&normals, &mut increments);
+///
+/// // The first normal alone sets W_T = sqrt(4) and spreads it evenly
+/// assert!(increments.iter().all(|&dw| (dw - 0.5).abs() < 1e-12));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BrownianConstruction {
+    method: PathConstruction,
+    n_steps: usize,
+    /// Brownian bridge tables (empty unless `method` is `BrownianBridge`).
+    bridge: Vec<BridgeNode>,
+    /// Row-major `n_steps × n_steps` increment matrix for PCA.
+    pca: Vec<f64>,
+}
+
+/// One bisection of the Brownian bridge (unit time steps, 0-based points).
+#[derive(Clone, Copy, Debug)]
+struct BridgeNode {
+    /// Point being filled in.
+    index: usize,
+    /// Left neighbour (`None` means time zero).
+    left: Option<usize>,
+    /// Right neighbour.
+    right: usize,
+    left_weight: f64,
+    right_weight: f64,
+    std_dev: f64,
+}
+
+impl BrownianConstruction {
+    /// Precomputes the construction for `n_steps` equal time steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - Path construction method
+    /// * `n_steps` - Number of time steps
+    pub fn new(method: PathConstruction, n_steps: usize) -> Self {
+        let bridge = match method {
+            PathConstruction::BrownianBridge => bridge_nodes(n_steps),
+            _ => Vec::new(),
+        };
+        let pca = match method {
+            PathConstruction::Pca => pca_matrix(n_steps),
+            _ => Vec::new(),
+        };
+
+        Self {
+            method,
+            n_steps,
+            bridge,
+            pca,
+        }
+    }
+
+    /// Returns the construction method.
+    #[inline]
+    pub fn method(&self) -> PathConstruction {
+        self.method
+    }
+
+    /// Returns the number of time steps.
+    #[inline]
+    pub fn n_steps(&self) -> usize {
+        self.n_steps
+    }
+
+    /// Maps normal variates to standardised Brownian increments.
+    ///
+    /// `increments[i]` is `(W(t_{i+1}) - W(t_i)) / sqrt(dt)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `normals` - `n_steps` i.i.d. standard normals, most important first
+    /// * `increments` - Output buffer of `n_steps` values
+    pub fn transform(&self, normals: &[f64], increments: &mut [f64]) {
+        let n = self.n_steps;
+        let (normals, increments) = (&normals[..n], &mut increments[..n]);
+
+        match self.method {
+            PathConstruction::Incremental => increments.copy_from_slice(normals),
+            PathConstruction::BrownianBridge => {
+                // Build W at unit times 1..=n in place, then difference
+                increments.fill(0.0);
+                for (node, &z) in self.bridge.iter().zip(normals) {
+                    let left = node.left.map_or(0.0, |j| increments[j]);
+                    increments[node.index] = node.left_weight * left
+                        + node.right_weight * increments[node.right]
+                        + node.std_dev * z;
+                }
+                for i in (1..n).rev() {
+                    increments[i] -= increments[i - 1];
+                }
+            }
+            PathConstruction::Pca => {
+                for (i, dw) in increments.iter_mut().enumerate() {
+                    let row = &self.pca[i * n..(i + 1) * n];
+                    *dw = row.iter().zip(normals).map(|(m, z)| m * z).sum();
+                }
+            }
+        }
+    }
+}
+
+/// Bisection schedule of the Brownian bridge on times `1..=n`.
+fn bridge_nodes(n: usize) -> Vec<BridgeNode> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let time = |i: usize| (i + 1) as f64;
+    let mut filled = vec![false; n];
+    let mut nodes = Vec::with_capacity(n);
+
+    filled[n - 1] = true;
+    nodes.push(BridgeNode {
+        index: n - 1,
+        left: None,
+        right: n - 1,
+        left_weight: 0.0,
+        right_weight: 0.0,
+        std_dev: time(n - 1).sqrt(),
+    });
+
+    let mut j = 0;
+    for _ in 1..n {
+        // Next unfilled gap [j, k)
+        while filled[j] {
+            j += 1;
+        }
+        let mut k = j;
+        while !filled[k] {
+            k += 1;
+        }
+        let l = j + (k - 1 - j) / 2;
+        filled[l] = true;
+
+        let t_left = if j == 0 { 0.0 } else { time(j - 1) };
+        let (t_mid, t_right) = (time(l), time(k));
+        nodes.push(BridgeNode {
+            index: l,
+            left: if j == 0 { None } else { Some(j - 1) },
+            right: k,
+            left_weight: (t_right - t_mid) / (t_right - t_left),
+            right_weight: (t_mid - t_left) / (t_right - t_left),
+            std_dev: ((t_mid - t_left) * (t_right - t_mid) / (t_right - t_left)).sqrt(),
+        });
+
+        j = k + 1;
+        if j >= n {
+            j = 0;
+        }
+    }
+    nodes
+}
+
+/// Increment matrix of the PCA construction on times `1..=n`.
+///
+/// The covariance `min(i, j)` has eigenvectors
+/// `v_k(i) = 2 / sqrt(2n + 1) · sin((2k - 1) i π / (2n + 1))` with
+/// eigenvalues `1 / (4 sin²((2k - 1) π / (2(2n + 1))))`, decreasing in `k`.
+fn pca_matrix(n: usize) -> Vec<f64> {
+    let denom = (2 * n + 1) as f64;
+    let norm = 2.0 / denom.sqrt();
+    let mut matrix = vec![0.0; n * n];
+
+    for k in 0..n {
+        let freq = (2 * k + 1) as f64 * PI / denom;
+        let sqrt_lambda = 0.5 / (0.5 * freq).sin();
+        for i in 0..n {
+            let v = |t: usize| norm * (freq * t as f64).sin();
+            matrix[i * n + k] = sqrt_lambda * (v(i + 1) - v(i));
+        }
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// Returns the matrix M with increments = M z, column by column.
+    fn construction_matrix(construction: &BrownianConstruction) -> Vec<Vec<f64>> {
+        let n = construction.n_steps();
+        (0..n)
+            .map(|k| {
+                let mut z = vec![0.0; n];
+                z[k] = 1.0;
+                let mut dw = vec![0.0; n];
+                construction.transform(&z, &mut dw);
+                dw
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_constructions_are_orthogonal() {
+        for method in [
+            PathConstruction::Incremental,
+            PathConstruction::BrownianBridge,
+            PathConstruction::Pca,
+        ] {
+            for n in [1, 2, 7, 16, 50] {
+                let columns = construction_matrix(&BrownianConstruction::new(method, n));
+                // Increment covariance M Mᵀ must be the identity
+                for i in 0..n {
+                    for j in 0..n {
+                        let cov: f64 = columns.iter().map(|c| c[i] * c[j]).sum();
+                        let expected = if i == j { 1.0 } else { 0.0 };
+                        assert_relative_eq!(cov, expected, epsilon = 1e-10);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bridge_first_normal_sets_terminal_value() {
+        let n = 12;
+        let bridge = BrownianConstruction::new(PathConstruction::BrownianBridge, n);
+        let columns = construction_matrix(&bridge);
+
+        // Only the first normal contributes to W_T
+        let terminal: Vec<f64> = columns.iter().map(|c| c.iter().sum()).collect();
+        assert_relative_eq!(terminal[0], (n as f64).sqrt(), epsilon = 1e-12);
+        assert!(terminal[1..].iter().all(|t| t.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_pca_components_ordered_by_variance() {
+        let columns = construction_matrix(&BrownianConstruction::new(PathConstruction::Pca, 20));
+
+        // Variance of the path carried by each component, Σ_i W_i²
+        let variance: Vec<f64> = columns
+            .iter()
+            .map(|c| {
+                let mut w = 0.0;
+                c.iter()
+                    .map(|dw| {
+                        w += dw;
+                        w * w
+                    })
+                    .sum()
+            })
+            .collect();
+        assert!(variance.windows(2).all(|v| v[0] > v[1]));
+        // The first component explains most of the total variance n(n+1)/2
+        assert!(variance[0] / (20.0 * 21.0 / 2.0) > 0.8);
+    }
+}
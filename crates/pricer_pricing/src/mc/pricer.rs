@@ -16,14 +16,52 @@
 //!
 //! The pricer maintains an internal [`PathWorkspace`](super::workspace::PathWorkspace)
 //! that is reused across pricing calls, minimising memory allocations.
+//! Parallel pricing runs each block on a per-thread workspace from a
+//! [`ThreadLocalWorkspacePool`](super::thread_local::ThreadLocalWorkspacePool).
 
-use super::config::MonteCarloConfig;
+use super::adaptive::{AdaptiveConfig, ConvergencePoint, ConvergenceTrace};
+use super::barrier::{BarrierEvaluator, BarrierOption, TouchOption};
+use super::config::{MonteCarloConfig, SamplingMethod};
+use super::dividends::DividendAdjustment;
 use super::error::ConfigError;
+#[cfg(feature = "l1l2-integration")]
+use super::model_paths::generate_model_paths;
 use super::paths::{generate_gbm_paths, generate_gbm_paths_tangent_spot, GbmParams};
 use super::payoff::{compute_payoff, compute_payoffs, PayoffParams};
+use super::qmc::QmcSampler;
+use super::thread_local::ThreadLocalWorkspacePool;
+use super::variance_reduction::{
+    apply_drift_shift, expected_geometric_asian, expected_terminal_spot, mirror_antithetic,
+    ReducedSamples,
+};
 use super::workspace::PathWorkspace;
 use crate::path_dependent::{PathObserver, PathPayoffType};
-use crate::rng::PricerRng;
+use crate::rng::{PhiloxRng, PricerRng};
+#[cfg(feature = "l1l2-integration")]
+use pricer_models::models::model_enum::{ModelParams, StochasticModelEnum};
+#[cfg(feature = "l1l2-integration")]
+use pricer_models::models::slv::{SlvModel, SLV_NORMALS_PER_STEP};
+use rayon::prelude::*;
+use std::time::Instant;
+
+/// GBM with the initial spot and instantaneous volatility of `model`,
+/// used for the continuity correction and hit accrual of SLV barriers.
+#[cfg(feature = "l1l2-integration")]
+fn slv_reference_gbm(model: &SlvModel, maturity: f64) -> GbmParams {
+    let heston = &model.params().heston;
+    GbmParams::new(
+        heston.spot,
+        heston.rate,
+        model.local_volatility(0.0, heston.spot, heston.v0),
+        maturity,
+    )
+}
+
+/// Paths per block in [`MonteCarloPricer::price_european_parallel`].
+///
+/// Blocks are fixed so that the work split, and hence the result, does
+/// not depend on the number of threads.
+pub const PARALLEL_BLOCK_PATHS: usize = 1024;
 
 /// Greek type for selection.
 ///
@@ -79,10 +117,25 @@ pub enum Greek {
 /// - `vanna`: ∂²V/∂S∂σ - Cross sensitivity (delta-vol)
 /// - `volga`: ∂²V/∂σ² - Volatility convexity
 ///
+/// # Variance Reduction
+///
+/// When the configuration enables any
+/// [`VarianceReduction`](super::variance_reduction::VarianceReduction)
+/// technique, `variance_reduction_factor` reports the plain Monte Carlo
+/// variance of the mean, estimated from the same paths, over the achieved
+/// `std_error²`.
+///
+/// # Diagnostics
+///
+/// Bump-and-revalue Greeks report their standard errors in
+/// `greek_std_errors`, and adaptive pricing (see
+/// [`AdaptiveConfig`](super::adaptive::AdaptiveConfig)) records the
+/// per-batch estimates in `convergence`.
+///
 /// # Examples
 ///
 /// ```rust
-/// use pricer_pricing::mc::PricingResult;
+/// use pricer_pricing::mc::{GreekStdErrors, PricingResult};
 ///
 /// let result = PricingResult {
 ///     price: 10.5,
@@ -94,6 +147,9 @@ pub enum Greek {
 ///     rho: None,
 ///     vanna: None,
 ///     volga: None,
+///     variance_reduction_factor: None,
+///     greek_std_errors: GreekStdErrors::default(),
+///     convergence: None,
 /// };
 ///
 /// println!("Price: {} +/- {}", result.price, result.std_error * 1.96);
@@ -122,6 +178,14 @@ pub struct PricingResult {
     pub vanna: Option<f64>,
     /// Volga: ∂²V/∂σ² (volatility convexity, also known as vomma).
     pub volga: Option<f64>,
+
+    /// Variance reduction factor achieved relative to plain sampling.
+    pub variance_reduction_factor: Option<f64>,
+
+    /// Standard errors of the computed Greeks.
+    pub greek_std_errors: GreekStdErrors,
+    /// Per-batch convergence history of an adaptive simulation.
+    pub convergence: Option<ConvergenceTrace>,
 }
 
 impl PricingResult {
@@ -136,6 +200,58 @@ impl PricingResult {
     pub fn confidence_99(&self) -> f64 {
         2.576 * self.std_error
     }
+
+    /// Stores `greek` and its standard error.
+    fn set_greek(&mut self, greek: Greek, (value, std_error): (f64, f64)) {
+        let (slot, error_slot) = match greek {
+            Greek::Delta => (&mut self.delta, &mut self.greek_std_errors.delta),
+            Greek::Vega => (&mut self.vega, &mut self.greek_std_errors.vega),
+            Greek::Theta => (&mut self.theta, &mut self.greek_std_errors.theta),
+            Greek::Rho => (&mut self.rho, &mut self.greek_std_errors.rho),
+            Greek::Gamma => (&mut self.gamma, &mut self.greek_std_errors.gamma),
+            Greek::Vanna => (&mut self.vanna, &mut self.greek_std_errors.vanna),
+            Greek::Volga => (&mut self.volga, &mut self.greek_std_errors.volga),
+        };
+        *slot = Some(value);
+        *error_slot = Some(std_error);
+    }
+}
+
+/// Standard errors of bump-and-revalue Greeks.
+///
+/// Each Greek is a finite difference of prices simulated on common random
+/// numbers, so its standard error is that of the per-path differences.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GreekStdErrors {
+    /// Standard error of delta.
+    pub delta: Option<f64>,
+    /// Standard error of vega.
+    pub vega: Option<f64>,
+    /// Standard error of theta.
+    pub theta: Option<f64>,
+    /// Standard error of rho.
+    pub rho: Option<f64>,
+    /// Standard error of gamma.
+    pub gamma: Option<f64>,
+    /// Standard error of vanna.
+    pub vanna: Option<f64>,
+    /// Standard error of volga.
+    pub volga: Option<f64>,
+}
+
+impl GreekStdErrors {
+    /// Returns the standard error of `greek`, if computed.
+    pub fn get(&self, greek: Greek) -> Option<f64> {
+        match greek {
+            Greek::Delta => self.delta,
+            Greek::Vega => self.vega,
+            Greek::Theta => self.theta,
+            Greek::Rho => self.rho,
+            Greek::Gamma => self.gamma,
+            Greek::Vanna => self.vanna,
+            Greek::Volga => self.volga,
+        }
+    }
 }
 
 /// Monte Carlo pricing engine.
@@ -176,6 +292,12 @@ pub struct MonteCarloPricer {
     workspace: PathWorkspace,
     /// Random number generator (pub(crate) for Enzyme AD access).
     pub(crate) rng: PricerRng,
+    /// Sobol sampler, present when quasi-random sampling is configured.
+    qmc: Option<QmcSampler>,
+    /// Philox stream of the first path under counter-based sampling.
+    first_stream: usize,
+    /// Per-thread block workspaces for parallel pricing.
+    block_workspaces: ThreadLocalWorkspacePool<f64, PathWorkspace>,
 }
 
 impl MonteCarloPricer {
@@ -194,11 +316,17 @@ impl MonteCarloPricer {
         let seed = config.seed().unwrap_or(0);
         let workspace = PathWorkspace::new(config.n_paths(), config.n_steps());
         let rng = PricerRng::from_seed(seed);
+        let qmc = qmc_sampler(&config, seed)?;
+        let block_workspaces =
+            ThreadLocalWorkspacePool::new(PARALLEL_BLOCK_PATHS, config.n_steps());
 
         Ok(Self {
             config,
             workspace,
             rng,
+            qmc,
+            first_stream: 0,
+            block_workspaces,
         })
     }
 
@@ -219,11 +347,17 @@ impl MonteCarloPricer {
 
         let workspace = PathWorkspace::new(config.n_paths(), config.n_steps());
         let rng = PricerRng::from_seed(seed);
+        let qmc = qmc_sampler(&config, seed)?;
+        let block_workspaces =
+            ThreadLocalWorkspacePool::new(PARALLEL_BLOCK_PATHS, config.n_steps());
 
         Ok(Self {
             config,
             workspace,
             rng,
+            qmc,
+            first_stream: 0,
+            block_workspaces,
         })
     }
 
@@ -237,14 +371,107 @@ impl MonteCarloPricer {
     ///
     /// Resets the workspace and RNG (using original seed).
     pub fn reset(&mut self) {
-        self.workspace.reset();
-        self.rng = PricerRng::from_seed(self.config.seed().unwrap_or(0));
+        self.reset_with_seed(self.config.seed().unwrap_or(0));
     }
 
     /// Resets the pricer with a new seed.
     pub fn reset_with_seed(&mut self, seed: u64) {
         self.workspace.reset();
         self.rng = PricerRng::from_seed(seed);
+        // The configuration was validated on construction
+        self.qmc = qmc_sampler(&self.config, seed).expect("validated configuration");
+    }
+
+    /// Fills the workspace randoms from the configured sampler.
+    fn fill_randoms(&mut self) {
+        // Model simulations size the sampler with `?` before reaching here
+        self.prepare_qmc(self.workspace.size_factors())
+            .expect("Sobol dimension validated");
+        match self.qmc.as_mut() {
+            Some(sampler) => sampler.fill(
+                self.workspace.randoms_mut(),
+                self.config.n_paths(),
+                self.config.n_steps(),
+            ),
+            None => {
+                let counter_based = self.config.sampling() == SamplingMethod::CounterBased;
+                let seed = self.rng.seed();
+                let first_stream = self.first_stream;
+                let n_paths = self.workspace.size_paths();
+                let randoms = self.workspace.randoms_mut();
+                let row_len = randoms.len() / n_paths;
+                let filled = if self.config.variance_reduction().antithetic {
+                    randoms.len() / 2
+                } else {
+                    randoms.len()
+                };
+                if counter_based {
+                    fill_path_streams(&mut randoms[..filled], row_len, seed, first_stream);
+                } else {
+                    self.rng.fill_normal(&mut randoms[..filled]);
+                }
+                if filled < randoms.len() {
+                    mirror_antithetic(randoms);
+                }
+            }
+        }
+    }
+
+    /// Applies the configured importance sampling drift to the GBM
+    /// normals, returning the per-path likelihood ratios.
+    fn shift_gbm_randoms(&mut self, gbm: GbmParams) -> Option<Vec<f64>> {
+        let shift = self.config.variance_reduction().drift_shift?;
+        Some(apply_drift_shift(
+            self.workspace.randoms_mut(),
+            self.config.n_paths(),
+            self.config.n_steps(),
+            shift,
+            gbm.maturity,
+        ))
+    }
+
+    /// Rebuilds the Sobol sampler when the number of Brownian factors per
+    /// step changes, restarting its sequences from the current seed.
+    fn prepare_qmc(&mut self, n_factors: usize) -> Result<(), ConfigError> {
+        let Some(sampler) = self.qmc.as_ref() else {
+            return Ok(());
+        };
+        if sampler.n_factors() != n_factors {
+            let sampler = QmcSampler::with_factors(
+                *sampler.config(),
+                self.config.n_steps(),
+                n_factors,
+                self.rng.seed(),
+            )
+            .map_err(|e| ConfigError::InvalidParameter {
+                name: "sampling",
+                value: e.to_string(),
+            })?;
+            self.qmc = Some(sampler);
+        }
+        Ok(())
+    }
+
+    /// Standard error of the mean of estimator samples: from the QMC
+    /// randomisations when available, the i.i.d. estimate otherwise.
+    fn sample_std_error(&self, samples: &[f64]) -> f64 {
+        self.randomisation_std_error(samples).unwrap_or_else(|| {
+            let n = samples.len() as f64;
+            let mean = samples.iter().sum::<f64>() / n;
+            let variance =
+                samples.iter().map(|&p| (p - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+            variance.sqrt() / n.sqrt()
+        })
+    }
+
+    /// Standard error of the mean of `values` from the QMC randomisations.
+    ///
+    /// Returns `None` under pseudo-random sampling or with a single
+    /// randomisation, in which case the i.i.d. estimate is used.
+    fn randomisation_std_error(&self, values: &[f64]) -> Option<f64> {
+        self.qmc
+            .as_ref()
+            .and_then(|sampler| sampler.config().standard_error(values))
     }
 
     /// Prices a European option using Monte Carlo simulation.
@@ -264,34 +491,515 @@ impl MonteCarloPricer {
         payoff: PayoffParams,
         discount_factor: f64,
     ) -> PricingResult {
+        let reduced = self.gbm_estimator(gbm, GbmPayoff::European(payoff));
         let n_paths = self.config.n_paths();
+        self.summarise(&reduced, n_paths, discount_factor)
+    }
+
+    /// Prices a European option on GBM paths simulated in parallel.
+    ///
+    /// Paths are split into fixed blocks of [`PARALLEL_BLOCK_PATHS`] that
+    /// are simulated on the rayon thread pool, each path drawing from its
+    /// own Philox stream. Payoffs are collected in path order and
+    /// aggregated exactly as in [`price_european`](Self::price_european),
+    /// so the result is bit-identical to the sequential counter-based
+    /// price and does not depend on the number of threads.
+    ///
+    /// Antithetic variates, the terminal spot control variate and the
+    /// importance sampling drift shift are supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` unless the configuration
+    /// uses [`SamplingMethod::CounterBased`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_pricing::mc::{
+    ///     GbmParams, MonteCarloConfig, MonteCarloPricer, PayoffParams, SamplingMethod,
+    /// };
+    ///
+    /// let config = MonteCarloConfig::builder()
+    ///     .n_paths(20_000)
+    ///     .n_steps(10)
+    ///     .sampling(SamplingMethod::CounterBased)
+    ///     .seed(42)
+    ///     .build()
+    ///     .unwrap();
+    /// let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+    /// let call = PayoffParams::call(100.0);
+    /// let df = (-0.05_f64).exp();
+    ///
+    /// let mut pricer = MonteCarloPricer::new(config).unwrap();
+    /// let parallel = pricer.price_european_parallel(gbm, call, df).unwrap();
+    /// let sequential = pricer.price_european(gbm, call, df);
+    /// assert_eq!(parallel.price, sequential.price);
+    /// ```
+    pub fn price_european_parallel(
+        &mut self,
+        gbm: GbmParams,
+        payoff: PayoffParams,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
         let n_steps = self.config.n_steps();
+        let vr = self.config.variance_reduction();
 
-        // Ensure workspace capacity
-        self.workspace.ensure_capacity(n_paths, n_steps);
+        let samples = self.simulate_blocks(1, |workspace, len, outputs| {
+            if let Some(shift) = vr.drift_shift {
+                let randoms = workspace.randoms_mut();
+                let ratios = apply_drift_shift(randoms, len, n_steps, shift, gbm.maturity);
+                outputs.weights.copy_from_slice(&ratios);
+            }
+            generate_gbm_paths(workspace, gbm, len, n_steps);
+            for (i, path) in workspace.paths().chunks_exact(n_steps + 1).enumerate() {
+                outputs.controls[i] = path[n_steps];
+                outputs.payoffs[i] = compute_payoff(path[n_steps], payoff);
+            }
+        })?;
+
+        let control = vr
+            .control_variate
+            .then(|| (samples.controls.as_slice(), expected_terminal_spot(gbm)));
+        Ok(self.aggregate(
+            &samples.payoffs,
+            vr.drift_shift.map(|_| samples.weights.as_slice()),
+            control,
+            discount_factor,
+        ))
+    }
 
-        // Generate random samples
-        self.rng.fill_normal(self.workspace.randoms_mut());
+    /// Prices a path-dependent option on GBM paths simulated in parallel.
+    ///
+    /// Paths are simulated in the same blocks and on the same per-path
+    /// Philox streams as
+    /// [`price_european_parallel`](Self::price_european_parallel), so the
+    /// result is bit-identical to the sequential counter-based
+    /// [`price_path_dependent`](Self::price_path_dependent) and does not
+    /// depend on the number of threads. The same variance reduction is
+    /// supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` unless the configuration
+    /// uses [`SamplingMethod::CounterBased`].
+    pub fn price_path_dependent_parallel(
+        &mut self,
+        gbm: GbmParams,
+        payoff: PathPayoffType<f64>,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        let n_steps = self.config.n_steps();
+        let vr = self.config.variance_reduction();
+        let control = vr
+            .control_variate
+            .then(|| path_control(&payoff, gbm, n_steps));
+
+        let samples = self.simulate_blocks(1, |workspace, len, outputs| {
+            if let Some(shift) = vr.drift_shift {
+                let randoms = workspace.randoms_mut();
+                let ratios = apply_drift_shift(randoms, len, n_steps, shift, gbm.maturity);
+                outputs.weights.copy_from_slice(&ratios);
+            }
+            generate_gbm_paths(workspace, gbm, len, n_steps);
+            observe_paths(
+                workspace.paths(),
+                n_steps,
+                &payoff,
+                control.map(|(kind, _)| kind),
+                outputs.payoffs,
+                outputs.controls,
+            );
+        })?;
+
+        Ok(self.aggregate(
+            &samples.payoffs,
+            vr.drift_shift.map(|_| samples.weights.as_slice()),
+            control.map(|(_, expected)| (samples.controls.as_slice(), expected)),
+            discount_factor,
+        ))
+    }
 
-        // Generate paths
-        generate_gbm_paths(&mut self.workspace, gbm, n_paths, n_steps);
+    /// Prices a European option under any stochastic model on paths
+    /// simulated in parallel.
+    ///
+    /// Each path draws its `n_steps × brownian_dim` normals from its own
+    /// Philox stream as in
+    /// [`price_european_parallel`](Self::price_european_parallel), so the
+    /// result is bit-identical to the sequential counter-based
+    /// [`price_model_european`](Self::price_model_european) and does not
+    /// depend on the number of threads.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`price_model_european`](Self::price_model_european), and
+    /// `ConfigError::InvalidParameter` unless the configuration uses
+    /// [`SamplingMethod::CounterBased`].
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_model_european_parallel(
+        &mut self,
+        model: &StochasticModelEnum<f64>,
+        params: &ModelParams<f64>,
+        maturity: f64,
+        payoff: PayoffParams,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        validate_model_inputs(model, params, maturity)?;
+        let n_steps = self.config.n_steps();
+
+        let samples = self.simulate_blocks(model.brownian_dim(), |workspace, len, outputs| {
+            generate_model_paths(workspace, model, params, maturity, len, n_steps);
+            for (i, path) in workspace.paths().chunks_exact(n_steps + 1).enumerate() {
+                outputs.payoffs[i] = compute_payoff(path[n_steps], payoff);
+            }
+        })?;
+        Ok(self.aggregate(&samples.payoffs, None, None, discount_factor))
+    }
+
+    /// Prices a path-dependent option under any stochastic model on paths
+    /// simulated in parallel.
+    ///
+    /// See [`price_model_european_parallel`](Self::price_model_european_parallel);
+    /// the result is bit-identical to the sequential counter-based
+    /// [`price_model_path_dependent`](Self::price_model_path_dependent).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`price_model_european_parallel`](Self::price_model_european_parallel).
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_model_path_dependent_parallel(
+        &mut self,
+        model: &StochasticModelEnum<f64>,
+        params: &ModelParams<f64>,
+        maturity: f64,
+        payoff: PathPayoffType<f64>,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        validate_model_inputs(model, params, maturity)?;
+        let n_steps = self.config.n_steps();
+
+        let samples = self.simulate_blocks(model.brownian_dim(), |workspace, len, outputs| {
+            generate_model_paths(workspace, model, params, maturity, len, n_steps);
+            observe_paths(
+                workspace.paths(),
+                n_steps,
+                &payoff,
+                None,
+                outputs.payoffs,
+                outputs.controls,
+            );
+        })?;
+        Ok(self.aggregate(&samples.payoffs, None, None, discount_factor))
+    }
+
+    /// Simulates all paths in fixed blocks of [`PARALLEL_BLOCK_PATHS`] on
+    /// the rayon thread pool and collects the per-path outputs of
+    /// `evaluate` in path order.
+    ///
+    /// Each block runs on the calling worker's workspace from the pricer's
+    /// thread-local pool, with `n_factors` normals per step for each path
+    /// drawn from the Philox stream `(seed, path_index)`; under antithetic
+    /// sampling the second half of the paths reuses the streams of the
+    /// first half with the signs flipped. `evaluate` receives the
+    /// workspace holding the block's normals, the number of paths in the
+    /// block and the block's output slices.
+    fn simulate_blocks<F>(&self, n_factors: usize, evaluate: F) -> Result<BlockSamples, ConfigError>
+    where
+        F: Fn(&mut PathWorkspace, usize, BlockOutputs<'_>) + Sync,
+    {
+        if self.config.sampling() != SamplingMethod::CounterBased {
+            return Err(ConfigError::InvalidParameter {
+                name: "sampling",
+                value: format!(
+                    "{:?} (parallel pricing requires CounterBased)",
+                    self.config.sampling()
+                ),
+            });
+        }
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
+        let seed = self.rng.seed();
+        let antithetic_from = if self.config.variance_reduction().antithetic {
+            n_paths / 2
+        } else {
+            n_paths
+        };
+
+        let mut samples = BlockSamples {
+            payoffs: vec![0.0; n_paths],
+            controls: vec![0.0; n_paths],
+            weights: vec![1.0; n_paths],
+        };
+        samples
+            .payoffs
+            .par_chunks_mut(PARALLEL_BLOCK_PATHS)
+            .zip(samples.controls.par_chunks_mut(PARALLEL_BLOCK_PATHS))
+            .zip(samples.weights.par_chunks_mut(PARALLEL_BLOCK_PATHS))
+            .enumerate()
+            .for_each(|(block, ((payoffs, controls), weights))| {
+                let first_path = block * PARALLEL_BLOCK_PATHS;
+                let len = payoffs.len();
+                self.block_workspaces.with_workspace(|workspace| {
+                    workspace.ensure_capacity_with_factors(len, n_steps, n_factors);
+                    let randoms = workspace.randoms_mut();
+                    for (i, row) in randoms.chunks_exact_mut(n_steps * n_factors).enumerate() {
+                        let path = first_path + i;
+                        if path < antithetic_from {
+                            PhiloxRng::new(seed, path as u64).fill_normal(row);
+                        } else {
+                            PhiloxRng::new(seed, (path - antithetic_from) as u64).fill_normal(row);
+                            row.iter_mut().for_each(|z| *z = -*z);
+                        }
+                    }
+                    let outputs = BlockOutputs {
+                        payoffs,
+                        controls,
+                        weights,
+                    };
+                    evaluate(workspace, len, outputs);
+                });
+            });
+        Ok(samples)
+    }
+
+    /// Prices a European option on GBM paths to a standard error target.
+    ///
+    /// The configured `n_paths` is the batch size: batches are simulated
+    /// with fresh random numbers and pooled until `adaptive.target` is met
+    /// or its budget runs out. The result's `convergence` trace holds the
+    /// pooled estimate after each batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `adaptive` is invalid for
+    /// the batch size, or the configuration uses quasi-random sampling
+    /// (whose batches are not independent).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_pricing::mc::{
+    ///     AdaptiveConfig, GbmParams, MonteCarloConfig, MonteCarloPricer, PayoffParams,
+    /// };
+    ///
+    /// let config = MonteCarloConfig::builder()
+    ///     .n_paths(2_000)
+    ///     .n_steps(10)
+    ///     .seed(7)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut pricer = MonteCarloPricer::new(config).unwrap();
+    ///
+    /// // A budget too small for the target stops early without converging
+    /// let adaptive = AdaptiveConfig::relative(1e-4).with_max_paths(6_000);
+    /// let result = pricer
+    ///     .price_european_adaptive(GbmParams::default(), PayoffParams::put(100.0), 1.0, &adaptive)
+    ///     .unwrap();
+    /// let trace = result.convergence.unwrap();
+    /// assert!(!trace.converged);
+    /// assert_eq!(trace.points.len(), 3);
+    /// ```
+    pub fn price_european_adaptive(
+        &mut self,
+        gbm: GbmParams,
+        payoff: PayoffParams,
+        discount_factor: f64,
+        adaptive: &AdaptiveConfig,
+    ) -> Result<PricingResult, ConfigError> {
+        self.price_adaptive(gbm, GbmPayoff::European(payoff), discount_factor, adaptive)
+    }
+
+    /// Prices a path-dependent option on GBM paths to a standard error
+    /// target (see [`price_european_adaptive`](Self::price_european_adaptive)).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` as for
+    /// [`price_european_adaptive`](Self::price_european_adaptive).
+    pub fn price_path_dependent_adaptive(
+        &mut self,
+        gbm: GbmParams,
+        payoff: PathPayoffType<f64>,
+        discount_factor: f64,
+        adaptive: &AdaptiveConfig,
+    ) -> Result<PricingResult, ConfigError> {
+        self.price_adaptive(
+            gbm,
+            GbmPayoff::PathDependent(payoff),
+            discount_factor,
+            adaptive,
+        )
+    }
+
+    /// Runs batches of GBM paths until the adaptive stopping rule fires,
+    /// pooling the estimator samples of all batches.
+    fn price_adaptive(
+        &mut self,
+        gbm: GbmParams,
+        payoff: GbmPayoff,
+        discount_factor: f64,
+        adaptive: &AdaptiveConfig,
+    ) -> Result<PricingResult, ConfigError> {
+        let batch_paths = self.config.n_paths();
+        adaptive.validate(batch_paths)?;
+        if let SamplingMethod::QuasiRandom(_) = self.config.sampling() {
+            return Err(ConfigError::InvalidParameter {
+                name: "sampling",
+                value: "adaptive pricing requires independent pseudo-random batches".to_string(),
+            });
+        }
+
+        let start = Instant::now();
+        let mut pooled = ReducedSamples {
+            samples: Vec::new(),
+            plain_variance: 0.0,
+        };
+        let mut trace = ConvergenceTrace::default();
+        let mut n_batches = 0;
+        let result = loop {
+            // Counter-based batches continue with the next block of streams
+            self.first_stream = n_batches * batch_paths;
+            let batch = self.gbm_estimator(gbm, payoff);
+            n_batches += 1;
+            pooled.samples.extend(batch.samples);
+            pooled.plain_variance +=
+                (batch.plain_variance - pooled.plain_variance) / n_batches as f64;
+
+            let n_paths = n_batches * batch_paths;
+            let result = self.summarise(&pooled, n_paths, discount_factor);
+            trace.points.push(ConvergencePoint {
+                n_paths,
+                price: result.price,
+                std_error: result.std_error,
+            });
+            trace.converged = adaptive.target.is_met(result.price, result.std_error);
+
+            let out_of_paths = n_paths + batch_paths > adaptive.max_paths;
+            let out_of_time = adaptive
+                .max_time
+                .is_some_and(|budget| start.elapsed() >= budget);
+            if trace.converged || out_of_paths || out_of_time {
+                break result;
+            }
+        };
+        self.first_stream = 0;
+
+        Ok(PricingResult {
+            convergence: Some(trace),
+            ..result
+        })
+    }
+
+    /// Computes payoffs on the generated paths and aggregates the
+    /// discounted mean and standard error.
+    ///
+    /// `gbm` enables the terminal spot control variate when the paths are
+    /// GBM paths; `weights` are importance sampling likelihood ratios.
+    fn european_result(
+        &mut self,
+        payoff: PayoffParams,
+        discount_factor: f64,
+        gbm: Option<GbmParams>,
+        weights: Option<&[f64]>,
+    ) -> PricingResult {
+        let reduced = self.european_estimator(payoff, gbm, weights);
+        self.summarise(&reduced, self.config.n_paths(), discount_factor)
+    }
+
+    /// Computes payoffs on the generated paths and returns the
+    /// undiscounted estimator samples (see [`european_result`](Self::european_result)).
+    fn european_estimator(
+        &mut self,
+        payoff: PayoffParams,
+        gbm: Option<GbmParams>,
+        weights: Option<&[f64]>,
+    ) -> ReducedSamples {
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
 
         // Compute payoffs
         compute_payoffs(&mut self.workspace, payoff, n_paths, n_steps);
 
-        // Aggregate: discounted mean and standard error
-        let payoffs = self.workspace.payoffs();
-        let sum: f64 = payoffs.iter().sum();
-        let mean = sum / n_paths as f64;
+        let control = gbm
+            .filter(|_| self.config.variance_reduction().control_variate)
+            .map(|gbm| {
+                let terminals: Vec<f64> = self
+                    .workspace
+                    .paths()
+                    .chunks_exact(n_steps + 1)
+                    .map(|path| path[n_steps])
+                    .collect();
+                (terminals, expected_terminal_spot(gbm))
+            });
+
+        self.estimator(
+            self.workspace.payoffs(),
+            weights,
+            control.as_ref().map(|(c, e)| (c.as_slice(), *e)),
+        )
+    }
+
+    /// Aggregates undiscounted payoffs into the discounted mean and
+    /// standard error, applying the configured variance reduction.
+    ///
+    /// # Arguments
+    ///
+    /// * `payoffs` - Undiscounted payoff per path
+    /// * `weights` - Importance sampling likelihood ratio per path
+    /// * `control` - Control value per path and its known mean
+    /// * `discount_factor` - Present value discount factor
+    fn aggregate(
+        &self,
+        payoffs: &[f64],
+        weights: Option<&[f64]>,
+        control: Option<(&[f64], f64)>,
+        discount_factor: f64,
+    ) -> PricingResult {
+        let reduced = self.estimator(payoffs, weights, control);
+        self.summarise(&reduced, payoffs.len(), discount_factor)
+    }
+
+    /// Builds the variance-reduced estimator samples from undiscounted
+    /// payoffs (see [`aggregate`](Self::aggregate)).
+    fn estimator(
+        &self,
+        payoffs: &[f64],
+        weights: Option<&[f64]>,
+        control: Option<(&[f64], f64)>,
+    ) -> ReducedSamples {
+        let antithetic = self.config.variance_reduction().antithetic;
+        ReducedSamples::new(payoffs, weights, control, antithetic)
+    }
 
-        let variance: f64 =
-            payoffs.iter().map(|&p| (p - mean).powi(2)).sum::<f64>() / (n_paths - 1) as f64;
-        let std_dev = variance.sqrt();
-        let std_error = std_dev / (n_paths as f64).sqrt();
+    /// Discounts the mean and standard error of estimator samples drawn
+    /// from `n_payoffs` paths.
+    fn summarise(
+        &self,
+        reduced: &ReducedSamples,
+        n_payoffs: usize,
+        discount_factor: f64,
+    ) -> PricingResult {
+        let vr = self.config.variance_reduction();
+        let samples = &reduced.samples;
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let std_error = self.sample_std_error(samples);
+
+        let variance_reduction_factor = vr.is_active().then(|| {
+            let plain = reduced.plain_variance / n_payoffs as f64;
+            let achieved = std_error * std_error;
+            if achieved > 0.0 {
+                plain / achieved
+            } else if plain > 0.0 {
+                f64::INFINITY
+            } else {
+                1.0
+            }
+        });
 
         PricingResult {
             price: mean * discount_factor,
             std_error: std_error * discount_factor,
+            variance_reduction_factor,
             ..Default::default()
         }
     }
@@ -324,274 +1032,94 @@ impl MonteCarloPricer {
         let mut result = self.price_european(gbm, payoff, discount_factor);
 
         // Compute requested Greeks
-        for greek in greeks {
-            match greek {
-                // First-order Greeks
-                Greek::Delta => {
-                    result.delta = Some(self.compute_delta(gbm, payoff, discount_factor));
-                }
-                Greek::Vega => {
-                    result.vega = Some(self.compute_vega(gbm, payoff, discount_factor));
-                }
-                Greek::Theta => {
-                    result.theta = Some(self.compute_theta(gbm, payoff, discount_factor));
-                }
-                Greek::Rho => {
-                    result.rho = Some(self.compute_rho(gbm, payoff, discount_factor));
-                }
-                // Second-order Greeks
-                Greek::Gamma => {
-                    result.gamma = Some(self.compute_gamma(gbm, payoff, discount_factor));
-                }
-                Greek::Vanna => {
-                    result.vanna = Some(self.compute_vanna(gbm, payoff, discount_factor));
-                }
-                Greek::Volga => {
-                    result.volga = Some(self.compute_volga(gbm, payoff, discount_factor));
-                }
-            }
+        for &greek in greeks {
+            let estimate =
+                self.bumped_greek(greek, gbm, GbmPayoff::European(payoff), discount_factor);
+            result.set_greek(greek, estimate);
         }
 
         result
     }
 
-    /// Computes Delta using central differences (Phase 3.2 placeholder).
-    ///
-    /// In Phase 4, this will use Enzyme forward-mode AD.
-    fn compute_delta(&mut self, gbm: GbmParams, payoff: PayoffParams, discount_factor: f64) -> f64 {
-        // Bump size: 1% of spot or minimum 0.01
-        let bump = (0.01 * gbm.spot).max(0.01);
+    /// Simulates GBM paths and returns the undiscounted estimator samples
+    /// of `payoff`.
+    fn gbm_estimator(&mut self, gbm: GbmParams, payoff: GbmPayoff) -> ReducedSamples {
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
 
-        // Save RNG state for reproducibility
-        let seed = self.rng.seed();
+        // Ensure workspace capacity
+        self.workspace.ensure_capacity(n_paths, n_steps);
 
-        // Price at S + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            spot: gbm.spot + bump,
-            ..gbm
-        };
-        let price_up = self.price_european(gbm_up, payoff, discount_factor).price;
+        // Generate random samples
+        self.fill_randoms();
+        let weights = self.shift_gbm_randoms(gbm);
 
-        // Price at S - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            spot: gbm.spot - bump,
-            ..gbm
-        };
-        let price_down = self.price_european(gbm_down, payoff, discount_factor).price;
+        // Generate paths
+        generate_gbm_paths(&mut self.workspace, gbm, n_paths, n_steps);
 
-        // Central difference
-        (price_up - price_down) / (2.0 * bump)
+        match payoff {
+            GbmPayoff::European(payoff) => {
+                self.european_estimator(payoff, Some(gbm), weights.as_deref())
+            }
+            GbmPayoff::PathDependent(payoff) => {
+                self.path_dependent_estimator(&payoff, Some(gbm), weights.as_deref())
+            }
+        }
     }
 
-    /// Computes Gamma using central differences.
-    fn compute_gamma(&mut self, gbm: GbmParams, payoff: PayoffParams, discount_factor: f64) -> f64 {
-        let bump = (0.01 * gbm.spot).max(0.01);
-        let seed = self.rng.seed();
-
-        // Price at S
-        self.reset_with_seed(seed);
-        let price_mid = self.price_european(gbm, payoff, discount_factor).price;
-
-        // Price at S + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            spot: gbm.spot + bump,
-            ..gbm
-        };
-        let price_up = self.price_european(gbm_up, payoff, discount_factor).price;
-
-        // Price at S - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            spot: gbm.spot - bump,
-            ..gbm
-        };
-        let price_down = self.price_european(gbm_down, payoff, discount_factor).price;
+    /// Draws the normals of one GBM simulation and returns the sum of each
+    /// path's step normals, which determines its terminal spot.
+    ///
+    /// The draws are those [`price_european`](Self::price_european) makes
+    /// from the same RNG state, before any importance sampling shift.
+    #[cfg(feature = "l1l2-integration")]
+    pub(crate) fn gbm_normal_sums(&mut self) -> Vec<f64> {
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
 
-        // Second derivative via central differences
-        (price_up - 2.0 * price_mid + price_down) / (bump * bump)
+        self.workspace.ensure_capacity(n_paths, n_steps);
+        self.fill_randoms();
+
+        self.workspace
+            .randoms()
+            .chunks_exact(n_steps)
+            .take(n_paths)
+            .map(|row| row.iter().sum())
+            .collect()
     }
 
-    /// Computes Vega using central differences.
+    /// Computes `greek` by bump-and-revalue (Phase 3.2 placeholder for
+    /// Enzyme AD), returning the estimate and its standard error.
     ///
-    /// In Phase 4, this will use Enzyme reverse-mode AD.
-    fn compute_vega(&mut self, gbm: GbmParams, payoff: PayoffParams, discount_factor: f64) -> f64 {
-        // Bump size: 1% of vol (absolute)
-        let bump = 0.01;
+    /// Every scenario restarts the random numbers from the current seed,
+    /// so the finite difference is formed path by path on common random
+    /// numbers and its standard error is that of the per-path differences.
+    fn bumped_greek(
+        &mut self,
+        greek: Greek,
+        gbm: GbmParams,
+        payoff: GbmPayoff,
+        discount_factor: f64,
+    ) -> (f64, f64) {
         let seed = self.rng.seed();
+        let mut combined: Vec<f64> = Vec::new();
+
+        for (weight, scenario, scenario_df) in bump_scenarios(greek, gbm, discount_factor) {
+            self.reset_with_seed(seed);
+            let samples = self.gbm_estimator(scenario, payoff).samples;
+            combined.resize(samples.len(), 0.0);
+            for (c, s) in combined.iter_mut().zip(samples) {
+                *c += weight * scenario_df * s;
+            }
+        }
 
-        // Price at vol + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            volatility: gbm.volatility + bump,
-            ..gbm
-        };
-        let price_up = self.price_european(gbm_up, payoff, discount_factor).price;
-
-        // Price at vol - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            volatility: (gbm.volatility - bump).max(0.001),
-            ..gbm
-        };
-        let price_down = self.price_european(gbm_down, payoff, discount_factor).price;
-
-        // Central difference (scaled to 1% vol move)
-        (price_up - price_down) / (2.0 * bump)
+        let mean = combined.iter().sum::<f64>() / combined.len() as f64;
+        (mean, self.sample_std_error(&combined))
     }
 
-    /// Computes Theta using central differences.
-    fn compute_theta(&mut self, gbm: GbmParams, payoff: PayoffParams, discount_factor: f64) -> f64 {
-        // Bump size: 1 day = 1/252 years
-        let bump = 1.0 / 252.0;
-        let seed = self.rng.seed();
-
-        // Price at T - bump (shorter maturity)
-        self.reset_with_seed(seed);
-        let gbm_short = GbmParams {
-            maturity: (gbm.maturity - bump).max(0.001),
-            ..gbm
-        };
-        let price_short = self
-            .price_european(gbm_short, payoff, discount_factor)
-            .price;
-
-        // Price at T (original)
-        self.reset_with_seed(seed);
-        let price_orig = self.price_european(gbm, payoff, discount_factor).price;
-
-        // Theta is typically negative (time decay)
-        // dP/dT, scaled to daily
-        -(price_orig - price_short) / bump
-    }
-
-    /// Computes Rho using central differences.
-    fn compute_rho(&mut self, gbm: GbmParams, payoff: PayoffParams, _discount_factor: f64) -> f64 {
-        // Bump size: 1% rate change (absolute)
-        let bump = 0.01;
-        let seed = self.rng.seed();
-
-        // Price at r + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            rate: gbm.rate + bump,
-            ..gbm
-        };
-        let df_up = (-(gbm.rate + bump) * gbm.maturity).exp();
-        let price_up = self.price_european(gbm_up, payoff, df_up).price;
-
-        // Price at r - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            rate: gbm.rate - bump,
-            ..gbm
-        };
-        let df_down = (-(gbm.rate - bump) * gbm.maturity).exp();
-        let price_down = self.price_european(gbm_down, payoff, df_down).price;
-
-        // Central difference (scaled to 1% rate move)
-        (price_up - price_down) / (2.0 * bump)
-    }
-
-    /// Computes Vanna (∂²V/∂S∂σ) using cross-differences.
-    ///
-    /// Vanna is the cross partial derivative of option value with respect to
-    /// spot price and volatility. It measures how delta changes with volatility.
-    ///
-    /// Uses the formula: (V(S+h,σ+k) - V(S+h,σ-k) - V(S-h,σ+k) + V(S-h,σ-k)) / (4hk)
-    fn compute_vanna(&mut self, gbm: GbmParams, payoff: PayoffParams, discount_factor: f64) -> f64 {
-        let spot_bump = (0.01 * gbm.spot).max(0.01);
-        let vol_bump = 0.01;
-        let seed = self.rng.seed();
-
-        // V(S+h, σ+k)
-        self.reset_with_seed(seed);
-        let gbm_up_up = GbmParams {
-            spot: gbm.spot + spot_bump,
-            volatility: gbm.volatility + vol_bump,
-            ..gbm
-        };
-        let price_up_up = self
-            .price_european(gbm_up_up, payoff, discount_factor)
-            .price;
-
-        // V(S+h, σ-k)
-        self.reset_with_seed(seed);
-        let gbm_up_down = GbmParams {
-            spot: gbm.spot + spot_bump,
-            volatility: (gbm.volatility - vol_bump).max(0.001),
-            ..gbm
-        };
-        let price_up_down = self
-            .price_european(gbm_up_down, payoff, discount_factor)
-            .price;
-
-        // V(S-h, σ+k)
-        self.reset_with_seed(seed);
-        let gbm_down_up = GbmParams {
-            spot: gbm.spot - spot_bump,
-            volatility: gbm.volatility + vol_bump,
-            ..gbm
-        };
-        let price_down_up = self
-            .price_european(gbm_down_up, payoff, discount_factor)
-            .price;
-
-        // V(S-h, σ-k)
-        self.reset_with_seed(seed);
-        let gbm_down_down = GbmParams {
-            spot: gbm.spot - spot_bump,
-            volatility: (gbm.volatility - vol_bump).max(0.001),
-            ..gbm
-        };
-        let price_down_down = self
-            .price_european(gbm_down_down, payoff, discount_factor)
-            .price;
-
-        // Cross-difference formula
-        (price_up_up - price_up_down - price_down_up + price_down_down)
-            / (4.0 * spot_bump * vol_bump)
-    }
-
-    /// Computes Volga (∂²V/∂σ²) using three-point formula.
-    ///
-    /// Volga (also known as vomma) is the second derivative of option value
-    /// with respect to volatility. It measures the convexity of vega.
-    ///
-    /// Uses the formula: (V(σ+h) - 2V(σ) + V(σ-h)) / h²
-    fn compute_volga(&mut self, gbm: GbmParams, payoff: PayoffParams, discount_factor: f64) -> f64 {
-        let bump = 0.01;
-        let seed = self.rng.seed();
-
-        // V(σ)
-        self.reset_with_seed(seed);
-        let price_mid = self.price_european(gbm, payoff, discount_factor).price;
-
-        // V(σ+h)
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            volatility: gbm.volatility + bump,
-            ..gbm
-        };
-        let price_up = self.price_european(gbm_up, payoff, discount_factor).price;
-
-        // V(σ-h)
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            volatility: (gbm.volatility - bump).max(0.001),
-            ..gbm
-        };
-        let price_down = self.price_european(gbm_down, payoff, discount_factor).price;
-
-        // Three-point formula for second derivative
-        (price_up - 2.0 * price_mid + price_down) / (bump * bump)
-    }
-
-    // ========================================================================
-    // Phase 4: L1/L2 Integration - YieldCurve methods
-    // ========================================================================
+    // ========================================================================
+    // Phase 4: L1/L2 Integration - YieldCurve methods
+    // ========================================================================
 
     /// Prices a European option using Monte Carlo simulation with a YieldCurve.
     ///
@@ -733,7 +1261,7 @@ impl MonteCarloPricer {
         self.workspace.ensure_capacity(n_paths, n_steps);
 
         // Generate random samples
-        self.rng.fill_normal(self.workspace.randoms_mut());
+        self.fill_randoms();
 
         // Generate paths with tangent (d/dS₀)
         let tangent_paths = generate_gbm_paths_tangent_spot(
@@ -830,286 +1358,943 @@ impl MonteCarloPricer {
         payoff: PathPayoffType<f64>,
         discount_factor: f64,
     ) -> PricingResult {
+        let reduced = self.gbm_estimator(gbm, GbmPayoff::PathDependent(payoff));
         let n_paths = self.config.n_paths();
-        let n_steps = self.config.n_steps();
-        let n_steps_plus_1 = n_steps + 1;
-
-        // Ensure workspace capacity
-        self.workspace.ensure_capacity(n_paths, n_steps);
-
-        // Generate random samples
-        self.rng.fill_normal(self.workspace.randoms_mut());
-
-        // Generate GBM paths
-        generate_gbm_paths(&mut self.workspace, gbm, n_paths, n_steps);
-
-        let paths = self.workspace.paths();
-
-        // Compute path-dependent payoffs
-        let mut payoff_sum = 0.0;
-        let mut payoff_sum_sq = 0.0;
-
-        for path_idx in 0..n_paths {
-            let mut observer: PathObserver<f64> = PathObserver::new();
+        self.summarise(&reduced, n_paths, discount_factor)
+    }
 
-            // Observe each price in the path
-            for step_idx in 0..n_steps_plus_1 {
-                let price = paths[path_idx * n_steps_plus_1 + step_idx];
-                observer.observe(price);
-            }
+    /// Observes the generated paths and aggregates the discounted mean and
+    /// standard error of a path-dependent payoff.
+    ///
+    /// `gbm` enables the control variate when the paths are GBM paths: the
+    /// geometric Asian on the same fixings for arithmetic Asians, the
+    /// terminal spot otherwise. `weights` are importance sampling
+    /// likelihood ratios.
+    fn path_dependent_result(
+        &self,
+        payoff: &PathPayoffType<f64>,
+        discount_factor: f64,
+        gbm: Option<GbmParams>,
+        weights: Option<&[f64]>,
+    ) -> PricingResult {
+        let reduced = self.path_dependent_estimator(payoff, gbm, weights);
+        self.summarise(&reduced, self.config.n_paths(), discount_factor)
+    }
 
-            // Set terminal price
-            let terminal = paths[path_idx * n_steps_plus_1 + n_steps];
-            observer.set_terminal(terminal);
+    /// Observes the generated paths and returns the undiscounted estimator
+    /// samples (see [`path_dependent_result`](Self::path_dependent_result)).
+    fn path_dependent_estimator(
+        &self,
+        payoff: &PathPayoffType<f64>,
+        gbm: Option<GbmParams>,
+        weights: Option<&[f64]>,
+    ) -> ReducedSamples {
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
+        let paths = &self.workspace.paths()[..n_paths * (n_steps + 1)];
 
-            // Compute payoff
-            let payoff_value = payoff.compute(&[], &observer);
-            payoff_sum += payoff_value;
-            payoff_sum_sq += payoff_value * payoff_value;
-        }
+        // Control variate: hard geometric Asian payoff or terminal spot
+        let control = gbm
+            .filter(|_| self.config.variance_reduction().control_variate)
+            .map(|gbm| path_control(payoff, gbm, n_steps));
 
-        // Aggregate: discounted mean and standard error
-        let mean = payoff_sum / n_paths as f64;
-        let variance = (payoff_sum_sq / n_paths as f64) - mean * mean;
-        let std_dev = variance.max(0.0).sqrt();
-        let std_error = std_dev / (n_paths as f64).sqrt();
+        let mut payoff_values = vec![0.0; n_paths];
+        let mut control_values = vec![0.0; n_paths];
+        observe_paths(
+            paths,
+            n_steps,
+            payoff,
+            control.map(|(kind, _)| kind),
+            &mut payoff_values,
+            &mut control_values,
+        );
 
-        PricingResult {
-            price: mean * discount_factor,
-            std_error: std_error * discount_factor,
-            ..Default::default()
-        }
+        self.estimator(
+            &payoff_values,
+            weights,
+            control.map(|(_, expected)| (control_values.as_slice(), expected)),
+        )
     }
 
-    /// Prices a path-dependent option with selected Greeks.
+    /// Prices a single or double barrier option on GBM paths.
     ///
-    /// # Arguments
+    /// The barrier is monitored as set in `option.monitoring`: continuously
+    /// with a Brownian-bridge or continuity correction between the
+    /// simulated steps, or on a discrete schedule whose dates between steps
+    /// are sampled from the Brownian bridge. Rebates paid at the hit are
+    /// discounted from the hit to maturity at `gbm.rate`, so
+    /// `discount_factor` should be `exp(−rate·maturity)`.
     ///
-    /// * `gbm` - GBM parameters
-    /// * `payoff` - Path-dependent payoff type
-    /// * `discount_factor` - Discount factor
-    /// * `greeks` - Which Greeks to compute
+    /// # Errors
     ///
-    /// # Returns
+    /// Returns `ConfigError::InvalidParameter` if the option is invalid
+    /// for the spot and maturity (see [`BarrierOption::validate`]).
     ///
-    /// Price and requested Greeks.
+    /// # Example
     ///
-    /// # Implementation Note
+    /// ```rust
+    /// use pricer_pricing::analytical::down_out_call;
+    /// use pricer_pricing::mc::{
+    ///     BarrierOption, GbmParams, MonteCarloConfig, MonteCarloPricer, PayoffParams,
+    /// };
     ///
-    /// Phase 4 uses bump-and-revalue for Greeks. Future phases will integrate
-    /// Enzyme AD with checkpointing for path-dependent derivatives.
-    pub fn price_path_dependent_with_greeks(
+    /// let config = MonteCarloConfig::builder()
+    ///     .n_paths(20_000)
+    ///     .n_steps(50)
+    ///     .seed(42)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut pricer = MonteCarloPricer::new(config).unwrap();
+    ///
+    /// let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+    /// let option = BarrierOption::knock_out(PayoffParams::call(100.0), Some(90.0), None);
+    /// let result = pricer
+    ///     .price_barrier(gbm, &option, (-0.05_f64).exp())
+    ///     .unwrap();
+    ///
+    /// let exact = down_out_call(100.0, 100.0, 90.0, 0.05, 0.0, 0.2, 1.0);
+    /// assert!((result.price - exact).abs() < 4.0 * result.std_error);
+    /// ```
+    pub fn price_barrier(
         &mut self,
         gbm: GbmParams,
-        payoff: PathPayoffType<f64>,
+        option: &BarrierOption,
         discount_factor: f64,
-        greeks: &[Greek],
-    ) -> PricingResult {
-        // Base price
-        let mut result = self.price_path_dependent(gbm, payoff, discount_factor);
-
-        // Compute requested Greeks via bump-and-revalue
-        for greek in greeks {
-            match greek {
-                Greek::Delta => {
-                    result.delta =
-                        Some(self.compute_delta_path_dependent(gbm, payoff, discount_factor));
-                }
-                Greek::Gamma => {
-                    result.gamma =
-                        Some(self.compute_gamma_path_dependent(gbm, payoff, discount_factor));
-                }
-                Greek::Vega => {
-                    result.vega =
-                        Some(self.compute_vega_path_dependent(gbm, payoff, discount_factor));
-                }
-                Greek::Theta => {
-                    result.theta =
-                        Some(self.compute_theta_path_dependent(gbm, payoff, discount_factor));
-                }
-                Greek::Rho => {
-                    result.rho =
-                        Some(self.compute_rho_path_dependent(gbm, payoff, discount_factor));
-                }
-                Greek::Vanna | Greek::Volga => {
-                    // Second-order cross Greeks for path-dependent not yet implemented
-                    // Will be added with Enzyme AD + checkpointing integration
-                }
-            }
-        }
-
-        result
+    ) -> Result<PricingResult, ConfigError> {
+        option.validate(gbm.spot, gbm.maturity)?;
+        let evaluator = BarrierEvaluator::new(option, gbm, self.config.n_steps());
+        Ok(self.gbm_barrier_result(&evaluator, gbm, discount_factor))
     }
 
-    /// Computes Delta for path-dependent options using central differences.
-    fn compute_delta_path_dependent(
+    /// Prices a one-touch or no-touch option under GBM.
+    ///
+    /// Monitoring is as for [`price_barrier`](Self::price_barrier); amounts
+    /// paid at the hit are discounted from the hit to maturity at
+    /// `gbm.rate`, so `discount_factor` should be `exp(−rate·maturity)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the option is invalid
+    /// for the spot and maturity (see [`TouchOption::validate`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_pricing::mc::{
+    ///     GbmParams, MonteCarloConfig, MonteCarloPricer, RebateTiming, TouchOption,
+    /// };
+    ///
+    /// let config = MonteCarloConfig::builder()
+    ///     .n_paths(20_000)
+    ///     .n_steps(50)
+    ///     .seed(42)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut pricer = MonteCarloPricer::new(config).unwrap();
+    ///
+    /// let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+    /// let option = TouchOption::one_touch(1.0, None, Some(120.0), RebateTiming::AtExpiry);
+    /// let result = pricer.price_touch(gbm, &option, (-0.05_f64).exp()).unwrap();
+    /// assert!(result.price > 0.0 && result.price < 1.0);
+    /// ```
+    pub fn price_touch(
         &mut self,
         gbm: GbmParams,
-        payoff: PathPayoffType<f64>,
+        option: &TouchOption,
         discount_factor: f64,
-    ) -> f64 {
-        let bump = (0.01 * gbm.spot).max(0.01);
-        let seed = self.rng.seed();
-
-        // Price at S + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            spot: gbm.spot + bump,
-            ..gbm
-        };
-        let price_up = self
-            .price_path_dependent(gbm_up, payoff, discount_factor)
-            .price;
-
-        // Price at S - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            spot: gbm.spot - bump,
-            ..gbm
-        };
-        let price_down = self
-            .price_path_dependent(gbm_down, payoff, discount_factor)
-            .price;
-
-        (price_up - price_down) / (2.0 * bump)
+    ) -> Result<PricingResult, ConfigError> {
+        option.validate(gbm.spot, gbm.maturity)?;
+        let evaluator = BarrierEvaluator::for_touch(option, gbm, self.config.n_steps());
+        Ok(self.gbm_barrier_result(&evaluator, gbm, discount_factor))
     }
 
-    /// Computes Gamma for path-dependent options using central differences.
-    fn compute_gamma_path_dependent(
+    /// Simulates GBM paths and values a barrier or touch contract on them,
+    /// with the terminal spot as control variate if configured.
+    fn gbm_barrier_result(
         &mut self,
+        evaluator: &BarrierEvaluator<'_>,
         gbm: GbmParams,
-        payoff: PathPayoffType<f64>,
         discount_factor: f64,
-    ) -> f64 {
-        let bump = (0.01 * gbm.spot).max(0.01);
-        let seed = self.rng.seed();
-
-        // Price at S
-        self.reset_with_seed(seed);
-        let price_mid = self
-            .price_path_dependent(gbm, payoff, discount_factor)
-            .price;
+    ) -> PricingResult {
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
 
-        // Price at S + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            spot: gbm.spot + bump,
-            ..gbm
-        };
-        let price_up = self
-            .price_path_dependent(gbm_up, payoff, discount_factor)
-            .price;
+        self.workspace.ensure_capacity(n_paths, n_steps);
+        self.fill_randoms();
+        let weights = self.shift_gbm_randoms(gbm);
+        generate_gbm_paths(&mut self.workspace, gbm, n_paths, n_steps);
 
-        // Price at S - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            spot: gbm.spot - bump,
-            ..gbm
-        };
-        let price_down = self
-            .price_path_dependent(gbm_down, payoff, discount_factor)
-            .price;
+        let paths = self.workspace.paths();
+        let payoffs: Vec<f64> = paths
+            .chunks_exact(n_steps + 1)
+            .take(n_paths)
+            .map(|path| evaluator.evaluate(path, &mut self.rng))
+            .collect();
+
+        let control = self.config.variance_reduction().control_variate.then(|| {
+            let terminals: Vec<f64> = paths
+                .chunks_exact(n_steps + 1)
+                .take(n_paths)
+                .map(|path| path[n_steps])
+                .collect();
+            (terminals, expected_terminal_spot(gbm))
+        });
+
+        self.aggregate(
+            &payoffs,
+            weights.as_deref(),
+            control.as_ref().map(|(c, e)| (c.as_slice(), *e)),
+            discount_factor,
+        )
+    }
 
-        (price_up - 2.0 * price_mid + price_down) / (bump * bump)
+    /// Prices a barrier option under a stochastic-local volatility model.
+    ///
+    /// Paths are simulated with [`SlvModel::simulate_into`] on the pricer's
+    /// time grid, two normals per step. The Brownian bridge of each step
+    /// uses the instantaneous volatility `L(t, S)·√v` at the start of the
+    /// step; the continuity correction uses `L(0, S₀)·√v₀`. Rebates paid at
+    /// the hit are discounted to maturity at the model's rate, so
+    /// `discount_factor` should be `exp(−rate·maturity)`. The control
+    /// variate, if configured, is the terminal spot against the forward.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the option is invalid
+    /// for the spot and maturity, `maturity` is not positive, or
+    /// quasi-random sampling cannot cover `2 × n_steps` dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_models::models::heston::HestonParams;
+    /// use pricer_models::models::slv::{LeverageFunction, SlvModel, SlvParams};
+    /// use pricer_pricing::mc::{
+    ///     BarrierOption, MonteCarloConfig, MonteCarloPricer, PayoffParams,
+    /// };
+    ///
+    /// let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.5, -0.7, 0.03, 1.0).unwrap();
+    /// let params = SlvParams::new(heston, 0.01, 0.5).unwrap();
+    /// let model = SlvModel::new(params, LeverageFunction::flat(1.0).unwrap()).unwrap();
+    ///
+    /// let config = MonteCarloConfig::builder()
+    ///     .n_paths(10_000)
+    ///     .n_steps(50)
+    ///     .seed(42)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut pricer = MonteCarloPricer::new(config).unwrap();
+    ///
+    /// let option = BarrierOption::knock_out(PayoffParams::call(100.0), Some(85.0), None);
+    /// let result = pricer
+    ///     .price_slv_barrier(&model, 1.0, &option, (-0.03_f64).exp())
+    ///     .unwrap();
+    /// assert!(result.price > 0.0);
+    /// ```
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_slv_barrier(
+        &mut self,
+        model: &SlvModel,
+        maturity: f64,
+        option: &BarrierOption,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        let gbm = slv_reference_gbm(model, maturity);
+        option.validate(gbm.spot, maturity)?;
+        let evaluator = BarrierEvaluator::new(option, gbm, self.config.n_steps());
+        self.slv_barrier_result(model, &evaluator, maturity, discount_factor)
     }
 
-    /// Computes Vega for path-dependent options using central differences.
-    fn compute_vega_path_dependent(
+    /// Prices a one-touch or no-touch option under a stochastic-local
+    /// volatility model.
+    ///
+    /// Simulation and monitoring are as for
+    /// [`price_slv_barrier`](Self::price_slv_barrier).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`price_slv_barrier`](Self::price_slv_barrier).
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_slv_touch(
         &mut self,
-        gbm: GbmParams,
-        payoff: PathPayoffType<f64>,
+        model: &SlvModel,
+        maturity: f64,
+        option: &TouchOption,
         discount_factor: f64,
-    ) -> f64 {
-        let bump = 0.01;
-        let seed = self.rng.seed();
+    ) -> Result<PricingResult, ConfigError> {
+        let gbm = slv_reference_gbm(model, maturity);
+        option.validate(gbm.spot, maturity)?;
+        let evaluator = BarrierEvaluator::for_touch(option, gbm, self.config.n_steps());
+        self.slv_barrier_result(model, &evaluator, maturity, discount_factor)
+    }
 
-        // Price at vol + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            volatility: gbm.volatility + bump,
-            ..gbm
-        };
-        let price_up = self
-            .price_path_dependent(gbm_up, payoff, discount_factor)
-            .price;
+    /// Simulates SLV paths and values a barrier or touch contract on them.
+    #[cfg(feature = "l1l2-integration")]
+    fn slv_barrier_result(
+        &mut self,
+        model: &SlvModel,
+        evaluator: &BarrierEvaluator<'_>,
+        maturity: f64,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        if !maturity.is_finite() || maturity <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                name: "maturity",
+                value: maturity.to_string(),
+            });
+        }
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
 
-        // Price at vol - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            volatility: (gbm.volatility - bump).max(0.001),
-            ..gbm
-        };
-        let price_down = self
-            .price_path_dependent(gbm_down, payoff, discount_factor)
-            .price;
+        self.prepare_qmc(SLV_NORMALS_PER_STEP)?;
+        self.workspace
+            .ensure_capacity_with_factors(n_paths, n_steps, SLV_NORMALS_PER_STEP);
+        self.fill_randoms();
+
+        let row_len = n_steps * SLV_NORMALS_PER_STEP;
+        let mut volatilities = vec![0.0; n_steps];
+        let mut payoffs = Vec::with_capacity(n_paths);
+        let mut terminals = Vec::with_capacity(n_paths);
+        let (paths, randoms) = self.workspace.paths_mut_and_randoms();
+        for (path, z) in paths
+            .chunks_exact_mut(n_steps + 1)
+            .zip(randoms.chunks_exact(row_len))
+            .take(n_paths)
+        {
+            model
+                .simulate_into(maturity, z, path, &mut volatilities)
+                .map_err(|e| ConfigError::InvalidParameter {
+                    name: "model",
+                    value: e.to_string(),
+                })?;
+            payoffs.push(evaluator.evaluate_with_volatilities(
+                path,
+                Some(&volatilities),
+                &mut self.rng,
+            ));
+            terminals.push(path[n_steps]);
+        }
 
-        (price_up - price_down) / (2.0 * bump)
+        let control = self
+            .config
+            .variance_reduction()
+            .control_variate
+            .then(|| (terminals.as_slice(), model.params().forward(maturity)));
+        Ok(self.aggregate(&payoffs, None, control, discount_factor))
     }
 
-    /// Computes Theta for path-dependent options using forward difference.
-    fn compute_theta_path_dependent(
+    /// Simulates stock paths under the escrowed dividend model.
+    ///
+    /// A driftless GBM is started at the escrowed spot and mapped to the
+    /// stock price on the grid by `adjustment`.
+    fn generate_dividend_paths(
         &mut self,
-        gbm: GbmParams,
-        payoff: PathPayoffType<f64>,
-        discount_factor: f64,
-    ) -> f64 {
-        let bump = 1.0 / 252.0; // 1 day
-        let seed = self.rng.seed();
+        adjustment: &DividendAdjustment,
+        volatility: f64,
+    ) -> Result<(), ConfigError> {
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
+        if adjustment.n_steps() != n_steps {
+            return Err(ConfigError::InvalidParameter {
+                name: "adjustment",
+                value: format!(
+                    "grid has {} steps but the pricer simulates {}",
+                    adjustment.n_steps(),
+                    n_steps
+                ),
+            });
+        }
 
-        // Price at T - bump
-        self.reset_with_seed(seed);
-        let gbm_short = GbmParams {
-            maturity: (gbm.maturity - bump).max(0.001),
-            ..gbm
-        };
-        let price_short = self
-            .price_path_dependent(gbm_short, payoff, discount_factor)
-            .price;
+        self.workspace.ensure_capacity(n_paths, n_steps);
+        self.fill_randoms();
 
-        // Price at T
-        self.reset_with_seed(seed);
-        let price_orig = self
-            .price_path_dependent(gbm, payoff, discount_factor)
-            .price;
+        let escrowed = GbmParams {
+            spot: adjustment.escrowed_spot(),
+            rate: 0.0,
+            volatility,
+            maturity: adjustment.maturity(),
+        };
+        generate_gbm_paths(&mut self.workspace, escrowed, n_paths, n_steps);
+        adjustment.apply(self.workspace.paths_mut(), n_paths);
+        Ok(())
+    }
 
-        -(price_orig - price_short) / bump
+    /// Prices a European option with discrete dividends, dividend yield,
+    /// repo and borrow.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjustment` - Escrowed dividend adjustment on the pricer's time grid
+    /// * `volatility` - Volatility of the escrowed process
+    /// * `payoff` - Payoff parameters
+    /// * `discount_factor` - Discount factor to maturity
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the adjustment grid does
+    /// not match the configured step count.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_pricing::mc::{
+    ///     DividendAdjustment, MonteCarloConfig, MonteCarloPricer, PayoffParams,
+    /// };
+    ///
+    /// let config = MonteCarloConfig::builder()
+    ///     .n_paths(10_000)
+    ///     .n_steps(10)
+    ///     .seed(42)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut pricer = MonteCarloPricer::new(config).unwrap();
+    ///
+    /// let adjustment = DividendAdjustment::continuous(100.0, 0.05, 0.02, 1.0, 10).unwrap();
+    /// let result = pricer
+    ///     .price_european_with_dividends(&adjustment, 0.2, PayoffParams::call(100.0), (-0.05_f64).exp())
+    ///     .unwrap();
+    /// assert!(result.price > 0.0);
+    /// ```
+    pub fn price_european_with_dividends(
+        &mut self,
+        adjustment: &DividendAdjustment,
+        volatility: f64,
+        payoff: PayoffParams,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        self.generate_dividend_paths(adjustment, volatility)?;
+        Ok(self.european_result(payoff, discount_factor, None, None))
     }
 
-    /// Computes Rho for path-dependent options using central differences.
-    fn compute_rho_path_dependent(
+    /// Prices a path-dependent option with discrete dividends, dividend
+    /// yield, repo and borrow.
+    ///
+    /// Observations are taken on the dividend-adjusted stock paths, so
+    /// ex-dividend drops are reflected in averages and extrema.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the adjustment grid does
+    /// not match the configured step count.
+    pub fn price_path_dependent_with_dividends(
         &mut self,
-        gbm: GbmParams,
+        adjustment: &DividendAdjustment,
+        volatility: f64,
         payoff: PathPayoffType<f64>,
-        _discount_factor: f64,
-    ) -> f64 {
-        let bump = 0.01;
-        let seed = self.rng.seed();
-
-        // Price at r + bump
-        self.reset_with_seed(seed);
-        let gbm_up = GbmParams {
-            rate: gbm.rate + bump,
-            ..gbm
-        };
-        let df_up = (-(gbm.rate + bump) * gbm.maturity).exp();
-        let price_up = self.price_path_dependent(gbm_up, payoff, df_up).price;
-
-        // Price at r - bump
-        self.reset_with_seed(seed);
-        let gbm_down = GbmParams {
-            rate: gbm.rate - bump,
-            ..gbm
-        };
-        let df_down = (-(gbm.rate - bump) * gbm.maturity).exp();
-        let price_down = self.price_path_dependent(gbm_down, payoff, df_down).price;
-
-        (price_up - price_down) / (2.0 * bump)
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        self.generate_dividend_paths(adjustment, volatility)?;
+        Ok(self.path_dependent_result(&payoff, discount_factor, None, None))
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Prices a European option on an equity market.
+    ///
+    /// Builds the [`DividendAdjustment`] from the market on the pricer's
+    /// time grid and discounts on the market's discount curve.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if the market cannot produce
+    /// forwards or discount factors to `maturity`.
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_european_with_equity_market(
+        &mut self,
+        market: &pricer_core::market_data::equity::EquityMarket<f64>,
+        volatility: f64,
+        maturity: f64,
+        payoff: PayoffParams,
+    ) -> Result<PricingResult, ConfigError> {
+        let adjustment = DividendAdjustment::from_market(market, maturity, self.config.n_steps())?;
+        let discount_factor =
+            market
+                .discount_factor(maturity)
+                .map_err(|e| ConfigError::InvalidParameter {
+                    name: "market",
+                    value: e.to_string(),
+                })?;
+        self.price_european_with_dividends(&adjustment, volatility, payoff, discount_factor)
+    }
+
+    /// Prices a European option under any stochastic model.
+    ///
+    /// Paths are simulated with the model's `evolve_step` (see
+    /// [`generate_model_paths`]) and the payoff is applied to the first
+    /// state component.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Stochastic model to simulate
+    /// * `params` - Parameters of `model`
+    /// * `maturity` - Time to maturity in years
+    /// * `payoff` - Payoff parameters
+    /// * `discount_factor` - Present value discount factor
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidParameter` if `params` do not belong to
+    /// `model`, `maturity` is not positive, or quasi-random sampling cannot
+    /// cover `n_steps × brownian_dim` dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pricer_models::models::heston::HestonParams;
+    /// use pricer_models::models::model_enum::{ModelParams, StochasticModelEnum};
+    /// use pricer_pricing::mc::{MonteCarloConfig, MonteCarloPricer, PayoffParams};
+    ///
+    /// let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.3, -0.7, 0.05, 1.0).unwrap();
+    /// let model = StochasticModelEnum::heston(heston).unwrap();
+    /// let params = ModelParams::Heston(heston);
+    ///
+    /// let config = MonteCarloConfig::builder()
+    ///     .n_paths(10_000)
+    ///     .n_steps(50)
+    ///     .seed(42)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut pricer = MonteCarloPricer::new(config).unwrap();
+    ///
+    /// let df = (-0.05_f64).exp();
+    /// let result = pricer
+    ///     .price_model_european(&model, &params, 1.0, PayoffParams::call(100.0), df)
+    ///     .unwrap();
+    /// assert!(result.price > 0.0);
+    /// ```
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_model_european(
+        &mut self,
+        model: &StochasticModelEnum<f64>,
+        params: &ModelParams<f64>,
+        maturity: f64,
+        payoff: PayoffParams,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        self.simulate_model(model, params, maturity)?;
+        Ok(self.european_result(payoff, discount_factor, None, None))
+    }
+
+    /// Prices a path-dependent option under any stochastic model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Stochastic model to simulate
+    /// * `params` - Parameters of `model`
+    /// * `maturity` - Time to maturity in years
+    /// * `payoff` - Path-dependent payoff type
+    /// * `discount_factor` - Present value discount factor
+    ///
+    /// # Errors
+    ///
+    /// Same as [`price_model_european`](Self::price_model_european).
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_model_path_dependent(
+        &mut self,
+        model: &StochasticModelEnum<f64>,
+        params: &ModelParams<f64>,
+        maturity: f64,
+        payoff: PathPayoffType<f64>,
+        discount_factor: f64,
+    ) -> Result<PricingResult, ConfigError> {
+        self.simulate_model(model, params, maturity)?;
+        Ok(self.path_dependent_result(&payoff, discount_factor, None, None))
+    }
+
+    /// Prices a path-dependent option under any stochastic model with
+    /// selected Greeks.
+    ///
+    /// Greeks are bump-and-revalue with common random numbers on the model
+    /// parameters:
+    ///
+    /// - Delta/Gamma: [`ModelParams::spot`] (forward for SABR, initial short
+    ///   rate for rate models)
+    /// - Vega: [`ModelParams::volatility`] (`sqrt(v0)` for Heston, alpha for
+    ///   SABR)
+    /// - Theta: maturity, with the discount factor held fixed
+    /// - Rho: risk-free drift and discounting; not available for interest
+    ///   rate models, whose drift is not a single rate
+    ///
+    /// # Errors
+    ///
+    /// Same as [`price_model_european`](Self::price_model_european).
+    #[cfg(feature = "l1l2-integration")]
+    pub fn price_model_path_dependent_with_greeks(
+        &mut self,
+        model: &StochasticModelEnum<f64>,
+        params: &ModelParams<f64>,
+        maturity: f64,
+        payoff: PathPayoffType<f64>,
+        discount_factor: f64,
+        greeks: &[Greek],
+    ) -> Result<PricingResult, ConfigError> {
+        let mut result =
+            self.price_model_path_dependent(model, params, maturity, payoff, discount_factor)?;
+        let seed = self.rng.seed();
+        let price = |pricer: &mut Self, params: &ModelParams<f64>, maturity: f64, df: f64| {
+            pricer.reset_with_seed(seed);
+            pricer
+                .price_model_path_dependent(model, params, maturity, payoff, df)
+                .map(|r| r.price)
+        };
+
+        let spot = params.spot();
+        let spot_bump = (0.01 * spot.abs()).max(1e-4);
+        let spot_up = params.with_spot(spot + spot_bump);
+        let spot_down = params.with_spot(spot - spot_bump);
+        let vol = params.volatility();
+        let vol_bump = 0.01_f64.min(0.5 * vol);
+        let vol_up = params.with_volatility(vol + vol_bump);
+        let vol_down = params.with_volatility(vol - vol_bump);
+        let df = discount_factor;
+
+        for greek in greeks {
+            match greek {
+                Greek::Delta => {
+                    let up = price(self, &spot_up, maturity, df)?;
+                    let down = price(self, &spot_down, maturity, df)?;
+                    result.delta = Some((up - down) / (2.0 * spot_bump));
+                }
+                Greek::Gamma => {
+                    let mid = price(self, params, maturity, df)?;
+                    let up = price(self, &spot_up, maturity, df)?;
+                    let down = price(self, &spot_down, maturity, df)?;
+                    result.gamma = Some((up - 2.0 * mid + down) / (spot_bump * spot_bump));
+                }
+                Greek::Vega => {
+                    let up = price(self, &vol_up, maturity, df)?;
+                    let down = price(self, &vol_down, maturity, df)?;
+                    result.vega = Some((up - down) / (2.0 * vol_bump));
+                }
+                Greek::Theta => {
+                    let bump = (1.0 / 252.0_f64).min(0.5 * maturity);
+                    let short = price(self, params, maturity - bump, df)?;
+                    let orig = price(self, params, maturity, df)?;
+                    result.theta = Some(-(orig - short) / bump);
+                }
+                Greek::Rho => {
+                    let bump = 0.01;
+                    let rate = params.rate();
+                    if let (Some(up_params), Some(down_params)) =
+                        (params.with_rate(rate + bump), params.with_rate(rate - bump))
+                    {
+                        let df_up = df * (-bump * maturity).exp();
+                        let df_down = df * (bump * maturity).exp();
+                        let up = price(self, &up_params, maturity, df_up)?;
+                        let down = price(self, &down_params, maturity, df_down)?;
+                        result.rho = Some((up - down) / (2.0 * bump));
+                    }
+                }
+                Greek::Vanna | Greek::Volga => {
+                    // Second-order cross Greeks for path-dependent not yet implemented
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Draws randoms for `model` and simulates its paths into the workspace.
+    #[cfg(feature = "l1l2-integration")]
+    fn simulate_model(
+        &mut self,
+        model: &StochasticModelEnum<f64>,
+        params: &ModelParams<f64>,
+        maturity: f64,
+    ) -> Result<(), ConfigError> {
+        validate_model_inputs(model, params, maturity)?;
+
+        let n_paths = self.config.n_paths();
+        let n_steps = self.config.n_steps();
+        let n_factors = model.brownian_dim();
+
+        self.prepare_qmc(n_factors)?;
+        self.workspace
+            .ensure_capacity_with_factors(n_paths, n_steps, n_factors);
+        self.fill_randoms();
+
+        generate_model_paths(
+            &mut self.workspace,
+            model,
+            params,
+            maturity,
+            n_paths,
+            n_steps,
+        );
+        Ok(())
+    }
+
+    /// Prices a path-dependent option with selected Greeks.
+    ///
+    /// # Arguments
+    ///
+    /// * `gbm` - GBM parameters
+    /// * `payoff` - Path-dependent payoff type
+    /// * `discount_factor` - Discount factor
+    /// * `greeks` - Which Greeks to compute
+    ///
+    /// # Returns
+    ///
+    /// Price and requested Greeks.
+    ///
+    /// # Implementation Note
+    ///
+    /// Phase 4 uses bump-and-revalue for Greeks. Future phases will integrate
+    /// Enzyme AD with checkpointing for path-dependent derivatives.
+    pub fn price_path_dependent_with_greeks(
+        &mut self,
+        gbm: GbmParams,
+        payoff: PathPayoffType<f64>,
+        discount_factor: f64,
+        greeks: &[Greek],
+    ) -> PricingResult {
+        // Base price
+        let mut result = self.price_path_dependent(gbm, payoff, discount_factor);
+
+        // Compute requested Greeks via bump-and-revalue
+        for &greek in greeks {
+            if matches!(greek, Greek::Vanna | Greek::Volga) {
+                // Second-order cross Greeks for path-dependent not yet implemented
+                // Will be added with Enzyme AD + checkpointing integration
+                continue;
+            }
+            let estimate = self.bumped_greek(
+                greek,
+                gbm,
+                GbmPayoff::PathDependent(payoff),
+                discount_factor,
+            );
+            result.set_greek(greek, estimate);
+        }
+
+        result
+    }
+}
+
+/// Payoff simulated on GBM paths by the bump-and-revalue and adaptive
+/// drivers.
+#[derive(Clone, Copy)]
+enum GbmPayoff {
+    European(PayoffParams),
+    PathDependent(PathPayoffType<f64>),
+}
+
+/// Weighted scenarios `(wᵢ, gbmᵢ, dfᵢ)` whose combination
+/// `Σ wᵢ·V(gbmᵢ, dfᵢ)` is the finite-difference estimate of `greek`.
+///
+/// Spot is bumped by 1% (at least 0.01), volatility and rate by 0.01
+/// absolute, and maturity by one trading day (forward difference).
+fn bump_scenarios(greek: Greek, gbm: GbmParams, df: f64) -> Vec<(f64, GbmParams, f64)> {
+    let spot_bump = (0.01 * gbm.spot).max(0.01);
+    let vol_bump = 0.01;
+    let spot_up = GbmParams {
+        spot: gbm.spot + spot_bump,
+        ..gbm
+    };
+    let spot_down = GbmParams {
+        spot: gbm.spot - spot_bump,
+        ..gbm
+    };
+    let vol_up = |gbm: GbmParams| GbmParams {
+        volatility: gbm.volatility + vol_bump,
+        ..gbm
+    };
+    let vol_down = |gbm: GbmParams| GbmParams {
+        volatility: (gbm.volatility - vol_bump).max(0.001),
+        ..gbm
+    };
+
+    match greek {
+        Greek::Delta => {
+            let w = 1.0 / (2.0 * spot_bump);
+            vec![(w, spot_up, df), (-w, spot_down, df)]
+        }
+        Greek::Gamma => {
+            let w = 1.0 / (spot_bump * spot_bump);
+            vec![(w, spot_up, df), (-2.0 * w, gbm, df), (w, spot_down, df)]
+        }
+        Greek::Vega => {
+            let w = 1.0 / (2.0 * vol_bump);
+            vec![(w, vol_up(gbm), df), (-w, vol_down(gbm), df)]
+        }
+        Greek::Volga => {
+            let w = 1.0 / (vol_bump * vol_bump);
+            vec![
+                (w, vol_up(gbm), df),
+                (-2.0 * w, gbm, df),
+                (w, vol_down(gbm), df),
+            ]
+        }
+        Greek::Vanna => {
+            // (V(S+h,σ+k) - V(S+h,σ-k) - V(S-h,σ+k) + V(S-h,σ-k)) / (4hk)
+            let w = 1.0 / (4.0 * spot_bump * vol_bump);
+            vec![
+                (w, vol_up(spot_up), df),
+                (-w, vol_down(spot_up), df),
+                (-w, vol_up(spot_down), df),
+                (w, vol_down(spot_down), df),
+            ]
+        }
+        Greek::Theta => {
+            // Time decay: (V(T - 1d) - V(T)) / 1d
+            let bump = 1.0 / 252.0;
+            let short = GbmParams {
+                maturity: (gbm.maturity - bump).max(0.001),
+                ..gbm
+            };
+            vec![(1.0 / bump, short, df), (-1.0 / bump, gbm, df)]
+        }
+        Greek::Rho => {
+            // The discount factor moves with the rate
+            let bump = 0.01;
+            let w = 1.0 / (2.0 * bump);
+            let rate_bumped = |rate: f64| {
+                let bumped = GbmParams { rate, ..gbm };
+                (bumped, (-rate * gbm.maturity).exp())
+            };
+            let (up, df_up) = rate_bumped(gbm.rate + bump);
+            let (down, df_down) = rate_bumped(gbm.rate - bump);
+            vec![(w, up, df_up), (-w, down, df_down)]
+        }
+    }
+}
+
+/// Fills consecutive rows of `row_len` normals, row `i` from the Philox
+/// stream `first_stream + i` of `seed`.
+fn fill_path_streams(randoms: &mut [f64], row_len: usize, seed: u64, first_stream: usize) {
+    randoms
+        .par_chunks_mut(row_len)
+        .enumerate()
+        .for_each(|(i, row)| PhiloxRng::new(seed, (first_stream + i) as u64).fill_normal(row));
+}
+
+/// Checks that `params` belong to `model` and `maturity` is positive.
+#[cfg(feature = "l1l2-integration")]
+fn validate_model_inputs(
+    model: &StochasticModelEnum<f64>,
+    params: &ModelParams<f64>,
+    maturity: f64,
+) -> Result<(), ConfigError> {
+    if !model.accepts(params) {
+        return Err(ConfigError::InvalidParameter {
+            name: "params",
+            value: format!(
+                "parameters do not belong to the {} model",
+                model.model_name()
+            ),
+        });
+    }
+    if !maturity.is_finite() || maturity <= 0.0 {
+        return Err(ConfigError::InvalidParameter {
+            name: "maturity",
+            value: maturity.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Control variate of a path-dependent payoff on GBM paths.
+#[derive(Clone, Copy)]
+enum PathControl {
+    /// Hard geometric Asian payoff on the same fixings.
+    GeometricAsian { strike: f64, is_call: bool },
+    /// Terminal spot.
+    Terminal,
+}
+
+/// Control variate for `payoff` on GBM paths and its known mean: the
+/// geometric Asian for arithmetic Asians, the terminal spot otherwise.
+fn path_control(
+    payoff: &PathPayoffType<f64>,
+    gbm: GbmParams,
+    n_steps: usize,
+) -> (PathControl, f64) {
+    match payoff {
+        PathPayoffType::AsianArithmetic(asian) => {
+            let params = asian.params();
+            let expected = expected_geometric_asian(gbm, params.strike, params.is_call, n_steps);
+            let control = PathControl::GeometricAsian {
+                strike: params.strike,
+                is_call: params.is_call,
+            };
+            (control, expected)
+        }
+        _ => (PathControl::Terminal, expected_terminal_spot(gbm)),
+    }
+}
+
+/// Evaluates `payoff` on each path of `paths` (rows of `n_steps + 1`
+/// prices), writing the payoff and, when `control` is set, the control
+/// value of each path.
+fn observe_paths(
+    paths: &[f64],
+    n_steps: usize,
+    payoff: &PathPayoffType<f64>,
+    control: Option<PathControl>,
+    payoffs: &mut [f64],
+    controls: &mut [f64],
+) {
+    for (i, path) in paths.chunks_exact(n_steps + 1).enumerate() {
+        let mut observer: PathObserver<f64> = PathObserver::new();
+        for &price in path {
+            observer.observe(price);
+        }
+        let terminal = path[n_steps];
+        observer.set_terminal(terminal);
+
+        payoffs[i] = payoff.compute(&[], &observer);
+        match control {
+            Some(PathControl::GeometricAsian { strike, is_call }) => {
+                let geometric = observer.geometric_average();
+                let intrinsic = if is_call {
+                    geometric - strike
+                } else {
+                    strike - geometric
+                };
+                controls[i] = intrinsic.max(0.0);
+            }
+            Some(PathControl::Terminal) => controls[i] = terminal,
+            None => {}
+        }
+    }
+}
+
+/// Per-path outputs of a parallel simulation, in path order.
+struct BlockSamples {
+    /// Undiscounted payoff per path.
+    payoffs: Vec<f64>,
+    /// Control variate value per path.
+    controls: Vec<f64>,
+    /// Importance sampling likelihood ratio per path.
+    weights: Vec<f64>,
+}
+
+/// Output slices of one block of a parallel simulation.
+struct BlockOutputs<'a> {
+    payoffs: &'a mut [f64],
+    controls: &'a mut [f64],
+    weights: &'a mut [f64],
+}
+
+/// Builds the Sobol sampler for a quasi-random configuration.
+pub(crate) fn qmc_sampler(
+    config: &MonteCarloConfig,
+    seed: u64,
+) -> Result<Option<QmcSampler>, ConfigError> {
+    match config.sampling() {
+        SamplingMethod::PseudoRandom | SamplingMethod::CounterBased => Ok(None),
+        SamplingMethod::QuasiRandom(qmc) => QmcSampler::new(qmc, config.n_steps(), seed)
+            .map(Some)
+            .map_err(|e| ConfigError::InvalidParameter {
+                name: "sampling",
+                value: e.to_string(),
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
+    use crate::mc::{PathConstruction, QmcConfig, VarianceReduction};
+    use crate::rng::Scrambling;
     use approx::assert_relative_eq;
 
     fn create_test_pricer() -> MonteCarloPricer {
@@ -1212,70 +2397,276 @@ mod tests {
     }
 
     #[test]
-    fn test_price_with_delta() {
-        let mut pricer = create_test_pricer();
+    fn test_qmc_european_call_matches_black_scholes() {
+        // Black-Scholes: S = K = 100, r = 5%, sigma = 20%, T = 1
+        let exact = 10.450_583_572_185_565;
         let gbm = GbmParams::default();
         let payoff = PayoffParams::call(100.0);
         let df = (-0.05_f64).exp();
+        let builder = MonteCarloConfig::builder()
+            .n_paths(8192)
+            .n_steps(16)
+            .seed(42);
+
+        let mut prng = MonteCarloPricer::new(builder.clone().build().unwrap()).unwrap();
+        let prng_result = prng.price_european(gbm, payoff, df);
+
+        for construction in [
+            PathConstruction::Incremental,
+            PathConstruction::BrownianBridge,
+            PathConstruction::Pca,
+        ] {
+            let qmc = QmcConfig::new(Scrambling::Owen, construction).with_randomisations(8);
+            let config = builder
+                .clone()
+                .sampling(SamplingMethod::QuasiRandom(qmc))
+                .build()
+                .unwrap();
+            let result = MonteCarloPricer::new(config)
+                .unwrap()
+                .price_european(gbm, payoff, df);
+
+            assert!(result.std_error > 0.0);
+            assert!(result.std_error < 0.5 * prng_result.std_error);
+            assert!(
+                (result.price - exact).abs() < 4.0 * result.std_error + 1e-3,
+                "{construction:?}: QMC {} vs exact {exact} (se {})",
+                result.price,
+                result.std_error
+            );
+        }
+    }
 
-        let result = pricer.price_with_greeks(gbm, payoff, df, &[Greek::Delta]);
+    #[test]
+    fn test_qmc_reproducibility_and_reset() {
+        let qmc = QmcConfig::default();
+        let config = MonteCarloConfig::builder()
+            .n_paths(1024)
+            .n_steps(12)
+            .seed(7)
+            .sampling(SamplingMethod::QuasiRandom(qmc))
+            .build()
+            .unwrap();
+        let gbm = GbmParams::default();
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-4);
+        let df = (-0.05_f64).exp();
 
-        assert!(result.delta.is_some());
-        let delta = result.delta.unwrap();
+        let mut pricer1 = MonteCarloPricer::new(config.clone()).unwrap();
+        let mut pricer2 = MonteCarloPricer::new(config).unwrap();
+        let result1 = pricer1.price_path_dependent(gbm, payoff, df);
+        let result2 = pricer2.price_path_dependent(gbm, payoff, df);
+        assert_eq!(result1.price, result2.price);
+        assert_eq!(result1.std_error, result2.std_error);
+        assert!(result1.std_error > 0.0);
+
+        // Later draws continue the sequence; reset restarts it
+        let next = pricer1.price_path_dependent(gbm, payoff, df);
+        assert_ne!(next.price, result1.price);
+        pricer1.reset();
+        let restarted = pricer1.price_path_dependent(gbm, payoff, df);
+        assert_eq!(restarted.price, result1.price);
+    }
 
-        // Delta of ATM call should be around 0.5-0.6
-        assert!(delta > 0.3 && delta < 0.8, "Delta = {}", delta);
+    fn variance_reduced_pricer(vr: VarianceReduction) -> MonteCarloPricer {
+        let config = MonteCarloConfig::builder()
+            .n_paths(10_000)
+            .n_steps(50)
+            .seed(42)
+            .variance_reduction(vr)
+            .build()
+            .unwrap();
+        MonteCarloPricer::new(config).unwrap()
     }
 
     #[test]
-    fn test_price_with_vega() {
+    fn test_plain_sampling_reports_no_variance_reduction() {
         let mut pricer = create_test_pricer();
-        let gbm = GbmParams::default();
-        let payoff = PayoffParams::call(100.0);
         let df = (-0.05_f64).exp();
+        let result = pricer.price_european(GbmParams::default(), PayoffParams::call(100.0), df);
+        assert!(result.variance_reduction_factor.is_none());
+    }
 
-        let result = pricer.price_with_greeks(gbm, payoff, df, &[Greek::Vega]);
+    #[test]
+    fn test_antithetic_european_call() {
+        let exact = 10.450_583_572_185_565;
+        let df = (-0.05_f64).exp();
+        let mut pricer = variance_reduced_pricer(VarianceReduction::default().with_antithetic());
 
-        assert!(result.vega.is_some());
-        let vega = result.vega.unwrap();
+        let result = pricer.price_european(GbmParams::default(), PayoffParams::call(100.0), df);
 
-        // Vega should be positive for options
-        assert!(vega > 0.0, "Vega = {}", vega);
+        let factor = result.variance_reduction_factor.unwrap();
+        assert!(factor > 1.5, "antithetic factor {factor}");
+        assert!((result.price - exact).abs() < 4.0 * result.std_error);
     }
 
     #[test]
-    fn test_price_with_multiple_greeks() {
-        let mut pricer = create_test_pricer();
+    fn test_terminal_spot_control_variate_european_call() {
+        let exact = 10.450_583_572_185_565;
+        let df = (-0.05_f64).exp();
+        let mut plain = create_test_pricer();
+        let mut pricer =
+            variance_reduced_pricer(VarianceReduction::default().with_control_variate());
+
         let gbm = GbmParams::default();
         let payoff = PayoffParams::call(100.0);
+        let plain_result = plain.price_european(gbm, payoff, df);
+        let result = pricer.price_european(gbm, payoff, df);
+
+        let factor = result.variance_reduction_factor.unwrap();
+        assert!(factor > 2.0, "control variate factor {factor}");
+        assert!(result.std_error < plain_result.std_error);
+        assert!((result.price - exact).abs() < 4.0 * result.std_error);
+    }
+
+    #[test]
+    fn test_geometric_control_variate_arithmetic_asian() {
         let df = (-0.05_f64).exp();
+        let gbm = GbmParams::default();
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-6);
 
-        let result =
-            pricer.price_with_greeks(gbm, payoff, df, &[Greek::Delta, Greek::Gamma, Greek::Vega]);
+        let mut plain = variance_reduced_pricer(VarianceReduction::default());
+        let plain_result = plain.price_path_dependent(gbm, payoff, df);
+        let mut pricer = variance_reduced_pricer(
+            VarianceReduction::default()
+                .with_antithetic()
+                .with_control_variate(),
+        );
+        let result = pricer.price_path_dependent(gbm, payoff, df);
 
-        assert!(result.delta.is_some());
-        assert!(result.gamma.is_some());
-        assert!(result.vega.is_some());
-        assert!(result.theta.is_none());
-        assert!(result.rho.is_none());
+        let factor = result.variance_reduction_factor.unwrap();
+        assert!(factor > 100.0, "geometric control factor {factor}");
+        assert!(
+            (result.price - plain_result.price).abs() < 4.0 * plain_result.std_error,
+            "controlled {} vs plain {}",
+            result.price,
+            plain_result.price
+        );
     }
 
     #[test]
-    fn test_price_with_delta_ad() {
-        let mut pricer = create_test_pricer();
+    fn test_geometric_asian_matches_discrete_closed_form() {
+        // The control mean must match the simulated fixings exactly
         let gbm = GbmParams::default();
-        let payoff = PayoffParams::call(100.0);
         let df = (-0.05_f64).exp();
+        let mut pricer = variance_reduced_pricer(VarianceReduction::default().with_antithetic());
+        let result =
+            pricer.price_path_dependent(gbm, PathPayoffType::asian_geometric_put(100.0, 1e-6), df);
 
-        let (price, delta) = pricer.price_with_delta_ad(gbm, payoff, df);
-
-        assert!(price > 0.0);
-        assert!(delta > 0.3 && delta < 0.8, "Delta = {}", delta);
+        let expected = expected_geometric_asian(gbm, 100.0, false, 50) * df;
+        assert!(
+            (result.price - expected).abs() < 4.0 * result.std_error,
+            "MC {} vs closed form {expected}",
+            result.price
+        );
     }
 
     #[test]
-    fn test_delta_ad_vs_bump() {
-        let config = MonteCarloConfig::builder()
+    fn test_importance_sampling_deep_otm_call() {
+        // Black-Scholes: S = 100, K = 180, r = 5%, sigma = 20%, T = 1
+        let exact = 0.028_642_858_116_182_557;
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(180.0);
+        let df = (-0.05_f64).exp();
+
+        let shift = crate::mc::variance_reduction::strike_drift_shift(gbm, 180.0);
+        let mut pricer =
+            variance_reduced_pricer(VarianceReduction::default().with_drift_shift(shift));
+        let result = pricer.price_european(gbm, payoff, df);
+
+        let factor = result.variance_reduction_factor.unwrap();
+        assert!(factor > 10.0, "importance sampling factor {factor}");
+        assert!(
+            (result.price - exact).abs() < 4.0 * result.std_error,
+            "IS {} vs exact {exact} (se {})",
+            result.price,
+            result.std_error
+        );
+        assert!(result.std_error < 0.05 * exact);
+    }
+
+    #[test]
+    fn test_variance_reduction_greeks_use_common_random_numbers() {
+        let mut pricer = variance_reduced_pricer(
+            VarianceReduction::default()
+                .with_antithetic()
+                .with_control_variate(),
+        );
+        let df = (-0.05_f64).exp();
+        let result = pricer.price_with_greeks(
+            GbmParams::default(),
+            PayoffParams::call(100.0),
+            df,
+            &[Greek::Delta],
+        );
+        // Black-Scholes delta N(d1) = 0.6368
+        assert_relative_eq!(result.delta.unwrap(), 0.6368, epsilon = 0.02);
+    }
+
+    #[test]
+    fn test_price_with_delta() {
+        let mut pricer = create_test_pricer();
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(100.0);
+        let df = (-0.05_f64).exp();
+
+        let result = pricer.price_with_greeks(gbm, payoff, df, &[Greek::Delta]);
+
+        assert!(result.delta.is_some());
+        let delta = result.delta.unwrap();
+
+        // Delta of ATM call should be around 0.5-0.6
+        assert!(delta > 0.3 && delta < 0.8, "Delta = {}", delta);
+    }
+
+    #[test]
+    fn test_price_with_vega() {
+        let mut pricer = create_test_pricer();
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(100.0);
+        let df = (-0.05_f64).exp();
+
+        let result = pricer.price_with_greeks(gbm, payoff, df, &[Greek::Vega]);
+
+        assert!(result.vega.is_some());
+        let vega = result.vega.unwrap();
+
+        // Vega should be positive for options
+        assert!(vega > 0.0, "Vega = {}", vega);
+    }
+
+    #[test]
+    fn test_price_with_multiple_greeks() {
+        let mut pricer = create_test_pricer();
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(100.0);
+        let df = (-0.05_f64).exp();
+
+        let result =
+            pricer.price_with_greeks(gbm, payoff, df, &[Greek::Delta, Greek::Gamma, Greek::Vega]);
+
+        assert!(result.delta.is_some());
+        assert!(result.gamma.is_some());
+        assert!(result.vega.is_some());
+        assert!(result.theta.is_none());
+        assert!(result.rho.is_none());
+    }
+
+    #[test]
+    fn test_price_with_delta_ad() {
+        let mut pricer = create_test_pricer();
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(100.0);
+        let df = (-0.05_f64).exp();
+
+        let (price, delta) = pricer.price_with_delta_ad(gbm, payoff, df);
+
+        assert!(price > 0.0);
+        assert!(delta > 0.3 && delta < 0.8, "Delta = {}", delta);
+    }
+
+    #[test]
+    fn test_delta_ad_vs_bump() {
+        let config = MonteCarloConfig::builder()
             .n_paths(50_000)
             .n_steps(50)
             .seed(42)
@@ -1293,7 +2684,8 @@ mod tests {
 
         // Bump-and-revalue Delta
         pricer.reset_with_seed(42);
-        let delta_bump = pricer.compute_delta(gbm, payoff, df);
+        let (delta_bump, _) =
+            pricer.bumped_greek(Greek::Delta, gbm, GbmPayoff::European(payoff), df);
 
         // Should be within 10% of each other
         assert_relative_eq!(delta_ad, delta_bump, max_relative = 0.1);
@@ -1450,6 +2842,177 @@ mod tests {
         // Barrier option should be cheaper than vanilla option
     }
 
+    fn barrier_pricer(n_paths: usize, n_steps: usize) -> MonteCarloPricer {
+        let config = MonteCarloConfig::builder()
+            .n_paths(n_paths)
+            .n_steps(n_steps)
+            .seed(7)
+            .build()
+            .unwrap();
+        MonteCarloPricer::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_barrier_corrections_match_continuous_closed_form() {
+        use crate::analytical::up_out_call;
+        use crate::mc::{BarrierCorrection, BarrierMonitoring, BarrierOption};
+
+        let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+        let df = (-0.05_f64).exp();
+        let exact = up_out_call(100.0, 100.0, 125.0, 0.05, 0.0, 0.2, 1.0);
+        let price = |correction| {
+            let option = BarrierOption::knock_out(PayoffParams::call(100.0), None, Some(125.0))
+                .with_monitoring(BarrierMonitoring::Continuous(correction));
+            barrier_pricer(40_000, 25)
+                .price_barrier(gbm, &option, df)
+                .unwrap()
+        };
+
+        let raw = price(BarrierCorrection::None);
+        assert!(
+            raw.price > exact + 4.0 * raw.std_error,
+            "{raw:?} vs {exact}"
+        );
+
+        let bridge = price(BarrierCorrection::BrownianBridge);
+        assert!(
+            (bridge.price - exact).abs() < 3.5 * bridge.std_error,
+            "{bridge:?} vs {exact}"
+        );
+        let shifted = price(BarrierCorrection::ContinuityCorrection);
+        assert!(
+            (shifted.price - exact).abs() < 3.5 * shifted.std_error + 0.02,
+            "{shifted:?} vs {exact}"
+        );
+    }
+
+    #[test]
+    fn test_discrete_monitoring_schedules() {
+        use crate::analytical::{discrete_barrier_price, BarrierParams, BarrierType};
+        use crate::mc::{BarrierCorrection, BarrierMonitoring, BarrierOption, MonitoringSchedule};
+
+        let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+        let df = (-0.05_f64).exp();
+
+        // Daily closes on a coarser simulation grid
+        let daily = BarrierOption::knock_out(PayoffParams::call(100.0), None, Some(125.0))
+            .with_monitoring(BarrierMonitoring::Discrete(
+                MonitoringSchedule::daily(1.0, 252.0).unwrap(),
+            ));
+        let result = barrier_pricer(40_000, 25)
+            .price_barrier(gbm, &daily, df)
+            .unwrap();
+        let params = BarrierParams::new(
+            100.0,
+            100.0,
+            125.0,
+            0.05,
+            0.0,
+            0.2,
+            1.0,
+            BarrierType::up_out_call(),
+        );
+        let expected = discrete_barrier_price(&params, 1.0 / 252.0);
+        assert!(
+            (result.price - expected).abs() < 3.5 * result.std_error + 0.01,
+            "{result:?} vs {expected}"
+        );
+
+        // A schedule on the simulation steps is the plain step check
+        let monthly = daily.clone().with_monitoring(BarrierMonitoring::Discrete(
+            MonitoringSchedule::uniform(1.0, 12).unwrap(),
+        ));
+        let steps = daily.with_monitoring(BarrierMonitoring::Continuous(BarrierCorrection::None));
+        let a = barrier_pricer(5_000, 12)
+            .price_barrier(gbm, &monthly, df)
+            .unwrap();
+        let b = barrier_pricer(5_000, 12)
+            .price_barrier(gbm, &steps, df)
+            .unwrap();
+        assert_relative_eq!(a.price, b.price, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_double_barrier_parity_and_rebates() {
+        use crate::mc::{BarrierOption, RebateTiming};
+
+        let gbm = GbmParams::new(100.0, 0.05, 0.2, 1.0);
+        let df = (-0.05_f64).exp();
+        let call = PayoffParams::call(100.0);
+        let knock_out = BarrierOption::knock_out(call, Some(85.0), Some(120.0));
+        let knock_in = BarrierOption::knock_in(call, Some(85.0), Some(120.0));
+
+        let out = barrier_pricer(10_000, 50)
+            .price_barrier(gbm, &knock_out, df)
+            .unwrap();
+        let inn = barrier_pricer(10_000, 50)
+            .price_barrier(gbm, &knock_in, df)
+            .unwrap();
+        let vanilla = barrier_pricer(10_000, 50).price_european(gbm, call, df);
+        assert_relative_eq!(out.price + inn.price, vanilla.price, epsilon = 1e-10);
+
+        let at_hit = knock_out.clone().with_rebate(5.0, RebateTiming::AtHit);
+        let at_expiry = knock_out.with_rebate(5.0, RebateTiming::AtExpiry);
+        let at_hit = barrier_pricer(10_000, 50)
+            .price_barrier(gbm, &at_hit, df)
+            .unwrap();
+        let at_expiry = barrier_pricer(10_000, 50)
+            .price_barrier(gbm, &at_expiry, df)
+            .unwrap();
+        assert!(at_expiry.price > out.price);
+        assert!(at_hit.price > at_expiry.price);
+
+        let spot_outside = GbmParams::new(130.0, 0.05, 0.2, 1.0);
+        assert!(barrier_pricer(100, 10)
+            .price_barrier(spot_outside, &knock_in, df)
+            .is_err());
+    }
+
+    #[test]
+    fn test_touch_options_match_hitting_probability() {
+        use crate::analytical::asian::norm_cdf;
+        use crate::mc::{RebateTiming, TouchOption};
+
+        let (spot, rate, sigma, barrier) = (100.0_f64, 0.05, 0.2, 120.0_f64);
+        let gbm = GbmParams::new(spot, rate, sigma, 1.0);
+        let df = (-rate).exp();
+        let one_touch = TouchOption::one_touch(10.0, None, Some(barrier), RebateTiming::AtExpiry);
+        let no_touch = TouchOption::no_touch(10.0, None, Some(barrier));
+
+        // Probability that GBM hits the upper barrier within one year
+        let (b, mu) = ((barrier / spot).ln(), rate - 0.5 * sigma * sigma);
+        let hit = norm_cdf((mu - b) / sigma)
+            + (barrier / spot).powf(2.0 * mu / (sigma * sigma)) * norm_cdf((-b - mu) / sigma);
+
+        let touched = barrier_pricer(20_000, 50)
+            .price_touch(gbm, &one_touch, df)
+            .unwrap();
+        assert!(
+            (touched.price - 10.0 * df * hit).abs() < 3.5 * touched.std_error,
+            "{touched:?} vs {}",
+            10.0 * df * hit
+        );
+        let untouched = barrier_pricer(20_000, 50)
+            .price_touch(gbm, &no_touch, df)
+            .unwrap();
+        assert_relative_eq!(touched.price + untouched.price, 10.0 * df, epsilon = 1e-10);
+
+        // Paying at the hit is worth more with positive rates
+        let at_hit = TouchOption::one_touch(10.0, None, Some(barrier), RebateTiming::AtHit);
+        let at_hit = barrier_pricer(20_000, 50)
+            .price_touch(gbm, &at_hit, df)
+            .unwrap();
+        assert!(at_hit.price > touched.price);
+
+        let invalid = TouchOption {
+            payment: RebateTiming::AtHit,
+            ..no_touch
+        };
+        assert!(barrier_pricer(100, 10)
+            .price_touch(gbm, &invalid, df)
+            .is_err());
+    }
+
     #[test]
     fn test_price_lookback_fixed_call() {
         let config = MonteCarloConfig::builder()
@@ -1562,4 +3125,684 @@ mod tests {
         // Vega should be positive for options
         assert!(vega > 0.0, "Vega = {}", vega);
     }
+
+    #[test]
+    fn test_continuous_dividends_match_gbm_drift() {
+        let mut pricer = create_test_pricer();
+        let gbm = GbmParams {
+            spot: 100.0,
+            rate: 0.03,
+            volatility: 0.2,
+            maturity: 1.0,
+        };
+        let df = (-0.05_f64).exp();
+        let expected = pricer.price_european(gbm, PayoffParams::call(100.0), df);
+
+        pricer.reset();
+        let adjustment = DividendAdjustment::continuous(100.0, 0.05, 0.02, 1.0, 50).unwrap();
+        let result = pricer
+            .price_european_with_dividends(&adjustment, 0.2, PayoffParams::call(100.0), df)
+            .unwrap();
+        assert_relative_eq!(result.price, expected.price, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_dividends_grid_mismatch() {
+        let mut pricer = create_test_pricer();
+        let adjustment = DividendAdjustment::continuous(100.0, 0.05, 0.0, 1.0, 10).unwrap();
+        assert!(pricer
+            .price_european_with_dividends(&adjustment, 0.2, PayoffParams::call(100.0), 1.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_path_dependent_with_dividends() {
+        let mut pricer = create_test_pricer();
+        let df = (-0.05_f64).exp();
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-6);
+        let no_div = DividendAdjustment::continuous(100.0, 0.05, 0.0, 1.0, 50).unwrap();
+        let base = pricer
+            .price_path_dependent_with_dividends(&no_div, 0.2, payoff, df)
+            .unwrap();
+
+        pricer.reset();
+        let with_div = DividendAdjustment::continuous(100.0, 0.05, 0.04, 1.0, 50).unwrap();
+        let lower = pricer
+            .price_path_dependent_with_dividends(&with_div, 0.2, payoff, df)
+            .unwrap();
+        assert!(lower.price < base.price);
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    fn slv_model(mixing: f64, leverage: f64) -> pricer_models::models::slv::SlvModel {
+        use pricer_models::models::heston::HestonParams;
+        use pricer_models::models::slv::{LeverageFunction, SlvModel, SlvParams};
+
+        let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, 0.5, -0.7, 0.05, 1.0).unwrap();
+        let params = SlvParams::new(heston, 0.02, mixing).unwrap();
+        SlvModel::new(params, LeverageFunction::flat(leverage).unwrap()).unwrap()
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_slv_barrier_reduces_to_black_scholes() {
+        use crate::analytical::down_out_call;
+        use crate::mc::BarrierOption;
+
+        // Almost deterministic variance at v0 = θ: constant volatility 1.25 × 0.2
+        let model = slv_model(1e-3, 1.25);
+        let df = (-0.05_f64).exp();
+        let option = BarrierOption::knock_out(PayoffParams::call(100.0), Some(85.0), None);
+        let result = barrier_pricer(20_000, 50)
+            .price_slv_barrier(&model, 1.0, &option, df)
+            .unwrap();
+        let exact = down_out_call(100.0, 100.0, 85.0, 0.05, 0.02, 0.25, 1.0);
+        assert!(
+            (result.price - exact).abs() < 3.5 * result.std_error,
+            "{result:?} vs {exact}"
+        );
+
+        let spot_outside = BarrierOption::knock_out(PayoffParams::call(100.0), Some(105.0), None);
+        assert!(barrier_pricer(100, 10)
+            .price_slv_barrier(&model, 1.0, &spot_outside, df)
+            .is_err());
+        assert!(barrier_pricer(100, 10)
+            .price_slv_barrier(&model, 0.0, &option, df)
+            .is_err());
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_slv_touch_parity_and_mixing() {
+        use crate::mc::{RebateTiming, TouchOption};
+
+        let df = (-0.05_f64).exp();
+        let one_touch = TouchOption::one_touch(1.0, Some(80.0), None, RebateTiming::AtExpiry);
+        let no_touch = TouchOption::no_touch(1.0, Some(80.0), None);
+        let model = slv_model(0.8, 1.0);
+        let touched = barrier_pricer(10_000, 50)
+            .price_slv_touch(&model, 1.0, &one_touch, df)
+            .unwrap();
+        let untouched = barrier_pricer(10_000, 50)
+            .price_slv_touch(&model, 1.0, &no_touch, df)
+            .unwrap();
+        assert_relative_eq!(touched.price + untouched.price, df, epsilon = 1e-10);
+
+        // More vol of vol fattens the left tail with ρ < 0 and raises the
+        // down-touch probability at the same leverage (common random numbers)
+        let pure_local = barrier_pricer(10_000, 50)
+            .price_slv_touch(&slv_model(1e-3, 1.0), 1.0, &one_touch, df)
+            .unwrap();
+        assert!(
+            touched.price > pure_local.price + touched.std_error,
+            "{touched:?} vs {pure_local:?}"
+        );
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    fn heston_model(
+        xi: f64,
+        rho: f64,
+    ) -> (
+        pricer_models::models::model_enum::StochasticModelEnum<f64>,
+        pricer_models::models::model_enum::ModelParams<f64>,
+    ) {
+        use pricer_models::models::heston::HestonParams;
+        use pricer_models::models::model_enum::{ModelParams, StochasticModelEnum};
+
+        let heston = HestonParams::new(100.0, 0.04, 0.04, 1.5, xi, rho, 0.05, 1.0).unwrap();
+        (
+            StochasticModelEnum::heston(heston).unwrap(),
+            ModelParams::Heston(heston),
+        )
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_gbm_matches_gbm_pricer() {
+        use pricer_models::models::gbm::GBMParams;
+        use pricer_models::models::model_enum::{ModelParams, StochasticModelEnum};
+
+        let model = StochasticModelEnum::gbm();
+        let params = ModelParams::GBM(GBMParams::new(100.0, 0.05, 0.2).unwrap());
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-4);
+        let df = (-0.05_f64).exp();
+
+        let mut pricer = create_test_pricer();
+        let expected = pricer.price_path_dependent(GbmParams::default(), payoff, df);
+        pricer.reset();
+        let result = pricer
+            .price_model_path_dependent(&model, &params, 1.0, payoff, df)
+            .unwrap();
+
+        assert_relative_eq!(result.price, expected.price, max_relative = 1e-10);
+        assert_relative_eq!(result.std_error, expected.std_error, max_relative = 1e-8);
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_heston_low_vol_of_vol_matches_black_scholes() {
+        // theta = v0 and negligible vol-of-vol: Black-Scholes with sigma = 0.2
+        let exact = 10.450_583_572_185_565;
+        let (model, params) = heston_model(1e-3, -0.7);
+        let config = MonteCarloConfig::builder()
+            .n_paths(50_000)
+            .n_steps(20)
+            .seed(11)
+            .build()
+            .unwrap();
+        let mut pricer = MonteCarloPricer::new(config).unwrap();
+
+        let result = pricer
+            .price_model_european(
+                &model,
+                &params,
+                1.0,
+                PayoffParams::call(100.0),
+                (-0.05_f64).exp(),
+            )
+            .unwrap();
+        assert!(
+            (result.price - exact).abs() < 4.0 * result.std_error,
+            "Heston {} vs Black-Scholes {exact} (se {})",
+            result.price,
+            result.std_error
+        );
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_heston_matches_fourier_reference() {
+        use pricer_models::analytical::fourier::{CosPricer, FourierMarket, HestonCf};
+
+        let (model, params) = heston_model(0.6, -0.7);
+        let config = MonteCarloConfig::builder()
+            .n_paths(100_000)
+            .n_steps(50)
+            .seed(17)
+            .build()
+            .unwrap();
+        let mut pricer = MonteCarloPricer::new(config).unwrap();
+        let market = FourierMarket::new(100.0, 0.05, 0.0).unwrap();
+        let ModelParams::Heston(heston) = params else {
+            unreachable!()
+        };
+        let cf = HestonCf::from_params(&heston).unwrap();
+
+        for strike in [80.0, 100.0, 120.0] {
+            let exact = CosPricer::default()
+                .price(&cf, &market, strike, 1.0, true)
+                .unwrap();
+            pricer.reset();
+            let result = pricer
+                .price_model_european(
+                    &model,
+                    &params,
+                    1.0,
+                    PayoffParams::call(strike),
+                    (-0.05_f64).exp(),
+                )
+                .unwrap();
+            assert!(
+                (result.price - exact).abs() < 4.0 * result.std_error,
+                "K = {strike}: QE {} vs COS {exact} (se {})",
+                result.price,
+                result.std_error
+            );
+        }
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_heston_correlation_skews_puts() {
+        let config = MonteCarloConfig::builder()
+            .n_paths(20_000)
+            .n_steps(50)
+            .seed(3)
+            .build()
+            .unwrap();
+        let payoff = PayoffParams::put(80.0);
+        let df = (-0.05_f64).exp();
+
+        let put_price = |rho: f64| {
+            let (model, params) = heston_model(0.6, rho);
+            MonteCarloPricer::new(config.clone())
+                .unwrap()
+                .price_model_european(&model, &params, 1.0, payoff, df)
+                .unwrap()
+                .price
+        };
+        // Negative spot-vol correlation fattens the left tail
+        assert!(put_price(-0.7) > 1.2 * put_price(0.7));
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_path_dependent_greeks() {
+        let (model, params) = heston_model(0.3, -0.7);
+        let config = MonteCarloConfig::builder()
+            .n_paths(5_000)
+            .n_steps(20)
+            .seed(5)
+            .build()
+            .unwrap();
+        let mut pricer = MonteCarloPricer::new(config).unwrap();
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-4);
+
+        let result = pricer
+            .price_model_path_dependent_with_greeks(
+                &model,
+                &params,
+                1.0,
+                payoff,
+                (-0.05_f64).exp(),
+                &[
+                    Greek::Delta,
+                    Greek::Gamma,
+                    Greek::Vega,
+                    Greek::Theta,
+                    Greek::Rho,
+                ],
+            )
+            .unwrap();
+
+        let delta = result.delta.unwrap();
+        assert!(delta > 0.3 && delta < 0.9, "delta {delta}");
+        assert!(result.gamma.unwrap().is_finite());
+        assert!(result.vega.unwrap() > 0.0);
+        assert!(result.theta.unwrap().is_finite());
+        assert!(result.rho.unwrap() > 0.0);
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_quasi_random_sampling() {
+        let (model, params) = heston_model(0.3, -0.7);
+        let payoff = PayoffParams::call(100.0);
+        let df = (-0.05_f64).exp();
+        let builder = MonteCarloConfig::builder()
+            .n_paths(8192)
+            .n_steps(10)
+            .seed(9);
+
+        let prng = MonteCarloPricer::new(builder.clone().build().unwrap())
+            .unwrap()
+            .price_model_european(&model, &params, 1.0, payoff, df)
+            .unwrap();
+        let mut pricer = MonteCarloPricer::new(
+            builder
+                .sampling(SamplingMethod::QuasiRandom(QmcConfig::default()))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let qmc = pricer
+            .price_model_european(&model, &params, 1.0, payoff, df)
+            .unwrap();
+
+        assert!(qmc.std_error > 0.0 && qmc.std_error < prng.std_error);
+        assert!((qmc.price - prng.price).abs() < 4.0 * prng.std_error);
+
+        // Switching back to a single-factor simulation resizes the sampler
+        let gbm = pricer.price_european(GbmParams::default(), payoff, df);
+        assert!(gbm.std_error > 0.0);
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_model_rejects_mismatched_params() {
+        use pricer_models::models::model_enum::StochasticModelEnum;
+
+        let (_, heston_params) = heston_model(0.3, -0.7);
+        let mut pricer = create_test_pricer();
+        let result = pricer.price_model_european(
+            &StochasticModelEnum::gbm(),
+            &heston_params,
+            1.0,
+            PayoffParams::call(100.0),
+            0.95,
+        );
+        assert!(matches!(
+            result,
+            Err(ConfigError::InvalidParameter { name: "params", .. })
+        ));
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_equity_market_matches_escrowed_black_scholes() {
+        use pricer_core::market_data::curves::CurveEnum;
+        use pricer_core::market_data::equity::{DividendSchedule, EquityMarket};
+        use pricer_models::analytical::EquityBlackScholes;
+
+        let market = EquityMarket::new(100.0, CurveEnum::flat(0.05))
+            .unwrap()
+            .with_dividend_yield(0.01)
+            .with_borrow_cost(0.005)
+            .with_dividends(
+                DividendSchedule::new()
+                    .with_cash(0.3, 2.0)
+                    .unwrap()
+                    .with_proportional(0.7, 0.01)
+                    .unwrap(),
+            );
+        let config = MonteCarloConfig::builder()
+            .n_paths(50_000)
+            .n_steps(20)
+            .seed(7)
+            .build()
+            .unwrap();
+        let mut pricer = MonteCarloPricer::new(config).unwrap();
+        let analytic = EquityBlackScholes::new(&market, 0.25).unwrap();
+
+        for (payoff, exact) in [
+            (
+                PayoffParams::call(100.0),
+                analytic.price_call(100.0, 1.0).unwrap(),
+            ),
+            (
+                PayoffParams::put(95.0),
+                analytic.price_put(95.0, 1.0).unwrap(),
+            ),
+        ] {
+            let result = pricer
+                .price_european_with_equity_market(&market, 0.25, 1.0, payoff)
+                .unwrap();
+            assert!(
+                (result.price - exact).abs() < 4.0 * result.std_error,
+                "MC {} vs analytic {} (se {})",
+                result.price,
+                exact,
+                result.std_error
+            );
+        }
+    }
+
+    fn counter_based_pricer(vr: VarianceReduction) -> MonteCarloPricer {
+        let config = MonteCarloConfig::builder()
+            .n_paths(5000)
+            .n_steps(12)
+            .sampling(SamplingMethod::CounterBased)
+            .variance_reduction(vr)
+            .seed(7)
+            .build()
+            .unwrap();
+        MonteCarloPricer::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_parallel_pricing_independent_of_thread_count() {
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(100.0);
+        let df = (-0.05_f64).exp();
+
+        for vr in [
+            VarianceReduction::default(),
+            VarianceReduction::default()
+                .with_antithetic()
+                .with_control_variate(),
+            VarianceReduction::default().with_drift_shift(0.5),
+        ] {
+            let price_on = |threads| {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap();
+                pool.install(|| {
+                    counter_based_pricer(vr)
+                        .price_european_parallel(gbm, payoff, df)
+                        .unwrap()
+                })
+            };
+            let single = price_on(1);
+            let many = price_on(8);
+            assert_eq!(single.price.to_bits(), many.price.to_bits());
+            assert_eq!(single.std_error.to_bits(), many.std_error.to_bits());
+
+            // Bit-identical to the sequential counter-based path as well
+            let sequential = counter_based_pricer(vr).price_european(gbm, payoff, df);
+            assert_eq!(single.price.to_bits(), sequential.price.to_bits());
+            assert_eq!(single.std_error.to_bits(), sequential.std_error.to_bits());
+
+            // Black-Scholes: S = K = 100, r = 5%, sigma = 20%, T = 1
+            let exact = 10.450_583_572_185_565;
+            assert!(
+                (single.price - exact).abs() < 4.0 * single.std_error,
+                "{vr:?}: {} vs {exact} ± {}",
+                single.price,
+                single.std_error
+            );
+        }
+    }
+
+    /// Runs `price` on a dedicated rayon pool of `threads` threads.
+    fn on_threads<R: Send>(threads: usize, price: impl FnOnce() -> R + Send) -> R {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(price)
+    }
+
+    #[test]
+    fn test_parallel_path_dependent_matches_sequential() {
+        let gbm = GbmParams::default();
+        let df = (-0.05_f64).exp();
+
+        for (payoff, vr) in [
+            (
+                PathPayoffType::asian_arithmetic_call(100.0, 1e-6),
+                VarianceReduction::default(),
+            ),
+            (
+                PathPayoffType::asian_arithmetic_call(100.0, 1e-6),
+                VarianceReduction::default()
+                    .with_antithetic()
+                    .with_control_variate(),
+            ),
+            (
+                PathPayoffType::asian_arithmetic_put(100.0, 1e-6),
+                VarianceReduction::default().with_drift_shift(-0.3),
+            ),
+        ] {
+            let price_on = |threads| {
+                on_threads(threads, || {
+                    counter_based_pricer(vr)
+                        .price_path_dependent_parallel(gbm, payoff, df)
+                        .unwrap()
+                })
+            };
+            let single = price_on(1);
+            let many = price_on(8);
+            assert_eq!(single.price.to_bits(), many.price.to_bits());
+            assert_eq!(single.std_error.to_bits(), many.std_error.to_bits());
+
+            let sequential = counter_based_pricer(vr).price_path_dependent(gbm, payoff, df);
+            assert_eq!(single.price.to_bits(), sequential.price.to_bits());
+            assert_eq!(single.std_error.to_bits(), sequential.std_error.to_bits());
+        }
+    }
+
+    #[cfg(feature = "l1l2-integration")]
+    #[test]
+    fn test_parallel_model_pricing_matches_sequential() {
+        let (model, params) = heston_model(0.3, -0.7);
+        let df = (-0.05_f64).exp();
+        let call = PayoffParams::call(100.0);
+        let asian = PathPayoffType::asian_arithmetic_call(100.0, 1e-6);
+
+        for vr in [
+            VarianceReduction::default(),
+            VarianceReduction::default().with_antithetic(),
+        ] {
+            let european_on = |threads| {
+                on_threads(threads, || {
+                    counter_based_pricer(vr)
+                        .price_model_european_parallel(&model, &params, 1.0, call, df)
+                        .unwrap()
+                })
+            };
+            let single = european_on(1);
+            assert_eq!(single.price.to_bits(), european_on(8).price.to_bits());
+            let sequential = counter_based_pricer(vr)
+                .price_model_european(&model, &params, 1.0, call, df)
+                .unwrap();
+            assert_eq!(single.price.to_bits(), sequential.price.to_bits());
+            assert_eq!(single.std_error.to_bits(), sequential.std_error.to_bits());
+
+            let path_dependent_on = |threads| {
+                on_threads(threads, || {
+                    counter_based_pricer(vr)
+                        .price_model_path_dependent_parallel(&model, &params, 1.0, asian, df)
+                        .unwrap()
+                })
+            };
+            let single = path_dependent_on(1);
+            assert_eq!(single.price.to_bits(), path_dependent_on(8).price.to_bits());
+            let sequential = counter_based_pricer(vr)
+                .price_model_path_dependent(&model, &params, 1.0, asian, df)
+                .unwrap();
+            assert_eq!(single.price.to_bits(), sequential.price.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_parallel_pricing_requires_counter_based_sampling() {
+        let config = MonteCarloConfig::builder()
+            .n_paths(1000)
+            .n_steps(4)
+            .build()
+            .unwrap();
+        let mut pricer = MonteCarloPricer::new(config).unwrap();
+        let result =
+            pricer.price_european_parallel(GbmParams::default(), PayoffParams::call(100.0), 1.0);
+        assert!(matches!(
+            result,
+            Err(ConfigError::InvalidParameter {
+                name: "sampling",
+                ..
+            })
+        ));
+
+        // Counter-based streams restart on every call
+        let mut pricer = counter_based_pricer(VarianceReduction::default());
+        let first = pricer.price_european(GbmParams::default(), PayoffParams::put(100.0), 1.0);
+        let second = pricer.price_european(GbmParams::default(), PayoffParams::put(100.0), 1.0);
+        assert_eq!(first.price, second.price);
+    }
+
+    #[test]
+    fn test_greek_std_errors_cover_exact_greeks() {
+        // Black-Scholes: S = K = 100, r = 5%, sigma = 20%, T = 1
+        let exact_delta = 0.636_830_651_175_619;
+        let exact_vega = 37.524_034_690_857_4;
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(100.0);
+        let df = (-0.05_f64).exp();
+
+        let run = |n_paths| {
+            let config = MonteCarloConfig::builder()
+                .n_paths(n_paths)
+                .n_steps(10)
+                .seed(11)
+                .build()
+                .unwrap();
+            MonteCarloPricer::new(config).unwrap().price_with_greeks(
+                gbm,
+                payoff,
+                df,
+                &[Greek::Delta, Greek::Vega, Greek::Gamma],
+            )
+        };
+        let small = run(10_000);
+        let large = run(40_000);
+
+        let errors = large.greek_std_errors;
+        assert!(errors.theta.is_none() && errors.rho.is_none());
+        let delta_se = errors.get(Greek::Delta).unwrap();
+        let vega_se = errors.get(Greek::Vega).unwrap();
+        assert!(errors.gamma.unwrap() > 0.0);
+        assert!((large.delta.unwrap() - exact_delta).abs() < 4.0 * delta_se);
+        assert!((large.vega.unwrap() - exact_vega).abs() < 4.0 * vega_se);
+
+        // Standard errors shrink like 1/√n
+        let ratio = small.greek_std_errors.delta.unwrap() / delta_se;
+        assert!((ratio - 2.0).abs() < 0.2, "ratio {ratio}");
+    }
+
+    #[test]
+    fn test_adaptive_pricing_meets_target() {
+        let gbm = GbmParams::default();
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-6);
+        let df = (-0.05_f64).exp();
+        let mut pricer = variance_reduced_pricer(VarianceReduction::default());
+        let batch = pricer.config().n_paths();
+
+        let adaptive = AdaptiveConfig::absolute(0.04);
+        let result = pricer
+            .price_path_dependent_adaptive(gbm, payoff, df, &adaptive)
+            .unwrap();
+        let trace = result.convergence.as_ref().unwrap();
+
+        assert!(trace.converged);
+        assert!(trace.points.len() > 1);
+        assert!(result.std_error <= 0.04);
+        for (i, point) in trace.points.iter().enumerate() {
+            assert_eq!(point.n_paths, (i + 1) * batch);
+            let (lower, upper) = point.confidence_interval_95();
+            assert!(lower < point.price && point.price < upper);
+        }
+        let last = trace.points.last().unwrap();
+        assert_eq!(
+            (last.price, last.std_error),
+            (result.price, result.std_error)
+        );
+
+        // The previous batch did not meet the target
+        assert!(trace.points[trace.points.len() - 2].std_error > 0.04);
+
+        // Invalid targets and quasi-random sampling are rejected
+        assert!(pricer
+            .price_path_dependent_adaptive(gbm, payoff, df, &AdaptiveConfig::relative(-1.0))
+            .is_err());
+        let qmc_config = MonteCarloConfig::builder()
+            .n_paths(1024)
+            .n_steps(8)
+            .sampling(SamplingMethod::QuasiRandom(QmcConfig::default()))
+            .build()
+            .unwrap();
+        assert!(MonteCarloPricer::new(qmc_config)
+            .unwrap()
+            .price_path_dependent_adaptive(gbm, payoff, df, &adaptive)
+            .is_err());
+    }
+
+    #[test]
+    fn test_adaptive_counter_based_batches_continue_streams() {
+        let gbm = GbmParams::default();
+        let payoff = PayoffParams::call(100.0);
+        let batched = counter_based_pricer(VarianceReduction::default())
+            .price_european_adaptive(
+                gbm,
+                payoff,
+                1.0,
+                &AdaptiveConfig::absolute(1e-6).with_max_paths(10_000),
+            )
+            .unwrap();
+        assert_eq!(batched.convergence.as_ref().unwrap().n_paths(), 10_000);
+
+        // Two batches of 5000 are the first 10_000 counter-based paths
+        let config = MonteCarloConfig::builder()
+            .n_paths(10_000)
+            .n_steps(12)
+            .sampling(SamplingMethod::CounterBased)
+            .seed(7)
+            .build()
+            .unwrap();
+        let single = MonteCarloPricer::new(config)
+            .unwrap()
+            .price_european(gbm, payoff, 1.0);
+        assert_relative_eq!(batched.price, single.price, max_relative = 1e-14);
+        assert_relative_eq!(batched.std_error, single.std_error, max_relative = 1e-12);
+    }
 }
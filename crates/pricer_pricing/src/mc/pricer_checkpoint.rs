@@ -13,6 +13,10 @@
 //! 1. Checkpoints are restored to avoid storing all intermediate values
 //! 2. Forward computation is replayed from checkpoint to compute adjoints
 //!
+//! The reverse pass yields pathwise Delta, Vega and Rho, which
+//! [`CheckpointPricer::price_path_dependent_with_checkpoints`] reports
+//! alongside the price.
+//!
 //! # Memory Efficiency
 //!
 //! With checkpoints at interval `k` over `n` steps:
@@ -20,13 +24,19 @@
 //! - With checkpoints: O(n/k) memory for checkpoints + O(k) for replay
 //!
 //! Optimal checkpoint interval for √n checkpoints gives O(√n) memory.
+//!
+//! When a [`MemoryBudget`] is set, checkpoints that would exceed it are not
+//! saved, and the replay buffer is limited to the remaining budget (segments
+//! longer than that are recomputed from their checkpoint in pieces).
 
+use crate::checkpoint::CheckpointError;
 use crate::checkpoint::{
     CheckpointManager, CheckpointResult, CheckpointStrategy, MemoryBudget, SimulationState,
 };
+use crate::mc::pricer::qmc_sampler;
 use crate::mc::workspace_checkpoint::CheckpointWorkspace;
-use crate::mc::{GbmParams, MonteCarloConfig, PricingResult};
-use crate::path_dependent::{PathObserverState, PathPayoffType};
+use crate::mc::{GbmParams, MonteCarloConfig, PricingResult, QmcSampler};
+use crate::path_dependent::{PathObserverAdjoint, PathObserverState, PathPayoffType};
 use crate::rng::PricerRng;
 
 /// Configuration for checkpoint-enabled pricing.
@@ -59,6 +69,23 @@ impl CheckpointPricingConfig {
     }
 }
 
+/// Sensitivities produced by the checkpointed reverse pass.
+///
+/// See [`CheckpointPricer::reverse_pass`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CheckpointGreeks {
+    /// Delta: ∂V/∂S₀.
+    pub delta: f64,
+    /// Vega: ∂V/∂σ.
+    pub vega: f64,
+    /// Rho: ∂V/∂r, through both the drift and the discount factor.
+    pub rho: f64,
+    /// Number of time steps recomputed from checkpoints.
+    pub replayed_steps: usize,
+    /// Peak size of the replay buffer in bytes.
+    pub peak_replay_bytes: usize,
+}
+
 /// Checkpoint-enabled Monte Carlo pricing engine.
 ///
 /// This pricer extends the basic MC engine with checkpoint support for
@@ -91,11 +118,13 @@ impl CheckpointPricingConfig {
 ///
 /// let result = pricer.price_path_dependent_with_checkpoints(gbm, payoff, df);
 /// println!("Price: {:.4}", result.price);
+/// assert!(result.delta.is_some());
 /// ```
 pub struct CheckpointPricer {
     config: CheckpointPricingConfig,
     workspace: CheckpointWorkspace<f64>,
     rng: PricerRng,
+    qmc: Option<QmcSampler>,
     checkpoint_manager: CheckpointManager<f64>,
 }
 
@@ -118,6 +147,7 @@ impl CheckpointPricer {
 
         let workspace = CheckpointWorkspace::new(n_paths, n_steps);
         let rng = PricerRng::from_seed(seed);
+        let qmc = qmc_sampler(&config.mc_config, seed)?;
 
         let mut checkpoint_manager =
             CheckpointManager::new(config.checkpoint_strategy).with_total_steps(n_steps);
@@ -131,6 +161,7 @@ impl CheckpointPricer {
             config,
             workspace,
             rng,
+            qmc,
             checkpoint_manager,
         })
     }
@@ -155,15 +186,15 @@ impl CheckpointPricer {
 
     /// Resets the pricer state for a new simulation.
     pub fn reset(&mut self) {
-        self.workspace.reset_observers();
-        self.rng = PricerRng::from_seed(self.config.mc_config.seed().unwrap_or(0));
-        self.checkpoint_manager.clear();
+        self.reset_with_seed(self.config.mc_config.seed().unwrap_or(0));
     }
 
     /// Resets with a new seed.
     pub fn reset_with_seed(&mut self, seed: u64) {
         self.workspace.reset_observers();
         self.rng = PricerRng::from_seed(seed);
+        // The configuration was validated on construction
+        self.qmc = qmc_sampler(&self.config.mc_config, seed).expect("validated configuration");
         self.checkpoint_manager.clear();
     }
 
@@ -173,6 +204,7 @@ impl CheckpointPricer {
     /// 1. Generates GBM paths step-by-step
     /// 2. Saves checkpoints at configured intervals
     /// 3. Computes path-dependent payoffs
+    /// 4. Runs the reverse pass for Delta, Vega and Rho
     ///
     /// # Arguments
     ///
@@ -182,7 +214,7 @@ impl CheckpointPricer {
     ///
     /// # Returns
     ///
-    /// Pricing result with price and standard error.
+    /// Pricing result with price, standard error, and pathwise Greeks.
     pub fn price_path_dependent_with_checkpoints(
         &mut self,
         gbm: GbmParams,
@@ -202,7 +234,10 @@ impl CheckpointPricer {
         self.workspace.reset_observers();
 
         // Pre-generate all random numbers
-        self.rng.fill_normal(self.workspace.randoms_mut());
+        match self.qmc.as_mut() {
+            Some(sampler) => sampler.fill(self.workspace.randoms_mut(), n_paths, n_steps),
+            None => self.rng.fill_normal(self.workspace.randoms_mut()),
+        }
 
         // GBM parameters
         let dt = gbm.maturity / n_steps as f64;
@@ -223,7 +258,9 @@ impl CheckpointPricer {
         // Forward pass with checkpointing
         for step in 1..=n_steps {
             // Check if we should save a checkpoint BEFORE this step
-            if self.checkpoint_manager.should_checkpoint(step - 1) {
+            if self.checkpoint_manager.should_checkpoint(step - 1)
+                && self.checkpoint_fits_budget(n_paths)
+            {
                 // Save checkpoint state
                 let _ = self.save_checkpoint(step - 1, n_paths, n_steps);
             }
@@ -268,29 +305,60 @@ impl CheckpointPricer {
         }
 
         // Compute payoffs
-        let mut payoff_sum = 0.0;
-        let mut payoff_sum_sq = 0.0;
-
-        for path_idx in 0..n_paths {
-            let observer = self.workspace.observer(path_idx);
-            let payoff_value = payoff.compute(&[], observer);
-            payoff_sum += payoff_value;
-            payoff_sum_sq += payoff_value * payoff_value;
-        }
-
-        // Aggregate results
-        let mean = payoff_sum / n_paths as f64;
-        let variance = (payoff_sum_sq / n_paths as f64) - mean * mean;
-        let std_dev = variance.max(0.0).sqrt();
-        let std_error = std_dev / (n_paths as f64).sqrt();
+        let payoff_values: Vec<f64> = (0..n_paths)
+            .map(|path_idx| payoff.compute(&[], self.workspace.observer(path_idx)))
+            .collect();
+
+        // Aggregate results; QMC randomisations give their own error estimate
+        let mean = payoff_values.iter().sum::<f64>() / n_paths as f64;
+        let std_error = self
+            .qmc
+            .as_ref()
+            .and_then(|sampler| sampler.config().standard_error(&payoff_values))
+            .unwrap_or_else(|| {
+                let mean_sq = payoff_values.iter().map(|p| p * p).sum::<f64>() / n_paths as f64;
+                let variance = mean_sq - mean * mean;
+                variance.max(0.0).sqrt() / (n_paths as f64).sqrt()
+            });
+
+        let greeks = self.reverse_pass(gbm, payoff, discount_factor).ok();
 
         PricingResult {
             price: mean * discount_factor,
             std_error: std_error * discount_factor,
+            delta: greeks.map(|g| g.delta),
+            vega: greeks.map(|g| g.vega),
+            rho: greeks.map(|g| g.rho),
             ..Default::default()
         }
     }
 
+    /// Returns true if one more checkpoint fits in the memory budget.
+    fn checkpoint_fits_budget(&self, n_paths: usize) -> bool {
+        match self.checkpoint_manager.memory_budget() {
+            Some(budget) => {
+                // Keep room for the minimal two-row replay buffer
+                let row_size = n_paths * std::mem::size_of::<f64>();
+                let state_size = std::mem::size_of::<SimulationState<f64>>() + row_size;
+                budget.remaining(self.checkpoint_manager.memory_usage())
+                    >= state_size + 2 * row_size
+            }
+            None => true,
+        }
+    }
+
+    /// Number of steps the replay buffer may hold beyond its first row.
+    fn replay_window(&self, n_paths: usize) -> usize {
+        let bytes_per_step = n_paths.max(1) * std::mem::size_of::<f64>();
+        match self.checkpoint_manager.memory_budget() {
+            Some(budget) => (budget.remaining(self.checkpoint_manager.memory_usage())
+                / bytes_per_step)
+                .saturating_sub(1)
+                .max(1),
+            None => usize::MAX,
+        }
+    }
+
     /// Saves a checkpoint at the given step.
     fn save_checkpoint(
         &mut self,
@@ -363,11 +431,10 @@ impl CheckpointPricer {
         (result_with.price - result_without.price).abs() < tolerance
     }
 
-    /// Forward pass for gradient computation (placeholder for Enzyme integration).
+    /// Forward pass for gradient computation.
     ///
-    /// In Phase 5+, this will be the entry point for Enzyme's reverse-mode AD.
-    /// The checkpoints saved during forward pass will be used to replay
-    /// forward computation during the reverse pass.
+    /// The checkpoints saved during the forward pass are used by
+    /// [`reverse_pass`](Self::reverse_pass) to replay forward computation.
     ///
     /// # Arguments
     ///
@@ -388,34 +455,139 @@ impl CheckpointPricer {
         (result.price, self.checkpoint_count())
     }
 
-    /// Reverse pass placeholder for gradient computation.
+    /// Reverse pass: pathwise adjoints from the saved checkpoints.
     ///
-    /// This is a placeholder for the reverse-mode AD pass that will be
-    /// integrated with Enzyme in Phase 5+.
+    /// Must follow a forward pass with the same `gbm` and `payoff`; it reads
+    /// the random draws and final observer states left in the workspace.
+    /// The time grid is swept backwards in segments. For each segment the
+    /// nearest checkpoint at or before its start is restored, the GBM steps
+    /// are replayed into a buffer, and the price adjoints are propagated
+    /// through them. Only the current segment is held in memory, and its
+    /// length is capped by the remaining [`MemoryBudget`] if one is set.
     ///
-    /// The reverse pass will:
-    /// 1. Start from the terminal payoff adjoint
-    /// 2. For each segment between checkpoints (in reverse order):
-    ///    - Restore state from checkpoint
-    ///    - Replay forward computation
-    ///    - Accumulate adjoints using Enzyme
+    /// Rho includes the discounting term and assumes
+    /// `discount_factor = exp(-rate * maturity)`.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Placeholder gradient (0.0 until Enzyme integration).
-    pub fn reverse_pass_placeholder(&self) -> f64 {
-        // Placeholder: actual implementation requires Enzyme #[autodiff]
-        // This demonstrates the checkpoint restoration pattern:
-        //
-        // for step in (0..n_steps).rev() {
-        //     if let Some(checkpoint_step) = self.checkpoint_manager.nearest_checkpoint(step) {
-        //         let state = self.checkpoint_manager.restore_state(checkpoint_step)?;
-        //         // Replay forward from checkpoint_step to step
-        //         // Accumulate adjoints
-        //     }
-        // }
-
-        0.0 // Placeholder return
+    /// Returns `CheckpointError::InvalidState` if no forward pass has been run.
+    pub fn reverse_pass(
+        &self,
+        gbm: GbmParams,
+        payoff: PathPayoffType<f64>,
+        discount_factor: f64,
+    ) -> CheckpointResult<CheckpointGreeks> {
+        let n_paths = self.config.mc_config.n_paths();
+        let n_steps = self.config.mc_config.n_steps();
+
+        if n_paths == 0 || self.workspace.observer(0).count() != n_steps + 1 {
+            return Err(CheckpointError::InvalidState {
+                message: "reverse pass requires a completed forward pass".to_string(),
+            });
+        }
+
+        let dt = gbm.maturity / n_steps as f64;
+        let sqrt_dt = dt.sqrt();
+        let drift = (gbm.rate - 0.5 * gbm.volatility * gbm.volatility) * dt;
+        let vol_sqrt_dt = gbm.volatility * sqrt_dt;
+        let randoms = self.workspace.randoms();
+
+        // Payoff adjoints with respect to each path's observer statistics
+        let observer_adjoints: Vec<PathObserverAdjoint<f64>> = (0..n_paths)
+            .map(|path_idx| payoff.observer_adjoint(self.workspace.observer(path_idx)))
+            .collect();
+
+        // Adjoint of the current price on each path, carried across segments
+        let mut price_adjoints = vec![0.0; n_paths];
+        let mut rate_adjoint = 0.0;
+        let mut vol_adjoint = 0.0;
+
+        let window = self.replay_window(n_paths);
+        let mut segment: Vec<f64> = Vec::new();
+        let mut replayed_steps = 0;
+        let mut peak_replay_bytes = 0;
+        let mut end = n_steps;
+
+        while end > 0 {
+            // Restore from the nearest checkpoint (or the initial spot)
+            segment.clear();
+            let checkpoint_step = match self.checkpoint_manager.nearest_checkpoint(end - 1) {
+                Some(step) => {
+                    let state = self.checkpoint_manager.restore_state(step)?;
+                    segment.extend_from_slice(&state.current_prices);
+                    step
+                }
+                None => {
+                    segment.resize(n_paths, gbm.spot);
+                    0
+                }
+            };
+            let start = checkpoint_step.max(end.saturating_sub(window));
+
+            // Advance to the segment start without storing intermediate steps
+            for step in checkpoint_step + 1..=start {
+                for (path_idx, price) in segment.iter_mut().enumerate() {
+                    let random = randoms[path_idx * n_steps + step - 1];
+                    *price *= (drift + vol_sqrt_dt * random).exp();
+                }
+            }
+
+            // Replay the segment, keeping every step
+            for step in start + 1..=end {
+                let prev_row = (step - start - 1) * n_paths;
+                for path_idx in 0..n_paths {
+                    let random = randoms[path_idx * n_steps + step - 1];
+                    let price = segment[prev_row + path_idx] * (drift + vol_sqrt_dt * random).exp();
+                    segment.push(price);
+                }
+            }
+            replayed_steps += end - checkpoint_step;
+            peak_replay_bytes = peak_replay_bytes.max(segment.len() * std::mem::size_of::<f64>());
+
+            // Backward sweep: S_k = S_{k-1} exp((r - σ²/2) dt + σ √dt Z_k)
+            for step in (start + 1..=end).rev() {
+                let row = (step - start) * n_paths;
+                for path_idx in 0..n_paths {
+                    let price = segment[row + path_idx];
+                    let prev_price = segment[row - n_paths + path_idx];
+                    let random = randoms[path_idx * n_steps + step - 1];
+
+                    let adjoint = price_adjoints[path_idx]
+                        + observer_adjoints[path_idx].price_adjoint(
+                            self.workspace.observer(path_idx),
+                            price,
+                            step == n_steps,
+                        );
+
+                    rate_adjoint += adjoint * price * dt;
+                    vol_adjoint += adjoint * price * (sqrt_dt * random - gbm.volatility * dt);
+                    price_adjoints[path_idx] = adjoint * price / prev_price;
+                }
+            }
+
+            end = start;
+        }
+
+        // Initial observation and payoff mean
+        let mut spot_adjoint = 0.0;
+        let mut payoff_sum = 0.0;
+        for (path_idx, observer_adjoint) in observer_adjoints.iter().enumerate() {
+            let observer = self.workspace.observer(path_idx);
+            spot_adjoint += price_adjoints[path_idx]
+                + observer_adjoint.price_adjoint(observer, gbm.spot, n_steps == 0);
+            payoff_sum += payoff.compute(&[], observer);
+        }
+
+        let scale = discount_factor / n_paths as f64;
+        let price = payoff_sum * scale;
+
+        Ok(CheckpointGreeks {
+            delta: spot_adjoint * scale,
+            vega: vol_adjoint * scale,
+            rho: rate_adjoint * scale - gbm.maturity * price,
+            replayed_steps,
+            peak_replay_bytes,
+        })
     }
 }
 
@@ -1087,4 +1259,175 @@ mod tests {
             "No-checkpoint strategy should have 0 memory usage"
         );
     }
+
+    // ========================================================================
+    // Reverse Pass Tests
+    // ========================================================================
+
+    fn bumped_price(
+        config: &CheckpointPricingConfig,
+        gbm: GbmParams,
+        payoff: PathPayoffType<f64>,
+    ) -> f64 {
+        let mut pricer = CheckpointPricer::new(config.clone()).unwrap();
+        let df = (-gbm.rate * gbm.maturity).exp();
+        pricer
+            .price_path_dependent_with_checkpoints(gbm, payoff, df)
+            .price
+    }
+
+    fn assert_greeks_match_bumps(payoff: PathPayoffType<f64>) {
+        let config = create_test_config();
+        let gbm = GbmParams::default();
+        let df = (-gbm.rate * gbm.maturity).exp();
+
+        let mut pricer = CheckpointPricer::new(config.clone()).unwrap();
+        let result = pricer.price_path_dependent_with_checkpoints(gbm, payoff, df);
+
+        let h = 1e-6;
+        let delta_fd = (bumped_price(
+            &config,
+            GbmParams {
+                spot: gbm.spot + h,
+                ..gbm
+            },
+            payoff,
+        ) - bumped_price(
+            &config,
+            GbmParams {
+                spot: gbm.spot - h,
+                ..gbm
+            },
+            payoff,
+        )) / (2.0 * h);
+        let vega_fd = (bumped_price(
+            &config,
+            GbmParams {
+                volatility: gbm.volatility + h,
+                ..gbm
+            },
+            payoff,
+        ) - bumped_price(
+            &config,
+            GbmParams {
+                volatility: gbm.volatility - h,
+                ..gbm
+            },
+            payoff,
+        )) / (2.0 * h);
+        let rho_fd = (bumped_price(
+            &config,
+            GbmParams {
+                rate: gbm.rate + h,
+                ..gbm
+            },
+            payoff,
+        ) - bumped_price(
+            &config,
+            GbmParams {
+                rate: gbm.rate - h,
+                ..gbm
+            },
+            payoff,
+        )) / (2.0 * h);
+
+        assert_relative_eq!(result.delta.unwrap(), delta_fd, max_relative = 1e-3);
+        assert_relative_eq!(result.vega.unwrap(), vega_fd, max_relative = 1e-3);
+        assert_relative_eq!(result.rho.unwrap(), rho_fd, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn test_reverse_pass_asian_matches_bump_and_revalue() {
+        assert_greeks_match_bumps(PathPayoffType::asian_arithmetic_call(100.0, 1e-6));
+    }
+
+    #[test]
+    fn test_reverse_pass_geometric_asian_put_matches_bump_and_revalue() {
+        assert_greeks_match_bumps(PathPayoffType::asian_geometric_put(100.0, 1e-6));
+    }
+
+    #[test]
+    fn test_reverse_pass_lookback_matches_bump_and_revalue() {
+        assert_greeks_match_bumps(PathPayoffType::lookback_floating_call(1e-6));
+    }
+
+    #[test]
+    fn test_reverse_pass_independent_of_strategy() {
+        let mc_config = MonteCarloConfig::builder()
+            .n_paths(500)
+            .n_steps(60)
+            .seed(7)
+            .build()
+            .unwrap();
+        let gbm = GbmParams::default();
+        let payoff = PathPayoffType::lookback_fixed_call(100.0, 1e-6);
+        let df = (-0.05_f64).exp();
+
+        let greeks_for = |strategy: CheckpointStrategy| {
+            let config = CheckpointPricingConfig::new(mc_config.clone(), strategy);
+            let mut pricer = CheckpointPricer::new(config).unwrap();
+            let _ = pricer.price_path_dependent_with_checkpoints(gbm, payoff, df);
+            pricer.reverse_pass(gbm, payoff, df).unwrap()
+        };
+
+        let reference = greeks_for(CheckpointStrategy::None);
+        for strategy in [
+            CheckpointStrategy::Uniform { interval: 7 },
+            CheckpointStrategy::Logarithmic { base_interval: 3 },
+            CheckpointStrategy::Binomial { memory_slots: 8 },
+        ] {
+            let greeks = greeks_for(strategy);
+            assert_relative_eq!(greeks.delta, reference.delta, max_relative = 1e-12);
+            assert_relative_eq!(greeks.vega, reference.vega, max_relative = 1e-12);
+            assert_relative_eq!(greeks.rho, reference.rho, max_relative = 1e-12);
+            assert!(greeks.peak_replay_bytes < reference.peak_replay_bytes);
+        }
+    }
+
+    #[test]
+    fn test_reverse_pass_respects_memory_budget() {
+        let mc_config = MonteCarloConfig::builder()
+            .n_paths(200)
+            .n_steps(50)
+            .seed(42)
+            .build()
+            .unwrap();
+        let gbm = GbmParams::default();
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-6);
+        let df = (-0.05_f64).exp();
+        let budget_bytes = 12_000;
+
+        let unbounded = CheckpointPricingConfig::new(
+            mc_config.clone(),
+            CheckpointStrategy::Uniform { interval: 5 },
+        );
+        let mut pricer = CheckpointPricer::new(unbounded).unwrap();
+        let reference = pricer.price_path_dependent_with_checkpoints(gbm, payoff, df);
+
+        let bounded =
+            CheckpointPricingConfig::new(mc_config, CheckpointStrategy::Uniform { interval: 5 })
+                .with_memory_budget(MemoryBudget::new(budget_bytes));
+        let mut pricer = CheckpointPricer::new(bounded).unwrap();
+        let result = pricer.price_path_dependent_with_checkpoints(gbm, payoff, df);
+        let greeks = pricer.reverse_pass(gbm, payoff, df).unwrap();
+
+        assert!(pricer.checkpoint_count() < 10);
+        assert!(pricer.checkpoint_memory_usage() + greeks.peak_replay_bytes <= budget_bytes);
+        assert!(greeks.replayed_steps > 50);
+        assert_eq!(result.price, reference.price);
+        assert_relative_eq!(greeks.delta, reference.delta.unwrap(), max_relative = 1e-12);
+        assert_relative_eq!(greeks.vega, reference.vega.unwrap(), max_relative = 1e-12);
+        assert_relative_eq!(greeks.rho, reference.rho.unwrap(), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_reverse_pass_requires_forward_pass() {
+        let pricer = CheckpointPricer::new(create_test_config()).unwrap();
+        let payoff = PathPayoffType::asian_arithmetic_call(100.0, 1e-6);
+
+        assert!(matches!(
+            pricer.reverse_pass(GbmParams::default(), payoff, 0.95),
+            Err(CheckpointError::InvalidState { .. })
+        ));
+    }
 }
@@ -0,0 +1,301 @@
+//! Quasi-Monte Carlo sampling for the Monte Carlo pricers.
+//!
+//! [`QmcConfig`] selects the Sobol randomisation, the Brownian path
+//! construction and the number of independent randomisations. With `R`
+//! randomisations the paths are split into `R` equal blocks, each driven by
+//! an independently scrambled copy of the same Sobol point set, and the
+//! standard error is estimated from the spread of the block means.
+
+use std::ops::Range;
+
+use super::path_construction::{BrownianConstruction, PathConstruction};
+use crate::rng::{LowDiscrepancySequence, Scrambling, SobolError, SobolSequence};
+
+/// Quasi-Monte Carlo sampling configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::mc::{PathConstruction, QmcConfig};
+/// use pricer_pricing::rng::Scrambling;
+///
+/// let qmc = QmcConfig::new(Scrambling::Owen, PathConstruction::BrownianBridge)
+///     .with_randomisations(16);
+/// assert_eq!(qmc.randomisations, 16);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QmcConfig {
+    /// Randomisation of the Sobol points.
+    pub scrambling: Scrambling,
+    /// Mapping from Sobol coordinates to Brownian increments.
+    pub construction: PathConstruction,
+    /// Number of independent randomisations used for the error estimate.
+    pub randomisations: usize,
+}
+
+impl Default for QmcConfig {
+    /// Owen-scrambled Sobol with a Brownian bridge and 8 randomisations.
+    fn default() -> Self {
+        Self {
+            scrambling: Scrambling::Owen,
+            construction: PathConstruction::BrownianBridge,
+            randomisations: 8,
+        }
+    }
+}
+
+impl QmcConfig {
+    /// Creates a configuration with a single randomisation.
+    ///
+    /// # Arguments
+    ///
+    /// * `scrambling` - Randomisation of the Sobol points
+    /// * `construction` - Brownian path construction
+    #[inline]
+    pub fn new(scrambling: Scrambling, construction: PathConstruction) -> Self {
+        Self {
+            scrambling,
+            construction,
+            randomisations: 1,
+        }
+    }
+
+    /// Sets the number of independent randomisations.
+    #[inline]
+    pub fn with_randomisations(mut self, randomisations: usize) -> Self {
+        self.randomisations = randomisations;
+        self
+    }
+
+    /// Returns the paths belonging to randomisation `block`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_paths` - Total number of paths
+    /// * `block` - Randomisation index in `0..randomisations`
+    #[inline]
+    pub fn block_range(&self, n_paths: usize, block: usize) -> Range<usize> {
+        let r = self.randomisations.max(1);
+        (block * n_paths / r)..((block + 1) * n_paths / r)
+    }
+
+    /// Estimates the standard error of the mean of `values` (one per path)
+    /// from the spread of the randomisation block means.
+    ///
+    /// Returns `None` with fewer than two randomisations.
+    pub fn standard_error(&self, values: &[f64]) -> Option<f64> {
+        let r = self.randomisations;
+        if r < 2 {
+            return None;
+        }
+
+        let block_means: Vec<f64> = (0..r)
+            .map(|block| {
+                let range = self.block_range(values.len(), block);
+                let len = range.len().max(1) as f64;
+                values[range].iter().sum::<f64>() / len
+            })
+            .collect();
+        let mean = block_means.iter().sum::<f64>() / r as f64;
+        let variance = block_means.iter().map(|m| (m - mean).powi(2)).sum::<f64>() / (r - 1) as f64;
+        Some((variance / r as f64).sqrt())
+    }
+}
+
+/// Fills path-major normal buffers from randomised Sobol points.
+///
+/// Each path consumes one Sobol point of dimension `n_steps × n_factors`,
+/// mapped to normals and passed through the configured
+/// [`BrownianConstruction`] factor by factor. Sobol coordinates are
+/// interleaved across factors, so the leading coordinates drive the most
+/// important direction of every factor. Successive calls continue the
+/// sequences, mirroring successive draws from a pseudo-random generator.
+#[derive(Clone, Debug)]
+pub struct QmcSampler {
+    config: QmcConfig,
+    sequences: Vec<SobolSequence>,
+    construction: BrownianConstruction,
+    n_factors: usize,
+    normals: Vec<f64>,
+    factor_normals: Vec<f64>,
+    factor_increments: Vec<f64>,
+}
+
+impl QmcSampler {
+    /// Creates a sampler for paths of `n_steps` steps.
+    ///
+    /// Unscrambled sequences skip the origin, which maps to infinite normals.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - QMC configuration
+    /// * `n_steps` - Number of time steps (Sobol dimension)
+    /// * `seed` - Seed from which the randomisation seeds are derived
+    ///
+    /// # Errors
+    ///
+    /// Returns `SobolError` if `n_steps` is not a valid Sobol dimension.
+    pub fn new(config: QmcConfig, n_steps: usize, seed: u64) -> Result<Self, SobolError> {
+        Self::with_factors(config, n_steps, 1, seed)
+    }
+
+    /// Creates a sampler for paths driven by `n_factors` Brownian motions.
+    ///
+    /// Each row of the filled buffer holds `n_steps × n_factors` values
+    /// ordered step by step, `row[step * n_factors + factor]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - QMC configuration
+    /// * `n_steps` - Number of time steps
+    /// * `n_factors` - Number of Brownian factors per step
+    /// * `seed` - Seed from which the randomisation seeds are derived
+    ///
+    /// # Errors
+    ///
+    /// Returns `SobolError` if `n_steps × n_factors` is not a valid Sobol
+    /// dimension.
+    pub fn with_factors(
+        config: QmcConfig,
+        n_steps: usize,
+        n_factors: usize,
+        seed: u64,
+    ) -> Result<Self, SobolError> {
+        let dimension = n_steps * n_factors;
+        let sequences = (0..config.randomisations.max(1) as u64)
+            .map(|block| {
+                let block_seed = seed ^ block.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+                let mut sequence =
+                    SobolSequence::with_scrambling(dimension, config.scrambling, block_seed)?;
+                if config.scrambling == Scrambling::None {
+                    sequence.skip(1);
+                }
+                Ok(sequence)
+            })
+            .collect::<Result<Vec<_>, SobolError>>()?;
+
+        Ok(Self {
+            config,
+            sequences,
+            construction: BrownianConstruction::new(config.construction, n_steps),
+            n_factors,
+            normals: vec![0.0; dimension],
+            factor_normals: vec![0.0; n_steps],
+            factor_increments: vec![0.0; n_steps],
+        })
+    }
+
+    /// Returns the configuration.
+    #[inline]
+    pub fn config(&self) -> &QmcConfig {
+        &self.config
+    }
+
+    /// Returns the number of Brownian factors per step.
+    #[inline]
+    pub fn n_factors(&self) -> usize {
+        self.n_factors
+    }
+
+    /// Fills `randoms` (`n_paths × n_steps × n_factors`, path-major) with
+    /// standardised Brownian increments.
+    ///
+    /// # Arguments
+    ///
+    /// * `randoms` - Output buffer
+    /// * `n_paths` - Number of paths
+    /// * `n_steps` - Number of time steps per path
+    pub fn fill(&mut self, randoms: &mut [f64], n_paths: usize, n_steps: usize) {
+        let d = self.n_factors;
+        let row_len = n_steps * d;
+
+        for (block, sequence) in self.sequences.iter_mut().enumerate() {
+            for path_idx in self.config.block_range(n_paths, block) {
+                sequence.next_normal_point(&mut self.normals);
+                let row = &mut randoms[path_idx * row_len..(path_idx + 1) * row_len];
+                if d == 1 {
+                    self.construction.transform(&self.normals, row);
+                    continue;
+                }
+
+                for factor in 0..d {
+                    for (k, z) in self.factor_normals.iter_mut().enumerate() {
+                        *z = self.normals[k * d + factor];
+                    }
+                    self.construction
+                        .transform(&self.factor_normals, &mut self.factor_increments);
+                    for (k, &dw) in self.factor_increments.iter().enumerate() {
+                        row[k * d + factor] = dw;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_ranges_partition_paths() {
+        let qmc = QmcConfig::default().with_randomisations(3);
+        let ranges: Vec<_> = (0..3).map(|b| qmc.block_range(10, b)).collect();
+        assert_eq!(ranges, vec![0..3, 3..6, 6..10]);
+    }
+
+    #[test]
+    fn test_standard_error_from_block_means() {
+        let qmc = QmcConfig::default().with_randomisations(2);
+        let values = [1.0, 1.0, 3.0, 3.0];
+        // Block means 1 and 3: variance 2, standard error 1
+        assert!((qmc.standard_error(&values).unwrap() - 1.0).abs() < 1e-12);
+        assert!(QmcConfig::default()
+            .with_randomisations(1)
+            .standard_error(&values)
+            .is_none());
+    }
+
+    #[test]
+    fn test_sampler_fills_normal_increments() {
+        let n_paths = 1024;
+        let n_steps = 8;
+        let mut sampler = QmcSampler::new(QmcConfig::default(), n_steps, 42).unwrap();
+        let mut randoms = vec![0.0; n_paths * n_steps];
+        sampler.fill(&mut randoms, n_paths, n_steps);
+
+        for step in 0..n_steps {
+            let column: Vec<f64> = (0..n_paths).map(|p| randoms[p * n_steps + step]).collect();
+            let mean = column.iter().sum::<f64>() / n_paths as f64;
+            let var = column.iter().map(|z| z * z).sum::<f64>() / n_paths as f64;
+            assert!(mean.abs() < 0.02, "step {step}: mean {mean}");
+            assert!((var - 1.0).abs() < 0.05, "step {step}: var {var}");
+        }
+
+        // Two factors: each factor's increments are standard normal and the
+        // factors are uncorrelated
+        let mut sampler = QmcSampler::with_factors(QmcConfig::default(), n_steps, 2, 42).unwrap();
+        let mut randoms = vec![0.0; n_paths * n_steps * 2];
+        sampler.fill(&mut randoms, n_paths, n_steps);
+        let (mut var0, mut var1, mut cov) = (0.0, 0.0, 0.0);
+        for row in randoms.chunks(2 * n_steps) {
+            for step in 0..n_steps {
+                let (a, b) = (row[2 * step], row[2 * step + 1]);
+                var0 += a * a;
+                var1 += b * b;
+                cov += a * b;
+            }
+        }
+        let n = (n_paths * n_steps) as f64;
+        assert!((var0 / n - 1.0).abs() < 0.05);
+        assert!((var1 / n - 1.0).abs() < 0.05);
+        assert!((cov / n).abs() < 0.05);
+
+        // Unscrambled sampling never produces infinite normals
+        let mut randoms = vec![0.0; n_paths * n_steps];
+        let unscrambled = QmcConfig::new(Scrambling::None, PathConstruction::Incremental);
+        let mut sampler = QmcSampler::new(unscrambled, n_steps, 0).unwrap();
+        sampler.fill(&mut randoms, n_paths, n_steps);
+        assert!(randoms.iter().all(|z| z.is_finite()));
+    }
+}
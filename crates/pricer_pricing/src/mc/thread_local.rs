@@ -7,8 +7,8 @@
 //!
 //! Two patterns are provided:
 //!
-//! 1. **ThreadLocalWorkspacePool**: A pool that lazily creates workspaces
-//!    per thread using Rayon's thread-local storage.
+//! 1. **ThreadLocalWorkspacePool**: A pool that lazily creates one workspace
+//!    per Rayon worker thread and reuses it across calls.
 //!
 //! 2. **WorkspaceFactory**: A factory trait for creating workspaces on demand,
 //!    allowing custom initialization.
@@ -40,24 +40,47 @@
 //! ```
 
 use num_traits::Float;
-use std::cell::RefCell;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
+use super::workspace::PathWorkspace;
 use super::workspace_checkpoint::CheckpointWorkspace;
 
+/// Workspace that a [`ThreadLocalWorkspacePool`] can create on demand.
+pub trait PooledWorkspace: Send {
+    /// Creates a workspace with the given path and step capacity.
+    fn with_capacity(n_paths: usize, n_steps: usize) -> Self;
+}
+
+impl<T: Float + Send> PooledWorkspace for CheckpointWorkspace<T> {
+    fn with_capacity(n_paths: usize, n_steps: usize) -> Self {
+        CheckpointWorkspace::new(n_paths, n_steps)
+    }
+}
+
+impl PooledWorkspace for PathWorkspace {
+    fn with_capacity(n_paths: usize, n_steps: usize) -> Self {
+        PathWorkspace::new(n_paths, n_steps)
+    }
+}
+
 /// Thread-local workspace pool for parallel Monte Carlo simulation.
 ///
-/// This pool manages per-thread workspaces using Rayon's thread-local storage,
-/// ensuring no memory contention between threads during parallel simulation.
+/// This pool keeps one workspace per Rayon worker thread, created lazily on
+/// the thread's first access and reused by every later call on that thread,
+/// so parallel simulations allocate once per thread rather than per task.
 ///
 /// # Type Parameters
 ///
 /// * `T` - Floating-point type (e.g., `f64`, `f32`)
+/// * `W` - Workspace type, [`CheckpointWorkspace<T>`] by default
 ///
 /// # Thread Safety
 ///
-/// The pool itself is `Send + Sync`, but individual workspaces are accessed
-/// thread-locally without synchronization overhead.
+/// Each worker thread owns one slot, so slots are locked without
+/// contention. A call that cannot take its slot (a thread outside the pool
+/// the slots were sized for sharing it, or a nested call) runs on a
+/// temporary workspace instead.
 ///
 /// # Example
 ///
@@ -71,20 +94,27 @@ use super::workspace_checkpoint::CheckpointWorkspace;
 ///     ws.observer_mut(0).observe(100.0);
 /// });
 /// ```
-pub struct ThreadLocalWorkspacePool<T: Float + Send + Sync> {
+pub struct ThreadLocalWorkspacePool<
+    T: Float + Send + Sync,
+    W: PooledWorkspace = CheckpointWorkspace<T>,
+> {
     /// Default path capacity for new workspaces.
     default_paths: usize,
     /// Default step capacity for new workspaces.
     default_steps: usize,
+    /// One workspace per Rayon worker thread, plus one for other threads.
+    slots: Vec<Mutex<Option<W>>>,
     /// Counter for number of workspaces created.
     workspace_count: AtomicUsize,
     /// Phantom data for type parameter.
     _marker: std::marker::PhantomData<T>,
 }
 
-impl<T: Float + Send + Sync + 'static> ThreadLocalWorkspacePool<T> {
+impl<T: Float + Send + Sync, W: PooledWorkspace> ThreadLocalWorkspacePool<T, W> {
     /// Creates a new thread-local workspace pool.
     ///
+    /// Slots are sized for the current Rayon thread pool.
+    ///
     /// # Arguments
     ///
     /// * `default_paths` - Default path capacity for new workspaces
@@ -98,9 +128,13 @@ impl<T: Float + Send + Sync + 'static> ThreadLocalWorkspacePool<T> {
     /// let pool: ThreadLocalWorkspacePool<f64> = ThreadLocalWorkspacePool::new(10_000, 252);
     /// ```
     pub fn new(default_paths: usize, default_steps: usize) -> Self {
+        let slots = (0..=rayon::current_num_threads())
+            .map(|_| Mutex::new(None))
+            .collect();
         Self {
             default_paths,
             default_steps,
+            slots,
             workspace_count: AtomicUsize::new(0),
             _marker: std::marker::PhantomData,
         }
@@ -129,7 +163,8 @@ impl<T: Float + Send + Sync + 'static> ThreadLocalWorkspacePool<T> {
     /// Executes a closure with access to the thread-local workspace.
     ///
     /// The workspace is lazily created on first access for each thread.
-    /// Subsequent calls on the same thread reuse the existing workspace.
+    /// Subsequent calls on the same thread reuse the existing workspace,
+    /// so its contents are those left by the previous call.
     ///
     /// # Arguments
     ///
@@ -153,61 +188,36 @@ impl<T: Float + Send + Sync + 'static> ThreadLocalWorkspacePool<T> {
     /// ```
     pub fn with_workspace<F, R>(&self, f: F) -> R
     where
-        F: FnOnce(&mut CheckpointWorkspace<T>) -> R,
+        F: FnOnce(&mut W) -> R,
     {
-        // Use thread_local! for truly thread-local storage
-        thread_local! {
-            static WORKSPACE: RefCell<Option<CheckpointWorkspace<f64>>> = const { RefCell::new(None) };
+        // Rayon workers own slots 0..n, every other thread shares the last
+        let index = rayon::current_thread_index()
+            .filter(|&i| i + 1 < self.slots.len())
+            .unwrap_or(self.slots.len() - 1);
+        match self.slots[index].try_lock() {
+            Ok(mut slot) => {
+                let workspace = slot.get_or_insert_with(|| self.create());
+                f(workspace)
+            }
+            Err(_) => f(&mut self.create()),
         }
+    }
 
-        // Note: Due to Rust's type system limitations with thread_local!,
-        // we implement this as a specialized version for f64.
-        // For generic types, use the with_workspace_generic method or
-        // create workspaces manually per thread.
-
-        // Increment workspace count on first access
-        let default_paths = self.default_paths;
-        let default_steps = self.default_steps;
-        let workspace_count = &self.workspace_count;
-
-        // We need to work around thread_local! generic limitations
-        // by using a more flexible approach
-        self.with_workspace_impl(f, default_paths, default_steps, workspace_count)
-    }
-
-    /// Internal implementation of workspace access.
-    fn with_workspace_impl<F, R>(
-        &self,
-        f: F,
-        default_paths: usize,
-        default_steps: usize,
-        workspace_count: &AtomicUsize,
-    ) -> R
-    where
-        F: FnOnce(&mut CheckpointWorkspace<T>) -> R,
-    {
-        // For the generic case, we use a different approach:
-        // Each call creates a workspace. This is less efficient but generic.
-        // In practice, the specialized f64 version should be used.
-        let mut ws = CheckpointWorkspace::new(default_paths, default_steps);
-        workspace_count.fetch_add(1, Ordering::Relaxed);
-        f(&mut ws)
+    /// Creates a workspace with the default capacity.
+    fn create(&self) -> W {
+        self.workspace_count.fetch_add(1, Ordering::Relaxed);
+        W::with_capacity(self.default_paths, self.default_steps)
     }
 
-    /// Resets all thread-local workspaces.
+    /// Resets the workspace count.
     ///
-    /// Note: This only resets the workspace count. Actual thread-local
-    /// workspaces cannot be cleared from outside their owning threads.
-    /// Use `with_workspace` with explicit `clear_all()` call instead.
+    /// Note: This only resets the workspace count; the pooled workspaces
+    /// are kept for reuse.
     pub fn reset_count(&self) {
         self.workspace_count.store(0, Ordering::Relaxed);
     }
 }
 
-// Safety: The pool itself contains no mutable state that requires synchronization
-unsafe impl<T: Float + Send + Sync> Send for ThreadLocalWorkspacePool<T> {}
-unsafe impl<T: Float + Send + Sync> Sync for ThreadLocalWorkspacePool<T> {}
-
 /// Factory trait for creating workspaces.
 ///
 /// Implement this trait to customize workspace initialization.
@@ -373,6 +383,42 @@ mod tests {
         assert_eq!(pool.workspace_count(), 0);
     }
 
+    #[test]
+    fn test_pool_reuses_thread_workspace() {
+        let pool: ThreadLocalWorkspacePool<f64> = ThreadLocalWorkspacePool::new(10, 5);
+
+        pool.with_workspace(|ws| ws.observer_mut(0).observe(100.0));
+        let count = pool.with_workspace(|ws| ws.observer(0).count());
+
+        assert_eq!(count, 1);
+        assert_eq!(pool.workspace_count(), 1);
+    }
+
+    #[test]
+    fn test_pool_one_workspace_per_thread() {
+        let pool: ThreadLocalWorkspacePool<f64, PathWorkspace> =
+            ThreadLocalWorkspacePool::new(10, 5);
+
+        (0..1000).into_par_iter().for_each(|_| {
+            pool.with_workspace(|ws| ws.ensure_capacity(10, 5));
+        });
+
+        assert!(pool.workspace_count() <= rayon::current_num_threads());
+    }
+
+    #[test]
+    fn test_pool_nested_access_uses_temporary() {
+        let pool: ThreadLocalWorkspacePool<f64> = ThreadLocalWorkspacePool::new(10, 5);
+
+        let inner = pool.with_workspace(|outer| {
+            outer.observer_mut(0).observe(1.0);
+            pool.with_workspace(|inner| inner.observer(0).count())
+        });
+
+        assert_eq!(inner, 0);
+        assert_eq!(pool.workspace_count(), 2);
+    }
+
     // ========================================================================
     // ParallelWorkspaces Tests
     // ========================================================================
@@ -0,0 +1,352 @@
+//! Variance reduction for the Monte Carlo pricers.
+//!
+//! [`VarianceReduction`] combines three techniques:
+//!
+//! - **Antithetic variates**: path `i + n/2` is driven by the negated
+//!   normals of path `i`, and the pair average is the sampled quantity.
+//! - **Control variates**: a control `C` with known mean is regressed out,
+//!   `Y − β(C − E[C])` with `β = Cov(Y, C) / Var(C)` estimated from the
+//!   same paths. Arithmetic Asians use the geometric Asian on the same
+//!   fixings (Kemna-Vorst closed form); everything else uses the terminal
+//!   spot, whose mean is the forward.
+//! - **Importance sampling**: the Brownian motion is given a drift `θ`,
+//!   `W̃(t) = W(t) + θt`, and each payoff is weighted by the likelihood
+//!   ratio `exp(−θW̃(T) + θ²T/2)`. Shifting towards the strike makes deep
+//!   out-of-the-money payoffs frequent instead of rare.
+//!
+//! The achieved variance reduction factor is the plain Monte Carlo
+//! variance of the mean, estimated from the same paths, divided by the
+//! squared standard error of the variance-reduced estimator.
+
+use super::paths::GbmParams;
+use crate::analytical::asian::{geometric_asian_call, geometric_asian_put};
+
+/// Variance reduction techniques applied by the Monte Carlo pricer.
+///
+/// Antithetic variates apply to every simulation; control variates and
+/// importance sampling apply to GBM pricing (`price_european`,
+/// `price_path_dependent` and their Greeks).
+///
+/// # Example
+///
+/// ```rust
+/// use pricer_pricing::mc::{GbmParams, MonteCarloConfig, VarianceReduction};
+/// use pricer_pricing::mc::variance_reduction::strike_drift_shift;
+///
+/// let gbm = GbmParams::default();
+/// let vr = VarianceReduction::default()
+///     .with_antithetic()
+///     .with_control_variate()
+///     .with_drift_shift(strike_drift_shift(gbm, 160.0));
+///
+/// let config = MonteCarloConfig::builder()
+///     .n_paths(10_000)
+///     .n_steps(50)
+///     .variance_reduction(vr)
+///     .build()
+///     .unwrap();
+/// assert!(config.variance_reduction().is_active());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VarianceReduction {
+    /// Pair each path with its antithetic (negated normals) path.
+    pub antithetic: bool,
+    /// Regress out a control variate with known mean.
+    pub control_variate: bool,
+    /// Brownian drift `θ` for importance sampling, per unit time.
+    pub drift_shift: Option<f64>,
+}
+
+impl VarianceReduction {
+    /// Enables antithetic variates.
+    #[inline]
+    pub fn with_antithetic(mut self) -> Self {
+        self.antithetic = true;
+        self
+    }
+
+    /// Enables control variates.
+    #[inline]
+    pub fn with_control_variate(mut self) -> Self {
+        self.control_variate = true;
+        self
+    }
+
+    /// Enables importance sampling with Brownian drift `shift`.
+    #[inline]
+    pub fn with_drift_shift(mut self, shift: f64) -> Self {
+        self.drift_shift = Some(shift);
+        self
+    }
+
+    /// Returns true if any technique is enabled.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.antithetic || self.control_variate || self.drift_shift.is_some()
+    }
+}
+
+/// Brownian drift that centres the terminal GBM distribution on `strike`.
+///
+/// Solves `ln(K/S₀) = (r − σ²/2)T + σθT` for `θ`, so that the median of
+/// the shifted terminal spot is the strike. Positive for out-of-the-money
+/// calls, negative for out-of-the-money puts.
+///
+/// # Arguments
+///
+/// * `gbm` - GBM parameters of the simulation
+/// * `strike` - Strike to centre the paths on
+pub fn strike_drift_shift(gbm: GbmParams, strike: f64) -> f64 {
+    let drift = (gbm.rate - 0.5 * gbm.volatility * gbm.volatility) * gbm.maturity;
+    ((strike / gbm.spot).ln() - drift) / (gbm.volatility * gbm.maturity)
+}
+
+/// Negates the first half of `randoms` into the second half.
+///
+/// The buffer is path-major, so path `i + n/2` becomes the antithetic
+/// partner of path `i`.
+pub(crate) fn mirror_antithetic(randoms: &mut [f64]) {
+    let (first, second) = randoms.split_at_mut(randoms.len() / 2);
+    for (mirror, &z) in second.iter_mut().zip(first.iter()) {
+        *mirror = -z;
+    }
+}
+
+/// Shifts the single-factor normals of every path by `θ√dt` and returns
+/// the per-path likelihood ratios `exp(−θW̃(T) + θ²T/2)`.
+pub(crate) fn apply_drift_shift(
+    randoms: &mut [f64],
+    n_paths: usize,
+    n_steps: usize,
+    shift: f64,
+    maturity: f64,
+) -> Vec<f64> {
+    let sqrt_dt = (maturity / n_steps as f64).sqrt();
+    let step_shift = shift * sqrt_dt;
+    let half_shift_sq = 0.5 * shift * shift * maturity;
+
+    randoms[..n_paths * n_steps]
+        .chunks_exact_mut(n_steps)
+        .map(|path| {
+            let mut z_sum = 0.0;
+            for z in path.iter_mut() {
+                *z += step_shift;
+                z_sum += *z;
+            }
+            (-shift * sqrt_dt * z_sum + half_shift_sq).exp()
+        })
+        .collect()
+}
+
+/// Expected terminal spot `S₀·exp(rT)` under the simulated GBM.
+#[inline]
+pub(crate) fn expected_terminal_spot(gbm: GbmParams) -> f64 {
+    gbm.spot * (gbm.rate * gbm.maturity).exp()
+}
+
+/// Undiscounted expected payoff of a geometric Asian on the simulation
+/// grid: `n_steps + 1` equally spaced fixings including `t = 0`.
+///
+/// The log of the discrete geometric average is normal with mean
+/// `ln S₀ + (r − σ²/2)T/2` and variance `σ²T(2n+1)/(6(n+1))`. The
+/// continuous Kemna-Vorst formula has the same form, so it is evaluated
+/// with the volatility and dividend yield that reproduce these moments.
+pub(crate) fn expected_geometric_asian(
+    gbm: GbmParams,
+    strike: f64,
+    is_call: bool,
+    n_steps: usize,
+) -> f64 {
+    let n = n_steps as f64;
+    let vol_sq = gbm.volatility * gbm.volatility;
+    let discrete_vol_sq = vol_sq * (2.0 * n + 1.0) / (2.0 * (n + 1.0));
+    let dividend = 0.5 * (vol_sq - discrete_vol_sq);
+    let volatility = discrete_vol_sq.sqrt();
+
+    let price = if is_call {
+        geometric_asian_call(
+            gbm.spot,
+            strike,
+            gbm.rate,
+            dividend,
+            volatility,
+            gbm.maturity,
+        )
+    } else {
+        geometric_asian_put(
+            gbm.spot,
+            strike,
+            gbm.rate,
+            dividend,
+            volatility,
+            gbm.maturity,
+        )
+    };
+    price * (gbm.rate * gbm.maturity).exp()
+}
+
+/// Per-path samples of the variance-reduced estimator.
+pub(crate) struct ReducedSamples {
+    /// Samples whose mean is the undiscounted price estimate.
+    pub samples: Vec<f64>,
+    /// Plain Monte Carlo variance of a single payoff under the pricing
+    /// measure, estimated from the same paths.
+    pub plain_variance: f64,
+}
+
+impl ReducedSamples {
+    /// Builds the estimator samples from raw payoffs.
+    ///
+    /// # Arguments
+    ///
+    /// * `payoffs` - Undiscounted payoff per path
+    /// * `weights` - Importance sampling likelihood ratio per path
+    /// * `control` - Control value per path and its known mean
+    /// * `antithetic` - Whether paths `i` and `i + n/2` are antithetic pairs
+    pub fn new(
+        payoffs: &[f64],
+        weights: Option<&[f64]>,
+        control: Option<(&[f64], f64)>,
+        antithetic: bool,
+    ) -> Self {
+        let n = payoffs.len() as f64;
+        let weight = |i: usize| weights.map_or(1.0, |w| w[i]);
+
+        let mut values: Vec<f64> = payoffs
+            .iter()
+            .enumerate()
+            .map(|(i, &y)| y * weight(i))
+            .collect();
+        // E_P[Y²] = E_Q[Y²·L] under importance sampling
+        let mean = values.iter().sum::<f64>() / n;
+        let second_moment = payoffs
+            .iter()
+            .zip(&values)
+            .map(|(&y, &x)| y * x)
+            .sum::<f64>()
+            / n;
+        let plain_variance = (second_moment - mean * mean).max(0.0) * n / (n - 1.0).max(1.0);
+
+        let mut controls: Option<(Vec<f64>, f64)> = control.map(|(c, expected)| {
+            let weighted = c.iter().enumerate().map(|(i, &c)| c * weight(i)).collect();
+            (weighted, expected)
+        });
+
+        if antithetic {
+            values = pair_means(&values);
+            if let Some((c, _)) = controls.as_mut() {
+                *c = pair_means(c);
+            }
+        }
+
+        if let Some((c, expected)) = controls {
+            let beta = regression_coefficient(&values, &c);
+            for (v, c) in values.iter_mut().zip(&c) {
+                *v -= beta * (c - expected);
+            }
+        }
+
+        Self {
+            samples: values,
+            plain_variance,
+        }
+    }
+}
+
+/// Averages antithetic pairs `(i, i + n/2)`.
+fn pair_means(values: &[f64]) -> Vec<f64> {
+    let (first, second) = values.split_at(values.len() / 2);
+    first
+        .iter()
+        .zip(second)
+        .map(|(a, b)| 0.5 * (a + b))
+        .collect()
+}
+
+/// Least-squares slope `Cov(y, c) / Var(c)`, zero for a constant control.
+fn regression_coefficient(y: &[f64], c: &[f64]) -> f64 {
+    let n = y.len() as f64;
+    let y_mean = y.iter().sum::<f64>() / n;
+    let c_mean = c.iter().sum::<f64>() / n;
+    let (cov, var) = y.iter().zip(c).fold((0.0, 0.0), |(cov, var), (&y, &c)| {
+        let dc = c - c_mean;
+        (cov + (y - y_mean) * dc, var + dc * dc)
+    });
+    if var > 0.0 {
+        cov / var
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_mirror_antithetic() {
+        let mut randoms = vec![0.5, -1.0, 2.0, 0.0, 0.0, 0.0];
+        mirror_antithetic(&mut randoms);
+        assert_eq!(randoms, vec![0.5, -1.0, 2.0, -0.5, 1.0, -2.0]);
+    }
+
+    #[test]
+    fn test_strike_drift_shift_centres_terminal_median() {
+        let gbm = GbmParams::default();
+        let strike = 180.0;
+        let theta = strike_drift_shift(gbm, strike);
+        let log_median = gbm.spot.ln()
+            + (gbm.rate - 0.5 * gbm.volatility * gbm.volatility) * gbm.maturity
+            + gbm.volatility * theta * gbm.maturity;
+        assert!(theta > 0.0);
+        assert_relative_eq!(log_median.exp(), strike, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_drift_shift_weights_are_likelihood_ratios() {
+        // Zero normals after the shift: W̃(T) = 0, L = exp(θ²T/2)
+        let shift = 0.8;
+        let mut randoms = vec![-shift * 0.5_f64.sqrt(); 4];
+        let weights = apply_drift_shift(&mut randoms, 2, 2, shift, 1.0);
+        for z in randoms {
+            assert_relative_eq!(z, 0.0, epsilon = 1e-14);
+        }
+        for w in weights {
+            assert_relative_eq!(w, (0.5 * shift * shift).exp(), epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_expected_geometric_asian_converges_to_continuous() {
+        let gbm = GbmParams::default();
+        let growth = (gbm.rate * gbm.maturity).exp();
+        let continuous = geometric_asian_call(100.0, 100.0, 0.05, 0.0, 0.2, 1.0) * growth;
+
+        let coarse = expected_geometric_asian(gbm, 100.0, true, 4);
+        let fine = expected_geometric_asian(gbm, 100.0, true, 10_000);
+        // Fewer fixings give the average a smaller log-variance, so the
+        // discrete call is cheaper than its continuous limit
+        assert!(coarse < fine);
+        assert_relative_eq!(fine, continuous, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn test_control_variate_removes_linear_noise() {
+        // Y = 2 + 3(C − 1): the control explains all variance
+        let controls = [0.0, 1.0, 2.0, 3.0, -1.0];
+        let payoffs: Vec<f64> = controls.iter().map(|c| 2.0 + 3.0 * (c - 1.0)).collect();
+        let reduced = ReducedSamples::new(&payoffs, None, Some((&controls, 1.0)), false);
+        for s in &reduced.samples {
+            assert_relative_eq!(*s, 2.0, epsilon = 1e-12);
+        }
+        assert!(reduced.plain_variance > 0.0);
+    }
+
+    #[test]
+    fn test_antithetic_pairs_average() {
+        let payoffs = [1.0, 2.0, 3.0, 5.0];
+        let reduced = ReducedSamples::new(&payoffs, None, None, true);
+        assert_eq!(reduced.samples, vec![2.0, 3.5]);
+    }
+}
@@ -7,7 +7,7 @@
 //! # Memory Layout
 //!
 //! All buffers use row-major contiguous layout for cache efficiency:
-//! - `randoms`: n_paths × n_steps (random normal samples)
+//! - `randoms`: n_paths × n_steps × n_factors (random normal samples)
 //! - `paths`: n_paths × (n_steps + 1) (price paths including initial spot)
 //! - `payoffs`: n_paths (terminal payoff values)
 //!
@@ -42,7 +42,7 @@
 /// let paths = workspace.paths();
 /// ```
 pub struct PathWorkspace {
-    /// Random normal samples (n_paths × n_steps).
+    /// Random normal samples (n_paths × n_steps × n_factors).
     randoms: Vec<f64>,
     /// Price paths (n_paths × (n_steps + 1)).
     paths: Vec<f64>,
@@ -56,6 +56,8 @@ pub struct PathWorkspace {
     size_paths: usize,
     /// Logical size for steps dimension.
     size_steps: usize,
+    /// Logical number of Brownian factors per step.
+    size_factors: usize,
 }
 
 impl PathWorkspace {
@@ -85,6 +87,7 @@ impl PathWorkspace {
             capacity_steps: n_steps,
             size_paths: n_paths,
             size_steps: n_steps,
+            size_factors: 1,
         }
     }
 
@@ -108,6 +111,27 @@ impl PathWorkspace {
     /// // Buffers now have capacity for 1000 paths × 100 steps
     /// ```
     pub fn ensure_capacity(&mut self, n_paths: usize, n_steps: usize) {
+        self.ensure_capacity_with_factors(n_paths, n_steps, 1);
+    }
+
+    /// Ensures capacity for paths driven by `n_factors` Brownian motions.
+    ///
+    /// The random buffer then holds `n_factors` normals per step, ordered
+    /// `randoms[(path_idx * n_steps + step_idx) * n_factors + factor]`.
+    /// Price paths and payoffs are sized as in
+    /// [`ensure_capacity`](Self::ensure_capacity).
+    ///
+    /// # Arguments
+    ///
+    /// * `n_paths` - Required number of paths
+    /// * `n_steps` - Required number of steps
+    /// * `n_factors` - Number of Brownian factors per step
+    pub fn ensure_capacity_with_factors(
+        &mut self,
+        n_paths: usize,
+        n_steps: usize,
+        n_factors: usize,
+    ) {
         let needs_growth = n_paths > self.capacity_paths || n_steps > self.capacity_steps;
 
         if needs_growth {
@@ -118,7 +142,8 @@ impl PathWorkspace {
             let randoms_size = new_capacity_paths * new_capacity_steps;
             let paths_size = new_capacity_paths * (new_capacity_steps + 1);
 
-            self.randoms.resize(randoms_size, 0.0);
+            self.randoms
+                .resize(randoms_size.max(self.randoms.len()), 0.0);
             self.paths.resize(paths_size, 0.0);
             self.payoffs.resize(new_capacity_paths, 0.0);
 
@@ -126,8 +151,14 @@ impl PathWorkspace {
             self.capacity_steps = new_capacity_steps;
         }
 
+        let randoms_size = n_paths * n_steps * n_factors;
+        if randoms_size > self.randoms.len() {
+            self.randoms.resize(randoms_size, 0.0);
+        }
+
         self.size_paths = n_paths;
         self.size_steps = n_steps;
+        self.size_factors = n_factors;
     }
 
     /// Resets workspace state without deallocating buffers.
@@ -147,6 +178,7 @@ impl PathWorkspace {
     pub fn reset(&mut self) {
         self.size_paths = 0;
         self.size_steps = 0;
+        self.size_factors = 1;
     }
 
     /// Fast reset that preserves both capacity and logical size.
@@ -217,16 +249,23 @@ impl PathWorkspace {
         self.size_steps
     }
 
+    /// Returns the logical number of Brownian factors per step.
+    #[inline]
+    pub fn size_factors(&self) -> usize {
+        self.size_factors
+    }
+
     /// Returns mutable slice of random buffer for filling.
     ///
-    /// Returns slice of size `n_paths × n_steps` based on current logical size.
+    /// Returns slice of size `n_paths × n_steps × n_factors` based on current
+    /// logical size.
     ///
     /// # Panics
     ///
     /// Panics if logical size exceeds capacity (programming error).
     #[inline]
     pub fn randoms_mut(&mut self) -> &mut [f64] {
-        let len = self.size_paths * self.size_steps;
+        let len = self.size_paths * self.size_steps * self.size_factors;
         debug_assert!(len <= self.randoms.len());
         &mut self.randoms[..len]
     }
@@ -234,7 +273,7 @@ impl PathWorkspace {
     /// Returns slice of random buffer.
     #[inline]
     pub fn randoms(&self) -> &[f64] {
-        let len = self.size_paths * self.size_steps;
+        let len = self.size_paths * self.size_steps * self.size_factors;
         &self.randoms[..len]
     }
 
@@ -279,7 +318,7 @@ impl PathWorkspace {
     /// Returns mutable slice of paths and immutable slice of randoms.
     #[inline]
     pub fn paths_mut_and_randoms(&mut self) -> (&mut [f64], &[f64]) {
-        let randoms_len = self.size_paths * self.size_steps;
+        let randoms_len = self.size_paths * self.size_steps * self.size_factors;
         let paths_len = self.size_paths * (self.size_steps + 1);
         (&mut self.paths[..paths_len], &self.randoms[..randoms_len])
     }
@@ -415,6 +454,20 @@ mod tests {
         assert_eq!(ws.payoffs()[0], 10.0);
     }
 
+    #[test]
+    fn test_workspace_factor_randoms() {
+        let mut ws = PathWorkspace::new(100, 10);
+        ws.ensure_capacity_with_factors(100, 10, 2);
+        assert_eq!(ws.size_factors(), 2);
+        assert_eq!(ws.randoms_mut().len(), 2000);
+        assert_eq!(ws.paths().len(), 1100);
+
+        // Single-factor sizing is restored without shrinking the buffer
+        ws.ensure_capacity(100, 10);
+        assert_eq!(ws.size_factors(), 1);
+        assert_eq!(ws.randoms().len(), 1000);
+    }
+
     #[test]
     fn test_workspace_default() {
         let ws = PathWorkspace::default();
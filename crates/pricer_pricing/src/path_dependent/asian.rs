@@ -12,7 +12,7 @@
 //! When the `l1l2-integration` feature is enabled, payoffs use
 //! `pricer_core::math::smoothing::smooth_max`.
 
-use super::{ObservationType, PathDependentPayoff, PathObserver};
+use super::{ObservationType, PathDependentPayoff, PathObserver, PathObserverAdjoint};
 use num_traits::Float;
 
 /// Parameters for Asian option payoffs.
@@ -64,6 +64,20 @@ fn soft_plus<T: Float>(x: T, epsilon: T) -> T {
     }
 }
 
+/// Derivative of [`soft_plus`] with respect to `x` (a logistic sigmoid).
+#[inline]
+fn soft_plus_derivative<T: Float>(x: T, epsilon: T) -> T {
+    let scaled = x / epsilon;
+    let twenty = T::from(20.0).unwrap();
+    if scaled > twenty {
+        T::one()
+    } else if scaled < -twenty {
+        scaled.exp()
+    } else {
+        T::one() / (T::one() + (-scaled).exp())
+    }
+}
+
 /// Arithmetic average Asian option payoff.
 ///
 /// Payoff is based on the arithmetic mean of observed prices:
@@ -92,6 +106,30 @@ impl<T: Float> AsianArithmeticPayoff<T> {
     pub fn put(strike: T, epsilon: T) -> Self {
         Self::new(AsianParams::put(strike, epsilon))
     }
+
+    /// Returns the payoff parameters.
+    #[inline]
+    pub fn params(&self) -> AsianParams<T> {
+        self.params
+    }
+
+    /// Adjoint of the payoff with respect to the observer statistics.
+    pub fn observer_adjoint(&self, observer: &PathObserver<T>) -> PathObserverAdjoint<T> {
+        if observer.count() == 0 {
+            return PathObserverAdjoint::default();
+        }
+        let avg = observer.arithmetic_average();
+        let (intrinsic, sign) = if self.params.is_call {
+            (avg - self.params.strike, T::one())
+        } else {
+            (self.params.strike - avg, -T::one())
+        };
+        let n = T::from(observer.count()).unwrap();
+        PathObserverAdjoint {
+            running_sum: sign * soft_plus_derivative(intrinsic, self.params.smoothing_epsilon) / n,
+            ..Default::default()
+        }
+    }
 }
 
 impl<T: Float + Send + Sync> PathDependentPayoff<T> for AsianArithmeticPayoff<T> {
@@ -145,6 +183,27 @@ impl<T: Float> AsianGeometricPayoff<T> {
     pub fn put(strike: T, epsilon: T) -> Self {
         Self::new(AsianParams::put(strike, epsilon))
     }
+
+    /// Adjoint of the payoff with respect to the observer statistics.
+    pub fn observer_adjoint(&self, observer: &PathObserver<T>) -> PathObserverAdjoint<T> {
+        if observer.count() == 0 {
+            return PathObserverAdjoint::default();
+        }
+        let geo_avg = observer.geometric_average();
+        let (intrinsic, sign) = if self.params.is_call {
+            (geo_avg - self.params.strike, T::one())
+        } else {
+            (self.params.strike - geo_avg, -T::one())
+        };
+        let n = T::from(observer.count()).unwrap();
+        PathObserverAdjoint {
+            running_product_log: sign
+                * soft_plus_derivative(intrinsic, self.params.smoothing_epsilon)
+                * geo_avg
+                / n,
+            ..Default::default()
+        }
+    }
 }
 
 impl<T: Float + Send + Sync> PathDependentPayoff<T> for AsianGeometricPayoff<T> {
@@ -11,8 +11,16 @@
 //!
 //! Barrier conditions use smooth indicator functions for AD compatibility.
 //! The smooth indicator approximates the Heaviside step function.
+//!
+//! # Monitoring
+//!
+//! The barrier is checked only at the observed path points. For continuous
+//! monitoring with a Brownian-bridge or continuity correction, discrete
+//! schedules, double barriers and rebates, use
+//! [`BarrierOption`](crate::mc::BarrierOption) with
+//! [`MonteCarloPricer::price_barrier`](crate::mc::MonteCarloPricer::price_barrier).
 
-use super::{ObservationType, PathDependentPayoff, PathObserver};
+use super::{ObservationType, PathDependentPayoff, PathObserver, PathObserverAdjoint};
 use num_traits::Float;
 
 /// Barrier type enumeration.
@@ -134,6 +142,33 @@ fn soft_plus<T: Float>(x: T, epsilon: T) -> T {
     }
 }
 
+/// Derivative of [`smooth_indicator`] with respect to `x`.
+#[inline]
+fn smooth_indicator_derivative<T: Float>(x: T, epsilon: T) -> T {
+    let scaled = x / epsilon;
+    let twenty = T::from(20.0).unwrap();
+    if scaled > twenty || scaled < -twenty {
+        T::zero()
+    } else {
+        let s = T::one() / (T::one() + (-scaled).exp());
+        s * (T::one() - s) / epsilon
+    }
+}
+
+/// Derivative of [`soft_plus`] with respect to `x` (a logistic sigmoid).
+#[inline]
+fn soft_plus_derivative<T: Float>(x: T, epsilon: T) -> T {
+    let scaled = x / epsilon;
+    let twenty = T::from(20.0).unwrap();
+    if scaled > twenty {
+        T::one()
+    } else if scaled < -twenty {
+        scaled.exp()
+    } else {
+        T::one() / (T::one() + (-scaled).exp())
+    }
+}
+
 /// Barrier option payoff.
 ///
 /// Computes payoff for all four barrier types:
@@ -203,6 +238,64 @@ impl<T: Float> BarrierPayoff<T> {
         }
     }
 
+    /// Adjoint of the payoff with respect to the observer statistics.
+    pub fn observer_adjoint(&self, observer: &PathObserver<T>) -> PathObserverAdjoint<T> {
+        let epsilon = self.params.smoothing_epsilon;
+        let terminal = observer.terminal();
+        let barrier_ind = self.barrier_indicator(observer);
+        let vanilla = self.vanilla_payoff(terminal);
+
+        let intrinsic = if self.params.is_call {
+            terminal - self.params.strike
+        } else {
+            self.params.strike - terminal
+        };
+        let d_vanilla = soft_plus_derivative(intrinsic, epsilon);
+        let d_terminal = if self.params.is_call {
+            d_vanilla
+        } else {
+            T::zero() - d_vanilla
+        };
+
+        let mut adjoint = PathObserverAdjoint {
+            terminal: barrier_ind * d_terminal,
+            ..Default::default()
+        };
+        match self.params.barrier_type {
+            BarrierType::UpIn => {
+                adjoint.running_max = vanilla
+                    * smooth_indicator_derivative(
+                        observer.maximum() - self.params.barrier,
+                        epsilon,
+                    );
+            }
+            BarrierType::UpOut => {
+                adjoint.running_max = T::zero()
+                    - vanilla
+                        * smooth_indicator_derivative(
+                            observer.maximum() - self.params.barrier,
+                            epsilon,
+                        );
+            }
+            BarrierType::DownIn => {
+                adjoint.running_min = T::zero()
+                    - vanilla
+                        * smooth_indicator_derivative(
+                            self.params.barrier - observer.minimum(),
+                            epsilon,
+                        );
+            }
+            BarrierType::DownOut => {
+                adjoint.running_min = vanilla
+                    * smooth_indicator_derivative(
+                        self.params.barrier - observer.minimum(),
+                        epsilon,
+                    );
+            }
+        }
+        adjoint
+    }
+
     /// Computes the vanilla payoff (without barrier condition).
     fn vanilla_payoff(&self, terminal: T) -> T {
         let epsilon = self.params.smoothing_epsilon;
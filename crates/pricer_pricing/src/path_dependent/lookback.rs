@@ -9,7 +9,7 @@
 //!
 //! Lookback options have payoffs based on path extrema (maximum or minimum).
 
-use super::{ObservationType, PathDependentPayoff, PathObserver};
+use super::{ObservationType, PathDependentPayoff, PathObserver, PathObserverAdjoint};
 use num_traits::Float;
 
 /// Lookback option type.
@@ -100,6 +100,20 @@ fn soft_plus<T: Float>(x: T, epsilon: T) -> T {
     }
 }
 
+/// Derivative of [`soft_plus`] with respect to `x` (a logistic sigmoid).
+#[inline]
+fn soft_plus_derivative<T: Float>(x: T, epsilon: T) -> T {
+    let scaled = x / epsilon;
+    let twenty = T::from(20.0).unwrap();
+    if scaled > twenty {
+        T::one()
+    } else if scaled < -twenty {
+        scaled.exp()
+    } else {
+        T::one() / (T::one() + (-scaled).exp())
+    }
+}
+
 /// Lookback option payoff.
 ///
 /// Computes payoffs for all four lookback types using path extrema.
@@ -138,6 +152,45 @@ impl<T: Float> LookbackPayoff<T> {
     pub fn floating_put(epsilon: T) -> Self {
         Self::new(LookbackParams::floating_put(epsilon))
     }
+
+    /// Adjoint of the payoff with respect to the observer statistics.
+    pub fn observer_adjoint(&self, observer: &PathObserver<T>) -> PathObserverAdjoint<T> {
+        let epsilon = self.params.smoothing_epsilon;
+        let zero = T::zero();
+
+        match self.params.lookback_type {
+            LookbackType::FixedCall => {
+                let d = soft_plus_derivative(observer.maximum() - self.params.strike, epsilon);
+                PathObserverAdjoint {
+                    running_max: d,
+                    ..Default::default()
+                }
+            }
+            LookbackType::FixedPut => {
+                let d = soft_plus_derivative(self.params.strike - observer.minimum(), epsilon);
+                PathObserverAdjoint {
+                    running_min: zero - d,
+                    ..Default::default()
+                }
+            }
+            LookbackType::FloatingCall => {
+                let d = soft_plus_derivative(observer.terminal() - observer.minimum(), epsilon);
+                PathObserverAdjoint {
+                    terminal: d,
+                    running_min: zero - d,
+                    ..Default::default()
+                }
+            }
+            LookbackType::FloatingPut => {
+                let d = soft_plus_derivative(observer.maximum() - observer.terminal(), epsilon);
+                PathObserverAdjoint {
+                    running_max: d,
+                    terminal: zero - d,
+                    ..Default::default()
+                }
+            }
+        }
+    }
 }
 
 impl<T: Float + Send + Sync> PathDependentPayoff<T> for LookbackPayoff<T> {
@@ -28,7 +28,7 @@ mod payoff_type;
 pub use asian::{AsianArithmeticPayoff, AsianGeometricPayoff, AsianParams};
 pub use barrier::{BarrierParams, BarrierPayoff, BarrierType};
 pub use lookback::{LookbackParams, LookbackPayoff, LookbackType};
-pub use observer::{PathObserver, PathObserverState};
+pub use observer::{PathObserver, PathObserverAdjoint, PathObserverState};
 pub use payoff::{ObservationType, PathDependentPayoff};
 pub use payoff_type::PathPayoffType;
 
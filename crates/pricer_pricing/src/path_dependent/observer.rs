@@ -280,6 +280,64 @@ impl<T: Float> Default for PathObserverState<T> {
     }
 }
 
+/// Adjoints of a payoff with respect to the streaming statistics.
+///
+/// Produced by [`PathPayoffType::observer_adjoint`](super::PathPayoffType::observer_adjoint)
+/// and used by reverse-mode sweeps to turn the payoff sensitivity into a
+/// per-observation price adjoint via [`price_adjoint`](Self::price_adjoint).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathObserverAdjoint<T: Float> {
+    /// ∂payoff/∂(running sum)
+    pub running_sum: T,
+    /// ∂payoff/∂(running log sum)
+    pub running_product_log: T,
+    /// ∂payoff/∂(running maximum)
+    pub running_max: T,
+    /// ∂payoff/∂(running minimum)
+    pub running_min: T,
+    /// ∂payoff/∂(terminal price)
+    pub terminal: T,
+}
+
+impl<T: Float> PathObserverAdjoint<T> {
+    /// Adjoint of the payoff with respect to one observed price.
+    ///
+    /// The extremum adjoints are routed to the observation that attained
+    /// the final maximum/minimum of `observer` (pathwise derivative).
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - Observer after the full path has been observed
+    /// * `price` - The observed price
+    /// * `is_terminal` - Whether this observation is the terminal price
+    #[inline]
+    pub fn price_adjoint(&self, observer: &PathObserver<T>, price: T, is_terminal: bool) -> T {
+        let mut adjoint = self.running_sum + self.running_product_log / price;
+        if price == observer.maximum() {
+            adjoint = adjoint + self.running_max;
+        }
+        if price == observer.minimum() {
+            adjoint = adjoint + self.running_min;
+        }
+        if is_terminal {
+            adjoint = adjoint + self.terminal;
+        }
+        adjoint
+    }
+}
+
+impl<T: Float> Default for PathObserverAdjoint<T> {
+    fn default() -> Self {
+        Self {
+            running_sum: T::zero(),
+            running_product_log: T::zero(),
+            running_max: T::zero(),
+            running_min: T::zero(),
+            terminal: T::zero(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,7 +5,7 @@
 
 use super::{
     AsianArithmeticPayoff, AsianGeometricPayoff, AsianParams, BarrierPayoff, LookbackPayoff,
-    ObservationType, PathDependentPayoff, PathObserver,
+    ObservationType, PathDependentPayoff, PathObserver, PathObserverAdjoint,
 };
 use num_traits::Float;
 
@@ -154,6 +154,18 @@ impl<T: Float + Send + Sync> PathPayoffType<T> {
         }
     }
 
+    /// Computes the adjoint of the payoff with respect to the observer
+    /// statistics, for use in reverse-mode sweeps.
+    #[inline]
+    pub fn observer_adjoint(&self, observer: &PathObserver<T>) -> PathObserverAdjoint<T> {
+        match self {
+            PathPayoffType::AsianArithmetic(payoff) => payoff.observer_adjoint(observer),
+            PathPayoffType::AsianGeometric(payoff) => payoff.observer_adjoint(observer),
+            PathPayoffType::Barrier(payoff) => payoff.observer_adjoint(observer),
+            PathPayoffType::Lookback(payoff) => payoff.observer_adjoint(observer),
+        }
+    }
+
     /// Returns the observation types required for this payoff.
     #[inline]
     pub fn required_observations(&self) -> ObservationType {
@@ -538,4 +550,54 @@ mod tests {
 
         assert_eq!(result1, result2);
     }
+
+    // ========================================================================
+    // Observer Adjoint Tests
+    // ========================================================================
+
+    fn observe_path(path: &[f64]) -> PathObserver<f64> {
+        let mut observer = PathObserver::new();
+        for &price in path {
+            observer.observe(price);
+        }
+        observer.set_terminal(*path.last().unwrap());
+        observer
+    }
+
+    #[test]
+    fn test_observer_adjoint_matches_finite_difference() {
+        let path = [100.0, 104.0, 97.0, 108.0, 102.0];
+        let payoffs = [
+            PathPayoffType::asian_arithmetic_call(100.0, 2.0),
+            PathPayoffType::asian_arithmetic_put(103.0, 2.0),
+            PathPayoffType::asian_geometric_call(100.0, 2.0),
+            PathPayoffType::asian_geometric_put(103.0, 2.0),
+            PathPayoffType::barrier_up_in_call(100.0, 107.0, 2.0),
+            PathPayoffType::barrier_up_out_call(100.0, 109.0, 2.0),
+            PathPayoffType::barrier_down_in_put(105.0, 98.0, 2.0),
+            PathPayoffType::barrier_down_out_put(105.0, 96.0, 2.0),
+            PathPayoffType::lookback_fixed_call(105.0, 2.0),
+            PathPayoffType::lookback_fixed_put(100.0, 2.0),
+            PathPayoffType::lookback_floating_call(2.0),
+            PathPayoffType::lookback_floating_put(2.0),
+        ];
+        let h = 1e-6;
+
+        for payoff in payoffs {
+            let observer = observe_path(&path);
+            let adjoint = payoff.observer_adjoint(&observer);
+
+            for i in 0..path.len() {
+                let mut up = path;
+                up[i] += h;
+                let mut down = path;
+                down[i] -= h;
+                let fd = (payoff.compute(&[], &observe_path(&up))
+                    - payoff.compute(&[], &observe_path(&down)))
+                    / (2.0 * h);
+                let ad = adjoint.price_adjoint(&observer, path[i], i == path.len() - 1);
+                assert_relative_eq!(ad, fd, epsilon = 1e-6);
+            }
+        }
+    }
 }
@@ -2,7 +2,8 @@
 //!
 //! This module provides random number generation facilities for Monte Carlo
 //! simulations in the pricer kernel. It includes pseudo-random number generators
-//! (PRNGs) and placeholders for quasi-Monte Carlo (QMC) sequences.
+//! (PRNGs), counter-based Philox streams and quasi-Monte Carlo (QMC) Sobol
+//! sequences.
 //!
 //! ## Design Rationale
 //!
@@ -24,7 +25,10 @@
 //! ## Module Structure
 //!
 //! - [`prng`]: Pseudo-random number generator wrapper with seed management
-//! - [`qmc`]: Quasi-Monte Carlo sequence traits and placeholders
+//! - [`philox`]: Philox4x32-10 counter-based streams for reproducible
+//!   parallel simulation
+//! - [`qmc`]: Quasi-Monte Carlo sequence traits and the inverse normal CDF
+//! - [`sobol`]: Sobol sequence with Joe-Kuo direction numbers and scrambling
 //!
 //! ## Usage Example
 //!
@@ -56,16 +60,21 @@
 //! This phase implements:
 //! - PRNG wrapper around `rand::StdRng`
 //! - Normal distribution via Ziggurat algorithm (`rand_distr::StandardNormal`)
-//! - QMC trait definitions (placeholders only)
+//! - QMC trait definitions
 //!
-//! Sobol sequence implementation is deferred to a future phase.
+//! Sobol sequences (with digital-shift/Owen scrambling and skip-ahead) were
+//! added subsequently; see [`SobolSequence`].
 
+mod philox;
 mod prng;
 mod qmc;
+mod sobol;
 
 // Public re-exports
+pub use philox::{philox4x32_10, PhiloxRng};
 pub use prng::PricerRng;
-pub use qmc::{LowDiscrepancySequence, SobolPlaceholder};
+pub use qmc::{inverse_normal_cdf, LowDiscrepancySequence};
+pub use sobol::{Scrambling, SobolError, SobolSequence, JOE_KUO_TABLE, MAX_SOBOL_DIMENSION};
 
 #[cfg(test)]
 mod tests;
@@ -1,15 +1,9 @@
-//! Quasi-Monte Carlo sequence traits and placeholders.
+//! Quasi-Monte Carlo sequence traits.
 //!
 //! This module defines the interface for low-discrepancy sequences used in
-//! quasi-Monte Carlo (QMC) methods. Currently, only placeholder implementations
-//! are provided; full Sobol sequence support is planned for a future phase.
-//!
-//! ## Future Integration
-//!
-//! The following implementations are planned:
-//! - Sobol sequences via the `sobol` crate (21,201 dimensions supported)
-//! - Halton sequences for lower-dimensional problems
-//! - Joe-Kuo D6 direction numbers for improved uniformity
+//! quasi-Monte Carlo (QMC) methods, and the inverse normal CDF used to map
+//! their points to Gaussian variates. The Sobol implementation is
+//! [`SobolSequence`](super::SobolSequence).
 
 /// Trait for low-discrepancy sequences used in quasi-Monte Carlo methods.
 ///
@@ -17,15 +11,10 @@
 /// compared to pseudo-random sequences, often leading to faster convergence in
 /// numerical integration problems.
 ///
-/// # Future Implementation
-///
-/// This trait will be implemented by Sobol, Halton, and other low-discrepancy
-/// sequence generators in future phases.
-///
-/// # Warning
+/// # Implementations
 ///
-/// No implementations are available in Phase 3.1a. Attempting to use
-/// placeholder types will result in panics with clear error messages.
+/// - [`SobolSequence`](super::SobolSequence): Sobol points with optional
+///   digital-shift or Owen scrambling
 ///
 /// # British English Note
 ///
@@ -60,77 +49,89 @@ pub trait LowDiscrepancySequence {
     ///
     /// * `n` - Number of points to skip
     fn skip(&mut self, n: usize);
+
+    /// Advances the sequence and writes the next point mapped to standard
+    /// normal variates via [`inverse_normal_cdf`].
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - Buffer of at least `dimension()` values
+    fn next_normal_point(&mut self, out: &mut [f64]) {
+        let point = self.next_point();
+        for (z, &u) in out.iter_mut().zip(point) {
+            *z = inverse_normal_cdf(u);
+        }
+    }
 }
 
-/// Placeholder for Sobol sequence generator.
-///
-/// # Not Implemented
+/// Inverse of the standard normal CDF.
 ///
-/// This type exists to define the future interface for Sobol sequences.
-/// All methods will panic with `unimplemented!()` if called.
+/// Uses Acklam's rational approximation (relative error below 1.2e-9).
+/// Returns `-inf`/`+inf` at 0 and 1.
 ///
-/// # Future Plans
+/// # Arguments
 ///
-/// - Integration with `sobol` crate (21,201 dimensions supported)
-/// - Joe-Kuo D6 parameters for high-dimensional sequences
-/// - Antonov-Saleev grey code optimisation
+/// * `u` - Probability in [0, 1]
 ///
-/// # Example (will panic)
+/// # Examples
 ///
-/// ```rust,should_panic
-/// use pricer_pricing::rng::SobolPlaceholder;
+/// ```rust
+/// use pricer_pricing::rng::inverse_normal_cdf;
 ///
-/// let mut sobol = SobolPlaceholder::new(10);  // panics!
+/// assert!(inverse_normal_cdf(0.5).abs() < 1e-12);
+/// assert!((inverse_normal_cdf(0.975) - 1.959964).abs() < 1e-6);
 /// ```
-///
-/// # British English Note
-///
-/// All documentation uses British English spelling conventions.
-pub struct SobolPlaceholder {
-    /// The dimensionality of the sequence (stored but not used).
-    #[allow(dead_code)]
-    dimension: usize,
-    /// Placeholder for sequence state.
-    #[allow(dead_code)]
-    buffer: Vec<f64>,
-}
+pub fn inverse_normal_cdf(u: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
 
-impl SobolPlaceholder {
-    /// Creates a placeholder Sobol sequence generator.
-    ///
-    /// # Panics
-    ///
-    /// Always panics with a message indicating that Sobol sequences
-    /// are not implemented in Phase 3.1a.
-    ///
-    /// # Arguments
-    ///
-    /// * `dimension` - The desired dimensionality of the sequence
-    #[allow(clippy::new_ret_no_self)]
-    pub fn new(dimension: usize) -> Self {
-        unimplemented!(
-            "Sobol sequence not implemented in Phase 3.1a. \
-             Dimension {} was requested. \
-             See research.md for future integration plans with `sobol` crate.",
-            dimension
-        );
+    if u <= 0.0 {
+        return f64::NEG_INFINITY;
     }
-}
-
-impl LowDiscrepancySequence for SobolPlaceholder {
-    fn dimension(&self) -> usize {
-        unimplemented!("Sobol sequence not implemented in Phase 3.1a")
-    }
-
-    fn next_point(&mut self) -> &[f64] {
-        unimplemented!("Sobol sequence not implemented in Phase 3.1a")
-    }
-
-    fn reset(&mut self) {
-        unimplemented!("Sobol sequence not implemented in Phase 3.1a")
+    if u >= 1.0 {
+        return f64::INFINITY;
     }
 
-    fn skip(&mut self, _n: usize) {
-        unimplemented!("Sobol sequence not implemented in Phase 3.1a")
+    if u < P_LOW {
+        let q = (-2.0 * u.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if u <= 1.0 - P_LOW {
+        let q = u - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - u).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
     }
 }
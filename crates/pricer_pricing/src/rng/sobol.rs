@@ -0,0 +1,645 @@
+//! Sobol low-discrepancy sequence generator.
+//!
+//! This module provides [`SobolSequence`], a Sobol sequence generator with
+//! optional randomisation, implementing [`LowDiscrepancySequence`].
+//!
+//! ## Direction Numbers
+//!
+//! Primitive polynomials and initial direction numbers are read from
+//! [`JOE_KUO_TABLE`], embedded at compile time from the data file
+//! `joe_kuo_directions.txt` in the format of the Joe-Kuo
+//! `new-joe-kuo-6.21201` file, and [`MAX_SOBOL_DIMENSION`] follows its
+//! number of rows. The file holds the leading rows of the published table;
+//! replacing it with the full file raises the limit to 21201 without code
+//! changes. Larger dimensions are rejected rather than filled
+//! with unpublished numbers; other tables in the same format can be loaded
+//! with [`SobolSequence::from_joe_kuo`].
+//!
+//! ## Randomisation
+//!
+//! - [`Scrambling::DigitalShift`]: XOR with a random shift per dimension
+//! - [`Scrambling::Owen`]: hash-based nested uniform scrambling
+//!   (Laine-Karras permutation, Burley 2020)
+//!
+//! Independent randomisations (different seeds) yield independent unbiased
+//! estimates, whose spread gives a valid QMC error estimate.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use pricer_pricing::rng::{LowDiscrepancySequence, Scrambling, SobolSequence};
+//!
+//! let mut sobol = SobolSequence::with_scrambling(4, Scrambling::Owen, 42).unwrap();
+//! let point = sobol.next_point();
+//! assert_eq!(point.len(), 4);
+//! assert!(point.iter().all(|&x| x > 0.0 && x < 1.0));
+//! ```
+
+use super::qmc::LowDiscrepancySequence;
+use thiserror::Error;
+
+/// Maximum number of dimensions supported by [`SobolSequence`]: dimension
+/// 1 plus one per row of [`JOE_KUO_TABLE`].
+pub const MAX_SOBOL_DIMENSION: usize = 1 + count_rows(JOE_KUO_TABLE);
+
+/// Number of bits in each generated coordinate.
+const BITS: usize = 32;
+
+/// Scale converting a 32-bit integer coordinate to [0, 1).
+const SCALE: f64 = 1.0 / 4_294_967_296.0;
+
+/// Built-in direction numbers: rows `d s a m_1 ... m_s` of dimensions
+/// 2..=[`MAX_SOBOL_DIMENSION`] from the Joe-Kuo `new-joe-kuo-6.21201` file,
+/// in its published format (dimension 1 uses `m_k = 1`), embedded from
+/// `joe_kuo_directions.txt`.
+pub const JOE_KUO_TABLE: &str = include_str!("joe_kuo_directions.txt");
+
+/// Number of direction number rows (lines starting with a digit) in a
+/// Joe-Kuo table.
+const fn count_rows(table: &str) -> usize {
+    let bytes = table.as_bytes();
+    let mut rows = 0;
+    let mut line_start = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        if line_start && bytes[i].is_ascii_digit() {
+            rows += 1;
+        }
+        line_start = bytes[i] == b'\n';
+        i += 1;
+    }
+    rows
+}
+
+/// Errors from Sobol sequence construction.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum SobolError {
+    /// Requested dimension is zero or exceeds the supported maximum.
+    #[error("Invalid Sobol dimension {dimension} (supported: 1..={max})")]
+    InvalidDimension {
+        /// Requested dimension
+        dimension: usize,
+        /// Maximum supported dimension
+        max: usize,
+    },
+
+    /// Direction number table could not be parsed or is inconsistent.
+    #[error("Invalid direction numbers: {0}")]
+    InvalidDirectionNumbers(String),
+}
+
+/// Randomisation applied to Sobol points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Scrambling {
+    /// Plain (deterministic) Sobol points.
+    #[default]
+    None,
+    /// Random digital shift: each coordinate is XOR-ed with a random word.
+    DigitalShift,
+    /// Owen-style nested uniform scrambling (hash-based).
+    Owen,
+}
+
+/// Sobol sequence generator with Gray-code ordering.
+///
+/// Points are generated in Antonov-Saleev (Gray code) order, so each new
+/// point costs one XOR per dimension. [`skip`](LowDiscrepancySequence::skip)
+/// jumps directly to any index.
+///
+/// Coordinates are returned as `(x + 0.5) / 2^32`, so they lie strictly
+/// inside (0, 1) and can be mapped through an inverse CDF.
+#[derive(Clone, Debug)]
+pub struct SobolSequence {
+    /// Number of dimensions.
+    dimension: usize,
+    /// Direction numbers, `BITS` per dimension.
+    directions: Vec<u32>,
+    /// Integer coordinates of the current (unscrambled) point.
+    state: Vec<u32>,
+    /// Index of the next point to be returned.
+    index: u64,
+    /// Randomisation method.
+    scrambling: Scrambling,
+    /// Per-dimension randomisation words (shift or scrambling seed).
+    randomisation: Vec<u32>,
+    /// Output buffer for the current point.
+    point: Vec<f64>,
+}
+
+impl SobolSequence {
+    /// Creates an unscrambled Sobol sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - Number of dimensions in `1..=MAX_SOBOL_DIMENSION`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SobolError::InvalidDimension` if `dimension` is out of range.
+    pub fn new(dimension: usize) -> Result<Self, SobolError> {
+        Self::with_scrambling(dimension, Scrambling::None, 0)
+    }
+
+    /// Creates a randomised Sobol sequence.
+    ///
+    /// Different seeds give independent randomisations of the same
+    /// underlying point set.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - Number of dimensions in `1..=MAX_SOBOL_DIMENSION`
+    /// * `scrambling` - Randomisation method
+    /// * `seed` - Seed for the randomisation (ignored for `Scrambling::None`)
+    ///
+    /// # Errors
+    ///
+    /// Returns `SobolError::InvalidDimension` if `dimension` is out of range.
+    /// Direction numbers come from [`JOE_KUO_TABLE`].
+    pub fn with_scrambling(
+        dimension: usize,
+        scrambling: Scrambling,
+        seed: u64,
+    ) -> Result<Self, SobolError> {
+        if dimension == 0 || dimension > MAX_SOBOL_DIMENSION {
+            return Err(SobolError::InvalidDimension {
+                dimension,
+                max: MAX_SOBOL_DIMENSION,
+            });
+        }
+
+        Self::from_joe_kuo(dimension, JOE_KUO_TABLE, scrambling, seed)
+    }
+
+    /// Creates a Sobol sequence from a Joe-Kuo direction number table.
+    ///
+    /// `table` uses the published format: an optional header line followed
+    /// by lines `d s a m_1 ... m_s` for dimensions 2, 3, .... Dimension 1
+    /// is implicit.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - Number of dimensions (at most one more than the table rows)
+    /// * `table` - Contents of a Joe-Kuo direction number file
+    /// * `scrambling` - Randomisation method
+    /// * `seed` - Seed for the randomisation
+    ///
+    /// # Errors
+    ///
+    /// Returns `SobolError::InvalidDirectionNumbers` if the table is malformed
+    /// or too short, or `SobolError::InvalidDimension` if `dimension` is zero.
+    pub fn from_joe_kuo(
+        dimension: usize,
+        table: &str,
+        scrambling: Scrambling,
+        seed: u64,
+    ) -> Result<Self, SobolError> {
+        if dimension == 0 {
+            return Err(SobolError::InvalidDimension {
+                dimension,
+                max: MAX_SOBOL_DIMENSION,
+            });
+        }
+
+        let mut directions = Vec::with_capacity(dimension * BITS);
+        directions.extend(unit_directions());
+
+        let rows = table
+            .lines()
+            .map(str::trim)
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_digit()));
+        for line in rows.take(dimension - 1) {
+            let fields: Vec<u32> = line
+                .split_whitespace()
+                .map(|f| f.parse::<u32>())
+                .collect::<Result<_, _>>()
+                .map_err(|e| SobolError::InvalidDirectionNumbers(format!("'{line}': {e}")))?;
+            let (degree, coefficients) = match fields.as_slice() {
+                [_, s, a, ..] => (*s as usize, *a),
+                _ => {
+                    return Err(SobolError::InvalidDirectionNumbers(format!(
+                        "'{line}': expected 'd s a m_1 ... m_s'"
+                    )))
+                }
+            };
+            let initial = &fields[3..];
+            let valid = degree > 0
+                && degree < BITS
+                && initial.len() == degree
+                && initial
+                    .iter()
+                    .enumerate()
+                    .all(|(k, &m)| m % 2 == 1 && m < (1u32 << (k + 1)));
+            if !valid {
+                return Err(SobolError::InvalidDirectionNumbers(format!(
+                    "'{line}': inconsistent degree or initial numbers"
+                )));
+            }
+            directions.extend(direction_numbers(degree, coefficients, initial));
+        }
+
+        if directions.len() != dimension * BITS {
+            return Err(SobolError::InvalidDirectionNumbers(format!(
+                "table provides {} dimensions, {} requested",
+                directions.len() / BITS,
+                dimension
+            )));
+        }
+
+        Ok(Self::from_directions(
+            dimension, directions, scrambling, seed,
+        ))
+    }
+
+    fn from_directions(
+        dimension: usize,
+        directions: Vec<u32>,
+        scrambling: Scrambling,
+        seed: u64,
+    ) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let randomisation = match scrambling {
+            Scrambling::None => vec![0; dimension],
+            _ => (0..dimension).map(|_| rng.next_u64() as u32).collect(),
+        };
+
+        Self {
+            dimension,
+            directions,
+            state: vec![0; dimension],
+            index: 0,
+            scrambling,
+            randomisation,
+            point: vec![0.0; dimension],
+        }
+    }
+
+    /// Returns the randomisation method.
+    #[inline]
+    pub fn scrambling(&self) -> Scrambling {
+        self.scrambling
+    }
+
+    /// Returns the index of the next point to be generated.
+    #[inline]
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the direction numbers of dimension `dim` (0-based).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dim >= dimension()`.
+    #[inline]
+    pub fn direction_numbers(&self, dim: usize) -> &[u32] {
+        &self.directions[dim * BITS..(dim + 1) * BITS]
+    }
+
+    /// Scrambles integer coordinate `x` of dimension `dim`.
+    #[inline]
+    fn randomise(&self, x: u32, dim: usize) -> u32 {
+        match self.scrambling {
+            Scrambling::None => x,
+            Scrambling::DigitalShift => x ^ self.randomisation[dim],
+            Scrambling::Owen => nested_uniform_scramble(x, self.randomisation[dim]),
+        }
+    }
+}
+
+impl LowDiscrepancySequence for SobolSequence {
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn next_point(&mut self) -> &[f64] {
+        for dim in 0..self.dimension {
+            let x = self.randomise(self.state[dim], dim);
+            self.point[dim] = (x as f64 + 0.5) * SCALE;
+        }
+
+        // Gray code update: flip the direction number of the lowest zero bit
+        let bit = self.index.trailing_ones() as usize;
+        if bit < BITS {
+            for dim in 0..self.dimension {
+                self.state[dim] ^= self.directions[dim * BITS + bit];
+            }
+        }
+        self.index += 1;
+
+        &self.point
+    }
+
+    fn reset(&mut self) {
+        self.state.iter_mut().for_each(|x| *x = 0);
+        self.index = 0;
+    }
+
+    fn skip(&mut self, n: usize) {
+        self.index += n as u64;
+        let gray = self.index ^ (self.index >> 1);
+        for dim in 0..self.dimension {
+            let directions = &self.directions[dim * BITS..(dim + 1) * BITS];
+            self.state[dim] = (0..BITS)
+                .filter(|&bit| (gray >> bit) & 1 == 1)
+                .fold(0, |acc, bit| acc ^ directions[bit]);
+        }
+    }
+}
+
+/// Direction numbers of the first dimension (van der Corput sequence).
+fn unit_directions() -> impl Iterator<Item = u32> {
+    (0..BITS).map(|k| 1u32 << (BITS - 1 - k))
+}
+
+/// Expands initial numbers `m_1..m_s` into `BITS` direction numbers using
+/// the recurrence of the primitive polynomial `(degree, coefficients)`.
+fn direction_numbers(degree: usize, coefficients: u32, initial: &[u32]) -> Vec<u32> {
+    let mut v = vec![0u32; BITS];
+    for k in 0..degree.min(BITS) {
+        v[k] = initial[k] << (BITS - 1 - k);
+    }
+    for k in degree..BITS {
+        let mut value = v[k - degree] ^ (v[k - degree] >> degree);
+        for j in 1..degree {
+            if (coefficients >> (degree - 1 - j)) & 1 == 1 {
+                value ^= v[k - j];
+            }
+        }
+        v[k] = value;
+    }
+    v
+}
+
+/// Hash-based nested uniform (Owen) scrambling of a 32-bit coordinate.
+///
+/// Each output bit depends only on the seed and the more significant input
+/// bits, which preserves the net structure of the point set.
+#[inline]
+fn nested_uniform_scramble(x: u32, seed: u32) -> u32 {
+    laine_karras_permutation(x.reverse_bits(), seed).reverse_bits()
+}
+
+/// Laine-Karras style permutation with Burley's improved constants.
+#[inline]
+fn laine_karras_permutation(mut x: u32, seed: u32) -> u32 {
+    x = x.wrapping_add(seed);
+    x ^= x.wrapping_mul(0x6c50_b47c);
+    x ^= x.wrapping_mul(0xb82f_1e52);
+    x ^= x.wrapping_mul(0xc7af_e638);
+    x ^= x.wrapping_mul(0x8d22_f6e6);
+    x
+}
+
+/// SplitMix64 generator for randomisation words.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// Returns the first `count` primitive polynomials over GF(2) of degree ≥ 1
+    /// as `(degree, a)` pairs, ordered by degree and then by `a`, where `a`
+    /// encodes the interior coefficients (Joe-Kuo convention).
+    fn primitive_polynomials(count: usize) -> Vec<(usize, u32)> {
+        let mut result = Vec::with_capacity(count);
+        let mut degree = 1;
+        while result.len() < count {
+            let order = (1u64 << degree) - 1;
+            let cofactors: Vec<u64> = prime_factors(order).iter().map(|q| order / q).collect();
+            for a in 0..(1u32 << (degree - 1)) {
+                if result.len() == count {
+                    break;
+                }
+                let poly = (1u64 << degree) | ((a as u64) << 1) | 1;
+                let is_primitive = poly_pow_x(order, poly, degree) == 1
+                    && cofactors.iter().all(|&e| poly_pow_x(e, poly, degree) != 1);
+                if is_primitive {
+                    result.push((degree, a));
+                }
+            }
+            degree += 1;
+        }
+        result
+    }
+
+    /// Distinct prime factors of `n`.
+    fn prime_factors(mut n: u64) -> Vec<u64> {
+        let mut factors = Vec::new();
+        let mut p = 2;
+        while p * p <= n {
+            if n % p == 0 {
+                factors.push(p);
+                while n % p == 0 {
+                    n /= p;
+                }
+            }
+            p += 1;
+        }
+        if n > 1 {
+            factors.push(n);
+        }
+        factors
+    }
+
+    /// Computes `x^e mod poly` over GF(2), where `poly` has the given degree.
+    fn poly_pow_x(mut e: u64, poly: u64, degree: usize) -> u64 {
+        let mut result = 1u64;
+        // Modulo x + 1 the polynomial x reduces to 1
+        let mut base = if degree == 1 { 1 } else { 2 };
+        while e > 0 {
+            if e & 1 == 1 {
+                result = poly_mul_mod(result, base, poly, degree);
+            }
+            base = poly_mul_mod(base, base, poly, degree);
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Multiplies two polynomials of degree < `degree` modulo `poly` over GF(2).
+    fn poly_mul_mod(mut a: u64, mut b: u64, poly: u64, degree: usize) -> u64 {
+        let mut result = 0u64;
+        while b > 0 {
+            if b & 1 == 1 {
+                result ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if (a >> degree) & 1 == 1 {
+                a ^= poly;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_primitive_polynomials_match_joe_kuo_order() {
+        let polys = primitive_polynomials(20);
+        assert_eq!(&polys[..5], &[(1, 0), (2, 1), (3, 1), (3, 2), (4, 1)]);
+        // Degree 5 and 6 rows of new-joe-kuo-6.21201
+        let degree_5: Vec<u32> = polys.iter().filter(|p| p.0 == 5).map(|p| p.1).collect();
+        assert_eq!(degree_5, vec![2, 4, 7, 11, 13, 14]);
+        let degree_6: Vec<u32> = polys.iter().filter(|p| p.0 == 6).map(|p| p.1).collect();
+        assert_eq!(degree_6, vec![1, 13, 16, 19, 22, 25]);
+    }
+
+    #[test]
+    fn test_table_polynomials_match_enumeration() {
+        let rows: Vec<Vec<u32>> = JOE_KUO_TABLE
+            .lines()
+            .skip(1)
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|f| f.parse().unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(rows.len(), MAX_SOBOL_DIMENSION - 1);
+
+        let polys = primitive_polynomials(rows.len());
+        for (i, (row, &(degree, a))) in rows.iter().zip(&polys).enumerate() {
+            assert_eq!(row[0] as usize, i + 2);
+            assert_eq!(
+                (row[1] as usize, row[2]),
+                (degree, a),
+                "dimension {}",
+                i + 2
+            );
+        }
+    }
+
+    #[test]
+    fn test_first_dimensions_known_points() {
+        let mut sobol = SobolSequence::new(2).unwrap();
+        let expected = [
+            [0.0, 0.0],
+            [0.5, 0.5],
+            [0.75, 0.25],
+            [0.25, 0.75],
+            [0.375, 0.375],
+            [0.875, 0.875],
+        ];
+        for point in expected {
+            let x = sobol.next_point();
+            assert_relative_eq!(x[0], point[0], epsilon = 1e-9);
+            assert_relative_eq!(x[1], point[1], epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_stratification_of_first_power_of_two() {
+        // Every dimension of the first 2^m points hits each interval
+        // [k/2^m, (k+1)/2^m) exactly once
+        let m = 8;
+        let n = 1usize << m;
+        let dim = MAX_SOBOL_DIMENSION;
+        for scrambling in [Scrambling::None, Scrambling::DigitalShift, Scrambling::Owen] {
+            let mut sobol = SobolSequence::with_scrambling(dim, scrambling, 7).unwrap();
+            let mut counts = vec![vec![0usize; n]; dim];
+            for _ in 0..n {
+                let x = sobol.next_point();
+                for d in 0..dim {
+                    counts[d][(x[d] * n as f64) as usize] += 1;
+                }
+            }
+            assert!(counts.iter().flatten().all(|&c| c == 1), "{scrambling:?}");
+        }
+    }
+
+    #[test]
+    fn test_skip_matches_sequential() {
+        let mut sequential =
+            SobolSequence::with_scrambling(MAX_SOBOL_DIMENSION, Scrambling::Owen, 3).unwrap();
+        for _ in 0..1000 {
+            sequential.next_point();
+        }
+        let mut skipped =
+            SobolSequence::with_scrambling(MAX_SOBOL_DIMENSION, Scrambling::Owen, 3).unwrap();
+        skipped.skip(1000);
+        assert_eq!(skipped.index(), 1000);
+        assert_eq!(sequential.next_point(), skipped.next_point());
+
+        skipped.reset();
+        let mut fresh =
+            SobolSequence::with_scrambling(MAX_SOBOL_DIMENSION, Scrambling::Owen, 3).unwrap();
+        assert_eq!(skipped.next_point(), fresh.next_point());
+    }
+
+    #[test]
+    fn test_max_dimension_construction() {
+        let mut sobol = SobolSequence::new(MAX_SOBOL_DIMENSION).unwrap();
+        sobol.skip(12345);
+        let x = sobol.next_point();
+        assert!(x.iter().all(|&v| v > 0.0 && v < 1.0));
+        assert!(SobolSequence::new(0).is_err());
+        assert_eq!(
+            SobolSequence::with_scrambling(MAX_SOBOL_DIMENSION + 1, Scrambling::Owen, 1)
+                .unwrap_err(),
+            SobolError::InvalidDimension {
+                dimension: MAX_SOBOL_DIMENSION + 1,
+                max: MAX_SOBOL_DIMENSION,
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_joe_kuo_matches_builtin() {
+        let table = "d s a m_i\n\
+                     2 1 0 1\n\
+                     3 2 1 1 3\n\
+                     4 3 1 1 3 1\n\
+                     5 3 2 1 1 1\n";
+        let parsed = SobolSequence::from_joe_kuo(5, table, Scrambling::None, 0).unwrap();
+        let builtin = SobolSequence::new(5).unwrap();
+        for dim in 0..5 {
+            assert_eq!(
+                parsed.direction_numbers(dim),
+                builtin.direction_numbers(dim)
+            );
+        }
+
+        assert!(SobolSequence::from_joe_kuo(7, table, Scrambling::None, 0).is_err());
+        assert!(SobolSequence::from_joe_kuo(2, "2 1 0 2\n", Scrambling::None, 0).is_err());
+    }
+
+    #[test]
+    fn test_randomisations_are_independent_and_unbiased() {
+        // Estimate E[x_1 x_2 ... x_5] = 1/32 with independent Owen scramblings
+        let dim = 5;
+        let n = 1024;
+        let estimates: Vec<f64> = (0..16)
+            .map(|seed| {
+                let mut sobol =
+                    SobolSequence::with_scrambling(dim, Scrambling::Owen, seed).unwrap();
+                (0..n)
+                    .map(|_| sobol.next_point().iter().product::<f64>())
+                    .sum::<f64>()
+                    / n as f64
+            })
+            .collect();
+
+        let mean = estimates.iter().sum::<f64>() / estimates.len() as f64;
+        let spread = estimates
+            .iter()
+            .map(|e| (e - mean).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        assert!(spread > 0.0);
+        assert_relative_eq!(mean, 1.0 / 32.0, epsilon = 1e-4);
+    }
+}
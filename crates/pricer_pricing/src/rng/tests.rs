@@ -4,7 +4,7 @@
 //! - Module structure and public API accessibility
 //! - PRNG seed reproducibility
 //! - Distribution properties (uniform range, normal moments)
-//! - QMC placeholder behaviour
+//! - QMC sequence behaviour
 //! - Large batch performance characteristics
 //! - Statistical properties via property-based testing
 
@@ -22,9 +22,9 @@ fn test_module_structure() {
     // Verify LowDiscrepancySequence trait is accessible
     fn _accepts_lds<T: LowDiscrepancySequence>(_: &T) {}
 
-    // Verify SobolPlaceholder type is accessible (but construction panics)
-    // Just checking the type exists at compile time
-    let _: fn(usize) -> SobolPlaceholder = SobolPlaceholder::new;
+    // Verify SobolSequence is accessible and implements the trait
+    let sobol = SobolSequence::new(3).unwrap();
+    _accepts_lds(&sobol);
 }
 
 /// Verifies that the same seed produces identical sequences.
@@ -83,11 +83,31 @@ fn test_empty_buffer() {
     rng.fill_normal(&mut empty);
 }
 
-/// Verifies that SobolPlaceholder::new panics with appropriate message.
+/// Verifies that Sobol points map to standard normal variates.
 #[test]
-#[should_panic(expected = "Sobol sequence not implemented in Phase 3.1a")]
-fn test_sobol_placeholder_panics() {
-    let _ = SobolPlaceholder::new(10);
+fn test_sobol_normal_points() {
+    let mut sobol = SobolSequence::with_scrambling(2, Scrambling::Owen, 1).unwrap();
+    let n = 4096;
+    let mut z = [0.0; 2];
+    let (mut sum, mut sum_sq) = (0.0, 0.0);
+    for _ in 0..n {
+        sobol.next_normal_point(&mut z);
+        sum += z[0];
+        sum_sq += z[0] * z[0];
+    }
+    let mean = sum / n as f64;
+    assert!(mean.abs() < 1e-3);
+    assert!((sum_sq / n as f64 - 1.0).abs() < 1e-2);
+}
+
+/// Verifies the inverse normal CDF against known quantiles.
+#[test]
+fn test_inverse_normal_cdf_quantiles() {
+    assert!(inverse_normal_cdf(0.5).abs() < 1e-12);
+    assert!((inverse_normal_cdf(0.841_344_746_068_543) - 1.0).abs() < 1e-8);
+    assert!((inverse_normal_cdf(0.001) + 3.090_232_306_167_813).abs() < 1e-8);
+    assert!((inverse_normal_cdf(0.999) - 3.090_232_306_167_813).abs() < 1e-8);
+    assert_eq!(inverse_normal_cdf(0.0), f64::NEG_INFINITY);
 }
 
 // ============================================================================
@@ -314,8 +334,7 @@ fn test_public_api_accessibility() {
 
     // Test that QMC types are accessible
     fn accepts_trait<T: LowDiscrepancySequence>(_: &T) {}
-    // SobolPlaceholder type exists (construction panics but type is accessible)
-    let _type_check: fn(usize) -> SobolPlaceholder = SobolPlaceholder::new;
+    accepts_trait(&SobolSequence::new(1).unwrap());
 }
 
 /// Verifies that the module has no dependencies on pricer_core.
@@ -422,7 +441,7 @@ fn test_documentation_completeness() {
     // Documented items:
     // - PricerRng struct and all public methods
     // - LowDiscrepancySequence trait and all methods
-    // - SobolPlaceholder struct and methods
+    // - SobolSequence struct and methods
     // - Module-level documentation in mod.rs
     //
     // British English conventions used:
@@ -12,6 +12,10 @@ pub enum CliError {
     #[error("Configuration error: {0}")]
     Config(#[from] config::ConfigError),
 
+    /// Engine settings error
+    #[error("Settings error: {0}")]
+    Settings(#[from] infra_config::ConfigError),
+
     /// I/O error
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
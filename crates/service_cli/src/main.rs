@@ -14,6 +14,8 @@
 //! orchestrates all other layers to provide a unified command-line interface.
 
 use clap::{Parser, Subcommand};
+use infra_config::Settings;
+use pricer_core::types::tape::Tape;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -111,6 +113,10 @@ fn main() -> Result<()> {
         info!("Verbose mode enabled");
     }
 
+    // Cap the AD tape of every pricing thread from the engine settings
+    let settings = Settings::load()?;
+    Tape::set_default_memory_limit(Some(settings.engine.memory_limit_bytes()));
+
     match cli.command {
         Commands::Calibrate {
             market_data,
@@ -23,6 +23,8 @@
 use std::net::SocketAddr;
 
 use anyhow::Result;
+use infra_config::Settings;
+use pricer_core::types::tape::Tape;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -45,9 +47,14 @@ async fn main() -> Result<()> {
     // Load configuration
     let config = config::ServerConfig::from_env()?;
 
+    // Cap the AD tape of every worker thread from the engine settings
+    let settings = Settings::load()?;
+    Tape::set_default_memory_limit(Some(settings.engine.memory_limit_bytes()));
+
     info!("Configuration loaded");
     info!("  REST enabled: {}", config.rest_enabled);
     info!("  gRPC enabled: {}", config.grpc_enabled);
+    info!("  AD memory limit: {} MB", settings.engine.memory_limit_mb);
 
     // Start REST server
     #[cfg(feature = "rest")]
@@ -2,6 +2,12 @@
 //!
 //! This module exposes Rust structs as Python classes.
 
+// PyO3 0.22's `#[pyfunction]` expansion converts `PyResult` errors into
+// `PyErr` again, which clippy flags as a useless conversion.
+#![allow(clippy::useless_conversion)]
+
+use pricer_models::analytical::BlackScholes;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 // ============================================================================
@@ -116,10 +122,13 @@ impl PyHullWhite {
 /// * `spot` - Current spot price
 /// * `vol` - Volatility (annualised)
 /// * `rate` - Risk-free rate (annualised)
-/// * `dividend` - Dividend yield (optional, default 0.0)
+/// * `dividend` - Continuous dividend yield (optional, default 0.0)
 ///
 /// # Returns
 /// The option price
+///
+/// # Errors
+/// Raises `ValueError` for non-positive spot or volatility
 #[pyfunction]
 #[pyo3(signature = (option, spot, vol, rate, dividend=0.0))]
 pub fn price_black_scholes(
@@ -128,22 +137,16 @@ pub fn price_black_scholes(
     vol: f64,
     rate: f64,
     dividend: f64,
-) -> f64 {
-    // Use pricer_models::analytical::black_scholes
-    // For now, return a placeholder implementation
-    let _ = dividend;
-    let d1 = ((spot / option.strike).ln() + (rate + 0.5 * vol * vol) * option.expiry)
-        / (vol * option.expiry.sqrt());
-    let d2 = d1 - vol * option.expiry.sqrt();
-
-    let nd1 = normal_cdf(d1);
-    let nd2 = normal_cdf(d2);
-
-    if option.is_call {
-        spot * nd1 - option.strike * (-rate * option.expiry).exp() * nd2
+) -> PyResult<f64> {
+    let model = BlackScholes::new(spot, rate, vol)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?
+        .with_dividend_yield(dividend);
+
+    Ok(if option.is_call {
+        model.price_call(option.strike, option.expiry)
     } else {
-        option.strike * (-rate * option.expiry).exp() * normal_cdf(-d2) - spot * normal_cdf(-d1)
-    }
+        model.price_put(option.strike, option.expiry)
+    })
 }
 
 /// Price an FX option using Garman-Kohlhagen formula
@@ -157,6 +160,9 @@ pub fn price_black_scholes(
 ///
 /// # Returns
 /// The option price
+///
+/// # Errors
+/// Raises `ValueError` for non-positive spot or volatility
 #[pyfunction]
 pub fn price_garman_kohlhagen(
     option: &PyVanillaOption,
@@ -164,28 +170,7 @@ pub fn price_garman_kohlhagen(
     vol: f64,
     domestic_rate: f64,
     foreign_rate: f64,
-) -> f64 {
+) -> PyResult<f64> {
     // Garman-Kohlhagen is Black-Scholes with foreign rate as dividend
     price_black_scholes(option, spot, vol, domestic_rate, foreign_rate)
 }
-
-// ============================================================================
-// Helper Functions
-// ============================================================================
-
-/// Standard normal CDF approximation (Abramowitz and Stegun)
-fn normal_cdf(x: f64) -> f64 {
-    let a1 = 0.254829592;
-    let a2 = -0.284496736;
-    let a3 = 1.421413741;
-    let a4 = -1.453152027;
-    let a5 = 1.061405429;
-    let p = 0.3275911;
-
-    let sign = if x < 0.0 { -1.0 } else { 1.0 };
-    let x = x.abs() / std::f64::consts::SQRT_2;
-    let t = 1.0 / (1.0 + p * x);
-    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
-
-    0.5 * (1.0 + sign * y)
-}
@@ -25,6 +25,9 @@
 //! print(f"Option price: {price}")
 //! ```
 
+use infra_config::Settings;
+use pricer_core::types::tape::Tape;
+use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
 
 mod bindings;
@@ -35,6 +38,10 @@ mod bindings;
 /// for Greeks computation.
 #[pymodule]
 fn neutryx(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Cap the AD tape of every thread from the engine settings
+    let settings = Settings::load().map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    Tape::set_default_memory_limit(Some(settings.engine.memory_limit_bytes()));
+
     // Register instrument types
     m.add_class::<bindings::PyVanillaOption>()?;
     m.add_class::<bindings::PyForward>()?;